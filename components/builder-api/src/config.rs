@@ -39,6 +39,9 @@ pub struct Config {
     pub events_enabled: bool,
     /// Where to record log events for funnel metrics
     pub log_dir: String,
+    /// Allow projects of the `local` VCS type, whose plans are read from a repository on the
+    /// API's own filesystem. Only useful when the workers share that filesystem.
+    pub allow_local_vcs: bool,
 }
 
 impl Default for Config {
@@ -51,6 +54,7 @@ impl Default for Config {
             depot: depot::config::Config::default(),
             events_enabled: false,
             log_dir: env::temp_dir().to_string_lossy().into_owned(),
+            allow_local_vcs: false,
         }
     }
 }
@@ -121,6 +125,8 @@ mod tests {
     #[test]
     fn config_from_file() {
         let content = r#"
+        allow_local_vcs = true
+
         [http]
         listen = "0:0:0:0:0:0:0:1"
         port = 9636
@@ -163,6 +169,7 @@ mod tests {
         assert_eq!(config.github.client_secret,
                    "438223113eeb6e7edf2d2f91a232b72de72b9bdf");
         assert_eq!(config.ui.root, Some("/some/path".to_string()));
+        assert!(config.allow_local_vcs);
     }
 
     #[test]
@@ -174,5 +181,6 @@ mod tests {
 
         let config = Config::from_raw(&content).unwrap();
         assert_eq!(config.http.port, 9000);
+        assert!(!config.allow_local_vcs);
     }
 }
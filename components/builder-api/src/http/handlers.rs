@@ -15,6 +15,9 @@
 //! A collection of handlers for the HTTP server's router
 
 use std::env;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
 
 use base64;
 use bodyparser;
@@ -23,25 +26,29 @@ use hab_core::package::Plan;
 use hab_core::event::*;
 use hab_net;
 use hab_net::http::controller::*;
+use hab_net::oauth::github::GitHubClient;
 use hab_net::routing::Broker;
 use iron::prelude::*;
 use iron::status;
 use iron::typemap;
 use params::{Params, Value, FromValue};
 use persistent;
-use protocol::jobsrv::{Job, JobGet, JobLogGet, JobLog, JobSpec, ProjectJobsGet, ProjectJobsGetResponse};
+use protocol::jobsrv::{Job, JobCancel, JobGet, JobLogGet, JobLog, JobPublishRetry, JobSpec,
+                       JobState, ProjectJobsGet, ProjectJobsGetResponse};
 use protocol::originsrv::*;
 use protocol::sessionsrv;
 use protocol::net::{self, NetOk, ErrCode};
 use router::Router;
 
-// For the initial release, Builder will only be enabled on the "core"
-// origin. Later, we'll roll it out to other origins; at that point,
-// we should consider other options, such as configurable middleware.
-const BUILDER_ENABLED_ORIGIN: &'static str = "core";
-
 define_event_log!();
 
+/// Whether projects may use the `local` VCS type, see the `allow_local_vcs` setting.
+pub struct AllowLocalVcs;
+
+impl typemap::Key for AllowLocalVcs {
+    type Value = bool;
+}
+
 #[derive(Clone, Serialize, Deserialize)]
 struct JobCreateReq {
     project_id: String,
@@ -51,21 +58,152 @@ struct JobCreateReq {
 struct ProjectCreateReq {
     origin: String,
     plan_path: String,
+    /// Repository holding the plan, unused by `local` projects
+    #[serde(default)]
     github: GitHubProject,
+    #[serde(default)]
+    cross_origin_rdeps: bool,
+    /// Build timeout in seconds, 0 for the worker's default
+    #[serde(default)]
+    build_timeout: u32,
+    #[serde(default)]
+    vcs: VcsSpec,
 }
 
 #[derive(Clone, Serialize, Deserialize)]
 struct ProjectUpdateReq {
     plan_path: String,
+    #[serde(default)]
     github: GitHubProject,
+    #[serde(default)]
+    cross_origin_rdeps: bool,
+    #[serde(default)]
+    build_timeout: u32,
+    #[serde(default)]
+    vcs: VcsSpec,
 }
 
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Clone, Default, Serialize, Deserialize)]
 struct GitHubProject {
     organization: String,
     repo: String,
 }
 
+/// How the worker checks out a project's source. Every field is optional and defaults to an
+/// anonymous, full clone of the default branch of the GitHub repository.
+#[derive(Clone, Default, Serialize, Deserialize)]
+struct VcsSpec {
+    /// Either `git`, the default, or `local` for a repository on the filesystem shared by the
+    /// API and the workers. `local` is refused unless `allow_local_vcs` is set.
+    #[serde(default, rename = "type")]
+    vcs_type: Option<String>,
+    /// Path or `file://` URL of a `local` repository
+    #[serde(default)]
+    url: String,
+    /// Branch, tag or commit SHA to build
+    #[serde(default, rename = "ref")]
+    reference: String,
+    #[serde(default)]
+    submodules: bool,
+    /// One of `anonymous`, `token` or `deploy-key`
+    #[serde(default)]
+    auth: Option<String>,
+    /// Name of the origin or project secret holding the token or deploy key
+    #[serde(default)]
+    auth_secret: String,
+}
+
+impl VcsSpec {
+    fn is_local(&self) -> bool {
+        self.vcs_type.as_ref().map_or(false, |vcs_type| vcs_type == "local")
+    }
+
+    /// Apply the spec to the project, returning the response to render if it is invalid.
+    fn apply(self, project: &mut OriginProject, allow_local: bool) -> Option<Response> {
+        match self.vcs_type.as_ref().map_or("git", |vcs_type| vcs_type.as_str()) {
+            "git" => project.set_vcs_type(String::from("git")),
+            "local" if !allow_local => {
+                return Some(Response::with((status::UnprocessableEntity,
+                                            "Local repositories are not allowed")))
+            }
+            "local" if self.url.len() <= 0 => {
+                return Some(Response::with((status::UnprocessableEntity,
+                                            "Missing value for field: `vcs.url`")))
+            }
+            "local" => {
+                project.set_vcs_type(String::from("local"));
+                project.set_vcs_data(self.url.clone());
+            }
+            _ => {
+                return Some(Response::with((status::UnprocessableEntity,
+                                            "Unknown value for field: `vcs.type`")))
+            }
+        }
+        let auth = match self.auth {
+            Some(ref auth) => {
+                match auth.parse::<VcsAuth>() {
+                    Ok(auth) => auth,
+                    Err(_) => {
+                        return Some(Response::with((status::UnprocessableEntity,
+                                                    "Unknown value for field: `vcs.auth`")))
+                    }
+                }
+            }
+            None => VcsAuth::Anonymous,
+        };
+        if auth != VcsAuth::Anonymous && self.auth_secret.len() <= 0 {
+            return Some(Response::with((status::UnprocessableEntity,
+                                        "Missing value for field: `vcs.auth_secret`")));
+        }
+        project.set_vcs_ref(self.reference);
+        project.set_vcs_submodules(self.submodules);
+        project.set_vcs_auth(auth);
+        project.set_vcs_auth_secret(self.auth_secret);
+        None
+    }
+}
+
+/// Fetches the contents of the project's plan, from GitHub or, for `local` projects, from the
+/// repository's working tree on this host. The error codes are rendered when the plan can't be
+/// fetched and when GitHub's contents can't be decoded, respectively.
+fn fetch_plan(github: &GitHubClient,
+              token: &str,
+              repo: &GitHubProject,
+              project: &OriginProject,
+              codes: (&'static str, &'static str))
+              -> Result<Vec<u8>, Response> {
+    if project.get_vcs_type() == "local" {
+        return read_local_plan(project).map_err(|e| {
+            warn!("Unable to read local plan, project={}, err={}",
+                  project.get_vcs_data(),
+                  e);
+            Response::with((status::UnprocessableEntity, codes.0))
+        });
+    }
+    match github.contents(token, &repo.organization, &repo.repo, project.get_plan_path()) {
+        Ok(contents) => {
+            base64::decode(&contents.content).map_err(|e| {
+                error!("Base64 decode failure: {:?}", e);
+                Response::with((status::UnprocessableEntity, codes.1))
+            })
+        }
+        Err(_) => Err(Response::with((status::UnprocessableEntity, codes.0))),
+    }
+}
+
+fn read_local_plan(project: &OriginProject) -> io::Result<Vec<u8>> {
+    let url = project.get_vcs_data();
+    let root = if url.starts_with("file://") {
+        &url["file://".len()..]
+    } else {
+        url
+    };
+    let mut bytes = vec![];
+    try!(try!(File::open(Path::new(root).join(project.get_plan_path())))
+             .read_to_end(&mut bytes));
+    Ok(bytes)
+}
+
 pub fn github_authenticate(req: &mut Request) -> IronResult<Response> {
     let code = {
         let params = req.extensions.get::<Router>().unwrap();
@@ -155,6 +293,86 @@ pub fn job_show(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+/// Cancel the given job. Responds with 200 if the job was cancelled right away, or with 202 if
+/// the worker building it has been asked to stop. Cancelling a job which failed to publish
+/// abandons its shelved artifact.
+pub fn job_cancel(req: &mut Request) -> IronResult<Response> {
+    let (session_id, id) = {
+        let session = req.extensions.get::<Authenticated>().unwrap();
+        let params = req.extensions.get::<Router>().unwrap();
+        match params.find("id").unwrap().parse::<u64>() {
+            Ok(id) => (session.get_id(), id),
+            Err(_) => return Ok(Response::with(status::BadRequest)),
+        }
+    };
+    let mut conn = Broker::connect().unwrap();
+    let mut job_get = JobGet::new();
+    job_get.set_id(id);
+    let job = match conn.route::<JobGet, Job>(&job_get) {
+        Ok(job) => job,
+        Err(err) => return Ok(render_net_error(&err)),
+    };
+
+    if !try!(check_origin_access(req, session_id, job.get_project().get_origin_name())) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut request = JobCancel::new();
+    request.set_id(id);
+    match conn.route::<JobCancel, Job>(&request) {
+        Ok(job) => {
+            log_event!(req,
+                       Event::JobCancel {
+                           job: job.get_id().to_string(),
+                           account: session_id.to_string(),
+                       });
+            match job.get_state() {
+                JobState::Cancelled | JobState::Failed => Ok(render_json(status::Ok, &job)),
+                _ => Ok(render_json(status::Accepted, &job)),
+            }
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+/// Publish the shelved artifact of a job which failed in post-processing again, without
+/// rebuilding it. Responds with 202 once the worker keeping the artifact has been asked to.
+pub fn job_publish_retry(req: &mut Request) -> IronResult<Response> {
+    let (session_id, id) = {
+        let session = req.extensions.get::<Authenticated>().unwrap();
+        let params = req.extensions.get::<Router>().unwrap();
+        match params.find("id").unwrap().parse::<u64>() {
+            Ok(id) => (session.get_id(), id),
+            Err(_) => return Ok(Response::with(status::BadRequest)),
+        }
+    };
+    let mut conn = Broker::connect().unwrap();
+    let mut job_get = JobGet::new();
+    job_get.set_id(id);
+    let job = match conn.route::<JobGet, Job>(&job_get) {
+        Ok(job) => job,
+        Err(err) => return Ok(render_net_error(&err)),
+    };
+
+    if !try!(check_origin_access(req, session_id, job.get_project().get_origin_name())) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut request = JobPublishRetry::new();
+    request.set_id(id);
+    match conn.route::<JobPublishRetry, Job>(&request) {
+        Ok(job) => {
+            log_event!(req,
+                       Event::JobPublishRetry {
+                           job: job.get_id().to_string(),
+                           account: session_id.to_string(),
+                       });
+            Ok(render_json(status::Accepted, &job))
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
 pub fn job_log(req: &mut Request) -> IronResult<Response> {
     let start = {
         let params = req.get_ref::<Params>().unwrap();
@@ -232,16 +450,14 @@ pub fn list_user_origins(req: &mut Request) -> IronResult<Response> {
 
 /// Create a new project as the authenticated user and associated to
 /// the given origin.
-///
-/// NOTE: This currently only allows creation of projects in the
-/// "core" origin.
 pub fn project_create(req: &mut Request) -> IronResult<Response> {
     let mut request = OriginProjectCreate::new();
     let mut project = OriginProject::new();
     let mut origin_get = OriginGet::new();
     let github = req.get::<persistent::Read<GitHubCli>>().unwrap();
+    let allow_local = *req.get::<persistent::Read<AllowLocalVcs>>().unwrap();
     let session = req.extensions.get::<Authenticated>().unwrap().clone();
-    let (organization, repo) = match req.get::<bodyparser::Struct<ProjectCreateReq>>() {
+    let gh_repo = match req.get::<bodyparser::Struct<ProjectCreateReq>>() {
         Ok(Some(body)) => {
             if body.origin.len() <= 0 {
                 return Ok(Response::with((status::UnprocessableEntity,
@@ -251,24 +467,33 @@ pub fn project_create(req: &mut Request) -> IronResult<Response> {
                 return Ok(Response::with((status::UnprocessableEntity,
                                           "Missing value for field: `plan_path`")));
             }
-            if body.github.organization.len() <= 0 {
+            let local = body.vcs.is_local();
+            if !local && body.github.organization.len() <= 0 {
                 return Ok(Response::with((status::UnprocessableEntity,
                                           "Missing value for field: `github.organization`")));
             }
-            if body.github.repo.len() <= 0 {
+            if !local && body.github.repo.len() <= 0 {
                 return Ok(Response::with((status::UnprocessableEntity,
                                           "Missing value for field: `github.repo`")));
             }
             origin_get.set_name(body.origin);
             project.set_plan_path(body.plan_path);
-            project.set_vcs_type(String::from("git"));
-            match github.repo(&session.get_token(),
-                              &body.github.organization,
-                              &body.github.repo) {
-                Ok(repo) => project.set_vcs_data(repo.clone_url),
-                Err(_) => return Ok(Response::with((status::UnprocessableEntity, "rg:pc:1"))),
+            project.set_cross_origin_rdeps(body.cross_origin_rdeps);
+            project.set_build_timeout(body.build_timeout);
+            if let Some(response) = body.vcs.apply(&mut project, allow_local) {
+                return Ok(response);
+            }
+            if !local {
+                match github.repo(&session.get_token(),
+                                  &body.github.organization,
+                                  &body.github.repo) {
+                    Ok(repo) => project.set_vcs_data(repo.clone_url),
+                    Err(_) => {
+                        return Ok(Response::with((status::UnprocessableEntity, "rg:pc:1")))
+                    }
+                }
             }
-            (body.github.organization, body.github.repo)
+            body.github
         }
         _ => return Ok(Response::with(status::UnprocessableEntity)),
     };
@@ -278,36 +503,25 @@ pub fn project_create(req: &mut Request) -> IronResult<Response> {
         Err(err) => return Ok(render_net_error(&err)),
     };
 
-    // Only allow projects to be created for the core origin initially.
-    if origin.get_name() != BUILDER_ENABLED_ORIGIN {
-        return Ok(Response::with((status::UnprocessableEntity, "rg:pc:5")))
+    if !try!(check_origin_access(req, session.get_id(), origin.get_name())) {
+        return Ok(Response::with(status::Forbidden));
     }
-    
-    match github.contents(&session.get_token(),
-                          &organization,
-                          &repo,
-                          &project.get_plan_path()) {
-        Ok(contents) => {
-            match base64::decode(&contents.content) {
-                Ok(ref bytes) => {
-                    match Plan::from_bytes(bytes) {
-                        Ok(plan) => {
-                            project.set_origin_name(String::from(origin.get_name()));
-                            project.set_origin_id(origin.get_id());
-                            project.set_package_name(String::from(plan.name));
-                        }
-                        Err(_) => {
-                            return Ok(Response::with((status::UnprocessableEntity, "rg:pc:3")))
-                        }
-                    }
-                }
-                Err(e) => {
-                    error!("Base64 decode failure: {:?}", e);
-                    return Ok(Response::with((status::UnprocessableEntity, "rg:pc:4")));
-                }
-            }
+
+    let bytes = match fetch_plan(&github,
+                                 session.get_token(),
+                                 &gh_repo,
+                                 &project,
+                                 ("rg:pc:2", "rg:pc:4")) {
+        Ok(bytes) => bytes,
+        Err(response) => return Ok(response),
+    };
+    match Plan::from_bytes(&bytes) {
+        Ok(plan) => {
+            project.set_origin_name(String::from(origin.get_name()));
+            project.set_origin_id(origin.get_id());
+            project.set_package_name(String::from(plan.name));
         }
-        Err(_) => return Ok(Response::with((status::UnprocessableEntity, "rg:pc:2"))),
+        Err(_) => return Ok(Response::with((status::UnprocessableEntity, "rg:pc:3"))),
     }
 
     project.set_owner_id(session.get_id());
@@ -338,13 +552,6 @@ pub fn project_delete(req: &mut Request) -> IronResult<Response> {
         let origin = params.find("origin").unwrap().to_owned();
         let name = params.find("name").unwrap();
 
-        // We're only allowing projects to be created for the core
-        // origin initially. Thus, if we try to delete a project for any
-        // other origin, we can safely short-circuit processing.
-        if origin != BUILDER_ENABLED_ORIGIN {
-            return Ok(Response::with((status::NotFound, "rg:pd:1")))
-        }
-
         project_del.set_name(format!("{}/{}", origin, name));
         (session_id, origin)
     };
@@ -368,19 +575,13 @@ pub fn project_update(req: &mut Request) -> IronResult<Response> {
         let params = req.extensions.get::<Router>().unwrap();
         let origin = params.find("origin").unwrap().to_owned();
         let name = params.find("name").unwrap().to_owned();
-
-        // We're only allowing projects to be created for the core
-        // origin initially. Thus, if we try to update a project for
-        // any other origin, we can safely short-circuit processing.
-        if origin != BUILDER_ENABLED_ORIGIN {
-            return Ok(Response::with((status::NotFound, "rg:pu:6")))
-        }
         (name, origin)
     };
 
     let mut request = OriginProjectUpdate::new();
     let mut project = OriginProject::new();
     let github = req.get::<persistent::Read<GitHubCli>>().unwrap();
+    let allow_local = *req.get::<persistent::Read<AllowLocalVcs>>().unwrap();
 
     let (session_token, session_id) = {
         let session = req.extensions.get::<Authenticated>().unwrap();
@@ -390,58 +591,60 @@ pub fn project_update(req: &mut Request) -> IronResult<Response> {
         (session_token, session_id)
     };
 
-    let (organization, repo) = match req.get::<bodyparser::Struct<ProjectCreateReq>>() {
+    let gh_repo = match req.get::<bodyparser::Struct<ProjectCreateReq>>() {
         Ok(Some(body)) => {
             if body.plan_path.len() <= 0 {
                 return Ok(Response::with((status::UnprocessableEntity,
                                           "Missing value for field: `plan_path`")));
             }
-            if body.github.organization.len() <= 0 {
+            let local = body.vcs.is_local();
+            if !local && body.github.organization.len() <= 0 {
                 return Ok(Response::with((status::UnprocessableEntity,
                                           "Missing value for field: `github.organization`")));
             }
-            if body.github.repo.len() <= 0 {
+            if !local && body.github.repo.len() <= 0 {
                 return Ok(Response::with((status::UnprocessableEntity,
                                           "Missing value for field: `github.repo`")));
             }
-            project.set_vcs_type(String::from("git"));
             project.set_plan_path(body.plan_path);
-            match github.repo(&session_token, &body.github.organization, &body.github.repo) {
-                Ok(repo) => project.set_vcs_data(repo.clone_url),
-                Err(_) => return Ok(Response::with((status::UnprocessableEntity, "rg:pu:1"))),
+            project.set_cross_origin_rdeps(body.cross_origin_rdeps);
+            project.set_build_timeout(body.build_timeout);
+            if let Some(response) = body.vcs.apply(&mut project, allow_local) {
+                return Ok(response);
             }
-            (body.github.organization, body.github.repo)
+            if !local {
+                match github.repo(&session_token, &body.github.organization, &body.github.repo) {
+                    Ok(repo) => project.set_vcs_data(repo.clone_url),
+                    Err(_) => {
+                        return Ok(Response::with((status::UnprocessableEntity, "rg:pu:1")))
+                    }
+                }
+            }
+            body.github
         }
         _ => return Ok(Response::with(status::UnprocessableEntity)),
     };
     let mut conn = Broker::connect().unwrap();
-    match github.contents(&session_token,
-                          &organization,
-                          &repo,
-                          &project.get_plan_path()) {
-        Ok(contents) => {
-            match base64::decode(&contents.content) {
-                Ok(ref bytes) => {
-                    match Plan::from_bytes(bytes) {
-                        Ok(plan) => {
-                           if !try!(check_origin_access(req, session_id, &origin)) {
-                                return Ok(Response::with(status::Forbidden));
-                            }
-                            if plan.name != name {
-                                return Ok(Response::with((status::UnprocessableEntity, "rg:pu:2")));
-                            }
-                            project.set_origin_name(String::from(origin));
-                            project.set_package_name(String::from(name));
-                        }
-                        Err(_) => {
-                            return Ok(Response::with((status::UnprocessableEntity, "rg:pu:3")))
-                        }
-                    }
-                }
-                Err(_) => return Ok(Response::with((status::UnprocessableEntity, "rg:pu:4"))),
+    let bytes = match fetch_plan(&github,
+                                 &session_token,
+                                 &gh_repo,
+                                 &project,
+                                 ("rg:pu:5", "rg:pu:4")) {
+        Ok(bytes) => bytes,
+        Err(response) => return Ok(response),
+    };
+    match Plan::from_bytes(&bytes) {
+        Ok(plan) => {
+            if !try!(check_origin_access(req, session_id, &origin)) {
+                return Ok(Response::with(status::Forbidden));
+            }
+            if plan.name != name {
+                return Ok(Response::with((status::UnprocessableEntity, "rg:pu:2")));
             }
+            project.set_origin_name(String::from(origin));
+            project.set_package_name(String::from(name));
         }
-        Err(_) => return Ok(Response::with((status::UnprocessableEntity, "rg:pu:5"))),
+        Err(_) => return Ok(Response::with((status::UnprocessableEntity, "rg:pu:3"))),
     }
     // JW TODO: owner_id should *not* be changing but we aren't using it just yet. FIXME before
     // making the project API public.
@@ -457,19 +660,16 @@ pub fn project_update(req: &mut Request) -> IronResult<Response> {
 /// Display the the given project's details
 pub fn project_show(req: &mut Request) -> IronResult<Response> {
     let mut project_get = OriginProjectGet::new();
-    let params = req.extensions.get::<Router>().unwrap();
-    {
-        let origin = params.find("origin").unwrap();
-
-        // We're only allowing projects to be created for the core
-        // origin initially. Thus, if we try to get a project for any
-        // other origin, we can safely short-circuit processing.
-        if origin != BUILDER_ENABLED_ORIGIN {
-            return Ok(Response::with((status::NotFound, "rg:ps:1")))
-        }
-        
+    let (session_id, origin) = {
+        let session_id = req.extensions.get::<Authenticated>().unwrap().get_id();
+        let params = req.extensions.get::<Router>().unwrap();
+        let origin = params.find("origin").unwrap().to_owned();
         let name = params.find("name").unwrap();
         project_get.set_name(format!("{}/{}", origin, name));
+        (session_id, origin)
+    };
+    if !try!(check_origin_access(req, session_id, origin)) {
+        return Ok(Response::with(status::Forbidden));
     }
     let mut conn = Broker::connect().unwrap();
     match conn.route::<OriginProjectGet, OriginProject>(&project_get) {
@@ -484,19 +684,16 @@ pub fn project_show(req: &mut Request) -> IronResult<Response> {
 /// etc.
 pub fn project_jobs(req: &mut Request) -> IronResult<Response> {
     let mut jobs_get = ProjectJobsGet::new();
-    let params = req.extensions.get::<Router>().unwrap();
-    {
-        let origin = params.find("origin").unwrap();
-
-        // We're only allowing projects to be created for the core
-        // origin initially. Thus, if we try to get jobs for any
-        // project in another, we can safely short-circuit processing.
-        if origin != BUILDER_ENABLED_ORIGIN {
-            return Ok(Response::with((status::NotFound, "rg:pj:1")))
-        }
-
+    let (session_id, origin) = {
+        let session_id = req.extensions.get::<Authenticated>().unwrap().get_id();
+        let params = req.extensions.get::<Router>().unwrap();
+        let origin = params.find("origin").unwrap().to_owned();
         let name = params.find("name").unwrap();
         jobs_get.set_name(format!("{}/{}", origin, name));
+        (session_id, origin)
+    };
+    if !try!(check_origin_access(req, session_id, origin)) {
+        return Ok(Response::with(status::Forbidden));
     }
     let mut conn = Broker::connect().unwrap();
     match conn.route::<ProjectJobsGet, ProjectJobsGetResponse>(&jobs_get) {
@@ -46,19 +46,23 @@ pub fn router(config: Arc<Config>) -> Result<Chain> {
         jobs: post "/jobs" => XHandler::new(job_create).before(basic.clone()),
         job: get "/jobs/:id" => job_show,
         job_log: get "/jobs/:id/log" => job_log,
+        job_cancel: post "/jobs/:id/cancel" => XHandler::new(job_cancel).before(basic.clone()),
+        job_publish_retry: post "/jobs/:id/publish" => {
+            XHandler::new(job_publish_retry).before(basic.clone())
+        },
 
         user_invitations: get "/user/invitations" => {
             XHandler::new(list_account_invitations).before(basic.clone())
         },
         user_origins: get "/user/origins" => XHandler::new(list_user_origins).before(basic.clone()),
 
-        // NOTE: Each of the handler functions for projects currently
-        // short-circuits processing if trying to do anything with a
-        // non-"core" origin, since we're not enabling Builder for any
-        // other origins at the moment.
         projects: post "/projects" => XHandler::new(project_create).before(basic.clone()),
-        project: get "/projects/:origin/:name" => project_show,
-        project_jobs: get "/projects/:origin/:name/jobs" => project_jobs,
+        project: get "/projects/:origin/:name" => {
+            XHandler::new(project_show).before(basic.clone())
+        },
+        project_jobs: get "/projects/:origin/:name/jobs" => {
+            XHandler::new(project_jobs).before(basic.clone())
+        },
         edit_project: put "/projects/:origin/:name" => {
             XHandler::new(project_update).before(basic.clone())
         },
@@ -69,6 +73,7 @@ pub fn router(config: Arc<Config>) -> Result<Chain> {
     let mut chain = Chain::new(router);
     chain.link(persistent::Read::<GitHubCli>::both(GitHubClient::new(&*config)));
     chain.link(Read::<EventLog>::both(EventLogger::new(&config.log_dir, config.events_enabled)));
+    chain.link(Read::<AllowLocalVcs>::both(config.allow_local_vcs));
     chain.link_before(RouteBroker);
     chain.link_after(Cors);
     Ok(chain)
@@ -88,7 +93,12 @@ pub fn router(config: Arc<Config>) -> Result<Chain> {
 pub fn run(config: Arc<Config>) -> Result<JoinHandle<()>> {
     let (tx, rx) = mpsc::sync_channel(1);
 
-    let depot = depot::DepotUtil::new(config.depot.clone());
+    depot::upstream::start_mirror(config.depot.clone());
+    let mut depot = depot::DepotUtil::new(config.depot.clone());
+    let (webhooks, _) = depot::webhooks::start_dispatcher(config.depot.webhooks.clone());
+    depot.webhooks = webhooks;
+    let (downloads, _) = depot::downloads::start_recorder();
+    depot.downloads = downloads;
     let depot_chain = try!(depot::server::router(depot));
 
     let mut mount = Mount::new();
@@ -28,12 +28,16 @@ pub const STATS_ENV: &'static str = "HAB_STATS_ADDR";
 #[derive(Debug, Clone)]
 pub enum Counter {
     SearchPackages,
+    DepCacheHit,
+    DepCacheMiss,
+    DepCacheEvict,
 }
 
 // Supported metrics
 #[derive(Debug, Clone)]
 pub enum Gauge {
     PackageCount,
+    DepCacheSize,
 }
 
 // Helper types
@@ -163,6 +167,9 @@ impl Metric for Counter {
     fn id(&self) -> &'static str {
         match *self {
             Counter::SearchPackages => "search-packages",
+            Counter::DepCacheHit => "dep-cache-hit",
+            Counter::DepCacheMiss => "dep-cache-miss",
+            Counter::DepCacheEvict => "dep-cache-evict",
         }
     }
 }
@@ -171,6 +178,7 @@ impl Metric for Gauge {
     fn id(&self) -> &'static str {
         match *self {
             Gauge::PackageCount => "package-count",
+            Gauge::DepCacheSize => "dep-cache-size",
         }
     }
 }
@@ -27,6 +27,7 @@ use hab_http;
 #[derive(Debug)]
 pub enum Error {
     APIError(hyper::status::StatusCode, String),
+    ChecksumMismatch(String, String),
     DownloadFailed(String),
     HabitatCore(hab_core::Error),
     HabitatHttpClient(hab_http::Error),
@@ -47,6 +48,11 @@ impl fmt::Display for Error {
         let msg = match *self {
             Error::APIError(ref c, ref m) if m.len() > 0 => format!("[{}] {}", c, m),
             Error::APIError(ref c, _) => format!("[{}]", c),
+            Error::ChecksumMismatch(ref expected, ref actual) => {
+                format!("Downloaded artifact checksum {} does not match the expected {}",
+                        actual,
+                        expected)
+            }
             Error::DownloadFailed(ref s) => format!("Download failed: {}", s),
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::HabitatHttpClient(ref e) => format!("{}", e),
@@ -70,6 +76,7 @@ impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
             Error::APIError(_, _) => "Received a non-2XX response code from API",
+            Error::ChecksumMismatch(_, _) => "Downloaded artifact does not match its checksum",
             Error::DownloadFailed(_) => "Download failed",
             Error::HabitatCore(ref err) => err.description(),
             Error::HabitatHttpClient(ref err) => err.description(),
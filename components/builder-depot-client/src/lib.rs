@@ -16,6 +16,7 @@ extern crate habitat_builder_protocol as protocol;
 extern crate habitat_core as hab_core;
 extern crate habitat_http_client as hab_http;
 extern crate broadcast;
+extern crate chrono;
 #[macro_use]
 extern crate hyper;
 extern crate hyper_openssl;
@@ -36,17 +37,20 @@ extern crate url;
 pub mod error;
 pub use error::{Error, Result};
 
-use std::fs::{self, File};
+use std::fs::{self, File, OpenOptions};
 use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
 use std::string::ToString;
 
 use broadcast::BroadcastWriter;
-use hab_core::package::{Identifiable, PackageArchive};
+use chrono::NaiveDate;
+use hab_core::crypto::hash;
+use hab_core::package::{Identifiable, PackageArchive, PackageTarget};
 use hab_http::ApiClient;
 use hyper::client::{Body, IntoUrl, Response, RequestBuilder};
 use hyper::status::StatusCode;
-use hyper::header::{Authorization, Bearer};
+use hyper::header::{Authorization, Bearer, ByteRangeSpec, ContentRange, ContentRangeSpec,
+                    ContentType, Range};
 use hyper::Url;
 use protobuf::core::ProtobufEnum;
 use protocol::{originsrv, net};
@@ -55,6 +59,7 @@ use tee::TeeReader;
 
 header! { (XFileName, "X-Filename") => [String] }
 header! { (ETag, "ETag") => [String] }
+header! { (IfRange, "If-Range") => [String] }
 
 #[derive(Clone, Deserialize)]
 #[serde(rename = "error")]
@@ -125,6 +130,27 @@ pub struct OriginSecretKey {
     pub owner_id: u64,
 }
 
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct OriginEncryptionKey {
+    #[serde(with = "json_u64")]
+    pub id: u64,
+    #[serde(with = "json_u64")]
+    pub origin_id: u64,
+    pub name: String,
+    pub revision: String,
+    pub public_body: Vec<u8>,
+    pub secret_body: Vec<u8>,
+    #[serde(with = "json_u64")]
+    pub owner_id: u64,
+}
+
+/// A build secret, with its value still encrypted with the origin's encryption key.
+#[derive(Clone, Deserialize, Debug)]
+pub struct OriginSecret {
+    pub name: String,
+    pub value: String,
+}
+
 #[derive(Clone, Deserialize)]
 pub struct Package {
     pub ident: PackageIdent,
@@ -134,6 +160,8 @@ pub struct Package {
     pub tdeps: Vec<PackageIdent>,
     pub exposes: Vec<u32>,
     pub config: String,
+    #[serde(default)]
+    pub yanked: bool,
 }
 
 impl Into<originsrv::OriginPackage> for Package {
@@ -146,6 +174,7 @@ impl Into<originsrv::OriginPackage> for Package {
         out.set_tdeps(self.tdeps.into_iter().map(|m| m.into()).collect());
         out.set_exposes(self.exposes);
         out.set_config(self.config);
+        out.set_yanked(self.yanked);
         out
     }
 }
@@ -177,6 +206,124 @@ pub struct PackageResults<T> {
     pub package_list: Vec<T>,
 }
 
+/// Filters narrowing down a package search on a remote Depot. Unset filters match every package.
+#[derive(Clone, Debug, Default)]
+pub struct SearchFilters {
+    /// Origin to search in, the Depot searches `core` when unset
+    pub origin: Option<String>,
+    pub channel: Option<String>,
+    pub target: Option<String>,
+    /// Lowest version to match, inclusive
+    pub min_version: Option<String>,
+    /// Highest version to match, exclusive
+    pub max_version: Option<String>,
+    /// Ports which a package must expose
+    pub exposes: Vec<u16>,
+    /// Services which a package must bind to
+    pub binds: Vec<String>,
+    /// Binaries which a package must put on the `PATH`
+    pub binaries: Vec<String>,
+    /// Path, or trailing part of a path, of a file which a package must contain
+    pub file: Option<String>,
+}
+
+impl SearchFilters {
+    fn append_to(&self, url: &mut Url) {
+        let mut pairs = url.query_pairs_mut();
+        let values = [("origin", &self.origin),
+                      ("channel", &self.channel),
+                      ("target", &self.target),
+                      ("min_version", &self.min_version),
+                      ("max_version", &self.max_version),
+                      ("file", &self.file)];
+        for &(key, value) in values.iter() {
+            if let Some(ref value) = *value {
+                pairs.append_pair(key, value);
+            }
+        }
+        if !self.exposes.is_empty() {
+            let ports: Vec<String> = self.exposes.iter().map(|p| p.to_string()).collect();
+            pairs.append_pair("exposes", &ports.join(","));
+        }
+        if !self.binds.is_empty() {
+            pairs.append_pair("binds", &self.binds.join(","));
+        }
+        if !self.binaries.is_empty() {
+            pairs.append_pair("binaries", &self.binaries.join(","));
+        }
+    }
+}
+
+/// A package matching a search, along with the metadata it can be searched by.
+#[derive(Clone, Deserialize)]
+pub struct SearchResult {
+    pub origin: String,
+    pub name: String,
+    pub version: Option<String>,
+    pub release: Option<String>,
+    #[serde(default)]
+    pub target: String,
+    #[serde(default)]
+    pub exposes: Vec<u16>,
+    #[serde(default)]
+    pub binds: Vec<String>,
+    #[serde(default)]
+    pub binaries: Vec<String>,
+    /// Files of the package matching the search's file filter
+    #[serde(default)]
+    pub files: Vec<String>,
+    #[serde(default)]
+    pub relevance: u32,
+}
+
+/// Number of times a package was downloaded on a single day.
+#[derive(Clone, Deserialize)]
+pub struct DownloadCount {
+    pub ident: hab_core::package::PackageIdent,
+    pub target: String,
+    /// UTC day of the downloads, formatted as `YYYY-MM-DD`
+    pub day: String,
+    pub count: u64,
+}
+
+/// Daily download counts of every release of a package, oldest first.
+#[derive(Clone, Deserialize)]
+pub struct DownloadStats {
+    pub total: u64,
+    pub downloads: Vec<DownloadCount>,
+}
+
+/// State of one project of a scheduled group.
+#[derive(Clone, Deserialize)]
+pub struct ScheduleProject {
+    pub name: String,
+    pub ident: String,
+    /// One of `NotStarted`, `InProgress`, `Success`, `Failure` or `Skipped`
+    pub state: String,
+    /// Id of the project's latest build job, or 0 if it was not dispatched yet
+    pub job_id: u64,
+}
+
+/// A group of projects the scheduler rebuilds after one of their dependencies changed.
+#[derive(Clone, Deserialize)]
+pub struct ScheduleStatus {
+    pub id: u64,
+    /// One of `Pending`, `Dispatching`, `Complete` or `Failed`
+    pub state: String,
+    pub projects: Vec<ScheduleProject>,
+    #[serde(default)]
+    pub created_at: String,
+}
+
+/// Outcome of importing a package bundle into a remote Depot.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct BundleImport {
+    /// Packages which were added to the Depot
+    pub imported: Vec<String>,
+    /// Packages which the Depot already had
+    pub existing: Vec<String>,
+}
+
 pub trait DisplayProgress: Write {
     fn size(&mut self, size: u64);
     fn finish(&mut self);
@@ -195,6 +342,19 @@ impl Client {
         Ok(Client(ApiClient::new(depot_url, product, version, fs_root_path)?))
     }
 
+    /// Returns a client which asks the Depot for packages of the given target rather than of
+    /// the current system.
+    pub fn new_for_target<U>(depot_url: U,
+                             product: &str,
+                             version: &str,
+                             fs_root_path: Option<&Path>,
+                             target: &PackageTarget)
+                             -> Result<Self>
+        where U: IntoUrl
+    {
+        Ok(Client(ApiClient::new_for_target(depot_url, product, version, fs_root_path, target)?))
+    }
+
     /// Download a public key from a remote Depot to the given filepath.
     ///
     /// # Failures
@@ -307,6 +467,59 @@ impl Client {
         Ok(key)
     }
 
+    /// Download the latest encryption key for an origin from a remote Depot.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Depot is not available
+    /// * The origin has no encryption key
+    ///
+    /// # Panics
+    ///
+    /// * Authorization token was not set on client
+    pub fn fetch_origin_encryption_key(&self,
+                                       origin: &str,
+                                       token: &str)
+                                       -> Result<OriginEncryptionKey> {
+        let mut res = self.add_authz(self.0.get(&origin_encryption_key_path(origin)), token)
+            .send()?;
+        if res.status != StatusCode::Ok {
+            return Err(err_from_response(res));
+        }
+        let mut encoded = String::new();
+        try!(res.read_to_string(&mut encoded));
+        let key = try!(serde_json::from_str(&encoded));
+        Ok(key)
+    }
+
+    /// Returns the encrypted secrets a build of the given project receives. Secrets shared by
+    /// the whole origin come first, followed by the project's own secrets.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Depot is not available
+    ///
+    /// # Panics
+    ///
+    /// * Authorization token was not set on client
+    pub fn fetch_origin_secrets(&self,
+                                origin: &str,
+                                project: &str,
+                                token: &str)
+                                -> Result<Vec<OriginSecret>> {
+        let path = origin_secrets_encrypted_path(origin);
+        let custom = |url: &mut Url| { url.query_pairs_mut().append_pair("project", project); };
+        let mut res = self.add_authz(self.0.get_with_custom_url(&path, custom), token)
+            .send()?;
+        if res.status != StatusCode::Ok {
+            return Err(err_from_response(res));
+        }
+        let mut encoded = String::new();
+        try!(res.read_to_string(&mut encoded));
+        let secrets = try!(serde_json::from_str(&encoded));
+        Ok(secrets)
+    }
+
     /// Upload a secret origin key to a remote Depot.
     ///
     /// # Failures
@@ -355,11 +568,16 @@ impl Client {
     /// the latest release of a given version. Specifying both a version and a release will
     /// retrieve that exact package.
     ///
+    /// An interrupted download leaves a partial artifact behind in `dst_path`, which the next
+    /// call for the same package resumes from rather than starting over. The completed artifact
+    /// is verified against the checksum the Depot advertises for it.
+    ///
     /// # Failures
     ///
     /// * Package cannot be found
     /// * Remote Depot is not available
     /// * File cannot be created and written to
+    /// * Downloaded artifact does not match its checksum
     pub fn fetch_package<D, I, P>(&self,
                                   ident: &I,
                                   dst_path: &P,
@@ -372,7 +590,12 @@ impl Client {
         // JW TODO: We need to add a channel scoped /download route to the API server. Technically
         // this is wrong because we only want to download packages that are in the channel we
         // specified to the API client
-        match self.download(&package_download(ident), dst_path.as_ref(), progress) {
+        let part_name = format!("{}-{}-{}-{}",
+                                ident.origin(),
+                                ident.name(),
+                                ident.version().unwrap_or("latest"),
+                                ident.release().unwrap_or("latest"));
+        match self.resumable_download(ident, &part_name, dst_path.as_ref(), progress, true) {
             Ok(file) => Ok(PackageArchive::new(PathBuf::from(file))),
             Err(e) => Err(e),
         }
@@ -480,6 +703,54 @@ impl Client {
         }
     }
 
+    /// Upload a package bundle to a remote Depot, importing every package and public origin key
+    /// it holds which the Depot doesn't already have.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Depot is not available
+    /// * File cannot be read
+    /// * Bundle cannot be verified by the Depot
+    ///
+    /// # Panics
+    ///
+    /// * Authorization token was not set on client
+    pub fn put_bundle<D>(&self,
+                         src_path: &Path,
+                         token: &str,
+                         progress: Option<D>)
+                         -> Result<BundleImport>
+        where D: DisplayProgress + Sized
+    {
+        let mut file = try!(File::open(src_path));
+        let file_size = try!(file.metadata()).len();
+        debug!("Reading from {}", src_path.display());
+
+        let result = if let Some(mut progress) = progress {
+            progress.size(file_size);
+            let mut reader = TeeReader::new(file, progress);
+            self.add_authz(self.0.post("pkgs/bundle"), token)
+                .body(Body::SizedBody(&mut reader, file_size))
+                .send()
+        } else {
+            self.add_authz(self.0.post("pkgs/bundle"), token)
+                .body(Body::SizedBody(&mut file, file_size))
+                .send()
+        };
+        match result {
+            Ok(mut response) => {
+                if response.status != StatusCode::Created {
+                    return Err(err_from_response(response));
+                }
+                let mut encoded = String::new();
+                try!(response.read_to_string(&mut encoded));
+                debug!("Body: {:?}", encoded);
+                Ok(try!(serde_json::from_str::<BundleImport>(&encoded)))
+            }
+            Err(e) => Err(Error::from(e)),
+        }
+    }
+
     /// Promote a package to a given channel
     ///
     /// # Failures
@@ -507,6 +778,105 @@ impl Client {
         Ok(())
     }
 
+    /// Demote a package from a given channel. The package stays in the Depot and in any other
+    /// channel it was promoted to.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Depot is not available
+    /// * Package is not in the given channel
+    /// * Channel is `unstable`, which every package belongs to
+    ///
+    /// # Panics
+    /// * If package identifier does not have a version/release
+    /// * Authorization token was not set on client
+    pub fn demote_package<I>(&self,
+                             ident: &I,
+                             channel: &str,
+                             reason: Option<&str>,
+                             token: &str)
+                             -> Result<()>
+        where I: Identifiable
+    {
+        let path = channel_package_demote(channel, ident);
+        debug!("Demoting package, path: {:?}", path);
+        let body = json!({ "reason": reason }).to_string();
+
+        let res = self.add_authz(self.0.put(&path), token)
+            .header(ContentType::json())
+            .body(body.as_str())
+            .send()?;
+
+        if res.status != StatusCode::Ok {
+            return Err(err_from_response(res));
+        };
+
+        Ok(())
+    }
+
+    /// Yank a package so it is no longer resolved as the latest release of its name or version.
+    /// A yanked package can still be downloaded by its fully qualified identifier.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Depot is not available
+    /// * Package does not exist
+    /// * Reason is empty
+    ///
+    /// # Panics
+    /// * If package identifier does not have a version/release
+    /// * Authorization token was not set on client
+    pub fn yank_package<I>(&self, ident: &I, reason: &str, token: &str) -> Result<()>
+        where I: Identifiable
+    {
+        let path = package_yank(ident);
+        debug!("Yanking package, path: {:?}", path);
+        let body = json!({ "reason": reason }).to_string();
+
+        let res = self.add_authz(self.0.put(&path), token)
+            .header(ContentType::json())
+            .body(body.as_str())
+            .send()?;
+
+        if res.status != StatusCode::Ok {
+            return Err(err_from_response(res));
+        };
+
+        Ok(())
+    }
+
+    /// Returns the identifiers of all packages in a channel of an origin.
+    ///
+    /// # Failures
+    ///
+    /// * Channel cannot be found
+    /// * Remote Depot is not available
+    pub fn show_channel_packages(&self,
+                                 origin: &str,
+                                 channel: &str)
+                                 -> Result<Vec<originsrv::OriginPackageIdent>> {
+        let path = channel_packages_path(origin, channel);
+        let mut packages = Vec::new();
+        loop {
+            let range = packages.len().to_string();
+            let custom = |url: &mut Url| { url.query_pairs_mut().append_pair("range", &range); };
+            let mut res = self.0.get_with_custom_url(&path, custom).send()?;
+            let partial = match res.status {
+                StatusCode::Ok => false,
+                StatusCode::PartialContent => true,
+                _ => return Err(err_from_response(res)),
+            };
+            let mut encoded = String::new();
+            try!(res.read_to_string(&mut encoded));
+            let results: PackageResults<PackageIdent> = try!(serde_json::from_str(&encoded));
+            let count = results.package_list.len();
+            packages.extend(results.package_list.into_iter().map(|m| m.into()));
+            if !partial || count == 0 {
+                return Ok(packages);
+            }
+        }
+    }
+
     /// Returns a vector of PackageIdent structs
     ///
     /// # Failures
@@ -530,6 +900,90 @@ impl Client {
         }
     }
 
+    /// Returns the packages matching a search term and a set of filters, most relevant first,
+    /// and whether the Depot holds more matches than were returned.
+    ///
+    /// # Failures
+    ///
+    /// * Remote depot unavailable
+    pub fn search_packages(&self,
+                           search_term: Option<&str>,
+                           filters: &SearchFilters)
+                           -> Result<(Vec<SearchResult>, bool)> {
+        let path = match search_term {
+            Some(term) => package_search(term),
+            None => "pkgs/search".to_string(),
+        };
+        let custom = |url: &mut Url| filters.append_to(url);
+        let mut res = self.0.get_with_custom_url(&path, custom).send()?;
+        match res.status {
+            StatusCode::Ok |
+            StatusCode::PartialContent => {
+                let mut encoded = String::new();
+                try!(res.read_to_string(&mut encoded));
+                let results: PackageResults<SearchResult> = try!(serde_json::from_str(&encoded));
+                Ok((results.package_list, res.status == StatusCode::PartialContent))
+            }
+            _ => Err(err_from_response(res)),
+        }
+    }
+
+    /// Returns how many times the releases of a package were downloaded on each day, optionally
+    /// narrowed down to a target and to an inclusive range of `YYYY-MM-DD` days.
+    ///
+    /// # Failures
+    ///
+    /// * Remote depot unavailable
+    pub fn package_download_stats<I>(&self,
+                                     package: &I,
+                                     target: Option<&str>,
+                                     from: Option<&str>,
+                                     to: Option<&str>)
+                                     -> Result<DownloadStats>
+        where I: Identifiable
+    {
+        let custom = |url: &mut Url| {
+            let mut pairs = url.query_pairs_mut();
+            let values = [("target", target), ("from", from), ("to", to)];
+            for &(key, value) in values.iter() {
+                if let Some(value) = value {
+                    pairs.append_pair(key, value);
+                }
+            }
+        };
+        let mut res = self.0
+            .get_with_custom_url(&package_stats_path(package), custom)
+            .send()?;
+        if res.status != StatusCode::Ok {
+            return Err(err_from_response(res));
+        }
+
+        let mut encoded = String::new();
+        try!(res.read_to_string(&mut encoded));
+        debug!("Body: {:?}", encoded);
+        let stats: DownloadStats = try!(serde_json::from_str(&encoded));
+        Ok(stats)
+    }
+
+    /// Returns the state of a scheduled group and of each of its projects.
+    ///
+    /// # Failures
+    ///
+    /// * Group cannot be found
+    /// * Remote Depot is not available
+    pub fn schedule_status(&self, group_id: u64) -> Result<ScheduleStatus> {
+        let mut res = self.0.get(&schedule_path(group_id)).send()?;
+        if res.status != StatusCode::Ok {
+            return Err(err_from_response(res));
+        }
+
+        let mut encoded = String::new();
+        try!(res.read_to_string(&mut encoded));
+        debug!("Body: {:?}", encoded);
+        let status: ScheduleStatus = try!(serde_json::from_str(&encoded));
+        Ok(status)
+    }
+
     fn add_authz<'a>(&'a self, rb: RequestBuilder<'a>, token: &str) -> RequestBuilder {
         rb.header(Authorization(Bearer { token: token.to_string() }))
     }
@@ -547,7 +1001,14 @@ impl Client {
 
         let file_name = match res.headers.get::<XFileName>() {
             Some(filename) => format!("{}", filename),
-            None => return Err(Error::NoXFilename),
+            // Depots backed by an object store may redirect downloads to a pre-signed URL,
+            // whose path ends with the artifact's file name
+            None => {
+                match res.url.path_segments().and_then(|s| s.last()) {
+                    Some(segment) if segment.ends_with(".hart") => segment.to_string(),
+                    _ => return Err(Error::NoXFilename),
+                }
+            }
         };
         let tmp_file_path = dst_path.join(format!("{}.tmp-{}",
                                                   file_name,
@@ -575,6 +1036,152 @@ impl Client {
         try!(fs::rename(&tmp_file_path, &dst_file_path));
         Ok(dst_file_path)
     }
+
+    /// Downloads into a partial file named after `part_name`, resuming from whatever a previous
+    /// attempt left behind. The validator returned with the partial content is kept alongside it
+    /// and sent back with `If-Range`, so a Depot serving a different artifact replies with the
+    /// whole of it instead of a range which would corrupt the file. The completed file is checked
+    /// against the checksum the Depot recorded for the package before it is moved into place.
+    fn resumable_download<D, I>(&self,
+                                ident: &I,
+                                part_name: &str,
+                                dst_path: &Path,
+                                progress: Option<D>,
+                                retry_unsatisfiable: bool)
+                                -> Result<PathBuf>
+        where D: DisplayProgress + Sized,
+              I: Identifiable
+    {
+        let path = package_download(ident);
+        let path = path.as_str();
+        try!(fs::create_dir_all(&dst_path));
+        let part_path = dst_path.join(format!("{}.part", part_name));
+        let etag_path = dst_path.join(format!("{}.part.etag", part_name));
+        let validator = read_validator(&etag_path);
+        let offset = match (validator.as_ref(), fs::metadata(&part_path)) {
+            (Some(_), Ok(meta)) => meta.len(),
+            _ => 0,
+        };
+
+        let mut req = self.0.get(path);
+        if offset > 0 {
+            debug!("Resuming {} from byte {}", part_path.display(), offset);
+            req = req.header(Range::Bytes(vec![ByteRangeSpec::AllFrom(offset)]))
+                .header(IfRange(validator.unwrap()));
+        }
+        let mut res = try!(req.send());
+        debug!("Response: {:?}", res);
+
+        let status = res.status;
+        let append = match status {
+            StatusCode::Ok => false,
+            StatusCode::PartialContent => {
+                match res.headers.get::<ContentRange>() {
+                    Some(&ContentRange(ContentRangeSpec::Bytes { range: Some((start, _)), .. }))
+                        if start == offset => true,
+                    _ => {
+                        try!(discard_partial(&part_path, &etag_path));
+                        return Err(Error::DownloadFailed(format!("Depot returned an unexpected \
+                                                                  range for {}",
+                                                                 path)));
+                    }
+                }
+            }
+            StatusCode::RangeNotSatisfiable if retry_unsatisfiable => {
+                try!(discard_partial(&part_path, &etag_path));
+                return self.resumable_download(ident, part_name, dst_path, progress, false);
+            }
+            _ => return Err(err_from_response(res)),
+        };
+
+        let file_name = match res.headers.get::<XFileName>() {
+            Some(filename) => format!("{}", filename),
+            None => {
+                match res.url.path_segments().and_then(|s| s.last()) {
+                    Some(segment) if segment.ends_with(".hart") => segment.to_string(),
+                    _ => return Err(Error::NoXFilename),
+                }
+            }
+        };
+        // Only a Depot's own validator is the artifact checksum; object stores we may have been
+        // redirected to use validators of their own, so the checksum is looked up instead
+        let checksum = match (res.headers.get::<XFileName>(), res.headers.get::<ETag>()) {
+            (Some(_), Some(etag)) => Some(etag.trim_matches('"').to_string()),
+            _ => None,
+        };
+        match res.headers.get::<ETag>() {
+            Some(etag) => {
+                let mut f = try!(File::create(&etag_path));
+                try!(f.write_all(etag.as_bytes()));
+            }
+            None => {
+                if etag_path.exists() {
+                    try!(fs::remove_file(&etag_path));
+                }
+            }
+        }
+
+        debug!("Writing to {}", &part_path.display());
+        let mut f = try!(OpenOptions::new()
+                             .write(true)
+                             .create(true)
+                             .append(append)
+                             .truncate(!append)
+                             .open(&part_path));
+        match progress {
+            Some(mut progress) => {
+                let size: u64 = res.headers
+                    .get::<hyper::header::ContentLength>()
+                    .map_or(0, |v| **v);
+                progress.size(size);
+                let mut writer = BroadcastWriter::new(&mut f, progress);
+                try!(io::copy(&mut res, &mut writer))
+            }
+            None => try!(io::copy(&mut res, &mut f)),
+        };
+
+        let expected = match checksum {
+            Some(checksum) => checksum,
+            None => try!(self.show_package(ident, None)).get_checksum().to_string(),
+        };
+        let actual = try!(hash::hash_file(&part_path));
+        if actual != expected {
+            try!(discard_partial(&part_path, &etag_path));
+            return Err(Error::ChecksumMismatch(expected, actual));
+        }
+        let dst_file_path = dst_path.join(file_name);
+        debug!("Moving {} to {}",
+               &part_path.display(),
+               &dst_file_path.display());
+        try!(fs::rename(&part_path, &dst_file_path));
+        if etag_path.exists() {
+            try!(fs::remove_file(&etag_path));
+        }
+        Ok(dst_file_path)
+    }
+}
+
+/// Whether a string is a calendar day formatted as `YYYY-MM-DD`, the form download statistics
+/// are narrowed down with. Days which don't exist, such as `2017-02-31`, are refused.
+pub fn is_valid_day(day: &str) -> bool {
+    day.len() == 10 && NaiveDate::parse_from_str(day, "%Y-%m-%d").is_ok()
+}
+
+fn read_validator(etag_path: &Path) -> Option<String> {
+    let mut validator = String::new();
+    match File::open(etag_path).and_then(|mut f| f.read_to_string(&mut validator)) {
+        Ok(_) if !validator.is_empty() => Some(validator),
+        _ => None,
+    }
+}
+
+fn discard_partial(part_path: &Path, etag_path: &Path) -> Result<()> {
+    for path in &[part_path, etag_path] {
+        if path.exists() {
+            try!(fs::remove_file(path));
+        }
+    }
+    Ok(())
 }
 
 fn err_from_response(mut response: hyper::client::Response) -> Error {
@@ -601,6 +1208,14 @@ fn origin_secret_keys_latest(origin: &str) -> String {
     format!("origins/{}/secret_keys/latest", origin)
 }
 
+fn origin_encryption_key_path(origin: &str) -> String {
+    format!("origins/{}/encryption_key", origin)
+}
+
+fn origin_secrets_encrypted_path(origin: &str) -> String {
+    format!("origins/{}/secrets/encrypted", origin)
+}
+
 fn package_download<I>(package: &I) -> String
     where I: Identifiable
 {
@@ -613,10 +1228,34 @@ fn package_path<I>(package: &I) -> String
     format!("pkgs/{}", package)
 }
 
+fn package_stats_path<I>(package: &I) -> String
+    where I: Identifiable
+{
+    format!("pkgs/{}/stats", package)
+}
+
+fn schedule_path(group_id: u64) -> String {
+    format!("pkgs/schedule/{}", group_id)
+}
+
+fn package_yank<I>(package: &I) -> String
+    where I: Identifiable
+{
+    format!("pkgs/{}/{}/{}/{}/yank",
+            package.origin(),
+            package.name(),
+            package.version().unwrap(),
+            package.release().unwrap())
+}
+
 fn package_search(term: &str) -> String {
     format!("pkgs/search/{}", term)
 }
 
+fn channel_packages_path(origin: &str, channel: &str) -> String {
+    format!("channels/{}/{}/pkgs", origin, channel)
+}
+
 fn channel_package_path<I>(channel: &str, package: &I) -> String
     where I: Identifiable
 {
@@ -646,11 +1285,298 @@ fn channel_package_promote<I>(channel: &str, package: &I) -> String
             package.release().unwrap())
 }
 
+fn channel_package_demote<I>(channel: &str, package: &I) -> String
+    where I: Identifiable
+{
+    format!("channels/{}/{}/pkgs/{}/{}/{}/demote",
+            package.origin(),
+            channel,
+            package.name(),
+            package.version().unwrap(),
+            package.release().unwrap())
+}
+
 #[cfg(test)]
 mod tests {
+    use std::env;
+    use std::io::{BufRead, BufReader};
+    use std::net::TcpListener;
+    use std::str::FromStr;
+    use std::sync::mpsc;
+    use std::thread;
+
+    use hab_core::package::PackageIdent;
     use serde_json;
     use super::*;
 
+    const ARTIFACT: &'static str = "core-foo-1.0.0-20170101000000-x86_64-linux.hart";
+    const CONTENT: &'static str = "a habitat artifact";
+
+    struct NoProgress;
+
+    impl Write for NoProgress {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl DisplayProgress for NoProgress {
+        fn size(&mut self, _size: u64) {}
+        fn finish(&mut self) {}
+    }
+
+    /// Starts a stand-in Depot which answers consecutive requests with the given responses,
+    /// returning its URL and the head of every request it receives.
+    fn depot(responses: Vec<String>) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/v1/depot", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || for response in responses {
+                          let (mut stream, _) = listener.accept().unwrap();
+                          let mut head = String::new();
+                          {
+                              let mut reader = BufReader::new(&mut stream);
+                              loop {
+                                  let mut line = String::new();
+                                  reader.read_line(&mut line).unwrap();
+                                  if line.trim().is_empty() {
+                                      break;
+                                  }
+                                  head.push_str(&line.to_lowercase());
+                              }
+                          }
+                          tx.send(head).unwrap();
+                          stream.write_all(response.as_bytes()).unwrap();
+                      });
+        (url, rx)
+    }
+
+    fn response(status: &str, headers: &[String], body: &str) -> String {
+        format!("HTTP/1.1 {}\r\n{}Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                headers.iter().map(|h| format!("{}\r\n", h)).collect::<String>(),
+                body.len(),
+                body)
+    }
+
+    fn download_dir() -> PathBuf {
+        let dir = env::temp_dir().join(format!("depot-client-{}",
+                                               thread_rng()
+                                                   .gen_ascii_chars()
+                                                   .take(8)
+                                                   .collect::<String>()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn write_partial(dir: &Path, content: &str, validator: &str) {
+        File::create(dir.join("core-foo-1.0.0-20170101000000.part"))
+            .unwrap()
+            .write_all(content.as_bytes())
+            .unwrap();
+        File::create(dir.join("core-foo-1.0.0-20170101000000.part.etag"))
+            .unwrap()
+            .write_all(validator.as_bytes())
+            .unwrap();
+    }
+
+    fn fetch(url: &str, dir: &Path) -> Result<PackageArchive> {
+        let client = Client::new(url, "hab", "0.0.0", None).unwrap();
+        let ident = PackageIdent::from_str("core/foo/1.0.0/20170101000000").unwrap();
+        client.fetch_package(&ident, dir, None::<NoProgress>)
+    }
+
+    fn read(path: &Path) -> String {
+        let mut content = String::new();
+        File::open(path)
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+        content
+    }
+
+    #[test]
+    fn fetch_package_resumes_partial_download() {
+        let checksum = hash::hash_string(CONTENT);
+        let headers = vec![format!("X-Filename: {}", ARTIFACT),
+                           format!("ETag: {}", checksum),
+                           format!("Content-Range: bytes 8-{}/{}",
+                                   CONTENT.len() - 1,
+                                   CONTENT.len())];
+        let (url, requests) = depot(vec![response("206 Partial Content",
+                                                  &headers,
+                                                  &CONTENT[8..])]);
+        let dir = download_dir();
+        write_partial(&dir, &CONTENT[..8], &checksum);
+
+        let archive = fetch(&url, &dir).unwrap();
+        let request = requests.recv().unwrap();
+        assert!(request.contains("range: bytes=8-\r\n"));
+        assert!(request.contains(&format!("if-range: {}\r\n", checksum)));
+        assert_eq!(archive.path, dir.join(ARTIFACT));
+        assert_eq!(read(&archive.path), CONTENT);
+        assert!(!dir.join("core-foo-1.0.0-20170101000000.part").exists());
+        assert!(!dir.join("core-foo-1.0.0-20170101000000.part.etag").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn fetch_package_restarts_unsatisfiable_download() {
+        let checksum = hash::hash_string(CONTENT);
+        let headers = vec![format!("X-Filename: {}", ARTIFACT), format!("ETag: {}", checksum)];
+        let (url, requests) = depot(vec![response("416 Range Not Satisfiable", &[], ""),
+                                         response("200 OK", &headers, CONTENT)]);
+        let dir = download_dir();
+        write_partial(&dir, "a much longer artifact which was replaced", &checksum);
+
+        let archive = fetch(&url, &dir).unwrap();
+        assert!(requests.recv().unwrap().contains("range: "));
+        assert!(!requests.recv().unwrap().contains("range: "));
+        assert_eq!(read(&archive.path), CONTENT);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn fetch_package_discards_download_with_wrong_checksum() {
+        let headers = vec![format!("X-Filename: {}", ARTIFACT),
+                           format!("ETag: {}", hash::hash_string("another artifact"))];
+        let (url, _requests) = depot(vec![response("200 OK", &headers, CONTENT)]);
+        let dir = download_dir();
+
+        match fetch(&url, &dir) {
+            Err(Error::ChecksumMismatch(..)) => (),
+            _ => panic!("expected a checksum mismatch"),
+        }
+        assert!(!dir.join("core-foo-1.0.0-20170101000000.part").exists());
+        assert!(!dir.join(ARTIFACT).exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    /// Responses of a Depot which redirects the download to an object store, followed by the
+    /// package recorded with the given checksum.
+    fn redirected(checksum: &str) -> Vec<String> {
+        let package = format!(r#"{{"ident":{{"origin":"core","name":"foo","version":"1.0.0",
+                                  "release":"20170101000000"}},"checksum":"{}","manifest":"",
+                                  "deps":[],"tdeps":[],"exposes":[],"config":""}}"#,
+                              checksum);
+        vec![response("302 Found", &[format!("Location: /objects/{}", ARTIFACT)], ""),
+             response("200 OK", &["ETag: \"object-store-etag\"".to_string()], CONTENT),
+             response("200 OK", &[], &package)]
+    }
+
+    #[test]
+    fn fetch_package_checks_redirected_download_against_recorded_checksum() {
+        let (url, requests) = depot(redirected(&hash::hash_string(CONTENT)));
+        let dir = download_dir();
+
+        let archive = fetch(&url, &dir).unwrap();
+        assert!(requests.recv().unwrap().starts_with("get /v1/depot/pkgs/core/foo/"));
+        assert!(requests.recv().unwrap().starts_with("get /objects/"));
+        assert!(requests
+                    .recv()
+                    .unwrap()
+                    .starts_with("get /v1/depot/pkgs/core/foo/1.0.0/20170101000000 "));
+        assert_eq!(archive.path, dir.join(ARTIFACT));
+        assert_eq!(read(&archive.path), CONTENT);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn fetch_package_discards_redirected_download_with_wrong_checksum() {
+        let (url, _requests) = depot(redirected(&hash::hash_string("another artifact")));
+        let dir = download_dir();
+
+        match fetch(&url, &dir) {
+            Err(Error::ChecksumMismatch(..)) => (),
+            _ => panic!("expected a checksum mismatch"),
+        }
+        assert!(!dir.join("core-foo-1.0.0-20170101000000.part").exists());
+        assert!(!dir.join(ARTIFACT).exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn search_filters_as_query() {
+        let mut url = Url::parse("http://depot.example.com/v1/depot/pkgs/search").unwrap();
+        let filters = SearchFilters {
+            origin: Some("acme".to_string()),
+            min_version: Some("1.0".to_string()),
+            exposes: vec![80, 443],
+            binaries: vec!["nginx".to_string()],
+            file: Some("bin/nginx".to_string()),
+            ..Default::default()
+        };
+        filters.append_to(&mut url);
+        assert_eq!(url.query(),
+                   Some("origin=acme&min_version=1.0&file=bin%2Fnginx&exposes=80%2C443&\
+                         binaries=nginx"));
+    }
+
+    #[test]
+    fn search_result_from_ident_only() {
+        let result: SearchResult =
+            serde_json::from_str(r#"{"origin":"core","name":"redis"}"#).unwrap();
+        assert_eq!(result.origin, "core");
+        assert!(result.version.is_none());
+        assert!(result.files.is_empty());
+    }
+
+    #[test]
+    fn download_stats_from_json() {
+        let stats: DownloadStats = serde_json::from_str(r#"{
+            "ident": {"origin": "core", "name": "redis"},
+            "total": 3,
+            "downloads": [{
+                "ident": {"origin": "core", "name": "redis", "version": "3.2.4",
+                          "release": "20170514150022"},
+                "target": "x86_64-linux",
+                "day": "2017-06-01",
+                "count": 3
+            }]
+        }"#)
+                .unwrap();
+        assert_eq!(stats.total, 3);
+        assert_eq!(stats.downloads[0].ident.to_string(),
+                   "core/redis/3.2.4/20170514150022");
+        assert_eq!(stats.downloads[0].day, "2017-06-01");
+    }
+
+    #[test]
+    fn valid_days() {
+        assert!(is_valid_day("2017-06-01"));
+        assert!(is_valid_day("2016-02-29"));
+        assert!(!is_valid_day("2017-02-29"));
+        assert!(!is_valid_day("2017-02-31"));
+        assert!(!is_valid_day("2017-13-01"));
+        assert!(!is_valid_day("2017-6-1"));
+        assert!(!is_valid_day("2017-06-01T00:00:00"));
+        assert!(!is_valid_day("yesterday"));
+    }
+
+    #[test]
+    fn schedule_status_from_json() {
+        let status: ScheduleStatus = serde_json::from_str(r#"{
+            "id": 15986865821137185538,
+            "state": "Dispatching",
+            "projects": [{
+                "name": "core/nginx",
+                "ident": "core/nginx/1.11.10/20170513215448",
+                "state": "Failure",
+                "job_id": 717233370829258752
+            }],
+            "created_at": "2017-06-01T10:00:00+00:00"
+        }"#)
+                .unwrap();
+        assert_eq!(status.id, 15986865821137185538);
+        assert_eq!(status.state, "Dispatching");
+        assert_eq!(status.projects[0].state, "Failure");
+        assert_eq!(status.projects[0].job_id, 717233370829258752);
+    }
+
     #[test]
     fn json_round_trip_u64_fields() {
         let pre = OriginSecretKey {
@@ -43,6 +43,14 @@ pub struct Config {
     pub log_dir: String,
     /// A list of package platform and architecture combinations which can be uploaded and hosted
     pub targets: Vec<PackageTarget>,
+    /// Origin key revisions which uploaded artifacts may no longer be signed with
+    pub revoked_keys: Vec<String>,
+    /// Where accepted package artifacts are stored
+    pub storage: StorageCfg,
+    /// Depots to pull packages through from and mirror packages of
+    pub upstream: UpstreamCfg,
+    /// Delivery of depot events to the webhooks registered by origins
+    pub webhooks: WebhooksCfg,
 }
 
 impl ConfigFile for Config {
@@ -62,6 +70,10 @@ impl Default for Config {
             log_dir: env::temp_dir().to_string_lossy().into_owned(),
             targets: vec![PackageTarget::new(Platform::Linux, Architecture::X86_64),
                           PackageTarget::new(Platform::Windows, Architecture::X86_64)],
+            revoked_keys: vec![],
+            storage: StorageCfg::default(),
+            upstream: UpstreamCfg::default(),
+            webhooks: WebhooksCfg::default(),
         }
     }
 }
@@ -113,6 +125,103 @@ impl ToSocketAddrs for HttpCfg {
     }
 }
 
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub enum StorageBackend {
+    #[serde(rename = "local")]
+    Local,
+    #[serde(rename = "s3")]
+    S3,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct StorageCfg {
+    pub backend: StorageBackend,
+
+    // These are for S3 storage
+    pub key: Option<String>,
+    pub secret: Option<String>,
+    pub endpoint: Option<String>,
+    pub bucket: Option<String>,
+    pub region: String,
+    /// Redirect downloads to pre-signed URLs of the object store instead of proxying them
+    pub presigned_urls: bool,
+    /// Number of seconds a pre-signed URL remains valid
+    pub presigned_url_ttl: u64,
+}
+
+impl Default for StorageCfg {
+    fn default() -> Self {
+        StorageCfg {
+            backend: StorageBackend::Local,
+            key: None,
+            secret: None,
+            endpoint: None,
+            bucket: None,
+            region: String::from("us-east-1"),
+            presigned_urls: false,
+            presigned_url_ttl: 3600,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct UpstreamCfg {
+    /// URLs of upstream depots, tried in order
+    pub urls: Vec<String>,
+    /// Origins whose packages are pulled through from upstream when not found locally
+    pub origins: Vec<String>,
+    /// Origin channels which are mirrored from upstream in full
+    pub mirror: Vec<MirrorCfg>,
+    /// Number of seconds between mirror runs
+    pub mirror_interval: u64,
+}
+
+impl Default for UpstreamCfg {
+    fn default() -> Self {
+        UpstreamCfg {
+            urls: vec![],
+            origins: vec!["core".to_string()],
+            mirror: vec![],
+            mirror_interval: 3600,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct MirrorCfg {
+    pub origin: String,
+    pub channel: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct WebhooksCfg {
+    /// Number of times a delivery is attempted before it's given up on
+    pub attempts: u32,
+    /// Number of seconds before a failed delivery is retried, doubled with every attempt
+    pub retry_delay: u64,
+    /// Number of threads making deliveries
+    pub workers: usize,
+    /// Number of deliveries which may wait for a thread before new ones are failed
+    pub queue_size: usize,
+    /// Whether webhooks may point at loopback, private and other internal addresses
+    pub allow_internal_urls: bool,
+}
+
+impl Default for WebhooksCfg {
+    fn default() -> Self {
+        WebhooksCfg {
+            attempts: 5,
+            retry_delay: 2,
+            workers: 4,
+            queue_size: 256,
+            allow_internal_urls: false,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -125,6 +234,7 @@ mod tests {
         builds_enabled = true
         events_enabled = true
         log_dir = "/hab/svc/hab-depot/var/log"
+        revoked_keys = ["core-20160810182414"]
 
         [[targets]]
         platform = "linux"
@@ -143,6 +253,29 @@ mod tests {
         port = 9001
         heartbeat = 9001
 
+        [storage]
+        backend = "s3"
+        bucket = "habitat-artifacts"
+        endpoint = "http://localhost:9000"
+        presigned_urls = true
+        presigned_url_ttl = 600
+
+        [upstream]
+        urls = ["https://willem.habitat.sh/v1/depot"]
+        origins = ["core", "acme"]
+        mirror_interval = 600
+
+        [[upstream.mirror]]
+        origin = "core"
+        channel = "stable"
+
+        [webhooks]
+        attempts = 3
+        retry_delay = 5
+        workers = 8
+        queue_size = 1024
+        allow_internal_urls = true
+
         [github]
         url = "https://api.github.com"
         client_id = "0c2f738a7d0bd300de10"
@@ -167,6 +300,29 @@ mod tests {
         assert_eq!(config.targets[0].architecture, Architecture::X86_64);
         assert_eq!(config.targets[1].platform, Platform::Windows);
         assert_eq!(config.targets[1].architecture, Architecture::X86_64);
+        assert_eq!(config.revoked_keys, vec!["core-20160810182414".to_string()]);
+        assert_eq!(config.storage.backend, StorageBackend::S3);
+        assert_eq!(config.storage.bucket, Some("habitat-artifacts".to_string()));
+        assert_eq!(config.storage.endpoint,
+                   Some("http://localhost:9000".to_string()));
+        assert_eq!(config.storage.region, "us-east-1");
+        assert_eq!(config.storage.presigned_urls, true);
+        assert_eq!(config.storage.presigned_url_ttl, 600);
+        assert_eq!(config.upstream.urls,
+                   vec!["https://willem.habitat.sh/v1/depot".to_string()]);
+        assert_eq!(config.upstream.origins,
+                   vec!["core".to_string(), "acme".to_string()]);
+        assert_eq!(config.upstream.mirror_interval, 600);
+        assert_eq!(config.upstream.mirror,
+                   vec![MirrorCfg {
+                            origin: "core".to_string(),
+                            channel: "stable".to_string(),
+                        }]);
+        assert_eq!(config.webhooks.attempts, 3);
+        assert_eq!(config.webhooks.retry_delay, 5);
+        assert_eq!(config.webhooks.workers, 8);
+        assert_eq!(config.webhooks.queue_size, 1024);
+        assert_eq!(config.webhooks.allow_internal_urls, true);
     }
 
     #[test]
@@ -178,5 +334,11 @@ mod tests {
 
         let config = Config::from_raw(&content).unwrap();
         assert_eq!(config.http.port, 9000);
+        assert_eq!(config.storage.backend, StorageBackend::Local);
+        assert!(config.upstream.urls.is_empty());
+        assert_eq!(config.upstream.origins, vec!["core".to_string()]);
+        assert_eq!(config.webhooks.attempts, 5);
+        assert_eq!(config.webhooks.workers, 4);
+        assert_eq!(config.webhooks.allow_internal_urls, false);
     }
 }
@@ -12,19 +12,29 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::fs;
+use std::fs::{self, File};
 use std::io;
 use std::path::PathBuf;
+use std::str::FromStr;
 
 use hab_core;
-use hab_core::package::{FromArchive, PackageArchive};
-use hab_net::routing::Broker;
+use hab_core::package::{FromArchive, PackageArchive, PackageTarget};
+use hab_http::ApiClient;
+use hab_net::config::RouterCfg;
+use hab_net::routing::{Broker, BrokerConn};
+use hab_net::server::NetIdent;
+use hyper::client::Response;
+use hyper::status::StatusCode;
+use protocol::net::{NetError, NetOk};
 use protocol::originsrv;
+use protobuf::RepeatedField;
 use time;
 use walkdir::WalkDir;
 
 use super::DepotUtil;
-use error::Result;
+use VERSION;
+use error::{Error, Result};
+use storage::ArtifactLocation;
 
 #[derive(Debug)]
 /// A struct containing the details of a repair run by `Doctor`.
@@ -108,6 +118,9 @@ pub enum OperationType {
     /// Record of cleaning up after the doctor has run. Contains the filepath of the trash which
     /// was cleaned.
     CleanupTrash(String),
+    /// Record of indexing the files of a package which was uploaded before package files were
+    /// indexed. Contains the key of the package's archive in artifact storage.
+    IndexFiles(String),
     /// Record of initializing the depot's datastore filesystem. Contains the filepath of the new
     /// filesystem.
     InitDepotFs(String),
@@ -124,6 +137,9 @@ pub enum Reason {
     IO(io::Error),
     FileExists,
     NotEmpty,
+    NotStored,
+    Remote(NetError),
+    Storage(Error),
 }
 
 #[derive(Debug)]
@@ -253,6 +269,93 @@ impl<'a> Doctor<'a> {
     }
 }
 
+/// Indexes the files of every package which has none indexed, which is the case for packages
+/// uploaded before the depot indexed them, so that they can be found by the files they contain.
+/// The files are read from the packages' archives in artifact storage.
+pub fn index_files(depot: &DepotUtil) -> Result<Report> {
+    let mut report = ReportBuilder::new();
+    Broker::run(DepotUtil::net_ident(), depot.config.route_addrs());
+    let mut conn = try!(Broker::connect());
+    let request = originsrv::OriginPackageFilesUnindexedListRequest::new();
+    let unindexed: originsrv::OriginPackageFilesUnindexedListResponse =
+        try!(conn.route(&request));
+    for package in unindexed.get_packages() {
+        let target = match PackageTarget::from_str(package.get_target()) {
+            Ok(target) => target,
+            Err(e) => {
+                report.failure(OperationType::IndexFiles(package.get_ident().to_string()),
+                               Reason::BadMetadata(e));
+                continue;
+            }
+        };
+        let key = depot.archive_key(package.get_ident(), &target);
+        match index_package_files(depot, &mut conn, package.get_ident(), &key) {
+            Ok(()) => report.success(OperationType::IndexFiles(key)),
+            Err(reason) => report.failure(OperationType::IndexFiles(key), reason),
+        };
+    }
+    Ok(report.generate())
+}
+
+fn index_package_files(depot: &DepotUtil,
+                       conn: &mut BrokerConn,
+                       ident: &originsrv::OriginPackageIdent,
+                       key: &str)
+                       -> ::std::result::Result<(), Reason> {
+    // Archives which aren't kept on the depot node are copied to its staging directory to be read
+    let staged = depot.staging_path().join(key.rsplit('/').next().unwrap());
+    let path = match depot.storage.fetch(key) {
+        Ok(Some(ArtifactLocation::File(path))) => path,
+        Ok(Some(ArtifactLocation::Stream(mut body, _))) => {
+            try!(stage(depot, &mut body, &staged).map_err(Reason::IO));
+            staged.clone()
+        }
+        Ok(Some(ArtifactLocation::Redirect(url))) => {
+            let mut response = try!(download(&url).map_err(Reason::Storage));
+            try!(stage(depot, &mut response, &staged).map_err(Reason::IO));
+            staged.clone()
+        }
+        Ok(None) => return Err(Reason::NotStored),
+        Err(e) => return Err(Reason::Storage(e)),
+    };
+    let files = PackageArchive::new(path.clone()).file_list();
+    if path == staged {
+        if let Err(e) = fs::remove_file(&staged) {
+            warn!("Unable to remove {}, err={}", staged.display(), e);
+        }
+    }
+    let files = try!(files.map_err(Reason::BadMetadata))
+        .into_iter()
+        .map(|file| file.path)
+        .collect();
+    let mut request = originsrv::OriginPackageFilesSet::new();
+    request.set_ident(ident.clone());
+    request.set_files(RepeatedField::from_vec(files));
+    try!(conn.route::<originsrv::OriginPackageFilesSet, NetOk>(&request)
+             .map_err(Reason::Remote));
+    Ok(())
+}
+
+fn stage<R: io::Read>(depot: &DepotUtil, body: &mut R, staged: &PathBuf) -> io::Result<()> {
+    try!(fs::create_dir_all(depot.staging_path()));
+    let mut file = try!(File::create(staged));
+    try!(io::copy(body, &mut file));
+    Ok(())
+}
+
+fn download(url: &str) -> Result<Response> {
+    let client = try!(ApiClient::new(url, "hab-depot", VERSION, None)
+                          .map_err(|e| Error::ArtifactStorage(e.to_string())));
+    let response = try!(client
+                            .get("")
+                            .send()
+                            .map_err(|e| Error::ArtifactStorage(e.to_string())));
+    if response.status != StatusCode::Ok {
+        return Err(Error::HTTP(response.status));
+    }
+    Ok(response)
+}
+
 /// Runs the repair tool on the given Depot and returns a Report containing the results. A repair
 /// tool analyzes all packages found within the Depot's metadata store and re-inserts them into
 /// the file system and re-builds all indices.
@@ -0,0 +1,128 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Records package downloads towards their download statistics.
+//!
+//! Downloads are recorded off the request path so a slow or unavailable originsrv never holds up
+//! a download. Records are queued for a recorder thread which routes them one at a time. The
+//! statistics are best effort: records which don't fit in the queue are dropped and logged.
+
+use std::sync::Mutex;
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::thread::{self, JoinHandle};
+
+use hab_net::routing::{Broker, BrokerConn};
+use protocol::net::NetOk;
+use protocol::originsrv::*;
+
+/// Number of records which may wait for the recorder before new ones are dropped.
+const QUEUE_SIZE: usize = 4096;
+
+/// Hands download records over to the recorder. Records are dropped when no recorder is
+/// running, which is the case for depots under test.
+#[derive(Default)]
+pub struct Downloads(Mutex<Option<SyncSender<OriginPackageDownloadRecord>>>);
+
+impl Downloads {
+    pub fn record(&self, package: &OriginPackage) {
+        let sender = self.0.lock().expect("downloads lock is poisoned");
+        if let Some(ref sender) = *sender {
+            let mut record = OriginPackageDownloadRecord::new();
+            record.set_package_id(package.get_id());
+            record.set_ident(package.get_ident().clone());
+            match sender.try_send(record) {
+                Ok(()) => (),
+                Err(TrySendError::Full(record)) => {
+                    warn!("Download record queue is full, dropping download of {}",
+                          record.get_ident());
+                }
+                Err(TrySendError::Disconnected(record)) => {
+                    error!("Download recorder is not running, dropping download of {}",
+                           record.get_ident());
+                }
+            }
+        }
+    }
+}
+
+/// Start the recorder thread, returning the handle downloads are recorded through.
+pub fn start_recorder() -> (Downloads, JoinHandle<()>) {
+    let (tx, rx) = mpsc::sync_channel(QUEUE_SIZE);
+    let handle = thread::Builder::new()
+        .name("download-recorder".to_string())
+        .spawn(move || run(rx))
+        .unwrap();
+    (Downloads(Mutex::new(Some(tx))), handle)
+}
+
+fn run(rx: Receiver<OriginPackageDownloadRecord>) {
+    let mut conn: Option<BrokerConn> = None;
+    while let Ok(record) = rx.recv() {
+        if conn.is_none() {
+            match Broker::connect() {
+                Ok(c) => conn = Some(c),
+                Err(e) => {
+                    error!("Unable to connect to broker, dropping download of {}, err={}",
+                           record.get_ident(),
+                           e);
+                    continue;
+                }
+            }
+        }
+        let result = conn.as_mut()
+            .unwrap()
+            .route::<OriginPackageDownloadRecord, NetOk>(&record);
+        if let Err(e) = result {
+            warn!("Unable to record download of {}, err={}",
+                  record.get_ident(),
+                  e);
+            conn = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_queues_download() {
+        let (tx, rx) = mpsc::sync_channel(1);
+        let downloads = Downloads(Mutex::new(Some(tx)));
+        let mut ident = OriginPackageIdent::new();
+        ident.set_origin("core".to_string());
+        ident.set_name("cacerts".to_string());
+        ident.set_version("2017.01.17".to_string());
+        ident.set_release("20170209064045".to_string());
+        let mut package = OriginPackage::new();
+        package.set_id(7);
+        package.set_ident(ident);
+
+        downloads.record(&package);
+        // A full queue drops the download rather than holding up the request.
+        downloads.record(&package);
+
+        let record = rx.try_recv().unwrap();
+        assert_eq!(record.get_package_id(), 7);
+        assert_eq!(record.get_ident().to_string(),
+                   "core/cacerts/2017.01.17/20170209064045");
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn record_without_recorder() {
+        let downloads = Downloads::default();
+        downloads.record(&OriginPackage::new());
+    }
+}
@@ -18,6 +18,7 @@ use std::io;
 use std::fmt;
 use std::result;
 
+use depot_client;
 use hab_core;
 use hab_core::package::{self, Identifiable};
 use hab_net;
@@ -26,9 +27,11 @@ use protocol::net::NetError;
 
 #[derive(Debug)]
 pub enum Error {
+    ArtifactStorage(String),
     BadPort(String),
     ChannelAlreadyExists(String),
     ChannelDoesNotExist(String),
+    DepotClient(depot_client::Error),
     HabitatCore(hab_core::Error),
     HabitatNet(hab_net::Error),
     HTTP(hyper::status::StatusCode),
@@ -41,6 +44,7 @@ pub enum Error {
     PackageIsAlreadyInChannel(String, String),
     ProtocolNetError(NetError),
     RemotePackageNotFound(package::PackageIdent),
+    Upstream(String),
     WriteSyncFailed,
 }
 
@@ -49,9 +53,11 @@ pub type Result<T> = result::Result<T, Error>;
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
+            Error::ArtifactStorage(ref e) => format!("Artifact storage error, {}", e),
             Error::BadPort(ref e) => format!("{} is an invalid port. Valid range 1-65535.", e),
             Error::ChannelAlreadyExists(ref e) => format!("{} already exists.", e),
             Error::ChannelDoesNotExist(ref e) => format!("{} does not exist.", e),
+            Error::DepotClient(ref e) => format!("{}", e),
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::HabitatNet(ref e) => format!("{}", e),
             Error::HTTP(ref e) => format!("{}", e),
@@ -79,6 +85,7 @@ impl fmt::Display for Error {
                     format!("Cannot find a release of package in any sources: {}", pkg)
                 }
             }
+            Error::Upstream(ref e) => format!("Upstream depot error, {}", e),
             Error::WriteSyncFailed => format!("Could not write to destination; perhaps the disk is full?"),
         };
         write!(f, "{}", msg)
@@ -88,9 +95,11 @@ impl fmt::Display for Error {
 impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
+            Error::ArtifactStorage(_) => "Failed to access the artifact storage",
             Error::BadPort(_) => "Received an invalid port or a number outside of the valid range.",
             Error::ChannelAlreadyExists(_) => "Channel already exists.",
             Error::ChannelDoesNotExist(_) => "Channel does not exist.",
+            Error::DepotClient(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
             Error::HabitatNet(ref err) => err.description(),
             Error::HTTP(_) => "Received an HTTP error",
@@ -103,6 +112,7 @@ impl error::Error for Error {
             Error::NoXFilename => "Invalid download from a Depot - missing X-Filename header",
             Error::NoFilePart => "An invalid path was passed - we needed a filename, and this path does not have one",
             Error::MessageTypeNotFound => "Unable to find message for given type",
+            Error::Upstream(_) => "Failed to pull a package from an upstream depot",
             Error::WriteSyncFailed => "Could not write to destination; bytes written was 0 on a non-0 buffer",
         }
     }
@@ -114,6 +124,12 @@ impl From<hab_core::Error> for Error {
     }
 }
 
+impl From<depot_client::Error> for Error {
+    fn from(err: depot_client::Error) -> Error {
+        Error::DepotClient(err)
+    }
+}
+
 impl From<ffi::NulError> for Error {
     fn from(err: ffi::NulError) -> Error {
         Error::NulError(err)
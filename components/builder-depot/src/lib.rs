@@ -15,11 +15,15 @@
 extern crate habitat_builder_protocol as protocol;
 #[macro_use]
 extern crate habitat_core as hab_core;
+extern crate habitat_depot_client as depot_client;
+extern crate habitat_http_client as hab_http;
 extern crate habitat_net as hab_net;
 extern crate builder_core as bld_core;
 extern crate bodyparser;
 extern crate crypto;
+#[macro_use]
 extern crate hyper;
+extern crate hyper_openssl;
 extern crate iron;
 extern crate iron_test;
 extern crate libc;
@@ -35,7 +39,9 @@ extern crate router;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
+#[macro_use]
 extern crate serde_json;
+extern crate tempdir;
 extern crate tempfile;
 extern crate time;
 extern crate toml;
@@ -49,75 +55,89 @@ extern crate uuid;
 pub mod config;
 pub mod error;
 pub mod doctor;
+pub mod downloads;
 pub mod server;
+pub mod storage;
+pub mod upstream;
+pub mod webhooks;
 
 pub use self::config::Config;
 pub use self::error::{Error, Result};
 
-use std::fs;
+pub const VERSION: &'static str = include_str!(concat!(env!("OUT_DIR"), "/VERSION"));
+
 use std::path::{Path, PathBuf};
 
 use crypto::sha2::Sha256;
 use crypto::digest::Digest;
-use hab_core::package::{Identifiable, PackageArchive, PackageTarget};
+use downloads::Downloads;
+use hab_core::package::{Identifiable, PackageTarget};
 use hab_net::server::NetIdent;
 use iron::typemap;
+use storage::ArtifactStorage;
+use upstream::Upstream;
+use webhooks::Webhooks;
 
 pub struct DepotUtil {
     pub config: Config,
+    pub storage: Box<ArtifactStorage>,
+    pub upstream: Upstream,
+    pub webhooks: Webhooks,
+    pub downloads: Downloads,
 }
 
 impl DepotUtil {
     pub fn new(config: Config) -> DepotUtil {
-        DepotUtil { config: config }
-    }
-
-    // Return a PackageArchive representing the given package. None is returned if the Depot
-    // doesn't have an archive for the given package.
-    fn archive<T: Identifiable>(&self,
-                                ident: &T,
-                                target: &PackageTarget)
-                                -> Option<PackageArchive> {
-        let file = self.archive_path(ident, target);
-        match fs::metadata(&file) {
-            Ok(_) => Some(PackageArchive::new(file)),
-            Err(_) => None,
+        let storage = storage::from_config(&config.storage,
+                                           Path::new(&config.path).join("pkgs"));
+        let upstream = Upstream::new(&config.upstream);
+        DepotUtil {
+            config: config,
+            storage: storage,
+            upstream: upstream,
+            webhooks: Webhooks::default(),
+            downloads: Downloads::default(),
         }
     }
 
-    // Return a formatted string representing the filename of an archive for the given package
-    // identifier pieces.
-    fn archive_path<T: Identifiable>(&self, ident: &T, target: &PackageTarget) -> PathBuf {
+    // Return the key the archive of the given package is kept under in artifact storage.
+    fn archive_key<T: Identifiable>(&self, ident: &T, target: &PackageTarget) -> String {
         let mut digest = Sha256::new();
         let mut output = [0; 64];
         digest.input_str(&ident.to_string());
         digest.result(&mut output);
-        self.packages_path()
-            .join(format!("{:x}", output[0]))
-            .join(format!("{:x}", output[1]))
-            .join(format!("{}-{}-{}-{}-{}-{}.hart",
-                          ident.origin(),
-                          ident.name(),
-                          ident.version().unwrap(),
-                          ident.release().unwrap(),
-                          target.architecture,
-                          target.platform))
+        format!("{:x}/{:x}/{}-{}-{}-{}-{}-{}.hart",
+                output[0],
+                output[1],
+                ident.origin(),
+                ident.name(),
+                ident.version().unwrap(),
+                ident.release().unwrap(),
+                target.architecture,
+                target.platform)
     }
 
-    // Return a formatted string representing the folder location for an archive.
-    fn archive_parent<T: Identifiable>(&self, ident: &T) -> PathBuf {
-        let mut digest = Sha256::new();
-        let mut output = [0; 64];
-        digest.input_str(&ident.to_string());
-        digest.result(&mut output);
-        self.packages_path()
-            .join(format!("{:x}", output[0]))
-            .join(format!("{:x}", output[1]))
+    // Return a formatted string representing the filename of an archive for the given package
+    // identifier pieces when kept in local storage.
+    fn archive_path<T: Identifiable>(&self, ident: &T, target: &PackageTarget) -> PathBuf {
+        self.packages_path().join(self.archive_key(ident, target))
     }
 
     fn packages_path(&self) -> PathBuf {
         Path::new(&self.config.path).join("pkgs")
     }
+
+    // Return the directory holding the temporary key caches origin encryption keys are loaded
+    // into to encrypt origin secrets.
+    fn key_cache_path(&self) -> PathBuf {
+        Path::new(&self.config.path).join("keys")
+    }
+
+    // Return the directory uploaded archives are written to before being handed over to
+    // artifact storage.
+    fn staging_path(&self) -> PathBuf {
+        Path::new(&self.config.path).join("staging")
+    }
 }
 
 impl typemap::Key for DepotUtil {
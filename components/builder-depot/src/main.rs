@@ -65,6 +65,9 @@ fn app<'a, 'b>() -> clap::App<'a, 'b> {
         (@subcommand repair =>
             (about: "Verify and repair data integrity of the package Depot")
         )
+        (@subcommand index =>
+            (about: "Index the files of packages uploaded before package files were indexed")
+        )
     )
 }
 
@@ -98,6 +101,7 @@ fn dispatch(config: Config, matches: &clap::ArgMatches) -> Result<()> {
     match matches.subcommand_name() {
         Some("start") => start(config),
         Some("repair") => repair(config),
+        Some("index") => index(config),
         Some(cmd) => {
             debug!("Dispatch failed, no match for command: {:?}", cmd);
             Ok(())
@@ -135,6 +139,19 @@ pub fn repair(config: Config) -> Result<()> {
     Ok(())
 }
 
+/// Indexes the files of the packages in the depot's metadata which have none indexed, reading
+/// them from the packages' archives.
+///
+/// # Failures
+///
+/// * The packages without indexed files cannot be listed
+pub fn index(config: Config) -> Result<()> {
+    let depot = depot::DepotUtil::new(config);
+    let report = try!(depot::doctor::index_files(&depot));
+    println!("Report: {:?}", &report);
+    Ok(())
+}
+
 fn exit_with(err: Error, code: i32) {
     println!("{}", err);
     process::exit(code)
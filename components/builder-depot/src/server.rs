@@ -13,48 +13,60 @@
 // limitations under the License.
 
 use std::any::TypeId;
-use std::collections::HashMap;
+use std::cmp;
+use std::collections::{HashMap, HashSet};
 use std::fs::{self, File};
-use std::path::PathBuf;
-use std::io::{Read, Write, BufWriter};
+use std::path::{Path, PathBuf};
+use std::io::{self, Read, Seek, SeekFrom, Write, BufWriter};
 use std::result;
-use std::str::FromStr;
+use std::str::{self, FromStr};
 
 use uuid::Uuid;
 use bodyparser;
-use hab_core::package::{Identifiable, FromArchive, PackageArchive, PackageTarget};
+use hab_core::package::{bundle, Identifiable, FromArchive, PackageArchive, PackageTarget};
+use hab_core::crypto::artifact;
 use hab_core::crypto::keys::{self, PairType};
-use hab_core::crypto::SigKeyPair;
+use hab_core::crypto::{BoxKeyPair, SigKeyPair};
+use hab_core::crypto::hash::Blake2bHasher;
 use hab_core::event::*;
 use hab_net::config::RouterCfg;
 use hab_net::http::controller::*;
 use hab_net::privilege;
 use hab_net::routing::{Broker, RouteResult};
 use hab_net::server::NetIdent;
-use hyper::header::{Charset, ContentDisposition, DispositionType, DispositionParam};
+use hyper::header::{AcceptRanges, ByteRangeSpec, Charset, ContentDisposition, ContentLength,
+                    ContentRange, ContentRangeSpec, DispositionType, DispositionParam, Range,
+                    RangeUnit};
 use hyper::mime::{Mime, TopLevel, SubLevel, Attr, Value};
 use iron::{status, headers, typemap};
 use iron::headers::{ContentType, UserAgent};
 use iron::middleware::BeforeMiddleware;
 use iron::prelude::*;
 use iron::request::Body;
+use iron::response::{BodyReader, WriteBody};
 use iron::typemap::Key;
 use mount::Mount;
 use persistent;
 use protobuf::{self, parse_from_bytes};
-use protocol::net::{NetOk, ErrCode, NetError};
+use protocol::net::{self, NetOk, ErrCode, NetError};
 use protocol::originsrv::*;
 use protocol::Routable;
-use protocol::scheduler::{Group, GroupCreate, GroupGet, PackageStatsGet, PackageStats};
+use protocol::scheduler::{Group, GroupCreate, GroupGet, GroupGraph, GroupGraphGet, GroupRetry,
+                          PackageStatsGet, PackageStats, ProjectState};
 use protocol::sessionsrv::{Account, AccountGet};
 use regex::Regex;
 use router::{Params, Router};
 use serde::Serialize;
 use serde_json;
+use tempdir::TempDir;
 use url;
 use urlencoded::UrlEncodedQuery;
 
 use super::DepotUtil;
+use downloads;
+use storage::ArtifactLocation;
+use upstream::{self, Route};
+use webhooks;
 use config::Config;
 use error::{Error, Result};
 
@@ -121,6 +133,54 @@ struct OriginCreateReq {
     name: String,
 }
 
+#[derive(Clone, Serialize, Deserialize)]
+struct OriginUpdateReq {
+    rdeps_builds: bool,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct PackageReasonReq {
+    reason: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct WebhookCreateReq {
+    url: String,
+    secret: String,
+    events: Vec<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct SecretCreateReq {
+    name: String,
+    value: String,
+    /// Name of the origin's project the secret is scoped to, if any
+    #[serde(default)]
+    project: Option<String>,
+}
+
+#[derive(Serialize)]
+struct EncryptedSecret<'a> {
+    name: &'a str,
+    value: &'a str,
+}
+
+#[derive(Serialize)]
+struct BundleImport {
+    imported: Vec<String>,
+    existing: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct SearchResults<'a> {
+    range_start: isize,
+    range_end: isize,
+    total_count: isize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_range: Option<isize>,
+    package_list: &'a [OriginPackageSearchResult],
+}
+
 #[derive(Serialize)]
 struct PackageResults<'a, T: 'a> {
     range_start: isize,
@@ -201,6 +261,35 @@ pub fn origin_show(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+pub fn origin_update(req: &mut Request) -> IronResult<Response> {
+    let (session_id, origin) = {
+        let session = req.extensions.get::<Authenticated>().unwrap();
+        let params = req.extensions.get::<Router>().unwrap();
+        match params.find("origin") {
+            Some(origin) => (session.get_id(), origin.to_string()),
+            None => return Ok(Response::with(status::BadRequest)),
+        }
+    };
+    if !try!(check_origin_access(req, session_id, &origin)) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut request = OriginUpdate::new();
+    request.set_name(origin);
+    match req.get::<bodyparser::Struct<OriginUpdateReq>>() {
+        Ok(Some(body)) => request.set_rdeps_builds(body.rdeps_builds),
+        _ => return Ok(Response::with(status::UnprocessableEntity)),
+    };
+    match route_message::<OriginUpdate, Origin>(req, &request) {
+        Ok(origin) => {
+            let mut response = render_json(status::Ok, &origin);
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
 pub fn get_origin<T: ToString>(req: &mut Request, origin: T) -> IronResult<Option<Origin>> {
     let mut request = OriginGet::new();
     request.set_name(origin.to_string());
@@ -331,6 +420,14 @@ pub fn invite_to_origin(req: &mut Request) -> IronResult<Response> {
                            id: invitation.get_id().to_string(),
                            account: session.get_id().to_string(),
                        });
+            notify_webhooks(req,
+                            invite_request.get_origin_id(),
+                            invite_request.get_origin_name(),
+                            OriginWebhookEvent::Invitation,
+                            json!({
+                                "invitation_id": invitation.get_id().to_string(),
+                                "account": invitation.get_account_name(),
+                            }));
             Ok(render_json(status::Created, &invitation))
         }
         Err(err) => Ok(render_net_error(&err)),
@@ -405,9 +502,85 @@ pub fn list_origin_members(req: &mut Request) -> IronResult<Response> {
     }
 }
 
-fn write_archive(filename: &PathBuf, body: &mut Body) -> Result<PackageArchive> {
+/// Verify the signatures of an uploaded artifact against the public keys stored for its signers.
+///
+/// The artifact must be signed with a key of the origin it's uploaded to, and every signature
+/// must come from a known key revision which hasn't been revoked. If the artifact can't be
+/// trusted, the reason is returned so it can be passed back to the uploader.
+fn verify_signatures(req: &mut Request,
+                     revoked_keys: &[String],
+                     origin: &str,
+                     archive_path: &Path,
+                     key_cache: &Path)
+                     -> IronResult<result::Result<(), String>> {
+    let header = match artifact::get_artifact_header(archive_path) {
+        Ok(header) => header,
+        Err(e) => return Ok(Err(format!("Artifact header is unreadable: {}", e))),
+    };
+    if let Err(e) = fs::create_dir_all(key_cache) {
+        error!("Unable to create key cache {:?}, err={:?}", key_cache, e);
+        return Err(IronError::new(e, status::InternalServerError));
+    }
+    for (i, signature) in header.signatures.iter().enumerate() {
+        let (name, revision) = match keys::parse_name_with_rev(&signature.key_name) {
+            Ok(name_with_rev) => name_with_rev,
+            Err(e) => return Ok(Err(format!("Artifact signature is invalid: {}", e))),
+        };
+        if i == 0 && name != origin {
+            return Ok(Err(format!("Artifact is signed with {} which is not a key of the {} \
+                                   origin",
+                                  signature.key_name,
+                                  origin)));
+        }
+        if revoked_keys.contains(&signature.key_name) {
+            return Ok(Err(format!("Artifact is signed with revoked key {}",
+                                  signature.key_name)));
+        }
+        if let Err(msg) = try!(cache_public_key(req, name, revision, key_cache)) {
+            return Ok(Err(format!("Artifact is signed with {}", msg)));
+        }
+    }
+    match artifact::verify(archive_path, key_cache) {
+        Ok(_) => Ok(Ok(())),
+        Err(e) => Ok(Err(format!("Artifact signature verification failed: {}", e))),
+    }
+}
+
+/// Write the public key stored for an origin key revision into `key_cache`. If the depot has no
+/// usable key for the revision, the reason is returned.
+fn cache_public_key(req: &mut Request,
+                    origin: String,
+                    revision: String,
+                    key_cache: &Path)
+                    -> IronResult<result::Result<(), String>> {
+    let name_with_rev = format!("{}-{}", origin, revision);
+    let mut request = OriginPublicKeyGet::new();
+    request.set_origin(origin);
+    request.set_revision(revision);
+    let key = match route_message::<OriginPublicKeyGet, OriginPublicKey>(req, &request) {
+        Ok(key) => key,
+        Err(err) => {
+            if err.get_code() == ErrCode::ENTITY_NOT_FOUND {
+                return Ok(Err(format!("unknown key {}", name_with_rev)));
+            }
+            let body = serde_json::to_string(&err).unwrap();
+            let status = net_err_to_http(err.get_code());
+            return Err(IronError::new(err, (body, status)));
+        }
+    };
+    let body = String::from_utf8_lossy(key.get_body());
+    if let Err(e) = SigKeyPair::write_file_from_str(&body, key_cache) {
+        return Ok(Err(format!("invalid key {}: {}", name_with_rev, e)));
+    }
+    Ok(Ok(()))
+}
+
+/// Write an uploaded archive to disk, hashing it on the way through so the archive doesn't
+/// have to be read back just to compute its checksum.
+fn write_archive(filename: &PathBuf, body: &mut Body) -> Result<(PackageArchive, String)> {
     let file = try!(File::create(&filename));
     let mut writer = BufWriter::new(file);
+    let mut hasher = Blake2bHasher::new();
     let mut written: i64 = 0;
     let mut buf = [0u8; 100000]; // Our byte buffer
     loop {
@@ -423,11 +596,12 @@ fn write_archive(filename: &PathBuf, body: &mut Body) -> Result<PackageArchive>
                 if bytes_written == 0 {
                     return Err(Error::WriteSyncFailed);
                 }
+                hasher.update(&buf[0..bytes_written]);
                 written = written + (bytes_written as i64);
             }
         };
     }
-    Ok(PackageArchive::new(filename))
+    Ok((PackageArchive::new(filename), hasher.finalize()))
 }
 
 fn upload_origin_key(req: &mut Request) -> IronResult<Response> {
@@ -499,6 +673,11 @@ fn upload_origin_key(req: &mut Request) -> IronResult<Response> {
                            version: request.get_revision().to_string(),
                            account: session.get_id().to_string(),
                        });
+            notify_webhooks(req,
+                            request.get_origin_id(),
+                            &origin,
+                            OriginWebhookEvent::KeyUpload,
+                            json!({ "revision": request.get_revision() }));
             let mut response =
                 Response::with((status::Created,
                                 format!("/origins/{}/keys/{}", &origin, &request.get_revision())));
@@ -644,23 +823,23 @@ fn upload_package(req: &mut Request) -> IronResult<Response> {
         }
     }
 
-    // Find the path to folder where archive should be created, and
-    // create the folder if necessary
-    let parent_path = depot.archive_parent(&ident);
+    // Uploads are staged on local disk until they have been checked and verified, and only
+    // then handed over to artifact storage
+    let staging_path = depot.staging_path();
 
-    match fs::create_dir_all(parent_path.clone()) {
+    match fs::create_dir_all(&staging_path) {
         Ok(_) => {}
         Err(e) => {
-            error!("Unable to create archive directory, err={:?}", e);
+            error!("Unable to create staging directory, err={:?}", e);
             return Ok(Response::with(status::InternalServerError));
         }
     };
 
-    // Create a temp file at the archive location
+    // Create a temp file in the staging directory
     let temp_name = format!("{}.tmp", Uuid::new_v4());
-    let temp_path = parent_path.join(temp_name);
+    let temp_path = staging_path.join(temp_name);
 
-    let mut archive = try!(write_archive(&temp_path, &mut req.body));
+    let (mut archive, checksum_from_artifact) = try!(write_archive(&temp_path, &mut req.body));
     debug!("Package Archive: {:#?}", archive);
 
     let target_from_artifact = match archive.target() {
@@ -685,8 +864,14 @@ fn upload_package(req: &mut Request) -> IronResult<Response> {
         Err(err) => {
             match err.get_code() {
                 ErrCode::ENTITY_NOT_FOUND => {
-                    if depot.archive(&ident, &target_from_artifact).is_some() {
-                        return Ok(Response::with((status::Conflict)));
+                    let key = depot.archive_key(&ident, &target_from_artifact);
+                    match depot.storage.exists(&key) {
+                        Ok(true) => return Ok(Response::with((status::Conflict))),
+                        Ok(false) => (),
+                        Err(e) => {
+                            error!("Unable to check artifact storage for {}, err={}", key, e);
+                            return Ok(Response::with(status::InternalServerError));
+                        }
                     }
                 }
                 _ => {
@@ -697,13 +882,6 @@ fn upload_package(req: &mut Request) -> IronResult<Response> {
         }
     }
 
-    let checksum_from_artifact = match archive.checksum() {
-        Ok(cksum) => cksum,
-        Err(e) => {
-            info!("Could not compute a checksum for {:#?}: {:#?}", archive, e);
-            return Ok(Response::with(status::UnprocessableEntity));
-        }
-    };
     if checksum_from_param != checksum_from_artifact {
         info!("Checksums did not match: from_param={:?}, from_artifact={:?}",
               checksum_from_param,
@@ -711,28 +889,42 @@ fn upload_package(req: &mut Request) -> IronResult<Response> {
         return Ok(Response::with(status::UnprocessableEntity));
     }
 
-    let filename = depot.archive_path(&ident, &target_from_artifact);
-
-    match fs::rename(&temp_path, &filename) {
-        Ok(_) => {}
-        Err(e) => {
-            error!("Unable to rename temp archive {:?} to {:?}, err={:?}",
-                   temp_path,
-                   filename,
-                   e);
-            return Ok(Response::with(status::InternalServerError));
-        }
+    let key_cache = temp_path.with_extension("keys");
+    let verified = verify_signatures(req,
+                                     &depot.config.revoked_keys,
+                                     ident.get_origin(),
+                                     &temp_path,
+                                     &key_cache);
+    let _ = fs::remove_dir_all(&key_cache);
+    if let Err(msg) = try!(verified) {
+        info!("Rejecting upload of {}: {}", ident, msg);
+        let _ = fs::remove_file(&temp_path);
+        return Ok(render_json(status::UnprocessableEntity,
+                              &net::err(ErrCode::REMOTE_REJECTED, msg)));
     }
 
-    info!("File added to Depot at {}", filename.to_string_lossy());
-    let mut archive = PackageArchive::new(filename);
     let mut package = match OriginPackageCreate::from_archive(&mut archive) {
         Ok(package) => package,
         Err(e) => {
             info!("Error building package from archive: {:#?}", e);
+            let _ = fs::remove_file(&temp_path);
             return Ok(Response::with(status::UnprocessableEntity));
         }
     };
+
+    let key = depot.archive_key(&ident, &target_from_artifact);
+    match depot.storage.store(&key, &temp_path) {
+        Ok(_) => info!("File added to Depot at {}", key),
+        Err(e) => {
+            error!("Unable to store temp archive {:?} as {}, err={}",
+                   temp_path,
+                   key,
+                   e);
+            let _ = fs::remove_file(&temp_path);
+            return Ok(Response::with(status::InternalServerError));
+        }
+    }
+
     if ident.satisfies(package.get_ident()) {
         package.set_owner_id(session.get_id());
 
@@ -763,11 +955,21 @@ fn upload_package(req: &mut Request) -> IronResult<Response> {
                        target: target_from_artifact.to_string(),
                        account: session.get_id().to_string(),
                    });
+        depot
+            .webhooks
+            .notify(package.get_origin_id(),
+                    ident.get_origin(),
+                    OriginWebhookEvent::Upload,
+                    json!({
+                        "ident": ident.to_string(),
+                        "target": target_from_artifact.to_string(),
+                        "checksum": package.get_checksum(),
+                    }));
 
         // Schedule re-build of dependent packages (if requested)
-        // Don't schedule builds if the upload is being done by the builder
-        // Currently, we only do dep builds of 'core' packages
-        if depot.config.builds_enabled && ident.get_origin() == "core" &&
+        // Don't schedule builds if the upload is being done by the builder. The scheduler decides
+        // which reverse dependencies to build based on each origin's `rdeps_builds` setting.
+        if depot.config.builds_enabled &&
            !match extract_query_value("builder", req) {
                 Some(_) => true,
                 None => false,
@@ -804,6 +1006,262 @@ fn upload_package(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+/// Import a bundle of artifacts and public origin keys created with `hab pkg bundle create`.
+///
+/// The bundle must be signed with a key the depot knows. Bundled keys are added to the origins
+/// of the bundled packages where they're missing, after which every artifact goes through the
+/// same checks and signature verification as an individual upload. Packages the depot already
+/// has are skipped.
+fn import_bundle(req: &mut Request) -> IronResult<Response> {
+    let lock = req.get::<persistent::State<DepotUtil>>()
+        .expect("depot not found");
+    let depot = lock.read().expect("depot read lock is poisoned");
+
+    let work_dir = depot.staging_path().join(format!("{}.bundle", Uuid::new_v4()));
+    if let Err(e) = fs::create_dir_all(work_dir.join("keys")) {
+        error!("Unable to create staging directory, err={:?}", e);
+        return Ok(Response::with(status::InternalServerError));
+    }
+    let result = import_bundle_from(req, &depot, &work_dir);
+    let _ = fs::remove_dir_all(&work_dir);
+    result
+}
+
+fn import_bundle_from(req: &mut Request,
+                      depot: &DepotUtil,
+                      work_dir: &Path)
+                      -> IronResult<Response> {
+    // TODO: SA - Eliminate need to clone the session
+    let session = req.extensions.get::<Authenticated>().unwrap().clone();
+    let bundle_path = work_dir.join("bundle");
+    let key_cache = work_dir.join("keys");
+    if let Err(e) = File::create(&bundle_path).and_then(|mut f| io::copy(&mut req.body, &mut f)) {
+        error!("Unable to write bundle to {:?}, err={:?}", bundle_path, e);
+        return Ok(Response::with(status::InternalServerError));
+    }
+
+    let signer = match artifact::get_artifact_header(&bundle_path)
+              .and_then(|header| header.key_name().map(|name| name.to_string())) {
+        Ok(signer) => signer,
+        Err(e) => return Ok(reject_bundle(format!("Bundle header is unreadable: {}", e))),
+    };
+    if depot.config.revoked_keys.contains(&signer) {
+        return Ok(reject_bundle(format!("Bundle is signed with revoked key {}", signer)));
+    }
+    let (name, revision) = match keys::parse_name_with_rev(&signer) {
+        Ok(name_with_rev) => name_with_rev,
+        Err(e) => return Ok(reject_bundle(format!("Bundle signature is invalid: {}", e))),
+    };
+    if let Err(msg) = try!(cache_public_key(req, name, revision, &key_cache)) {
+        return Ok(reject_bundle(format!("Bundle is signed with {}", msg)));
+    }
+    let bundle = match bundle::extract(&bundle_path, &key_cache, &work_dir.join("contents")) {
+        Ok(bundle) => bundle,
+        Err(e) => return Ok(reject_bundle(format!("Bundle verification failed: {}", e))),
+    };
+
+    // Every artifact is read before anything is imported, so a bundle holding something the
+    // depot can't take is rejected as a whole
+    let mut packages = Vec::with_capacity(bundle.artifacts.len());
+    let mut origin_ids: HashMap<String, u64> = HashMap::new();
+    for path in bundle.artifacts.iter() {
+        let mut archive = PackageArchive::new(path.clone());
+        let ident = match archive.ident() {
+            Ok(ident) => OriginPackageIdent::from(ident),
+            Err(e) => {
+                return Ok(reject_bundle(format!("{} is not a package artifact: {}",
+                                                path.display(),
+                                                e)))
+            }
+        };
+        let target = match archive.target() {
+            Ok(target) => target,
+            Err(e) => {
+                return Ok(reject_bundle(format!("Could not read the target of {}: {}", ident, e)))
+            }
+        };
+        if !depot.config.targets.contains(&target) {
+            return Ok(reject_bundle(format!("Unsupported package platform or architecture {} \
+                                             of {}",
+                                            target,
+                                            ident)));
+        }
+        if !origin_ids.contains_key(ident.get_origin()) {
+            if !depot.config.insecure &&
+               !try!(check_origin_access(req, session.get_id(), ident.get_origin())) {
+                debug!("Failed origin access check, session: {}, ident: {}",
+                       session.get_id(),
+                       ident);
+                return Ok(Response::with(status::Forbidden));
+            }
+            match try!(get_origin(req, ident.get_origin())) {
+                Some(origin) => origin_ids.insert(ident.get_origin().to_string(), origin.get_id()),
+                None => return Ok(Response::with(status::NotFound)),
+            };
+        }
+        packages.push((ident, target, archive));
+    }
+
+    for path in bundle.keys.iter() {
+        if let Err(msg) = try!(import_bundle_key(req, session.get_id(), &origin_ids, path)) {
+            return Ok(reject_bundle(msg));
+        }
+    }
+
+    let mut result = BundleImport {
+        imported: Vec::new(),
+        existing: Vec::new(),
+    };
+    for (ident, target, mut archive) in packages {
+        let mut request = OriginPackageGet::new();
+        request.set_ident(ident.clone());
+        match route_message::<OriginPackageGet, OriginPackage>(req, &request) {
+            Ok(_) => {
+                result.existing.push(ident.to_string());
+                continue;
+            }
+            Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => (),
+            Err(err) => {
+                error!("import_bundle:1, err={:?}", err);
+                return Ok(Response::with(status::InternalServerError));
+            }
+        }
+
+        if let Err(msg) = try!(verify_signatures(req,
+                                                 &depot.config.revoked_keys,
+                                                 ident.get_origin(),
+                                                 &archive.path,
+                                                 &key_cache)) {
+            return Ok(reject_bundle(format!("{}: {}", ident, msg)));
+        }
+        let mut package = match OriginPackageCreate::from_archive(&mut archive) {
+            Ok(package) => package,
+            Err(e) => {
+                return Ok(reject_bundle(format!("Error building package {} from archive: {}",
+                                                ident,
+                                                e)))
+            }
+        };
+        package.set_owner_id(session.get_id());
+        package.set_origin_id(origin_ids[ident.get_origin()]);
+
+        let key = depot.archive_key(&ident, &target);
+        if let Err(e) = depot.storage.store(&key, &archive.path) {
+            error!("Unable to store bundled archive {:?} as {}, err={}",
+                   archive.path,
+                   key,
+                   e);
+            return Ok(Response::with(status::InternalServerError));
+        }
+        if let Err(err) = route_message::<OriginPackageCreate, OriginPackage>(req, &package) {
+            error!("Unable to create origin package for {:?}, err={:?}",
+                   ident,
+                   err);
+            return Ok(Response::with(status::InternalServerError));
+        }
+        log_event!(req,
+                   Event::PackageUpload {
+                       origin: ident.get_origin().to_string(),
+                       package: ident.get_name().to_string(),
+                       version: ident.get_version().to_string(),
+                       release: ident.get_release().to_string(),
+                       target: target.to_string(),
+                       account: session.get_id().to_string(),
+                   });
+        depot
+            .webhooks
+            .notify(package.get_origin_id(),
+                    ident.get_origin(),
+                    OriginWebhookEvent::Upload,
+                    json!({
+                        "ident": ident.to_string(),
+                        "target": target.to_string(),
+                        "checksum": package.get_checksum(),
+                    }));
+        result.imported.push(ident.to_string());
+    }
+    info!("Imported bundle signed with {}, {} new packages, {} existing",
+          bundle.signer,
+          result.imported.len(),
+          result.existing.len());
+    Ok(render_json(status::Created, &result))
+}
+
+/// Add a bundled public key to its origin unless the depot already has it. Only keys of the
+/// origins the bundle's packages belong to are imported; artifacts signed with keys of any other
+/// origin can only be verified if the depot already has those keys.
+fn import_bundle_key(req: &mut Request,
+                     owner_id: u64,
+                     origin_ids: &HashMap<String, u64>,
+                     path: &Path)
+                     -> IronResult<result::Result<(), String>> {
+    let mut content = String::new();
+    if let Err(e) = File::open(path).and_then(|mut f| f.read_to_string(&mut content)) {
+        error!("Unable to read bundled key {:?}, err={:?}", path, e);
+        return Err(IronError::new(e, status::InternalServerError));
+    }
+    let name_with_rev = match SigKeyPair::parse_key_str(&content) {
+        Ok((PairType::Public, name_with_rev, _)) => name_with_rev,
+        Ok(_) => return Ok(Err("Bundle holds a secret key".to_string())),
+        Err(e) => return Ok(Err(format!("Bundled key is invalid: {}", e))),
+    };
+    let (origin, revision) = match keys::parse_name_with_rev(&name_with_rev) {
+        Ok(name_with_rev) => name_with_rev,
+        Err(e) => return Ok(Err(format!("Bundled key is invalid: {}", e))),
+    };
+    let origin_id = match origin_ids.get(&origin) {
+        Some(id) => *id,
+        None => return Ok(Ok(())),
+    };
+
+    let mut request = OriginPublicKeyGet::new();
+    request.set_origin(origin.clone());
+    request.set_revision(revision.clone());
+    match route_message::<OriginPublicKeyGet, OriginPublicKey>(req, &request) {
+        Ok(_) => return Ok(Ok(())),
+        Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => (),
+        Err(err) => {
+            let body = serde_json::to_string(&err).unwrap();
+            let status = net_err_to_http(err.get_code());
+            return Err(IronError::new(err, (body, status)));
+        }
+    }
+
+    let mut request = OriginPublicKeyCreate::new();
+    request.set_origin_id(origin_id);
+    request.set_name(origin.clone());
+    request.set_revision(revision.clone());
+    request.set_body(content.into_bytes());
+    request.set_owner_id(owner_id);
+    match route_message::<OriginPublicKeyCreate, OriginPublicKey>(req, &request) {
+        Ok(_) => {
+            notify_webhooks(req,
+                            origin_id,
+                            &origin,
+                            OriginWebhookEvent::KeyUpload,
+                            json!({ "revision": revision }));
+            log_event!(req,
+                       Event::OriginKeyUpload {
+                           origin: origin,
+                           version: revision,
+                           account: owner_id.to_string(),
+                       });
+            Ok(Ok(()))
+        }
+        Err(err) => {
+            let body = serde_json::to_string(&err).unwrap();
+            let status = net_err_to_http(err.get_code());
+            Err(IronError::new(err, (body, status)))
+        }
+    }
+}
+
+fn reject_bundle(msg: String) -> Response {
+    info!("Rejecting bundle: {}", msg);
+    render_json(status::UnprocessableEntity,
+                &net::err(ErrCode::REMOTE_REJECTED, msg))
+}
+
 fn package_stats(req: &mut Request) -> IronResult<Response> {
     let origin = {
         let params = req.extensions.get::<Router>().unwrap();
@@ -884,6 +1342,88 @@ fn get_schedule(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+fn group_id_param(req: &mut Request) -> Option<u64> {
+    let params = req.extensions.get::<Router>().unwrap();
+    params.find("groupid").and_then(|id| id.parse::<u64>().ok())
+}
+
+fn retry_schedule(req: &mut Request) -> IronResult<Response> {
+    let group_id = match group_id_param(req) {
+        Some(id) => id,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let insecure = {
+        let lock = req.get::<persistent::State<DepotUtil>>()
+            .expect("depot not found");
+        let depot = lock.read().expect("depot read lock is poisoned");
+        depot.config.insecure
+    };
+
+    if !insecure {
+        let session_id = req.extensions.get::<Authenticated>().unwrap().get_id();
+        let mut request = GroupGet::new();
+        request.set_group_id(group_id);
+        let group = match route_message::<GroupGet, Group>(req, &request) {
+            Ok(group) => group,
+            Err(err) => return Ok(render_net_error(&err)),
+        };
+        // A retry rebuilds the failed and skipped projects of the group, which may belong to
+        // other origins than the one it was scheduled for
+        let origins: HashSet<String> = group
+            .get_projects()
+            .iter()
+            .filter(|project| match project.get_state() {
+                        ProjectState::Failure | ProjectState::Skipped => true,
+                        _ => false,
+                    })
+            .filter_map(|project| project.get_name().split("/").next())
+            .map(|origin| origin.to_string())
+            .collect();
+        for origin in origins {
+            if !try!(check_origin_access(req, session_id, &origin)) {
+                debug!("Failed origin access check, session: {}, group: {}, origin: {}",
+                       session_id,
+                       group_id,
+                       origin);
+                return Ok(Response::with(status::Forbidden));
+            }
+        }
+    }
+
+    let mut request = GroupRetry::new();
+    request.set_group_id(group_id);
+
+    match route_message::<GroupRetry, Group>(req, &request) {
+        Ok(group) => {
+            let mut response = render_json(status::Ok, &group);
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+fn get_schedule_graph(req: &mut Request) -> IronResult<Response> {
+    let group_id = match group_id_param(req) {
+        Some(id) => id,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    let mut conn = Broker::connect().unwrap();
+
+    let mut request = GroupGraphGet::new();
+    request.set_group_id(group_id);
+
+    match conn.route::<GroupGraphGet, GroupGraph>(&request) {
+        Ok(graph) => {
+            let mut response = render_json(status::Ok, &graph);
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
 // This function should not require authentication (session/auth token)
 fn download_origin_key(req: &mut Request) -> IronResult<Response> {
     let params = req.extensions.get::<Router>().unwrap();
@@ -946,6 +1486,108 @@ fn download_latest_origin_key(req: &mut Request) -> IronResult<Response> {
     Ok(response)
 }
 
+fn set_download_headers(response: &mut Response, file_name: String) {
+    let disp = ContentDisposition {
+        disposition: DispositionType::Attachment,
+        parameters: vec![DispositionParam::Filename(Charset::Iso_8859_1,
+                                                    None,
+                                                    file_name.as_bytes().to_vec())],
+    };
+    response.headers.set(disp);
+    response.headers.set(XFileName(file_name));
+}
+
+/// The portion of an artifact a download request asks for.
+enum ByteRange {
+    Full,
+    Partial(u64, u64),
+    Unsatisfiable,
+}
+
+impl ByteRange {
+    /// Resuming a download should not count it twice, so only a request which starts at the
+    /// first byte of an artifact counts as a new download.
+    fn is_new_download(&self) -> bool {
+        match *self {
+            ByteRange::Full | ByteRange::Partial(0, _) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Resolves the `Range` header of a download request against an artifact of `len` bytes. Only a
+/// single byte range is honoured; anything else, or an `If-Range` validator which no longer
+/// matches the artifact's checksum, results in the whole artifact being served.
+fn requested_range(req: &Request, checksum: &str, len: u64) -> ByteRange {
+    let spec = match req.headers.get::<Range>() {
+        Some(&Range::Bytes(ref specs)) if specs.len() == 1 => &specs[0],
+        _ => return ByteRange::Full,
+    };
+    if let Some(&IfRange(ref validator)) = req.headers.get::<IfRange>() {
+        if validator.trim_matches('"') != checksum {
+            return ByteRange::Full;
+        }
+    }
+    if len == 0 {
+        return ByteRange::Unsatisfiable;
+    }
+    let (start, end) = match *spec {
+        ByteRangeSpec::FromTo(start, end) => (start, cmp::min(end, len - 1)),
+        ByteRangeSpec::AllFrom(start) => (start, len - 1),
+        ByteRangeSpec::Last(count) => (len.saturating_sub(count), len - 1),
+    };
+    if start >= len || start > end {
+        ByteRange::Unsatisfiable
+    } else {
+        ByteRange::Partial(start, end)
+    }
+}
+
+fn partial_file_body(path: &Path, start: u64, end: u64) -> io::Result<Box<WriteBody>> {
+    let mut file = try!(File::open(path));
+    try!(file.seek(SeekFrom::Start(start)));
+    Ok(Box::new(BodyReader(file.take(end - start + 1))))
+}
+
+fn partial_stream_body(mut body: Box<Read + Send>,
+                       start: u64,
+                       end: u64)
+                       -> io::Result<Box<WriteBody>> {
+    try!(io::copy(&mut body.by_ref().take(start), &mut io::sink()));
+    Ok(Box::new(BodyReader(body.take(end - start + 1))))
+}
+
+fn partial_response(body: Box<WriteBody>, start: u64, end: u64, len: u64) -> Response {
+    let mut response = Response::with((status::PartialContent, body));
+    response.headers.set(ContentLength(end - start + 1));
+    response
+        .headers
+        .set(ContentRange(ContentRangeSpec::Bytes {
+                              range: Some((start, end)),
+                              instance_length: Some(len),
+                          }));
+    response
+}
+
+fn unsatisfiable_response(len: u64) -> Response {
+    let mut response = Response::with(status::RangeNotSatisfiable);
+    response
+        .headers
+        .set(ContentRange(ContentRangeSpec::Bytes {
+                              range: None,
+                              instance_length: Some(len),
+                          }));
+    dont_cache_response(&mut response);
+    response
+}
+
+/// The artifact checksum doubles as a strong validator, letting clients resume an interrupted
+/// download with `If-Range` without risking a splice of two different artifacts.
+fn set_range_headers(response: &mut Response, checksum: &str) {
+    response.headers.set(AcceptRanges(vec![RangeUnit::Bytes]));
+    response.headers.set(ETag(checksum.to_string()));
+}
+
 fn download_package(req: &mut Request) -> IronResult<Response> {
     let lock = req.get::<persistent::State<DepotUtil>>()
         .expect("depot not found");
@@ -962,44 +1604,112 @@ fn download_package(req: &mut Request) -> IronResult<Response> {
         return Ok(Response::with(status::NotImplemented));
     }
 
-    match route_message::<OriginPackageGet, OriginPackage>(req, &ident_req) {
-        Ok(package) => {
-            if let Some(archive) = depot.archive(package.get_ident(), &agent_target) {
-                match fs::metadata(&archive.path) {
-                    Ok(_) => {
-                        let mut response = Response::with((status::Ok, archive.path.clone()));
-                        do_cache_response(&mut response);
-                        let disp = ContentDisposition {
-                            disposition: DispositionType::Attachment,
-                            parameters: vec![DispositionParam::Filename(Charset::Iso_8859_1,
-                                                                        None,
-                                                                        archive
-                                                                            .file_name()
-                                                                            .as_bytes()
-                                                                            .to_vec())],
-                        };
-                        response.headers.set(disp);
-                        response.headers.set(XFileName(archive.file_name()));
-                        Ok(response)
-                    }
-                    Err(_) => Ok(Response::with(status::NotFound)),
-                }
-            } else {
-                // This should never happen. Writing the package to disk and recording it's
-                // existence in the metadata is a transactional operation and one cannot exist
-                // without the other.
-                panic!("Inconsistent package metadata! Exit and run `hab-depot repair` to fix \
-                        data integrity.");
-            }
-        }
+    let package = match route_message::<OriginPackageGet, OriginPackage>(req, &ident_req) {
+        Ok(package) => package,
         Err(err) => {
             match err.get_code() {
-                ErrCode::ENTITY_NOT_FOUND => Ok(Response::with((status::NotFound))),
+                ErrCode::ENTITY_NOT_FOUND => {
+                    match try!(pull_from_upstream(req,
+                                                  &depot,
+                                                  ident_req.get_ident(),
+                                                  None,
+                                                  &agent_target)) {
+                        Some(package) => package,
+                        None => return Ok(Response::with((status::NotFound))),
+                    }
+                }
                 _ => {
                     error!("download_package:1, err={:?}", err);
-                    Ok(Response::with(status::InternalServerError))
+                    return Ok(Response::with(status::InternalServerError));
+                }
+            }
+        }
+    };
+
+    let key = depot.archive_key(package.get_ident(), &agent_target);
+    let file_name = key.rsplit('/').next().unwrap().to_string();
+    let checksum = package.get_checksum();
+    match depot.storage.fetch(&key) {
+        Ok(Some(ArtifactLocation::File(path))) => {
+            let len = match fs::metadata(&path) {
+                Ok(meta) => meta.len(),
+                Err(e) => {
+                    error!("Unable to stat {}, err={}", path.display(), e);
+                    return Ok(Response::with(status::InternalServerError));
+                }
+            };
+            let range = requested_range(req, checksum, len);
+            if range.is_new_download() {
+                depot.downloads.record(&package);
+            }
+            let mut response = match range {
+                ByteRange::Full => Response::with((status::Ok, path)),
+                ByteRange::Partial(start, end) => {
+                    match partial_file_body(&path, start, end) {
+                        Ok(body) => partial_response(body, start, end, len),
+                        Err(e) => {
+                            error!("Unable to read {}, err={}", path.display(), e);
+                            return Ok(Response::with(status::InternalServerError));
+                        }
+                    }
+                }
+                ByteRange::Unsatisfiable => return Ok(unsatisfiable_response(len)),
+            };
+            do_cache_response(&mut response);
+            set_download_headers(&mut response, file_name);
+            set_range_headers(&mut response, checksum);
+            Ok(response)
+        }
+        Ok(Some(ArtifactLocation::Stream(body, len))) => {
+            let range = requested_range(req, checksum, len);
+            if range.is_new_download() {
+                depot.downloads.record(&package);
+            }
+            let mut response = match range {
+                ByteRange::Full => {
+                    let body: Box<WriteBody> = Box::new(BodyReader(body));
+                    let mut response = Response::with((status::Ok, body));
+                    response.headers.set(ContentLength(len));
+                    response
+                }
+                ByteRange::Partial(start, end) => {
+                    match partial_stream_body(body, start, end) {
+                        Ok(body) => partial_response(body, start, end, len),
+                        Err(e) => {
+                            error!("Unable to read {} from artifact storage, err={}", key, e);
+                            return Ok(Response::with(status::InternalServerError));
+                        }
+                    }
                 }
+                ByteRange::Unsatisfiable => return Ok(unsatisfiable_response(len)),
+            };
+            do_cache_response(&mut response);
+            set_download_headers(&mut response, file_name);
+            set_range_headers(&mut response, checksum);
+            Ok(response)
+        }
+        Ok(Some(ArtifactLocation::Redirect(url))) => {
+            // Without the artifact's length a range can't be resolved here, so only count
+            // requests for the whole artifact
+            if req.headers.get::<Range>().is_none() {
+                depot.downloads.record(&package);
             }
+            // Pre-signed URLs expire, so the redirect itself must not be cached
+            let mut response = Response::with(status::Found);
+            response.headers.set(headers::Location(url));
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Ok(None) => {
+            // This should never happen. Writing the package to storage and recording
+            // it's existence in the metadata is a transactional operation and one cannot
+            // exist without the other.
+            panic!("Inconsistent package metadata! Exit and run `hab-depot repair` to \
+                    fix data integrity.");
+        }
+        Err(e) => {
+            error!("Unable to fetch {} from artifact storage, err={}", key, e);
+            Ok(Response::with(status::InternalServerError))
         }
     }
 }
@@ -1372,6 +2082,475 @@ fn delete_channel(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+/// Resolves the id of the origin a webhook or secret route is for, provided the requestor has
+/// access to the origin. Webhooks and secrets are only ever shown to origin members.
+fn authorized_origin_id(req: &mut Request) -> IronResult<result::Result<u64, Response>> {
+    let (session_id, origin) = {
+        let session = req.extensions.get::<Authenticated>().unwrap();
+        let params = req.extensions.get::<Router>().unwrap();
+        match params.find("origin") {
+            Some(origin) => (session.get_id(), origin.to_string()),
+            None => return Ok(Err(Response::with(status::BadRequest))),
+        }
+    };
+    if !try!(check_origin_access(req, session_id, &origin)) {
+        return Ok(Err(Response::with(status::Forbidden)));
+    }
+    match try!(get_origin(req, &origin)) {
+        Some(origin) => Ok(Ok(origin.get_id())),
+        None => Ok(Err(Response::with(status::NotFound))),
+    }
+}
+
+fn id_from_params(req: &Request) -> Option<u64> {
+    let params = req.extensions.get::<Router>().unwrap();
+    params.find("id").and_then(|id| id.parse().ok())
+}
+
+fn create_webhook(req: &mut Request) -> IronResult<Response> {
+    let session_id = req.extensions.get::<Authenticated>().unwrap().get_id();
+    let origin_id = match try!(authorized_origin_id(req)) {
+        Ok(origin_id) => origin_id,
+        Err(response) => return Ok(response),
+    };
+    let body = match req.get::<bodyparser::Struct<WebhookCreateReq>>() {
+        Ok(Some(body)) => body,
+        _ => return Ok(Response::with(status::UnprocessableEntity)),
+    };
+    let allow_internal = {
+        let lock = req.get::<persistent::State<DepotUtil>>()
+            .expect("depot not found");
+        let depot = lock.read().expect("depot read lock is poisoned");
+        depot.config.webhooks.allow_internal_urls
+    };
+    if let Err(e) = webhooks::validate_url(&body.url, allow_internal) {
+        debug!("Refusing webhook URL {}, {}", body.url, e);
+        return Ok(Response::with(status::UnprocessableEntity));
+    }
+    if body.secret.is_empty() || body.events.is_empty() {
+        return Ok(Response::with(status::UnprocessableEntity));
+    }
+    let mut events = Vec::new();
+    for event in body.events.iter() {
+        match event.parse::<OriginWebhookEvent>() {
+            Ok(event) => events.push(event),
+            Err(_) => return Ok(Response::with(status::UnprocessableEntity)),
+        }
+    }
+
+    let mut request = OriginWebhookCreate::new();
+    request.set_origin_id(origin_id);
+    request.set_url(body.url);
+    request.set_secret(body.secret);
+    request.set_events(events);
+    request.set_owner_id(session_id);
+    match route_message::<OriginWebhookCreate, OriginWebhook>(req, &request) {
+        Ok(webhook) => Ok(render_json(status::Created, &webhook)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+fn list_webhooks(req: &mut Request) -> IronResult<Response> {
+    let origin_id = match try!(authorized_origin_id(req)) {
+        Ok(origin_id) => origin_id,
+        Err(response) => return Ok(response),
+    };
+
+    let mut request = OriginWebhookListRequest::new();
+    request.set_origin_id(origin_id);
+    match route_message::<OriginWebhookListRequest, OriginWebhookListResponse>(req, &request) {
+        Ok(list) => {
+            let mut response = render_json(status::Ok, &list.get_webhooks());
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+fn delete_webhook(req: &mut Request) -> IronResult<Response> {
+    let webhook_id = match id_from_params(req) {
+        Some(id) => id,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let origin_id = match try!(authorized_origin_id(req)) {
+        Ok(origin_id) => origin_id,
+        Err(response) => return Ok(response),
+    };
+
+    let mut request = OriginWebhookDelete::new();
+    request.set_id(webhook_id);
+    request.set_origin_id(origin_id);
+    match route_message::<OriginWebhookDelete, NetOk>(req, &request) {
+        Ok(_) => Ok(Response::with(status::NoContent)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+fn list_webhook_deliveries(req: &mut Request) -> IronResult<Response> {
+    let webhook_id = match id_from_params(req) {
+        Some(id) => id,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let origin_id = match try!(authorized_origin_id(req)) {
+        Ok(origin_id) => origin_id,
+        Err(response) => return Ok(response),
+    };
+
+    let mut request = OriginWebhookDeliveryListRequest::new();
+    request.set_webhook_id(webhook_id);
+    request.set_origin_id(origin_id);
+    match route_message::<OriginWebhookDeliveryListRequest,
+                          OriginWebhookDeliveryListResponse>(req, &request) {
+        Ok(list) => {
+            let mut response = render_json(status::Ok, &list);
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+/// Secret names become environment variable names inside the build studio.
+fn valid_secret_name(name: &str) -> bool {
+    match name.chars().next() {
+        Some('0'...'9') | None => false,
+        _ => {
+            name.chars()
+                .all(|c| match c {
+                         'a'...'z' | 'A'...'Z' | '0'...'9' | '_' => true,
+                         _ => false,
+                     })
+        }
+    }
+}
+
+/// Resolves the id of the project a secret is scoped to, or zero for secrets shared by every
+/// project in the origin.
+fn secret_project_id(req: &mut Request,
+                     origin: &str,
+                     project: Option<&String>)
+                     -> IronResult<result::Result<u64, Response>> {
+    let project = match project {
+        Some(project) => project,
+        None => return Ok(Ok(0)),
+    };
+    let mut request = OriginProjectGet::new();
+    request.set_name(format!("{}/{}", origin, project));
+    match route_message::<OriginProjectGet, OriginProject>(req, &request) {
+        Ok(project) => Ok(Ok(project.get_id())),
+        Err(err) => Ok(Err(render_net_error(&err))),
+    }
+}
+
+/// Returns the origin's encryption key pair, generating one the first time a secret is stored
+/// in the origin. The key files are only written to a temporary key cache, which is removed
+/// once the pair is loaded.
+fn origin_encryption_pair(req: &mut Request,
+                          origin_id: u64,
+                          origin: &str,
+                          owner_id: u64)
+                          -> IronResult<result::Result<BoxKeyPair, Response>> {
+    let cache = {
+        let lock = req.get::<persistent::State<DepotUtil>>()
+            .expect("depot not found");
+        let depot = lock.read().expect("depot read lock is poisoned");
+        depot.key_cache_path()
+    };
+    let cache = match fs::create_dir_all(&cache).and_then(|_| TempDir::new_in(&cache, "origin")) {
+        Ok(cache) => cache,
+        Err(e) => {
+            error!("Unable to create key cache, origin={}, err={}", origin, e);
+            return Ok(Err(Response::with(status::InternalServerError)));
+        }
+    };
+    let mut request = OriginEncryptionKeyGet::new();
+    request.set_origin_id(origin_id);
+    match route_message::<OriginEncryptionKeyGet, OriginEncryptionKey>(req, &request) {
+        Ok(key) => return Ok(load_origin_encryption_pair(&key, origin, cache.path())),
+        Err(err) => {
+            if err.get_code() != ErrCode::ENTITY_NOT_FOUND {
+                return Ok(Err(render_net_error(&err)));
+            }
+        }
+    }
+
+    let (pair, public_body, secret_body) =
+        match generate_origin_encryption_pair(origin, cache.path()) {
+            Ok(generated) => generated,
+            Err(e) => {
+                error!("Unable to generate origin encryption key, origin={}, err={}",
+                       origin,
+                       e);
+                return Ok(Err(Response::with(status::InternalServerError)));
+            }
+        };
+    let mut request = OriginEncryptionKeyCreate::new();
+    request.set_origin_id(origin_id);
+    request.set_name(pair.name.clone());
+    request.set_revision(pair.rev.clone());
+    request.set_public_body(public_body);
+    request.set_secret_body(secret_body);
+    request.set_owner_id(owner_id);
+    match route_message::<OriginEncryptionKeyCreate, OriginEncryptionKey>(req, &request) {
+        // The origin's key is returned, which is another one than the generated pair if a
+        // concurrent request created it first
+        Ok(key) => Ok(load_origin_encryption_pair(&key, origin, cache.path())),
+        Err(err) => Ok(Err(render_net_error(&err))),
+    }
+}
+
+/// Loads both halves of a stored origin encryption key into the key cache.
+fn load_origin_encryption_pair(key: &OriginEncryptionKey,
+                               origin: &str,
+                               cache: &Path)
+                               -> result::Result<BoxKeyPair, Response> {
+    let mut pair = None;
+    for body in vec![key.get_public_body(), key.get_secret_body()] {
+        let result = str::from_utf8(body)
+            .map_err(|e| e.to_string())
+            .and_then(|content| {
+                          BoxKeyPair::write_file_from_str(content, cache).map_err(|e| e.to_string())
+                      });
+        match result {
+            Ok((p, _)) => pair = Some(p),
+            Err(e) => {
+                error!("Unable to load origin encryption key, origin={}, err={}",
+                       origin,
+                       e);
+                return Err(Response::with(status::InternalServerError));
+            }
+        }
+    }
+    // Both halves of the key are written by now, so this is the complete pair
+    Ok(pair.unwrap())
+}
+
+/// Generates a new encryption key pair for the origin, returning it along with the contents of
+/// its public and secret key files.
+fn generate_origin_encryption_pair(origin: &str,
+                                   cache: &Path)
+                                   -> Result<(BoxKeyPair, Vec<u8>, Vec<u8>)> {
+    let pair = try!(BoxKeyPair::generate_pair_for_origin(origin, cache));
+    let mut public_body = Vec::new();
+    let mut secret_body = Vec::new();
+    try!(File::open(try!(BoxKeyPair::get_public_key_path(&pair.name_with_rev(), cache)))
+             .and_then(|mut f| f.read_to_end(&mut public_body)));
+    try!(File::open(try!(BoxKeyPair::get_secret_key_path(&pair.name_with_rev(), cache)))
+             .and_then(|mut f| f.read_to_end(&mut secret_body)));
+    Ok((pair, public_body, secret_body))
+}
+
+fn create_secret(req: &mut Request) -> IronResult<Response> {
+    let session_id = req.extensions.get::<Authenticated>().unwrap().get_id();
+    let origin_id = match try!(authorized_origin_id(req)) {
+        Ok(origin_id) => origin_id,
+        Err(response) => return Ok(response),
+    };
+    let origin = {
+        let params = req.extensions.get::<Router>().unwrap();
+        params.find("origin").unwrap().to_string()
+    };
+    let body = match req.get::<bodyparser::Struct<SecretCreateReq>>() {
+        Ok(Some(body)) => body,
+        _ => return Ok(Response::with(status::UnprocessableEntity)),
+    };
+    // The studio can't pass values containing whitespace into the build, and a job with such a
+    // secret fails
+    if !valid_secret_name(&body.name) || body.value.is_empty() ||
+       body.value.contains(char::is_whitespace) {
+        return Ok(Response::with(status::UnprocessableEntity));
+    }
+    let project_id = match try!(secret_project_id(req, &origin, body.project.as_ref())) {
+        Ok(project_id) => project_id,
+        Err(response) => return Ok(response),
+    };
+    let pair = match try!(origin_encryption_pair(req, origin_id, &origin, session_id)) {
+        Ok(pair) => pair,
+        Err(response) => return Ok(response),
+    };
+    let value = match pair.encrypt(body.value.as_bytes(), &pair) {
+        Ok(payload) => String::from_utf8(payload).expect("Encrypted payloads are UTF-8"),
+        Err(e) => {
+            error!("Unable to encrypt origin secret, origin={}, err={}", origin, e);
+            return Ok(Response::with(status::InternalServerError));
+        }
+    };
+
+    let mut request = OriginSecretCreate::new();
+    request.set_origin_id(origin_id);
+    request.set_project_id(project_id);
+    request.set_name(body.name);
+    request.set_value(value);
+    request.set_owner_id(session_id);
+    match route_message::<OriginSecretCreate, OriginSecret>(req, &request) {
+        Ok(secret) => Ok(render_json(status::Created, &secret)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+fn list_secrets(req: &mut Request) -> IronResult<Response> {
+    let origin_id = match try!(authorized_origin_id(req)) {
+        Ok(origin_id) => origin_id,
+        Err(response) => return Ok(response),
+    };
+    let origin = {
+        let params = req.extensions.get::<Router>().unwrap();
+        params.find("origin").unwrap().to_string()
+    };
+    let project = extract_query_value("project", req);
+    let project_id = match try!(secret_project_id(req, &origin, project.as_ref())) {
+        Ok(project_id) => project_id,
+        Err(response) => return Ok(response),
+    };
+
+    let mut request = OriginSecretListRequest::new();
+    request.set_origin_id(origin_id);
+    request.set_project_id(project_id);
+    match route_message::<OriginSecretListRequest, OriginSecretListResponse>(req, &request) {
+        Ok(list) => {
+            let mut response = render_json(status::Ok, &list.get_secrets());
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+fn delete_secret(req: &mut Request) -> IronResult<Response> {
+    let secret_id = match id_from_params(req) {
+        Some(id) => id,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let origin_id = match try!(authorized_origin_id(req)) {
+        Ok(origin_id) => origin_id,
+        Err(response) => return Ok(response),
+    };
+
+    let mut request = OriginSecretDelete::new();
+    request.set_id(secret_id);
+    request.set_origin_id(origin_id);
+    match route_message::<OriginSecretDelete, NetOk>(req, &request) {
+        Ok(_) => Ok(Response::with(status::NoContent)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+fn download_origin_encryption_key(req: &mut Request) -> IronResult<Response> {
+    let origin = {
+        let params = req.extensions.get::<Router>().unwrap();
+        params.find("origin").unwrap().to_owned()
+    };
+    let mut request = OriginEncryptionKeyGet::new();
+    match try!(get_origin(req, origin)) {
+        Some(origin) => request.set_origin_id(origin.get_id()),
+        None => return Ok(Response::with(status::NotFound)),
+    }
+    match route_message::<OriginEncryptionKeyGet, OriginEncryptionKey>(req, &request) {
+        Ok(ref key) => Ok(render_json(status::Ok, key)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+/// Lists the still encrypted secrets a build of the given project receives. Only build workers,
+/// which hold the origin's encryption key, are able to read them.
+fn list_encrypted_secrets(req: &mut Request) -> IronResult<Response> {
+    let origin = {
+        let params = req.extensions.get::<Router>().unwrap();
+        params.find("origin").unwrap().to_owned()
+    };
+    let origin_id = match try!(get_origin(req, &origin)) {
+        Some(origin) => origin.get_id(),
+        None => return Ok(Response::with(status::NotFound)),
+    };
+    let project = extract_query_value("project", req);
+    let project_id = match try!(secret_project_id(req, &origin, project.as_ref())) {
+        Ok(project_id) => project_id,
+        Err(response) => return Ok(response),
+    };
+
+    let mut request = OriginSecretListRequest::new();
+    request.set_origin_id(origin_id);
+    request.set_project_id(project_id);
+    match route_message::<OriginSecretListRequest, OriginSecretListResponse>(req, &request) {
+        Ok(list) => {
+            let secrets: Vec<EncryptedSecret> = list.get_secrets()
+                .iter()
+                .map(|secret| {
+                         EncryptedSecret {
+                             name: secret.get_name(),
+                             value: secret.get_value(),
+                         }
+                     })
+                .collect();
+            let mut response = render_json(status::Ok, &secrets);
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+/// Hand an origin event to the webhooks registered for it. Delivery happens in the background,
+/// so this never fails the request the event happened in.
+fn notify_webhooks(req: &mut Request,
+                   origin_id: u64,
+                   origin: &str,
+                   event: OriginWebhookEvent,
+                   data: serde_json::Value) {
+    let lock = req.get::<persistent::State<DepotUtil>>()
+        .expect("depot not found");
+    let depot = lock.read().expect("depot read lock is poisoned");
+    depot.webhooks.notify(origin_id, origin, event, data);
+}
+
+/// Routes messages on behalf of a request, so packages pulled through from upstream are
+/// recorded the same way as everything else the request does.
+struct RequestRoute<'r, 'a: 'r, 'b: 'a>(&'r mut Request<'a, 'b>);
+
+impl<'r, 'a, 'b> Route for RequestRoute<'r, 'a, 'b> {
+    fn route<M: Routable, R: protobuf::MessageStatic>(&mut self, msg: &M) -> RouteResult<R> {
+        route_message::<M, R>(self.0, msg)
+    }
+}
+
+/// Pull a package which the depot doesn't have through from upstream, if its origin is
+/// configured for it.
+fn pull_from_upstream(req: &mut Request,
+                      depot: &DepotUtil,
+                      ident: &OriginPackageIdent,
+                      channel: Option<&str>,
+                      target: &PackageTarget)
+                      -> IronResult<Option<OriginPackage>> {
+    if !depot.upstream.pulls_through(ident.get_origin()) {
+        return Ok(None);
+    }
+    match depot.upstream.pull(depot, &mut RequestRoute(req), ident, channel, target) {
+        Ok(package) => Ok(package),
+        Err(e) => {
+            error!("Unable to pull {} through from upstream, err={}", ident, e);
+            Err(IronError::new(e, status::BadGateway))
+        }
+    }
+}
+
+fn render_upstream_package(req: &mut Request,
+                           ident: &OriginPackageIdent,
+                           channel: Option<&str>)
+                           -> IronResult<Response> {
+    let lock = req.get::<persistent::State<DepotUtil>>()
+        .expect("depot not found");
+    let depot = lock.read().expect("depot read lock is poisoned");
+    let target = match target_from_headers(&req.headers.get::<UserAgent>().unwrap()) {
+        Ok(target) => target,
+        Err(response) => return Ok(response),
+    };
+    match try!(pull_from_upstream(req, &depot, ident, channel, &target)) {
+        Some(pkg) => render_package(&pkg, false),
+        None => Ok(Response::with(status::NotFound)),
+    }
+}
+
 fn show_package(req: &mut Request) -> IronResult<Response> {
     let (mut ident, channel) = {
         let params = req.extensions.get::<Router>().unwrap();
@@ -1391,6 +2570,7 @@ fn show_package(req: &mut Request) -> IronResult<Response> {
     };
 
     let qualified = ident.fully_qualified();
+    let requested = ident.clone();
 
     if let Some(channel) = channel {
         if !qualified {
@@ -1406,7 +2586,9 @@ fn show_package(req: &mut Request) -> IronResult<Response> {
                 Ok(id) => ident = id.into(),
                 Err(err) => {
                     match err.get_code() {
-                        ErrCode::ENTITY_NOT_FOUND => return Ok(Response::with((status::NotFound))),
+                        ErrCode::ENTITY_NOT_FOUND => {
+                            return render_upstream_package(req, &requested, Some(&channel))
+                        }
                         _ => {
                             error!("show_package:2, err={:?}", err);
                             return Ok(Response::with(status::InternalServerError));
@@ -1417,13 +2599,15 @@ fn show_package(req: &mut Request) -> IronResult<Response> {
         }
 
         let mut request = OriginChannelPackageGet::new();
-        request.set_name(channel);
+        request.set_name(channel.clone());
         request.set_ident(ident);
         match route_message::<OriginChannelPackageGet, OriginPackage>(req, &request) {
             Ok(pkg) => render_package(&pkg, false),
             Err(err) => {
                 match err.get_code() {
-                    ErrCode::ENTITY_NOT_FOUND => Ok(Response::with((status::NotFound))),
+                    ErrCode::ENTITY_NOT_FOUND => {
+                        render_upstream_package(req, &requested, Some(&channel))
+                    }
                     _ => {
                         error!("show_package:3, err={:?}", err);
                         Ok(Response::with(status::InternalServerError))
@@ -1443,7 +2627,9 @@ fn show_package(req: &mut Request) -> IronResult<Response> {
                 Ok(id) => ident = id.into(),
                 Err(err) => {
                     match err.get_code() {
-                        ErrCode::ENTITY_NOT_FOUND => return Ok(Response::with((status::NotFound))),
+                        ErrCode::ENTITY_NOT_FOUND => {
+                            return render_upstream_package(req, &requested, None)
+                        }
                         _ => {
                             error!("show_package:5, err={:?}", err);
                             return Ok(Response::with(status::InternalServerError));
@@ -1467,7 +2653,7 @@ fn show_package(req: &mut Request) -> IronResult<Response> {
             }
             Err(err) => {
                 match err.get_code() {
-                    ErrCode::ENTITY_NOT_FOUND => Ok(Response::with((status::NotFound))),
+                    ErrCode::ENTITY_NOT_FOUND => render_upstream_package(req, &requested, None),
                     _ => {
                         error!("show_package:6, err={:?}", err);
                         Ok(Response::with(status::InternalServerError))
@@ -1489,13 +2675,12 @@ fn search_packages(req: &mut Request) -> IronResult<Response> {
 
     {
         let params = req.extensions.get::<Router>().unwrap();
-        request.set_query(url::percent_encoding::percent_decode(params
-                                                                    .find("query")
-                                                                    .unwrap()
-                                                                    .as_bytes())
+        if let Some(query) = params.find("query") {
+            request.set_query(url::percent_encoding::percent_decode(query.as_bytes())
                                   .decode_utf8()
                                   .unwrap()
                                   .to_string());
+        }
     };
 
     debug!("search_packages called with: {}", request.get_query());
@@ -1504,32 +2689,75 @@ fn search_packages(req: &mut Request) -> IronResult<Response> {
     // Counter::SearchPackages.increment();
     // Gauge::PackageCount.set(depot.datastore.key_count().unwrap() as f64);
 
-    // TODO MW: constraining to core is temporary until we have a cross origin index
-    request.set_origin("core".to_string());
-
-    // Setting distinct to true makes this query ignore the origin set above, because it's going to
-    // search both the origin name and the package name for the query string provided. This is
-    // likely sub-optimal for performance but it makes things work right now and we should probably
-    // switch to some kind of full-text search engine in the future anyway.
+    // TODO MW: defaulting to core is temporary until we have a cross origin index
+    request.set_origin(extract_query_value("origin", req).unwrap_or("core".to_string()));
+    if let Some(channel) = extract_query_value("channel", req) {
+        request.set_channel(channel);
+    }
+    if let Some(target) = extract_query_value("target", req) {
+        request.set_target(target);
+    }
+    if let Some(version) = extract_query_value("min_version", req) {
+        request.set_min_version(version);
+    }
+    if let Some(version) = extract_query_value("max_version", req) {
+        request.set_max_version(version);
+    }
+    if let Some(file) = extract_query_value("file", req) {
+        request.set_file(file);
+    }
+    let mut exposes = Vec::new();
+    for port in extract_query_list("exposes", req) {
+        match port.parse::<u16>() {
+            Ok(port) => exposes.push(port as u32),
+            Err(_) => return Ok(Response::with(status::BadRequest)),
+        }
+    }
+    request.set_exposes(exposes);
+    let binds = extract_query_list("binds", req);
+    request.set_binds(protobuf::RepeatedField::from_vec(binds));
+    let binaries = extract_query_list("binaries", req);
+    request.set_binaries(protobuf::RepeatedField::from_vec(binaries));
+
+    // Setting distinct to true makes this query ignore the origin, because it's going to search
+    // both the origin name and the package name for the query string provided. This is likely
+    // sub-optimal for performance but it makes things work right now and we should probably
+    // switch to some kind of full-text search engine in the future anyway. None of the filters
+    // above apply to it, so they are refused rather than silently ignored.
     // Also, to get this behavior, you need to ensure that "distinct" is a URL parameter in your
     // request, e.g. blah?distinct=true
     if extract_query_value("distinct", req).is_some() {
+        if request.has_channel() || request.has_target() || request.has_min_version() ||
+           request.has_max_version() || request.has_file() ||
+           !request.get_exposes().is_empty() || !request.get_binds().is_empty() ||
+           !request.get_binaries().is_empty() {
+            return Ok(Response::with((status::BadRequest,
+                                      "Filters can't be combined with a distinct search")));
+        }
         request.set_distinct(true);
     }
 
-    match route_message::<OriginPackageSearchRequest, OriginPackageListResponse>(req, &request) {
+    match route_message::<OriginPackageSearchRequest, OriginPackageSearchResponse>(req, &request) {
         Ok(packages) => {
             debug!("search_packages start: {}, stop: {}, total count: {}",
                    packages.get_start(),
                    packages.get_stop(),
                    packages.get_count());
-            let body = package_results_json(&packages.get_idents().to_vec(),
-                                            packages.get_count() as isize,
-                                            packages.get_start() as isize,
-                                            packages.get_stop() as isize);
+            let more = packages.get_count() as isize > (packages.get_stop() as isize + 1);
+            let results = SearchResults {
+                range_start: packages.get_start() as isize,
+                range_end: packages.get_stop() as isize,
+                total_count: packages.get_count() as isize,
+                next_range: if more {
+                    Some(packages.get_stop() as isize + 1)
+                } else {
+                    None
+                },
+                package_list: packages.get_packages(),
+            };
+            let body = serde_json::to_string(&results).unwrap();
 
-            let mut response = if packages.get_count() as isize >
-                                  (packages.get_stop() as isize + 1) {
+            let mut response = if more {
                 Response::with((status::PartialContent, body))
             } else {
                 Response::with((status::Ok, body))
@@ -1627,7 +2855,17 @@ fn promote_package(req: &mut Request) -> IronResult<Response> {
                     promote.set_package_id(package.get_id());
                     promote.set_ident(ident);
                     match route_message::<OriginPackagePromote, NetOk>(req, &promote) {
-                        Ok(_) => Ok(Response::with(status::Ok)),
+                        Ok(_) => {
+                            notify_webhooks(req,
+                                            origin_channel.get_origin_id(),
+                                            promote.get_ident().get_origin(),
+                                            OriginWebhookEvent::Promote,
+                                            json!({
+                                                "ident": promote.get_ident().to_string(),
+                                                "channel": origin_channel.get_name(),
+                                            }));
+                            Ok(Response::with(status::Ok))
+                        }
                         Err(err) => {
                             error!("Error promoting package, {}", err);
                             Ok(render_net_error(&err))
@@ -1657,6 +2895,212 @@ fn promote_package(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+fn demote_package(req: &mut Request) -> IronResult<Response> {
+    let (channel, ident, session_id, session_name) = {
+        let session = req.extensions.get::<Authenticated>().unwrap();
+        let session_id = session.get_id();
+        let session_name = session.get_name().to_string();
+
+        let params = req.extensions.get::<Router>().unwrap();
+        let channel = match params.find("channel") {
+            Some(c) => c.to_string(),
+            _ => return Ok(Response::with(status::BadRequest)),
+        };
+
+        (channel, ident_from_params(params), session_id, session_name)
+    };
+
+    // every package lives in unstable, it can only be yanked
+    if channel == "unstable" {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let reason = match req.get::<bodyparser::Struct<PackageReasonReq>>() {
+        Ok(Some(body)) => body.reason.unwrap_or_default(),
+        Ok(None) => String::new(),
+        Err(_) => return Ok(Response::with(status::UnprocessableEntity)),
+    };
+
+    let mut channel_req = OriginChannelGet::new();
+    channel_req.set_origin_name(ident.get_origin().to_string());
+    channel_req.set_name(channel.clone());
+    let origin_channel = match route_message::<OriginChannelGet, OriginChannel>(req,
+                                                                                 &channel_req) {
+        Ok(origin_channel) => origin_channel,
+        Err(err) => {
+            match err.get_code() {
+                ErrCode::ENTITY_NOT_FOUND => return Ok(Response::with((status::NotFound))),
+                _ => {
+                    error!("demote_package:1, err={:?}", err);
+                    return Ok(Response::with(status::InternalServerError));
+                }
+            }
+        }
+    };
+
+    if !try!(check_origin_access(req, session_id, &ident.get_origin())) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut request = OriginChannelPackageGet::new();
+    request.set_name(channel);
+    request.set_ident(ident.clone());
+    match route_message::<OriginChannelPackageGet, OriginPackage>(req, &request) {
+        Ok(package) => {
+            let mut demote = OriginPackageDemote::new();
+            demote.set_channel_id(origin_channel.get_id());
+            demote.set_package_id(package.get_id());
+            demote.set_ident(ident);
+            demote.set_requestor_id(session_id);
+            demote.set_requestor_name(session_name);
+            demote.set_reason(reason);
+            match route_message::<OriginPackageDemote, NetOk>(req, &demote) {
+                Ok(_) => {
+                    notify_webhooks(req,
+                                    origin_channel.get_origin_id(),
+                                    demote.get_ident().get_origin(),
+                                    OriginWebhookEvent::Demote,
+                                    json!({
+                                        "ident": demote.get_ident().to_string(),
+                                        "channel": origin_channel.get_name(),
+                                        "reason": demote.get_reason(),
+                                    }));
+                    Ok(Response::with(status::Ok))
+                }
+                Err(err) => {
+                    error!("Error demoting package, {}", err);
+                    Ok(render_net_error(&err))
+                }
+            }
+        }
+        Err(err) => {
+            match err.get_code() {
+                ErrCode::ENTITY_NOT_FOUND => Ok(Response::with((status::NotFound))),
+                _ => {
+                    error!("demote_package:2, err={:?}", err);
+                    Ok(Response::with(status::InternalServerError))
+                }
+            }
+        }
+    }
+}
+
+/// Packages are yanked rather than deleted. A yanked package is left out of `latest` resolution
+/// but its artifact and audit trail stay, so anything pinned to the exact ident keeps working.
+fn yank_package(req: &mut Request) -> IronResult<Response> {
+    let (ident, session_id, session_name) = {
+        let session = req.extensions.get::<Authenticated>().unwrap();
+        let session_id = session.get_id();
+        let session_name = session.get_name().to_string();
+
+        let params = req.extensions.get::<Router>().unwrap();
+        (ident_from_params(params), session_id, session_name)
+    };
+
+    // A yanked package stays around for anyone pinned to it, so say why it was pulled
+    let reason = match req.get::<bodyparser::Struct<PackageReasonReq>>() {
+        Ok(Some(PackageReasonReq { reason: Some(ref reason) })) if !reason.trim().is_empty() => {
+            reason.trim().to_string()
+        }
+        _ => return Ok(Response::with(status::UnprocessableEntity)),
+    };
+
+    if !try!(check_origin_access(req, session_id, &ident.get_origin())) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut request = OriginPackageGet::new();
+    request.set_ident(ident.clone());
+    match route_message::<OriginPackageGet, OriginPackage>(req, &request) {
+        Ok(package) => {
+            let mut yank = OriginPackageYank::new();
+            yank.set_package_id(package.get_id());
+            yank.set_ident(ident);
+            yank.set_requestor_id(session_id);
+            yank.set_requestor_name(session_name);
+            yank.set_reason(reason);
+            match route_message::<OriginPackageYank, NetOk>(req, &yank) {
+                Ok(_) => Ok(Response::with(status::Ok)),
+                Err(err) => {
+                    error!("Error yanking package, {}", err);
+                    Ok(render_net_error(&err))
+                }
+            }
+        }
+        Err(err) => {
+            match err.get_code() {
+                ErrCode::ENTITY_NOT_FOUND => Ok(Response::with((status::NotFound))),
+                _ => {
+                    error!("yank_package:1, err={:?}", err);
+                    Ok(Response::with(status::InternalServerError))
+                }
+            }
+        }
+    }
+}
+
+fn package_audit(req: &mut Request) -> IronResult<Response> {
+    let ident = {
+        let params = req.extensions.get::<Router>().unwrap();
+        ident_from_params(params)
+    };
+
+    let mut request = OriginPackageAuditListRequest::new();
+    request.set_ident(ident);
+    match route_message::<OriginPackageAuditListRequest,
+                          OriginPackageAuditListResponse>(req, &request) {
+        Ok(audit) => {
+            let mut response = render_json(status::Ok, &audit);
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(err) => {
+            error!("package_audit:1, err={:?}", err);
+            Ok(render_net_error(&err))
+        }
+    }
+}
+
+fn package_download_stats(req: &mut Request) -> IronResult<Response> {
+    let ident = {
+        let params = req.extensions.get::<Router>().unwrap();
+        ident_from_params(params)
+    };
+
+    let mut request = OriginPackageDownloadStatsGet::new();
+    request.set_ident(ident);
+    if let Some(target) = extract_query_value("target", req) {
+        request.set_target(target);
+    }
+    if let Some(from) = extract_query_value("from", req) {
+        if !depot_client::is_valid_day(&from) {
+            return Ok(Response::with(status::BadRequest));
+        }
+        request.set_from_day(from);
+    }
+    if let Some(to) = extract_query_value("to", req) {
+        if !depot_client::is_valid_day(&to) {
+            return Ok(Response::with(status::BadRequest));
+        }
+        request.set_to_day(to);
+    }
+
+    match route_message::<OriginPackageDownloadStatsGet,
+                          OriginPackageDownloadStats>(req, &request) {
+        Ok(stats) => {
+            let mut response = render_json(status::Ok, &stats);
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(err) => {
+            error!("package_download_stats:1, err={:?}", err);
+            Ok(render_net_error(&err))
+        }
+    }
+}
+
+/// Counts a download towards the package's statistics. Recording happens in the background, so
+/// this never holds up or fails the download.
 fn ident_from_params(params: &Params) -> OriginPackageIdent {
     let mut ident = OriginPackageIdent::new();
     ident.set_origin(params.find("origin").unwrap().to_string());
@@ -1737,6 +3181,21 @@ fn extract_query_value(key: &str, req: &mut Request) -> Option<String> {
     }
 }
 
+/// Returns the comma separated values of a query parameter, e.g. `exposes=80,443`.
+fn extract_query_list(key: &str, req: &mut Request) -> Vec<String> {
+    match extract_query_value(key, req) {
+        Some(value) => {
+            value
+                .split(',')
+                .map(|v| v.trim())
+                .filter(|v| !v.is_empty())
+                .map(|v| v.to_string())
+                .collect()
+        }
+        None => Vec::new(),
+    }
+}
+
 fn do_cache_response(response: &mut Response) {
     response
         .headers
@@ -1767,25 +3226,48 @@ pub fn routes<M>(insecure: bool, basic: M, worker: M) -> Router
             "/channels/:origin/:channel/pkgs/:pkg/:version/:release/promote" => {
             XHandler::new(promote_package).before(basic.clone())
         },
+        channel_package_demote: put
+            "/channels/:origin/:channel/pkgs/:pkg/:version/:release/demote" => {
+            XHandler::new(demote_package).before(basic.clone())
+        },
         channel_create: post "/channels/:origin/:channel" => {
             XHandler::new(create_channel).before(basic.clone())
         },
         channel_delete: delete "/channels/:origin/:channel" => {
             XHandler::new(delete_channel).before(basic.clone())
         },
+        package_search_all: get "/pkgs/search" => search_packages,
         package_search: get "/pkgs/search/:query" => search_packages,
         packages: get "/pkgs/:origin" => list_packages,
         packages_unique: get "/:origin/pkgs" => list_unique_packages,
         packages_pkg: get "/pkgs/:origin/:pkg" => list_packages,
         package_pkg_versions: get "/pkgs/:origin/:pkg/versions" => list_package_versions,
+        package_pkg_stats: get "/pkgs/:origin/:pkg/stats" => package_download_stats,
         package_pkg_latest: get "/pkgs/:origin/:pkg/latest" => show_package,
         packages_version: get "/pkgs/:origin/:pkg/:version" => list_packages,
         package_version_latest: get "/pkgs/:origin/:pkg/:version/latest" => show_package,
+        package_version_stats: get "/pkgs/:origin/:pkg/:version/stats" => {
+            package_download_stats
+        },
         package: get "/pkgs/:origin/:pkg/:version/:release" => show_package,
 
         package_download: get "/pkgs/:origin/:pkg/:version/:release/download" => {
             download_package
         },
+        package_audit: get "/pkgs/:origin/:pkg/:version/:release/audit" => package_audit,
+        package_download_stats: get "/pkgs/:origin/:pkg/:version/:release/stats" => {
+            package_download_stats
+        },
+        package_yank: put "/pkgs/:origin/:pkg/:version/:release/yank" => {
+            XHandler::new(yank_package).before(basic.clone())
+        },
+        package_bundle: post "/pkgs/bundle" => {
+            if insecure {
+                XHandler::new(import_bundle)
+            } else {
+                XHandler::new(import_bundle).before(basic.clone())
+            }
+        },
         package_upload: post "/pkgs/:origin/:pkg/:version/:release" => {
             if insecure {
                 XHandler::new(upload_package)
@@ -1802,11 +3284,22 @@ pub fn routes<M>(insecure: bool, basic: M, worker: M) -> Router
             }
         },
         schedule_get: get "/pkgs/schedule/:groupid" => get_schedule,
+        schedule_graph: get "/pkgs/schedule/:groupid/graph" => get_schedule_graph,
+        schedule_retry: post "/pkgs/schedule/:groupid/retry" => {
+            if insecure {
+                XHandler::new(retry_schedule)
+            } else {
+                XHandler::new(retry_schedule).before(basic.clone())
+            }
+        },
 
         origin_create: post "/origins" => {
             XHandler::new(origin_create).before(basic.clone())
         },
         origin: get "/origins/:origin" => origin_show,
+        origin_update: put "/origins/:origin" => {
+            XHandler::new(origin_update).before(basic.clone())
+        },
 
         origin_keys: get "/origins/:origin/keys" => list_origin_keys,
         origin_key_latest: get "/origins/:origin/keys/latest" => download_latest_origin_key,
@@ -1843,6 +3336,41 @@ pub fn routes<M>(insecure: bool, basic: M, worker: M) -> Router
         },
         origin_users: get "/origins/:origin/users" => {
             XHandler::new(list_origin_members).before(basic.clone())
+        },
+        origin_webhooks: get "/origins/:origin/webhooks" => {
+            XHandler::new(list_webhooks).before(basic.clone())
+        },
+        origin_webhook_create: post "/origins/:origin/webhooks" => {
+            XHandler::new(create_webhook).before(basic.clone())
+        },
+        origin_webhook_delete: delete "/origins/:origin/webhooks/:id" => {
+            XHandler::new(delete_webhook).before(basic.clone())
+        },
+        origin_webhook_deliveries: get "/origins/:origin/webhooks/:id/deliveries" => {
+            XHandler::new(list_webhook_deliveries).before(basic.clone())
+        },
+        origin_secrets: get "/origins/:origin/secrets" => {
+            XHandler::new(list_secrets).before(basic.clone())
+        },
+        origin_secret_create: post "/origins/:origin/secrets" => {
+            XHandler::new(create_secret).before(basic.clone())
+        },
+        origin_secret_delete: delete "/origins/:origin/secrets/:id" => {
+            XHandler::new(delete_secret).before(basic.clone())
+        },
+        origin_secrets_encrypted: get "/origins/:origin/secrets/encrypted" => {
+            if insecure {
+                XHandler::new(list_encrypted_secrets)
+            } else {
+                XHandler::new(list_encrypted_secrets).before(worker.clone())
+            }
+        },
+        origin_encryption_key: get "/origins/:origin/encryption_key" => {
+            if insecure {
+                XHandler::new(download_origin_encryption_key)
+            } else {
+                XHandler::new(download_origin_encryption_key).before(worker.clone())
+            }
         }
     )
 }
@@ -1861,9 +3389,14 @@ pub fn router(depot: DepotUtil) -> Result<Chain> {
 }
 
 pub fn run(config: Config) -> Result<()> {
-    let depot = DepotUtil::new(config.clone());
+    let mut depot = DepotUtil::new(config.clone());
+    let (webhooks, _) = webhooks::start_dispatcher(config.webhooks.clone());
+    depot.webhooks = webhooks;
+    let (downloads, _) = downloads::start_recorder();
+    depot.downloads = downloads;
     let v1 = try!(router(depot));
     let broker = Broker::run(DepotUtil::net_ident(), &config.route_addrs().clone());
+    upstream::start_mirror(config.clone());
 
     let mut mount = Mount::new();
     mount.mount("/v1", v1);
@@ -1898,6 +3431,7 @@ mod test {
 
     use hab_core::crypto::hash;
     use protocol::net::{self, ErrCode};
+    use protocol::scheduler::{GroupState, Project};
     use protocol::sessionsrv::Session;
 
     use std::env;
@@ -1924,6 +3458,26 @@ mod test {
             .join(name)
     }
 
+    /// Copies of the hart fixtures signed with a key whose public half is checked in, for the
+    /// tests which upload artifacts and so need their signatures to verify
+    pub fn signed_hart_file(name: &str) -> PathBuf {
+        hart_file("signed").join(name)
+    }
+
+    /// The public key the signed hart fixtures are signed with
+    fn fixture_public_key() -> OriginPublicKey {
+        let mut body = Vec::new();
+        File::open(signed_hart_file("core-20170523165914.pub"))
+            .unwrap()
+            .read_to_end(&mut body)
+            .unwrap();
+        let mut key = OriginPublicKey::new();
+        key.set_name("core".to_string());
+        key.set_revision("20170523165914".to_string());
+        key.set_body(body);
+        key
+    }
+
     fn iron_request(method: method::Method,
                     path: &str,
                     body: &mut Vec<u8>,
@@ -2049,10 +3603,11 @@ mod test {
         broker.setup::<OriginGet, Origin>(&origin_res);
 
         broker.setup::<OriginPackageCreate, OriginPackage>(&OriginPackage::new());
+        broker.setup::<OriginPublicKeyGet, OriginPublicKey>(&fixture_public_key());
 
         //inject hart fixture to upload
         let mut body: Vec<u8> = Vec::new();
-        let path = hart_file("core-cacerts-2017.01.17-20170209064044-x86_64-windows.hart");
+        let path = signed_hart_file("core-cacerts-2017.01.17-20170209064044-x86_64-windows.hart");
         File::open(&path)
             .unwrap()
             .read_to_end(&mut body)
@@ -2085,6 +3640,36 @@ mod test {
         assert_eq!(package_req.get_target().to_string(), target.to_string());
     }
 
+    #[test]
+    fn upload_package_signed_with_unknown_key() {
+        let mut broker: TestableBroker = Default::default();
+        let mut access_res = CheckOriginAccessResponse::new();
+        access_res.set_has_access(true);
+        broker.setup::<CheckOriginAccessRequest, CheckOriginAccessResponse>(&access_res);
+        broker.setup_error::<OriginPackageGet>(net::err(ErrCode::ENTITY_NOT_FOUND, ""));
+        broker.setup_error::<OriginPublicKeyGet>(net::err(ErrCode::ENTITY_NOT_FOUND, ""));
+
+        let mut body: Vec<u8> = Vec::new();
+        let path = signed_hart_file("core-cacerts-2017.01.17-20170209064044-x86_64-windows.hart");
+        File::open(&path)
+            .unwrap()
+            .read_to_end(&mut body)
+            .unwrap();
+        let checksum = hash::hash_file(&path).unwrap();
+
+        let (resp, msgs) = iron_request(method::Post,
+                                    format!("http://localhost/pkgs/core/cacerts/2017.01.17/20170209064044?checksum={}", checksum).as_str(),
+                                    &mut body,
+                                    Headers::new(),
+                                    broker);
+
+        let response = resp.unwrap();
+        assert_eq!(response.status, Some(status::UnprocessableEntity));
+        let result_body = response::extract_body_to_string(response);
+        assert!(result_body.contains("Artifact is signed with unknown key core-20170523165914"));
+        assert!(msgs.get::<OriginPackageCreate>().is_err());
+    }
+
     #[test]
     fn download_package() {
         //upload hart so it gets saved to disk
@@ -2094,9 +3679,10 @@ mod test {
         upload_broker.setup::<CheckOriginAccessRequest, CheckOriginAccessResponse>(&access_res);
         upload_broker.setup_error::<OriginPackageGet>(net::err(ErrCode::ENTITY_NOT_FOUND, ""));
         upload_broker.setup::<OriginPackageCreate, OriginPackage>(&OriginPackage::new());
+        upload_broker.setup::<OriginPublicKeyGet, OriginPublicKey>(&fixture_public_key());
 
         let mut body: Vec<u8> = Vec::new();
-        let path = hart_file("core-cacerts-2017.01.17-20170209064045-x86_64-windows.hart");
+        let path = signed_hart_file("core-cacerts-2017.01.17-20170209064045-x86_64-windows.hart");
         File::open(&path)
             .unwrap()
             .read_to_end(&mut body)
@@ -2119,6 +3705,8 @@ mod test {
         ident.set_version("2017.01.17".to_string());
         ident.set_release("20170209064045".to_string());
         package.set_ident(ident);
+        package.set_checksum(checksum.clone());
+        package.set_id(7);
         download_broker.setup::<OriginPackageGet, OriginPackage>(&package);
 
         //set the user agent to look like a windows download
@@ -2126,11 +3714,13 @@ mod test {
         headers.set(UserAgent("hab/0.20.0-dev/20170326090935 (x86_64-windows; 10.0.14915)"
                                   .to_string()));
 
-        let (response, _) = iron_request(method::Get,
-                                         "http://localhost/pkgs/core/cacerts/2017.01.17/20170209064045/download",
-                                         &mut Vec::new(),
-                                         headers,
-                                         download_broker);
+        let (response, msgs) = iron_request(method::Get,
+                                            "http://localhost/pkgs/core/cacerts/2017.01.17/20170209064045/download",
+                                            &mut Vec::new(),
+                                            headers.clone(),
+                                            download_broker);
+        //downloads are recorded in the background, not on the request path
+        assert!(msgs.get::<OriginPackageDownloadRecord>().is_err());
 
         //assert headers
         let response = response.unwrap();
@@ -2144,10 +3734,64 @@ mod test {
             )],
         };
         assert_eq!(response.headers.get::<ContentDisposition>(), Some(&disp));
+        assert_eq!(response.headers.get::<ETag>(), Some(&ETag(checksum.clone())));
+        assert_eq!(response.headers.get::<AcceptRanges>(),
+                   Some(&AcceptRanges(vec![RangeUnit::Bytes])));
 
         //assert file content
         let result_body = response::extract_body_to_bytes(response);
         assert_eq!(result_body, body);
+
+        //resume from an offset with a matching validator
+        let mut range_broker: TestableBroker = Default::default();
+        range_broker.setup::<OriginPackageGet, OriginPackage>(&package);
+        let mut range_headers = headers.clone();
+        range_headers.set(Range::Bytes(vec![ByteRangeSpec::AllFrom(100)]));
+        range_headers.set(IfRange(checksum.clone()));
+        let (response, msgs) = iron_request(method::Get,
+                                            "http://localhost/pkgs/core/cacerts/2017.01.17/20170209064045/download",
+                                            &mut Vec::new(),
+                                            range_headers,
+                                            range_broker);
+        //resuming doesn't count as another download
+        assert!(msgs.get::<OriginPackageDownloadRecord>().is_err());
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::PartialContent));
+        assert_eq!(response.headers.get::<ContentRange>(),
+                   Some(&ContentRange(ContentRangeSpec::Bytes {
+                                          range: Some((100, body.len() as u64 - 1)),
+                                          instance_length: Some(body.len() as u64),
+                                      })));
+        let result_body = response::extract_body_to_bytes(response);
+        assert_eq!(result_body, &body[100..]);
+
+        //a stale validator gets the whole artifact
+        let mut stale_broker: TestableBroker = Default::default();
+        stale_broker.setup::<OriginPackageGet, OriginPackage>(&package);
+        let mut stale_headers = headers.clone();
+        stale_headers.set(Range::Bytes(vec![ByteRangeSpec::AllFrom(100)]));
+        stale_headers.set(IfRange("not-the-checksum".to_string()));
+        let (response, _) = iron_request(method::Get,
+                                         "http://localhost/pkgs/core/cacerts/2017.01.17/20170209064045/download",
+                                         &mut Vec::new(),
+                                         stale_headers,
+                                         stale_broker);
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::Ok));
+        let result_body = response::extract_body_to_bytes(response);
+        assert_eq!(result_body, body);
+
+        //a range past the end of the artifact cannot be satisfied
+        let mut past_broker: TestableBroker = Default::default();
+        past_broker.setup::<OriginPackageGet, OriginPackage>(&package);
+        let mut past_headers = headers.clone();
+        past_headers.set(Range::Bytes(vec![ByteRangeSpec::AllFrom(body.len() as u64)]));
+        let (response, _) = iron_request(method::Get,
+                                         "http://localhost/pkgs/core/cacerts/2017.01.17/20170209064045/download",
+                                         &mut Vec::new(),
+                                         past_headers,
+                                         past_broker);
+        assert_eq!(response.unwrap().status, Some(status::RangeNotSatisfiable));
     }
 
     #[test]
@@ -2695,7 +4339,7 @@ mod test {
     fn search_packages() {
         let mut broker: TestableBroker = Default::default();
 
-        let mut pkg_res = OriginPackageListResponse::new();
+        let mut pkg_res = OriginPackageSearchResponse::new();
         pkg_res.set_start(0);
         pkg_res.set_stop(1);
         pkg_res.set_count(2);
@@ -2706,17 +4350,27 @@ mod test {
         ident1.set_name("name1".to_string());
         ident1.set_version("1.1.1".to_string());
         ident1.set_release("20170101010101".to_string());
-        packages.push(ident1);
+        let mut result1 = OriginPackageSearchResult::new();
+        result1.set_ident(ident1);
+        result1.set_target("x86_64-linux".to_string());
+        result1.set_exposes(vec![80]);
+        result1.set_relevance(100);
+        packages.push(result1);
 
         let mut ident2 = OriginPackageIdent::new();
         ident2.set_origin("org".to_string());
         ident2.set_name("name2".to_string());
         ident2.set_version("2.2.2".to_string());
         ident2.set_release("20170202020202".to_string());
-        packages.push(ident2);
+        let mut result2 = OriginPackageSearchResult::new();
+        result2.set_ident(ident2);
+        result2.set_target("x86_64-linux".to_string());
+        result2.set_binds(protobuf::RepeatedField::from_vec(vec!["name1".to_string()]));
+        result2.set_relevance(75);
+        packages.push(result2);
 
-        pkg_res.set_idents(packages);
-        broker.setup::<OriginPackageSearchRequest, OriginPackageListResponse>(&pkg_res);
+        pkg_res.set_packages(packages);
+        broker.setup::<OriginPackageSearchRequest, OriginPackageSearchResponse>(&pkg_res);
 
         let (response, msgs) = iron_request(method::Get,
                                             "http://localhost/pkgs/search/org%2Fname?range=2",
@@ -2739,13 +4393,25 @@ mod test {
                     \"origin\":\"org\",\
                     \"name\":\"name1\",\
                     \"version\":\"1.1.1\",\
-                    \"release\":\"20170101010101\"\
+                    \"release\":\"20170101010101\",\
+                    \"target\":\"x86_64-linux\",\
+                    \"exposes\":[80],\
+                    \"binds\":[],\
+                    \"binaries\":[],\
+                    \"files\":[],\
+                    \"relevance\":100\
                 },\
                 {\
                     \"origin\":\"org\",\
                     \"name\":\"name2\",\
                     \"version\":\"2.2.2\",\
-                    \"release\":\"20170202020202\"\
+                    \"release\":\"20170202020202\",\
+                    \"target\":\"x86_64-linux\",\
+                    \"exposes\":[],\
+                    \"binds\":[\"name1\"],\
+                    \"binaries\":[],\
+                    \"files\":[],\
+                    \"relevance\":75\
                 }\
             ]\
         }");
@@ -2755,6 +4421,68 @@ mod test {
         assert_eq!(package_req.get_start(), 2);
         assert_eq!(package_req.get_stop(), 51);
         assert_eq!(package_req.get_query(), "org/name".to_string());
+        assert_eq!(package_req.get_origin(), "core");
+    }
+
+    #[test]
+    fn search_packages_with_filters() {
+        let mut broker: TestableBroker = Default::default();
+
+        let mut pkg_res = OriginPackageSearchResponse::new();
+        pkg_res.set_start(0);
+        pkg_res.set_stop(49);
+        pkg_res.set_count(120);
+        broker.setup::<OriginPackageSearchRequest, OriginPackageSearchResponse>(&pkg_res);
+
+        let (response, msgs) = iron_request(method::Get,
+                                            "http://localhost/pkgs/search?origin=acme&\
+                                             channel=stable&target=x86_64-linux&\
+                                             min_version=1.0&max_version=2.0&exposes=80,443&\
+                                             binds=database&binaries=nginx&file=bin%2Fnginx",
+                                            &mut Vec::new(),
+                                            Headers::new(),
+                                            broker);
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::PartialContent));
+        let result_body = response::extract_body_to_string(response);
+        assert!(result_body.contains("\"next_range\":50"));
+
+        let package_req = msgs.get::<OriginPackageSearchRequest>().unwrap();
+        assert_eq!(package_req.get_query(), "");
+        assert_eq!(package_req.get_origin(), "acme");
+        assert_eq!(package_req.get_channel(), "stable");
+        assert_eq!(package_req.get_target(), "x86_64-linux");
+        assert_eq!(package_req.get_min_version(), "1.0");
+        assert_eq!(package_req.get_max_version(), "2.0");
+        assert_eq!(package_req.get_exposes(), &[80, 443]);
+        assert_eq!(package_req.get_binds(), &["database".to_string()]);
+        assert_eq!(package_req.get_binaries(), &["nginx".to_string()]);
+        assert_eq!(package_req.get_file(), "bin/nginx");
+    }
+
+    #[test]
+    fn search_packages_distinct_with_filters() {
+        let broker: TestableBroker = Default::default();
+        let (response, msgs) = iron_request(method::Get,
+                                            "http://localhost/pkgs/search/nginx?distinct=true&\
+                                             file=bin%2Fnginx",
+                                            &mut Vec::new(),
+                                            Headers::new(),
+                                            broker);
+        assert_eq!(response.unwrap().status, Some(status::BadRequest));
+        assert!(msgs.get::<OriginPackageSearchRequest>().is_err());
+    }
+
+    #[test]
+    fn search_packages_with_bad_port() {
+        let broker: TestableBroker = Default::default();
+        let (response, _) = iron_request(method::Get,
+                                         "http://localhost/pkgs/search/nginx?exposes=http",
+                                         &mut Vec::new(),
+                                         Headers::new(),
+                                         broker);
+        assert_eq!(response.unwrap().status, Some(status::BadRequest));
     }
 
     #[test]
@@ -2888,6 +4616,176 @@ mod test {
         assert_eq!(promote.get_ident().to_string(), ident.to_string());
     }
 
+    #[test]
+    fn demote_package() {
+        let mut broker: TestableBroker = Default::default();
+
+        let mut ident = OriginPackageIdent::new();
+        ident.set_origin("org".to_string());
+        ident.set_name("name".to_string());
+        ident.set_version("1.1.1".to_string());
+        ident.set_release("20170101010101".to_string());
+
+        let mut package = OriginPackage::new();
+        package.set_id(5000);
+        package.set_ident(ident.clone());
+        broker.setup::<OriginChannelPackageGet, OriginPackage>(&package);
+
+        let mut channel = OriginChannel::new();
+        channel.set_id(6000);
+        channel.set_name("stable".to_string());
+        broker.setup::<OriginChannelGet, OriginChannel>(&channel);
+
+        let mut access_res = CheckOriginAccessResponse::new();
+        access_res.set_has_access(true);
+        broker.setup::<CheckOriginAccessRequest, CheckOriginAccessResponse>(&access_res);
+
+        broker.setup::<OriginPackageDemote, NetOk>(&NetOk::new());
+
+        let mut headers = Headers::new();
+        headers.set(ContentType::json());
+        let (response, msgs) = iron_request(method::Put,
+                                            "http://localhost/channels/org/stable/pkgs/name/1.1.1/20170101010101/demote",
+                                            &mut r#"{"reason": "broken release"}"#
+                                                     .as_bytes()
+                                                     .to_vec(),
+                                            headers,
+                                            broker);
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::Ok));
+
+        let package_get = msgs.get::<OriginChannelPackageGet>().unwrap();
+        assert_eq!(package_get.get_name(), "stable");
+        assert_eq!(package_get.get_ident().to_string(), ident.to_string());
+
+        let demote = msgs.get::<OriginPackageDemote>().unwrap();
+        assert_eq!(demote.get_channel_id(), 6000);
+        assert_eq!(demote.get_package_id(), 5000);
+        assert_eq!(demote.get_ident().to_string(), ident.to_string());
+        assert_eq!(demote.get_reason(), "broken release");
+    }
+
+    #[test]
+    fn demote_package_from_unstable() {
+        let broker: TestableBroker = Default::default();
+
+        let (response, _) = iron_request(method::Put,
+                                         "http://localhost/channels/org/unstable/pkgs/name/1.1.1/20170101010101/demote",
+                                         &mut Vec::new(),
+                                         Headers::new(),
+                                         broker);
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::Forbidden));
+    }
+
+    #[test]
+    fn yank_package() {
+        let mut broker: TestableBroker = Default::default();
+
+        let mut ident = OriginPackageIdent::new();
+        ident.set_origin("org".to_string());
+        ident.set_name("name".to_string());
+        ident.set_version("1.1.1".to_string());
+        ident.set_release("20170101010101".to_string());
+
+        let mut package = OriginPackage::new();
+        package.set_id(5000);
+        package.set_ident(ident.clone());
+        broker.setup::<OriginPackageGet, OriginPackage>(&package);
+
+        let mut access_res = CheckOriginAccessResponse::new();
+        access_res.set_has_access(true);
+        broker.setup::<CheckOriginAccessRequest, CheckOriginAccessResponse>(&access_res);
+
+        broker.setup::<OriginPackageYank, NetOk>(&NetOk::new());
+
+        let mut headers = Headers::new();
+        headers.set(ContentType::json());
+        let (response, msgs) = iron_request(method::Put,
+                                            "http://localhost/pkgs/org/name/1.1.1/20170101010101/yank",
+                                            &mut r#"{"reason": "CVE-2017-0001"}"#
+                                                     .as_bytes()
+                                                     .to_vec(),
+                                            headers,
+                                            broker);
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::Ok));
+
+        let yank = msgs.get::<OriginPackageYank>().unwrap();
+        assert_eq!(yank.get_package_id(), 5000);
+        assert_eq!(yank.get_ident().to_string(), ident.to_string());
+        assert_eq!(yank.get_reason(), "CVE-2017-0001");
+    }
+
+    #[test]
+    fn yank_package_without_reason() {
+        let broker: TestableBroker = Default::default();
+
+        let (response, msgs) = iron_request(method::Put,
+                                            "http://localhost/pkgs/org/name/1.1.1/20170101010101/yank",
+                                            &mut Vec::new(),
+                                            Headers::new(),
+                                            broker);
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::UnprocessableEntity));
+        assert!(msgs.get::<OriginPackageYank>().is_err());
+    }
+
+    #[test]
+    fn package_download_stats() {
+        let mut broker: TestableBroker = Default::default();
+        let mut ident = OriginPackageIdent::new();
+        ident.set_origin("core".to_string());
+        ident.set_name("cacerts".to_string());
+        ident.set_version("2017.01.17".to_string());
+        ident.set_release("20170209064045".to_string());
+        let mut count = OriginPackageDownloadCount::new();
+        count.set_ident(ident);
+        count.set_target("x86_64-linux".to_string());
+        count.set_day("2017-06-01".to_string());
+        count.set_count(12);
+        let mut stats = OriginPackageDownloadStats::new();
+        stats.set_total(12);
+        stats.set_downloads(protobuf::RepeatedField::from_vec(vec![count]));
+        broker.setup::<OriginPackageDownloadStatsGet, OriginPackageDownloadStats>(&stats);
+
+        let (response, msgs) = iron_request(method::Get,
+                                            "http://localhost/pkgs/core/cacerts/2017.01.17/stats?from=2017-06-01&to=2017-06-30&target=x86_64-linux",
+                                            &mut Vec::new(),
+                                            Headers::new(),
+                                            broker);
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::Ok));
+        let body = response::extract_body_to_string(response);
+        assert!(body.contains(r#""total":12"#));
+        assert!(body.contains(r#""day":"2017-06-01","count":12"#));
+
+        let request = msgs.get::<OriginPackageDownloadStatsGet>().unwrap();
+        assert_eq!(request.get_ident().to_string(), "core/cacerts/2017.01.17");
+        assert_eq!(request.get_target(), "x86_64-linux");
+        assert_eq!(request.get_from_day(), "2017-06-01");
+        assert_eq!(request.get_to_day(), "2017-06-30");
+    }
+
+    #[test]
+    fn package_download_stats_with_bad_day() {
+        let broker: TestableBroker = Default::default();
+
+        let (response, msgs) = iron_request(method::Get,
+                                            "http://localhost/pkgs/core/cacerts/stats?from=2017-13-01",
+                                            &mut Vec::new(),
+                                            Headers::new(),
+                                            broker);
+
+        assert_eq!(response.unwrap().status, Some(status::BadRequest));
+        assert!(msgs.get::<OriginPackageDownloadStatsGet>().is_err());
+    }
+
     #[test]
     fn channel_delete() {
         let mut broker: TestableBroker = Default::default();
@@ -2916,4 +4814,311 @@ mod test {
         assert_eq!(delete.get_id(), 6000);
         assert_eq!(delete.get_origin_id(), 5000);
     }
+
+    #[test]
+    fn origin_update() {
+        let mut broker: TestableBroker = Default::default();
+        let mut access_res = CheckOriginAccessResponse::new();
+        access_res.set_has_access(true);
+        broker.setup::<CheckOriginAccessRequest, CheckOriginAccessResponse>(&access_res);
+
+        let mut origin_res = Origin::new();
+        origin_res.set_name(String::from("acme"));
+        origin_res.set_id(5000);
+        origin_res.set_rdeps_builds(true);
+        broker.setup::<OriginUpdate, Origin>(&origin_res);
+
+        let mut headers = Headers::new();
+        headers.set(ContentType::json());
+        let (response, msgs) = iron_request(method::Put,
+                                            "http://localhost/origins/acme",
+                                            &mut r#"{"rdeps_builds": true}"#.as_bytes().to_vec(),
+                                            headers,
+                                            broker);
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::Ok));
+        let body = response::extract_body_to_string(response);
+        assert!(body.contains(r#""rdeps_builds":true"#));
+
+        let update = msgs.get::<OriginUpdate>().unwrap();
+        assert_eq!(update.get_name(), "acme");
+        assert!(update.get_rdeps_builds());
+    }
+
+    fn webhook_broker() -> TestableBroker {
+        let mut broker: TestableBroker = Default::default();
+        let mut access_res = CheckOriginAccessResponse::new();
+        access_res.set_has_access(true);
+        broker.setup::<CheckOriginAccessRequest, CheckOriginAccessResponse>(&access_res);
+
+        let mut origin_res = Origin::new();
+        origin_res.set_name(String::from("neurosis"));
+        origin_res.set_id(5000);
+        broker.setup::<OriginGet, Origin>(&origin_res);
+        broker
+    }
+
+    #[test]
+    fn create_webhook() {
+        let mut broker = webhook_broker();
+        let mut webhook = OriginWebhook::new();
+        webhook.set_id(7000);
+        webhook.set_origin_id(5000);
+        webhook.set_url("https://ci.example.com/hooks/habitat".to_string());
+        webhook.set_secret("through-silver-in-blood".to_string());
+        webhook.set_events(vec![OriginWebhookEvent::Upload, OriginWebhookEvent::Promote]);
+        broker.setup::<OriginWebhookCreate, OriginWebhook>(&webhook);
+
+        let mut headers = Headers::new();
+        headers.set(ContentType::json());
+        let (response, msgs) = iron_request(method::Post,
+                                            "http://localhost/origins/neurosis/webhooks",
+                                            &mut r#"{
+                                                "url": "https://ci.example.com/hooks/habitat",
+                                                "secret": "through-silver-in-blood",
+                                                "events": ["upload", "promote"]
+                                            }"#
+                                                     .as_bytes()
+                                                     .to_vec(),
+                                            headers,
+                                            broker);
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::Created));
+        let body = response::extract_body_to_string(response);
+        assert!(body.contains(r#""events":["upload","promote"]"#));
+        assert!(!body.contains("through-silver-in-blood"));
+
+        let create = msgs.get::<OriginWebhookCreate>().unwrap();
+        assert_eq!(create.get_origin_id(), 5000);
+        assert_eq!(create.get_url(), "https://ci.example.com/hooks/habitat");
+        assert_eq!(create.get_secret(), "through-silver-in-blood");
+        assert_eq!(create.get_events(),
+                   &[OriginWebhookEvent::Upload, OriginWebhookEvent::Promote]);
+    }
+
+    #[test]
+    fn create_webhook_with_unknown_event() {
+        let broker = webhook_broker();
+
+        let mut headers = Headers::new();
+        headers.set(ContentType::json());
+        let (response, msgs) = iron_request(method::Post,
+                                            "http://localhost/origins/neurosis/webhooks",
+                                            &mut r#"{
+                                                "url": "https://ci.example.com/hooks/habitat",
+                                                "secret": "through-silver-in-blood",
+                                                "events": ["upload", "rebuild"]
+                                            }"#
+                                                     .as_bytes()
+                                                     .to_vec(),
+                                            headers,
+                                            broker);
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::UnprocessableEntity));
+        assert!(msgs.get::<OriginWebhookCreate>().is_err());
+    }
+
+    #[test]
+    fn create_webhook_with_internal_url() {
+        let broker = webhook_broker();
+
+        let mut headers = Headers::new();
+        headers.set(ContentType::json());
+        let (response, msgs) = iron_request(method::Post,
+                                            "http://localhost/origins/neurosis/webhooks",
+                                            &mut r#"{
+                                                "url": "http://169.254.169.254/latest/meta-data",
+                                                "secret": "through-silver-in-blood",
+                                                "events": ["upload"]
+                                            }"#
+                                                     .as_bytes()
+                                                     .to_vec(),
+                                            headers,
+                                            broker);
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::UnprocessableEntity));
+        assert!(msgs.get::<OriginWebhookCreate>().is_err());
+    }
+
+    #[test]
+    fn list_webhook_deliveries() {
+        let mut broker = webhook_broker();
+        let mut delivery = OriginWebhookDelivery::new();
+        delivery.set_id(8000);
+        delivery.set_webhook_id(7000);
+        delivery.set_event(OriginWebhookEvent::Demote);
+        delivery.set_status_code(503);
+        delivery.set_attempts(5);
+        delivery.set_success(false);
+        let mut list = OriginWebhookDeliveryListResponse::new();
+        list.set_webhook_id(7000);
+        list.mut_deliveries().push(delivery);
+        broker.setup::<OriginWebhookDeliveryListRequest,
+                       OriginWebhookDeliveryListResponse>(&list);
+
+        let (response, msgs) = iron_request(method::Get,
+                                            "http://localhost/origins/neurosis/webhooks/7000/deliveries",
+                                            &mut Vec::new(),
+                                            Headers::new(),
+                                            broker);
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::Ok));
+        let body = response::extract_body_to_string(response);
+        assert!(body.contains(r#""event":"demote""#));
+        assert!(body.contains(r#""status_code":503"#));
+
+        let request = msgs.get::<OriginWebhookDeliveryListRequest>().unwrap();
+        assert_eq!(request.get_webhook_id(), 7000);
+        assert_eq!(request.get_origin_id(), 5000);
+    }
+
+    #[test]
+    fn create_secret() {
+        let mut broker = webhook_broker();
+        broker.setup_error::<OriginEncryptionKeyGet>(net::err(ErrCode::ENTITY_NOT_FOUND, ""));
+        // The origin's key was created by a concurrent request, so it's the one returned
+        let cache = TempDir::new("create-secret").unwrap();
+        let (pair, public_body, secret_body) =
+            generate_origin_encryption_pair("neurosis", cache.path()).unwrap();
+        let mut key = OriginEncryptionKey::new();
+        key.set_name(pair.name.clone());
+        key.set_revision(pair.rev.clone());
+        key.set_public_body(public_body);
+        key.set_secret_body(secret_body);
+        broker.setup::<OriginEncryptionKeyCreate, OriginEncryptionKey>(&key);
+        let mut secret = OriginSecret::new();
+        secret.set_id(9000);
+        secret.set_origin_id(5000);
+        secret.set_name("REGISTRY_TOKEN".to_string());
+        secret.set_value("BOX-1".to_string());
+        broker.setup::<OriginSecretCreate, OriginSecret>(&secret);
+
+        let mut headers = Headers::new();
+        headers.set(ContentType::json());
+        let (response, msgs) = iron_request(method::Post,
+                                            "http://localhost/origins/neurosis/secrets",
+                                            &mut r#"{
+                                                "name": "REGISTRY_TOKEN",
+                                                "value": "through-silver-in-blood"
+                                            }"#
+                                                     .as_bytes()
+                                                     .to_vec(),
+                                            headers,
+                                            broker);
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::Created));
+        let body = response::extract_body_to_string(response);
+        assert!(body.contains(r#""name":"REGISTRY_TOKEN""#));
+        assert!(!body.contains("BOX-1"));
+
+        let key = msgs.get::<OriginEncryptionKeyCreate>().unwrap();
+        assert_eq!(key.get_name(), "neurosis");
+        assert!(key.get_secret_body().starts_with(b"BOX-SEC-1"));
+        let create = msgs.get::<OriginSecretCreate>().unwrap();
+        assert_eq!(create.get_origin_id(), 5000);
+        assert_eq!(create.get_project_id(), 0);
+        assert!(create.get_value().starts_with("BOX-1"));
+        assert_eq!(create.get_value().lines().nth(2),
+                   Some(pair.name_with_rev().as_str()));
+        assert!(!create.get_value().contains("through-silver-in-blood"));
+    }
+
+    #[test]
+    fn create_secret_with_invalid_name() {
+        let broker = webhook_broker();
+
+        let mut headers = Headers::new();
+        headers.set(ContentType::json());
+        let (response, msgs) = iron_request(method::Post,
+                                            "http://localhost/origins/neurosis/secrets",
+                                            &mut r#"{
+                                                "name": "1-REGISTRY-TOKEN",
+                                                "value": "through-silver-in-blood"
+                                            }"#
+                                                     .as_bytes()
+                                                     .to_vec(),
+                                            headers,
+                                            broker);
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::UnprocessableEntity));
+        assert!(msgs.get::<OriginSecretCreate>().is_err());
+    }
+
+    #[test]
+    fn create_secret_with_whitespace() {
+        let broker = webhook_broker();
+
+        let mut headers = Headers::new();
+        headers.set(ContentType::json());
+        let (response, msgs) = iron_request(method::Post,
+                                            "http://localhost/origins/neurosis/secrets",
+                                            &mut r#"{
+                                                "name": "LICENSE_KEY",
+                                                "value": "through silver\nin blood"
+                                            }"#
+                                                     .as_bytes()
+                                                     .to_vec(),
+                                            headers,
+                                            broker);
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::UnprocessableEntity));
+        assert!(msgs.get::<OriginSecretCreate>().is_err());
+    }
+
+    fn retry_broker(has_access: bool) -> TestableBroker {
+        let mut broker: TestableBroker = Default::default();
+        let mut group = Group::new();
+        group.set_id(4000);
+        group.set_state(GroupState::Failed);
+        let mut failed = Project::new();
+        failed.set_name("neurosis/times-of-grace".to_string());
+        failed.set_state(ProjectState::Failure);
+        let mut succeeded = Project::new();
+        succeeded.set_name("core/glibc".to_string());
+        succeeded.set_state(ProjectState::Success);
+        group.set_projects(vec![failed, succeeded].into());
+        broker.setup::<GroupGet, Group>(&group);
+        broker.setup::<GroupRetry, Group>(&group);
+        let mut access_res = CheckOriginAccessResponse::new();
+        access_res.set_has_access(has_access);
+        broker.setup::<CheckOriginAccessRequest, CheckOriginAccessResponse>(&access_res);
+        broker
+    }
+
+    #[test]
+    fn retry_schedule() {
+        let (response, msgs) = iron_request(method::Post,
+                                            "http://localhost/pkgs/schedule/4000/retry",
+                                            &mut Vec::new(),
+                                            Headers::new(),
+                                            retry_broker(true));
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::Ok));
+        let access = msgs.get::<CheckOriginAccessRequest>().unwrap();
+        assert_eq!(access.get_origin_name(), "neurosis");
+        let retry = msgs.get::<GroupRetry>().unwrap();
+        assert_eq!(retry.get_group_id(), 4000);
+    }
+
+    #[test]
+    fn retry_schedule_without_origin_access() {
+        let (response, msgs) = iron_request(method::Post,
+                                            "http://localhost/pkgs/schedule/4000/retry",
+                                            &mut Vec::new(),
+                                            Headers::new(),
+                                            retry_broker(false));
+
+        let response = response.unwrap();
+        assert_eq!(response.status, Some(status::Forbidden));
+        assert!(msgs.get::<GroupRetry>().is_err());
+    }
 }
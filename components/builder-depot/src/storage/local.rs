@@ -0,0 +1,65 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Storage variant which keeps artifacts on the depot node's filesystem, below the `pkgs`
+//! directory of the depot's data path.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use error::Result;
+use super::{ArtifactLocation, ArtifactStorage};
+
+pub struct LocalStorage {
+    root: PathBuf,
+}
+
+impl LocalStorage {
+    pub fn new(root: PathBuf) -> Self {
+        LocalStorage { root: root }
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.root.join(key)
+    }
+}
+
+impl ArtifactStorage for LocalStorage {
+    fn exists(&self, key: &str) -> Result<bool> {
+        Ok(self.path(key).is_file())
+    }
+
+    fn store(&self, key: &str, src: &Path) -> Result<()> {
+        let dst = self.path(key);
+        if let Some(parent) = dst.parent() {
+            try!(fs::create_dir_all(parent));
+        }
+        // The staging directory lives in the same data path, so this is a cheap rename. Fall
+        // back to copying in case the data path spans filesystems.
+        if fs::rename(src, &dst).is_err() {
+            try!(fs::copy(src, &dst));
+            try!(fs::remove_file(src));
+        }
+        Ok(())
+    }
+
+    fn fetch(&self, key: &str) -> Result<Option<ArtifactLocation>> {
+        let path = self.path(key);
+        if path.is_file() {
+            Ok(Some(ArtifactLocation::File(path)))
+        } else {
+            Ok(None)
+        }
+    }
+}
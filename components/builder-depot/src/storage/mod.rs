@@ -0,0 +1,60 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Contract for storage and retrieval of package artifacts.
+//!
+//! Uploaded artifacts are first written to a staging directory on the depot node where they are
+//! checked and verified. Once accepted, they are handed over to the configured storage backend
+//! under a key derived from the package identifier and target, which is the relative path of the
+//! artifact in the local backend.
+
+pub mod local;
+pub mod s3;
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use config::{StorageBackend, StorageCfg};
+use error::Result;
+
+/// Where an artifact can be downloaded from.
+pub enum ArtifactLocation {
+    /// A file on the depot node's filesystem
+    File(PathBuf),
+    /// The contents of an artifact being read from a remote store, along with its length
+    Stream(Box<Read + Send>, u64),
+    /// A URL the client should be redirected to
+    Redirect(String),
+}
+
+pub trait ArtifactStorage: Send + Sync {
+    /// Returns whether an artifact is stored under the given key.
+    fn exists(&self, key: &str) -> Result<bool>;
+
+    /// Places the artifact at `src` into storage under the given key. The file at `src` may be
+    /// moved by the backend.
+    fn store(&self, key: &str, src: &Path) -> Result<()>;
+
+    /// Returns where the artifact stored under the given key can be downloaded from, or `None`
+    /// if the backend knows there is no such artifact.
+    fn fetch(&self, key: &str) -> Result<Option<ArtifactLocation>>;
+}
+
+/// Create the appropriate ArtifactStorage variant based on configuration values.
+pub fn from_config(config: &StorageCfg, packages_path: PathBuf) -> Box<ArtifactStorage> {
+    match config.backend {
+        StorageBackend::Local => Box::new(local::LocalStorage::new(packages_path)),
+        StorageBackend::S3 => Box::new(s3::S3Storage::new(config.clone())),
+    }
+}
@@ -0,0 +1,474 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Pulls packages through from upstream depots and mirrors channels of them.
+//!
+//! When a package of a pull-through origin is requested which the depot doesn't have, the
+//! package metadata, the artifact and the public keys of the artifact's signers are fetched from
+//! the first upstream depot which has it. The artifact is checked against the upstream checksum
+//! and its signatures are verified before it is stored and recorded, just like an upload would
+//! be. Public keys the depot already stores are trusted over the upstream's copies, and the
+//! first signature must be made with a key of the package's origin.
+//!
+//! Configured origin channels are additionally mirrored in full on a fixed interval, which keeps
+//! depots without a route to their upstream at run time up to date.
+
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use depot_client::{Client, DisplayProgress};
+use hab_core::crypto::{artifact, hash, keys, SigKeyPair};
+use hab_core::crypto::artifact::ArtifactHeader;
+use hab_core::package::{FromArchive, PackageTarget};
+use hab_net::routing::{Broker, BrokerConn, RouteResult};
+use protobuf;
+use protocol::Routable;
+use protocol::net::{ErrCode, NetOk};
+use protocol::originsrv::*;
+use uuid::Uuid;
+
+use VERSION;
+use config::{Config, UpstreamCfg};
+use error::{Error, Result};
+use super::DepotUtil;
+
+/// Product name the depot identifies itself with to upstream depots.
+const PRODUCT: &'static str = "hab-depot";
+
+/// Account which owns origins, channels and packages created from upstream.
+const UPSTREAM_OWNER_ID: u64 = 0;
+const UPSTREAM_OWNER_NAME: &'static str = "upstream";
+
+/// Sends messages to the services behind the depot. This is implemented by broker connections
+/// for the mirror, and by requests in the HTTP server so pulling through honours a request's
+/// routing.
+pub trait Route {
+    fn route<M: Routable, R: protobuf::MessageStatic>(&mut self, msg: &M) -> RouteResult<R>;
+}
+
+impl Route for BrokerConn {
+    fn route<M: Routable, R: protobuf::MessageStatic>(&mut self, msg: &M) -> RouteResult<R> {
+        BrokerConn::route::<M, R>(self, msg)
+    }
+}
+
+pub struct Upstream {
+    depots: Vec<(String, Client)>,
+    origins: Vec<String>,
+}
+
+impl Upstream {
+    pub fn new(config: &UpstreamCfg) -> Self {
+        let mut depots = Vec::new();
+        for url in config.urls.iter() {
+            match Client::new(url.as_str(), PRODUCT, VERSION, None) {
+                Ok(client) => depots.push((url.clone(), client)),
+                Err(e) => error!("Unable to create client for upstream depot {}, err={}", url, e),
+            }
+        }
+        Upstream {
+            depots: depots,
+            origins: config.origins.clone(),
+        }
+    }
+
+    /// Returns whether packages of the given origin are pulled through from upstream.
+    pub fn pulls_through(&self, origin: &str) -> bool {
+        !self.depots.is_empty() && self.origins.iter().any(|o| o == origin)
+    }
+
+    /// Pull a package through from the first upstream depot which has it, and return the
+    /// package as recorded locally. When a channel is given, the package is resolved within
+    /// that channel upstream and promoted to the channel of the same name locally. The package
+    /// is requested for the given target, which is the target of the client asking for it.
+    ///
+    /// `None` is returned if no upstream depot has the package.
+    pub fn pull<R: Route>(&self,
+                          depot: &DepotUtil,
+                          router: &mut R,
+                          ident: &OriginPackageIdent,
+                          channel: Option<&str>,
+                          target: &PackageTarget)
+                          -> Result<Option<OriginPackage>> {
+        for &(ref url, _) in self.depots.iter() {
+            let client = Client::new_for_target(url.as_str(), PRODUCT, VERSION, None, target);
+            let client = match client {
+                Ok(client) => client,
+                Err(e) => {
+                    error!("Unable to create client for upstream depot {}, err={}", url, e);
+                    continue;
+                }
+            };
+            let package = match client.show_package(ident, channel) {
+                Ok(package) => package,
+                Err(e) => {
+                    debug!("Package {} not available from {}, err={}", ident, url, e);
+                    continue;
+                }
+            };
+            let package =
+                try!(self.import(depot, router, &client, package, channel, Some(target)));
+            info!("Pulled {} through from {}", package.get_ident(), url);
+            return Ok(Some(package));
+        }
+        Ok(None)
+    }
+
+    /// Mirror all packages of an origin channel from the first upstream depot which has the
+    /// channel, returning the number of packages in the mirrored channel. Packages which fail to
+    /// import are logged and skipped so they're retried on the next run.
+    pub fn mirror<R: Route>(&self,
+                            depot: &DepotUtil,
+                            router: &mut R,
+                            origin: &str,
+                            channel: &str)
+                            -> Result<usize> {
+        for &(ref url, ref client) in self.depots.iter() {
+            let idents = match client.show_channel_packages(origin, channel) {
+                Ok(idents) => idents,
+                Err(e) => {
+                    warn!("Unable to list {}/{} at {}, err={}", origin, channel, url, e);
+                    continue;
+                }
+            };
+            let count = idents.len();
+            for ident in idents {
+                let result = match client.show_package(&ident, Some(channel)) {
+                    Ok(package) => {
+                        self.import(depot, router, client, package, Some(channel), None)
+                    }
+                    Err(e) => Err(Error::from(e)),
+                };
+                if let Err(e) = result {
+                    error!("Unable to mirror {} from {}, err={}", ident, url, e);
+                }
+            }
+            return Ok(count);
+        }
+        Err(Error::Upstream(format!("No upstream depot provides the {} channel of {}",
+                                    channel,
+                                    origin)))
+    }
+
+    fn import<R: Route>(&self,
+                        depot: &DepotUtil,
+                        router: &mut R,
+                        client: &Client,
+                        upstream: OriginPackage,
+                        channel: Option<&str>,
+                        target: Option<&PackageTarget>)
+                        -> Result<OriginPackage> {
+        let mut request = OriginPackageGet::new();
+        request.set_ident(upstream.get_ident().clone());
+        let package = match router.route::<OriginPackageGet, OriginPackage>(&request) {
+            Ok(package) => package,
+            Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => {
+                let dir = depot.staging_path().join(Uuid::new_v4().to_string());
+                try!(fs::create_dir_all(&dir));
+                let result =
+                    self.import_artifact(depot, router, client, &upstream, target, &dir);
+                let _ = fs::remove_dir_all(&dir);
+                try!(result)
+            }
+            Err(err) => return Err(Error::from(err)),
+        };
+        if let Some(channel) = channel {
+            try!(promote(router, &package, channel));
+        }
+        Ok(package)
+    }
+
+    fn import_artifact<R: Route>(&self,
+                                 depot: &DepotUtil,
+                                 router: &mut R,
+                                 client: &Client,
+                                 upstream: &OriginPackage,
+                                 requested: Option<&PackageTarget>,
+                                 dir: &Path)
+                                 -> Result<OriginPackage> {
+        let ident = upstream.get_ident();
+        let mut archive = try!(client.fetch_package(ident, dir, None::<NoProgress>));
+        let checksum = try!(hash::hash_file(&archive.path));
+        if checksum != upstream.get_checksum() {
+            return Err(Error::Upstream(format!("Checksum of {} is {}, expected {}",
+                                               ident,
+                                               checksum,
+                                               upstream.get_checksum())));
+        }
+        let target = try!(archive.target());
+        if let Some(requested) = requested {
+            if target != *requested {
+                return Err(Error::Upstream(format!("Upstream returned {} of {}, requested {}",
+                                                   ident,
+                                                   target,
+                                                   requested)));
+            }
+        }
+        if !depot.config.targets.contains(&target) {
+            return Err(Error::Upstream(format!("Unsupported package platform or \
+                                                architecture {} of {}",
+                                               target,
+                                               ident)));
+        }
+
+        let key_cache = dir.join("keys");
+        try!(fs::create_dir_all(&key_cache));
+        let header = try!(artifact::get_artifact_header(&archive.path));
+        let mut fetched = Vec::new();
+        for (name, revision) in try!(signer_keys(ident, &header, &depot.config.revoked_keys)) {
+            // The upstream's copy of a key is only used if the depot doesn't have the key yet,
+            // so an upstream can't vouch for an artifact with a key of its own making.
+            match try!(stored_key(router, &name, &revision)) {
+                Some(key) => {
+                    try!(SigKeyPair::write_file_from_str(&String::from_utf8_lossy(key.get_body()),
+                                                         &key_cache));
+                }
+                None => {
+                    let path = try!(client.fetch_origin_key(&name,
+                                                            &revision,
+                                                            &key_cache,
+                                                            None::<NoProgress>));
+                    fetched.push((name, revision, path));
+                }
+            }
+        }
+        try!(artifact::verify(&archive.path, &key_cache));
+
+        let origin = try!(ensure_origin(router, ident.get_origin()));
+        for (name, revision, path) in fetched {
+            try!(import_key(router, &name, &revision, &path));
+        }
+
+        let mut package = try!(OriginPackageCreate::from_archive(&mut archive));
+        package.set_owner_id(UPSTREAM_OWNER_ID);
+        package.set_origin_id(origin.get_id());
+        let key = depot.archive_key(ident, &target);
+        if !try!(depot.storage.exists(&key)) {
+            try!(depot.storage.store(&key, &archive.path));
+        }
+        Ok(try!(router.route::<OriginPackageCreate, OriginPackage>(&package)))
+    }
+}
+
+/// Start a thread which mirrors the configured origin channels from upstream on the configured
+/// interval. Returns `None` if there's nothing to mirror.
+pub fn start_mirror(config: Config) -> Option<JoinHandle<()>> {
+    if config.upstream.urls.is_empty() || config.upstream.mirror.is_empty() {
+        return None;
+    }
+    let handle = thread::Builder::new()
+        .name("upstream-mirror".to_string())
+        .spawn(move || {
+            let depot = DepotUtil::new(config);
+            let interval = Duration::from_secs(depot.config.upstream.mirror_interval);
+            loop {
+                match Broker::connect() {
+                    Ok(mut conn) => {
+                        for mirror in depot.config.upstream.mirror.iter() {
+                            match depot.upstream.mirror(&depot,
+                                                        &mut conn,
+                                                        &mirror.origin,
+                                                        &mirror.channel) {
+                                Ok(count) => {
+                                    info!("Mirrored {} packages of {}/{}",
+                                          count,
+                                          mirror.origin,
+                                          mirror.channel)
+                                }
+                                Err(e) => {
+                                    error!("Unable to mirror {}/{}, err={}",
+                                           mirror.origin,
+                                           mirror.channel,
+                                           e)
+                                }
+                            }
+                        }
+                    }
+                    Err(e) => error!("Unable to connect to broker, err={}", e),
+                }
+                thread::sleep(interval);
+            }
+        })
+        .unwrap();
+    Some(handle)
+}
+
+fn ensure_origin<R: Route>(router: &mut R, name: &str) -> Result<Origin> {
+    let mut request = OriginGet::new();
+    request.set_name(name.to_string());
+    match router.route::<OriginGet, Origin>(&request) {
+        Ok(origin) => Ok(origin),
+        Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => {
+            let mut request = OriginCreate::new();
+            request.set_name(name.to_string());
+            request.set_owner_id(UPSTREAM_OWNER_ID);
+            request.set_owner_name(UPSTREAM_OWNER_NAME.to_string());
+            Ok(try!(router.route::<OriginCreate, Origin>(&request)))
+        }
+        Err(err) => Err(Error::from(err)),
+    }
+}
+
+/// Returns the origin key revisions which signed an artifact, refusing revoked keys and artifacts
+/// whose first signature isn't made with a key of the package's origin, like an upload would be.
+fn signer_keys(ident: &OriginPackageIdent,
+               header: &ArtifactHeader,
+               revoked_keys: &[String])
+               -> Result<Vec<(String, String)>> {
+    let mut signers = Vec::with_capacity(header.signatures.len());
+    for (i, signature) in header.signatures.iter().enumerate() {
+        let (name, revision) = try!(keys::parse_name_with_rev(&signature.key_name));
+        if i == 0 && name != ident.get_origin() {
+            return Err(Error::Upstream(format!("{} is signed with {} which is not a key of \
+                                                the {} origin",
+                                               ident,
+                                               signature.key_name,
+                                               ident.get_origin())));
+        }
+        if revoked_keys.contains(&signature.key_name) {
+            return Err(Error::Upstream(format!("{} is signed with revoked key {}",
+                                               ident,
+                                               signature.key_name)));
+        }
+        signers.push((name, revision));
+    }
+    Ok(signers)
+}
+
+/// Returns the public key the depot stores for an origin key revision, if any.
+fn stored_key<R: Route>(router: &mut R,
+                        origin: &str,
+                        revision: &str)
+                        -> Result<Option<OriginPublicKey>> {
+    let mut request = OriginPublicKeyGet::new();
+    request.set_origin(origin.to_string());
+    request.set_revision(revision.to_string());
+    match router.route::<OriginPublicKeyGet, OriginPublicKey>(&request) {
+        Ok(key) => Ok(Some(key)),
+        Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => Ok(None),
+        Err(err) => Err(Error::from(err)),
+    }
+}
+
+fn import_key<R: Route>(router: &mut R, origin: &str, revision: &str, path: &Path) -> Result<()> {
+    if try!(stored_key(router, origin, revision)).is_some() {
+        return Ok(());
+    }
+    let origin = try!(ensure_origin(router, origin));
+    let mut request = OriginPublicKeyCreate::new();
+    request.set_origin_id(origin.get_id());
+    request.set_name(origin.get_name().to_string());
+    request.set_revision(revision.to_string());
+    let mut body = Vec::new();
+    try!(try!(File::open(path)).read_to_end(&mut body));
+    request.set_body(body);
+    request.set_owner_id(UPSTREAM_OWNER_ID);
+    try!(router.route::<OriginPublicKeyCreate, OriginPublicKey>(&request));
+    Ok(())
+}
+
+fn promote<R: Route>(router: &mut R, package: &OriginPackage, channel: &str) -> Result<()> {
+    let mut request = OriginChannelGet::new();
+    request.set_origin_name(package.get_ident().get_origin().to_string());
+    request.set_name(channel.to_string());
+    let origin_channel = match router.route::<OriginChannelGet, OriginChannel>(&request) {
+        Ok(origin_channel) => origin_channel,
+        Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => {
+            let mut request = OriginChannelCreate::new();
+            request.set_origin_id(package.get_origin_id());
+            request.set_origin_name(package.get_ident().get_origin().to_string());
+            request.set_name(channel.to_string());
+            request.set_owner_id(UPSTREAM_OWNER_ID);
+            try!(router.route::<OriginChannelCreate, OriginChannel>(&request))
+        }
+        Err(err) => return Err(Error::from(err)),
+    };
+    let mut request = OriginPackagePromote::new();
+    request.set_channel_id(origin_channel.get_id());
+    request.set_package_id(package.get_id());
+    request.set_ident(package.get_ident().clone());
+    try!(router.route::<OriginPackagePromote, NetOk>(&request));
+    Ok(())
+}
+
+/// Downloads from upstream happen in the background, so there's no progress to display.
+struct NoProgress;
+
+impl Write for NoProgress {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl DisplayProgress for NoProgress {
+    fn size(&mut self, _size: u64) {}
+    fn finish(&mut self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use hab_core::crypto::artifact::ArtifactSignature;
+
+    use config::UpstreamCfg;
+    use super::*;
+
+    #[test]
+    fn pulls_through_configured_origins() {
+        let mut config = UpstreamCfg::default();
+        config.urls = vec!["http://depot.example.com/v1/depot".to_string()];
+        let upstream = Upstream::new(&config);
+        assert!(upstream.pulls_through("core"));
+        assert!(!upstream.pulls_through("acme"));
+    }
+
+    #[test]
+    fn pulls_through_nothing_without_upstream_depots() {
+        let upstream = Upstream::new(&UpstreamCfg::default());
+        assert!(!upstream.pulls_through("core"));
+    }
+
+    fn header(key_names: &[&str]) -> ArtifactHeader {
+        let signatures = key_names
+            .iter()
+            .map(|name| ArtifactSignature::new(name.to_string(), String::new()))
+            .collect();
+        ArtifactHeader::new("HART-1".to_string(), "BLAKE2b".to_string(), signatures)
+    }
+
+    #[test]
+    fn first_signer_must_be_the_packages_origin() {
+        let mut ident = OriginPackageIdent::new();
+        ident.set_origin("core".to_string());
+        ident.set_name("cacerts".to_string());
+        let signers = signer_keys(&ident,
+                                  &header(&["core-20160810182414", "acme-20170101000000"]),
+                                  &[])
+            .unwrap();
+        assert_eq!(signers,
+                   vec![("core".to_string(), "20160810182414".to_string()),
+                        ("acme".to_string(), "20170101000000".to_string())]);
+        assert!(signer_keys(&ident, &header(&["acme-20170101000000"]), &[]).is_err());
+        assert!(signer_keys(&ident,
+                            &header(&["core-20160810182414"]),
+                            &["core-20160810182414".to_string()])
+                        .is_err());
+    }
+}
@@ -0,0 +1,480 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Delivers depot events to the webhooks origins have registered for them.
+//!
+//! Every delivery is a JSON document POSTed to the webhook's URL and signed with an HMAC-SHA256
+//! of the body keyed with the webhook's secret. The signature is sent hex encoded in the
+//! `X-Habitat-Signature` header, which lets receivers check that the delivery came from this
+//! depot.
+//!
+//! Deliveries are made off the request path. A dispatcher thread queues them for a fixed pool
+//! of delivery threads, and each one is retried with a doubling delay until the receiver
+//! responds with a success status or the configured number of attempts is used up. Deliveries
+//! which don't fit in the queue are failed right away. The outcome is recorded in the webhook's
+//! delivery log.
+//!
+//! Webhooks may not point at the depot's own network, so URLs resolving to loopback, private,
+//! link-local or otherwise internal addresses are refused, both when a webhook is registered and
+//! before every delivery attempt. A delivery connects to the very address which was checked,
+//! whatever the host resolves to by then, and redirects are not followed.
+
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, TcpStream, ToSocketAddrs};
+use std::sync::{Arc, Mutex};
+use std::sync::mpsc::{self, Receiver, Sender, TrySendError};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use crypto::hmac::Hmac;
+use crypto::mac::Mac;
+use crypto::sha2::Sha256;
+use hab_net::routing::Broker;
+use hyper;
+use hyper::client::{Client, RedirectPolicy};
+use hyper::header::{ContentType, UserAgent};
+use hyper::net::{HttpStream, HttpsConnector, NetworkConnector};
+use hyper_openssl::OpensslClient;
+use protocol::originsrv::*;
+use serde_json;
+use time;
+use url::{Host, Url};
+use uuid::Uuid;
+
+use VERSION;
+use config::WebhooksCfg;
+
+/// Product name the depot identifies itself with to webhook receivers.
+const PRODUCT: &'static str = "hab-depot";
+/// Number of seconds a delivery waits on the receiver before giving up
+const DELIVERY_TIMEOUT: u64 = 30;
+
+header! { (XHabitatDelivery, "X-Habitat-Delivery") => [String] }
+header! { (XHabitatEvent, "X-Habitat-Event") => [String] }
+header! { (XHabitatSignature, "X-Habitat-Signature") => [String] }
+
+/// An event which happened within an origin.
+pub struct Notification {
+    pub origin_id: u64,
+    pub origin: String,
+    pub event: OriginWebhookEvent,
+    pub data: serde_json::Value,
+}
+
+/// Hands notifications over to the dispatcher. Notifications are dropped when no dispatcher is
+/// running, which is the case for depots under test.
+#[derive(Default)]
+pub struct Webhooks(Mutex<Option<Sender<Notification>>>);
+
+impl Webhooks {
+    pub fn notify(&self,
+                  origin_id: u64,
+                  origin: &str,
+                  event: OriginWebhookEvent,
+                  data: serde_json::Value) {
+        let sender = self.0.lock().expect("webhooks lock is poisoned");
+        if let Some(ref sender) = *sender {
+            let notification = Notification {
+                origin_id: origin_id,
+                origin: origin.to_string(),
+                event: event,
+                data: data,
+            };
+            if let Err(e) = sender.send(notification) {
+                error!("Unable to queue webhook notification, err={}", e);
+            }
+        }
+    }
+}
+
+/// Start the dispatcher thread, returning the handle notifications are sent to it through.
+pub fn start_dispatcher(config: WebhooksCfg) -> (Webhooks, JoinHandle<()>) {
+    let (tx, rx) = mpsc::channel();
+    let handle = thread::Builder::new()
+        .name("webhook-dispatcher".to_string())
+        .spawn(move || dispatch(config, rx))
+        .unwrap();
+    (Webhooks(Mutex::new(Some(tx))), handle)
+}
+
+fn dispatch(config: WebhooksCfg, rx: Receiver<Notification>) {
+    let (queue, deliveries) = mpsc::sync_channel(config.queue_size);
+    let deliveries = Arc::new(Mutex::new(deliveries));
+    for i in 0..config.workers {
+        let deliveries = deliveries.clone();
+        let config = config.clone();
+        let spawned = thread::Builder::new()
+            .name(format!("webhook-delivery-{}", i))
+            .spawn(move || deliver(config, deliveries));
+        if let Err(e) = spawned {
+            error!("Unable to start webhook delivery thread, err={}", e);
+        }
+    }
+
+    while let Ok(notification) = rx.recv() {
+        let mut conn = match Broker::connect() {
+            Ok(conn) => conn,
+            Err(e) => {
+                error!("Unable to connect to broker, err={}", e);
+                continue;
+            }
+        };
+        let mut request = OriginWebhookListRequest::new();
+        request.set_origin_id(notification.origin_id);
+        let webhooks = match conn.route::<OriginWebhookListRequest,
+                                          OriginWebhookListResponse>(&request) {
+            Ok(mut list) => list.take_webhooks().into_vec(),
+            Err(e) => {
+                error!("Unable to list webhooks of {}, err={}", notification.origin, e);
+                continue;
+            }
+        };
+        for webhook in webhooks {
+            if !webhook.get_events().contains(&notification.event) {
+                continue;
+            }
+            match queue.try_send(Delivery::new(&notification, webhook)) {
+                Ok(()) => (),
+                Err(TrySendError::Full(delivery)) => {
+                    warn!("Webhook delivery queue is full, dropping delivery {}", delivery.id);
+                    delivery.record(0, Err((0, "Delivery queue is full".to_string())));
+                }
+                Err(TrySendError::Disconnected(delivery)) => {
+                    error!("No webhook delivery threads, dropping delivery {}", delivery.id);
+                    delivery.record(0, Err((0, "No delivery threads running".to_string())));
+                }
+            }
+        }
+    }
+}
+
+fn deliver(config: WebhooksCfg, deliveries: Arc<Mutex<Receiver<Delivery>>>) {
+    loop {
+        let delivery = match deliveries
+                  .lock()
+                  .expect("webhook delivery lock is poisoned")
+                  .recv() {
+            Ok(delivery) => delivery,
+            Err(_) => break,
+        };
+        delivery.run(&config);
+    }
+}
+
+struct Delivery {
+    id: String,
+    webhook: OriginWebhook,
+    event: OriginWebhookEvent,
+    payload: String,
+}
+
+impl Delivery {
+    fn new(notification: &Notification, webhook: OriginWebhook) -> Self {
+        let id = Uuid::new_v4().simple().to_string();
+        let payload = json!({
+            "id": id,
+            "event": notification.event.as_str(),
+            "origin": notification.origin,
+            "timestamp": format!("{}", time::now_utc().rfc3339()),
+            "data": notification.data,
+        });
+        Delivery {
+            id: id,
+            webhook: webhook,
+            event: notification.event,
+            payload: payload.to_string(),
+        }
+    }
+
+    fn run(self, config: &WebhooksCfg) {
+        let mut delay = Duration::from_secs(config.retry_delay);
+        let mut attempts = 0;
+        let mut outcome = Err((0, String::new()));
+        while attempts < config.attempts {
+            if attempts > 0 {
+                thread::sleep(delay);
+                delay = delay * 2;
+            }
+            attempts += 1;
+            outcome = self.attempt(config);
+            match outcome {
+                Ok(_) => break,
+                Err((_, ref e)) => {
+                    warn!("Webhook delivery {} to {} failed, attempt {} of {}, err={}",
+                          self.id,
+                          self.webhook.get_url(),
+                          attempts,
+                          config.attempts,
+                          e)
+                }
+            }
+        }
+        self.record(attempts, outcome);
+    }
+
+    /// Make a single delivery attempt, returning the status code the receiver responded with.
+    fn attempt(&self, config: &WebhooksCfg) -> Result<u16, (u16, String)> {
+        // Checked again on every attempt, as the receiver's host may resolve differently by now
+        let (url, addrs) = try!(resolve_url(self.webhook.get_url(), config.allow_internal_urls)
+                                    .map_err(|e| (0, e)));
+        let addr = match addrs.first() {
+            Some(addr) => *addr,
+            None => return Err((0, format!("Unable to resolve {}", url))),
+        };
+        let client = try!(pinned_client(addr).map_err(|e| (0, e.to_string())));
+        let signature = format!("sha256={}", signature(self.webhook.get_secret(), &self.payload));
+        match client
+                  .post(url)
+                  .header(UserAgent(format!("{}/{}", PRODUCT, VERSION)))
+                  .header(ContentType::json())
+                  .header(XHabitatEvent(self.event.as_str().to_string()))
+                  .header(XHabitatDelivery(self.id.clone()))
+                  .header(XHabitatSignature(signature))
+                  .body(self.payload.as_str())
+                  .send() {
+            Ok(ref res) if res.status.is_success() => Ok(res.status.to_u16()),
+            Ok(res) => Err((res.status.to_u16(), res.status.to_string())),
+            Err(e) => Err((0, e.to_string())),
+        }
+    }
+
+    fn record(&self, attempts: u32, outcome: Result<u16, (u16, String)>) {
+        let mut request = OriginWebhookDeliveryCreate::new();
+        request.set_webhook_id(self.webhook.get_id());
+        request.set_origin_id(self.webhook.get_origin_id());
+        request.set_event(self.event);
+        request.set_delivery_id(self.id.clone());
+        request.set_payload(self.payload.clone());
+        request.set_attempts(attempts);
+        match outcome {
+            Ok(status) => {
+                request.set_status_code(status as u32);
+                request.set_success(true);
+            }
+            Err((status, error)) => {
+                request.set_status_code(status as u32);
+                request.set_success(false);
+                request.set_error(error);
+            }
+        }
+        let result = match Broker::connect() {
+            Ok(mut conn) => {
+                conn.route::<OriginWebhookDeliveryCreate, OriginWebhookDelivery>(&request)
+                    .map(|_| ())
+                    .map_err(|e| e.to_string())
+            }
+            Err(e) => Err(e.to_string()),
+        };
+        if let Err(e) = result {
+            error!("Unable to record webhook delivery {}, err={}", self.id, e);
+        }
+    }
+}
+
+/// Checks that a webhook URL is an HTTP or HTTPS URL which doesn't lead to an internal address,
+/// returning why it's refused otherwise. Hosts which can't be resolved pass, as deliveries to
+/// them fail anyway and they're checked again before every attempt.
+pub fn validate_url(url: &str, allow_internal: bool) -> Result<(), String> {
+    resolve_url(url, allow_internal).map(|_| ())
+}
+
+/// Parses and resolves a webhook URL like `validate_url`, returning the addresses its host
+/// resolves to, none of which is internal unless `allow_internal` is set.
+fn resolve_url(url: &str, allow_internal: bool) -> Result<(Url, Vec<SocketAddr>), String> {
+    let url = try!(Url::parse(url).map_err(|e| format!("Invalid URL {}: {}", url, e)));
+    if url.scheme() != "http" && url.scheme() != "https" {
+        return Err(format!("Unsupported URL scheme {}", url.scheme()));
+    }
+    let port = url.port_or_known_default().unwrap_or(80);
+    let addrs = match url.host() {
+        Some(Host::Ipv4(ip)) => vec![SocketAddr::new(IpAddr::V4(ip), port)],
+        Some(Host::Ipv6(ip)) => vec![SocketAddr::new(IpAddr::V6(ip), port)],
+        Some(Host::Domain(domain)) => {
+            let domain = domain.trim_right_matches('.').to_lowercase();
+            if !allow_internal && (domain == "localhost" || domain.ends_with(".localhost")) {
+                return Err(format!("{} is an internal host", domain));
+            }
+            match (domain.as_str(), port).to_socket_addrs() {
+                Ok(addrs) => addrs.collect(),
+                Err(e) => {
+                    debug!("Unable to resolve webhook host {}, err={}", domain, e);
+                    vec![]
+                }
+            }
+        }
+        None => return Err("URL has no host".to_string()),
+    };
+    if !allow_internal {
+        if let Some(addr) = addrs.iter().find(|addr| is_internal(&addr.ip())) {
+            return Err(format!("{} is an internal address", addr.ip()));
+        }
+    }
+    Ok((url, addrs))
+}
+
+/// Returns a client which connects to the given address whatever the host of a request's URL,
+/// and which doesn't follow redirects, so a delivery can't be led to another address than the
+/// one which was checked.
+fn pinned_client(addr: SocketAddr) -> hyper::Result<Client> {
+    let ssl = try!(OpensslClient::new().map_err(|e| hyper::Error::Ssl(Box::new(e))));
+    let mut client = Client::with_connector(HttpsConnector::with_connector(ssl,
+                                                                           PinnedConnector(addr)));
+    client.set_redirect_policy(RedirectPolicy::FollowNone);
+    client.set_read_timeout(Some(Duration::from_secs(DELIVERY_TIMEOUT)));
+    client.set_write_timeout(Some(Duration::from_secs(DELIVERY_TIMEOUT)));
+    Ok(client)
+}
+
+/// Connects to a fixed address, ignoring the host it's asked to connect to.
+struct PinnedConnector(SocketAddr);
+
+impl NetworkConnector for PinnedConnector {
+    type Stream = HttpStream;
+
+    fn connect(&self, _host: &str, _port: u16, _scheme: &str) -> hyper::Result<HttpStream> {
+        Ok(HttpStream(try!(TcpStream::connect(self.0))))
+    }
+}
+
+fn is_internal(ip: &IpAddr) -> bool {
+    match *ip {
+        IpAddr::V4(ref ip) => is_internal_v4(ip),
+        IpAddr::V6(ref ip) => {
+            if let Some(ip) = ip.to_ipv4() {
+                return is_internal_v4(&ip);
+            }
+            let first = ip.segments()[0];
+            ip.is_loopback() || ip.is_unspecified() || ip.is_multicast() ||
+            // Unique local (fc00::/7) and link-local (fe80::/10) addresses
+            (first & 0xfe00) == 0xfc00 || (first & 0xffc0) == 0xfe80
+        }
+    }
+}
+
+fn is_internal_v4(ip: &Ipv4Addr) -> bool {
+    let octets = ip.octets();
+    ip.is_loopback() || ip.is_private() || ip.is_link_local() || ip.is_unspecified() ||
+    ip.is_broadcast() || ip.is_multicast() || octets[0] == 0 ||
+    // Shared address space used for carrier-grade NAT (100.64.0.0/10)
+    (octets[0] == 100 && (octets[1] & 0xc0) == 64)
+}
+
+/// Returns the hex encoded HMAC-SHA256 of a payload keyed with a webhook's secret.
+pub fn signature(secret: &str, payload: &str) -> String {
+    let mut hmac = Hmac::new(Sha256::new(), secret.as_bytes());
+    hmac.input(payload.as_bytes());
+    hmac.result()
+        .code()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+
+    use super::*;
+
+    #[test]
+    fn signature_is_hmac_sha256() {
+        // RFC 4231 test case 2
+        assert_eq!(signature("Jefe", "what do ya want for nothing?"),
+                   "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843");
+    }
+
+    #[test]
+    fn validate_url_refuses_internal_addresses() {
+        for url in ["http://127.0.0.1/hook",
+                    "http://localhost:8080/hook",
+                    "http://10.0.0.5/hook",
+                    "http://172.16.1.1/hook",
+                    "http://192.168.1.1/hook",
+                    "http://169.254.169.254/latest/meta-data",
+                    "http://100.64.0.1/hook",
+                    "http://0.0.0.0/hook",
+                    "http://[::1]/hook",
+                    "http://[fd00::1]/hook",
+                    "http://[fe80::1]/hook",
+                    "http://[::ffff:127.0.0.1]/hook"]
+                    .iter() {
+            assert!(validate_url(url, false).is_err(), "{} was accepted", url);
+        }
+    }
+
+    #[test]
+    fn validate_url_accepts_public_addresses() {
+        assert!(validate_url("https://93.184.216.34/hook", false).is_ok());
+        assert!(validate_url("http://[2606:2800:220:1:248:1893:25c8:1946]/hook", false).is_ok());
+        assert!(validate_url("http://127.0.0.1:8080/hook", true).is_ok());
+    }
+
+    #[test]
+    fn validate_url_refuses_other_schemes() {
+        assert!(validate_url("ftp://93.184.216.34/hook", false).is_err());
+        assert!(validate_url("file:///etc/passwd", true).is_err());
+        assert!(validate_url("not a url", false).is_err());
+    }
+
+    #[test]
+    fn resolve_url_keeps_port() {
+        let (_, addrs) = resolve_url("https://93.184.216.34/hook", false).unwrap();
+        assert_eq!(addrs, vec!["93.184.216.34:443".parse::<SocketAddr>().unwrap()]);
+    }
+
+    #[test]
+    fn attempt_does_not_follow_redirects() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            {
+                let mut reader = BufReader::new(&mut stream);
+                let mut len = 0;
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap() > 2 {
+                    let header = line.to_lowercase();
+                    if header.starts_with("content-length:") {
+                        len = header["content-length:".len()..].trim().parse().unwrap();
+                    }
+                    line.clear();
+                }
+                reader.take(len).read_to_end(&mut Vec::new()).unwrap();
+            }
+            stream
+                .write_all(b"HTTP/1.1 302 Found\r\nLocation: http://169.254.169.254/\r\n\
+                             Content-Length: 0\r\nConnection: close\r\n\r\n")
+                .unwrap();
+        });
+        let mut config = WebhooksCfg::default();
+        config.allow_internal_urls = true;
+        let mut webhook = OriginWebhook::new();
+        webhook.set_url(url);
+        webhook.set_secret("secret".to_string());
+        let notification = Notification {
+            origin_id: 1,
+            origin: "core".to_string(),
+            event: OriginWebhookEvent::Upload,
+            data: json!({}),
+        };
+        let delivery = Delivery::new(&notification, webhook);
+        assert_eq!(delivery.attempt(&config), Err((302, "302 Found".to_string())));
+    }
+
+    #[test]
+    fn notify_without_dispatcher() {
+        let webhooks = Webhooks::default();
+        webhooks.notify(1, "core", OriginWebhookEvent::Upload, json!({}));
+    }
+}
@@ -23,8 +23,8 @@ use db::pool::Pool;
 use error::{Result, Error};
 use hab_net::routing::Broker;
 use postgres;
-use protobuf;
-use protocol::net::NetOk;
+use protobuf::{self, ProtobufEnum};
+use protocol::net::{self, NetOk};
 use protocol::{originsrv, jobsrv, scheduler};
 use std::str::FromStr;
 
@@ -281,7 +281,180 @@ impl DataStore {
         // migrating any old data.
         migrator.migrate("jobsrv", r#"ALTER TABLE jobs DROP COLUMN IF EXISTS log_url"#)?;
         migrator.migrate("jobsrv", r#"DROP FUNCTION IF EXISTS set_log_url_v1(bigint, text)"#)?;
-        
+
+        // Cancel a job which is still waiting in the queue. Jobs which were already handed to a
+        // worker are left alone; the worker building them reports them as cancelled.
+        migrator.migrate("jobsrv",
+                         r#"CREATE OR REPLACE FUNCTION cancel_pending_job_v1 (jid bigint) RETURNS SETOF jobs AS $$
+                            BEGIN
+                                RETURN QUERY UPDATE jobs SET job_state='Cancelled', scheduler_sync=false, updated_at=now() WHERE id=jid AND job_state='Pending' RETURNING *;
+                                RETURN;
+                            END
+                         $$ LANGUAGE plpgsql VOLATILE"#)?;
+
+        // Per-project build timeouts, and the resources used by the build as reported by workers
+        // which limit them
+        migrator.migrate("jobsrv", r#"ALTER TABLE jobs ADD COLUMN IF NOT EXISTS project_build_timeout INTEGER DEFAULT 0 NOT NULL"#)?;
+        migrator.migrate("jobsrv", r#"ALTER TABLE jobs ADD COLUMN IF NOT EXISTS cpu_usage_ms BIGINT DEFAULT NULL"#)?;
+        migrator.migrate("jobsrv", r#"ALTER TABLE jobs ADD COLUMN IF NOT EXISTS memory_usage_bytes BIGINT DEFAULT NULL"#)?;
+        migrator.migrate("jobsrv",
+                             r#"CREATE OR REPLACE FUNCTION insert_job_v2 (
+                                owner_id bigint,
+                                project_id bigint,
+                                project_name text,
+                                project_owner_id bigint,
+                                project_plan_path text,
+                                vcs text,
+                                vcs_arguments text[],
+                                project_build_timeout integer
+                                ) RETURNS SETOF jobs AS $$
+                                    BEGIN
+                                        RETURN QUERY INSERT INTO jobs (owner_id, job_state, project_id, project_name, project_owner_id, project_plan_path, vcs, vcs_arguments, project_build_timeout)
+                                            VALUES (owner_id, 'Pending', project_id, project_name, project_owner_id, project_plan_path, vcs, vcs_arguments, project_build_timeout)
+                                            RETURNING *;
+                                        RETURN;
+                                    END
+                                $$ LANGUAGE plpgsql VOLATILE
+                                "#)?;
+        migrator.migrate("jobsrv",
+                         r#"CREATE OR REPLACE FUNCTION update_job_v2(
+                           p_job_id bigint,
+                           p_state text,
+                           p_build_started_at timestamptz,
+                           p_build_finished_at timestamptz,
+                           p_package_ident text,
+                           p_net_error_code int,
+                           p_net_error_msg text,
+                           p_cpu_usage_ms bigint,
+                           p_memory_usage_bytes bigint)
+                         RETURNS VOID
+                         LANGUAGE SQL VOLATILE AS $$
+                           UPDATE jobs
+                           SET job_state = p_state,
+                               scheduler_sync = false,
+                               updated_at = now(),
+                               build_started_at = p_build_started_at,
+                               build_finished_at = p_build_finished_at,
+                               package_ident = p_package_ident,
+                               net_error_code = p_net_error_code,
+                               net_error_msg = p_net_error_msg,
+                               cpu_usage_ms = p_cpu_usage_ms,
+                               memory_usage_bytes = p_memory_usage_bytes
+                           WHERE id = p_job_id;
+                         $$"#)?;
+
+        // Progress of the phases a job goes through on its worker, reported as the worker
+        // reaches each of them
+        migrator.migrate("jobsrv",
+                         r#"CREATE TABLE IF NOT EXISTS job_phases (
+                                    job_id bigint REFERENCES jobs(id),
+                                    phase text,
+                                    started_at timestamptz,
+                                    finished_at timestamptz,
+                                    PRIMARY KEY (job_id, phase)
+                             )"#)?;
+        migrator.migrate("jobsrv",
+                         r#"CREATE OR REPLACE FUNCTION set_job_phase_v1(
+                           p_job_id bigint,
+                           p_phase text,
+                           p_started_at timestamptz,
+                           p_finished_at timestamptz)
+                         RETURNS VOID
+                         LANGUAGE SQL VOLATILE AS $$
+                           INSERT INTO job_phases (job_id, phase, started_at, finished_at)
+                           VALUES (p_job_id, p_phase, p_started_at, p_finished_at)
+                           ON CONFLICT ON CONSTRAINT job_phases_pkey DO UPDATE
+                           SET started_at = p_started_at, finished_at = p_finished_at;
+                         $$"#)?;
+        migrator.migrate("jobsrv",
+                         r#"CREATE OR REPLACE FUNCTION get_job_phases_v1(p_job_id bigint)
+                         RETURNS SETOF job_phases
+                         LANGUAGE SQL STABLE AS $$
+                           SELECT * FROM job_phases WHERE job_id = p_job_id ORDER BY started_at ASC;
+                         $$"#)?;
+
+        // The worker a job was dispatched to, which keeps the artifact of a job that failed in
+        // post-processing for as long as the job is shelved
+        migrator.migrate("jobsrv",
+                         r#"ALTER TABLE jobs ADD COLUMN IF NOT EXISTS worker TEXT DEFAULT NULL"#)?;
+        migrator.migrate("jobsrv",
+                         r#"ALTER TABLE jobs
+                            ADD COLUMN IF NOT EXISTS shelved BOOLEAN DEFAULT FALSE NOT NULL"#)?;
+        migrator.migrate("jobsrv",
+                         r#"CREATE OR REPLACE FUNCTION update_job_v3(
+                           p_job_id bigint,
+                           p_state text,
+                           p_build_started_at timestamptz,
+                           p_build_finished_at timestamptz,
+                           p_package_ident text,
+                           p_net_error_code int,
+                           p_net_error_msg text,
+                           p_cpu_usage_ms bigint,
+                           p_memory_usage_bytes bigint,
+                           p_worker text,
+                           p_shelved bool)
+                         RETURNS VOID
+                         LANGUAGE SQL VOLATILE AS $$
+                           UPDATE jobs
+                           SET job_state = p_state,
+                               scheduler_sync = false,
+                               updated_at = now(),
+                               build_started_at = p_build_started_at,
+                               build_finished_at = p_build_finished_at,
+                               package_ident = p_package_ident,
+                               net_error_code = p_net_error_code,
+                               net_error_msg = p_net_error_msg,
+                               cpu_usage_ms = p_cpu_usage_ms,
+                               memory_usage_bytes = p_memory_usage_bytes,
+                               worker = p_worker,
+                               shelved = p_shelved
+                           WHERE id = p_job_id;
+                         $$"#)?;
+
+        // Results of the post-processing steps of a job, by their position in the project's
+        // pipeline
+        migrator.migrate("jobsrv",
+                         r#"CREATE TABLE IF NOT EXISTS job_post_process (
+                                    job_id bigint REFERENCES jobs(id),
+                                    step integer,
+                                    name text,
+                                    success bool,
+                                    detail text,
+                                    PRIMARY KEY (job_id, step)
+                             )"#)?;
+        migrator.migrate("jobsrv",
+                         r#"CREATE OR REPLACE FUNCTION set_job_post_process_v1(
+                           p_job_id bigint,
+                           p_step integer,
+                           p_name text,
+                           p_success bool,
+                           p_detail text)
+                         RETURNS VOID
+                         LANGUAGE SQL VOLATILE AS $$
+                           INSERT INTO job_post_process (job_id, step, name, success, detail)
+                           VALUES (p_job_id, p_step, p_name, p_success, p_detail)
+                           ON CONFLICT ON CONSTRAINT job_post_process_pkey DO UPDATE
+                           SET name = p_name, success = p_success, detail = p_detail;
+                         $$"#)?;
+        migrator.migrate("jobsrv",
+                         r#"CREATE OR REPLACE FUNCTION get_job_post_process_v1(p_job_id bigint)
+                         RETURNS SETOF job_post_process
+                         LANGUAGE SQL STABLE AS $$
+                           SELECT * FROM job_post_process WHERE job_id = p_job_id ORDER BY step ASC;
+                         $$"#)?;
+
+        // Artifacts are shelved on the worker which built them and go away along with it
+        migrator.migrate("jobsrv",
+                         r#"CREATE OR REPLACE FUNCTION unshelve_worker_jobs_v1(p_worker text)
+                         RETURNS SETOF jobs
+                         LANGUAGE SQL VOLATILE AS $$
+                           UPDATE jobs
+                           SET shelved = false,
+                               updated_at = now()
+                           WHERE worker = p_worker AND shelved
+                           RETURNING *;
+                         $$"#)?;
+
         migrator.finish()?;
 
         self.async.register("sync_jobs".to_string(), sync_jobs);
@@ -305,22 +478,24 @@ impl DataStore {
     pub fn create_job(&self, job: &jobsrv::Job) -> Result<jobsrv::Job> {
         let conn = self.pool.get_shard(0)?;
 
-        if job.get_project().get_vcs_type() == "git" {
-            let project = job.get_project();
-
-            let rows = conn.query("SELECT * FROM insert_job_v1($1, $2, $3, $4, $5, $6, $7)",
-                                  &[&(job.get_owner_id() as i64),
-                                    &(project.get_id() as i64),
-                                    &project.get_name(),
-                                    &(project.get_owner_id() as i64),
-                                    &project.get_plan_path(),
-                                    &project.get_vcs_type(),
-                                    &vec![project.get_vcs_data()]])
-                .map_err(Error::JobCreate)?;
-            let job = row_to_job(&rows.get(0))?;
-            return Ok(job);
-        } else {
-            return Err(Error::UnknownVCS);
+        match job.get_project().get_vcs_type() {
+            "git" | "local" => {
+                let project = job.get_project();
+
+                let rows = conn.query("SELECT * FROM insert_job_v2($1, $2, $3, $4, $5, $6, $7, $8)",
+                                      &[&(job.get_owner_id() as i64),
+                                        &(project.get_id() as i64),
+                                        &project.get_name(),
+                                        &(project.get_owner_id() as i64),
+                                        &project.get_plan_path(),
+                                        &project.get_vcs_type(),
+                                        &vcs_arguments(project),
+                                        &(project.get_build_timeout() as i32)])
+                    .map_err(Error::JobCreate)?;
+                let job = row_to_job(&rows.get(0))?;
+                Ok(job)
+            }
+            _ => Err(Error::UnknownVCS),
         }
     }
 
@@ -337,7 +512,23 @@ impl DataStore {
                                &[&(get_job.get_id() as i64)])
                         .map_err(Error::JobGet)?;
         for row in rows {
-            let job = row_to_job(&row)?;
+            let mut job = row_to_job(&row)?;
+            let phase_rows = &conn.query("SELECT * FROM get_job_phases_v1($1)",
+                                         &[&(get_job.get_id() as i64)])
+                                  .map_err(Error::JobGet)?;
+            let mut phases = protobuf::RepeatedField::new();
+            for phase_row in phase_rows {
+                phases.push(row_to_job_phase(&phase_row)?);
+            }
+            job.set_phases(phases);
+            let result_rows = &conn.query("SELECT * FROM get_job_post_process_v1($1)",
+                                          &[&(get_job.get_id() as i64)])
+                                   .map_err(Error::JobGet)?;
+            let mut results = protobuf::RepeatedField::new();
+            for result_row in result_rows {
+                results.push(row_to_post_process_result(&result_row));
+            }
+            job.set_post_process(results);
             return Ok(Some(job));
         }
         Ok(None)
@@ -397,9 +588,84 @@ impl DataStore {
         Ok(())
     }
 
+    /// Clear the shelved flag of the jobs whose artifacts were shelved on the given worker,
+    /// returning their ids. The artifacts can no longer be published again once the worker
+    /// has gone away.
+    ///
+    /// # Errors
+    ///
+    /// * If a connection cannot be gotten from the pool
+    /// * If the jobs cannot be updated in the database
+    pub fn unshelve_worker_jobs(&self, worker: &str) -> Result<Vec<u64>> {
+        let conn = self.pool.get_shard(0)?;
+        let rows = &conn.query("SELECT * FROM unshelve_worker_jobs_v1($1)", &[&worker])
+                        .map_err(Error::JobUnshelve)?;
+        let mut jobs = Vec::new();
+        for row in rows {
+            let job = row_to_job(&row)?;
+            jobs.push(job.get_id());
+        }
+        Ok(jobs)
+    }
+
+    /// Cancel a job if it is still Pending, returning the cancelled job. `None` means the job
+    /// doesn't exist or is no longer Pending.
+    ///
+    /// # Errors
+    ///
+    /// * If a connection cannot be gotten from the pool
+    /// * If the job cannot be updated in the database
+    pub fn cancel_pending_job(&self, job_id: u64) -> Result<Option<jobsrv::Job>> {
+        let conn = self.pool.get_shard(0)?;
+        let rows = &conn.query("SELECT * FROM cancel_pending_job_v1($1)",
+                               &[&(job_id as i64)])
+                        .map_err(Error::JobCancel)?;
+        for row in rows {
+            let job = row_to_job(&row)?;
+            self.async.schedule("sync_jobs")?;
+            return Ok(Some(job));
+        }
+        Ok(None)
+    }
+
+    /// Finish a job which no worker is building any longer with the given state and error,
+    /// returning the finished job. `None` means the job doesn't exist or is already finished.
+    ///
+    /// # Errors
+    ///
+    /// * If a connection cannot be gotten from the pool
+    /// * If the job cannot be read or updated in the database
+    pub fn abort_job(&self,
+                     job_id: u64,
+                     state: jobsrv::JobState,
+                     error: Option<net::NetError>)
+                     -> Result<Option<jobsrv::Job>> {
+        let mut get_job = jobsrv::JobGet::new();
+        get_job.set_id(job_id);
+        let mut job = match self.get_job(&get_job)? {
+            Some(job) => job,
+            None => return Ok(None),
+        };
+        match job.get_state() {
+            jobsrv::JobState::Pending |
+            jobsrv::JobState::Dispatched |
+            jobsrv::JobState::Processing => (),
+            _ => return Ok(None),
+        }
+        job.set_state(state);
+        if let Some(error) = error {
+            job.set_error(error);
+        }
+        self.update_job(&job)?;
+        Ok(Some(job))
+    }
+
     /// Updates a job. Currently, this entails updating the state,
-    /// build start and stop times, and recording the identifier of
-    /// the package the job produced, if any.
+    /// build start and stop times, recording the identifier of
+    /// the package the job produced, if any, along with the error the
+    /// job failed with, the resources its build used, the times of
+    /// the phases it went through, the results of its post-processing
+    /// steps and where its artifact is shelved.
     ///
     /// # Errors
     ///
@@ -415,6 +681,7 @@ impl DataStore {
             jobsrv::JobState::Complete => "Complete",
             jobsrv::JobState::Rejected => "Rejected",
             jobsrv::JobState::Failed => "Failed",
+            jobsrv::JobState::Cancelled => "Cancelled",
         };
         
         // Note: the following fields may all be NULL. As currently
@@ -433,10 +700,61 @@ impl DataStore {
             true => Some(job.get_package_ident().to_string()),
             false => None
         };
+        let (error_code, error_msg) = match job.has_error() {
+            true => {
+                (Some(job.get_error().get_code().value()),
+                 Some(job.get_error().get_msg().to_string()))
+            }
+            false => (None, None),
+        };
+        let cpu_usage_ms = match job.has_cpu_usage_ms() {
+            true => Some(job.get_cpu_usage_ms() as i64),
+            false => None,
+        };
+        let memory_usage_bytes = match job.has_memory_usage_bytes() {
+            true => Some(job.get_memory_usage_bytes() as i64),
+            false => None,
+        };
+
+        let worker = match job.has_worker() {
+            true => Some(job.get_worker().to_string()),
+            false => None,
+        };
 
-        conn.execute("SELECT update_job_v1($1, $2, $3, $4, $5)",
-                     &[&job_id, &job_state, &build_started_at, &build_finished_at, &ident])
-            .map_err(Error::JobSetState)?; 
+        conn.execute("SELECT update_job_v3($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)",
+                     &[&job_id,
+                       &job_state,
+                       &build_started_at,
+                       &build_finished_at,
+                       &ident,
+                       &error_code,
+                       &error_msg,
+                       &cpu_usage_ms,
+                       &memory_usage_bytes,
+                       &worker,
+                       &job.get_shelved()])
+            .map_err(Error::JobSetState)?;
+
+        for phase in job.get_phases() {
+            let started_at = parse_phase_time(phase.get_started_at())?;
+            let finished_at = match phase.has_finished_at() {
+                true => Some(parse_phase_time(phase.get_finished_at())?),
+                false => None,
+            };
+            conn.execute("SELECT set_job_phase_v1($1, $2, $3, $4)",
+                         &[&job_id, &job_phase_name(phase.get_phase()), &started_at, &finished_at])
+                .map_err(Error::JobSetState)?;
+        }
+
+        for (step, result) in job.get_post_process().iter().enumerate() {
+            conn.execute("SELECT set_job_post_process_v1($1, $2, $3, $4, $5)",
+                         &[&job_id,
+                           &(step as i32),
+                           &result.get_name(),
+                           &result.get_success(),
+                           &result.get_detail()])
+                .map_err(Error::JobSetState)?;
+        }
 
         self.async.schedule("sync_jobs")?;
 
@@ -475,6 +793,7 @@ fn row_to_job(row: &postgres::rows::Row) -> Result<jobsrv::Job> {
         "Complete" => jobsrv::JobState::Complete,
         "Rejected" => jobsrv::JobState::Rejected,
         "Failed" => jobsrv::JobState::Failed,
+        "Cancelled" => jobsrv::JobState::Cancelled,
         _ => return Err(Error::UnknownJobState),
     };
     job.set_state(job_state);
@@ -496,6 +815,28 @@ fn row_to_job(row: &postgres::rows::Row) -> Result<jobsrv::Job> {
         let ident: originsrv::OriginPackageIdent = ident_str.parse().unwrap();
         job.set_package_ident(ident);
     }
+
+    if let Some(Ok(code)) = row.get_opt::<&str, i32>("net_error_code") {
+        match net::ErrCode::from_i32(code) {
+            Some(code) => {
+                let msg: Option<String> = row.get("net_error_msg");
+                job.set_error(net::err(code, msg.unwrap_or_default()));
+            }
+            None => warn!("Unknown error code, {}", code),
+        }
+    }
+
+    // Resource usage is only reported by workers which limit it
+    if let Some(Ok(cpu_usage_ms)) = row.get_opt::<&str, i64>("cpu_usage_ms") {
+        job.set_cpu_usage_ms(cpu_usage_ms as u64);
+    }
+    if let Some(Ok(memory_usage_bytes)) = row.get_opt::<&str, i64>("memory_usage_bytes") {
+        job.set_memory_usage_bytes(memory_usage_bytes as u64);
+    }
+    if let Some(Ok(worker)) = row.get_opt::<&str, String>("worker") {
+        job.set_worker(worker);
+    }
+    job.set_shelved(row.get("shelved"));
     
     let mut project = originsrv::OriginProject::new();
     let project_id: i64 = row.get("project_id");
@@ -517,13 +858,15 @@ fn row_to_job(row: &postgres::rows::Row) -> Result<jobsrv::Job> {
     let project_owner_id: i64 = row.get("project_owner_id");
     project.set_owner_id(project_owner_id as u64);
     project.set_plan_path(row.get("project_plan_path"));
+    let build_timeout: i32 = row.get("project_build_timeout");
+    project.set_build_timeout(build_timeout as u32);
 
     let rvcs: String = row.get("vcs");
     match rvcs.as_ref() {
-        "git" => {
-            let mut vcsa: Vec<String> = row.get("vcs_arguments");
-            project.set_vcs_type(String::from("git"));
-            project.set_vcs_data(vcsa.remove(0));
+        "git" | "local" => {
+            let vcsa: Vec<String> = row.get("vcs_arguments");
+            project.set_vcs_type(rvcs.clone());
+            set_vcs_arguments(&mut project, vcsa);
         }
         e => {
             error!("Unknown VCS, {}", e);
@@ -537,6 +880,78 @@ fn row_to_job(row: &postgres::rows::Row) -> Result<jobsrv::Job> {
     Ok(job)
 }
 
+/// The VCS spec of a project, stored positionally as `[url, ref, depth, submodules, auth,
+/// auth_secret]`. Jobs created before the spec existed only carry the url.
+fn vcs_arguments(project: &originsrv::OriginProject) -> Vec<String> {
+    vec![project.get_vcs_data().to_string(),
+         project.get_vcs_ref().to_string(),
+         project.get_vcs_depth().to_string(),
+         project.get_vcs_submodules().to_string(),
+         project.get_vcs_auth().as_str().to_string(),
+         project.get_vcs_auth_secret().to_string()]
+}
+
+fn set_vcs_arguments(project: &mut originsrv::OriginProject, vcsa: Vec<String>) {
+    let mut vcsa = vcsa.into_iter();
+    project.set_vcs_data(vcsa.next().unwrap_or_default());
+    project.set_vcs_ref(vcsa.next().unwrap_or_default());
+    project.set_vcs_depth(vcsa.next().and_then(|d| d.parse().ok()).unwrap_or(0));
+    project.set_vcs_submodules(vcsa.next().and_then(|s| s.parse().ok()).unwrap_or(false));
+    project.set_vcs_auth(vcsa.next()
+                             .and_then(|a| a.parse().ok())
+                             .unwrap_or(originsrv::VcsAuth::Anonymous));
+    project.set_vcs_auth_secret(vcsa.next().unwrap_or_default());
+}
+
+fn job_phase_name(phase: jobsrv::JobPhase) -> &'static str {
+    match phase {
+        jobsrv::JobPhase::FetchKey => "FetchKey",
+        jobsrv::JobPhase::Clone => "Clone",
+        jobsrv::JobPhase::Build => "Build",
+        jobsrv::JobPhase::PostProcess => "PostProcess",
+        jobsrv::JobPhase::Publish => "Publish",
+    }
+}
+
+/// Parse the RFC3339 time a worker reported a job phase starting or finishing at.
+fn parse_phase_time(time: &str) -> Result<DateTime<UTC>> {
+    DateTime::<UTC>::from_str(time).map_err(|e| Error::JobPhaseTime(time.to_string(), e))
+}
+
+/// Translate a database `job_phases` row to a `jobsrv::JobPhaseTime`.
+///
+/// # Errors
+///
+/// * If the phase is unknown
+fn row_to_job_phase(row: &postgres::rows::Row) -> Result<jobsrv::JobPhaseTime> {
+    let mut phase = jobsrv::JobPhaseTime::new();
+    let name: String = row.get("phase");
+    let job_phase = match &name[..] {
+        "FetchKey" => jobsrv::JobPhase::FetchKey,
+        "Clone" => jobsrv::JobPhase::Clone,
+        "Build" => jobsrv::JobPhase::Build,
+        "PostProcess" => jobsrv::JobPhase::PostProcess,
+        "Publish" => jobsrv::JobPhase::Publish,
+        _ => return Err(Error::UnknownJobPhase),
+    };
+    phase.set_phase(job_phase);
+    let started_at = row.get::<&str, DateTime<UTC>>("started_at");
+    phase.set_started_at(started_at.to_rfc3339());
+    if let Some(Ok(finished_at)) = row.get_opt::<&str, DateTime<UTC>>("finished_at") {
+        phase.set_finished_at(finished_at.to_rfc3339());
+    }
+    Ok(phase)
+}
+
+/// Translate a database `job_post_process` row to a `jobsrv::PostProcessResult`.
+fn row_to_post_process_result(row: &postgres::rows::Row) -> jobsrv::PostProcessResult {
+    let mut result = jobsrv::PostProcessResult::new();
+    result.set_name(row.get("name"));
+    result.set_success(row.get("success"));
+    result.set_detail(row.get("detail"));
+    result
+}
+
 fn sync_jobs(pool: Pool) -> DbResult<EventOutcome> {
     let mut result = EventOutcome::Finished;
     for shard in pool.shards.iter() {
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use aws_sdk_rust;
+use chrono;
 use db;
 use extern_url;
 use hab_core;
@@ -39,15 +40,18 @@ pub enum Error {
     HabitatCore(hab_core::Error),
     InvalidUrl,
     IO(io::Error),
+    JobCancel(postgres::error::Error),
     JobCreate(postgres::error::Error),
     JobGet(postgres::error::Error),
     JobLogArchive(u64, aws_sdk_rust::aws::errors::s3::S3Error),
     JobLogRetrieval(u64, aws_sdk_rust::aws::errors::s3::S3Error),
     JobMarkArchived(postgres::error::Error),
+    JobPhaseTime(String, chrono::ParseError),
     JobPending(postgres::error::Error),
     JobReset(postgres::error::Error),
     JobSetLogUrl(postgres::error::Error),
     JobSetState(postgres::error::Error),
+    JobUnshelve(postgres::error::Error),
     LogDirDoesNotExist(PathBuf, io::Error),
     LogDirIsNotDir(PathBuf),
     LogDirNotWritable(PathBuf),
@@ -55,6 +59,7 @@ pub enum Error {
     ProjectJobsGet(postgres::error::Error),
     Protobuf(protobuf::ProtobufError),
     UnknownVCS,
+    UnknownJobPhase,
     UnknownJobState,
     Zmq(zmq::Error),
 }
@@ -83,6 +88,7 @@ impl fmt::Display for Error {
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::InvalidUrl => format!("Bad URL!"),
             Error::IO(ref e) => format!("{}", e),
+            Error::JobCancel(ref e) => format!("Database error cancelling a job, {}", e),
             Error::JobCreate(ref e) => format!("Database error creating a new job, {}", e),
             Error::JobGet(ref e) => format!("Database error getting job data, {}", e),
             Error::JobLogArchive(job_id, ref e) => {
@@ -94,10 +100,14 @@ impl fmt::Display for Error {
             Error::JobMarkArchived(ref e) => {
                 format!("Database error marking job as archived, {}", e)
             }
+            Error::JobPhaseTime(ref time, ref e) => {
+                format!("Job phase time {} is not an RFC3339 time, {}", time, e)
+            }
             Error::JobPending(ref e) => format!("Database error getting pending jobs, {}", e),
             Error::JobReset(ref e) => format!("Database error reseting jobs, {}", e),
             Error::JobSetLogUrl(ref e) => format!("Database error setting job log URL, {}", e),
             Error::JobSetState(ref e) => format!("Database error setting job state, {}", e),
+            Error::JobUnshelve(ref e) => format!("Database error unshelving jobs, {}", e),
             Error::LogDirDoesNotExist(ref path, ref e) => {
                 format!("Build log directory {:?} doesn't exist!: {:?}", path, e)
             }
@@ -113,6 +123,7 @@ impl fmt::Display for Error {
                 format!("Database error getting jobs for project, {}", e)
             }
             Error::UnknownVCS => format!("Unknown VCS"),
+            Error::UnknownJobPhase => format!("Unknown Job Phase"),
             Error::UnknownJobState => format!("Unknown Job State"),
             Error::Zmq(ref e) => format!("{}", e),
         };
@@ -133,21 +144,25 @@ impl error::Error for Error {
             Error::HabitatCore(ref err) => err.description(),
             Error::IO(ref err) => err.description(),
             Error::InvalidUrl => "Bad Url!",
+            Error::JobCancel(ref err) => err.description(),
             Error::JobCreate(ref err) => err.description(),
             Error::JobGet(ref err) => err.description(),
             Error::JobLogArchive(_, ref err) => err.description(),
             Error::JobLogRetrieval(_, ref err) => err.description(),
             Error::JobMarkArchived(ref err) => err.description(),
+            Error::JobPhaseTime(_, ref err) => err.description(),
             Error::JobPending(ref err) => err.description(),
             Error::JobReset(ref err) => err.description(),
             Error::JobSetLogUrl(ref err) => err.description(),
             Error::JobSetState(ref err) => err.description(),
+            Error::JobUnshelve(ref err) => err.description(),
             Error::LogDirDoesNotExist(_, ref err) => err.description(),
             Error::LogDirIsNotDir(_) => "Build log directory is not a directory",
             Error::LogDirNotWritable(_) => "Build log directory is not writable",
             Error::NetError(ref err) => err.description(),
             Error::ProjectJobsGet(ref err) => err.description(),
             Error::Protobuf(ref err) => err.description(),
+            Error::UnknownJobPhase => "Unknown Job Phase",
             Error::UnknownJobState => "Unknown Job State",
             Error::UnknownVCS => "Unknown VCS",
             Error::Zmq(ref err) => err.description(),
@@ -61,6 +61,122 @@ pub fn job_get(req: &mut Envelope, sock: &mut zmq::Socket, state: &mut ServerSta
     Ok(())
 }
 
+pub fn job_cancel(req: &mut Envelope,
+                  sock: &mut zmq::Socket,
+                  state: &mut ServerState)
+                  -> Result<()> {
+    let msg: proto::JobCancel = try!(req.parse_msg());
+    let mut get = proto::JobGet::new();
+    get.set_id(msg.get_id());
+    let job = match state.datastore().get_job(&get) {
+        Ok(Some(job)) => job,
+        Ok(None) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "jb:job-cancel:1");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::DATA_STORE, "jb:job-cancel:2");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+    };
+    match job.get_state() {
+        proto::JobState::Pending |
+        proto::JobState::Dispatched |
+        proto::JobState::Processing => (),
+        proto::JobState::Failed if job.get_shelved() => {
+            return job_abandon(job, req, sock, state);
+        }
+        _ => {
+            let err = net::err(ErrCode::ENTITY_CONFLICT, "jb:job-cancel:3");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+    }
+    // A job may be dispatched between reading and cancelling it, in which case the worker
+    // manager cancels it on the worker which received it, or directly if no worker has it
+    match state.datastore().cancel_pending_job(job.get_id()) {
+        Ok(Some(ref cancelled)) => {
+            debug!("Job cancelled: id={}", cancelled.get_id());
+            try!(req.reply_complete(sock, cancelled));
+        }
+        Ok(None) => {
+            debug!("Cancelling job through the worker manager: id={}", job.get_id());
+            try!(state.worker_mgr().cancel_job(&msg));
+            try!(req.reply_complete(sock, &job));
+        }
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::DATA_STORE, "jb:job-cancel:4");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+/// Abandon a job which failed in post-processing, throwing away its shelved artifact
+fn job_abandon(mut job: proto::Job,
+               req: &mut Envelope,
+               sock: &mut zmq::Socket,
+               state: &mut ServerState)
+               -> Result<()> {
+    job.set_shelved(false);
+    if let Err(e) = state.datastore().update_job(&job) {
+        error!("datastore error, err={:?}", e);
+        let err = net::err(ErrCode::DATA_STORE, "jb:job-cancel:5");
+        try!(req.reply_complete(sock, &err));
+        return Ok(());
+    }
+    debug!("Job abandoned: id={}", job.get_id());
+    try!(state.worker_mgr().discard_shelved(&job));
+    try!(req.reply_complete(sock, &job));
+    Ok(())
+}
+
+pub fn job_publish_retry(req: &mut Envelope,
+                         sock: &mut zmq::Socket,
+                         state: &mut ServerState)
+                         -> Result<()> {
+    let msg: proto::JobPublishRetry = try!(req.parse_msg());
+    let mut get = proto::JobGet::new();
+    get.set_id(msg.get_id());
+    let mut job = match state.datastore().get_job(&get) {
+        Ok(Some(job)) => job,
+        Ok(None) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "jb:job-publish-retry:1");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+        Err(e) => {
+            error!("datastore error, err={:?}", e);
+            let err = net::err(ErrCode::DATA_STORE, "jb:job-publish-retry:2");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+    };
+    if job.get_state() != proto::JobState::Failed || !job.get_shelved() {
+        let err = net::err(ErrCode::ENTITY_CONFLICT, "jb:job-publish-retry:3");
+        try!(req.reply_complete(sock, &err));
+        return Ok(());
+    }
+    job.set_state(proto::JobState::Dispatched);
+    job.clear_error();
+    if let Err(e) = state.datastore().update_job(&job) {
+        error!("datastore error, err={:?}", e);
+        let err = net::err(ErrCode::DATA_STORE, "jb:job-publish-retry:4");
+        try!(req.reply_complete(sock, &err));
+        return Ok(());
+    }
+    debug!("Retrying publish on worker: id={}, worker={}",
+           job.get_id(),
+           job.get_worker());
+    try!(state.worker_mgr().retry_publish(&job));
+    try!(req.reply_complete(sock, &job));
+    Ok(())
+}
+
 pub fn project_jobs_get(req: &mut Envelope,
                         sock: &mut zmq::Socket,
                         state: &mut ServerState)
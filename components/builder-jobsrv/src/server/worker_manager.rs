@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp;
+use std::collections::HashMap;
 use std::sync::{mpsc, Arc, RwLock};
 use std::time::{Duration, Instant};
 use std::thread::{self, JoinHandle};
@@ -20,6 +22,7 @@ use linked_hash_map::LinkedHashMap;
 use hab_net::server::ZMQ_CONTEXT;
 use protobuf::{parse_from_bytes, Message};
 use protocol::jobsrv;
+use protocol::net::{self, ErrCode};
 use zmq;
 
 use config::Config;
@@ -28,6 +31,22 @@ use error::Result;
 
 const WORKER_MGR_ADDR: &'static str = "inproc://work-manager";
 const WORKER_TIMEOUT_MS: u64 = 33_000;
+/// Protocol message to indicate there may be pending jobs to dispatch
+const WORK_NOTIFY: u8 = 1;
+/// Protocol message to indicate a job should be cancelled on the worker building it
+const WORK_CANCEL: u8 = 2;
+/// Protocol message to indicate a job's shelved artifact should be published again
+const WORK_PUBLISH: u8 = 3;
+/// Protocol message to indicate a job's shelved artifact should be thrown away
+const WORK_DISCARD: u8 = 4;
+/// ZMQ protocol frame to indicate a job is being dispatched to a worker
+const JOB_DISPATCH: &'static str = "D";
+/// ZMQ protocol frame to indicate a worker should cancel the job it is building
+const JOB_CANCEL: &'static str = "C";
+/// ZMQ protocol frame to indicate a worker should publish a job's shelved artifact again
+const JOB_PUBLISH: &'static str = "P";
+/// ZMQ protocol frame to indicate a worker should throw away a job's shelved artifact
+const JOB_DISCARD: &'static str = "X";
 
 pub struct WorkerMgrClient {
     socket: zmq::Socket,
@@ -40,7 +59,25 @@ impl WorkerMgrClient {
     }
 
     pub fn notify_work(&mut self) -> Result<()> {
-        try!(self.socket.send(&[1], 0));
+        try!(self.socket.send(&[WORK_NOTIFY], 0));
+        Ok(())
+    }
+
+    pub fn cancel_job(&mut self, msg: &jobsrv::JobCancel) -> Result<()> {
+        try!(self.socket.send(&[WORK_CANCEL], zmq::SNDMORE));
+        try!(self.socket.send(&msg.write_to_bytes().unwrap(), 0));
+        Ok(())
+    }
+
+    pub fn retry_publish(&mut self, job: &jobsrv::Job) -> Result<()> {
+        try!(self.socket.send(&[WORK_PUBLISH], zmq::SNDMORE));
+        try!(self.socket.send(&job.write_to_bytes().unwrap(), 0));
+        Ok(())
+    }
+
+    pub fn discard_shelved(&mut self, job: &jobsrv::Job) -> Result<()> {
+        try!(self.socket.send(&[WORK_DISCARD], zmq::SNDMORE));
+        try!(self.socket.send(&job.write_to_bytes().unwrap(), 0));
         Ok(())
     }
 }
@@ -55,6 +92,139 @@ impl Default for WorkerMgrClient {
     }
 }
 
+/// Jobs which were sent to a worker that has not yet reported them as finished, keyed by job id.
+#[derive(Default)]
+struct DispatchedJobs(HashMap<u64, String>);
+
+impl DispatchedJobs {
+    /// The worker building the given job, if any
+    fn worker(&self, job_id: u64) -> Option<String> {
+        self.0.get(&job_id).cloned()
+    }
+
+    fn insert(&mut self, job_id: u64, worker: String) {
+        self.0.insert(job_id, worker);
+    }
+
+    fn remove(&mut self, job_id: u64) {
+        self.0.remove(&job_id);
+    }
+
+    /// Forget the jobs of workers which are no longer known, returning their ids
+    fn retain_workers(&mut self, workers: &HashMap<String, Instant>) -> Vec<u64> {
+        let dispatched = &mut self.0;
+        let lost: Vec<u64> = dispatched
+            .iter()
+            .filter(|&(_, worker)| !workers.contains_key(worker))
+            .map(|(job_id, _)| *job_id)
+            .collect();
+        for job_id in lost.iter() {
+            dispatched.remove(job_id);
+        }
+        lost
+    }
+}
+
+/// A worker which is ready to take on jobs
+struct Worker {
+    expiry: Instant,
+    /// Number of jobs the worker can still take on
+    free_slots: u32,
+}
+
+/// Workers which are ready to take on jobs, ordered by when they expire
+struct ReadyWorkers {
+    workers: LinkedHashMap<String, Worker>,
+    /// Number of jobs sent to each worker. Heartbeats report how many of them the worker had
+    /// received, so those sent before the worker took on the latest job can be told apart.
+    jobs_sent: HashMap<String, u64>,
+}
+
+impl ReadyWorkers {
+    fn new() -> Self {
+        ReadyWorkers {
+            workers: LinkedHashMap::new(),
+            jobs_sent: HashMap::new(),
+        }
+    }
+
+    /// When the first worker expires unless it sends another heartbeat
+    fn next_expiry(&self) -> Option<Instant> {
+        self.workers.front().map(|(_, worker)| worker.expiry)
+    }
+
+    /// The worker with the most free slots, spreading jobs across workers
+    fn next_worker(&self) -> Option<String> {
+        self.workers
+            .iter()
+            .max_by_key(|&(_, worker)| worker.free_slots)
+            .map(|(endpoint, _)| endpoint.clone())
+    }
+
+    /// Record a job sent to the worker, taking up one of its free slots. The worker is
+    /// forgotten once it has none left until it next reports being ready.
+    fn claim_slot(&mut self, endpoint: &str) {
+        *self.jobs_sent.entry(endpoint.to_string()).or_insert(0) += 1;
+        let full = match self.workers.get_mut(endpoint) {
+            Some(worker) => {
+                worker.free_slots -= 1;
+                worker.free_slots == 0
+            }
+            None => false,
+        };
+        if full {
+            self.workers.remove(endpoint);
+        }
+    }
+
+    /// Update the worker's state from its heartbeat, returning whether it has become ready to
+    /// take on jobs. Heartbeats sent before the worker received every job sent to it are
+    /// dropped, as their free slots don't account for those jobs yet.
+    fn heartbeat(&mut self, heartbeat: &jobsrv::Heartbeat, expiry: Instant) -> bool {
+        let endpoint = heartbeat.get_endpoint();
+        let sent = self.jobs_sent.get(endpoint).cloned().unwrap_or(0);
+        if heartbeat.get_jobs_received() < sent {
+            debug!("dropping stale heartbeat, worker={:?}, received={}, sent={}",
+                   endpoint,
+                   heartbeat.get_jobs_received(),
+                   sent);
+            return false;
+        }
+        // Re-inserting moves the worker to the back, keeping the list ordered by expiry
+        self.workers.remove(endpoint);
+        match heartbeat.get_state() {
+            jobsrv::WorkerState::Ready => {
+                let worker = Worker {
+                    expiry: expiry,
+                    // Workers without build slots build one job at a time
+                    free_slots: cmp::max(heartbeat.get_free_slots(), 1),
+                };
+                self.workers.insert(endpoint.to_string(), worker);
+                true
+            }
+            jobsrv::WorkerState::Busy => false,
+        }
+    }
+
+    fn remove(&mut self, endpoint: &str) {
+        self.workers.remove(endpoint);
+    }
+
+    /// Forget the workers which expired before `now`
+    fn expire(&mut self, now: Instant) {
+        while self.next_expiry().map_or(false, |expiry| expiry < now) {
+            if let Some((endpoint, _)) = self.workers.pop_front() {
+                debug!("expiring worker due to inactivity, worker={:?}", endpoint);
+            }
+        }
+    }
+
+    /// Forget the jobs sent to workers which are no longer known
+    fn retain_jobs_sent(&mut self, workers: &HashMap<String, Instant>) {
+        self.jobs_sent.retain(|worker, _| workers.contains_key(worker));
+    }
+}
+
 pub struct WorkerMgr {
     config: Arc<RwLock<Config>>,
     datastore: DataStore,
@@ -62,7 +232,10 @@ pub struct WorkerMgr {
     rq_sock: zmq::Socket,
     work_mgr_sock: zmq::Socket,
     msg: zmq::Message,
-    workers: LinkedHashMap<String, Instant>,
+    workers: ReadyWorkers,
+    /// When each worker, ready or busy, expires unless it sends another heartbeat
+    seen: HashMap<String, Instant>,
+    dispatched: DispatchedJobs,
 }
 
 impl WorkerMgr {
@@ -83,7 +256,9 @@ impl WorkerMgr {
                rq_sock: rq_sock,
                work_mgr_sock: work_mgr_sock,
                msg: msg,
-               workers: LinkedHashMap::new(),
+               workers: ReadyWorkers::new(),
+               seen: HashMap::new(),
+               dispatched: DispatchedJobs::default(),
            })
     }
 
@@ -152,9 +327,14 @@ impl WorkerMgr {
                 rq_sock = false;
             }
             if work_mgr_sock {
-                process_work = true;
                 work_mgr_sock = false;
                 try!(self.work_mgr_sock.recv(&mut self.msg, 0));
+                match self.msg.first() {
+                    Some(&WORK_CANCEL) => try!(self.process_cancel()),
+                    Some(&WORK_PUBLISH) => try!(self.process_publish()),
+                    Some(&WORK_DISCARD) => try!(self.process_discard()),
+                    _ => process_work = true,
+                }
             }
 
             // Handle potential work in pending_jobs queue
@@ -165,8 +345,8 @@ impl WorkerMgr {
     }
 
     fn poll_timeout(&self) -> i64 {
-        if let Some((_, expiry)) = self.workers.front() {
-            let timeout = *expiry - Instant::now();
+        if let Some(expiry) = self.workers.next_expiry() {
+            let timeout = expiry - Instant::now();
             (timeout.as_secs() as i64 * 1000) + (timeout.subsec_nanos() as i64 / 1000 / 1000)
         } else {
             -1
@@ -185,29 +365,42 @@ impl WorkerMgr {
             // This unwrap is fine, because we just checked our length
             let mut job = jobs.pop().unwrap();
 
-            match self.workers.pop_front() {
-                Some((worker, _)) => {
+            match self.workers.next_worker() {
+                Some(worker) => {
                     debug!("sending work, worker={:?}, job={:?}", worker, job);
                     if self.rq_sock.send_str(&worker, zmq::SNDMORE).is_err() {
                         debug!("failed to send, worker went away, worker={:?}", worker);
+                        self.workers.remove(&worker);
                         job.set_state(jobsrv::JobState::Pending);
                         self.datastore.update_job(&job)?;
                         continue;
                     }
                     if self.rq_sock.send(&[], zmq::SNDMORE).is_err() {
                         debug!("failed to send, worker went away, worker={:?}", worker);
+                        self.workers.remove(&worker);
+                        job.set_state(jobsrv::JobState::Pending);
+                        self.datastore.update_job(&job)?;
+                        continue;
+                    }
+                    if self.rq_sock.send_str(JOB_DISPATCH, zmq::SNDMORE).is_err() {
+                        debug!("failed to send, worker went away, worker={:?}", worker);
+                        self.workers.remove(&worker);
                         job.set_state(jobsrv::JobState::Pending);
                         self.datastore.update_job(&job)?;
                         continue;
                     }
+                    job.set_worker(worker.clone());
                     if self.rq_sock
                            .send(&job.write_to_bytes().unwrap(), 0)
                            .is_err() {
                         debug!("failed to send, worker went away, worker={:?}", worker);
+                        self.workers.remove(&worker);
                         job.set_state(jobsrv::JobState::Pending);
                         self.datastore.update_job(&job)?;
                         continue;
                     }
+                    self.workers.claim_slot(&worker);
+                    self.dispatched.insert(job.get_id(), worker);
                 }
                 None => {
                     debug!("no workers available - bailing for now");
@@ -222,16 +415,38 @@ impl WorkerMgr {
 
     fn expire_workers(&mut self) {
         let now = Instant::now();
-        loop {
-            if let Some((_, expiry)) = self.workers.front() {
-                if expiry >= &now {
-                    break;
+        self.workers.expire(now);
+        let gone: Vec<String> = self.seen
+            .iter()
+            .filter(|&(_, expiry)| *expiry < now)
+            .map(|(worker, _)| worker.clone())
+            .collect();
+        for worker in gone {
+            self.seen.remove(&worker);
+            // Shelved artifacts went away along with the worker which kept them
+            match self.datastore.unshelve_worker_jobs(&worker) {
+                Ok(ref jobs) if jobs.is_empty() => (),
+                Ok(jobs) => {
+                    warn!("unshelving jobs of worker which went away, worker={:?}, jobs={:?}",
+                          worker,
+                          jobs)
                 }
-            } else {
-                break;
+                Err(err) => {
+                    error!("unable to unshelve jobs, worker={:?}, err={}", worker, err)
+                }
+            }
+        }
+        self.workers.retain_jobs_sent(&self.seen);
+        let lost = self.dispatched.retain_workers(&self.seen);
+        if !lost.is_empty() {
+            warn!("failing jobs of workers which went away, jobs={:?}", lost);
+        }
+        for job_id in lost {
+            let error = net::err(ErrCode::TIMEOUT, "jb:expire-workers:1");
+            if let Err(err) = self.datastore
+                   .abort_job(job_id, jobsrv::JobState::Failed, Some(error)) {
+                error!("unable to fail job of lost worker, job={}, err={}", job_id, err)
             }
-            let worker = self.workers.pop_front();
-            debug!("expiring worker due to inactivity, worker={:?}", worker);
         }
     }
 
@@ -239,20 +454,9 @@ impl WorkerMgr {
         try!(self.hb_sock.recv(&mut self.msg, 0));
         let heartbeat: jobsrv::Heartbeat = try!(parse_from_bytes(&self.msg));
         debug!("heartbeat={:?}", heartbeat);
-        let result = match heartbeat.get_state() {
-            jobsrv::WorkerState::Ready => {
-                let now = Instant::now();
-                let expiry = now + Duration::from_millis(WORKER_TIMEOUT_MS);
-                self.workers
-                    .insert(heartbeat.get_endpoint().to_string(), expiry);
-                true
-            }
-            jobsrv::WorkerState::Busy => {
-                self.workers.remove(heartbeat.get_endpoint());
-                false
-            }
-        };
-        Ok(result)
+        let expiry = Instant::now() + Duration::from_millis(WORKER_TIMEOUT_MS);
+        self.seen.insert(heartbeat.get_endpoint().to_string(), expiry);
+        Ok(self.workers.heartbeat(&heartbeat, expiry))
     }
 
     fn process_job_status(&mut self) -> Result<()> {
@@ -262,8 +466,199 @@ impl WorkerMgr {
         try!(self.rq_sock.recv(&mut self.msg, 0));
         let job: jobsrv::Job = try!(parse_from_bytes(&self.msg));
         debug!("job_status={:?}", job);
-        try!(self.datastore.update_job(&job));
+        // A malformed status from one worker mustn't take down the manager
+        if let Err(err) = self.datastore.update_job(&job) {
+            error!("unable to update job status, job={}, err={}", job.get_id(), err);
+        }
+        match job.get_state() {
+            jobsrv::JobState::Complete |
+            jobsrv::JobState::Rejected |
+            jobsrv::JobState::Failed |
+            jobsrv::JobState::Cancelled => {
+                self.dispatched.remove(job.get_id());
+            }
+            _ => (),
+        }
+
+        Ok(())
+    }
+
+    fn process_cancel(&mut self) -> Result<()> {
+        try!(self.work_mgr_sock.recv(&mut self.msg, 0));
+        let cancel: jobsrv::JobCancel = try!(parse_from_bytes(&self.msg));
+        let worker = match self.dispatched.worker(cancel.get_id()) {
+            Some(worker) => worker,
+            None => {
+                // Jobs are only dispatched by this thread, so no worker will pick it up either
+                debug!("cancelling job no worker is building, job={}", cancel.get_id());
+                try!(self.datastore
+                         .abort_job(cancel.get_id(), jobsrv::JobState::Cancelled, None));
+                return Ok(());
+            }
+        };
+        debug!("cancelling work, worker={:?}, job={}", worker, cancel.get_id());
+        // The worker reports the job as Cancelled, or as finished if the build completed first
+        if self.rq_sock.send_str(&worker, zmq::SNDMORE).is_err() ||
+           self.rq_sock.send(&[], zmq::SNDMORE).is_err() ||
+           self.rq_sock.send_str(JOB_CANCEL, zmq::SNDMORE).is_err() ||
+           self.rq_sock.send(&cancel.write_to_bytes().unwrap(), 0).is_err() {
+            warn!("failed to send cancellation, worker went away, worker={:?}",
+                  worker);
+        }
+        Ok(())
+    }
+
+    fn process_publish(&mut self) -> Result<()> {
+        try!(self.work_mgr_sock.recv(&mut self.msg, 0));
+        let mut job: jobsrv::Job = try!(parse_from_bytes(&self.msg));
+        let worker = job.get_worker().to_string();
+        debug!("retrying publish, worker={:?}, job={}", worker, job.get_id());
+        if self.rq_sock.send_str(&worker, zmq::SNDMORE).is_err() ||
+           self.rq_sock.send(&[], zmq::SNDMORE).is_err() ||
+           self.rq_sock.send_str(JOB_PUBLISH, zmq::SNDMORE).is_err() ||
+           self.rq_sock.send(&job.write_to_bytes().unwrap(), 0).is_err() {
+            // The shelved artifact went away along with the worker which kept it
+            warn!("failed to retry publish, worker went away, worker={:?}", worker);
+            job.set_state(jobsrv::JobState::Failed);
+            job.set_shelved(false);
+            job.set_error(net::err(ErrCode::REMOTE_REJECTED, "jb:publish:1"));
+            try!(self.datastore.update_job(&job));
+            return Ok(());
+        }
+        // The worker is connected, so it's alive even if it hasn't sent a heartbeat yet
+        if !self.seen.contains_key(&worker) {
+            self.seen.insert(worker.clone(),
+                             Instant::now() + Duration::from_millis(WORKER_TIMEOUT_MS));
+        }
+        // Publishing takes up one of the worker's build slots like a dispatched job
+        self.workers.claim_slot(&worker);
+        self.dispatched.insert(job.get_id(), worker);
+        Ok(())
+    }
 
+    fn process_discard(&mut self) -> Result<()> {
+        try!(self.work_mgr_sock.recv(&mut self.msg, 0));
+        let job: jobsrv::Job = try!(parse_from_bytes(&self.msg));
+        let mut cancel = jobsrv::JobCancel::new();
+        cancel.set_id(job.get_id());
+        debug!("discarding shelved artifact, worker={:?}, job={}",
+               job.get_worker(),
+               job.get_id());
+        if self.rq_sock.send_str(job.get_worker(), zmq::SNDMORE).is_err() ||
+           self.rq_sock.send(&[], zmq::SNDMORE).is_err() ||
+           self.rq_sock.send_str(JOB_DISCARD, zmq::SNDMORE).is_err() ||
+           self.rq_sock.send(&cancel.write_to_bytes().unwrap(), 0).is_err() {
+            warn!("failed to discard shelved artifact, worker went away, worker={:?}",
+                  job.get_worker());
+        }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dispatched_jobs_of_lost_workers_are_forgotten() {
+        let mut dispatched = DispatchedJobs::default();
+        dispatched.insert(1, "alive".to_string());
+        dispatched.insert(2, "gone".to_string());
+        dispatched.insert(3, "gone".to_string());
+        let mut seen = HashMap::new();
+        seen.insert("alive".to_string(), Instant::now());
+
+        let mut lost = dispatched.retain_workers(&seen);
+        lost.sort();
+        assert_eq!(lost, vec![2, 3]);
+        assert_eq!(dispatched.worker(1), Some("alive".to_string()));
+        assert_eq!(dispatched.worker(2), None);
+
+        dispatched.remove(1);
+        assert_eq!(dispatched.worker(1), None);
+    }
+
+    fn heartbeat(endpoint: &str,
+                 state: jobsrv::WorkerState,
+                 free_slots: u32,
+                 jobs_received: u64)
+                 -> jobsrv::Heartbeat {
+        let mut heartbeat = jobsrv::Heartbeat::new();
+        heartbeat.set_endpoint(endpoint.to_string());
+        heartbeat.set_state(state);
+        heartbeat.set_free_slots(free_slots);
+        heartbeat.set_jobs_received(jobs_received);
+        heartbeat
+    }
+
+    #[test]
+    fn next_worker_has_most_free_slots() {
+        let mut workers = ReadyWorkers::new();
+        let expiry = Instant::now();
+        assert_eq!(workers.next_worker(), None);
+        assert!(workers.heartbeat(&heartbeat("a", jobsrv::WorkerState::Ready, 2, 0), expiry));
+        assert!(workers.heartbeat(&heartbeat("b", jobsrv::WorkerState::Ready, 3, 0), expiry));
+        assert_eq!(workers.next_worker(), Some("b".to_string()));
+
+        workers.claim_slot("b");
+        workers.claim_slot("b");
+        assert_eq!(workers.next_worker(), Some("a".to_string()));
+    }
+
+    #[test]
+    fn claim_slot_forgets_full_worker() {
+        let mut workers = ReadyWorkers::new();
+        // Workers without build slots build one job at a time
+        assert!(workers.heartbeat(&heartbeat("a", jobsrv::WorkerState::Ready, 0, 0),
+                                  Instant::now()));
+        assert_eq!(workers.next_worker(), Some("a".to_string()));
+
+        workers.claim_slot("a");
+        assert_eq!(workers.next_worker(), None);
+        // Jobs sent to unknown workers, such as publish retries, are still counted
+        workers.claim_slot("b");
+        assert_eq!(workers.jobs_sent.get("b"), Some(&1));
+    }
+
+    #[test]
+    fn heartbeat_sets_free_slots() {
+        let mut workers = ReadyWorkers::new();
+        let expiry = Instant::now();
+        assert!(workers.heartbeat(&heartbeat("a", jobsrv::WorkerState::Ready, 1, 0), expiry));
+        workers.claim_slot("a");
+        assert!(workers.heartbeat(&heartbeat("a", jobsrv::WorkerState::Ready, 2, 1), expiry));
+        assert_eq!(workers.workers.get("a").unwrap().free_slots, 2);
+
+        assert!(!workers.heartbeat(&heartbeat("a", jobsrv::WorkerState::Busy, 0, 1), expiry));
+        assert_eq!(workers.next_worker(), None);
+    }
+
+    #[test]
+    fn stale_heartbeat_keeps_claimed_slot() {
+        let mut workers = ReadyWorkers::new();
+        let expiry = Instant::now();
+        assert!(workers.heartbeat(&heartbeat("a", jobsrv::WorkerState::Ready, 2, 0), expiry));
+        workers.claim_slot("a");
+        assert_eq!(workers.workers.get("a").unwrap().free_slots, 1);
+
+        // Pulsed before the worker received the job, so it still advertises both slots
+        assert!(!workers.heartbeat(&heartbeat("a", jobsrv::WorkerState::Ready, 2, 0), expiry));
+        assert_eq!(workers.workers.get("a").unwrap().free_slots, 1);
+
+        assert!(workers.heartbeat(&heartbeat("a", jobsrv::WorkerState::Ready, 1, 1), expiry));
+        assert_eq!(workers.workers.get("a").unwrap().free_slots, 1);
+    }
+
+    #[test]
+    fn jobs_sent_to_lost_workers_are_forgotten() {
+        let mut workers = ReadyWorkers::new();
+        workers.claim_slot("alive");
+        workers.claim_slot("gone");
+        let mut seen = HashMap::new();
+        seen.insert("alive".to_string(), Instant::now());
+
+        workers.retain_jobs_sent(&seen);
+        assert_eq!(workers.jobs_sent.get("alive"), Some(&1));
+        assert_eq!(workers.jobs_sent.get("gone"), None);
+    }
+}
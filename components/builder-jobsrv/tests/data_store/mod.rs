@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use jobsrv::data_store::DataStore;
-use protocol::jobsrv;
+use protocol::{jobsrv, net, originsrv};
 
 #[test]
 fn migration() {
@@ -147,3 +147,232 @@ fn update_job() {
         .expect("No job found");
     assert_eq!(failed_job.get_state(), jobsrv::JobState::Failed);
 }
+
+#[test]
+fn cancel_pending_job() {
+    let mut job1 = test_job();
+    let mut job2 = test_job();
+    let ds = datastore_test!(DataStore);
+    ds.setup().expect("Failed to migrate data");
+    let rjob1 = ds.create_job(&mut job1).expect("Failed to create job");
+    let rjob2 = ds.create_job(&mut job2).expect("Failed to create job");
+
+    let cancelled = ds.cancel_pending_job(rjob2.get_id())
+        .expect("Failed to cancel job")
+        .expect("Pending job should be cancelled");
+    assert_eq!(cancelled.get_state(), jobsrv::JobState::Cancelled);
+
+    // A cancelled job never leaves the queue
+    let pending_jobs = ds.pending_jobs(5).expect("Failed to get pending jobs");
+    assert_eq!(pending_jobs.len(), 1);
+    assert_eq!(pending_jobs[0].get_id(), rjob1.get_id());
+
+    // Dispatched jobs are cancelled by their worker instead
+    let result = ds.cancel_pending_job(rjob1.get_id())
+        .expect("Failed to cancel job");
+    assert!(result.is_none());
+    let mut get_job = jobsrv::JobGet::new();
+    get_job.set_id(rjob1.get_id());
+    let dispatched = ds.get_job(&get_job)
+        .expect("Failed to get job from database")
+        .expect("No job found");
+    assert_eq!(dispatched.get_state(), jobsrv::JobState::Dispatched);
+}
+
+#[test]
+fn abort_job() {
+    let mut job1 = test_job();
+    let ds = datastore_test!(DataStore);
+    ds.setup().expect("Failed to migrate data");
+    let rjob1 = ds.create_job(&mut job1).expect("Failed to create job");
+    ds.pending_jobs(1).expect("Failed to get pending jobs");
+
+    let error = net::err(net::ErrCode::TIMEOUT, "jb:expire-workers:1");
+    let failed = ds.abort_job(rjob1.get_id(), jobsrv::JobState::Failed, Some(error))
+        .expect("Failed to abort job")
+        .expect("Dispatched job should be aborted");
+    assert_eq!(failed.get_state(), jobsrv::JobState::Failed);
+
+    let mut get_job = jobsrv::JobGet::new();
+    get_job.set_id(rjob1.get_id());
+    let stored = ds.get_job(&get_job)
+        .expect("Failed to get job from database")
+        .expect("No job found");
+    assert_eq!(stored.get_state(), jobsrv::JobState::Failed);
+    assert_eq!(stored.get_error().get_code(), net::ErrCode::TIMEOUT);
+
+    // Finished jobs are left as they are
+    let result = ds.abort_job(rjob1.get_id(), jobsrv::JobState::Cancelled, None)
+        .expect("Failed to abort job");
+    assert!(result.is_none());
+}
+
+#[test]
+fn job_build_limits() {
+    let mut job1 = test_job();
+    job1.mut_project().set_build_timeout(600);
+    let ds = datastore_test!(DataStore);
+    ds.setup().expect("Failed to migrate data");
+    let mut rjob1 = ds.create_job(&mut job1).expect("Failed to create job");
+    assert_eq!(rjob1.get_project().get_build_timeout(), 600);
+    assert!(!rjob1.has_cpu_usage_ms());
+    assert!(!rjob1.has_error());
+
+    rjob1.set_state(jobsrv::JobState::Failed);
+    rjob1.set_error(net::err(net::ErrCode::BUILD_TIMEOUT, "wk:run:7"));
+    rjob1.set_cpu_usage_ms(1500);
+    rjob1.set_memory_usage_bytes(1 << 30);
+    ds.update_job(&rjob1).expect("Failed to update job");
+
+    let mut get_job = jobsrv::JobGet::new();
+    get_job.set_id(rjob1.get_id());
+    let failed_job = ds.get_job(&get_job)
+        .expect("Failed to get job from database")
+        .expect("No job found");
+    assert_eq!(failed_job.get_error().get_code(), net::ErrCode::BUILD_TIMEOUT);
+    assert_eq!(failed_job.get_error().get_msg(), "wk:run:7");
+    assert_eq!(failed_job.get_cpu_usage_ms(), 1500);
+    assert_eq!(failed_job.get_memory_usage_bytes(), 1 << 30);
+    assert_eq!(failed_job.get_project().get_build_timeout(), 600);
+}
+
+#[test]
+fn job_vcs_spec() {
+    let mut job = test_job();
+    job.mut_project().set_vcs_ref(String::from("release-1.0"));
+    job.mut_project().set_vcs_depth(1);
+    job.mut_project().set_vcs_submodules(true);
+    job.mut_project().set_vcs_auth(originsrv::VcsAuth::Token);
+    job.mut_project().set_vcs_auth_secret(String::from("GITHUB_TOKEN"));
+    let mut local = test_job();
+    local.mut_project().set_vcs_type(String::from("local"));
+    local.mut_project().set_vcs_data(String::from("file:///src/habitat.git"));
+    let mut svn = test_job();
+    svn.mut_project().set_vcs_type(String::from("svn"));
+    let ds = datastore_test!(DataStore);
+    ds.setup().expect("Failed to migrate data");
+
+    let rjob = ds.create_job(&mut job).expect("Failed to create job");
+    let project = rjob.get_project();
+    assert_eq!(project.get_vcs_data(), "http://github.com/habitat-sh/habitat");
+    assert_eq!(project.get_vcs_ref(), "release-1.0");
+    assert_eq!(project.get_vcs_depth(), 1);
+    assert!(project.get_vcs_submodules());
+    assert_eq!(project.get_vcs_auth(), originsrv::VcsAuth::Token);
+    assert_eq!(project.get_vcs_auth_secret(), "GITHUB_TOKEN");
+
+    let rlocal = ds.create_job(&mut local).expect("Failed to create local job");
+    assert_eq!(rlocal.get_project().get_vcs_type(), "local");
+    assert_eq!(rlocal.get_project().get_vcs_auth(), originsrv::VcsAuth::Anonymous);
+    assert!(ds.create_job(&mut svn).is_err(), "Unknown VCS types should be rejected");
+}
+
+#[test]
+fn job_phases() {
+    let mut job1 = test_job();
+    let ds = datastore_test!(DataStore);
+    ds.setup().expect("Failed to migrate data");
+    let mut rjob1 = ds.create_job(&mut job1).expect("Failed to create job");
+    assert!(rjob1.get_phases().is_empty());
+
+    let mut fetch_key = jobsrv::JobPhaseTime::new();
+    fetch_key.set_phase(jobsrv::JobPhase::FetchKey);
+    fetch_key.set_started_at("2017-05-01T10:00:00+00:00".to_string());
+    fetch_key.set_finished_at("2017-05-01T10:00:05+00:00".to_string());
+    let mut clone = jobsrv::JobPhaseTime::new();
+    clone.set_phase(jobsrv::JobPhase::Clone);
+    clone.set_started_at("2017-05-01T10:00:05+00:00".to_string());
+    rjob1.set_state(jobsrv::JobState::Processing);
+    rjob1.mut_phases().push(fetch_key);
+    rjob1.mut_phases().push(clone);
+    ds.update_job(&rjob1).expect("Failed to update job");
+
+    // Reporting the same phase again updates it
+    rjob1.mut_phases()[1].set_finished_at("2017-05-01T10:01:00+00:00".to_string());
+    ds.update_job(&rjob1).expect("Failed to update job");
+
+    let mut get_job = jobsrv::JobGet::new();
+    get_job.set_id(rjob1.get_id());
+    let processing_job = ds.get_job(&get_job)
+        .expect("Failed to get job from database")
+        .expect("No job found");
+    assert_eq!(processing_job.get_state(), jobsrv::JobState::Processing);
+    let phases = processing_job.get_phases();
+    assert_eq!(phases.len(), 2);
+    assert_eq!(phases[0].get_phase(), jobsrv::JobPhase::FetchKey);
+    assert_eq!(phases[1].get_phase(), jobsrv::JobPhase::Clone);
+    assert_eq!(phases[1].get_started_at(), "2017-05-01T10:00:05+00:00");
+    assert_eq!(phases[1].get_finished_at(), "2017-05-01T10:01:00+00:00");
+}
+
+#[test]
+fn job_shelved() {
+    let mut job1 = test_job();
+    let ds = datastore_test!(DataStore);
+    ds.setup().expect("Failed to migrate data");
+    let mut rjob1 = ds.create_job(&mut job1).expect("Failed to create job");
+    assert!(!rjob1.has_worker());
+    assert!(!rjob1.get_shelved());
+
+    rjob1.set_state(jobsrv::JobState::Failed);
+    rjob1.set_worker("worker-1".to_string());
+    rjob1.set_shelved(true);
+    ds.update_job(&rjob1).expect("Failed to update job");
+
+    let mut get_job = jobsrv::JobGet::new();
+    get_job.set_id(rjob1.get_id());
+    let failed_job = ds.get_job(&get_job)
+        .expect("Failed to get job from database")
+        .expect("No job found");
+    assert_eq!(failed_job.get_worker(), "worker-1");
+    assert!(failed_job.get_shelved());
+
+    let unshelved = ds.unshelve_worker_jobs("worker-2").expect("Failed to unshelve jobs");
+    assert!(unshelved.is_empty());
+    let unshelved = ds.unshelve_worker_jobs("worker-1").expect("Failed to unshelve jobs");
+    assert_eq!(unshelved, vec![rjob1.get_id()]);
+    let unshelved_job = ds.get_job(&get_job)
+        .expect("Failed to get job from database")
+        .expect("No job found");
+    assert!(!unshelved_job.get_shelved());
+}
+
+#[test]
+fn job_post_process() {
+    let mut job1 = test_job();
+    let ds = datastore_test!(DataStore);
+    ds.setup().expect("Failed to migrate data");
+    let mut rjob1 = ds.create_job(&mut job1).expect("Failed to create job");
+    assert!(rjob1.get_post_process().is_empty());
+
+    let mut publish = jobsrv::PostProcessResult::new();
+    publish.set_name("publish".to_string());
+    publish.set_success(true);
+    publish.set_detail("published to unstable".to_string());
+    let mut export = jobsrv::PostProcessResult::new();
+    export.set_name("export".to_string());
+    export.set_success(false);
+    export.set_detail("docker exited with non-zero exit code, 1".to_string());
+    rjob1.set_state(jobsrv::JobState::Failed);
+    rjob1.mut_post_process().push(publish);
+    rjob1.mut_post_process().push(export);
+    ds.update_job(&rjob1).expect("Failed to update job");
+
+    // Running a step again replaces its result
+    rjob1.mut_post_process()[1].set_success(true);
+    rjob1.mut_post_process()[1].set_detail("localhost:5000/core/nginx:1.0-1".to_string());
+    ds.update_job(&rjob1).expect("Failed to update job");
+
+    let mut get_job = jobsrv::JobGet::new();
+    get_job.set_id(rjob1.get_id());
+    let job = ds.get_job(&get_job)
+        .expect("Failed to get job from database")
+        .expect("No job found");
+    let results = job.get_post_process();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].get_name(), "publish");
+    assert!(results[0].get_success());
+    assert_eq!(results[1].get_name(), "export");
+    assert!(results[1].get_success());
+    assert_eq!(results[1].get_detail(), "localhost:5000/core/nginx:1.0-1");
+}
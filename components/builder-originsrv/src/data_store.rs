@@ -14,6 +14,7 @@
 
 //! The PostgreSQL backend for the Vault.
 
+use chrono::{DateTime, UTC};
 use db::pool::Pool;
 use db::async::{AsyncServer, EventOutcome};
 use db::migration::Migrator;
@@ -25,7 +26,7 @@ use protocol::{originsrv, sessionsrv, scheduler};
 use protocol::net::NetOk;
 use protocol::originsrv::Pageable;
 use postgres;
-use protobuf;
+use protobuf::{self, ProtobufEnum};
 
 use config::Config;
 use error::{Result, Error};
@@ -35,6 +36,9 @@ use std::collections::HashMap;
 use std::fmt::Display;
 use std::str::FromStr;
 
+/// Maximum number of deliveries returned from a webhook's delivery log, most recent first.
+const WEBHOOK_DELIVERY_LOG_LIMIT: i64 = 100;
+
 
 #[derive(Debug, Clone)]
 pub struct DataStore {
@@ -80,6 +84,17 @@ impl DataStore {
         migrations::origin_projects::migrate(&mut migrator)?;
         migrations::origin_packages::migrate(&mut migrator)?;
         migrations::origin_channels::migrate(&mut migrator)?;
+        migrations::origin_package_audit::migrate(&mut migrator)?;
+        migrations::origin_webhooks::migrate(&mut migrator)?;
+        migrations::origin_rdeps::migrate(&mut migrator)?;
+        migrations::origin_package_search::migrate(&mut migrator)?;
+        migrations::origin_package_downloads::migrate(&mut migrator)?;
+        migrations::origin_project_limits::migrate(&mut migrator)?;
+        migrations::origin_secrets::migrate(&mut migrator)?;
+        migrations::origin_project_vcs::migrate(&mut migrator)?;
+        migrations::origin_package_yanked::migrate(&mut migrator)?;
+        migrations::origin_encryption_key_create::migrate(&mut migrator)?;
+        migrations::origin_package_file_index::migrate(&mut migrator)?;
 
         migrator.finish()?;
 
@@ -102,14 +117,22 @@ impl DataStore {
     pub fn update_origin_project(&self, opc: &originsrv::OriginProjectUpdate) -> Result<()> {
         let conn = self.pool.get(opc)?;
         let project = opc.get_project();
-        conn.execute("SELECT update_origin_project_v1($1, $2, $3, $4, $5, $6, $7)",
+        conn.execute("SELECT update_origin_project_v4($1, $2, $3, $4, $5, $6, $7, $8, $9, \
+                      $10, $11, $12, $13, $14)",
                      &[&(project.get_id() as i64),
                        &(project.get_origin_id() as i64),
                        &project.get_package_name(),
                        &project.get_plan_path(),
                        &project.get_vcs_type(),
                        &project.get_vcs_data(),
-                       &(project.get_owner_id() as i64)])
+                       &(project.get_owner_id() as i64),
+                       &project.get_cross_origin_rdeps(),
+                       &(project.get_build_timeout() as i32),
+                       &project.get_vcs_ref(),
+                       &(project.get_vcs_depth() as i32),
+                       &project.get_vcs_submodules(),
+                       &(project.get_vcs_auth().value() as i16),
+                       &project.get_vcs_auth_secret()])
             .map_err(Error::OriginProjectUpdate)?;
         Ok(())
     }
@@ -133,13 +156,15 @@ impl DataStore {
                         .map_err(Error::OriginProjectGet)?;
         if rows.len() != 0 {
             let row = rows.get(0);
-            Ok(Some(self.row_to_origin_project(&row)))
+            Ok(Some(self.row_to_origin_project(&row)?))
         } else {
             Ok(None)
         }
     }
 
-    pub fn row_to_origin_project(&self, row: &postgres::rows::Row) -> originsrv::OriginProject {
+    pub fn row_to_origin_project(&self,
+                                 row: &postgres::rows::Row)
+                                 -> Result<originsrv::OriginProject> {
         let mut project = originsrv::OriginProject::new();
         let id: i64 = row.get("id");
         project.set_id(id as u64);
@@ -153,7 +178,20 @@ impl DataStore {
         project.set_plan_path(row.get("plan_path"));
         project.set_vcs_type(row.get("vcs_type"));
         project.set_vcs_data(row.get("vcs_data"));
-        project
+        project.set_cross_origin_rdeps(row.get("cross_origin_rdeps"));
+        let build_timeout: i32 = row.get("build_timeout");
+        project.set_build_timeout(build_timeout as u32);
+        project.set_vcs_ref(row.get("vcs_ref"));
+        let vcs_depth: i32 = row.get("vcs_depth");
+        project.set_vcs_depth(vcs_depth as u32);
+        project.set_vcs_submodules(row.get("vcs_submodules"));
+        let vcs_auth: i16 = row.get("vcs_auth");
+        match originsrv::VcsAuth::from_i32(vcs_auth as i32) {
+            Some(auth) => project.set_vcs_auth(auth),
+            None => return Err(Error::UnknownVcsAuth(vcs_auth)),
+        }
+        project.set_vcs_auth_secret(row.get("vcs_auth_secret"));
+        Ok(project)
     }
 
     pub fn create_origin_project(&self,
@@ -161,16 +199,24 @@ impl DataStore {
                                  -> Result<originsrv::OriginProject> {
         let conn = self.pool.get(opc)?;
         let project = opc.get_project();
-        let rows = conn.query("SELECT * FROM insert_origin_project_v1($1, $2, $3, $4, $5, $6)",
+        let rows = conn.query("SELECT * FROM insert_origin_project_v4($1, $2, $3, $4, $5, $6, $7, \
+                               $8, $9, $10, $11, $12, $13)",
                               &[&project.get_origin_name(),
                                 &project.get_package_name(),
                                 &project.get_plan_path(),
                                 &project.get_vcs_type(),
                                 &project.get_vcs_data(),
-                                &(project.get_owner_id() as i64)])
+                                &(project.get_owner_id() as i64),
+                                &project.get_cross_origin_rdeps(),
+                                &(project.get_build_timeout() as i32),
+                                &project.get_vcs_ref(),
+                                &(project.get_vcs_depth() as i32),
+                                &project.get_vcs_submodules(),
+                                &(project.get_vcs_auth().value() as i16),
+                                &project.get_vcs_auth_secret()])
             .map_err(Error::OriginProjectCreate)?;
         let row = rows.get(0);
-        Ok(self.row_to_origin_project(&row))
+        self.row_to_origin_project(&row)
     }
 
     pub fn check_account_in_origin(&self,
@@ -338,6 +384,61 @@ impl DataStore {
         }
     }
 
+    /// Creates the origin's encryption key unless it already has one, returning the origin's
+    /// key either way.
+    pub fn create_origin_encryption_key(&self,
+                                        oek: &originsrv::OriginEncryptionKeyCreate)
+                                        -> Result<originsrv::OriginEncryptionKey> {
+        let conn = self.pool.get(oek)?;
+        let rows = conn.query("SELECT * FROM insert_origin_encryption_key_v2($1, $2, $3, $4, $5, \
+                               $6, $7)",
+                              &[&(oek.get_origin_id() as i64),
+                                &(oek.get_owner_id() as i64),
+                                &oek.get_name(),
+                                &oek.get_revision(),
+                                &format!("{}-{}", oek.get_name(), oek.get_revision()),
+                                &oek.get_public_body(),
+                                &oek.get_secret_body()])
+            .map_err(Error::OriginEncryptionKeyCreate)?;
+        let row = rows.iter()
+            .nth(0)
+            .expect("Insert returns row, but no row present");
+        Ok(self.row_to_origin_encryption_key(row))
+    }
+
+    fn row_to_origin_encryption_key(&self,
+                                    row: postgres::rows::Row)
+                                    -> originsrv::OriginEncryptionKey {
+        let mut oek = originsrv::OriginEncryptionKey::new();
+        let oek_id: i64 = row.get("id");
+        oek.set_id(oek_id as u64);
+        let oek_origin_id: i64 = row.get("origin_id");
+        oek.set_origin_id(oek_origin_id as u64);
+        oek.set_name(row.get("name"));
+        oek.set_revision(row.get("revision"));
+        oek.set_public_body(row.get("public_body"));
+        oek.set_secret_body(row.get("secret_body"));
+        let oek_owner_id: i64 = row.get("owner_id");
+        oek.set_owner_id(oek_owner_id as u64);
+        oek
+    }
+
+    pub fn get_origin_encryption_key(&self,
+                                     oek_get: &originsrv::OriginEncryptionKeyGet)
+                                     -> Result<Option<originsrv::OriginEncryptionKey>> {
+        let conn = self.pool.get(oek_get)?;
+        let rows = &conn.query("SELECT * FROM get_origin_encryption_key_v1($1)",
+                               &[&(oek_get.get_origin_id() as i64)])
+                        .map_err(Error::OriginEncryptionKeyGet)?;
+        if rows.len() != 0 {
+            // We just checked - we know there is a value here
+            let row = rows.iter().nth(0).unwrap();
+            Ok(Some(self.row_to_origin_encryption_key(row)))
+        } else {
+            Ok(None)
+        }
+    }
+
     pub fn create_origin_public_key(&self,
                                     opk: &originsrv::OriginPublicKeyCreate)
                                     -> Result<originsrv::OriginPublicKey> {
@@ -431,6 +532,7 @@ impl DataStore {
         if let Some(Ok(pk)) = private_key_name {
             origin.set_private_key_name(pk);
         }
+        origin.set_rdeps_builds(row.get("rdeps_builds"));
         origin
     }
 
@@ -465,7 +567,7 @@ impl DataStore {
         origin_get.set_name(origin_name.to_string());
         let conn = self.pool.get(&origin_get)?;
         let rows =
-            &conn.query("SELECT * FROM origins_with_secret_key_full_name_v1 WHERE name = $1 LIMIT \
+            &conn.query("SELECT * FROM origins_with_secret_key_full_name_v2 WHERE name = $1 LIMIT \
                         1",
                         &[&origin_name])
                  .map_err(Error::OriginGet)?;
@@ -481,18 +583,34 @@ impl DataStore {
             if let Some(pk) = private_key_name {
                 origin.set_private_key_name(pk);
             }
+            origin.set_rdeps_builds(row.get("rdeps_builds"));
             Ok(Some(origin))
         } else {
             Ok(None)
         }
     }
 
+    pub fn update_origin(&self,
+                         origin: &originsrv::OriginUpdate)
+                         -> Result<Option<originsrv::Origin>> {
+        let conn = self.pool.get(origin)?;
+        let rows = conn.query("SELECT * FROM update_origin_v1($1, $2)",
+                              &[&origin.get_name(), &origin.get_rdeps_builds()])
+            .map_err(Error::OriginUpdate)?;
+        if rows.len() != 0 {
+            // Re-read through the view so the reply carries the origin's private key name
+            self.get_origin_by_name(origin.get_name())
+        } else {
+            Ok(None)
+        }
+    }
+
     pub fn create_origin_package(&self,
                                  opc: &originsrv::OriginPackageCreate)
                                  -> Result<originsrv::OriginPackage> {
         let conn = self.pool.get(opc)?;
         let ident = opc.get_ident();
-        let rows = conn.query("SELECT * FROM insert_origin_package_v1($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)",
+        let rows = conn.query("SELECT * FROM insert_origin_package_v2($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)",
                               &[&(opc.get_origin_id() as i64),
                                 &(opc.get_owner_id() as i64),
                                 &ident.get_name(),
@@ -503,7 +621,10 @@ impl DataStore {
                                 &opc.get_target(),
                                 &self.into_delimited(opc.get_deps().to_vec()),
                                 &self.into_delimited(opc.get_tdeps().to_vec()),
-                                &self.into_delimited(opc.get_exposes().to_vec())])
+                                &self.into_delimited(opc.get_exposes().to_vec()),
+                                &self.into_delimited(opc.get_binds().to_vec()),
+                                &self.into_delimited(opc.get_binaries().to_vec()),
+                                &opc.get_files().to_vec()])
             .map_err(Error::OriginPackageCreate)?;
 
         self.async.schedule("sync_packages")?;
@@ -569,7 +690,7 @@ impl DataStore {
                                      opc: &originsrv::OriginPackageLatestGet)
                                      -> Result<Option<originsrv::OriginPackageIdent>> {
         let conn = self.pool.get(opc)?;
-        let rows = conn.query("SELECT * FROM get_origin_package_latest_v2($1, $2)",
+        let rows = conn.query("SELECT * FROM get_origin_package_latest_v3($1, $2)",
                               &[&self.searchable_ident(opc.get_ident()), &opc.get_target()])
             .map_err(Error::OriginPackageLatestGet)?;
         if rows.len() != 0 {
@@ -584,7 +705,7 @@ impl DataStore {
                                              ocpg: &originsrv::OriginChannelPackageLatestGet)
                                              -> Result<Option<originsrv::OriginPackageIdent>> {
         let conn = self.pool.get(ocpg)?;
-        let rows = conn.query("SELECT * FROM get_origin_channel_package_latest_v2($1, $2, $3, $4)",
+        let rows = conn.query("SELECT * FROM get_origin_channel_package_latest_v3($1, $2, $3, $4)",
                               &[&ocpg.get_ident().get_origin(),
                                 &ocpg.get_name(),
                                 &self.searchable_ident(ocpg.get_ident()),
@@ -743,35 +864,102 @@ impl DataStore {
 
     pub fn search_origin_package_for_origin(&self,
                                             ops: &originsrv::OriginPackageSearchRequest)
-                                            -> Result<originsrv::OriginPackageListResponse> {
+                                            -> Result<originsrv::OriginPackageSearchResponse> {
         let conn = self.pool.get(ops)?;
 
-        let rows = if *&ops.get_distinct() {
-            conn.query("SELECT * FROM search_all_origin_packages_dynamic_v2($1, $2, $3)",
-                       &[&ops.get_query(), &ops.limit(), &(ops.get_start() as i64)])
-                .map_err(Error::OriginPackageSearch)?
+        let mut response = originsrv::OriginPackageSearchResponse::new();
+        let mut packages = protobuf::RepeatedField::new();
+        response.set_start(ops.get_start());
+
+        if *&ops.get_distinct() {
+            let rows = conn.query("SELECT * FROM search_all_origin_packages_dynamic_v3($1, $2, $3)",
+                                  &[&ops.get_query(), &ops.limit(), &(ops.get_start() as i64)])
+                .map_err(Error::OriginPackageSearch)?;
+            response.set_stop(self.last_index(ops, &rows));
+            for row in rows.iter() {
+                let count: i64 = row.get("total_count");
+                response.set_count(count as u64);
+                let mut result = originsrv::OriginPackageSearchResult::new();
+                result.set_ident(self.row_to_origin_package_ident(&row));
+                packages.push(result);
+            }
         } else {
-            conn.query("SELECT * FROM search_origin_packages_for_origin_v1($1, $2, $3, $4)",
-                       &[&ops.get_origin(),
-                         &ops.get_query(),
-                         &ops.limit(),
-                         &(ops.get_start() as i64)])
-                .map_err(Error::OriginPackageSearch)?
-        };
+            let exposes: Vec<String> = ops.get_exposes().iter().map(|p| p.to_string()).collect();
+            let rows = conn.query("SELECT * FROM search_origin_packages_v4($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)",
+                                  &[&ops.get_origin(),
+                                    &ops.get_query(),
+                                    &ops.get_channel(),
+                                    &ops.get_target(),
+                                    &ops.get_min_version(),
+                                    &ops.get_max_version(),
+                                    &exposes,
+                                    &ops.get_binds().to_vec(),
+                                    &ops.get_binaries().to_vec(),
+                                    &ops.get_file(),
+                                    &ops.limit(),
+                                    &(ops.get_start() as i64)])
+                .map_err(Error::OriginPackageSearch)?;
+            response.set_stop(self.last_index(ops, &rows));
+            for row in rows.iter() {
+                let count: i64 = row.get("total_count");
+                response.set_count(count as u64);
+                packages.push(self.row_to_origin_package_search_result(&row));
+            }
+        }
 
-        let mut response = originsrv::OriginPackageListResponse::new();
-        response.set_start(ops.get_start());
-        response.set_stop(self.last_index(ops, &rows));
-        let mut idents = protobuf::RepeatedField::new();
+        response.set_packages(packages);
+        Ok(response)
+    }
+
+    pub fn set_origin_package_files(&self, opfs: &originsrv::OriginPackageFilesSet) -> Result<()> {
+        let conn = self.pool.get(opfs)?;
+        conn.execute("SELECT set_origin_package_files_v1($1, $2)",
+                     &[&opfs.get_ident().to_string(), &opfs.get_files().to_vec()])
+            .map_err(Error::OriginPackageFilesSet)?;
+        Ok(())
+    }
+
+    pub fn list_origin_packages_unindexed
+        (&self,
+         opful: &originsrv::OriginPackageFilesUnindexedListRequest)
+         -> Result<originsrv::OriginPackageFilesUnindexedListResponse> {
+        let conn = self.pool.get(opful)?;
+        let rows = conn.query("SELECT * FROM get_origin_packages_unindexed_dynamic_v1()", &[])
+            .map_err(Error::OriginPackageFilesUnindexedList)?;
+
+        let mut response = originsrv::OriginPackageFilesUnindexedListResponse::new();
+        let mut packages = protobuf::RepeatedField::new();
         for row in rows.iter() {
-            let count: i64 = row.get("total_count");
-            response.set_count(count as u64);
-            idents.push(self.row_to_origin_package_ident(&row));
+            let mut package = originsrv::OriginPackageFilesUnindexed::new();
+            package.set_ident(self.row_to_origin_package_ident(&row));
+            package.set_target(row.get("target"));
+            packages.push(package);
         }
-        response.set_idents(idents);
+        response.set_packages(packages);
         Ok(response)
     }
 
+    fn row_to_origin_package_search_result(&self,
+                                           row: &postgres::rows::Row)
+                                           -> originsrv::OriginPackageSearchResult {
+        let mut result = originsrv::OriginPackageSearchResult::new();
+        result.set_ident(self.row_to_origin_package_ident(row));
+        result.set_target(row.get("target"));
+        let exposes: Option<String> = row.get("exposes");
+        result.set_exposes(exposes
+                               .unwrap_or_default()
+                               .split(":")
+                               .filter_map(|port| port.parse::<u32>().ok())
+                               .collect());
+        result.set_binds(self.into_delimited_strings(row.get("binds")));
+        result.set_binaries(self.into_delimited_strings(row.get("binaries")));
+        let files: Vec<String> = row.get("files");
+        result.set_files(protobuf::RepeatedField::from_vec(files));
+        let relevance: i32 = row.get("relevance");
+        result.set_relevance(relevance as u32);
+        result
+    }
+
     fn into_delimited<T: Display>(&self, parts: Vec<T>) -> String {
         let mut buffer = String::new();
         for part in parts.iter() {
@@ -780,6 +968,17 @@ impl DataStore {
         buffer
     }
 
+    fn into_delimited_strings(&self,
+                              column: Option<String>)
+                              -> protobuf::RepeatedField<String> {
+        column
+            .unwrap_or_default()
+            .split(":")
+            .filter(|part| !part.is_empty())
+            .map(|part| part.to_string())
+            .collect()
+    }
+
     fn into_idents(&self,
                    column: String)
                    -> protobuf::RepeatedField<originsrv::OriginPackageIdent> {
@@ -817,6 +1016,9 @@ impl DataStore {
         package.set_exposes(exposes);
         package.set_deps(self.into_idents(row.get("deps")));
         package.set_tdeps(self.into_idents(row.get("tdeps")));
+        package.set_yanked(row.get("yanked"));
+        package.set_binds(self.into_delimited_strings(row.get("binds")));
+        package.set_binaries(self.into_delimited_strings(row.get("binaries")));
         package
     }
 
@@ -901,6 +1103,294 @@ impl DataStore {
         Ok(())
     }
 
+    pub fn demote_origin_package(&self, opd: &originsrv::OriginPackageDemote) -> Result<()> {
+        let conn = self.pool.get(opd)?;
+        conn.execute("SELECT demote_origin_package_v1($1, $2, $3, $4, $5)",
+                     &[&(opd.get_channel_id() as i64),
+                       &(opd.get_package_id() as i64),
+                       &(opd.get_requestor_id() as i64),
+                       &opd.get_requestor_name(),
+                       &opd.get_reason()])
+            .map_err(Error::OriginPackageDemote)?;
+        Ok(())
+    }
+
+    pub fn yank_origin_package(&self, opy: &originsrv::OriginPackageYank) -> Result<()> {
+        let conn = self.pool.get(opy)?;
+        conn.execute("SELECT yank_origin_package_v1($1, $2, $3, $4)",
+                     &[&(opy.get_package_id() as i64),
+                       &(opy.get_requestor_id() as i64),
+                       &opy.get_requestor_name(),
+                       &opy.get_reason()])
+            .map_err(Error::OriginPackageYank)?;
+        Ok(())
+    }
+
+    pub fn list_origin_package_audit(&self,
+                                     opal: &originsrv::OriginPackageAuditListRequest)
+                                     -> Result<originsrv::OriginPackageAuditListResponse> {
+        let conn = self.pool.get(opal)?;
+        let rows = &conn.query("SELECT * FROM get_origin_package_audit_v1($1)",
+                               &[&opal.get_ident().to_string()])
+                        .map_err(Error::OriginPackageAuditList)?;
+
+        let mut response = originsrv::OriginPackageAuditListResponse::new();
+        let mut events = protobuf::RepeatedField::new();
+        for row in rows {
+            let mut event = originsrv::OriginPackageAudit::new();
+            let package_id: i64 = row.get("package_id");
+            event.set_package_id(package_id as u64);
+            event.set_ident(opal.get_ident().clone());
+            let operation: i16 = row.get("operation");
+            match originsrv::OriginPackageOperation::from_i32(operation as i32) {
+                Some(operation) => event.set_operation(operation),
+                None => return Err(Error::UnknownOriginPackageOperation(operation)),
+            }
+            if let Some(channel) = row.get::<&str, Option<String>>("channel") {
+                event.set_channel(channel);
+            }
+            let requestor_id: i64 = row.get("requestor_id");
+            event.set_requestor_id(requestor_id as u64);
+            event.set_requestor_name(row.get("requestor_name"));
+            event.set_reason(row.get("reason"));
+            let created_at = row.get::<&str, DateTime<UTC>>("created_at");
+            event.set_created_at(created_at.to_rfc3339());
+            events.push(event);
+        }
+        response.set_events(events);
+        Ok(response)
+    }
+
+    pub fn record_origin_package_download(&self,
+                                          opdr: &originsrv::OriginPackageDownloadRecord)
+                                          -> Result<()> {
+        let conn = self.pool.get(opdr)?;
+        conn.execute("SELECT record_origin_package_download_v1($1)",
+                     &[&(opdr.get_package_id() as i64)])
+            .map_err(Error::OriginPackageDownloadRecord)?;
+        Ok(())
+    }
+
+    pub fn get_origin_package_download_stats(&self,
+                                             opdsg: &originsrv::OriginPackageDownloadStatsGet)
+                                             -> Result<originsrv::OriginPackageDownloadStats> {
+        let conn = self.pool.get(opdsg)?;
+        let rows = &conn.query("SELECT * FROM get_origin_package_downloads_v1($1, $2, $3, $4)",
+                               &[&opdsg.get_ident().to_string(),
+                                 &opdsg.get_target(),
+                                 &opdsg.get_from_day(),
+                                 &opdsg.get_to_day()])
+                        .map_err(Error::OriginPackageDownloadStats)?;
+
+        let mut response = originsrv::OriginPackageDownloadStats::new();
+        response.set_ident(opdsg.get_ident().clone());
+        let mut total = 0;
+        let mut downloads = protobuf::RepeatedField::new();
+        for row in rows {
+            let mut download = originsrv::OriginPackageDownloadCount::new();
+            let ident: String = row.get("ident");
+            download.set_ident(originsrv::OriginPackageIdent::from_str(ident.as_str()).unwrap());
+            download.set_target(row.get("target"));
+            download.set_day(row.get("day"));
+            let count: i64 = row.get("count");
+            download.set_count(count as u64);
+            total += count as u64;
+            downloads.push(download);
+        }
+        response.set_total(total);
+        response.set_downloads(downloads);
+        Ok(response)
+    }
+
+    pub fn create_origin_webhook(&self,
+                                 owc: &originsrv::OriginWebhookCreate)
+                                 -> Result<originsrv::OriginWebhook> {
+        let conn = self.pool.get(owc)?;
+        let events: Vec<i16> = owc.get_events().iter().map(|e| e.value() as i16).collect();
+        let rows = conn.query("SELECT * FROM insert_origin_webhook_v1($1, $2, $3, $4, $5)",
+                              &[&(owc.get_origin_id() as i64),
+                                &owc.get_url(),
+                                &owc.get_secret(),
+                                &events,
+                                &(owc.get_owner_id() as i64)])
+            .map_err(Error::OriginWebhookCreate)?;
+        let row = rows.iter()
+            .nth(0)
+            .expect("Insert returns row, but no row present");
+        Ok(self.row_to_origin_webhook(row))
+    }
+
+    fn row_to_origin_webhook(&self, row: postgres::rows::Row) -> originsrv::OriginWebhook {
+        let mut webhook = originsrv::OriginWebhook::new();
+        let id: i64 = row.get("id");
+        webhook.set_id(id as u64);
+        let origin_id: i64 = row.get("origin_id");
+        webhook.set_origin_id(origin_id as u64);
+        webhook.set_url(row.get("url"));
+        webhook.set_secret(row.get("secret"));
+        let events: Vec<i16> = row.get("events");
+        webhook.set_events(events
+                               .into_iter()
+                               .filter_map(|e| originsrv::OriginWebhookEvent::from_i32(e as i32))
+                               .collect());
+        let owner_id: i64 = row.get("owner_id");
+        webhook.set_owner_id(owner_id as u64);
+        let created_at = row.get::<&str, DateTime<UTC>>("created_at");
+        webhook.set_created_at(created_at.to_rfc3339());
+        webhook
+    }
+
+    pub fn list_origin_webhooks(&self,
+                                owlr: &originsrv::OriginWebhookListRequest)
+                                -> Result<originsrv::OriginWebhookListResponse> {
+        let conn = self.pool.get(owlr)?;
+        let rows = &conn.query("SELECT * FROM get_origin_webhooks_v1($1)",
+                               &[&(owlr.get_origin_id() as i64)])
+                        .map_err(Error::OriginWebhookList)?;
+
+        let mut response = originsrv::OriginWebhookListResponse::new();
+        response.set_origin_id(owlr.get_origin_id());
+        let mut webhooks = protobuf::RepeatedField::new();
+        for row in rows {
+            webhooks.push(self.row_to_origin_webhook(row))
+        }
+        response.set_webhooks(webhooks);
+        Ok(response)
+    }
+
+    pub fn delete_origin_webhook(&self, owd: &originsrv::OriginWebhookDelete) -> Result<()> {
+        let conn = self.pool.get(owd)?;
+        conn.execute("SELECT delete_origin_webhook_v1($1, $2)",
+                     &[&(owd.get_id() as i64), &(owd.get_origin_id() as i64)])
+            .map_err(Error::OriginWebhookDelete)?;
+        Ok(())
+    }
+
+    pub fn create_origin_webhook_delivery(&self,
+                                          owdc: &originsrv::OriginWebhookDeliveryCreate)
+                                          -> Result<originsrv::OriginWebhookDelivery> {
+        let conn = self.pool.get(owdc)?;
+        let rows = conn.query("SELECT * FROM insert_origin_webhook_delivery_v1($1, $2, $3, $4, \
+                               $5, $6, $7, $8)",
+                              &[&(owdc.get_webhook_id() as i64),
+                                &(owdc.get_event().value() as i16),
+                                &owdc.get_delivery_id(),
+                                &owdc.get_payload(),
+                                &(owdc.get_status_code() as i32),
+                                &(owdc.get_attempts() as i32),
+                                &owdc.get_success(),
+                                &owdc.get_error()])
+            .map_err(Error::OriginWebhookDeliveryCreate)?;
+        let row = rows.iter()
+            .nth(0)
+            .expect("Insert returns row, but no row present");
+        Ok(self.row_to_origin_webhook_delivery(row))
+    }
+
+    fn row_to_origin_webhook_delivery(&self,
+                                      row: postgres::rows::Row)
+                                      -> originsrv::OriginWebhookDelivery {
+        let mut delivery = originsrv::OriginWebhookDelivery::new();
+        let id: i64 = row.get("id");
+        delivery.set_id(id as u64);
+        let webhook_id: i64 = row.get("webhook_id");
+        delivery.set_webhook_id(webhook_id as u64);
+        let event: i16 = row.get("event");
+        delivery.set_event(originsrv::OriginWebhookEvent::from_i32(event as i32).unwrap());
+        delivery.set_delivery_id(row.get("delivery_id"));
+        delivery.set_payload(row.get("payload"));
+        let status_code: i32 = row.get("status_code");
+        delivery.set_status_code(status_code as u32);
+        let attempts: i32 = row.get("attempts");
+        delivery.set_attempts(attempts as u32);
+        delivery.set_success(row.get("success"));
+        delivery.set_error(row.get("error"));
+        let created_at = row.get::<&str, DateTime<UTC>>("created_at");
+        delivery.set_created_at(created_at.to_rfc3339());
+        delivery
+    }
+
+    pub fn list_origin_webhook_deliveries(&self,
+                                          owdl: &originsrv::OriginWebhookDeliveryListRequest)
+                                          -> Result<originsrv::OriginWebhookDeliveryListResponse> {
+        let conn = self.pool.get(owdl)?;
+        let rows = &conn.query("SELECT * FROM get_origin_webhook_deliveries_v1($1, $2, $3)",
+                               &[&(owdl.get_webhook_id() as i64),
+                                 &(owdl.get_origin_id() as i64),
+                                 &WEBHOOK_DELIVERY_LOG_LIMIT])
+                        .map_err(Error::OriginWebhookDeliveryList)?;
+
+        let mut response = originsrv::OriginWebhookDeliveryListResponse::new();
+        response.set_webhook_id(owdl.get_webhook_id());
+        let mut deliveries = protobuf::RepeatedField::new();
+        for row in rows {
+            deliveries.push(self.row_to_origin_webhook_delivery(row))
+        }
+        response.set_deliveries(deliveries);
+        Ok(response)
+    }
+
+    pub fn create_origin_secret(&self,
+                                osc: &originsrv::OriginSecretCreate)
+                                -> Result<originsrv::OriginSecret> {
+        let conn = self.pool.get(osc)?;
+        let rows = conn.query("SELECT * FROM insert_origin_secret_v1($1, $2, $3, $4, $5)",
+                              &[&(osc.get_origin_id() as i64),
+                                &(osc.get_project_id() as i64),
+                                &osc.get_name(),
+                                &osc.get_value(),
+                                &(osc.get_owner_id() as i64)])
+            .map_err(Error::OriginSecretCreate)?;
+        let row = rows.iter()
+            .nth(0)
+            .expect("Insert returns row, but no row present");
+        Ok(self.row_to_origin_secret(row))
+    }
+
+    fn row_to_origin_secret(&self, row: postgres::rows::Row) -> originsrv::OriginSecret {
+        let mut secret = originsrv::OriginSecret::new();
+        let id: i64 = row.get("id");
+        secret.set_id(id as u64);
+        let origin_id: i64 = row.get("origin_id");
+        secret.set_origin_id(origin_id as u64);
+        let project_id: i64 = row.get("project_id");
+        secret.set_project_id(project_id as u64);
+        secret.set_name(row.get("name"));
+        secret.set_value(row.get("value"));
+        let owner_id: i64 = row.get("owner_id");
+        secret.set_owner_id(owner_id as u64);
+        let created_at = row.get::<&str, DateTime<UTC>>("created_at");
+        secret.set_created_at(created_at.to_rfc3339());
+        secret
+    }
+
+    pub fn list_origin_secrets(&self,
+                               oslr: &originsrv::OriginSecretListRequest)
+                               -> Result<originsrv::OriginSecretListResponse> {
+        let conn = self.pool.get(oslr)?;
+        let rows = &conn.query("SELECT * FROM get_origin_secrets_v1($1, $2)",
+                               &[&(oslr.get_origin_id() as i64),
+                                 &(oslr.get_project_id() as i64)])
+                        .map_err(Error::OriginSecretList)?;
+
+        let mut response = originsrv::OriginSecretListResponse::new();
+        response.set_origin_id(oslr.get_origin_id());
+        let mut secrets = protobuf::RepeatedField::new();
+        for row in rows {
+            secrets.push(self.row_to_origin_secret(row))
+        }
+        response.set_secrets(secrets);
+        Ok(response)
+    }
+
+    pub fn delete_origin_secret(&self, osd: &originsrv::OriginSecretDelete) -> Result<()> {
+        let conn = self.pool.get(osd)?;
+        conn.execute("SELECT delete_origin_secret_v1($1, $2)",
+                     &[&(osd.get_id() as i64), &(osd.get_origin_id() as i64)])
+            .map_err(Error::OriginSecretDelete)?;
+        Ok(())
+    }
+
     pub fn delete_origin_channel_by_id(&self, ocd: &originsrv::OriginChannelDelete) -> Result<()> {
         let conn = self.pool.get(ocd)?;
         conn.execute("SELECT delete_origin_channel_v1($1)",
@@ -53,7 +53,13 @@ pub enum Error {
     OriginInvitationListForOrigin(postgres::error::Error),
     OriginInvitationListForAccount(postgres::error::Error),
     OriginInvitationValidate(postgres::error::Error),
+    OriginPackageAuditList(postgres::error::Error),
     OriginPackageCreate(postgres::error::Error),
+    OriginPackageDemote(postgres::error::Error),
+    OriginPackageDownloadRecord(postgres::error::Error),
+    OriginPackageDownloadStats(postgres::error::Error),
+    OriginPackageFilesSet(postgres::error::Error),
+    OriginPackageFilesUnindexedList(postgres::error::Error),
     OriginPackageGet(postgres::error::Error),
     OriginPackageLatestGet(postgres::error::Error),
     OriginPackageList(postgres::error::Error),
@@ -61,10 +67,16 @@ pub enum Error {
     OriginPackagePromote(postgres::error::Error),
     OriginPackageSearch(postgres::error::Error),
     OriginPackageUniqueList(postgres::error::Error),
+    OriginPackageYank(postgres::error::Error),
     OriginProjectCreate(postgres::error::Error),
     OriginProjectDelete(postgres::error::Error),
     OriginProjectGet(postgres::error::Error),
     OriginProjectUpdate(postgres::error::Error),
+    OriginEncryptionKeyCreate(postgres::error::Error),
+    OriginEncryptionKeyGet(postgres::error::Error),
+    OriginSecretCreate(postgres::error::Error),
+    OriginSecretDelete(postgres::error::Error),
+    OriginSecretList(postgres::error::Error),
     OriginSecretKeyCreate(postgres::error::Error),
     OriginSecretKeyGet(postgres::error::Error),
     OriginPublicKeyCreate(postgres::error::Error),
@@ -73,8 +85,16 @@ pub enum Error {
     OriginPublicKeyListForOrigin(postgres::error::Error),
     OriginAccountList(postgres::error::Error),
     OriginAccountInOrigin(postgres::error::Error),
+    OriginUpdate(postgres::error::Error),
+    OriginWebhookCreate(postgres::error::Error),
+    OriginWebhookDelete(postgres::error::Error),
+    OriginWebhookDeliveryCreate(postgres::error::Error),
+    OriginWebhookDeliveryList(postgres::error::Error),
+    OriginWebhookList(postgres::error::Error),
     SyncInvitations(postgres::error::Error),
     SyncInvitationsUpdate(postgres::error::Error),
+    UnknownOriginPackageOperation(i16),
+    UnknownVcsAuth(i16),
     Protobuf(protobuf::ProtobufError),
     Zmq(zmq::Error),
 }
@@ -147,9 +167,29 @@ impl fmt::Display for Error {
                 format!("Error validating origin invitation for an account in database, {}",
                         e)
             }
+            Error::OriginPackageAuditList(ref e) => {
+                format!("Error getting audit trail of package in database, {}", e)
+            }
             Error::OriginPackageCreate(ref e) => {
                 format!("Error creating package in database, {}", e)
             }
+            Error::OriginPackageDemote(ref e) => {
+                format!("Error demoting package from channel, {}", e)
+            }
+            Error::OriginPackageDownloadRecord(ref e) => {
+                format!("Error recording package download in database, {}", e)
+            }
+            Error::OriginPackageDownloadStats(ref e) => {
+                format!("Error getting package download statistics from database, {}",
+                        e)
+            }
+            Error::OriginPackageFilesSet(ref e) => {
+                format!("Error indexing package files in database, {}", e)
+            }
+            Error::OriginPackageFilesUnindexedList(ref e) => {
+                format!("Error getting packages without indexed files from database, {}",
+                        e)
+            }
             Error::OriginPackageGet(ref e) => format!("Error getting package in database, {}", e),
             Error::OriginPackageLatestGet(ref e) => {
                 format!("Error getting latest package in database, {}", e)
@@ -171,6 +211,7 @@ impl fmt::Display for Error {
                 format!("Error getting unique list of packages for this origin, {}",
                         e)
             }
+            Error::OriginPackageYank(ref e) => format!("Error yanking package in database, {}", e),
             Error::OriginProjectCreate(ref e) => {
                 format!("Error creating project in database, {}", e)
             }
@@ -181,6 +222,21 @@ impl fmt::Display for Error {
             Error::OriginProjectUpdate(ref e) => {
                 format!("Error updating project in database, {}", e)
             }
+            Error::OriginEncryptionKeyCreate(ref e) => {
+                format!("Error creating origin encryption key in database, {}", e)
+            }
+            Error::OriginEncryptionKeyGet(ref e) => {
+                format!("Error getting origin encryption key from database, {}", e)
+            }
+            Error::OriginSecretCreate(ref e) => {
+                format!("Error creating origin secret in database, {}", e)
+            }
+            Error::OriginSecretDelete(ref e) => {
+                format!("Error deleting origin secret in database, {}", e)
+            }
+            Error::OriginSecretList(ref e) => {
+                format!("Error listing origin secrets from database, {}", e)
+            }
             Error::OriginSecretKeyCreate(ref e) => {
                 format!("Error creating origin secret key in database, {}", e)
             }
@@ -207,12 +263,32 @@ impl fmt::Display for Error {
             Error::OriginAccountInOrigin(ref e) => {
                 format!("Error checking if this account is in an origin, {}", e)
             }
+            Error::OriginUpdate(ref e) => format!("Error updating origin in database, {}", e),
+            Error::OriginWebhookCreate(ref e) => {
+                format!("Error creating webhook in database, {}", e)
+            }
+            Error::OriginWebhookDelete(ref e) => {
+                format!("Error deleting webhook in database, {}", e)
+            }
+            Error::OriginWebhookDeliveryCreate(ref e) => {
+                format!("Error recording webhook delivery in database, {}", e)
+            }
+            Error::OriginWebhookDeliveryList(ref e) => {
+                format!("Error listing webhook deliveries from database, {}", e)
+            }
+            Error::OriginWebhookList(ref e) => {
+                format!("Error listing webhooks for an origin from database, {}", e)
+            }
             Error::SyncInvitations(ref e) => {
                 format!("Error syncing invitations for account, {}", e)
             }
             Error::SyncInvitationsUpdate(ref e) => {
                 format!("Error update invitation sync for account, {}", e)
             }
+            Error::UnknownOriginPackageOperation(ref e) => {
+                format!("Unknown package operation {} in audit trail", e)
+            }
+            Error::UnknownVcsAuth(ref e) => format!("Unknown VCS authentication {} for project", e),
             Error::Protobuf(ref e) => format!("{}", e),
             Error::Zmq(ref e) => format!("{}", e),
         };
@@ -248,7 +324,13 @@ impl error::Error for Error {
             Error::OriginInvitationListForOrigin(ref err) => err.description(),
             Error::OriginInvitationListForAccount(ref err) => err.description(),
             Error::OriginInvitationValidate(ref err) => err.description(),
+            Error::OriginPackageAuditList(ref err) => err.description(),
             Error::OriginPackageCreate(ref err) => err.description(),
+            Error::OriginPackageDemote(ref err) => err.description(),
+            Error::OriginPackageDownloadRecord(ref err) => err.description(),
+            Error::OriginPackageDownloadStats(ref err) => err.description(),
+            Error::OriginPackageFilesSet(ref err) => err.description(),
+            Error::OriginPackageFilesUnindexedList(ref err) => err.description(),
             Error::OriginPackageGet(ref err) => err.description(),
             Error::OriginPackageLatestGet(ref err) => err.description(),
             Error::OriginPackageList(ref err) => err.description(),
@@ -256,10 +338,16 @@ impl error::Error for Error {
             Error::OriginPackagePromote(ref err) => err.description(),
             Error::OriginPackageSearch(ref err) => err.description(),
             Error::OriginPackageUniqueList(ref err) => err.description(),
+            Error::OriginPackageYank(ref err) => err.description(),
             Error::OriginProjectCreate(ref err) => err.description(),
             Error::OriginProjectDelete(ref err) => err.description(),
             Error::OriginProjectGet(ref err) => err.description(),
             Error::OriginProjectUpdate(ref err) => err.description(),
+            Error::OriginEncryptionKeyCreate(ref err) => err.description(),
+            Error::OriginEncryptionKeyGet(ref err) => err.description(),
+            Error::OriginSecretCreate(ref err) => err.description(),
+            Error::OriginSecretDelete(ref err) => err.description(),
+            Error::OriginSecretList(ref err) => err.description(),
             Error::OriginSecretKeyCreate(ref err) => err.description(),
             Error::OriginSecretKeyGet(ref err) => err.description(),
             Error::OriginPublicKeyCreate(ref err) => err.description(),
@@ -268,8 +356,16 @@ impl error::Error for Error {
             Error::OriginPublicKeyListForOrigin(ref err) => err.description(),
             Error::OriginAccountList(ref err) => err.description(),
             Error::OriginAccountInOrigin(ref err) => err.description(),
+            Error::OriginUpdate(ref err) => err.description(),
+            Error::OriginWebhookCreate(ref err) => err.description(),
+            Error::OriginWebhookDelete(ref err) => err.description(),
+            Error::OriginWebhookDeliveryCreate(ref err) => err.description(),
+            Error::OriginWebhookDeliveryList(ref err) => err.description(),
+            Error::OriginWebhookList(ref err) => err.description(),
             Error::SyncInvitations(ref err) => err.description(),
             Error::SyncInvitationsUpdate(ref err) => err.description(),
+            Error::UnknownOriginPackageOperation(_) => "Unknown package operation in audit trail",
+            Error::UnknownVcsAuth(_) => "Unknown VCS authentication for project",
             Error::Protobuf(ref err) => err.description(),
             Error::Zmq(ref err) => err.description(),
         }
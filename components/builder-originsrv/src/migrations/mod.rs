@@ -20,3 +20,14 @@ pub mod origin_invitations;
 pub mod origin_projects;
 pub mod origin_packages;
 pub mod origin_channels;
+pub mod origin_package_audit;
+pub mod origin_webhooks;
+pub mod origin_rdeps;
+pub mod origin_package_search;
+pub mod origin_package_downloads;
+pub mod origin_project_limits;
+pub mod origin_secrets;
+pub mod origin_project_vcs;
+pub mod origin_package_yanked;
+pub mod origin_encryption_key_create;
+pub mod origin_package_file_index;
@@ -0,0 +1,47 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use db::migration::Migrator;
+
+use error::Result;
+
+// An origin has a single encryption key, created along with its first secret. Creating it locks
+// the origin's row so that concurrent creations are serialized, and every creator gets back the
+// key which won.
+pub fn migrate(migrator: &mut Migrator) -> Result<()> {
+    migrator.migrate("originsrv-v14",
+                     r#"CREATE OR REPLACE FUNCTION insert_origin_encryption_key_v2 (
+                    oek_origin_id bigint,
+                    oek_owner_id bigint,
+                    oek_name text,
+                    oek_revision text,
+                    oek_full_name text,
+                    oek_public_body bytea,
+                    oek_secret_body bytea
+                 ) RETURNS SETOF origin_encryption_keys AS $$
+                    BEGIN
+                        PERFORM 1 FROM origins WHERE id = oek_origin_id FOR UPDATE;
+                        IF NOT EXISTS (SELECT 1 FROM origin_encryption_keys WHERE origin_id = oek_origin_id) THEN
+                            INSERT INTO origin_encryption_keys (origin_id, owner_id, name, revision, full_name, public_body, secret_body)
+                                VALUES (oek_origin_id, oek_owner_id, oek_name, oek_revision, oek_full_name, oek_public_body, oek_secret_body);
+                        END IF;
+                        RETURN QUERY SELECT * FROM origin_encryption_keys
+                          WHERE origin_id = oek_origin_id
+                          ORDER BY full_name DESC
+                          LIMIT 1;
+                        RETURN;
+                    END
+                 $$ LANGUAGE plpgsql VOLATILE"#)?;
+    Ok(())
+}
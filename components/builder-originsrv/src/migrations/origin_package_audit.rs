@@ -0,0 +1,120 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use db::migration::Migrator;
+
+use error::Result;
+
+pub fn migrate(migrator: &mut Migrator) -> Result<()> {
+    migrator
+        .migrate("originsrv-v5",
+                 r#"CREATE SEQUENCE IF NOT EXISTS origin_package_audit_id_seq;"#)?;
+    migrator
+        .migrate("originsrv-v5",
+                 r#"ALTER TABLE origin_packages ADD COLUMN yanked bool DEFAULT false"#)?;
+    migrator
+        .migrate("originsrv-v5",
+                 r#"CREATE TABLE origin_package_audit (
+                    id bigint PRIMARY KEY DEFAULT next_id_v1('origin_package_audit_id_seq'),
+                    package_id bigint REFERENCES origin_packages(id),
+                    operation smallint,
+                    channel text,
+                    requestor_id bigint,
+                    requestor_name text,
+                    reason text,
+                    created_at timestamptz DEFAULT now()
+             )"#)?;
+    migrator
+        .migrate("originsrv-v5",
+                 r#"CREATE OR REPLACE FUNCTION demote_origin_package_v1 (
+                    opd_channel_id bigint,
+                    opd_package_id bigint,
+                    opd_requestor_id bigint,
+                    opd_requestor_name text,
+                    opd_reason text
+                 ) RETURNS void AS $$
+                    BEGIN
+                        DELETE FROM origin_channel_packages WHERE channel_id = opd_channel_id AND package_id = opd_package_id;
+                        IF FOUND THEN
+                            INSERT INTO origin_package_audit (package_id, operation, channel, requestor_id, requestor_name, reason)
+                                SELECT opd_package_id, 0, name, opd_requestor_id, opd_requestor_name, opd_reason
+                                FROM origin_channels WHERE id = opd_channel_id;
+                        END IF;
+                    END
+                 $$ LANGUAGE plpgsql VOLATILE"#)?;
+    migrator
+        .migrate("originsrv-v5",
+                 r#"CREATE OR REPLACE FUNCTION yank_origin_package_v1 (
+                    opy_package_id bigint,
+                    opy_requestor_id bigint,
+                    opy_requestor_name text,
+                    opy_reason text
+                 ) RETURNS void AS $$
+                    BEGIN
+                        UPDATE origin_packages SET yanked = true, updated_at = now() WHERE id = opy_package_id AND yanked = false;
+                        IF FOUND THEN
+                            INSERT INTO origin_package_audit (package_id, operation, requestor_id, requestor_name, reason)
+                                VALUES (opy_package_id, 1, opy_requestor_id, opy_requestor_name, opy_reason);
+                        END IF;
+                    END
+                 $$ LANGUAGE plpgsql VOLATILE"#)?;
+    migrator
+        .migrate("originsrv-v5",
+                 r#"CREATE OR REPLACE FUNCTION get_origin_package_audit_v1 (
+                    op_ident text
+                 ) RETURNS SETOF origin_package_audit AS $$
+                    BEGIN
+                        RETURN QUERY SELECT opa.*
+                          FROM origin_package_audit opa
+                          INNER JOIN origin_packages op ON op.id = opa.package_id
+                          WHERE op.ident = op_ident
+                          ORDER BY opa.created_at ASC;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#)?;
+    migrator
+        .migrate("originsrv-v5",
+                 r#"CREATE OR REPLACE FUNCTION get_origin_package_latest_v3 (
+                    op_ident text,
+                    op_target text
+                 ) RETURNS SETOF origin_packages AS $$
+                    BEGIN
+                        RETURN QUERY SELECT * FROM origin_packages WHERE ident LIKE (op_ident  || '%') AND target = op_target AND yanked = false;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#)?;
+    migrator
+        .migrate("originsrv-v5",
+                 r#"CREATE OR REPLACE FUNCTION get_origin_channel_package_latest_v3 (
+                    op_origin text,
+                    op_channel text,
+                    op_ident text,
+                    op_target text
+                 ) RETURNS SETOF origin_packages AS $$
+                    BEGIN
+                        RETURN QUERY SELECT op.*
+                          FROM origin_packages op
+                          INNER JOIN origin_channel_packages ocp on ocp.package_id = op.id
+                          INNER JOIN origin_channels oc on ocp.channel_id = oc.id
+                          INNER JOIN origins o on oc.origin_id = o.id
+                          WHERE o.name = op_origin
+                          AND oc.name = op_channel
+                          AND op.ident LIKE (op_ident  || '%')
+                          AND op.target = op_target
+                          AND op.yanked = false;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#)?;
+    Ok(())
+}
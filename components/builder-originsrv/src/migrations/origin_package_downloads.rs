@@ -0,0 +1,63 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use db::migration::Migrator;
+
+use error::Result;
+
+pub fn migrate(migrator: &mut Migrator) -> Result<()> {
+    migrator
+        .migrate("originsrv-v9",
+                 r#"CREATE TABLE origin_package_downloads (
+                    package_id bigint REFERENCES origin_packages(id) ON DELETE CASCADE,
+                    day date,
+                    count bigint DEFAULT 0,
+                    PRIMARY KEY (package_id, day)
+             )"#)?;
+    migrator
+        .migrate("originsrv-v9",
+                 r#"CREATE OR REPLACE FUNCTION record_origin_package_download_v1 (
+                    opd_package_id bigint
+                 ) RETURNS void AS $$
+                    BEGIN
+                        INSERT INTO origin_package_downloads (package_id, day, count)
+                            VALUES (opd_package_id, (now() AT TIME ZONE 'UTC')::date, 1)
+                            ON CONFLICT ON CONSTRAINT origin_package_downloads_pkey
+                            DO UPDATE SET count = origin_package_downloads.count + 1;
+                    END
+                 $$ LANGUAGE plpgsql VOLATILE"#)?;
+    // An identifier matches itself and every more qualified identifier below it, so core/redis
+    // covers all of its releases but not those of core/redis-sentinel.
+    migrator
+        .migrate("originsrv-v9",
+                 r#"CREATE OR REPLACE FUNCTION get_origin_package_downloads_v1 (
+                    op_ident text,
+                    op_target text,
+                    op_from_day text,
+                    op_to_day text
+                 ) RETURNS TABLE(ident text, target text, day text, count bigint) AS $$
+                    BEGIN
+                        RETURN QUERY SELECT op.ident, op.target, to_char(opd.day, 'YYYY-MM-DD'), opd.count
+                          FROM origin_package_downloads opd
+                          INNER JOIN origin_packages op ON op.id = opd.package_id
+                          WHERE (op.ident = op_ident OR op.ident LIKE (op_ident || '/%'))
+                          AND (op_target = '' OR op.target = op_target)
+                          AND (op_from_day = '' OR opd.day >= op_from_day::date)
+                          AND (op_to_day = '' OR opd.day <= op_to_day::date)
+                          ORDER BY opd.day ASC, op.ident ASC;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#)?;
+    Ok(())
+}
@@ -0,0 +1,134 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use db::migration::Migrator;
+
+use error::Result;
+
+// Files are searched by their base name first, which is indexed, before matching the rest of the
+// path. Packages uploaded before files were indexed have none, and are listed so that the depot
+// can index them from their artifacts.
+pub fn migrate(migrator: &mut Migrator) -> Result<()> {
+    migrator
+        .migrate("originsrv-v15",
+                 r#"ALTER TABLE origin_package_files ADD COLUMN basename text"#)?;
+    migrator.migrate("originsrv-v15",
+                     r#"UPDATE origin_package_files
+                    SET basename = regexp_replace(path, '^.*/', '')"#)?;
+    migrator.migrate("originsrv-v15",
+                     r#"CREATE INDEX origin_package_files_basename
+                    ON origin_package_files(basename)"#)?;
+    migrator.migrate("originsrv-v15",
+                     r#"CREATE OR REPLACE FUNCTION origin_package_files_basename_v1()
+                    RETURNS trigger AS $$
+                    BEGIN
+                        NEW.basename := regexp_replace(NEW.path, '^.*/', '');
+                        RETURN NEW;
+                    END
+                    $$ LANGUAGE plpgsql"#)?;
+    migrator.migrate("originsrv-v15",
+                     r#"CREATE TRIGGER origin_package_files_basename
+                    BEFORE INSERT OR UPDATE OF path ON origin_package_files
+                    FOR EACH ROW EXECUTE PROCEDURE origin_package_files_basename_v1()"#)?;
+    // Wildcards in the searched path are escaped so that they only match themselves
+    migrator.migrate("originsrv-v15",
+                     r#"CREATE OR REPLACE FUNCTION origin_package_file_matches_v1 (
+                    f_path text,
+                    f_basename text,
+                    op_file text
+                 ) RETURNS boolean AS $$
+                    SELECT f_basename = regexp_replace(op_file, '^.*/', '')
+                       AND (f_path = op_file
+                            OR f_path LIKE ('%/' || replace(replace(replace(ltrim(op_file, '/'), '\', '\\'), '%', '\%'), '_', '\_')))
+                 $$ LANGUAGE sql IMMUTABLE"#)?;
+    migrator.migrate("originsrv-v15",
+                     r#"CREATE OR REPLACE FUNCTION search_origin_packages_v4 (
+                   op_origin text,
+                   op_query text,
+                   op_channel text,
+                   op_target text,
+                   op_min_version text,
+                   op_max_version text,
+                   op_exposes text[],
+                   op_binds text[],
+                   op_binaries text[],
+                   op_file text,
+                   op_limit bigint,
+                   op_offset bigint
+                 ) RETURNS TABLE(total_count bigint, ident text, target text, exposes text, binds text, binaries text, files text[], relevance integer) AS $$
+                    BEGIN
+                        RETURN QUERY SELECT COUNT(*) OVER () AS total_count, p.ident, p.target, p.exposes, p.binds, p.binaries, p.files, p.relevance
+                          FROM (SELECT op.ident, op.name, op.target, op.exposes, op.binds, op.binaries,
+                                       origin_package_version_parts_v1(split_part(op.ident, '/', 3)) AS version_parts,
+                                       split_part(op.ident, '/', 4) AS release,
+                                       CASE WHEN op_file = '' THEN ARRAY[]::text[]
+                                            ELSE ARRAY(SELECT f.path FROM origin_package_files f
+                                                        WHERE f.package_id = op.id AND origin_package_file_matches_v1(f.path, f.basename, op_file)
+                                                        ORDER BY f.path)
+                                       END AS files,
+                                       CASE WHEN op_query = '' THEN 0
+                                            WHEN op.name = op_query THEN 100
+                                            WHEN op.name LIKE (op_query || '%') THEN 75
+                                            ELSE 50
+                                       END AS relevance
+                                  FROM origins o INNER JOIN origin_packages op ON o.id = op.origin_id
+                                 WHERE o.name = op_origin
+                                   AND op.yanked = false
+                                   AND op.name LIKE ('%' || op_query || '%')
+                                   AND (op_target = '' OR op.target = op_target)
+                                   AND (op_channel = '' OR EXISTS (SELECT 1 FROM origin_channels oc
+                                                                    INNER JOIN origin_channel_packages ocp ON oc.id = ocp.channel_id
+                                                                    WHERE oc.origin_id = op.origin_id AND oc.name = op_channel AND ocp.package_id = op.id))
+                                   AND (op_min_version = '' OR origin_package_version_parts_v1(split_part(op.ident, '/', 3)) >= origin_package_version_parts_v1(op_min_version))
+                                   AND (op_max_version = '' OR origin_package_version_parts_v1(split_part(op.ident, '/', 3)) < origin_package_version_parts_v1(op_max_version))
+                                   AND string_to_array(COALESCE(op.exposes, ''), ':') @> op_exposes
+                                   AND string_to_array(COALESCE(op.binds, ''), ':') @> op_binds
+                                   AND string_to_array(COALESCE(op.binaries, ''), ':') @> op_binaries
+                                   AND (op_file = '' OR op.id IN (SELECT f.package_id FROM origin_package_files f
+                                                                   WHERE origin_package_file_matches_v1(f.path, f.basename, op_file)))
+                               ) AS p
+                          ORDER BY p.relevance DESC, p.name ASC, p.version_parts DESC, p.release DESC
+                          LIMIT op_limit OFFSET op_offset;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#)?;
+    migrator.migrate("originsrv-v15",
+                     r#"CREATE OR REPLACE FUNCTION get_origin_packages_unindexed_dynamic_v1()
+                    RETURNS TABLE(ident text, target text) AS $$
+                    DECLARE
+                      schema RECORD;
+                    BEGIN
+                      FOR schema IN EXECUTE
+                        format(
+                          'SELECT schema_name FROM information_schema.schemata WHERE left(schema_name, 6) = %L',
+                          'shard_'
+                        )
+                      LOOP
+                        RETURN QUERY EXECUTE
+                        format('SELECT op.ident, op.target FROM %I.origin_packages op WHERE NOT EXISTS (SELECT 1 FROM %I.origin_package_files f WHERE f.package_id = op.id) ORDER BY op.ident', schema.schema_name, schema.schema_name);
+                      END LOOP;
+                    END;
+                    $$ LANGUAGE plpgsql STABLE"#)?;
+    migrator.migrate("originsrv-v15",
+                     r#"CREATE OR REPLACE FUNCTION set_origin_package_files_v1 (
+                    op_ident text,
+                    op_files text[]
+                 ) RETURNS void AS $$
+                    INSERT INTO origin_package_files (package_id, path)
+                           SELECT DISTINCT op.id, f.path FROM origin_packages op, unnest(op_files) AS f(path)
+                            WHERE op.ident = op_ident
+                              AND NOT EXISTS (SELECT 1 FROM origin_package_files pf WHERE pf.package_id = op.id AND pf.path = f.path)
+                 $$ LANGUAGE sql VOLATILE"#)?;
+    Ok(())
+}
@@ -0,0 +1,129 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use db::migration::Migrator;
+
+use error::Result;
+
+pub fn migrate(migrator: &mut Migrator) -> Result<()> {
+    migrator
+        .migrate("originsrv-v8",
+                 r#"ALTER TABLE origin_packages ADD COLUMN binds text"#)?;
+    migrator
+        .migrate("originsrv-v8",
+                 r#"ALTER TABLE origin_packages ADD COLUMN binaries text"#)?;
+    migrator
+        .migrate("originsrv-v8",
+                 r#"CREATE TABLE origin_package_files (
+                    package_id bigint REFERENCES origin_packages(id) ON DELETE CASCADE,
+                    path text,
+                    PRIMARY KEY (package_id, path)
+             )"#)?;
+    // Versions are compared by their numeric components so that 1.10.0 sorts after 1.9.0. Any
+    // component without a leading number compares as 0.
+    migrator
+        .migrate("originsrv-v8",
+                 r#"CREATE OR REPLACE FUNCTION origin_package_version_parts_v1 (
+                    op_version text
+                 ) RETURNS bigint[] AS $$
+                    SELECT COALESCE(array_agg(COALESCE(NULLIF(substring(v.part FROM '^[0-9]+'), '')::bigint, 0) ORDER BY v.n), ARRAY[]::bigint[])
+                      FROM unnest(string_to_array(op_version, '.')) WITH ORDINALITY AS v(part, n)
+                 $$ LANGUAGE sql IMMUTABLE"#)?;
+    migrator.migrate("originsrv-v8",
+                 r#"CREATE OR REPLACE FUNCTION insert_origin_package_v2 (
+                    op_origin_id bigint,
+                    op_owner_id bigint,
+                    op_name text,
+                    op_ident text,
+                    op_checksum text,
+                    op_manifest text,
+                    op_config text,
+                    op_target text,
+                    op_deps text,
+                    op_tdeps text,
+                    op_exposes text,
+                    op_binds text,
+                    op_binaries text,
+                    op_files text[]
+                 ) RETURNS SETOF origin_packages AS $$
+                     DECLARE
+                        inserted_package origin_packages;
+                        channel_id bigint;
+                     BEGIN
+                         INSERT INTO origin_packages (origin_id, owner_id, name, ident, checksum, manifest, config, target, deps, tdeps, exposes, binds, binaries)
+                                VALUES (op_origin_id, op_owner_id, op_name, op_ident, op_checksum, op_manifest, op_config, op_target, op_deps, op_tdeps, op_exposes, op_binds, op_binaries)
+                                RETURNING * into inserted_package;
+
+                         INSERT INTO origin_package_files (package_id, path)
+                                SELECT DISTINCT inserted_package.id, f.path FROM unnest(op_files) AS f(path);
+
+                         SELECT id FROM origin_channels WHERE origin_id = op_origin_id AND name = 'unstable' INTO channel_id;
+                         PERFORM promote_origin_package_v1(channel_id, inserted_package.id);
+
+                         RETURN NEXT inserted_package;
+                         RETURN;
+                     END
+                 $$ LANGUAGE plpgsql VOLATILE"#)?;
+    migrator.migrate("originsrv-v8",
+                     r#"CREATE OR REPLACE FUNCTION search_origin_packages_v2 (
+                   op_origin text,
+                   op_query text,
+                   op_channel text,
+                   op_target text,
+                   op_min_version text,
+                   op_max_version text,
+                   op_exposes text[],
+                   op_binds text[],
+                   op_binaries text[],
+                   op_file text,
+                   op_limit bigint,
+                   op_offset bigint
+                 ) RETURNS TABLE(total_count bigint, ident text, target text, exposes text, binds text, binaries text, files text[], relevance integer) AS $$
+                    BEGIN
+                        RETURN QUERY SELECT COUNT(*) OVER () AS total_count, p.ident, p.target, p.exposes, p.binds, p.binaries, p.files, p.relevance
+                          FROM (SELECT op.ident, op.name, op.target, op.exposes, op.binds, op.binaries,
+                                       origin_package_version_parts_v1(split_part(op.ident, '/', 3)) AS version_parts,
+                                       split_part(op.ident, '/', 4) AS release,
+                                       CASE WHEN op_file = '' THEN ARRAY[]::text[]
+                                            ELSE ARRAY(SELECT f.path FROM origin_package_files f
+                                                        WHERE f.package_id = op.id AND (f.path = op_file OR f.path LIKE ('%/' || ltrim(op_file, '/')))
+                                                        ORDER BY f.path)
+                                       END AS files,
+                                       CASE WHEN op_query = '' THEN 0
+                                            WHEN op.name = op_query THEN 100
+                                            WHEN op.name LIKE (op_query || '%') THEN 75
+                                            ELSE 50
+                                       END AS relevance
+                                  FROM origins o INNER JOIN origin_packages op ON o.id = op.origin_id
+                                 WHERE o.name = op_origin
+                                   AND op.name LIKE ('%' || op_query || '%')
+                                   AND (op_target = '' OR op.target = op_target)
+                                   AND (op_channel = '' OR EXISTS (SELECT 1 FROM origin_channels oc
+                                                                    INNER JOIN origin_channel_packages ocp ON oc.id = ocp.channel_id
+                                                                    WHERE oc.origin_id = op.origin_id AND oc.name = op_channel AND ocp.package_id = op.id))
+                                   AND (op_min_version = '' OR origin_package_version_parts_v1(split_part(op.ident, '/', 3)) >= origin_package_version_parts_v1(op_min_version))
+                                   AND (op_max_version = '' OR origin_package_version_parts_v1(split_part(op.ident, '/', 3)) < origin_package_version_parts_v1(op_max_version))
+                                   AND string_to_array(COALESCE(op.exposes, ''), ':') @> op_exposes
+                                   AND string_to_array(COALESCE(op.binds, ''), ':') @> op_binds
+                                   AND string_to_array(COALESCE(op.binaries, ''), ':') @> op_binaries
+                                   AND (op_file = '' OR EXISTS (SELECT 1 FROM origin_package_files f
+                                                                 WHERE f.package_id = op.id AND (f.path = op_file OR f.path LIKE ('%/' || ltrim(op_file, '/')))))
+                               ) AS p
+                          ORDER BY p.relevance DESC, p.name ASC, p.version_parts DESC, p.release DESC
+                          LIMIT op_limit OFFSET op_offset;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#)?;
+    Ok(())
+}
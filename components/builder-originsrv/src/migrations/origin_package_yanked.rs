@@ -0,0 +1,94 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use db::migration::Migrator;
+
+use error::Result;
+
+// Yanked packages are left out of search results, the same way they are left out of `latest`
+// resolution. They can still be fetched by their fully qualified identifier.
+pub fn migrate(migrator: &mut Migrator) -> Result<()> {
+    migrator.migrate("originsrv-v13",
+                     r#"CREATE OR REPLACE FUNCTION search_origin_packages_v3 (
+                   op_origin text,
+                   op_query text,
+                   op_channel text,
+                   op_target text,
+                   op_min_version text,
+                   op_max_version text,
+                   op_exposes text[],
+                   op_binds text[],
+                   op_binaries text[],
+                   op_file text,
+                   op_limit bigint,
+                   op_offset bigint
+                 ) RETURNS TABLE(total_count bigint, ident text, target text, exposes text, binds text, binaries text, files text[], relevance integer) AS $$
+                    BEGIN
+                        RETURN QUERY SELECT COUNT(*) OVER () AS total_count, p.ident, p.target, p.exposes, p.binds, p.binaries, p.files, p.relevance
+                          FROM (SELECT op.ident, op.name, op.target, op.exposes, op.binds, op.binaries,
+                                       origin_package_version_parts_v1(split_part(op.ident, '/', 3)) AS version_parts,
+                                       split_part(op.ident, '/', 4) AS release,
+                                       CASE WHEN op_file = '' THEN ARRAY[]::text[]
+                                            ELSE ARRAY(SELECT f.path FROM origin_package_files f
+                                                        WHERE f.package_id = op.id AND (f.path = op_file OR f.path LIKE ('%/' || ltrim(op_file, '/')))
+                                                        ORDER BY f.path)
+                                       END AS files,
+                                       CASE WHEN op_query = '' THEN 0
+                                            WHEN op.name = op_query THEN 100
+                                            WHEN op.name LIKE (op_query || '%') THEN 75
+                                            ELSE 50
+                                       END AS relevance
+                                  FROM origins o INNER JOIN origin_packages op ON o.id = op.origin_id
+                                 WHERE o.name = op_origin
+                                   AND op.yanked = false
+                                   AND op.name LIKE ('%' || op_query || '%')
+                                   AND (op_target = '' OR op.target = op_target)
+                                   AND (op_channel = '' OR EXISTS (SELECT 1 FROM origin_channels oc
+                                                                    INNER JOIN origin_channel_packages ocp ON oc.id = ocp.channel_id
+                                                                    WHERE oc.origin_id = op.origin_id AND oc.name = op_channel AND ocp.package_id = op.id))
+                                   AND (op_min_version = '' OR origin_package_version_parts_v1(split_part(op.ident, '/', 3)) >= origin_package_version_parts_v1(op_min_version))
+                                   AND (op_max_version = '' OR origin_package_version_parts_v1(split_part(op.ident, '/', 3)) < origin_package_version_parts_v1(op_max_version))
+                                   AND string_to_array(COALESCE(op.exposes, ''), ':') @> op_exposes
+                                   AND string_to_array(COALESCE(op.binds, ''), ':') @> op_binds
+                                   AND string_to_array(COALESCE(op.binaries, ''), ':') @> op_binaries
+                                   AND (op_file = '' OR EXISTS (SELECT 1 FROM origin_package_files f
+                                                                 WHERE f.package_id = op.id AND (f.path = op_file OR f.path LIKE ('%/' || ltrim(op_file, '/')))))
+                               ) AS p
+                          ORDER BY p.relevance DESC, p.name ASC, p.version_parts DESC, p.release DESC
+                          LIMIT op_limit OFFSET op_offset;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#)?;
+    migrator.migrate("originsrv-v13",
+                     r#"CREATE OR REPLACE FUNCTION search_all_origin_packages_dynamic_v3 (
+                    op_query text,
+                    op_limit bigint,
+                    op_offset bigint
+                    ) RETURNS TABLE(total_count bigint, ident text) AS $$
+                    DECLARE
+                      schema RECORD;
+                    BEGIN
+                      FOR schema IN EXECUTE
+                        format(
+                          'SELECT schema_name FROM information_schema.schemata WHERE left(schema_name, 6) = %L',
+                          'shard_'
+                        )
+                      LOOP
+                        RETURN QUERY EXECUTE
+                        format('SELECT COUNT(p.partial_ident[1] || %L || p.partial_ident[2]) OVER () AS total_count, p.partial_ident[1] || %L || p.partial_ident[2] AS ident FROM (SELECT regexp_split_to_array(op.ident, %L) as partial_ident FROM %I.origin_packages op WHERE op.ident LIKE (%L || %L || %L) AND op.yanked = false) AS p GROUP BY (p.partial_ident[1] || %L || p.partial_ident[2]) LIMIT %L OFFSET %L', '/', '/', '/', schema.schema_name, '%', op_query, '%', '/', op_limit, op_offset);
+                      END LOOP;
+                    END;
+                    $$ LANGUAGE plpgsql STABLE"#)?;
+    Ok(())
+}
@@ -0,0 +1,87 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use db::migration::Migrator;
+
+use error::Result;
+
+pub fn migrate(migrator: &mut Migrator) -> Result<()> {
+    migrator
+        .migrate("originsrv-v10",
+                 r#"ALTER TABLE origin_projects ADD COLUMN build_timeout integer DEFAULT 0"#)?;
+    migrator
+        .migrate("originsrv-v10",
+                 r#"CREATE OR REPLACE FUNCTION insert_origin_project_v3 (
+                        project_origin_name text,
+                        project_package_name text,
+                        project_plan_path text,
+                        project_vcs_type text,
+                        project_vcs_data text,
+                        project_owner_id bigint,
+                        project_cross_origin_rdeps bool,
+                        project_build_timeout integer
+                 ) RETURNS SETOF origin_projects AS $$
+                     BEGIN
+                         RETURN QUERY INSERT INTO origin_projects (origin_id,
+                                                      origin_name,
+                                                      package_name,
+                                                      name,
+                                                      plan_path,
+                                                      owner_id,
+                                                      vcs_type,
+                                                      vcs_data,
+                                                      cross_origin_rdeps,
+                                                      build_timeout)
+                                VALUES (
+                                    (SELECT id FROM origins where name = project_origin_name),
+                                    project_origin_name,
+                                    project_package_name,
+                                    project_origin_name || '/' || project_package_name,
+                                    project_plan_path,
+                                    project_owner_id,
+                                    project_vcs_type,
+                                    project_vcs_data,
+                                    project_cross_origin_rdeps,
+                                    project_build_timeout)
+                                RETURNING *;
+                         RETURN;
+                     END
+                 $$ LANGUAGE plpgsql VOLATILE"#)?;
+    migrator.migrate("originsrv-v10",
+                     r#"CREATE OR REPLACE FUNCTION update_origin_project_v3 (
+                        project_id bigint,
+                        project_origin_id bigint,
+                        project_package_name text,
+                        project_plan_path text,
+                        project_vcs_type text,
+                        project_vcs_data text,
+                        project_owner_id bigint,
+                        project_cross_origin_rdeps bool,
+                        project_build_timeout integer
+                 ) RETURNS void AS $$
+                     BEGIN
+                        UPDATE origin_projects SET
+                            package_name = project_package_name,
+                            name = (SELECT name FROM origins WHERE id = project_origin_id) || '/' || project_package_name,
+                            plan_path = project_plan_path,
+                            vcs_type = project_vcs_type,
+                            vcs_data = project_vcs_data,
+                            owner_id = project_owner_id,
+                            cross_origin_rdeps = project_cross_origin_rdeps,
+                            build_timeout = project_build_timeout,
+                            updated_at = now()
+                            WHERE id = project_id;
+                     END
+                 $$ LANGUAGE plpgsql VOLATILE"#)?;
+    Ok(())
+}
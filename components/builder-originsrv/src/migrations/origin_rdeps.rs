@@ -0,0 +1,113 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use db::migration::Migrator;
+
+use error::Result;
+
+pub fn migrate(migrator: &mut Migrator) -> Result<()> {
+    migrator
+        .migrate("originsrv-v7",
+                 r#"ALTER TABLE origins ADD COLUMN rdeps_builds bool DEFAULT false"#)?;
+    // Reverse dependency rebuilds used to be hard-wired to the core origin; keep it that way
+    // until an origin owner says otherwise.
+    migrator
+        .migrate("originsrv-v7",
+                 r#"UPDATE origins SET rdeps_builds = true WHERE name = 'core'"#)?;
+    migrator
+        .migrate("originsrv-v7",
+                 r#"ALTER TABLE origin_projects ADD COLUMN cross_origin_rdeps bool DEFAULT false"#)?;
+    migrator.migrate("originsrv-v7",
+                 r#"CREATE OR REPLACE VIEW origins_with_secret_key_full_name_v2 AS
+                        SELECT origins.id, origins.name, origins.owner_id, origins.rdeps_builds,
+                               origin_secret_keys.full_name AS private_key_name
+                          FROM origins
+                          LEFT OUTER JOIN origin_secret_keys ON (origins.id = origin_secret_keys.origin_id)
+                          ORDER BY origins.id, origin_secret_keys.full_name DESC"#)?;
+    migrator
+        .migrate("originsrv-v7",
+                 r#"CREATE OR REPLACE FUNCTION update_origin_v1 (
+                    origin_name text,
+                    origin_rdeps_builds bool
+                 ) RETURNS SETOF origins AS $$
+                     BEGIN
+                         RETURN QUERY UPDATE origins SET
+                            rdeps_builds = origin_rdeps_builds,
+                            updated_at = now()
+                            WHERE name = origin_name
+                            RETURNING *;
+                         RETURN;
+                     END
+                 $$ LANGUAGE plpgsql VOLATILE"#)?;
+    migrator
+        .migrate("originsrv-v7",
+                 r#"CREATE OR REPLACE FUNCTION insert_origin_project_v2 (
+                        project_origin_name text,
+                        project_package_name text,
+                        project_plan_path text,
+                        project_vcs_type text,
+                        project_vcs_data text,
+                        project_owner_id bigint,
+                        project_cross_origin_rdeps bool
+                 ) RETURNS SETOF origin_projects AS $$
+                     BEGIN
+                         RETURN QUERY INSERT INTO origin_projects (origin_id,
+                                                      origin_name,
+                                                      package_name,
+                                                      name,
+                                                      plan_path,
+                                                      owner_id,
+                                                      vcs_type,
+                                                      vcs_data,
+                                                      cross_origin_rdeps)
+                                VALUES (
+                                    (SELECT id FROM origins where name = project_origin_name),
+                                    project_origin_name,
+                                    project_package_name,
+                                    project_origin_name || '/' || project_package_name,
+                                    project_plan_path,
+                                    project_owner_id,
+                                    project_vcs_type,
+                                    project_vcs_data,
+                                    project_cross_origin_rdeps)
+                                RETURNING *;
+                         RETURN;
+                     END
+                 $$ LANGUAGE plpgsql VOLATILE"#)?;
+    migrator.migrate("originsrv-v7",
+                     r#"CREATE OR REPLACE FUNCTION update_origin_project_v2 (
+                        project_id bigint,
+                        project_origin_id bigint,
+                        project_package_name text,
+                        project_plan_path text,
+                        project_vcs_type text,
+                        project_vcs_data text,
+                        project_owner_id bigint,
+                        project_cross_origin_rdeps bool
+                 ) RETURNS void AS $$
+                     BEGIN
+                        UPDATE origin_projects SET
+                            package_name = project_package_name,
+                            name = (SELECT name FROM origins WHERE id = project_origin_id) || '/' || project_package_name,
+                            plan_path = project_plan_path,
+                            vcs_type = project_vcs_type,
+                            vcs_data = project_vcs_data,
+                            owner_id = project_owner_id,
+                            cross_origin_rdeps = project_cross_origin_rdeps,
+                            updated_at = now()
+                            WHERE id = project_id;
+                     END
+                 $$ LANGUAGE plpgsql VOLATILE"#)?;
+    Ok(())
+}
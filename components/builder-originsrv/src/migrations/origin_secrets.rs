@@ -0,0 +1,127 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use db::migration::Migrator;
+
+use error::Result;
+
+pub fn migrate(migrator: &mut Migrator) -> Result<()> {
+    migrator
+        .migrate("originsrv-v11",
+                 r#"CREATE SEQUENCE IF NOT EXISTS origin_encryption_key_id_seq;"#)?;
+    migrator
+        .migrate("originsrv-v11",
+                 r#"CREATE TABLE origin_encryption_keys (
+                    id bigint PRIMARY KEY DEFAULT next_id_v1('origin_encryption_key_id_seq'),
+                    origin_id bigint REFERENCES origins(id),
+                    owner_id bigint,
+                    name text,
+                    revision text,
+                    full_name text UNIQUE,
+                    public_body bytea,
+                    secret_body bytea,
+                    created_at timestamptz DEFAULT now(),
+                    updated_at timestamptz
+             )"#)?;
+    migrator
+        .migrate("originsrv-v11",
+                 r#"CREATE OR REPLACE FUNCTION insert_origin_encryption_key_v1 (
+                    oek_origin_id bigint,
+                    oek_owner_id bigint,
+                    oek_name text,
+                    oek_revision text,
+                    oek_full_name text,
+                    oek_public_body bytea,
+                    oek_secret_body bytea
+                 ) RETURNS SETOF origin_encryption_keys AS $$
+                    BEGIN
+                        RETURN QUERY INSERT INTO origin_encryption_keys (origin_id, owner_id, name, revision, full_name, public_body, secret_body)
+                            VALUES (oek_origin_id, oek_owner_id, oek_name, oek_revision, oek_full_name, oek_public_body, oek_secret_body)
+                            RETURNING *;
+                        RETURN;
+                    END
+                 $$ LANGUAGE plpgsql VOLATILE"#)?;
+    migrator
+        .migrate("originsrv-v11",
+                 r#"CREATE OR REPLACE FUNCTION get_origin_encryption_key_v1 (
+                    oek_origin_id bigint
+                 ) RETURNS SETOF origin_encryption_keys AS $$
+                    BEGIN
+                        RETURN QUERY SELECT * FROM origin_encryption_keys
+                          WHERE origin_id = oek_origin_id
+                          ORDER BY full_name DESC
+                          LIMIT 1;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#)?;
+    migrator
+        .migrate("originsrv-v11",
+                 r#"CREATE SEQUENCE IF NOT EXISTS origin_secret_id_seq;"#)?;
+    migrator
+        .migrate("originsrv-v11",
+                 r#"CREATE TABLE origin_secrets (
+                    id bigint PRIMARY KEY DEFAULT next_id_v1('origin_secret_id_seq'),
+                    origin_id bigint REFERENCES origins(id),
+                    project_id bigint DEFAULT 0,
+                    name text,
+                    value text,
+                    owner_id bigint,
+                    created_at timestamptz DEFAULT now(),
+                    updated_at timestamptz,
+                    UNIQUE (origin_id, project_id, name)
+             )"#)?;
+    migrator
+        .migrate("originsrv-v11",
+                 r#"CREATE OR REPLACE FUNCTION insert_origin_secret_v1 (
+                    os_origin_id bigint,
+                    os_project_id bigint,
+                    os_name text,
+                    os_value text,
+                    os_owner_id bigint
+                 ) RETURNS SETOF origin_secrets AS $$
+                    BEGIN
+                        RETURN QUERY INSERT INTO origin_secrets (origin_id, project_id, name, value, owner_id)
+                            VALUES (os_origin_id, os_project_id, os_name, os_value, os_owner_id)
+                            ON CONFLICT (origin_id, project_id, name)
+                            DO UPDATE SET value = os_value, owner_id = os_owner_id, updated_at = now()
+                            RETURNING *;
+                        RETURN;
+                    END
+                 $$ LANGUAGE plpgsql VOLATILE"#)?;
+    migrator
+        .migrate("originsrv-v11",
+                 r#"CREATE OR REPLACE FUNCTION get_origin_secrets_v1 (
+                    os_origin_id bigint,
+                    os_project_id bigint
+                 ) RETURNS SETOF origin_secrets AS $$
+                    BEGIN
+                        RETURN QUERY SELECT * FROM origin_secrets
+                          WHERE origin_id = os_origin_id
+                          AND project_id IN (0, os_project_id)
+                          ORDER BY project_id ASC, name ASC;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#)?;
+    migrator
+        .migrate("originsrv-v11",
+                 r#"CREATE OR REPLACE FUNCTION delete_origin_secret_v1 (
+                    os_id bigint,
+                    os_origin_id bigint
+                 ) RETURNS void AS $$
+                    BEGIN
+                        DELETE FROM origin_secrets WHERE id = os_id AND origin_id = os_origin_id;
+                    END
+                 $$ LANGUAGE plpgsql VOLATILE"#)?;
+    Ok(())
+}
@@ -0,0 +1,128 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use db::migration::Migrator;
+
+use error::Result;
+
+pub fn migrate(migrator: &mut Migrator) -> Result<()> {
+    migrator
+        .migrate("originsrv-v6",
+                 r#"CREATE SEQUENCE IF NOT EXISTS origin_webhook_id_seq;"#)?;
+    migrator
+        .migrate("originsrv-v6",
+                 r#"CREATE TABLE origin_webhooks (
+                    id bigint PRIMARY KEY DEFAULT next_id_v1('origin_webhook_id_seq'),
+                    origin_id bigint REFERENCES origins(id),
+                    url text,
+                    secret text,
+                    events smallint[],
+                    owner_id bigint,
+                    created_at timestamptz DEFAULT now(),
+                    updated_at timestamptz
+             )"#)?;
+    migrator
+        .migrate("originsrv-v6",
+                 r#"CREATE SEQUENCE IF NOT EXISTS origin_webhook_delivery_id_seq;"#)?;
+    migrator
+        .migrate("originsrv-v6",
+                 r#"CREATE TABLE origin_webhook_deliveries (
+                    id bigint PRIMARY KEY DEFAULT next_id_v1('origin_webhook_delivery_id_seq'),
+                    webhook_id bigint REFERENCES origin_webhooks(id) ON DELETE CASCADE,
+                    event smallint,
+                    delivery_id text,
+                    payload text,
+                    status_code integer,
+                    attempts integer,
+                    success bool,
+                    error text,
+                    created_at timestamptz DEFAULT now()
+             )"#)?;
+    migrator
+        .migrate("originsrv-v6",
+                 r#"CREATE OR REPLACE FUNCTION insert_origin_webhook_v1 (
+                    ow_origin_id bigint,
+                    ow_url text,
+                    ow_secret text,
+                    ow_events smallint[],
+                    ow_owner_id bigint
+                 ) RETURNS SETOF origin_webhooks AS $$
+                    BEGIN
+                        RETURN QUERY INSERT INTO origin_webhooks (origin_id, url, secret, events, owner_id)
+                            VALUES (ow_origin_id, ow_url, ow_secret, ow_events, ow_owner_id)
+                            RETURNING *;
+                        RETURN;
+                    END
+                 $$ LANGUAGE plpgsql VOLATILE"#)?;
+    migrator
+        .migrate("originsrv-v6",
+                 r#"CREATE OR REPLACE FUNCTION get_origin_webhooks_v1 (
+                    ow_origin_id bigint
+                 ) RETURNS SETOF origin_webhooks AS $$
+                    BEGIN
+                        RETURN QUERY SELECT * FROM origin_webhooks
+                          WHERE origin_id = ow_origin_id
+                          ORDER BY created_at ASC;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#)?;
+    migrator
+        .migrate("originsrv-v6",
+                 r#"CREATE OR REPLACE FUNCTION delete_origin_webhook_v1 (
+                    ow_id bigint,
+                    ow_origin_id bigint
+                 ) RETURNS void AS $$
+                    BEGIN
+                        DELETE FROM origin_webhooks WHERE id = ow_id AND origin_id = ow_origin_id;
+                    END
+                 $$ LANGUAGE plpgsql VOLATILE"#)?;
+    migrator
+        .migrate("originsrv-v6",
+                 r#"CREATE OR REPLACE FUNCTION insert_origin_webhook_delivery_v1 (
+                    owd_webhook_id bigint,
+                    owd_event smallint,
+                    owd_delivery_id text,
+                    owd_payload text,
+                    owd_status_code integer,
+                    owd_attempts integer,
+                    owd_success bool,
+                    owd_error text
+                 ) RETURNS SETOF origin_webhook_deliveries AS $$
+                    BEGIN
+                        RETURN QUERY INSERT INTO origin_webhook_deliveries (webhook_id, event, delivery_id, payload, status_code, attempts, success, error)
+                            VALUES (owd_webhook_id, owd_event, owd_delivery_id, owd_payload, owd_status_code, owd_attempts, owd_success, owd_error)
+                            RETURNING *;
+                        RETURN;
+                    END
+                 $$ LANGUAGE plpgsql VOLATILE"#)?;
+    migrator
+        .migrate("originsrv-v6",
+                 r#"CREATE OR REPLACE FUNCTION get_origin_webhook_deliveries_v1 (
+                    owd_webhook_id bigint,
+                    owd_origin_id bigint,
+                    owd_limit bigint
+                 ) RETURNS SETOF origin_webhook_deliveries AS $$
+                    BEGIN
+                        RETURN QUERY SELECT owd.*
+                          FROM origin_webhook_deliveries owd
+                          INNER JOIN origin_webhooks ow ON ow.id = owd.webhook_id
+                          WHERE owd.webhook_id = owd_webhook_id
+                          AND ow.origin_id = owd_origin_id
+                          ORDER BY owd.created_at DESC
+                          LIMIT owd_limit;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#)?;
+    Ok(())
+}
@@ -75,6 +75,27 @@ pub fn origin_get(req: &mut Envelope,
     Ok(())
 }
 
+pub fn origin_update(req: &mut Envelope,
+                     sock: &mut zmq::Socket,
+                     state: &mut ServerState)
+                     -> Result<()> {
+    let msg: proto::OriginUpdate = try!(req.parse_msg());
+
+    match state.datastore.update_origin(&msg) {
+        Ok(Some(ref origin)) => try!(req.reply_complete(sock, origin)),
+        Ok(None) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-update:0");
+            try!(req.reply_complete(sock, &err));
+        }
+        Err(err) => {
+            error!("OriginUpdate, err={:?}", err);
+            let err = net::err(ErrCode::DATA_STORE, "vt:origin-update:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
 pub fn origin_invitation_accept(req: &mut Envelope,
                                 sock: &mut zmq::Socket,
                                 state: &mut ServerState)
@@ -578,6 +599,118 @@ pub fn origin_package_promote(req: &mut Envelope,
     Ok(())
 }
 
+pub fn origin_package_demote(req: &mut Envelope,
+                             sock: &mut zmq::Socket,
+                             state: &mut ServerState)
+                             -> Result<()> {
+    let msg: proto::OriginPackageDemote = try!(req.parse_msg());
+    match state.datastore.demote_origin_package(&msg) {
+        Ok(()) => try!(req.reply_complete(sock, &net::NetOk::new())),
+        Err(err) => {
+            error!("OriginPackageDemote, err={:?}", err);
+            let err = net::err(ErrCode::DATA_STORE, "vt:origin-package-demote:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_package_yank(req: &mut Envelope,
+                           sock: &mut zmq::Socket,
+                           state: &mut ServerState)
+                           -> Result<()> {
+    let msg: proto::OriginPackageYank = try!(req.parse_msg());
+    match state.datastore.yank_origin_package(&msg) {
+        Ok(()) => try!(req.reply_complete(sock, &net::NetOk::new())),
+        Err(err) => {
+            error!("OriginPackageYank, err={:?}", err);
+            let err = net::err(ErrCode::DATA_STORE, "vt:origin-package-yank:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_package_files_set(req: &mut Envelope,
+                                sock: &mut zmq::Socket,
+                                state: &mut ServerState)
+                                -> Result<()> {
+    let msg: proto::OriginPackageFilesSet = try!(req.parse_msg());
+    match state.datastore.set_origin_package_files(&msg) {
+        Ok(()) => try!(req.reply_complete(sock, &net::NetOk::new())),
+        Err(err) => {
+            error!("OriginPackageFilesSet, err={:?}", err);
+            let err = net::err(ErrCode::DATA_STORE, "vt:origin-package-files-set:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_package_files_unindexed_list(req: &mut Envelope,
+                                           sock: &mut zmq::Socket,
+                                           state: &mut ServerState)
+                                           -> Result<()> {
+    let msg: proto::OriginPackageFilesUnindexedListRequest = try!(req.parse_msg());
+    match state.datastore.list_origin_packages_unindexed(&msg) {
+        Ok(ref packages) => try!(req.reply_complete(sock, packages)),
+        Err(err) => {
+            error!("OriginPackageFilesUnindexedList, err={:?}", err);
+            let err = net::err(ErrCode::DATA_STORE, "vt:origin-package-files-unindexed-list:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_package_audit_list(req: &mut Envelope,
+                                 sock: &mut zmq::Socket,
+                                 state: &mut ServerState)
+                                 -> Result<()> {
+    let msg: proto::OriginPackageAuditListRequest = try!(req.parse_msg());
+    match state.datastore.list_origin_package_audit(&msg) {
+        Ok(ref opalr) => try!(req.reply_complete(sock, opalr)),
+        Err(err) => {
+            error!("OriginPackageAuditList, err={:?}", err);
+            let err = net::err(ErrCode::DATA_STORE, "vt:origin-package-audit-list:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_package_download_record(req: &mut Envelope,
+                                      sock: &mut zmq::Socket,
+                                      state: &mut ServerState)
+                                      -> Result<()> {
+    let msg: proto::OriginPackageDownloadRecord = try!(req.parse_msg());
+    match state.datastore.record_origin_package_download(&msg) {
+        Ok(()) => try!(req.reply_complete(sock, &net::NetOk::new())),
+        Err(err) => {
+            error!("OriginPackageDownloadRecord, err={:?}", err);
+            let err = net::err(ErrCode::DATA_STORE, "vt:origin-package-download-record:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_package_download_stats(req: &mut Envelope,
+                                     sock: &mut zmq::Socket,
+                                     state: &mut ServerState)
+                                     -> Result<()> {
+    let msg: proto::OriginPackageDownloadStatsGet = try!(req.parse_msg());
+    match state.datastore.get_origin_package_download_stats(&msg) {
+        Ok(ref stats) => try!(req.reply_complete(sock, stats)),
+        Err(err) => {
+            error!("OriginPackageDownloadStatsGet, err={:?}", err);
+            let err = net::err(ErrCode::DATA_STORE, "vt:origin-package-download-stats:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
 pub fn origin_package_unique_list(req: &mut Envelope,
                                   sock: &mut zmq::Socket,
                                   state: &mut ServerState)
@@ -611,3 +744,167 @@ pub fn origin_package_search(req: &mut Envelope,
     }
     Ok(())
 }
+
+pub fn origin_webhook_create(req: &mut Envelope,
+                             sock: &mut zmq::Socket,
+                             state: &mut ServerState)
+                             -> Result<()> {
+    let msg: proto::OriginWebhookCreate = try!(req.parse_msg());
+    match state.datastore.create_origin_webhook(&msg) {
+        Ok(ref webhook) => try!(req.reply_complete(sock, webhook)),
+        Err(err) => {
+            error!("OriginWebhookCreate, err={:?}", err);
+            let err = net::err(ErrCode::DATA_STORE, "vt:origin-webhook-create:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_webhook_delete(req: &mut Envelope,
+                             sock: &mut zmq::Socket,
+                             state: &mut ServerState)
+                             -> Result<()> {
+    let msg: proto::OriginWebhookDelete = try!(req.parse_msg());
+    match state.datastore.delete_origin_webhook(&msg) {
+        Ok(()) => try!(req.reply_complete(sock, &net::NetOk::new())),
+        Err(err) => {
+            error!("OriginWebhookDelete, err={:?}", err);
+            let err = net::err(ErrCode::DATA_STORE, "vt:origin-webhook-delete:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_webhook_delivery_create(req: &mut Envelope,
+                                      sock: &mut zmq::Socket,
+                                      state: &mut ServerState)
+                                      -> Result<()> {
+    let msg: proto::OriginWebhookDeliveryCreate = try!(req.parse_msg());
+    match state.datastore.create_origin_webhook_delivery(&msg) {
+        Ok(ref delivery) => try!(req.reply_complete(sock, delivery)),
+        Err(err) => {
+            error!("OriginWebhookDeliveryCreate, err={:?}", err);
+            let err = net::err(ErrCode::DATA_STORE, "vt:origin-webhook-delivery-create:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_webhook_delivery_list(req: &mut Envelope,
+                                    sock: &mut zmq::Socket,
+                                    state: &mut ServerState)
+                                    -> Result<()> {
+    let msg: proto::OriginWebhookDeliveryListRequest = try!(req.parse_msg());
+    match state.datastore.list_origin_webhook_deliveries(&msg) {
+        Ok(ref owdl) => try!(req.reply_complete(sock, owdl)),
+        Err(err) => {
+            error!("OriginWebhookDeliveryList, err={:?}", err);
+            let err = net::err(ErrCode::DATA_STORE, "vt:origin-webhook-delivery-list:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_webhook_list(req: &mut Envelope,
+                           sock: &mut zmq::Socket,
+                           state: &mut ServerState)
+                           -> Result<()> {
+    let msg: proto::OriginWebhookListRequest = try!(req.parse_msg());
+    match state.datastore.list_origin_webhooks(&msg) {
+        Ok(ref owl) => try!(req.reply_complete(sock, owl)),
+        Err(err) => {
+            error!("OriginWebhookList, err={:?}", err);
+            let err = net::err(ErrCode::DATA_STORE, "vt:origin-webhook-list:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_encryption_key_create(req: &mut Envelope,
+                                    sock: &mut zmq::Socket,
+                                    state: &mut ServerState)
+                                    -> Result<()> {
+    let msg: proto::OriginEncryptionKeyCreate = try!(req.parse_msg());
+    match state.datastore.create_origin_encryption_key(&msg) {
+        Ok(ref key) => try!(req.reply_complete(sock, key)),
+        Err(err) => {
+            error!("OriginEncryptionKeyCreate, err={:?}", err);
+            let err = net::err(ErrCode::DATA_STORE, "vt:origin-encryption-key-create:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_encryption_key_get(req: &mut Envelope,
+                                 sock: &mut zmq::Socket,
+                                 state: &mut ServerState)
+                                 -> Result<()> {
+    let msg: proto::OriginEncryptionKeyGet = try!(req.parse_msg());
+    match state.datastore.get_origin_encryption_key(&msg) {
+        Ok(Some(ref key)) => try!(req.reply_complete(sock, key)),
+        Ok(None) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "vt:origin-encryption-key-get:0");
+            try!(req.reply_complete(sock, &err));
+        }
+        Err(err) => {
+            error!("OriginEncryptionKeyGet, err={:?}", err);
+            let err = net::err(ErrCode::DATA_STORE, "vt:origin-encryption-key-get:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_secret_create(req: &mut Envelope,
+                            sock: &mut zmq::Socket,
+                            state: &mut ServerState)
+                            -> Result<()> {
+    let msg: proto::OriginSecretCreate = try!(req.parse_msg());
+    match state.datastore.create_origin_secret(&msg) {
+        Ok(ref secret) => try!(req.reply_complete(sock, secret)),
+        Err(err) => {
+            error!("OriginSecretCreate, err={:?}", err);
+            let err = net::err(ErrCode::DATA_STORE, "vt:origin-secret-create:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_secret_delete(req: &mut Envelope,
+                            sock: &mut zmq::Socket,
+                            state: &mut ServerState)
+                            -> Result<()> {
+    let msg: proto::OriginSecretDelete = try!(req.parse_msg());
+    match state.datastore.delete_origin_secret(&msg) {
+        Ok(()) => try!(req.reply_complete(sock, &net::NetOk::new())),
+        Err(err) => {
+            error!("OriginSecretDelete, err={:?}", err);
+            let err = net::err(ErrCode::DATA_STORE, "vt:origin-secret-delete:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_secret_list(req: &mut Envelope,
+                          sock: &mut zmq::Socket,
+                          state: &mut ServerState)
+                          -> Result<()> {
+    let msg: proto::OriginSecretListRequest = try!(req.parse_msg());
+    match state.datastore.list_origin_secrets(&msg) {
+        Ok(ref osl) => try!(req.reply_complete(sock, osl)),
+        Err(err) => {
+            error!("OriginSecretList, err={:?}", err);
+            let err = net::err(ErrCode::DATA_STORE, "vt:origin-secret-list:1");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+    Ok(())
+}
@@ -72,6 +72,7 @@ impl Dispatcher for Worker {
             "CheckOriginAccessRequest" => handlers::origin_check_access(message, sock, state),
             "OriginCreate" => handlers::origin_create(message, sock, state),
             "OriginGet" => handlers::origin_get(message, sock, state),
+            "OriginUpdate" => handlers::origin_update(message, sock, state),
             "OriginInvitationAcceptRequest" => {
                 handlers::origin_invitation_accept(message, sock, state)
             }
@@ -80,6 +81,13 @@ impl Dispatcher for Worker {
             "OriginMemberListRequest" => handlers::origin_member_list(message, sock, state),
             "OriginSecretKeyCreate" => handlers::origin_secret_key_create(message, sock, state),
             "OriginSecretKeyGet" => handlers::origin_secret_key_get(message, sock, state),
+            "OriginEncryptionKeyCreate" => {
+                handlers::origin_encryption_key_create(message, sock, state)
+            }
+            "OriginEncryptionKeyGet" => handlers::origin_encryption_key_get(message, sock, state),
+            "OriginSecretCreate" => handlers::origin_secret_create(message, sock, state),
+            "OriginSecretDelete" => handlers::origin_secret_delete(message, sock, state),
+            "OriginSecretListRequest" => handlers::origin_secret_list(message, sock, state),
             "OriginPublicKeyCreate" => handlers::origin_public_key_create(message, sock, state),
             "OriginPublicKeyGet" => handlers::origin_public_key_get(message, sock, state),
             "OriginPublicKeyLatestGet" => {
@@ -98,6 +106,21 @@ impl Dispatcher for Worker {
                 handlers::origin_package_version_list(message, sock, state)
             }
             "OriginPackagePromote" => handlers::origin_package_promote(message, sock, state),
+            "OriginPackageDemote" => handlers::origin_package_demote(message, sock, state),
+            "OriginPackageYank" => handlers::origin_package_yank(message, sock, state),
+            "OriginPackageFilesSet" => handlers::origin_package_files_set(message, sock, state),
+            "OriginPackageFilesUnindexedListRequest" => {
+                handlers::origin_package_files_unindexed_list(message, sock, state)
+            }
+            "OriginPackageAuditListRequest" => {
+                handlers::origin_package_audit_list(message, sock, state)
+            }
+            "OriginPackageDownloadRecord" => {
+                handlers::origin_package_download_record(message, sock, state)
+            }
+            "OriginPackageDownloadStatsGet" => {
+                handlers::origin_package_download_stats(message, sock, state)
+            }
             "OriginPackageUniqueListRequest" => {
                 handlers::origin_package_unique_list(message, sock, state)
             }
@@ -113,6 +136,15 @@ impl Dispatcher for Worker {
             "OriginChannelPackageListRequest" => {
                 handlers::origin_channel_package_list(message, sock, state)
             }
+            "OriginWebhookCreate" => handlers::origin_webhook_create(message, sock, state),
+            "OriginWebhookDelete" => handlers::origin_webhook_delete(message, sock, state),
+            "OriginWebhookListRequest" => handlers::origin_webhook_list(message, sock, state),
+            "OriginWebhookDeliveryCreate" => {
+                handlers::origin_webhook_delivery_create(message, sock, state)
+            }
+            "OriginWebhookDeliveryListRequest" => {
+                handlers::origin_webhook_delivery_list(message, sock, state)
+            }
             _ => {
                 debug!("dispatch: unhandled message: {}", message.message_id());
                 Ok(())
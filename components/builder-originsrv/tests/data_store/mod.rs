@@ -56,6 +56,42 @@ fn get_origin_by_name() {
     assert_eq!(fg.get_private_key_name(), "");
 }
 
+#[test]
+fn update_origin() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("neurosis"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    ds.create_origin(&origin).expect("Should create origin");
+
+    let neurosis = ds.get_origin_by_name("neurosis")
+        .expect("Could not get the origin")
+        .expect("Origin does not exist");
+    assert!(!neurosis.get_rdeps_builds(),
+            "New origins should not rebuild reverse dependencies");
+
+    let mut ou = originsrv::OriginUpdate::new();
+    ou.set_name(String::from("neurosis"));
+    ou.set_rdeps_builds(true);
+    let updated = ds.update_origin(&ou)
+        .expect("Failed to update origin")
+        .expect("Origin does not exist");
+    assert_eq!(updated.get_id(), neurosis.get_id());
+    assert!(updated.get_rdeps_builds());
+
+    let neurosis = ds.get_origin_by_name("neurosis")
+        .expect("Could not get the origin")
+        .expect("Origin does not exist");
+    assert!(neurosis.get_rdeps_builds());
+
+    ou.set_name(String::from("isis"));
+    assert!(ds.update_origin(&ou)
+                .expect("Failed to update origin")
+                .is_none(),
+            "Updating a missing origin should return nothing");
+}
+
 #[test]
 fn create_origin_secret_key() {
     let ds = datastore_test!(DataStore);
@@ -523,6 +559,13 @@ fn update_origin_project() {
     project.set_vcs_type(String::from("svn"));
     project.set_vcs_data(String::from("svn://github.com/habitat-sh/core-plans"));
     project.set_owner_id(2);
+    project.set_cross_origin_rdeps(true);
+    project.set_build_timeout(3600);
+    project.set_vcs_ref(String::from("v1.2.0"));
+    project.set_vcs_depth(1);
+    project.set_vcs_submodules(true);
+    project.set_vcs_auth(originsrv::VcsAuth::DeployKey);
+    project.set_vcs_auth_secret(String::from("DEPLOY_KEY"));
 
     let mut opu = originsrv::OriginProjectUpdate::new();
     let updated_project = project.clone();
@@ -561,6 +604,21 @@ fn update_origin_project() {
     assert_eq!(updated_project.get_vcs_data(),
                sepultura.get_vcs_data(),
                "Should have the same vcs data");
+    assert!(sepultura.get_cross_origin_rdeps(),
+            "Should have opted in to cross-origin rebuilds");
+    assert_eq!(sepultura.get_build_timeout(),
+               3600,
+               "Should have the updated build timeout");
+    assert_eq!(sepultura.get_vcs_ref(), "v1.2.0", "Should have the updated vcs ref");
+    assert_eq!(sepultura.get_vcs_depth(), 1, "Should have the updated clone depth");
+    assert!(sepultura.get_vcs_submodules(),
+            "Should have opted in to submodules");
+    assert_eq!(sepultura.get_vcs_auth(),
+               originsrv::VcsAuth::DeployKey,
+               "Should have the updated vcs auth");
+    assert_eq!(sepultura.get_vcs_auth_secret(),
+               "DEPLOY_KEY",
+               "Should have the updated vcs auth secret");
 }
 
 #[test]
@@ -1152,11 +1210,11 @@ fn search_origin_package_for_origin() {
     ops.set_stop(2);
     let result = ds.search_origin_package_for_origin(&ops.clone())
         .expect("Could not get the packages from the database");
-    assert_eq!(result.get_idents().len(), 1);
+    assert_eq!(result.get_packages().len(), 1);
     assert_eq!(result.get_start(), 0);
     assert_eq!(result.get_stop(), 0);
     assert_eq!(result.get_count(), 1);
-    let pkg1 = result.get_idents().iter().nth(0).unwrap();
+    let pkg1 = result.get_packages().iter().nth(0).unwrap().get_ident();
     assert_eq!(pkg1.to_string(), ident4.to_string());
 
     ops.set_query("red".to_string());
@@ -1164,13 +1222,14 @@ fn search_origin_package_for_origin() {
     ops.set_stop(20);
     let result2 = ds.search_origin_package_for_origin(&ops)
         .expect("Could not get the packages from the database");
-    assert_eq!(result2.get_idents().len(), 2);
+    assert_eq!(result2.get_packages().len(), 2);
     assert_eq!(result2.get_start(), 1);
     assert_eq!(result2.get_stop(), 2);
     assert_eq!(result2.get_count(), 3);
-    let pkg1 = result2.get_idents().iter().nth(0).unwrap();
-    assert_eq!(pkg1.to_string(), ident2.to_string());
-    let pkg2 = result2.get_idents().iter().nth(1).unwrap();
+    // Exact name matches rank first, newest version first
+    let pkg1 = result2.get_packages().iter().nth(0).unwrap().get_ident();
+    assert_eq!(pkg1.to_string(), ident1.to_string());
+    let pkg2 = result2.get_packages().iter().nth(1).unwrap().get_ident();
     assert_eq!(pkg2.to_string(), ident4.to_string());
 
     ops.set_query("do".to_string());
@@ -1178,11 +1237,11 @@ fn search_origin_package_for_origin() {
     ops.set_stop(2);
     let result3 = ds.search_origin_package_for_origin(&ops)
         .expect("Could not get the packages from the database");
-    assert_eq!(result3.get_idents().len(), 1);
+    assert_eq!(result3.get_packages().len(), 1);
     assert_eq!(result3.get_start(), 0);
     assert_eq!(result3.get_stop(), 0);
     assert_eq!(result3.get_count(), 1);
-    let pkg1 = result3.get_idents().iter().nth(0).unwrap();
+    let pkg1 = result3.get_packages().iter().nth(0).unwrap().get_ident();
     assert_eq!(pkg1.to_string(), ident4.to_string());
 
     ops.set_query("core/re".to_string());
@@ -1191,16 +1250,210 @@ fn search_origin_package_for_origin() {
     ops.set_distinct(true);
     let result2 = ds.search_origin_package_for_origin(&ops)
         .expect("Could not get the packages from the database");
-    assert_eq!(result2.get_idents().len(), 2);
+    assert_eq!(result2.get_packages().len(), 2);
     assert_eq!(result2.get_start(), 0);
     assert_eq!(result2.get_stop(), 1);
     assert_eq!(result2.get_count(), 2);
-    let pkg1 = result2.get_idents().iter().nth(0).unwrap();
+    let pkg1 = result2.get_packages().iter().nth(0).unwrap().get_ident();
     assert_eq!(pkg1.to_string(), "core/red");
-    let pkg2 = result2.get_idents().iter().nth(1).unwrap();
+    let pkg2 = result2.get_packages().iter().nth(1).unwrap().get_ident();
     assert_eq!(pkg2.to_string(), "core/red_dog");
 }
 
+#[test]
+fn search_origin_package_with_filters() {
+    let ds = datastore_test!(DataStore);
+
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("core"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    let origin1 = ds.create_origin(&origin)
+        .expect("Should create origin")
+        .unwrap();
+
+    let mut ident1 = originsrv::OriginPackageIdent::new();
+    ident1.set_origin("core".to_string());
+    ident1.set_name("redis".to_string());
+    ident1.set_version("3.2.9".to_string());
+    ident1.set_release("20170514010101".to_string());
+
+    let mut ident2 = originsrv::OriginPackageIdent::new();
+    ident2.set_origin("core".to_string());
+    ident2.set_name("redis".to_string());
+    ident2.set_version("3.2.10".to_string());
+    ident2.set_release("20170614010101".to_string());
+
+    let mut ident3 = originsrv::OriginPackageIdent::new();
+    ident3.set_origin("core".to_string());
+    ident3.set_name("redis-sentinel".to_string());
+    ident3.set_version("4.0.0".to_string());
+    ident3.set_release("20170714010101".to_string());
+
+    let mut package = originsrv::OriginPackageCreate::new();
+    package.set_owner_id(1);
+    package.set_origin_id(origin1.get_id());
+    package.set_ident(ident1.clone());
+    package.set_checksum("checksum".to_string());
+    package.set_manifest("manifest".to_string());
+    package.set_config("config".to_string());
+    package.set_target("x86_64-linux".to_string());
+    package.set_exposes(vec![6379]);
+    package.set_binaries(protobuf::RepeatedField::from_vec(vec!["redis-server".to_string(),
+                                                                "redis-cli".to_string()]));
+    package.set_files(protobuf::RepeatedField::from_vec(vec![
+        "/hab/pkgs/core/redis/3.2.9/20170514010101/bin/redis-server".to_string(),
+        "/hab/pkgs/core/redis/3.2.9/20170514010101/bin/redis-cli".to_string(),
+    ]));
+    ds.create_origin_package(&package)
+        .expect("Failed to create origin package");
+
+    package.set_ident(ident2.clone());
+    package.set_files(protobuf::RepeatedField::from_vec(vec![
+        "/hab/pkgs/core/redis/3.2.10/20170614010101/bin/redis-server".to_string(),
+    ]));
+    ds.create_origin_package(&package)
+        .expect("Failed to create origin package");
+
+    package.set_ident(ident3.clone());
+    package.set_exposes(vec![26379]);
+    package.set_binds(protobuf::RepeatedField::from_vec(vec!["redis".to_string()]));
+    package.set_binaries(protobuf::RepeatedField::from_vec(vec!["redis-sentinel".to_string()]));
+    package.set_files(protobuf::RepeatedField::new());
+    ds.create_origin_package(&package)
+        .expect("Failed to create origin package");
+
+    let mut ops = originsrv::OriginPackageSearchRequest::new();
+    ops.set_origin("core".to_string());
+    ops.set_query("redis".to_string());
+    ops.set_start(0);
+    ops.set_stop(50);
+
+    // Version ranges compare numerically, so 3.2.10 is newer than 3.2.9
+    ops.set_min_version("3.2.10".to_string());
+    ops.set_max_version("4.0.0".to_string());
+    let result = ds.search_origin_package_for_origin(&ops)
+        .expect("Could not search packages");
+    assert_eq!(result.get_count(), 1);
+    assert_eq!(result.get_packages()[0].get_ident().to_string(),
+               ident2.to_string());
+    ops.clear_min_version();
+    ops.clear_max_version();
+
+    ops.set_exposes(vec![26379]);
+    let result = ds.search_origin_package_for_origin(&ops)
+        .expect("Could not search packages");
+    assert_eq!(result.get_count(), 1);
+    let found = &result.get_packages()[0];
+    assert_eq!(found.get_ident().to_string(), ident3.to_string());
+    assert_eq!(found.get_binds(), &["redis".to_string()]);
+    assert_eq!(found.get_relevance(), 75);
+    ops.clear_exposes();
+
+    ops.set_binaries(protobuf::RepeatedField::from_vec(vec!["redis-cli".to_string()]));
+    let result = ds.search_origin_package_for_origin(&ops)
+        .expect("Could not search packages");
+    assert_eq!(result.get_count(), 2);
+    assert_eq!(result.get_packages()[0].get_ident().to_string(),
+               ident2.to_string());
+    assert_eq!(result.get_packages()[0].get_relevance(), 100);
+    ops.clear_binaries();
+
+    ops.set_query("".to_string());
+    ops.set_file("bin/redis-cli".to_string());
+    let result = ds.search_origin_package_for_origin(&ops)
+        .expect("Could not search packages");
+    assert_eq!(result.get_count(), 1);
+    let found = &result.get_packages()[0];
+    assert_eq!(found.get_ident().to_string(), ident1.to_string());
+    assert_eq!(found.get_files(),
+               &["/hab/pkgs/core/redis/3.2.9/20170514010101/bin/redis-cli".to_string()]);
+
+    // Wildcards only match themselves
+    ops.set_file("bin/redis_cli".to_string());
+    let result = ds.search_origin_package_for_origin(&ops)
+        .expect("Could not search packages");
+    assert_eq!(result.get_count(), 0);
+    ops.set_file("%/redis-cli".to_string());
+    let result = ds.search_origin_package_for_origin(&ops)
+        .expect("Could not search packages");
+    assert_eq!(result.get_count(), 0);
+
+    ops.set_file("".to_string());
+    ops.set_channel("stable".to_string());
+    let result = ds.search_origin_package_for_origin(&ops)
+        .expect("Could not search packages");
+    assert_eq!(result.get_count(), 0);
+    ops.set_channel("unstable".to_string());
+    ops.set_target("x86_64-windows".to_string());
+    let result = ds.search_origin_package_for_origin(&ops)
+        .expect("Could not search packages");
+    assert_eq!(result.get_count(), 0);
+}
+
+#[test]
+fn set_files_of_unindexed_origin_package() {
+    let ds = datastore_test!(DataStore);
+
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("core"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    let origin1 = ds.create_origin(&origin)
+        .expect("Should create origin")
+        .unwrap();
+
+    let mut ident = originsrv::OriginPackageIdent::new();
+    ident.set_origin("core".to_string());
+    ident.set_name("redis".to_string());
+    ident.set_version("3.2.9".to_string());
+    ident.set_release("20170514010101".to_string());
+
+    let mut package = originsrv::OriginPackageCreate::new();
+    package.set_owner_id(1);
+    package.set_origin_id(origin1.get_id());
+    package.set_ident(ident.clone());
+    package.set_checksum("checksum".to_string());
+    package.set_manifest("manifest".to_string());
+    package.set_config("config".to_string());
+    package.set_target("x86_64-linux".to_string());
+    ds.create_origin_package(&package)
+        .expect("Failed to create origin package");
+
+    let request = originsrv::OriginPackageFilesUnindexedListRequest::new();
+    let unindexed = ds.list_origin_packages_unindexed(&request)
+        .expect("Could not list unindexed packages");
+    assert_eq!(unindexed.get_packages().len(), 1);
+    assert_eq!(unindexed.get_packages()[0].get_ident().to_string(),
+               ident.to_string());
+    assert_eq!(unindexed.get_packages()[0].get_target(), "x86_64-linux");
+
+    let mut opfs = originsrv::OriginPackageFilesSet::new();
+    opfs.set_ident(ident.clone());
+    opfs.set_files(protobuf::RepeatedField::from_vec(vec![
+        "/hab/pkgs/core/redis/3.2.9/20170514010101/bin/redis-cli".to_string(),
+    ]));
+    ds.set_origin_package_files(&opfs)
+        .expect("Failed to set origin package files");
+    // Setting the same files again leaves them as they are
+    ds.set_origin_package_files(&opfs)
+        .expect("Failed to set origin package files");
+    let unindexed = ds.list_origin_packages_unindexed(&request)
+        .expect("Could not list unindexed packages");
+    assert!(unindexed.get_packages().is_empty());
+
+    let mut ops = originsrv::OriginPackageSearchRequest::new();
+    ops.set_origin("core".to_string());
+    ops.set_start(0);
+    ops.set_stop(50);
+    ops.set_file("redis-cli".to_string());
+    let result = ds.search_origin_package_for_origin(&ops)
+        .expect("Could not search packages");
+    assert_eq!(result.get_count(), 1);
+    assert_eq!(result.get_packages()[0].get_files(),
+               &["/hab/pkgs/core/redis/3.2.9/20170514010101/bin/redis-cli".to_string()]);
+}
+
 #[test]
 fn create_origin_channel() {
     let ds = datastore_test!(DataStore);
@@ -1408,6 +1661,256 @@ fn get_channel_package() {
     assert_eq!(result.get_ident().to_string(), ident1.to_string());
 }
 
+#[test]
+fn demote_channel_package() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("neurosis"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    ds.create_origin(&origin).expect("Should create origin");
+
+    let neurosis = ds.get_origin_by_name("neurosis")
+        .expect("Could not retrieve origin")
+        .expect("Origin does not exist");
+
+    // Create a new origin channel
+    let mut oscc = originsrv::OriginChannelCreate::new();
+    oscc.set_origin_id(neurosis.get_id());
+    oscc.set_origin_name(neurosis.get_name().to_string());
+    oscc.set_name(String::from("eve"));
+    oscc.set_owner_id(1);
+    let channel1 = ds.create_origin_channel(&oscc)
+        .expect("Failed to create origin channel");
+
+    let mut ident1 = originsrv::OriginPackageIdent::new();
+    ident1.set_origin("neurosis".to_string());
+    ident1.set_name("cacerts".to_string());
+    ident1.set_version("2017.01.17".to_string());
+    ident1.set_release("20170209064044".to_string());
+
+    let mut package = originsrv::OriginPackageCreate::new();
+    package.set_owner_id(1);
+    package.set_origin_id(neurosis.get_id());
+    package.set_ident(ident1.clone());
+    let package = ds.create_origin_package(&package.clone())
+        .expect("Failed to create origin package");
+
+    let mut opp = originsrv::OriginPackagePromote::new();
+    opp.set_channel_id(channel1.get_id());
+    opp.set_package_id(package.get_id());
+    opp.set_ident(ident1.clone());
+    ds.promote_origin_package(&opp)
+        .expect("Could not promote package");
+
+    let mut opd = originsrv::OriginPackageDemote::new();
+    opd.set_channel_id(channel1.get_id());
+    opd.set_package_id(package.get_id());
+    opd.set_ident(ident1.clone());
+    opd.set_requestor_id(1);
+    opd.set_requestor_name("scottkelly".to_string());
+    opd.set_reason("broken release".to_string());
+    ds.demote_origin_package(&opd)
+        .expect("Could not demote package");
+
+    let mut package_get = originsrv::OriginChannelPackageGet::new();
+    package_get.set_ident(ident1.clone());
+    package_get.set_name(channel1.get_name().to_string());
+    let result = ds.get_origin_channel_package(&package_get)
+        .expect("Failed to get origin channel package");
+    assert!(result.is_none());
+
+    // Demoting a package which isn't in the channel leaves no trace in the audit trail
+    ds.demote_origin_package(&opd)
+        .expect("Could not demote package");
+
+    let mut opal = originsrv::OriginPackageAuditListRequest::new();
+    opal.set_ident(ident1.clone());
+    let audit = ds.list_origin_package_audit(&opal)
+        .expect("Could not get the package audit trail");
+    assert_eq!(audit.get_events().len(), 1);
+    let event = &audit.get_events()[0];
+    assert_eq!(event.get_operation(), originsrv::OriginPackageOperation::Demote);
+    assert_eq!(event.get_channel(), "eve");
+    assert_eq!(event.get_requestor_name(), "scottkelly");
+    assert_eq!(event.get_reason(), "broken release");
+}
+
+#[test]
+fn yank_package() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("core"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    let origin = ds.create_origin(&origin)
+        .expect("Should create origin")
+        .unwrap();
+
+    let mut ident1 = originsrv::OriginPackageIdent::new();
+    ident1.set_origin("core".to_string());
+    ident1.set_name("cacerts".to_string());
+    ident1.set_version("2017.01.17".to_string());
+    ident1.set_release("20170209064044".to_string());
+
+    let mut ident2 = originsrv::OriginPackageIdent::new();
+    ident2.set_origin("core".to_string());
+    ident2.set_name("cacerts".to_string());
+    ident2.set_version("2017.01.18".to_string());
+    ident2.set_release("20170209064044".to_string());
+
+    let mut package = originsrv::OriginPackageCreate::new();
+    package.set_owner_id(1);
+    package.set_origin_id(origin.get_id());
+    package.set_ident(ident1.clone());
+    package.set_target("x86_64-linux".to_string());
+    ds.create_origin_package(&package.clone())
+        .expect("Failed to create origin package");
+
+    package.set_ident(ident2.clone());
+    let yanked = ds.create_origin_package(&package.clone())
+        .expect("Failed to create origin package");
+
+    let mut opy = originsrv::OriginPackageYank::new();
+    opy.set_package_id(yanked.get_id());
+    opy.set_ident(ident2.clone());
+    opy.set_requestor_id(1);
+    opy.set_requestor_name("scottkelly".to_string());
+    opy.set_reason("CVE-2017-0001".to_string());
+    ds.yank_origin_package(&opy)
+        .expect("Could not yank package");
+
+    let mut search_ident = originsrv::OriginPackageIdent::new();
+    search_ident.set_origin("core".to_string());
+    search_ident.set_name("cacerts".to_string());
+    let mut latest_get = originsrv::OriginPackageLatestGet::new();
+    latest_get.set_ident(search_ident.clone());
+    latest_get.set_target("x86_64-linux".to_string());
+    let latest = ds.get_origin_package_latest(&latest_get)
+        .expect("Failed to get latest origin package")
+        .unwrap();
+    assert_eq!(latest.to_string(), ident1.to_string());
+
+    let mut channel_get = originsrv::OriginChannelPackageLatestGet::new();
+    channel_get.set_name("unstable".to_string());
+    channel_get.set_ident(search_ident.clone());
+    channel_get.set_target("x86_64-linux".to_string());
+    let latest = ds.get_origin_channel_package_latest(&channel_get)
+        .expect("Failed to get latest origin channel package")
+        .unwrap();
+    assert_eq!(latest.to_string(), ident1.to_string());
+
+    // Yanked packages can still be fetched by their fully qualified identifier
+    let mut package_get = originsrv::OriginPackageGet::new();
+    package_get.set_ident(ident2.clone());
+    let result = ds.get_origin_package(&package_get)
+        .expect("Failed to get origin package")
+        .unwrap();
+    assert!(result.get_yanked());
+
+    // Yanked packages are left out of search results
+    let mut ops = originsrv::OriginPackageSearchRequest::new();
+    ops.set_origin("core".to_string());
+    ops.set_query("cacerts".to_string());
+    ops.set_start(0);
+    ops.set_stop(20);
+    let result = ds.search_origin_package_for_origin(&ops.clone())
+        .expect("Could not get the packages from the database");
+    assert_eq!(result.get_count(), 1);
+    assert_eq!(result.get_packages()[0].get_ident().to_string(),
+               ident1.to_string());
+
+    let mut opal = originsrv::OriginPackageAuditListRequest::new();
+    opal.set_ident(ident2.clone());
+    let audit = ds.list_origin_package_audit(&opal)
+        .expect("Could not get the package audit trail");
+    assert_eq!(audit.get_events().len(), 1);
+    let event = &audit.get_events()[0];
+    assert_eq!(event.get_operation(), originsrv::OriginPackageOperation::Yank);
+    assert_eq!(event.get_channel(), "");
+    assert_eq!(event.get_reason(), "CVE-2017-0001");
+}
+
+#[test]
+fn package_download_stats() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("core"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    let origin = ds.create_origin(&origin)
+        .expect("Should create origin")
+        .unwrap();
+
+    let mut ident1 = originsrv::OriginPackageIdent::new();
+    ident1.set_origin("core".to_string());
+    ident1.set_name("cacerts".to_string());
+    ident1.set_version("2017.01.17".to_string());
+    ident1.set_release("20170209064044".to_string());
+
+    let mut ident2 = ident1.clone();
+    ident2.set_version("2017.01.18".to_string());
+
+    let mut ident3 = ident1.clone();
+    ident3.set_name("cacerts-extra".to_string());
+
+    let mut package = originsrv::OriginPackageCreate::new();
+    package.set_owner_id(1);
+    package.set_origin_id(origin.get_id());
+    package.set_target("x86_64-linux".to_string());
+    let mut packages = Vec::new();
+    for ident in vec![&ident1, &ident2, &ident3] {
+        package.set_ident(ident.clone());
+        packages.push(ds.create_origin_package(&package.clone())
+                          .expect("Failed to create origin package"));
+    }
+
+    for (package, times) in packages.iter().zip(vec![2, 1, 4]) {
+        let mut opdr = originsrv::OriginPackageDownloadRecord::new();
+        opdr.set_package_id(package.get_id());
+        opdr.set_ident(package.get_ident().clone());
+        for _ in 0..times {
+            ds.record_origin_package_download(&opdr)
+                .expect("Could not record package download");
+        }
+    }
+
+    let mut search_ident = originsrv::OriginPackageIdent::new();
+    search_ident.set_origin("core".to_string());
+    search_ident.set_name("cacerts".to_string());
+    let mut opdsg = originsrv::OriginPackageDownloadStatsGet::new();
+    opdsg.set_ident(search_ident.clone());
+    let stats = ds.get_origin_package_download_stats(&opdsg)
+        .expect("Could not get package download stats");
+    assert_eq!(stats.get_total(), 3);
+    let downloads = stats.get_downloads();
+    assert_eq!(downloads.len(), 2);
+    assert_eq!(downloads[0].get_ident().to_string(), ident1.to_string());
+    assert_eq!(downloads[0].get_target(), "x86_64-linux");
+    assert_eq!(downloads[0].get_count(), 2);
+    assert_eq!(downloads[0].get_day().len(), 10);
+    assert_eq!(downloads[1].get_ident().to_string(), ident2.to_string());
+    assert_eq!(downloads[1].get_count(), 1);
+
+    opdsg.set_ident(ident2.clone());
+    let stats = ds.get_origin_package_download_stats(&opdsg)
+        .expect("Could not get package download stats");
+    assert_eq!(stats.get_total(), 1);
+
+    opdsg.set_ident(search_ident);
+    opdsg.set_from_day("2099-01-01".to_string());
+    let stats = ds.get_origin_package_download_stats(&opdsg)
+        .expect("Could not get package download stats");
+    assert_eq!(stats.get_total(), 0);
+    assert!(stats.get_downloads().is_empty());
+
+    opdsg.clear_from_day();
+    opdsg.set_target("x86_64-windows".to_string());
+    let stats = ds.get_origin_package_download_stats(&opdsg)
+        .expect("Could not get package download stats");
+    assert_eq!(stats.get_total(), 0);
+}
+
 #[test]
 fn get_latest_channel_package() {
     let ds = datastore_test!(DataStore);
@@ -1823,3 +2326,158 @@ fn delete_origin_channel_by_name() {
                 .is_none(),
             "Channel package should not exist");
 }
+
+#[test]
+fn origin_webhooks_and_deliveries() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("neurosis"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    ds.create_origin(&origin).expect("Should create origin");
+
+    let neurosis = ds.get_origin_by_name("neurosis")
+        .expect("Could not retrieve origin")
+        .expect("Origin does not exist");
+
+    let mut owc = originsrv::OriginWebhookCreate::new();
+    owc.set_origin_id(neurosis.get_id());
+    owc.set_url(String::from("https://ci.example.com/hooks/habitat"));
+    owc.set_secret(String::from("through-silver-in-blood"));
+    owc.set_events(vec![originsrv::OriginWebhookEvent::Upload,
+                        originsrv::OriginWebhookEvent::Promote]);
+    owc.set_owner_id(1);
+    let webhook = ds.create_origin_webhook(&owc)
+        .expect("Failed to create webhook");
+    assert_eq!(webhook.get_url(), "https://ci.example.com/hooks/habitat");
+    assert_eq!(webhook.get_secret(), "through-silver-in-blood");
+    assert_eq!(webhook.get_events(),
+               &[originsrv::OriginWebhookEvent::Upload, originsrv::OriginWebhookEvent::Promote]);
+
+    let mut owlr = originsrv::OriginWebhookListRequest::new();
+    owlr.set_origin_id(neurosis.get_id());
+    let list = ds.list_origin_webhooks(&owlr)
+        .expect("Could not list webhooks");
+    assert_eq!(list.get_webhooks().len(), 1);
+    assert_eq!(list.get_webhooks()[0].get_id(), webhook.get_id());
+
+    let mut owdc = originsrv::OriginWebhookDeliveryCreate::new();
+    owdc.set_webhook_id(webhook.get_id());
+    owdc.set_origin_id(neurosis.get_id());
+    owdc.set_event(originsrv::OriginWebhookEvent::Upload);
+    owdc.set_delivery_id(String::from("a-delivery"));
+    owdc.set_payload(String::from("{}"));
+    owdc.set_status_code(500);
+    owdc.set_attempts(3);
+    owdc.set_success(false);
+    owdc.set_error(String::from("Internal Server Error"));
+    ds.create_origin_webhook_delivery(&owdc)
+        .expect("Failed to record webhook delivery");
+
+    let mut owdl = originsrv::OriginWebhookDeliveryListRequest::new();
+    owdl.set_webhook_id(webhook.get_id());
+    owdl.set_origin_id(neurosis.get_id());
+    let deliveries = ds.list_origin_webhook_deliveries(&owdl)
+        .expect("Could not list webhook deliveries");
+    assert_eq!(deliveries.get_deliveries().len(), 1);
+    let delivery = &deliveries.get_deliveries()[0];
+    assert_eq!(delivery.get_event(), originsrv::OriginWebhookEvent::Upload);
+    assert_eq!(delivery.get_status_code(), 500);
+    assert_eq!(delivery.get_attempts(), 3);
+    assert!(!delivery.get_success());
+
+    let mut owd = originsrv::OriginWebhookDelete::new();
+    owd.set_id(webhook.get_id());
+    owd.set_origin_id(neurosis.get_id());
+    ds.delete_origin_webhook(&owd)
+        .expect("Failed to delete webhook");
+    let list = ds.list_origin_webhooks(&owlr)
+        .expect("Could not list webhooks");
+    assert_eq!(list.get_webhooks().len(), 0);
+    let deliveries = ds.list_origin_webhook_deliveries(&owdl)
+        .expect("Could not list webhook deliveries");
+    assert_eq!(deliveries.get_deliveries().len(), 0);
+}
+
+#[test]
+fn origin_encryption_keys_and_secrets() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("neurosis"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    ds.create_origin(&origin).expect("Should create origin");
+
+    let neurosis = ds.get_origin_by_name("neurosis")
+        .expect("Could not retrieve origin")
+        .expect("Origin does not exist");
+
+    let mut oekg = originsrv::OriginEncryptionKeyGet::new();
+    oekg.set_origin_id(neurosis.get_id());
+    assert!(ds.get_origin_encryption_key(&oekg)
+                .expect("Could not get origin encryption key")
+                .is_none());
+
+    let mut oekc = originsrv::OriginEncryptionKeyCreate::new();
+    oekc.set_origin_id(neurosis.get_id());
+    oekc.set_name(String::from("neurosis"));
+    oekc.set_revision(String::from("20160612031944"));
+    oekc.set_public_body(String::from("public").into_bytes());
+    oekc.set_secret_body(String::from("very_secret").into_bytes());
+    oekc.set_owner_id(1);
+    ds.create_origin_encryption_key(&oekc)
+        .expect("Failed to create origin encryption key");
+    // A key created after the first is dropped and the first one is returned instead
+    oekc.set_revision(String::from("20160612031945"));
+    oekc.set_secret_body(String::from("lost_race").into_bytes());
+    let key = ds.create_origin_encryption_key(&oekc)
+        .expect("Failed to create origin encryption key");
+    assert_eq!(key.get_revision(), "20160612031944");
+
+    let key = ds.get_origin_encryption_key(&oekg)
+        .expect("Could not get origin encryption key")
+        .expect("Origin encryption key does not exist");
+    assert_eq!(key.get_revision(), "20160612031944");
+    assert_eq!(key.get_secret_body(), "very_secret".as_bytes());
+
+    let mut osc = originsrv::OriginSecretCreate::new();
+    osc.set_origin_id(neurosis.get_id());
+    osc.set_name(String::from("REGISTRY_TOKEN"));
+    osc.set_value(String::from("origin-payload"));
+    osc.set_owner_id(1);
+    ds.create_origin_secret(&osc)
+        .expect("Failed to create origin secret");
+    osc.set_project_id(42);
+    osc.set_value(String::from("project-payload"));
+    ds.create_origin_secret(&osc)
+        .expect("Failed to create project secret");
+    // Setting a secret again replaces its value
+    osc.set_value(String::from("new-project-payload"));
+    let project_secret = ds.create_origin_secret(&osc)
+        .expect("Failed to update project secret");
+    assert_eq!(project_secret.get_value(), "new-project-payload");
+
+    let mut oslr = originsrv::OriginSecretListRequest::new();
+    oslr.set_origin_id(neurosis.get_id());
+    let list = ds.list_origin_secrets(&oslr)
+        .expect("Could not list origin secrets");
+    assert_eq!(list.get_secrets().len(), 1);
+    assert_eq!(list.get_secrets()[0].get_value(), "origin-payload");
+
+    // Project secrets are listed after the origin's shared secrets
+    oslr.set_project_id(42);
+    let list = ds.list_origin_secrets(&oslr)
+        .expect("Could not list project secrets");
+    assert_eq!(list.get_secrets().len(), 2);
+    assert_eq!(list.get_secrets()[0].get_project_id(), 0);
+    assert_eq!(list.get_secrets()[1].get_value(), "new-project-payload");
+
+    let mut osd = originsrv::OriginSecretDelete::new();
+    osd.set_id(project_secret.get_id());
+    osd.set_origin_id(neurosis.get_id());
+    ds.delete_origin_secret(&osd)
+        .expect("Failed to delete project secret");
+    let list = ds.list_origin_secrets(&oslr)
+        .expect("Could not list project secrets");
+    assert_eq!(list.get_secrets().len(), 1);
+}
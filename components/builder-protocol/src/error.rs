@@ -20,6 +20,8 @@ use std::result;
 pub enum ProtocolError {
     BadSearchEntity(String),
     BadSearchKey(String),
+    BadWebhookEvent(String),
+    BadVcsAuth(String),
 }
 
 pub type ProtocolResult<T> = result::Result<T, ProtocolError>;
@@ -33,6 +35,8 @@ impl fmt::Display for ProtocolError {
             ProtocolError::BadSearchKey(ref e) => {
                 format!("Search not implemented for entity with key: {}", e)
             }
+            ProtocolError::BadWebhookEvent(ref e) => format!("Unknown webhook event: {}", e),
+            ProtocolError::BadVcsAuth(ref e) => format!("Unknown VCS authentication: {}", e),
         };
         write!(f, "{}", msg)
     }
@@ -43,6 +47,8 @@ impl error::Error for ProtocolError {
         match *self {
             ProtocolError::BadSearchEntity(_) => "Search not implemented for entity.",
             ProtocolError::BadSearchKey(_) => "Entity not indexed by the given key.",
+            ProtocolError::BadWebhookEvent(_) => "Webhook event not recognized.",
+            ProtocolError::BadVcsAuth(_) => "VCS authentication not recognized.",
         }
     }
 }
@@ -54,6 +54,22 @@ impl Routable for JobLogGet {
     }
 }
 
+impl Routable for JobCancel {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_id()))
+    }
+}
+
+impl Routable for JobPublishRetry {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_id()))
+    }
+}
+
 impl Routable for JobGet {
     type H = InstaId;
 
@@ -133,6 +149,26 @@ impl Serialize for Job {
 
         strukt.serialize_field("state", &self.get_state())?;
 
+        if !self.get_phases().is_empty() {
+            strukt.serialize_field("phases", self.get_phases())?;
+        }
+
+        if self.has_cpu_usage_ms() {
+            strukt.serialize_field("cpu_usage_ms", &self.get_cpu_usage_ms())?;
+        }
+        if self.has_memory_usage_bytes() {
+            strukt
+                .serialize_field("memory_usage_bytes", &self.get_memory_usage_bytes())?;
+        }
+
+        if !self.get_post_process().is_empty() {
+            strukt.serialize_field("post_process", self.get_post_process())?;
+        }
+
+        if self.get_shelved() {
+            strukt.serialize_field("shelved", &true)?;
+        }
+
         if self.has_error() {
             try!(strukt.serialize_field("error", self.get_error()));
         }
@@ -201,11 +237,52 @@ impl Serialize for JobState {
             3 => serializer.serialize_str("Rejected"),
             4 => serializer.serialize_str("Failed"),
             5 => serializer.serialize_str("Dispatched"),
+            6 => serializer.serialize_str("Cancelled"),
             _ => panic!("Unexpected enum value"),
         }
     }
 }
 
+impl Serialize for JobPhase {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        match *self {
+            JobPhase::FetchKey => serializer.serialize_str("FetchKey"),
+            JobPhase::Clone => serializer.serialize_str("Clone"),
+            JobPhase::Build => serializer.serialize_str("Build"),
+            JobPhase::PostProcess => serializer.serialize_str("PostProcess"),
+            JobPhase::Publish => serializer.serialize_str("Publish"),
+        }
+    }
+}
+
+impl Serialize for JobPhaseTime {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let mut strukt = try!(serializer.serialize_struct("job_phase", 3));
+        try!(strukt.serialize_field("phase", &self.get_phase()));
+        try!(strukt.serialize_field("started_at", self.get_started_at()));
+        if self.has_finished_at() {
+            try!(strukt.serialize_field("finished_at", self.get_finished_at()));
+        }
+        strukt.end()
+    }
+}
+
+impl Serialize for PostProcessResult {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let mut strukt = try!(serializer.serialize_struct("post_process_result", 3));
+        try!(strukt.serialize_field("name", self.get_name()));
+        try!(strukt.serialize_field("success", &self.get_success()));
+        try!(strukt.serialize_field("detail", self.get_detail()));
+        strukt.end()
+    }
+}
+
 impl FromStr for JobState {
     type Err = Error;
 
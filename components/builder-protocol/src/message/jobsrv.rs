@@ -22,134 +22,151 @@ use protobuf::Message as Message_imported_for_functions;
 use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Heartbeat {
+pub struct JobPhaseTime {
     // message fields
-    endpoint: ::protobuf::SingularField<::std::string::String>,
-    os: ::std::option::Option<Os>,
-    state: ::std::option::Option<WorkerState>,
+    phase: ::std::option::Option<JobPhase>,
+    started_at: ::protobuf::SingularField<::std::string::String>,
+    finished_at: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for Heartbeat {}
+unsafe impl ::std::marker::Sync for JobPhaseTime {}
 
-impl Heartbeat {
-    pub fn new() -> Heartbeat {
+impl JobPhaseTime {
+    pub fn new() -> JobPhaseTime {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static Heartbeat {
-        static mut instance: ::protobuf::lazy::Lazy<Heartbeat> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static JobPhaseTime {
+        static mut instance: ::protobuf::lazy::Lazy<JobPhaseTime> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Heartbeat,
+            ptr: 0 as *const JobPhaseTime,
         };
         unsafe {
-            instance.get(Heartbeat::new)
+            instance.get(JobPhaseTime::new)
         }
     }
 
-    // optional string endpoint = 1;
+    // optional .jobsrv.JobPhase phase = 1;
 
-    pub fn clear_endpoint(&mut self) {
-        self.endpoint.clear();
+    pub fn clear_phase(&mut self) {
+        self.phase = ::std::option::Option::None;
     }
 
-    pub fn has_endpoint(&self) -> bool {
-        self.endpoint.is_some()
+    pub fn has_phase(&self) -> bool {
+        self.phase.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_endpoint(&mut self, v: ::std::string::String) {
-        self.endpoint = ::protobuf::SingularField::some(v);
+    pub fn set_phase(&mut self, v: JobPhase) {
+        self.phase = ::std::option::Option::Some(v);
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_endpoint(&mut self) -> &mut ::std::string::String {
-        if self.endpoint.is_none() {
-            self.endpoint.set_default();
-        };
-        self.endpoint.as_mut().unwrap()
+    pub fn get_phase(&self) -> JobPhase {
+        self.phase.unwrap_or(JobPhase::FetchKey)
     }
 
-    // Take field
-    pub fn take_endpoint(&mut self) -> ::std::string::String {
-        self.endpoint.take().unwrap_or_else(|| ::std::string::String::new())
+    fn get_phase_for_reflect(&self) -> &::std::option::Option<JobPhase> {
+        &self.phase
     }
 
-    pub fn get_endpoint(&self) -> &str {
-        match self.endpoint.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    fn mut_phase_for_reflect(&mut self) -> &mut ::std::option::Option<JobPhase> {
+        &mut self.phase
     }
 
-    fn get_endpoint_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.endpoint
-    }
+    // optional string started_at = 2;
 
-    fn mut_endpoint_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.endpoint
+    pub fn clear_started_at(&mut self) {
+        self.started_at.clear();
     }
 
-    // optional .jobsrv.Os os = 2;
+    pub fn has_started_at(&self) -> bool {
+        self.started_at.is_some()
+    }
 
-    pub fn clear_os(&mut self) {
-        self.os = ::std::option::Option::None;
+    // Param is passed by value, moved
+    pub fn set_started_at(&mut self, v: ::std::string::String) {
+        self.started_at = ::protobuf::SingularField::some(v);
     }
 
-    pub fn has_os(&self) -> bool {
-        self.os.is_some()
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_started_at(&mut self) -> &mut ::std::string::String {
+        if self.started_at.is_none() {
+            self.started_at.set_default();
+        };
+        self.started_at.as_mut().unwrap()
     }
 
-    // Param is passed by value, moved
-    pub fn set_os(&mut self, v: Os) {
-        self.os = ::std::option::Option::Some(v);
+    // Take field
+    pub fn take_started_at(&mut self) -> ::std::string::String {
+        self.started_at.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_os(&self) -> Os {
-        self.os.unwrap_or(Os::Linux)
+    pub fn get_started_at(&self) -> &str {
+        match self.started_at.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 
-    fn get_os_for_reflect(&self) -> &::std::option::Option<Os> {
-        &self.os
+    fn get_started_at_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.started_at
     }
 
-    fn mut_os_for_reflect(&mut self) -> &mut ::std::option::Option<Os> {
-        &mut self.os
+    fn mut_started_at_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.started_at
     }
 
-    // optional .jobsrv.WorkerState state = 3;
+    // optional string finished_at = 3;
 
-    pub fn clear_state(&mut self) {
-        self.state = ::std::option::Option::None;
+    pub fn clear_finished_at(&mut self) {
+        self.finished_at.clear();
     }
 
-    pub fn has_state(&self) -> bool {
-        self.state.is_some()
+    pub fn has_finished_at(&self) -> bool {
+        self.finished_at.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_state(&mut self, v: WorkerState) {
-        self.state = ::std::option::Option::Some(v);
+    pub fn set_finished_at(&mut self, v: ::std::string::String) {
+        self.finished_at = ::protobuf::SingularField::some(v);
     }
 
-    pub fn get_state(&self) -> WorkerState {
-        self.state.unwrap_or(WorkerState::Ready)
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_finished_at(&mut self) -> &mut ::std::string::String {
+        if self.finished_at.is_none() {
+            self.finished_at.set_default();
+        };
+        self.finished_at.as_mut().unwrap()
     }
 
-    fn get_state_for_reflect(&self) -> &::std::option::Option<WorkerState> {
-        &self.state
+    // Take field
+    pub fn take_finished_at(&mut self) -> ::std::string::String {
+        self.finished_at.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    fn mut_state_for_reflect(&mut self) -> &mut ::std::option::Option<WorkerState> {
-        &mut self.state
+    pub fn get_finished_at(&self) -> &str {
+        match self.finished_at.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_finished_at_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.finished_at
+    }
+
+    fn mut_finished_at_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.finished_at
     }
 }
 
-impl ::protobuf::Message for Heartbeat {
+impl ::protobuf::Message for JobPhaseTime {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -159,21 +176,17 @@ impl ::protobuf::Message for Heartbeat {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.endpoint)?;
-                },
-                2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     };
                     let tmp = is.read_enum()?;
-                    self.os = ::std::option::Option::Some(tmp);
+                    self.phase = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.started_at)?;
                 },
                 3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    let tmp = is.read_enum()?;
-                    self.state = ::std::option::Option::Some(tmp);
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.finished_at)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -187,14 +200,14 @@ impl ::protobuf::Message for Heartbeat {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(v) = self.endpoint.as_ref() {
-            my_size += ::protobuf::rt::string_size(1, &v);
+        if let Some(v) = self.phase {
+            my_size += ::protobuf::rt::enum_size(1, v);
         };
-        if let Some(v) = self.os {
-            my_size += ::protobuf::rt::enum_size(2, v);
+        if let Some(v) = self.started_at.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
         };
-        if let Some(v) = self.state {
-            my_size += ::protobuf::rt::enum_size(3, v);
+        if let Some(v) = self.finished_at.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -202,14 +215,14 @@ impl ::protobuf::Message for Heartbeat {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.endpoint.as_ref() {
-            os.write_string(1, &v)?;
+        if let Some(v) = self.phase {
+            os.write_enum(1, v.value())?;
         };
-        if let Some(v) = self.os {
-            os.write_enum(2, v.value())?;
+        if let Some(v) = self.started_at.as_ref() {
+            os.write_string(2, &v)?;
         };
-        if let Some(v) = self.state {
-            os.write_enum(3, v.value())?;
+        if let Some(v) = self.finished_at.as_ref() {
+            os.write_string(3, &v)?;
         };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -242,12 +255,12 @@ impl ::protobuf::Message for Heartbeat {
     }
 }
 
-impl ::protobuf::MessageStatic for Heartbeat {
-    fn new() -> Heartbeat {
-        Heartbeat::new()
+impl ::protobuf::MessageStatic for JobPhaseTime {
+    fn new() -> JobPhaseTime {
+        JobPhaseTime::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Heartbeat>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<JobPhaseTime>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -255,23 +268,23 @@ impl ::protobuf::MessageStatic for Heartbeat {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "endpoint",
-                    Heartbeat::get_endpoint_for_reflect,
-                    Heartbeat::mut_endpoint_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<JobPhase>>(
+                    "phase",
+                    JobPhaseTime::get_phase_for_reflect,
+                    JobPhaseTime::mut_phase_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Os>>(
-                    "os",
-                    Heartbeat::get_os_for_reflect,
-                    Heartbeat::mut_os_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "started_at",
+                    JobPhaseTime::get_started_at_for_reflect,
+                    JobPhaseTime::mut_started_at_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<WorkerState>>(
-                    "state",
-                    Heartbeat::get_state_for_reflect,
-                    Heartbeat::mut_state_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "finished_at",
+                    JobPhaseTime::get_finished_at_for_reflect,
+                    JobPhaseTime::mut_finished_at_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Heartbeat>(
-                    "Heartbeat",
+                ::protobuf::reflect::MessageDescriptor::new::<JobPhaseTime>(
+                    "JobPhaseTime",
                     fields,
                     file_descriptor_proto()
                 )
@@ -280,428 +293,1949 @@ impl ::protobuf::MessageStatic for Heartbeat {
     }
 }
 
-impl ::protobuf::Clear for Heartbeat {
+impl ::protobuf::Clear for JobPhaseTime {
     fn clear(&mut self) {
-        self.clear_endpoint();
-        self.clear_os();
-        self.clear_state();
+        self.clear_phase();
+        self.clear_started_at();
+        self.clear_finished_at();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Heartbeat {
+impl ::std::fmt::Debug for JobPhaseTime {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Heartbeat {
+impl ::protobuf::reflect::ProtobufValue for JobPhaseTime {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Job {
+pub struct PostProcessResult {
     // message fields
-    id: ::std::option::Option<u64>,
-    owner_id: ::std::option::Option<u64>,
-    state: ::std::option::Option<JobState>,
-    project: ::protobuf::SingularPtrField<super::originsrv::OriginProject>,
-    error: ::protobuf::SingularPtrField<super::net::NetError>,
-    created_at: ::protobuf::SingularField<::std::string::String>,
-    build_started_at: ::protobuf::SingularField<::std::string::String>,
-    build_finished_at: ::protobuf::SingularField<::std::string::String>,
-    package_ident: ::protobuf::SingularPtrField<super::originsrv::OriginPackageIdent>,
-    is_archived: ::std::option::Option<bool>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    success: ::std::option::Option<bool>,
+    detail: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for Job {}
+unsafe impl ::std::marker::Sync for PostProcessResult {}
 
-impl Job {
-    pub fn new() -> Job {
+impl PostProcessResult {
+    pub fn new() -> PostProcessResult {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static Job {
-        static mut instance: ::protobuf::lazy::Lazy<Job> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static PostProcessResult {
+        static mut instance: ::protobuf::lazy::Lazy<PostProcessResult> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Job,
+            ptr: 0 as *const PostProcessResult,
         };
         unsafe {
-            instance.get(Job::new)
+            instance.get(PostProcessResult::new)
         }
     }
 
-    // optional uint64 id = 1;
+    // optional string name = 1;
 
-    pub fn clear_id(&mut self) {
-        self.id = ::std::option::Option::None;
+    pub fn clear_name(&mut self) {
+        self.name.clear();
     }
 
-    pub fn has_id(&self) -> bool {
-        self.id.is_some()
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_id(&mut self, v: u64) {
-        self.id = ::std::option::Option::Some(v);
-    }
-
-    pub fn get_id(&self) -> u64 {
-        self.id.unwrap_or(0)
-    }
-
-    fn get_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.id
-    }
-
-    fn mut_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.id
-    }
-
-    // optional uint64 owner_id = 2;
-
-    pub fn clear_owner_id(&mut self) {
-        self.owner_id = ::std::option::Option::None;
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
     }
 
-    pub fn has_owner_id(&self) -> bool {
-        self.owner_id.is_some()
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        };
+        self.name.as_mut().unwrap()
     }
 
-    // Param is passed by value, moved
-    pub fn set_owner_id(&mut self, v: u64) {
-        self.owner_id = ::std::option::Option::Some(v);
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_owner_id(&self) -> u64 {
-        self.owner_id.unwrap_or(0)
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 
-    fn get_owner_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.owner_id
+    fn get_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.name
     }
 
-    fn mut_owner_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.owner_id
+    fn mut_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.name
     }
 
-    // optional .jobsrv.JobState state = 3;
+    // optional bool success = 2;
 
-    pub fn clear_state(&mut self) {
-        self.state = ::std::option::Option::None;
+    pub fn clear_success(&mut self) {
+        self.success = ::std::option::Option::None;
     }
 
-    pub fn has_state(&self) -> bool {
-        self.state.is_some()
+    pub fn has_success(&self) -> bool {
+        self.success.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_state(&mut self, v: JobState) {
-        self.state = ::std::option::Option::Some(v);
+    pub fn set_success(&mut self, v: bool) {
+        self.success = ::std::option::Option::Some(v);
     }
 
-    pub fn get_state(&self) -> JobState {
-        self.state.unwrap_or(JobState::Pending)
+    pub fn get_success(&self) -> bool {
+        self.success.unwrap_or(false)
     }
 
-    fn get_state_for_reflect(&self) -> &::std::option::Option<JobState> {
-        &self.state
+    fn get_success_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.success
     }
 
-    fn mut_state_for_reflect(&mut self) -> &mut ::std::option::Option<JobState> {
-        &mut self.state
+    fn mut_success_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.success
     }
 
-    // optional .originsrv.OriginProject project = 4;
+    // optional string detail = 3;
 
-    pub fn clear_project(&mut self) {
-        self.project.clear();
+    pub fn clear_detail(&mut self) {
+        self.detail.clear();
     }
 
-    pub fn has_project(&self) -> bool {
-        self.project.is_some()
+    pub fn has_detail(&self) -> bool {
+        self.detail.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_project(&mut self, v: super::originsrv::OriginProject) {
-        self.project = ::protobuf::SingularPtrField::some(v);
+    pub fn set_detail(&mut self, v: ::std::string::String) {
+        self.detail = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_project(&mut self) -> &mut super::originsrv::OriginProject {
-        if self.project.is_none() {
-            self.project.set_default();
+    pub fn mut_detail(&mut self) -> &mut ::std::string::String {
+        if self.detail.is_none() {
+            self.detail.set_default();
         };
-        self.project.as_mut().unwrap()
+        self.detail.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_project(&mut self) -> super::originsrv::OriginProject {
-        self.project.take().unwrap_or_else(|| super::originsrv::OriginProject::new())
+    pub fn take_detail(&mut self) -> ::std::string::String {
+        self.detail.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_project(&self) -> &super::originsrv::OriginProject {
-        self.project.as_ref().unwrap_or_else(|| super::originsrv::OriginProject::default_instance())
+    pub fn get_detail(&self) -> &str {
+        match self.detail.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 
-    fn get_project_for_reflect(&self) -> &::protobuf::SingularPtrField<super::originsrv::OriginProject> {
-        &self.project
+    fn get_detail_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.detail
     }
 
-    fn mut_project_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<super::originsrv::OriginProject> {
-        &mut self.project
+    fn mut_detail_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.detail
     }
+}
 
-    // optional .net.NetError error = 5;
-
-    pub fn clear_error(&mut self) {
-        self.error.clear();
+impl ::protobuf::Message for PostProcessResult {
+    fn is_initialized(&self) -> bool {
+        true
     }
 
-    pub fn has_error(&self) -> bool {
-        self.error.is_some()
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_bool()?;
+                    self.success = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.detail)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
     }
 
-    // Param is passed by value, moved
-    pub fn set_error(&mut self, v: super::net::NetError) {
-        self.error = ::protobuf::SingularPtrField::some(v);
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        };
+        if let Some(v) = self.success {
+            my_size += 2;
+        };
+        if let Some(v) = self.detail.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_error(&mut self) -> &mut super::net::NetError {
-        if self.error.is_none() {
-            self.error.set_default();
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.name.as_ref() {
+            os.write_string(1, &v)?;
         };
-        self.error.as_mut().unwrap()
+        if let Some(v) = self.success {
+            os.write_bool(2, v)?;
+        };
+        if let Some(v) = self.detail.as_ref() {
+            os.write_string(3, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
     }
 
-    // Take field
-    pub fn take_error(&mut self) -> super::net::NetError {
-        self.error.take().unwrap_or_else(|| super::net::NetError::new())
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
     }
 
-    pub fn get_error(&self) -> &super::net::NetError {
-        self.error.as_ref().unwrap_or_else(|| super::net::NetError::default_instance())
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
     }
 
-    fn get_error_for_reflect(&self) -> &::protobuf::SingularPtrField<super::net::NetError> {
-        &self.error
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
     }
 
-    fn mut_error_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<super::net::NetError> {
-        &mut self.error
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
     }
-
-    // optional string created_at = 6;
-
-    pub fn clear_created_at(&mut self) {
-        self.created_at.clear();
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
     }
 
-    pub fn has_created_at(&self) -> bool {
-        self.created_at.is_some()
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_created_at(&mut self, v: ::std::string::String) {
-        self.created_at = ::protobuf::SingularField::some(v);
+impl ::protobuf::MessageStatic for PostProcessResult {
+    fn new() -> PostProcessResult {
+        PostProcessResult::new()
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_created_at(&mut self) -> &mut ::std::string::String {
-        if self.created_at.is_none() {
-            self.created_at.set_default();
+    fn descriptor_static(_: ::std::option::Option<PostProcessResult>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
         };
-        self.created_at.as_mut().unwrap()
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "name",
+                    PostProcessResult::get_name_for_reflect,
+                    PostProcessResult::mut_name_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "success",
+                    PostProcessResult::get_success_for_reflect,
+                    PostProcessResult::mut_success_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "detail",
+                    PostProcessResult::get_detail_for_reflect,
+                    PostProcessResult::mut_detail_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<PostProcessResult>(
+                    "PostProcessResult",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
     }
+}
 
-    // Take field
-    pub fn take_created_at(&mut self) -> ::std::string::String {
-        self.created_at.take().unwrap_or_else(|| ::std::string::String::new())
+impl ::protobuf::Clear for PostProcessResult {
+    fn clear(&mut self) {
+        self.clear_name();
+        self.clear_success();
+        self.clear_detail();
+        self.unknown_fields.clear();
     }
+}
 
-    pub fn get_created_at(&self) -> &str {
-        match self.created_at.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+impl ::std::fmt::Debug for PostProcessResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
+}
 
-    fn get_created_at_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.created_at
+impl ::protobuf::reflect::ProtobufValue for PostProcessResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
+}
 
-    fn mut_created_at_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.created_at
+#[derive(PartialEq,Clone,Default)]
+pub struct Heartbeat {
+    // message fields
+    endpoint: ::protobuf::SingularField<::std::string::String>,
+    os: ::std::option::Option<Os>,
+    state: ::std::option::Option<WorkerState>,
+    slots: ::std::option::Option<u32>,
+    free_slots: ::std::option::Option<u32>,
+    jobs_received: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Heartbeat {}
+
+impl Heartbeat {
+    pub fn new() -> Heartbeat {
+        ::std::default::Default::default()
     }
 
-    // optional string build_started_at = 7;
+    pub fn default_instance() -> &'static Heartbeat {
+        static mut instance: ::protobuf::lazy::Lazy<Heartbeat> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Heartbeat,
+        };
+        unsafe {
+            instance.get(Heartbeat::new)
+        }
+    }
 
-    pub fn clear_build_started_at(&mut self) {
-        self.build_started_at.clear();
+    // optional string endpoint = 1;
+
+    pub fn clear_endpoint(&mut self) {
+        self.endpoint.clear();
     }
 
-    pub fn has_build_started_at(&self) -> bool {
-        self.build_started_at.is_some()
+    pub fn has_endpoint(&self) -> bool {
+        self.endpoint.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_build_started_at(&mut self, v: ::std::string::String) {
-        self.build_started_at = ::protobuf::SingularField::some(v);
+    pub fn set_endpoint(&mut self, v: ::std::string::String) {
+        self.endpoint = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_build_started_at(&mut self) -> &mut ::std::string::String {
-        if self.build_started_at.is_none() {
-            self.build_started_at.set_default();
+    pub fn mut_endpoint(&mut self) -> &mut ::std::string::String {
+        if self.endpoint.is_none() {
+            self.endpoint.set_default();
         };
-        self.build_started_at.as_mut().unwrap()
+        self.endpoint.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_build_started_at(&mut self) -> ::std::string::String {
-        self.build_started_at.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_endpoint(&mut self) -> ::std::string::String {
+        self.endpoint.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_build_started_at(&self) -> &str {
-        match self.build_started_at.as_ref() {
+    pub fn get_endpoint(&self) -> &str {
+        match self.endpoint.as_ref() {
             Some(v) => &v,
             None => "",
         }
     }
 
-    fn get_build_started_at_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.build_started_at
+    fn get_endpoint_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.endpoint
     }
 
-    fn mut_build_started_at_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.build_started_at
+    fn mut_endpoint_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.endpoint
     }
 
-    // optional string build_finished_at = 8;
+    // optional .jobsrv.Os os = 2;
 
-    pub fn clear_build_finished_at(&mut self) {
-        self.build_finished_at.clear();
+    pub fn clear_os(&mut self) {
+        self.os = ::std::option::Option::None;
     }
 
-    pub fn has_build_finished_at(&self) -> bool {
-        self.build_finished_at.is_some()
+    pub fn has_os(&self) -> bool {
+        self.os.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_build_finished_at(&mut self, v: ::std::string::String) {
-        self.build_finished_at = ::protobuf::SingularField::some(v);
+    pub fn set_os(&mut self, v: Os) {
+        self.os = ::std::option::Option::Some(v);
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_build_finished_at(&mut self) -> &mut ::std::string::String {
-        if self.build_finished_at.is_none() {
-            self.build_finished_at.set_default();
-        };
-        self.build_finished_at.as_mut().unwrap()
+    pub fn get_os(&self) -> Os {
+        self.os.unwrap_or(Os::Linux)
     }
 
-    // Take field
-    pub fn take_build_finished_at(&mut self) -> ::std::string::String {
-        self.build_finished_at.take().unwrap_or_else(|| ::std::string::String::new())
+    fn get_os_for_reflect(&self) -> &::std::option::Option<Os> {
+        &self.os
     }
 
-    pub fn get_build_finished_at(&self) -> &str {
-        match self.build_finished_at.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    fn mut_os_for_reflect(&mut self) -> &mut ::std::option::Option<Os> {
+        &mut self.os
     }
 
-    fn get_build_finished_at_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.build_finished_at
-    }
+    // optional .jobsrv.WorkerState state = 3;
 
-    fn mut_build_finished_at_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.build_finished_at
+    pub fn clear_state(&mut self) {
+        self.state = ::std::option::Option::None;
     }
 
-    // optional .originsrv.OriginPackageIdent package_ident = 9;
-
-    pub fn clear_package_ident(&mut self) {
-        self.package_ident.clear();
+    pub fn has_state(&self) -> bool {
+        self.state.is_some()
     }
 
-    pub fn has_package_ident(&self) -> bool {
-        self.package_ident.is_some()
+    // Param is passed by value, moved
+    pub fn set_state(&mut self, v: WorkerState) {
+        self.state = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_state(&self) -> WorkerState {
+        self.state.unwrap_or(WorkerState::Ready)
+    }
+
+    fn get_state_for_reflect(&self) -> &::std::option::Option<WorkerState> {
+        &self.state
+    }
+
+    fn mut_state_for_reflect(&mut self) -> &mut ::std::option::Option<WorkerState> {
+        &mut self.state
+    }
+
+    // optional uint32 slots = 4;
+
+    pub fn clear_slots(&mut self) {
+        self.slots = ::std::option::Option::None;
+    }
+
+    pub fn has_slots(&self) -> bool {
+        self.slots.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_slots(&mut self, v: u32) {
+        self.slots = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_slots(&self) -> u32 {
+        self.slots.unwrap_or(0)
+    }
+
+    fn get_slots_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.slots
+    }
+
+    fn mut_slots_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.slots
+    }
+
+    // optional uint32 free_slots = 5;
+
+    pub fn clear_free_slots(&mut self) {
+        self.free_slots = ::std::option::Option::None;
+    }
+
+    pub fn has_free_slots(&self) -> bool {
+        self.free_slots.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_free_slots(&mut self, v: u32) {
+        self.free_slots = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_free_slots(&self) -> u32 {
+        self.free_slots.unwrap_or(0)
+    }
+
+    fn get_free_slots_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.free_slots
+    }
+
+    fn mut_free_slots_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.free_slots
+    }
+
+    // optional uint64 jobs_received = 6;
+
+    pub fn clear_jobs_received(&mut self) {
+        self.jobs_received = ::std::option::Option::None;
+    }
+
+    pub fn has_jobs_received(&self) -> bool {
+        self.jobs_received.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_jobs_received(&mut self, v: u64) {
+        self.jobs_received = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_jobs_received(&self) -> u64 {
+        self.jobs_received.unwrap_or(0)
+    }
+
+    fn get_jobs_received_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.jobs_received
+    }
+
+    fn mut_jobs_received_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.jobs_received
+    }
+}
+
+impl ::protobuf::Message for Heartbeat {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.endpoint)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_enum()?;
+                    self.os = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_enum()?;
+                    self.state = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_uint32()?;
+                    self.slots = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_uint32()?;
+                    self.free_slots = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_uint64()?;
+                    self.jobs_received = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.endpoint.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        };
+        if let Some(v) = self.os {
+            my_size += ::protobuf::rt::enum_size(2, v);
+        };
+        if let Some(v) = self.state {
+            my_size += ::protobuf::rt::enum_size(3, v);
+        };
+        if let Some(v) = self.slots {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if let Some(v) = self.free_slots {
+            my_size += ::protobuf::rt::value_size(5, v, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if let Some(v) = self.jobs_received {
+            my_size += ::protobuf::rt::value_size(6, v, ::protobuf::wire_format::WireTypeVarint);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.endpoint.as_ref() {
+            os.write_string(1, &v)?;
+        };
+        if let Some(v) = self.os {
+            os.write_enum(2, v.value())?;
+        };
+        if let Some(v) = self.state {
+            os.write_enum(3, v.value())?;
+        };
+        if let Some(v) = self.slots {
+            os.write_uint32(4, v)?;
+        };
+        if let Some(v) = self.free_slots {
+            os.write_uint32(5, v)?;
+        };
+        if let Some(v) = self.jobs_received {
+            os.write_uint64(6, v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Heartbeat {
+    fn new() -> Heartbeat {
+        Heartbeat::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Heartbeat>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "endpoint",
+                    Heartbeat::get_endpoint_for_reflect,
+                    Heartbeat::mut_endpoint_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Os>>(
+                    "os",
+                    Heartbeat::get_os_for_reflect,
+                    Heartbeat::mut_os_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<WorkerState>>(
+                    "state",
+                    Heartbeat::get_state_for_reflect,
+                    Heartbeat::mut_state_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "slots",
+                    Heartbeat::get_slots_for_reflect,
+                    Heartbeat::mut_slots_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "free_slots",
+                    Heartbeat::get_free_slots_for_reflect,
+                    Heartbeat::mut_free_slots_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "jobs_received",
+                    Heartbeat::get_jobs_received_for_reflect,
+                    Heartbeat::mut_jobs_received_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Heartbeat>(
+                    "Heartbeat",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Heartbeat {
+    fn clear(&mut self) {
+        self.clear_endpoint();
+        self.clear_os();
+        self.clear_state();
+        self.clear_slots();
+        self.clear_free_slots();
+        self.clear_jobs_received();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Heartbeat {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Heartbeat {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Job {
+    // message fields
+    id: ::std::option::Option<u64>,
+    owner_id: ::std::option::Option<u64>,
+    state: ::std::option::Option<JobState>,
+    project: ::protobuf::SingularPtrField<super::originsrv::OriginProject>,
+    error: ::protobuf::SingularPtrField<super::net::NetError>,
+    created_at: ::protobuf::SingularField<::std::string::String>,
+    build_started_at: ::protobuf::SingularField<::std::string::String>,
+    build_finished_at: ::protobuf::SingularField<::std::string::String>,
+    package_ident: ::protobuf::SingularPtrField<super::originsrv::OriginPackageIdent>,
+    is_archived: ::std::option::Option<bool>,
+    cpu_usage_ms: ::std::option::Option<u64>,
+    memory_usage_bytes: ::std::option::Option<u64>,
+    phases: ::protobuf::RepeatedField<JobPhaseTime>,
+    worker: ::protobuf::SingularField<::std::string::String>,
+    shelved: ::std::option::Option<bool>,
+    post_process: ::protobuf::RepeatedField<PostProcessResult>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Job {}
+
+impl Job {
+    pub fn new() -> Job {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Job {
+        static mut instance: ::protobuf::lazy::Lazy<Job> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Job,
+        };
+        unsafe {
+            instance.get(Job::new)
+        }
+    }
+
+    // optional uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    fn get_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.id
+    }
+
+    fn mut_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.id
+    }
+
+    // optional uint64 owner_id = 2;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    fn get_owner_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.owner_id
+    }
+
+    fn mut_owner_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.owner_id
+    }
+
+    // optional .jobsrv.JobState state = 3;
+
+    pub fn clear_state(&mut self) {
+        self.state = ::std::option::Option::None;
+    }
+
+    pub fn has_state(&self) -> bool {
+        self.state.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_state(&mut self, v: JobState) {
+        self.state = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_state(&self) -> JobState {
+        self.state.unwrap_or(JobState::Pending)
+    }
+
+    fn get_state_for_reflect(&self) -> &::std::option::Option<JobState> {
+        &self.state
+    }
+
+    fn mut_state_for_reflect(&mut self) -> &mut ::std::option::Option<JobState> {
+        &mut self.state
+    }
+
+    // optional .originsrv.OriginProject project = 4;
+
+    pub fn clear_project(&mut self) {
+        self.project.clear();
+    }
+
+    pub fn has_project(&self) -> bool {
+        self.project.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_project(&mut self, v: super::originsrv::OriginProject) {
+        self.project = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_project(&mut self) -> &mut super::originsrv::OriginProject {
+        if self.project.is_none() {
+            self.project.set_default();
+        };
+        self.project.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_project(&mut self) -> super::originsrv::OriginProject {
+        self.project.take().unwrap_or_else(|| super::originsrv::OriginProject::new())
+    }
+
+    pub fn get_project(&self) -> &super::originsrv::OriginProject {
+        self.project.as_ref().unwrap_or_else(|| super::originsrv::OriginProject::default_instance())
+    }
+
+    fn get_project_for_reflect(&self) -> &::protobuf::SingularPtrField<super::originsrv::OriginProject> {
+        &self.project
+    }
+
+    fn mut_project_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<super::originsrv::OriginProject> {
+        &mut self.project
+    }
+
+    // optional .net.NetError error = 5;
+
+    pub fn clear_error(&mut self) {
+        self.error.clear();
+    }
+
+    pub fn has_error(&self) -> bool {
+        self.error.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error(&mut self, v: super::net::NetError) {
+        self.error = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_error(&mut self) -> &mut super::net::NetError {
+        if self.error.is_none() {
+            self.error.set_default();
+        };
+        self.error.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_error(&mut self) -> super::net::NetError {
+        self.error.take().unwrap_or_else(|| super::net::NetError::new())
+    }
+
+    pub fn get_error(&self) -> &super::net::NetError {
+        self.error.as_ref().unwrap_or_else(|| super::net::NetError::default_instance())
+    }
+
+    fn get_error_for_reflect(&self) -> &::protobuf::SingularPtrField<super::net::NetError> {
+        &self.error
+    }
+
+    fn mut_error_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<super::net::NetError> {
+        &mut self.error
+    }
+
+    // optional string created_at = 6;
+
+    pub fn clear_created_at(&mut self) {
+        self.created_at.clear();
+    }
+
+    pub fn has_created_at(&self) -> bool {
+        self.created_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_created_at(&mut self, v: ::std::string::String) {
+        self.created_at = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_created_at(&mut self) -> &mut ::std::string::String {
+        if self.created_at.is_none() {
+            self.created_at.set_default();
+        };
+        self.created_at.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_created_at(&mut self) -> ::std::string::String {
+        self.created_at.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_created_at(&self) -> &str {
+        match self.created_at.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_created_at_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.created_at
+    }
+
+    fn mut_created_at_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.created_at
+    }
+
+    // optional string build_started_at = 7;
+
+    pub fn clear_build_started_at(&mut self) {
+        self.build_started_at.clear();
+    }
+
+    pub fn has_build_started_at(&self) -> bool {
+        self.build_started_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_build_started_at(&mut self, v: ::std::string::String) {
+        self.build_started_at = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_build_started_at(&mut self) -> &mut ::std::string::String {
+        if self.build_started_at.is_none() {
+            self.build_started_at.set_default();
+        };
+        self.build_started_at.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_build_started_at(&mut self) -> ::std::string::String {
+        self.build_started_at.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_build_started_at(&self) -> &str {
+        match self.build_started_at.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_build_started_at_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.build_started_at
+    }
+
+    fn mut_build_started_at_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.build_started_at
+    }
+
+    // optional string build_finished_at = 8;
+
+    pub fn clear_build_finished_at(&mut self) {
+        self.build_finished_at.clear();
+    }
+
+    pub fn has_build_finished_at(&self) -> bool {
+        self.build_finished_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_build_finished_at(&mut self, v: ::std::string::String) {
+        self.build_finished_at = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_build_finished_at(&mut self) -> &mut ::std::string::String {
+        if self.build_finished_at.is_none() {
+            self.build_finished_at.set_default();
+        };
+        self.build_finished_at.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_build_finished_at(&mut self) -> ::std::string::String {
+        self.build_finished_at.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_build_finished_at(&self) -> &str {
+        match self.build_finished_at.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_build_finished_at_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.build_finished_at
+    }
+
+    fn mut_build_finished_at_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.build_finished_at
+    }
+
+    // optional .originsrv.OriginPackageIdent package_ident = 9;
+
+    pub fn clear_package_ident(&mut self) {
+        self.package_ident.clear();
+    }
+
+    pub fn has_package_ident(&self) -> bool {
+        self.package_ident.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_package_ident(&mut self, v: super::originsrv::OriginPackageIdent) {
+        self.package_ident = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_package_ident(&mut self) -> &mut super::originsrv::OriginPackageIdent {
+        if self.package_ident.is_none() {
+            self.package_ident.set_default();
+        };
+        self.package_ident.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_package_ident(&mut self) -> super::originsrv::OriginPackageIdent {
+        self.package_ident.take().unwrap_or_else(|| super::originsrv::OriginPackageIdent::new())
+    }
+
+    pub fn get_package_ident(&self) -> &super::originsrv::OriginPackageIdent {
+        self.package_ident.as_ref().unwrap_or_else(|| super::originsrv::OriginPackageIdent::default_instance())
+    }
+
+    fn get_package_ident_for_reflect(&self) -> &::protobuf::SingularPtrField<super::originsrv::OriginPackageIdent> {
+        &self.package_ident
+    }
+
+    fn mut_package_ident_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<super::originsrv::OriginPackageIdent> {
+        &mut self.package_ident
+    }
+
+    // optional bool is_archived = 11;
+
+    pub fn clear_is_archived(&mut self) {
+        self.is_archived = ::std::option::Option::None;
+    }
+
+    pub fn has_is_archived(&self) -> bool {
+        self.is_archived.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_archived(&mut self, v: bool) {
+        self.is_archived = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_is_archived(&self) -> bool {
+        self.is_archived.unwrap_or(false)
+    }
+
+    fn get_is_archived_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.is_archived
+    }
+
+    fn mut_is_archived_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.is_archived
+    }
+
+    // optional uint64 cpu_usage_ms = 12;
+
+    pub fn clear_cpu_usage_ms(&mut self) {
+        self.cpu_usage_ms = ::std::option::Option::None;
+    }
+
+    pub fn has_cpu_usage_ms(&self) -> bool {
+        self.cpu_usage_ms.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cpu_usage_ms(&mut self, v: u64) {
+        self.cpu_usage_ms = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_cpu_usage_ms(&self) -> u64 {
+        self.cpu_usage_ms.unwrap_or(0)
+    }
+
+    fn get_cpu_usage_ms_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.cpu_usage_ms
+    }
+
+    fn mut_cpu_usage_ms_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.cpu_usage_ms
+    }
+
+    // optional uint64 memory_usage_bytes = 13;
+
+    pub fn clear_memory_usage_bytes(&mut self) {
+        self.memory_usage_bytes = ::std::option::Option::None;
+    }
+
+    pub fn has_memory_usage_bytes(&self) -> bool {
+        self.memory_usage_bytes.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_memory_usage_bytes(&mut self, v: u64) {
+        self.memory_usage_bytes = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_memory_usage_bytes(&self) -> u64 {
+        self.memory_usage_bytes.unwrap_or(0)
+    }
+
+    fn get_memory_usage_bytes_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.memory_usage_bytes
+    }
+
+    fn mut_memory_usage_bytes_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.memory_usage_bytes
+    }
+
+    // repeated .jobsrv.JobPhaseTime phases = 14;
+
+    pub fn clear_phases(&mut self) {
+        self.phases.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_phases(&mut self, v: ::protobuf::RepeatedField<JobPhaseTime>) {
+        self.phases = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_phases(&mut self) -> &mut ::protobuf::RepeatedField<JobPhaseTime> {
+        &mut self.phases
+    }
+
+    // Take field
+    pub fn take_phases(&mut self) -> ::protobuf::RepeatedField<JobPhaseTime> {
+        ::std::mem::replace(&mut self.phases, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_phases(&self) -> &[JobPhaseTime] {
+        &self.phases
+    }
+
+    fn get_phases_for_reflect(&self) -> &::protobuf::RepeatedField<JobPhaseTime> {
+        &self.phases
+    }
+
+    fn mut_phases_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<JobPhaseTime> {
+        &mut self.phases
+    }
+
+    // optional string worker = 15;
+
+    pub fn clear_worker(&mut self) {
+        self.worker.clear();
+    }
+
+    pub fn has_worker(&self) -> bool {
+        self.worker.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_worker(&mut self, v: ::std::string::String) {
+        self.worker = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_worker(&mut self) -> &mut ::std::string::String {
+        if self.worker.is_none() {
+            self.worker.set_default();
+        };
+        self.worker.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_worker(&mut self) -> ::std::string::String {
+        self.worker.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_worker(&self) -> &str {
+        match self.worker.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_worker_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.worker
+    }
+
+    fn mut_worker_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.worker
+    }
+
+    // optional bool shelved = 16;
+
+    pub fn clear_shelved(&mut self) {
+        self.shelved = ::std::option::Option::None;
+    }
+
+    pub fn has_shelved(&self) -> bool {
+        self.shelved.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_shelved(&mut self, v: bool) {
+        self.shelved = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_shelved(&self) -> bool {
+        self.shelved.unwrap_or(false)
+    }
+
+    fn get_shelved_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.shelved
+    }
+
+    fn mut_shelved_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.shelved
+    }
+
+    // repeated .jobsrv.PostProcessResult post_process = 17;
+
+    pub fn clear_post_process(&mut self) {
+        self.post_process.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_post_process(&mut self, v: ::protobuf::RepeatedField<PostProcessResult>) {
+        self.post_process = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_post_process(&mut self) -> &mut ::protobuf::RepeatedField<PostProcessResult> {
+        &mut self.post_process
+    }
+
+    // Take field
+    pub fn take_post_process(&mut self) -> ::protobuf::RepeatedField<PostProcessResult> {
+        ::std::mem::replace(&mut self.post_process, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_post_process(&self) -> &[PostProcessResult] {
+        &self.post_process
+    }
+
+    fn get_post_process_for_reflect(&self) -> &::protobuf::RepeatedField<PostProcessResult> {
+        &self.post_process
+    }
+
+    fn mut_post_process_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<PostProcessResult> {
+        &mut self.post_process
+    }
+}
+
+impl ::protobuf::Message for Job {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_uint64()?;
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_uint64()?;
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_enum()?;
+                    self.state = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.project)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.error)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.created_at)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.build_started_at)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.build_finished_at)?;
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.package_ident)?;
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_bool()?;
+                    self.is_archived = ::std::option::Option::Some(tmp);
+                },
+                12 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_uint64()?;
+                    self.cpu_usage_ms = ::std::option::Option::Some(tmp);
+                },
+                13 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_uint64()?;
+                    self.memory_usage_bytes = ::std::option::Option::Some(tmp);
+                },
+                14 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.phases)?;
+                },
+                15 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.worker)?;
+                },
+                16 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_bool()?;
+                    self.shelved = ::std::option::Option::Some(tmp);
+                },
+                17 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.post_process)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if let Some(v) = self.owner_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if let Some(v) = self.state {
+            my_size += ::protobuf::rt::enum_size(3, v);
+        };
+        if let Some(v) = self.project.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(v) = self.error.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(v) = self.created_at.as_ref() {
+            my_size += ::protobuf::rt::string_size(6, &v);
+        };
+        if let Some(v) = self.build_started_at.as_ref() {
+            my_size += ::protobuf::rt::string_size(7, &v);
+        };
+        if let Some(v) = self.build_finished_at.as_ref() {
+            my_size += ::protobuf::rt::string_size(8, &v);
+        };
+        if let Some(v) = self.package_ident.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(v) = self.is_archived {
+            my_size += 2;
+        };
+        if let Some(v) = self.cpu_usage_ms {
+            my_size += ::protobuf::rt::value_size(12, v, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if let Some(v) = self.memory_usage_bytes {
+            my_size += ::protobuf::rt::value_size(13, v, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in &self.phases {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(v) = self.worker.as_ref() {
+            my_size += ::protobuf::rt::string_size(15, &v);
+        };
+        if let Some(v) = self.shelved {
+            my_size += 3;
+        };
+        for value in &self.post_process {
+            let len = value.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            os.write_uint64(1, v)?;
+        };
+        if let Some(v) = self.owner_id {
+            os.write_uint64(2, v)?;
+        };
+        if let Some(v) = self.state {
+            os.write_enum(3, v.value())?;
+        };
+        if let Some(v) = self.project.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(v) = self.error.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(v) = self.created_at.as_ref() {
+            os.write_string(6, &v)?;
+        };
+        if let Some(v) = self.build_started_at.as_ref() {
+            os.write_string(7, &v)?;
+        };
+        if let Some(v) = self.build_finished_at.as_ref() {
+            os.write_string(8, &v)?;
+        };
+        if let Some(v) = self.package_ident.as_ref() {
+            os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(v) = self.is_archived {
+            os.write_bool(11, v)?;
+        };
+        if let Some(v) = self.cpu_usage_ms {
+            os.write_uint64(12, v)?;
+        };
+        if let Some(v) = self.memory_usage_bytes {
+            os.write_uint64(13, v)?;
+        };
+        for v in &self.phases {
+            os.write_tag(14, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(v) = self.worker.as_ref() {
+            os.write_string(15, &v)?;
+        };
+        if let Some(v) = self.shelved {
+            os.write_bool(16, v)?;
+        };
+        for v in &self.post_process {
+            os.write_tag(17, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Job {
+    fn new() -> Job {
+        Job::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Job>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "id",
+                    Job::get_id_for_reflect,
+                    Job::mut_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "owner_id",
+                    Job::get_owner_id_for_reflect,
+                    Job::mut_owner_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<JobState>>(
+                    "state",
+                    Job::get_state_for_reflect,
+                    Job::mut_state_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::originsrv::OriginProject>>(
+                    "project",
+                    Job::get_project_for_reflect,
+                    Job::mut_project_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::net::NetError>>(
+                    "error",
+                    Job::get_error_for_reflect,
+                    Job::mut_error_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "created_at",
+                    Job::get_created_at_for_reflect,
+                    Job::mut_created_at_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "build_started_at",
+                    Job::get_build_started_at_for_reflect,
+                    Job::mut_build_started_at_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "build_finished_at",
+                    Job::get_build_finished_at_for_reflect,
+                    Job::mut_build_finished_at_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::originsrv::OriginPackageIdent>>(
+                    "package_ident",
+                    Job::get_package_ident_for_reflect,
+                    Job::mut_package_ident_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "is_archived",
+                    Job::get_is_archived_for_reflect,
+                    Job::mut_is_archived_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "cpu_usage_ms",
+                    Job::get_cpu_usage_ms_for_reflect,
+                    Job::mut_cpu_usage_ms_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "memory_usage_bytes",
+                    Job::get_memory_usage_bytes_for_reflect,
+                    Job::mut_memory_usage_bytes_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<JobPhaseTime>>(
+                    "phases",
+                    Job::get_phases_for_reflect,
+                    Job::mut_phases_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "worker",
+                    Job::get_worker_for_reflect,
+                    Job::mut_worker_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "shelved",
+                    Job::get_shelved_for_reflect,
+                    Job::mut_shelved_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PostProcessResult>>(
+                    "post_process",
+                    Job::get_post_process_for_reflect,
+                    Job::mut_post_process_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Job>(
+                    "Job",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Job {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_owner_id();
+        self.clear_state();
+        self.clear_project();
+        self.clear_error();
+        self.clear_created_at();
+        self.clear_build_started_at();
+        self.clear_build_finished_at();
+        self.clear_package_ident();
+        self.clear_is_archived();
+        self.clear_cpu_usage_ms();
+        self.clear_memory_usage_bytes();
+        self.clear_phases();
+        self.clear_worker();
+        self.clear_shelved();
+        self.clear_post_process();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Job {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Job {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobCancel {
+    // message fields
+    id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobCancel {}
+
+impl JobCancel {
+    pub fn new() -> JobCancel {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobCancel {
+        static mut instance: ::protobuf::lazy::Lazy<JobCancel> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobCancel,
+        };
+        unsafe {
+            instance.get(JobCancel::new)
+        }
+    }
+
+    // optional uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_package_ident(&mut self, v: super::originsrv::OriginPackageIdent) {
-        self.package_ident = ::protobuf::SingularPtrField::some(v);
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_package_ident(&mut self) -> &mut super::originsrv::OriginPackageIdent {
-        if self.package_ident.is_none() {
-            self.package_ident.set_default();
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    fn get_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.id
+    }
+
+    fn mut_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.id
+    }
+}
+
+impl ::protobuf::Message for JobCancel {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    };
+                    let tmp = is.read_uint64()?;
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
         };
-        self.package_ident.as_mut().unwrap()
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
     }
 
-    // Take field
-    pub fn take_package_ident(&mut self) -> super::originsrv::OriginPackageIdent {
-        self.package_ident.take().unwrap_or_else(|| super::originsrv::OriginPackageIdent::new())
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            os.write_uint64(1, v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobCancel {
+    fn new() -> JobCancel {
+        JobCancel::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobCancel>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "id",
+                    JobCancel::get_id_for_reflect,
+                    JobCancel::mut_id_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobCancel>(
+                    "JobCancel",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobCancel {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobCancel {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
+}
 
-    pub fn get_package_ident(&self) -> &super::originsrv::OriginPackageIdent {
-        self.package_ident.as_ref().unwrap_or_else(|| super::originsrv::OriginPackageIdent::default_instance())
+impl ::protobuf::reflect::ProtobufValue for JobCancel {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
+}
 
-    fn get_package_ident_for_reflect(&self) -> &::protobuf::SingularPtrField<super::originsrv::OriginPackageIdent> {
-        &self.package_ident
+#[derive(PartialEq,Clone,Default)]
+pub struct JobPublishRetry {
+    // message fields
+    id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobPublishRetry {}
+
+impl JobPublishRetry {
+    pub fn new() -> JobPublishRetry {
+        ::std::default::Default::default()
     }
 
-    fn mut_package_ident_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<super::originsrv::OriginPackageIdent> {
-        &mut self.package_ident
+    pub fn default_instance() -> &'static JobPublishRetry {
+        static mut instance: ::protobuf::lazy::Lazy<JobPublishRetry> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobPublishRetry,
+        };
+        unsafe {
+            instance.get(JobPublishRetry::new)
+        }
     }
 
-    // optional bool is_archived = 11;
+    // optional uint64 id = 1;
 
-    pub fn clear_is_archived(&mut self) {
-        self.is_archived = ::std::option::Option::None;
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
     }
 
-    pub fn has_is_archived(&self) -> bool {
-        self.is_archived.is_some()
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_is_archived(&mut self, v: bool) {
-        self.is_archived = ::std::option::Option::Some(v);
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_is_archived(&self) -> bool {
-        self.is_archived.unwrap_or(false)
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
     }
 
-    fn get_is_archived_for_reflect(&self) -> &::std::option::Option<bool> {
-        &self.is_archived
+    fn get_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.id
     }
 
-    fn mut_is_archived_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
-        &mut self.is_archived
+    fn mut_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.id
     }
 }
 
-impl ::protobuf::Message for Job {
+impl ::protobuf::Message for JobPublishRetry {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -717,45 +2251,6 @@ impl ::protobuf::Message for Job {
                     let tmp = is.read_uint64()?;
                     self.id = ::std::option::Option::Some(tmp);
                 },
-                2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    let tmp = is.read_uint64()?;
-                    self.owner_id = ::std::option::Option::Some(tmp);
-                },
-                3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    let tmp = is.read_enum()?;
-                    self.state = ::std::option::Option::Some(tmp);
-                },
-                4 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.project)?;
-                },
-                5 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.error)?;
-                },
-                6 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.created_at)?;
-                },
-                7 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.build_started_at)?;
-                },
-                8 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.build_finished_at)?;
-                },
-                9 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.package_ident)?;
-                },
-                11 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    };
-                    let tmp = is.read_bool()?;
-                    self.is_archived = ::std::option::Option::Some(tmp);
-                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -771,36 +2266,6 @@ impl ::protobuf::Message for Job {
         if let Some(v) = self.id {
             my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
         };
-        if let Some(v) = self.owner_id {
-            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
-        };
-        if let Some(v) = self.state {
-            my_size += ::protobuf::rt::enum_size(3, v);
-        };
-        if let Some(v) = self.project.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
-        if let Some(v) = self.error.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
-        if let Some(v) = self.created_at.as_ref() {
-            my_size += ::protobuf::rt::string_size(6, &v);
-        };
-        if let Some(v) = self.build_started_at.as_ref() {
-            my_size += ::protobuf::rt::string_size(7, &v);
-        };
-        if let Some(v) = self.build_finished_at.as_ref() {
-            my_size += ::protobuf::rt::string_size(8, &v);
-        };
-        if let Some(v) = self.package_ident.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
-        if let Some(v) = self.is_archived {
-            my_size += 2;
-        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -810,39 +2275,6 @@ impl ::protobuf::Message for Job {
         if let Some(v) = self.id {
             os.write_uint64(1, v)?;
         };
-        if let Some(v) = self.owner_id {
-            os.write_uint64(2, v)?;
-        };
-        if let Some(v) = self.state {
-            os.write_enum(3, v.value())?;
-        };
-        if let Some(v) = self.project.as_ref() {
-            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        };
-        if let Some(v) = self.error.as_ref() {
-            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        };
-        if let Some(v) = self.created_at.as_ref() {
-            os.write_string(6, &v)?;
-        };
-        if let Some(v) = self.build_started_at.as_ref() {
-            os.write_string(7, &v)?;
-        };
-        if let Some(v) = self.build_finished_at.as_ref() {
-            os.write_string(8, &v)?;
-        };
-        if let Some(v) = self.package_ident.as_ref() {
-            os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        };
-        if let Some(v) = self.is_archived {
-            os.write_bool(11, v)?;
-        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -874,12 +2306,12 @@ impl ::protobuf::Message for Job {
     }
 }
 
-impl ::protobuf::MessageStatic for Job {
-    fn new() -> Job {
-        Job::new()
+impl ::protobuf::MessageStatic for JobPublishRetry {
+    fn new() -> JobPublishRetry {
+        JobPublishRetry::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Job>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<JobPublishRetry>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -889,56 +2321,11 @@ impl ::protobuf::MessageStatic for Job {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
                     "id",
-                    Job::get_id_for_reflect,
-                    Job::mut_id_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "owner_id",
-                    Job::get_owner_id_for_reflect,
-                    Job::mut_owner_id_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<JobState>>(
-                    "state",
-                    Job::get_state_for_reflect,
-                    Job::mut_state_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::originsrv::OriginProject>>(
-                    "project",
-                    Job::get_project_for_reflect,
-                    Job::mut_project_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::net::NetError>>(
-                    "error",
-                    Job::get_error_for_reflect,
-                    Job::mut_error_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "created_at",
-                    Job::get_created_at_for_reflect,
-                    Job::mut_created_at_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "build_started_at",
-                    Job::get_build_started_at_for_reflect,
-                    Job::mut_build_started_at_for_reflect,
+                    JobPublishRetry::get_id_for_reflect,
+                    JobPublishRetry::mut_id_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "build_finished_at",
-                    Job::get_build_finished_at_for_reflect,
-                    Job::mut_build_finished_at_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::originsrv::OriginPackageIdent>>(
-                    "package_ident",
-                    Job::get_package_ident_for_reflect,
-                    Job::mut_package_ident_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "is_archived",
-                    Job::get_is_archived_for_reflect,
-                    Job::mut_is_archived_for_reflect,
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<Job>(
-                    "Job",
+                ::protobuf::reflect::MessageDescriptor::new::<JobPublishRetry>(
+                    "JobPublishRetry",
                     fields,
                     file_descriptor_proto()
                 )
@@ -947,29 +2334,20 @@ impl ::protobuf::MessageStatic for Job {
     }
 }
 
-impl ::protobuf::Clear for Job {
+impl ::protobuf::Clear for JobPublishRetry {
     fn clear(&mut self) {
         self.clear_id();
-        self.clear_owner_id();
-        self.clear_state();
-        self.clear_project();
-        self.clear_error();
-        self.clear_created_at();
-        self.clear_build_started_at();
-        self.clear_build_finished_at();
-        self.clear_package_ident();
-        self.clear_is_archived();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Job {
+impl ::std::fmt::Debug for JobPublishRetry {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Job {
+impl ::protobuf::reflect::ProtobufValue for JobPublishRetry {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
@@ -2843,6 +4221,7 @@ pub enum JobState {
     Rejected = 3,
     Failed = 4,
     Dispatched = 5,
+    Cancelled = 6,
 }
 
 impl ::protobuf::ProtobufEnum for JobState {
@@ -2858,6 +4237,7 @@ impl ::protobuf::ProtobufEnum for JobState {
             3 => ::std::option::Option::Some(JobState::Rejected),
             4 => ::std::option::Option::Some(JobState::Failed),
             5 => ::std::option::Option::Some(JobState::Dispatched),
+            6 => ::std::option::Option::Some(JobState::Cancelled),
             _ => ::std::option::Option::None
         }
     }
@@ -2870,6 +4250,7 @@ impl ::protobuf::ProtobufEnum for JobState {
             JobState::Rejected,
             JobState::Failed,
             JobState::Dispatched,
+            JobState::Cancelled,
         ];
         values
     }
@@ -2896,360 +4277,654 @@ impl ::protobuf::reflect::ProtobufValue for JobState {
     }
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum JobPhase {
+    FetchKey = 0,
+    Clone = 1,
+    Build = 2,
+    PostProcess = 3,
+    Publish = 4,
+}
+
+impl ::protobuf::ProtobufEnum for JobPhase {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<JobPhase> {
+        match value {
+            0 => ::std::option::Option::Some(JobPhase::FetchKey),
+            1 => ::std::option::Option::Some(JobPhase::Clone),
+            2 => ::std::option::Option::Some(JobPhase::Build),
+            3 => ::std::option::Option::Some(JobPhase::PostProcess),
+            4 => ::std::option::Option::Some(JobPhase::Publish),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [JobPhase] = &[
+            JobPhase::FetchKey,
+            JobPhase::Clone,
+            JobPhase::Build,
+            JobPhase::PostProcess,
+            JobPhase::Publish,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: Option<JobPhase>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("JobPhase", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for JobPhase {
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobPhase {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = &[
     0x0a, 0x16, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x73, 0x2f, 0x6a, 0x6f, 0x62, 0x73,
     0x72, 0x76, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x06, 0x6a, 0x6f, 0x62, 0x73, 0x72, 0x76,
     0x1a, 0x13, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x73, 0x2f, 0x6e, 0x65, 0x74, 0x2e,
     0x70, 0x72, 0x6f, 0x74, 0x6f, 0x1a, 0x19, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x73,
     0x2f, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x73, 0x72, 0x76, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f,
-    0x22, 0x6e, 0x0a, 0x09, 0x48, 0x65, 0x61, 0x72, 0x74, 0x62, 0x65, 0x61, 0x74, 0x12, 0x1a, 0x0a,
-    0x08, 0x65, 0x6e, 0x64, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52,
-    0x08, 0x65, 0x6e, 0x64, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x12, 0x1a, 0x0a, 0x02, 0x6f, 0x73, 0x18,
-    0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x0a, 0x2e, 0x6a, 0x6f, 0x62, 0x73, 0x72, 0x76, 0x2e, 0x4f,
-    0x73, 0x52, 0x02, 0x6f, 0x73, 0x12, 0x29, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x74, 0x65, 0x18, 0x03,
-    0x20, 0x01, 0x28, 0x0e, 0x32, 0x13, 0x2e, 0x6a, 0x6f, 0x62, 0x73, 0x72, 0x76, 0x2e, 0x57, 0x6f,
-    0x72, 0x6b, 0x65, 0x72, 0x53, 0x74, 0x61, 0x74, 0x65, 0x52, 0x05, 0x73, 0x74, 0x61, 0x74, 0x65,
-    0x22, 0x9a, 0x03, 0x0a, 0x03, 0x4a, 0x6f, 0x62, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01,
-    0x20, 0x01, 0x28, 0x04, 0x52, 0x02, 0x69, 0x64, 0x12, 0x19, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65,
-    0x72, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x77, 0x6e, 0x65,
-    0x72, 0x49, 0x64, 0x12, 0x26, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x74, 0x65, 0x18, 0x03, 0x20, 0x01,
-    0x28, 0x0e, 0x32, 0x10, 0x2e, 0x6a, 0x6f, 0x62, 0x73, 0x72, 0x76, 0x2e, 0x4a, 0x6f, 0x62, 0x53,
-    0x74, 0x61, 0x74, 0x65, 0x52, 0x05, 0x73, 0x74, 0x61, 0x74, 0x65, 0x12, 0x32, 0x0a, 0x07, 0x70,
-    0x72, 0x6f, 0x6a, 0x65, 0x63, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x18, 0x2e, 0x6f,
-    0x72, 0x69, 0x67, 0x69, 0x6e, 0x73, 0x72, 0x76, 0x2e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x50,
-    0x72, 0x6f, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x07, 0x70, 0x72, 0x6f, 0x6a, 0x65, 0x63, 0x74, 0x12,
-    0x23, 0x0a, 0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0d,
-    0x2e, 0x6e, 0x65, 0x74, 0x2e, 0x4e, 0x65, 0x74, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x52, 0x05, 0x65,
-    0x72, 0x72, 0x6f, 0x72, 0x12, 0x1d, 0x0a, 0x0a, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x5f,
-    0x61, 0x74, 0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65,
-    0x64, 0x41, 0x74, 0x12, 0x28, 0x0a, 0x10, 0x62, 0x75, 0x69, 0x6c, 0x64, 0x5f, 0x73, 0x74, 0x61,
-    0x72, 0x74, 0x65, 0x64, 0x5f, 0x61, 0x74, 0x18, 0x07, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0e, 0x62,
-    0x75, 0x69, 0x6c, 0x64, 0x53, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x41, 0x74, 0x12, 0x2a, 0x0a,
-    0x11, 0x62, 0x75, 0x69, 0x6c, 0x64, 0x5f, 0x66, 0x69, 0x6e, 0x69, 0x73, 0x68, 0x65, 0x64, 0x5f,
-    0x61, 0x74, 0x18, 0x08, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0f, 0x62, 0x75, 0x69, 0x6c, 0x64, 0x46,
-    0x69, 0x6e, 0x69, 0x73, 0x68, 0x65, 0x64, 0x41, 0x74, 0x12, 0x42, 0x0a, 0x0d, 0x70, 0x61, 0x63,
-    0x6b, 0x61, 0x67, 0x65, 0x5f, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0b,
-    0x32, 0x1d, 0x2e, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x73, 0x72, 0x76, 0x2e, 0x4f, 0x72, 0x69,
-    0x67, 0x69, 0x6e, 0x50, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x49, 0x64, 0x65, 0x6e, 0x74, 0x52,
-    0x0c, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x49, 0x64, 0x65, 0x6e, 0x74, 0x12, 0x1f, 0x0a,
-    0x0b, 0x69, 0x73, 0x5f, 0x61, 0x72, 0x63, 0x68, 0x69, 0x76, 0x65, 0x64, 0x18, 0x0b, 0x20, 0x01,
-    0x28, 0x08, 0x52, 0x0a, 0x69, 0x73, 0x41, 0x72, 0x63, 0x68, 0x69, 0x76, 0x65, 0x64, 0x4a, 0x04,
-    0x08, 0x0a, 0x10, 0x0b, 0x52, 0x07, 0x6c, 0x6f, 0x67, 0x5f, 0x75, 0x72, 0x6c, 0x22, 0x18, 0x0a,
-    0x06, 0x4a, 0x6f, 0x62, 0x47, 0x65, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20,
-    0x01, 0x28, 0x04, 0x52, 0x02, 0x69, 0x64, 0x22, 0x58, 0x0a, 0x07, 0x4a, 0x6f, 0x62, 0x53, 0x70,
-    0x65, 0x63, 0x12, 0x19, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01,
-    0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x49, 0x64, 0x12, 0x32, 0x0a,
-    0x07, 0x70, 0x72, 0x6f, 0x6a, 0x65, 0x63, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x18,
+    0x22, 0x76, 0x0a, 0x0c, 0x4a, 0x6f, 0x62, 0x50, 0x68, 0x61, 0x73, 0x65, 0x54, 0x69, 0x6d, 0x65,
+    0x12, 0x26, 0x0a, 0x05, 0x70, 0x68, 0x61, 0x73, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e, 0x32,
+    0x10, 0x2e, 0x6a, 0x6f, 0x62, 0x73, 0x72, 0x76, 0x2e, 0x4a, 0x6f, 0x62, 0x50, 0x68, 0x61, 0x73,
+    0x65, 0x52, 0x05, 0x70, 0x68, 0x61, 0x73, 0x65, 0x12, 0x1d, 0x0a, 0x0a, 0x73, 0x74, 0x61, 0x72,
+    0x74, 0x65, 0x64, 0x5f, 0x61, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x73, 0x74,
+    0x61, 0x72, 0x74, 0x65, 0x64, 0x41, 0x74, 0x12, 0x1f, 0x0a, 0x0b, 0x66, 0x69, 0x6e, 0x69, 0x73,
+    0x68, 0x65, 0x64, 0x5f, 0x61, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x66, 0x69,
+    0x6e, 0x69, 0x73, 0x68, 0x65, 0x64, 0x41, 0x74, 0x22, 0x59, 0x0a, 0x11, 0x50, 0x6f, 0x73, 0x74,
+    0x50, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x52, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x12, 0x12, 0x0a,
+    0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d,
+    0x65, 0x12, 0x18, 0x0a, 0x07, 0x73, 0x75, 0x63, 0x63, 0x65, 0x73, 0x73, 0x18, 0x02, 0x20, 0x01,
+    0x28, 0x08, 0x52, 0x07, 0x73, 0x75, 0x63, 0x63, 0x65, 0x73, 0x73, 0x12, 0x16, 0x0a, 0x06, 0x64,
+    0x65, 0x74, 0x61, 0x69, 0x6c, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x64, 0x65, 0x74,
+    0x61, 0x69, 0x6c, 0x22, 0xc8, 0x01, 0x0a, 0x09, 0x48, 0x65, 0x61, 0x72, 0x74, 0x62, 0x65, 0x61,
+    0x74, 0x12, 0x1a, 0x0a, 0x08, 0x65, 0x6e, 0x64, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x18, 0x01, 0x20,
+    0x01, 0x28, 0x09, 0x52, 0x08, 0x65, 0x6e, 0x64, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x12, 0x1a, 0x0a,
+    0x02, 0x6f, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x0a, 0x2e, 0x6a, 0x6f, 0x62, 0x73,
+    0x72, 0x76, 0x2e, 0x4f, 0x73, 0x52, 0x02, 0x6f, 0x73, 0x12, 0x29, 0x0a, 0x05, 0x73, 0x74, 0x61,
+    0x74, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x13, 0x2e, 0x6a, 0x6f, 0x62, 0x73, 0x72,
+    0x76, 0x2e, 0x57, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x53, 0x74, 0x61, 0x74, 0x65, 0x52, 0x05, 0x73,
+    0x74, 0x61, 0x74, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x73, 0x6c, 0x6f, 0x74, 0x73, 0x18, 0x04, 0x20,
+    0x01, 0x28, 0x0d, 0x52, 0x05, 0x73, 0x6c, 0x6f, 0x74, 0x73, 0x12, 0x1d, 0x0a, 0x0a, 0x66, 0x72,
+    0x65, 0x65, 0x5f, 0x73, 0x6c, 0x6f, 0x74, 0x73, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x09,
+    0x66, 0x72, 0x65, 0x65, 0x53, 0x6c, 0x6f, 0x74, 0x73, 0x12, 0x23, 0x0a, 0x0d, 0x6a, 0x6f, 0x62,
+    0x73, 0x5f, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04,
+    0x52, 0x0c, 0x6a, 0x6f, 0x62, 0x73, 0x52, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x22, 0x88,
+    0x05, 0x0a, 0x03, 0x4a, 0x6f, 0x62, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x04, 0x52, 0x02, 0x69, 0x64, 0x12, 0x19, 0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f,
+    0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x49,
+    0x64, 0x12, 0x26, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x74, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0e,
+    0x32, 0x10, 0x2e, 0x6a, 0x6f, 0x62, 0x73, 0x72, 0x76, 0x2e, 0x4a, 0x6f, 0x62, 0x53, 0x74, 0x61,
+    0x74, 0x65, 0x52, 0x05, 0x73, 0x74, 0x61, 0x74, 0x65, 0x12, 0x32, 0x0a, 0x07, 0x70, 0x72, 0x6f,
+    0x6a, 0x65, 0x63, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x18, 0x2e, 0x6f, 0x72, 0x69,
+    0x67, 0x69, 0x6e, 0x73, 0x72, 0x76, 0x2e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x50, 0x72, 0x6f,
+    0x6a, 0x65, 0x63, 0x74, 0x52, 0x07, 0x70, 0x72, 0x6f, 0x6a, 0x65, 0x63, 0x74, 0x12, 0x23, 0x0a,
+    0x05, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x0d, 0x2e, 0x6e,
+    0x65, 0x74, 0x2e, 0x4e, 0x65, 0x74, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x52, 0x05, 0x65, 0x72, 0x72,
+    0x6f, 0x72, 0x12, 0x1d, 0x0a, 0x0a, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x5f, 0x61, 0x74,
+    0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x41,
+    0x74, 0x12, 0x28, 0x0a, 0x10, 0x62, 0x75, 0x69, 0x6c, 0x64, 0x5f, 0x73, 0x74, 0x61, 0x72, 0x74,
+    0x65, 0x64, 0x5f, 0x61, 0x74, 0x18, 0x07, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0e, 0x62, 0x75, 0x69,
+    0x6c, 0x64, 0x53, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x41, 0x74, 0x12, 0x2a, 0x0a, 0x11, 0x62,
+    0x75, 0x69, 0x6c, 0x64, 0x5f, 0x66, 0x69, 0x6e, 0x69, 0x73, 0x68, 0x65, 0x64, 0x5f, 0x61, 0x74,
+    0x18, 0x08, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0f, 0x62, 0x75, 0x69, 0x6c, 0x64, 0x46, 0x69, 0x6e,
+    0x69, 0x73, 0x68, 0x65, 0x64, 0x41, 0x74, 0x12, 0x42, 0x0a, 0x0d, 0x70, 0x61, 0x63, 0x6b, 0x61,
+    0x67, 0x65, 0x5f, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1d,
     0x2e, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x73, 0x72, 0x76, 0x2e, 0x4f, 0x72, 0x69, 0x67, 0x69,
-    0x6e, 0x50, 0x72, 0x6f, 0x6a, 0x65, 0x63, 0x74, 0x52, 0x07, 0x70, 0x72, 0x6f, 0x6a, 0x65, 0x63,
-    0x74, 0x22, 0x24, 0x0a, 0x0e, 0x50, 0x72, 0x6f, 0x6a, 0x65, 0x63, 0x74, 0x4a, 0x6f, 0x62, 0x73,
-    0x47, 0x65, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28,
-    0x09, 0x52, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x22, 0x39, 0x0a, 0x16, 0x50, 0x72, 0x6f, 0x6a, 0x65,
-    0x63, 0x74, 0x4a, 0x6f, 0x62, 0x73, 0x47, 0x65, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73,
-    0x65, 0x12, 0x1f, 0x0a, 0x04, 0x6a, 0x6f, 0x62, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32,
-    0x0b, 0x2e, 0x6a, 0x6f, 0x62, 0x73, 0x72, 0x76, 0x2e, 0x4a, 0x6f, 0x62, 0x52, 0x04, 0x6a, 0x6f,
-    0x62, 0x73, 0x22, 0x50, 0x0a, 0x0b, 0x4a, 0x6f, 0x62, 0x4c, 0x6f, 0x67, 0x43, 0x68, 0x75, 0x6e,
-    0x6b, 0x12, 0x15, 0x0a, 0x06, 0x6a, 0x6f, 0x62, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28,
-    0x04, 0x52, 0x05, 0x6a, 0x6f, 0x62, 0x49, 0x64, 0x12, 0x10, 0x0a, 0x03, 0x73, 0x65, 0x71, 0x18,
-    0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x03, 0x73, 0x65, 0x71, 0x12, 0x18, 0x0a, 0x07, 0x63, 0x6f,
-    0x6e, 0x74, 0x65, 0x6e, 0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x63, 0x6f, 0x6e,
-    0x74, 0x65, 0x6e, 0x74, 0x22, 0x27, 0x0a, 0x0e, 0x4a, 0x6f, 0x62, 0x4c, 0x6f, 0x67, 0x43, 0x6f,
-    0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x12, 0x15, 0x0a, 0x06, 0x6a, 0x6f, 0x62, 0x5f, 0x69, 0x64,
-    0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x05, 0x6a, 0x6f, 0x62, 0x49, 0x64, 0x22, 0x31, 0x0a,
-    0x09, 0x4a, 0x6f, 0x62, 0x4c, 0x6f, 0x67, 0x47, 0x65, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64,
-    0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x02, 0x69, 0x64, 0x12, 0x14, 0x0a, 0x05, 0x73, 0x74,
-    0x61, 0x72, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x05, 0x73, 0x74, 0x61, 0x72, 0x74,
-    0x22, 0x6d, 0x0a, 0x06, 0x4a, 0x6f, 0x62, 0x4c, 0x6f, 0x67, 0x12, 0x14, 0x0a, 0x05, 0x73, 0x74,
-    0x61, 0x72, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x05, 0x73, 0x74, 0x61, 0x72, 0x74,
-    0x12, 0x12, 0x0a, 0x04, 0x73, 0x74, 0x6f, 0x70, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x04,
-    0x73, 0x74, 0x6f, 0x70, 0x12, 0x18, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x18,
-    0x03, 0x20, 0x03, 0x28, 0x09, 0x52, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x12, 0x1f,
-    0x0a, 0x0b, 0x69, 0x73, 0x5f, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x18, 0x04, 0x20,
-    0x01, 0x28, 0x08, 0x52, 0x0a, 0x69, 0x73, 0x43, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x2a,
-    0x28, 0x0a, 0x02, 0x4f, 0x73, 0x12, 0x09, 0x0a, 0x05, 0x4c, 0x69, 0x6e, 0x75, 0x78, 0x10, 0x01,
-    0x12, 0x0a, 0x0a, 0x06, 0x44, 0x61, 0x72, 0x77, 0x69, 0x6e, 0x10, 0x02, 0x12, 0x0b, 0x0a, 0x07,
-    0x57, 0x69, 0x6e, 0x64, 0x6f, 0x77, 0x73, 0x10, 0x03, 0x2a, 0x22, 0x0a, 0x0b, 0x57, 0x6f, 0x72,
-    0x6b, 0x65, 0x72, 0x53, 0x74, 0x61, 0x74, 0x65, 0x12, 0x09, 0x0a, 0x05, 0x52, 0x65, 0x61, 0x64,
-    0x79, 0x10, 0x00, 0x12, 0x08, 0x0a, 0x04, 0x42, 0x75, 0x73, 0x79, 0x10, 0x01, 0x2a, 0x5f, 0x0a,
-    0x08, 0x4a, 0x6f, 0x62, 0x53, 0x74, 0x61, 0x74, 0x65, 0x12, 0x0b, 0x0a, 0x07, 0x50, 0x65, 0x6e,
-    0x64, 0x69, 0x6e, 0x67, 0x10, 0x00, 0x12, 0x0e, 0x0a, 0x0a, 0x50, 0x72, 0x6f, 0x63, 0x65, 0x73,
-    0x73, 0x69, 0x6e, 0x67, 0x10, 0x01, 0x12, 0x0c, 0x0a, 0x08, 0x43, 0x6f, 0x6d, 0x70, 0x6c, 0x65,
-    0x74, 0x65, 0x10, 0x02, 0x12, 0x0c, 0x0a, 0x08, 0x52, 0x65, 0x6a, 0x65, 0x63, 0x74, 0x65, 0x64,
-    0x10, 0x03, 0x12, 0x0a, 0x0a, 0x06, 0x46, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x10, 0x04, 0x12, 0x0e,
-    0x0a, 0x0a, 0x44, 0x69, 0x73, 0x70, 0x61, 0x74, 0x63, 0x68, 0x65, 0x64, 0x10, 0x05, 0x4a, 0x87,
-    0x22, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x74, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03,
-    0x00, 0x08, 0x0e, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x01, 0x07, 0x1c, 0x0a, 0x09,
-    0x0a, 0x02, 0x03, 0x01, 0x12, 0x03, 0x02, 0x07, 0x22, 0x0a, 0x0a, 0x0a, 0x02, 0x05, 0x00, 0x12,
-    0x04, 0x04, 0x00, 0x08, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x00, 0x01, 0x12, 0x03, 0x04, 0x05,
-    0x07, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x00, 0x12, 0x03, 0x05, 0x02, 0x0c, 0x0a, 0x0c,
-    0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x05, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05,
-    0x05, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x05, 0x0a, 0x0b, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00,
-    0x02, 0x01, 0x12, 0x03, 0x06, 0x02, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x01,
-    0x12, 0x03, 0x06, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03,
-    0x06, 0x0b, 0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x02, 0x12, 0x03, 0x07, 0x02, 0x0e,
-    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x07, 0x02, 0x09, 0x0a, 0x0c,
-    0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x07, 0x0c, 0x0d, 0x0a, 0x0a, 0x0a, 0x02,
-    0x05, 0x01, 0x12, 0x04, 0x0a, 0x00, 0x0d, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x01, 0x01, 0x12,
-    0x03, 0x0a, 0x05, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x00, 0x12, 0x03, 0x0b, 0x02,
-    0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0b, 0x02, 0x07, 0x0a,
-    0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x00, 0x02, 0x12, 0x03, 0x0b, 0x0a, 0x0b, 0x0a, 0x0b, 0x0a,
-    0x04, 0x05, 0x01, 0x02, 0x01, 0x12, 0x03, 0x0c, 0x02, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01,
-    0x02, 0x01, 0x01, 0x12, 0x03, 0x0c, 0x02, 0x06, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01,
-    0x02, 0x12, 0x03, 0x0c, 0x09, 0x0a, 0x0a, 0x0a, 0x0a, 0x02, 0x05, 0x02, 0x12, 0x04, 0x0f, 0x00,
-    0x16, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x02, 0x01, 0x12, 0x03, 0x0f, 0x05, 0x0d, 0x0a, 0x0b,
-    0x0a, 0x04, 0x05, 0x02, 0x02, 0x00, 0x12, 0x03, 0x10, 0x02, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
-    0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x10, 0x02, 0x09, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02,
-    0x00, 0x02, 0x12, 0x03, 0x10, 0x0c, 0x0d, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12,
-    0x03, 0x11, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x11,
-    0x02, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x01, 0x02, 0x12, 0x03, 0x11, 0x0f, 0x10,
-    0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x02, 0x12, 0x03, 0x12, 0x02, 0x0f, 0x0a, 0x0c, 0x0a,
-    0x05, 0x05, 0x02, 0x02, 0x02, 0x01, 0x12, 0x03, 0x12, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
-    0x02, 0x02, 0x02, 0x02, 0x12, 0x03, 0x12, 0x0d, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02,
-    0x03, 0x12, 0x03, 0x13, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x03, 0x01, 0x12,
-    0x03, 0x13, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x03, 0x02, 0x12, 0x03, 0x13,
-    0x0d, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x04, 0x12, 0x03, 0x14, 0x02, 0x0d, 0x0a,
-    0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x04, 0x01, 0x12, 0x03, 0x14, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
-    0x05, 0x05, 0x02, 0x02, 0x04, 0x02, 0x12, 0x03, 0x14, 0x0b, 0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x05,
-    0x02, 0x02, 0x05, 0x12, 0x03, 0x15, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x05,
-    0x01, 0x12, 0x03, 0x15, 0x02, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x05, 0x02, 0x12,
-    0x03, 0x15, 0x0f, 0x10, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x18, 0x00, 0x1c, 0x01,
-    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x18, 0x08, 0x11, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x19, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x00, 0x04, 0x12, 0x03, 0x19, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x05,
-    0x12, 0x03, 0x19, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x19, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x19, 0x1d,
-    0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x1a, 0x02, 0x15, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12, 0x03, 0x1a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x01, 0x06, 0x12, 0x03, 0x1a, 0x0b, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x01, 0x01, 0x12, 0x03, 0x1a, 0x0e, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01,
-    0x03, 0x12, 0x03, 0x1a, 0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03,
-    0x1b, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x04, 0x12, 0x03, 0x1b, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x06, 0x12, 0x03, 0x1b, 0x0b, 0x16, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x1b, 0x17, 0x1c, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03, 0x1b, 0x1f, 0x20, 0x0a, 0x0a, 0x0a, 0x02, 0x04,
-    0x01, 0x12, 0x04, 0x1e, 0x00, 0x35, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03,
-    0x1e, 0x08, 0x0b, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x09, 0x12, 0x03, 0x1f, 0x0b, 0x0e, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x01, 0x09, 0x00, 0x12, 0x03, 0x1f, 0x0b, 0x0d, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x01, 0x09, 0x00, 0x01, 0x12, 0x03, 0x1f, 0x0b, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
-    0x09, 0x00, 0x02, 0x12, 0x03, 0x1f, 0x0b, 0x0d, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x0a, 0x12,
-    0x03, 0x20, 0x0b, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x0a, 0x00, 0x12, 0x03, 0x20, 0x0b,
-    0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x21, 0x02, 0x19, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x04, 0x12, 0x03, 0x21, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03, 0x21, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
-    0x02, 0x00, 0x01, 0x12, 0x03, 0x21, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00,
-    0x03, 0x12, 0x03, 0x21, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03,
-    0x22, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x04, 0x12, 0x03, 0x22, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x22, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x22, 0x12, 0x1a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x22, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x01, 0x02, 0x02, 0x12, 0x03, 0x23, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02,
-    0x04, 0x12, 0x03, 0x23, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x06, 0x12,
-    0x03, 0x23, 0x0b, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x23,
-    0x14, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x23, 0x1c, 0x1d,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x03, 0x12, 0x03, 0x24, 0x02, 0x2f, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x01, 0x02, 0x03, 0x04, 0x12, 0x03, 0x24, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x01, 0x02, 0x03, 0x06, 0x12, 0x03, 0x24, 0x0b, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x03, 0x01, 0x12, 0x03, 0x24, 0x23, 0x2a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x03,
-    0x12, 0x03, 0x24, 0x2d, 0x2e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x04, 0x12, 0x03, 0x25,
-    0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x04, 0x12, 0x03, 0x25, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x06, 0x12, 0x03, 0x25, 0x0b, 0x17, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x01, 0x12, 0x03, 0x25, 0x18, 0x1d, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x01, 0x02, 0x04, 0x03, 0x12, 0x03, 0x25, 0x20, 0x21, 0x0a, 0xa5, 0x01, 0x0a, 0x04, 0x04,
-    0x01, 0x02, 0x05, 0x12, 0x03, 0x29, 0x02, 0x21, 0x1a, 0x97, 0x01, 0x20, 0x54, 0x68, 0x65, 0x20,
-    0x52, 0x46, 0x43, 0x33, 0x33, 0x33, 0x39, 0x2d, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x74, 0x65,
-    0x64, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6a, 0x6f, 0x62, 0x20, 0x77,
-    0x61, 0x73, 0x20, 0x65, 0x6e, 0x74, 0x65, 0x72, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x74, 0x6f, 0x20,
-    0x74, 0x68, 0x65, 0x0a, 0x20, 0x73, 0x79, 0x73, 0x74, 0x65, 0x6d, 0x2e, 0x20, 0x49, 0x74, 0x20,
-    0x6d, 0x61, 0x79, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x62, 0x65, 0x67, 0x69, 0x6e, 0x20, 0x70, 0x72,
-    0x6f, 0x63, 0x65, 0x73, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x6f, 0x6d,
-    0x65, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x20, 0x61, 0x66, 0x74, 0x65, 0x72, 0x20, 0x74, 0x68, 0x69,
-    0x73, 0x2c, 0x0a, 0x20, 0x62, 0x61, 0x73, 0x65, 0x64, 0x20, 0x6f, 0x6e, 0x20, 0x63, 0x75, 0x72,
-    0x72, 0x65, 0x6e, 0x74, 0x20, 0x73, 0x79, 0x73, 0x74, 0x65, 0x6d, 0x20, 0x6c, 0x6f, 0x61, 0x64,
-    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x04, 0x12, 0x03, 0x29, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x05, 0x12, 0x03, 0x29, 0x0b, 0x11, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x01, 0x12, 0x03, 0x29, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x01, 0x02, 0x05, 0x03, 0x12, 0x03, 0x29, 0x1f, 0x20, 0x0a, 0x52, 0x0a, 0x04, 0x04, 0x01,
-    0x02, 0x06, 0x12, 0x03, 0x2c, 0x02, 0x27, 0x1a, 0x45, 0x20, 0x54, 0x68, 0x65, 0x20, 0x52, 0x46,
-    0x43, 0x33, 0x33, 0x33, 0x39, 0x2d, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x74, 0x65, 0x64, 0x20,
-    0x74, 0x69, 0x6d, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x60, 0x68, 0x61, 0x62, 0x20, 0x73, 0x74,
-    0x75, 0x64, 0x69, 0x6f, 0x20, 0x62, 0x75, 0x69, 0x6c, 0x64, 0x60, 0x20, 0x70, 0x72, 0x6f, 0x63,
-    0x65, 0x73, 0x73, 0x0a, 0x20, 0x73, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x04, 0x12, 0x03, 0x2c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x01, 0x02, 0x06, 0x05, 0x12, 0x03, 0x2c, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
-    0x02, 0x06, 0x01, 0x12, 0x03, 0x2c, 0x12, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06,
-    0x03, 0x12, 0x03, 0x2c, 0x25, 0x26, 0x0a, 0x65, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x07, 0x12, 0x03,
-    0x2f, 0x02, 0x28, 0x1a, 0x58, 0x20, 0x54, 0x68, 0x65, 0x20, 0x52, 0x46, 0x43, 0x33, 0x33, 0x33,
-    0x39, 0x2d, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x74, 0x65, 0x64, 0x20, 0x74, 0x69, 0x6d, 0x65,
-    0x20, 0x74, 0x68, 0x65, 0x20, 0x60, 0x68, 0x61, 0x62, 0x20, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f,
-    0x20, 0x62, 0x75, 0x69, 0x6c, 0x64, 0x60, 0x20, 0x70, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x0a,
-    0x20, 0x73, 0x74, 0x6f, 0x70, 0x70, 0x65, 0x64, 0x2c, 0x20, 0x73, 0x75, 0x63, 0x63, 0x65, 0x73,
-    0x73, 0x66, 0x75, 0x6c, 0x20, 0x6f, 0x72, 0x20, 0x6e, 0x6f, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x01, 0x02, 0x07, 0x04, 0x12, 0x03, 0x2f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x01, 0x02, 0x07, 0x05, 0x12, 0x03, 0x2f, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x07, 0x01, 0x12, 0x03, 0x2f, 0x12, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x03,
-    0x12, 0x03, 0x2f, 0x26, 0x27, 0x0a, 0x62, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x08, 0x12, 0x03, 0x32,
-    0x02, 0x3a, 0x1a, 0x55, 0x20, 0x54, 0x68, 0x65, 0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66,
-    0x69, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x61, 0x63, 0x6b, 0x61,
-    0x67, 0x65, 0x20, 0x62, 0x75, 0x69, 0x6c, 0x74, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20,
-    0x6a, 0x6f, 0x62, 0x2e, 0x20, 0x53, 0x65, 0x74, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x61, 0x0a,
-    0x20, 0x73, 0x75, 0x63, 0x63, 0x65, 0x73, 0x73, 0x66, 0x75, 0x6c, 0x6c, 0x79, 0x2d, 0x62, 0x75,
-    0x69, 0x6c, 0x74, 0x20, 0x4a, 0x6f, 0x62, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x08, 0x04, 0x12, 0x03, 0x32, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x06,
-    0x12, 0x03, 0x32, 0x0b, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x01, 0x12, 0x03,
-    0x32, 0x28, 0x35, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x03, 0x12, 0x03, 0x32, 0x38,
-    0x39, 0x0a, 0x43, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x09, 0x12, 0x03, 0x34, 0x02, 0x21, 0x1a, 0x36,
-    0x20, 0x57, 0x68, 0x65, 0x74, 0x68, 0x65, 0x72, 0x20, 0x6f, 0x72, 0x20, 0x6e, 0x6f, 0x74, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x6c, 0x6f, 0x67, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20,
-    0x6a, 0x6f, 0x62, 0x20, 0x68, 0x61, 0x73, 0x20, 0x62, 0x65, 0x65, 0x6e, 0x20, 0x61, 0x72, 0x63,
-    0x68, 0x69, 0x76, 0x65, 0x64, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x04, 0x12,
-    0x03, 0x34, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x05, 0x12, 0x03, 0x34,
-    0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x01, 0x12, 0x03, 0x34, 0x10, 0x1b,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x03, 0x12, 0x03, 0x34, 0x1e, 0x20, 0x0a, 0x29,
-    0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x38, 0x00, 0x3a, 0x01, 0x1a, 0x1d, 0x20, 0x52, 0x65, 0x74,
-    0x72, 0x69, 0x65, 0x76, 0x65, 0x20, 0x61, 0x20, 0x73, 0x69, 0x6e, 0x67, 0x6c, 0x65, 0x20, 0x6a,
-    0x6f, 0x62, 0x20, 0x62, 0x79, 0x20, 0x49, 0x44, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01,
-    0x12, 0x03, 0x38, 0x08, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x39,
-    0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x39, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05, 0x12, 0x03, 0x39, 0x0b, 0x11, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x39, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x39, 0x17, 0x18, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03,
-    0x12, 0x04, 0x3c, 0x00, 0x3f, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x3c,
-    0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x3d, 0x02, 0x1f, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x04, 0x12, 0x03, 0x3d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x3d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x3d, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
-    0x00, 0x03, 0x12, 0x03, 0x3d, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12,
-    0x03, 0x3e, 0x02, 0x2f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x04, 0x12, 0x03, 0x3e,
-    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x06, 0x12, 0x03, 0x3e, 0x0b, 0x22,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x3e, 0x23, 0x2a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x3e, 0x2d, 0x2e, 0x0a, 0x88, 0x01, 0x0a,
-    0x02, 0x04, 0x04, 0x12, 0x04, 0x45, 0x00, 0x48, 0x01, 0x1a, 0x7c, 0x20, 0x52, 0x65, 0x74, 0x72,
-    0x69, 0x65, 0x76, 0x65, 0x20, 0x6a, 0x6f, 0x62, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20,
-    0x73, 0x70, 0x65, 0x63, 0x69, 0x66, 0x69, 0x63, 0x20, 0x70, 0x72, 0x6f, 0x6a, 0x65, 0x63, 0x74,
-    0x2e, 0x0a, 0x0a, 0x20, 0x43, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x6c, 0x79, 0x20, 0x72, 0x65,
-    0x74, 0x72, 0x69, 0x65, 0x76, 0x65, 0x73, 0x20, 0x35, 0x30, 0x20, 0x6d, 0x6f, 0x73, 0x74, 0x20,
-    0x72, 0x65, 0x63, 0x65, 0x6e, 0x74, 0x3b, 0x20, 0x61, 0x64, 0x64, 0x69, 0x74, 0x69, 0x6f, 0x6e,
-    0x61, 0x6c, 0x20, 0x66, 0x69, 0x6c, 0x74, 0x65, 0x72, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x6e, 0x64,
-    0x0a, 0x20, 0x73, 0x6f, 0x72, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x63, 0x6f, 0x6d, 0x65, 0x73, 0x20,
-    0x6c, 0x61, 0x74, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03,
-    0x45, 0x08, 0x16, 0x0a, 0x48, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x47, 0x02, 0x1b,
-    0x1a, 0x3b, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x2d, 0x71, 0x75,
-    0x61, 0x6c, 0x69, 0x66, 0x69, 0x65, 0x64, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x20, 0x6f, 0x66, 0x20,
-    0x61, 0x20, 0x70, 0x72, 0x6f, 0x6a, 0x65, 0x63, 0x74, 0x2c, 0x20, 0x65, 0x2e, 0x67, 0x2e, 0x20,
-    0x22, 0x63, 0x6f, 0x72, 0x65, 0x2f, 0x6e, 0x67, 0x69, 0x6e, 0x78, 0x22, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x04, 0x02, 0x00, 0x04, 0x12, 0x03, 0x47, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x04, 0x02, 0x00, 0x05, 0x12, 0x03, 0x47, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02,
-    0x00, 0x01, 0x12, 0x03, 0x47, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03,
-    0x12, 0x03, 0x47, 0x19, 0x1a, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04, 0x4a, 0x00, 0x4c,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x4a, 0x08, 0x1e, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x4b, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
-    0x02, 0x00, 0x04, 0x12, 0x03, 0x4b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00,
-    0x06, 0x12, 0x03, 0x4b, 0x0b, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12,
-    0x03, 0x4b, 0x0f, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x03, 0x4b,
-    0x16, 0x17, 0x0a, 0x51, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x4f, 0x00, 0x58, 0x01, 0x1a, 0x45,
-    0x20, 0x53, 0x65, 0x6e, 0x74, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x61, 0x20, 0x77, 0x6f, 0x72,
-    0x6b, 0x65, 0x72, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6a, 0x6f, 0x62, 0x20, 0x73,
-    0x65, 0x72, 0x76, 0x65, 0x72, 0x27, 0x73, 0x20, 0x6c, 0x6f, 0x67, 0x20, 0x69, 0x6e, 0x67, 0x65,
-    0x73, 0x74, 0x65, 0x72, 0x20, 0x64, 0x75, 0x72, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x20, 0x62, 0x75,
-    0x69, 0x6c, 0x64, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x4f, 0x08,
-    0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x50, 0x02, 0x1d, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x04, 0x12, 0x03, 0x50, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x06, 0x02, 0x00, 0x05, 0x12, 0x03, 0x50, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
-    0x02, 0x00, 0x01, 0x12, 0x03, 0x50, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00,
-    0x03, 0x12, 0x03, 0x50, 0x1b, 0x1c, 0x0a, 0x9c, 0x01, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x01, 0x12,
-    0x03, 0x54, 0x02, 0x1a, 0x1a, 0x8e, 0x01, 0x20, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x69, 0x6e, 0x67,
-    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x63, 0x68, 0x75, 0x6e, 0x6b, 0x20, 0x69,
-    0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x76, 0x65, 0x72, 0x61, 0x6c, 0x6c, 0x20, 0x6c, 0x6f,
-    0x67, 0x20, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x2c, 0x20, 0x73, 0x74, 0x61, 0x72, 0x74, 0x69,
-    0x6e, 0x67, 0x20, 0x61, 0x74, 0x20, 0x31, 0x3b, 0x0a, 0x20, 0x57, 0x65, 0x20, 0x63, 0x75, 0x72,
-    0x72, 0x65, 0x6e, 0x74, 0x6c, 0x79, 0x20, 0x73, 0x65, 0x6e, 0x64, 0x20, 0x6f, 0x6e, 0x65, 0x20,
-    0x6c, 0x69, 0x6e, 0x65, 0x20, 0x61, 0x74, 0x20, 0x61, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x2c, 0x20,
-    0x73, 0x6f, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x63, 0x6f, 0x72, 0x72, 0x65, 0x73, 0x70, 0x6f,
-    0x6e, 0x64, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x6c, 0x69, 0x6e, 0x65, 0x0a, 0x20, 0x6e, 0x75, 0x6d,
-    0x62, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x04, 0x12, 0x03,
-    0x54, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x05, 0x12, 0x03, 0x54, 0x0b,
-    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x01, 0x12, 0x03, 0x54, 0x12, 0x15, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x03, 0x12, 0x03, 0x54, 0x18, 0x19, 0x0a, 0x64, 0x0a,
-    0x04, 0x04, 0x06, 0x02, 0x02, 0x12, 0x03, 0x57, 0x02, 0x1e, 0x1a, 0x57, 0x20, 0x54, 0x68, 0x65,
-    0x20, 0x6c, 0x6f, 0x67, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x20, 0x62, 0x65, 0x69,
-    0x6e, 0x67, 0x20, 0x73, 0x65, 0x6e, 0x74, 0x0a, 0x20, 0x54, 0x4f, 0x44, 0x4f, 0x3a, 0x20, 0x4d,
-    0x61, 0x6b, 0x65, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x61, 0x20, 0x72, 0x65, 0x70, 0x65, 0x61,
-    0x74, 0x65, 0x64, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x66, 0x75,
-    0x74, 0x75, 0x72, 0x65, 0x20, 0x63, 0x6f, 0x6d, 0x70, 0x61, 0x74, 0x69, 0x62, 0x69, 0x6c, 0x69,
-    0x74, 0x79, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x04, 0x12, 0x03, 0x57, 0x02,
-    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x05, 0x12, 0x03, 0x57, 0x0b, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x02, 0x01, 0x12, 0x03, 0x57, 0x12, 0x19, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x06, 0x02, 0x02, 0x03, 0x12, 0x03, 0x57, 0x1c, 0x1d, 0x0a, 0x5c, 0x0a, 0x02, 0x04,
-    0x07, 0x12, 0x04, 0x5c, 0x00, 0x5e, 0x01, 0x1a, 0x50, 0x20, 0x53, 0x65, 0x6e, 0x74, 0x20, 0x66,
-    0x72, 0x6f, 0x6d, 0x20, 0x61, 0x20, 0x77, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x20, 0x74, 0x6f, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x6a, 0x6f, 0x62, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x27, 0x73,
-    0x20, 0x6c, 0x6f, 0x67, 0x20, 0x69, 0x6e, 0x67, 0x65, 0x73, 0x74, 0x65, 0x72, 0x20, 0x77, 0x68,
-    0x65, 0x6e, 0x20, 0x61, 0x20, 0x62, 0x75, 0x69, 0x6c, 0x64, 0x20, 0x69, 0x73, 0x0a, 0x20, 0x63,
-    0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01,
-    0x12, 0x03, 0x5c, 0x08, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x03, 0x5d,
-    0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x04, 0x12, 0x03, 0x5d, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x05, 0x12, 0x03, 0x5d, 0x0b, 0x11, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x5d, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03, 0x5d, 0x1b, 0x1c, 0x0a, 0x6c, 0x0a, 0x02, 0x04, 0x08,
-    0x12, 0x04, 0x62, 0x00, 0x66, 0x01, 0x1a, 0x60, 0x20, 0x49, 0x6e, 0x69, 0x74, 0x69, 0x61, 0x74,
-    0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x41, 0x50, 0x49, 0x20, 0x72, 0x65, 0x71, 0x75, 0x65, 0x73,
-    0x74, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x65, 0x74, 0x72, 0x69, 0x65, 0x76, 0x65, 0x20, 0x61, 0x20,
-    0x70, 0x6f, 0x72, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x6a, 0x6f, 0x62,
-    0x27, 0x73, 0x20, 0x6c, 0x6f, 0x67, 0x20, 0x64, 0x61, 0x74, 0x61, 0x2c, 0x0a, 0x20, 0x62, 0x65,
-    0x67, 0x69, 0x6e, 0x6e, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x74, 0x20, 0x6c, 0x69, 0x6e, 0x65, 0x20,
-    0x60, 0x73, 0x74, 0x61, 0x72, 0x74, 0x60, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12,
-    0x03, 0x62, 0x08, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x63, 0x02,
-    0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x04, 0x12, 0x03, 0x63, 0x02, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x05, 0x12, 0x03, 0x63, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x03, 0x63, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x08, 0x02, 0x00, 0x03, 0x12, 0x03, 0x63, 0x17, 0x18, 0x0a, 0x40, 0x0a, 0x04, 0x04, 0x08, 0x02,
-    0x01, 0x12, 0x03, 0x65, 0x02, 0x1c, 0x1a, 0x33, 0x20, 0x5a, 0x65, 0x72, 0x6f, 0x2d, 0x69, 0x6e,
-    0x64, 0x65, 0x78, 0x65, 0x64, 0x20, 0x6c, 0x69, 0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
-    0x65, 0x20, 0x6c, 0x6f, 0x67, 0x20, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x20, 0x74, 0x6f, 0x20,
-    0x73, 0x74, 0x61, 0x72, 0x74, 0x20, 0x77, 0x69, 0x74, 0x68, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x08, 0x02, 0x01, 0x04, 0x12, 0x03, 0x65, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
-    0x01, 0x05, 0x12, 0x03, 0x65, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x01,
-    0x12, 0x03, 0x65, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x03, 0x12, 0x03,
-    0x65, 0x1a, 0x1b, 0x0a, 0x2a, 0x0a, 0x02, 0x04, 0x09, 0x12, 0x04, 0x69, 0x00, 0x74, 0x01, 0x1a,
-    0x1e, 0x20, 0x53, 0x65, 0x6e, 0x74, 0x20, 0x69, 0x6e, 0x20, 0x72, 0x65, 0x70, 0x6c, 0x79, 0x20,
-    0x74, 0x6f, 0x20, 0x61, 0x20, 0x4a, 0x6f, 0x62, 0x4c, 0x6f, 0x67, 0x47, 0x65, 0x74, 0x0a, 0x0a,
-    0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x03, 0x69, 0x08, 0x0e, 0x0a, 0x4b, 0x0a, 0x04, 0x04,
-    0x09, 0x02, 0x00, 0x12, 0x03, 0x6b, 0x02, 0x1c, 0x1a, 0x3e, 0x20, 0x5a, 0x65, 0x72, 0x6f, 0x2d,
-    0x69, 0x6e, 0x64, 0x65, 0x78, 0x65, 0x64, 0x20, 0x28, 0x69, 0x6e, 0x63, 0x6c, 0x75, 0x73, 0x69,
-    0x76, 0x65, 0x29, 0x20, 0x6c, 0x69, 0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
-    0x6c, 0x6f, 0x67, 0x20, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x20, 0x69, 0x6e, 0x20, 0x60, 0x63,
-    0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x60, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00,
-    0x04, 0x12, 0x03, 0x6b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x05, 0x12,
-    0x03, 0x6b, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x01, 0x12, 0x03, 0x6b,
-    0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x03, 0x12, 0x03, 0x6b, 0x1a, 0x1b,
-    0x0a, 0x4b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x01, 0x12, 0x03, 0x6d, 0x02, 0x1b, 0x1a, 0x3e, 0x20,
-    0x5a, 0x65, 0x72, 0x6f, 0x2d, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x65, 0x64, 0x20, 0x28, 0x65, 0x78,
+    0x6e, 0x50, 0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x49, 0x64, 0x65, 0x6e, 0x74, 0x52, 0x0c, 0x70,
+    0x61, 0x63, 0x6b, 0x61, 0x67, 0x65, 0x49, 0x64, 0x65, 0x6e, 0x74, 0x12, 0x1f, 0x0a, 0x0b, 0x69,
+    0x73, 0x5f, 0x61, 0x72, 0x63, 0x68, 0x69, 0x76, 0x65, 0x64, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x08,
+    0x52, 0x0a, 0x69, 0x73, 0x41, 0x72, 0x63, 0x68, 0x69, 0x76, 0x65, 0x64, 0x12, 0x20, 0x0a, 0x0c,
+    0x63, 0x70, 0x75, 0x5f, 0x75, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x6d, 0x73, 0x18, 0x0c, 0x20, 0x01,
+    0x28, 0x04, 0x52, 0x0a, 0x63, 0x70, 0x75, 0x55, 0x73, 0x61, 0x67, 0x65, 0x4d, 0x73, 0x12, 0x2c,
+    0x0a, 0x12, 0x6d, 0x65, 0x6d, 0x6f, 0x72, 0x79, 0x5f, 0x75, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x62,
+    0x79, 0x74, 0x65, 0x73, 0x18, 0x0d, 0x20, 0x01, 0x28, 0x04, 0x52, 0x10, 0x6d, 0x65, 0x6d, 0x6f,
+    0x72, 0x79, 0x55, 0x73, 0x61, 0x67, 0x65, 0x42, 0x79, 0x74, 0x65, 0x73, 0x12, 0x2c, 0x0a, 0x06,
+    0x70, 0x68, 0x61, 0x73, 0x65, 0x73, 0x18, 0x0e, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x14, 0x2e, 0x6a,
+    0x6f, 0x62, 0x73, 0x72, 0x76, 0x2e, 0x4a, 0x6f, 0x62, 0x50, 0x68, 0x61, 0x73, 0x65, 0x54, 0x69,
+    0x6d, 0x65, 0x52, 0x06, 0x70, 0x68, 0x61, 0x73, 0x65, 0x73, 0x12, 0x16, 0x0a, 0x06, 0x77, 0x6f,
+    0x72, 0x6b, 0x65, 0x72, 0x18, 0x0f, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x77, 0x6f, 0x72, 0x6b,
+    0x65, 0x72, 0x12, 0x18, 0x0a, 0x07, 0x73, 0x68, 0x65, 0x6c, 0x76, 0x65, 0x64, 0x18, 0x10, 0x20,
+    0x01, 0x28, 0x08, 0x52, 0x07, 0x73, 0x68, 0x65, 0x6c, 0x76, 0x65, 0x64, 0x12, 0x3c, 0x0a, 0x0c,
+    0x70, 0x6f, 0x73, 0x74, 0x5f, 0x70, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x18, 0x11, 0x20, 0x03,
+    0x28, 0x0b, 0x32, 0x19, 0x2e, 0x6a, 0x6f, 0x62, 0x73, 0x72, 0x76, 0x2e, 0x50, 0x6f, 0x73, 0x74,
+    0x50, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x52, 0x65, 0x73, 0x75, 0x6c, 0x74, 0x52, 0x0b, 0x70,
+    0x6f, 0x73, 0x74, 0x50, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x4a, 0x04, 0x08, 0x0a, 0x10, 0x0b,
+    0x52, 0x07, 0x6c, 0x6f, 0x67, 0x5f, 0x75, 0x72, 0x6c, 0x22, 0x1b, 0x0a, 0x09, 0x4a, 0x6f, 0x62,
+    0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x04, 0x52, 0x02, 0x69, 0x64, 0x22, 0x21, 0x0a, 0x0f, 0x4a, 0x6f, 0x62, 0x50, 0x75, 0x62,
+    0x6c, 0x69, 0x73, 0x68, 0x52, 0x65, 0x74, 0x72, 0x79, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18,
+    0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x02, 0x69, 0x64, 0x22, 0x18, 0x0a, 0x06, 0x4a, 0x6f, 0x62,
+    0x47, 0x65, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52,
+    0x02, 0x69, 0x64, 0x22, 0x58, 0x0a, 0x07, 0x4a, 0x6f, 0x62, 0x53, 0x70, 0x65, 0x63, 0x12, 0x19,
+    0x0a, 0x08, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04,
+    0x52, 0x07, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x49, 0x64, 0x12, 0x32, 0x0a, 0x07, 0x70, 0x72, 0x6f,
+    0x6a, 0x65, 0x63, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x18, 0x2e, 0x6f, 0x72, 0x69,
+    0x67, 0x69, 0x6e, 0x73, 0x72, 0x76, 0x2e, 0x4f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x50, 0x72, 0x6f,
+    0x6a, 0x65, 0x63, 0x74, 0x52, 0x07, 0x70, 0x72, 0x6f, 0x6a, 0x65, 0x63, 0x74, 0x22, 0x24, 0x0a,
+    0x0e, 0x50, 0x72, 0x6f, 0x6a, 0x65, 0x63, 0x74, 0x4a, 0x6f, 0x62, 0x73, 0x47, 0x65, 0x74, 0x12,
+    0x12, 0x0a, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6e,
+    0x61, 0x6d, 0x65, 0x22, 0x39, 0x0a, 0x16, 0x50, 0x72, 0x6f, 0x6a, 0x65, 0x63, 0x74, 0x4a, 0x6f,
+    0x62, 0x73, 0x47, 0x65, 0x74, 0x52, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x73, 0x65, 0x12, 0x1f, 0x0a,
+    0x04, 0x6a, 0x6f, 0x62, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x0b, 0x2e, 0x6a, 0x6f,
+    0x62, 0x73, 0x72, 0x76, 0x2e, 0x4a, 0x6f, 0x62, 0x52, 0x04, 0x6a, 0x6f, 0x62, 0x73, 0x22, 0x50,
+    0x0a, 0x0b, 0x4a, 0x6f, 0x62, 0x4c, 0x6f, 0x67, 0x43, 0x68, 0x75, 0x6e, 0x6b, 0x12, 0x15, 0x0a,
+    0x06, 0x6a, 0x6f, 0x62, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x05, 0x6a,
+    0x6f, 0x62, 0x49, 0x64, 0x12, 0x10, 0x0a, 0x03, 0x73, 0x65, 0x71, 0x18, 0x02, 0x20, 0x01, 0x28,
+    0x04, 0x52, 0x03, 0x73, 0x65, 0x71, 0x12, 0x18, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e,
+    0x74, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74,
+    0x22, 0x27, 0x0a, 0x0e, 0x4a, 0x6f, 0x62, 0x4c, 0x6f, 0x67, 0x43, 0x6f, 0x6d, 0x70, 0x6c, 0x65,
+    0x74, 0x65, 0x12, 0x15, 0x0a, 0x06, 0x6a, 0x6f, 0x62, 0x5f, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x04, 0x52, 0x05, 0x6a, 0x6f, 0x62, 0x49, 0x64, 0x22, 0x31, 0x0a, 0x09, 0x4a, 0x6f, 0x62,
+    0x4c, 0x6f, 0x67, 0x47, 0x65, 0x74, 0x12, 0x0e, 0x0a, 0x02, 0x69, 0x64, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x04, 0x52, 0x02, 0x69, 0x64, 0x12, 0x14, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x72, 0x74, 0x18,
+    0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x05, 0x73, 0x74, 0x61, 0x72, 0x74, 0x22, 0x6d, 0x0a, 0x06,
+    0x4a, 0x6f, 0x62, 0x4c, 0x6f, 0x67, 0x12, 0x14, 0x0a, 0x05, 0x73, 0x74, 0x61, 0x72, 0x74, 0x18,
+    0x01, 0x20, 0x01, 0x28, 0x04, 0x52, 0x05, 0x73, 0x74, 0x61, 0x72, 0x74, 0x12, 0x12, 0x0a, 0x04,
+    0x73, 0x74, 0x6f, 0x70, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x04, 0x73, 0x74, 0x6f, 0x70,
+    0x12, 0x18, 0x0a, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x18, 0x03, 0x20, 0x03, 0x28,
+    0x09, 0x52, 0x07, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x12, 0x1f, 0x0a, 0x0b, 0x69, 0x73,
+    0x5f, 0x63, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x08, 0x52,
+    0x0a, 0x69, 0x73, 0x43, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x2a, 0x28, 0x0a, 0x02, 0x4f,
+    0x73, 0x12, 0x09, 0x0a, 0x05, 0x4c, 0x69, 0x6e, 0x75, 0x78, 0x10, 0x01, 0x12, 0x0a, 0x0a, 0x06,
+    0x44, 0x61, 0x72, 0x77, 0x69, 0x6e, 0x10, 0x02, 0x12, 0x0b, 0x0a, 0x07, 0x57, 0x69, 0x6e, 0x64,
+    0x6f, 0x77, 0x73, 0x10, 0x03, 0x2a, 0x22, 0x0a, 0x0b, 0x57, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x53,
+    0x74, 0x61, 0x74, 0x65, 0x12, 0x09, 0x0a, 0x05, 0x52, 0x65, 0x61, 0x64, 0x79, 0x10, 0x00, 0x12,
+    0x08, 0x0a, 0x04, 0x42, 0x75, 0x73, 0x79, 0x10, 0x01, 0x2a, 0x6e, 0x0a, 0x08, 0x4a, 0x6f, 0x62,
+    0x53, 0x74, 0x61, 0x74, 0x65, 0x12, 0x0b, 0x0a, 0x07, 0x50, 0x65, 0x6e, 0x64, 0x69, 0x6e, 0x67,
+    0x10, 0x00, 0x12, 0x0e, 0x0a, 0x0a, 0x50, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x69, 0x6e, 0x67,
+    0x10, 0x01, 0x12, 0x0c, 0x0a, 0x08, 0x43, 0x6f, 0x6d, 0x70, 0x6c, 0x65, 0x74, 0x65, 0x10, 0x02,
+    0x12, 0x0c, 0x0a, 0x08, 0x52, 0x65, 0x6a, 0x65, 0x63, 0x74, 0x65, 0x64, 0x10, 0x03, 0x12, 0x0a,
+    0x0a, 0x06, 0x46, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x10, 0x04, 0x12, 0x0e, 0x0a, 0x0a, 0x44, 0x69,
+    0x73, 0x70, 0x61, 0x74, 0x63, 0x68, 0x65, 0x64, 0x10, 0x05, 0x12, 0x0d, 0x0a, 0x09, 0x43, 0x61,
+    0x6e, 0x63, 0x65, 0x6c, 0x6c, 0x65, 0x64, 0x10, 0x06, 0x2a, 0x4c, 0x0a, 0x08, 0x4a, 0x6f, 0x62,
+    0x50, 0x68, 0x61, 0x73, 0x65, 0x12, 0x0c, 0x0a, 0x08, 0x46, 0x65, 0x74, 0x63, 0x68, 0x4b, 0x65,
+    0x79, 0x10, 0x00, 0x12, 0x09, 0x0a, 0x05, 0x43, 0x6c, 0x6f, 0x6e, 0x65, 0x10, 0x01, 0x12, 0x09,
+    0x0a, 0x05, 0x42, 0x75, 0x69, 0x6c, 0x64, 0x10, 0x02, 0x12, 0x0f, 0x0a, 0x0b, 0x50, 0x6f, 0x73,
+    0x74, 0x50, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x10, 0x03, 0x12, 0x0b, 0x0a, 0x07, 0x50, 0x75,
+    0x62, 0x6c, 0x69, 0x73, 0x68, 0x10, 0x04, 0x4a, 0x96, 0x3a, 0x0a, 0x07, 0x12, 0x05, 0x00, 0x00,
+    0xb0, 0x01, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x00, 0x08, 0x0e, 0x0a, 0x09, 0x0a,
+    0x02, 0x03, 0x00, 0x12, 0x03, 0x01, 0x07, 0x1c, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x01, 0x12, 0x03,
+    0x02, 0x07, 0x22, 0x0a, 0x0a, 0x0a, 0x02, 0x05, 0x00, 0x12, 0x04, 0x04, 0x00, 0x08, 0x01, 0x0a,
+    0x0a, 0x0a, 0x03, 0x05, 0x00, 0x01, 0x12, 0x03, 0x04, 0x05, 0x07, 0x0a, 0x0b, 0x0a, 0x04, 0x05,
+    0x00, 0x02, 0x00, 0x12, 0x03, 0x05, 0x02, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x05, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x02, 0x12,
+    0x03, 0x05, 0x0a, 0x0b, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x01, 0x12, 0x03, 0x06, 0x02,
+    0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x06, 0x02, 0x08, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x06, 0x0b, 0x0c, 0x0a, 0x0b, 0x0a,
+    0x04, 0x05, 0x00, 0x02, 0x02, 0x12, 0x03, 0x07, 0x02, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00,
+    0x02, 0x02, 0x01, 0x12, 0x03, 0x07, 0x02, 0x09, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02,
+    0x02, 0x12, 0x03, 0x07, 0x0c, 0x0d, 0x0a, 0x0a, 0x0a, 0x02, 0x05, 0x01, 0x12, 0x04, 0x0a, 0x00,
+    0x0d, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x01, 0x01, 0x12, 0x03, 0x0a, 0x05, 0x10, 0x0a, 0x0b,
+    0x0a, 0x04, 0x05, 0x01, 0x02, 0x00, 0x12, 0x03, 0x0b, 0x02, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
+    0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0b, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02,
+    0x00, 0x02, 0x12, 0x03, 0x0b, 0x0a, 0x0b, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x01, 0x12,
+    0x03, 0x0c, 0x02, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0c,
+    0x02, 0x06, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x02, 0x12, 0x03, 0x0c, 0x09, 0x0a,
+    0x0a, 0x0a, 0x0a, 0x02, 0x05, 0x02, 0x12, 0x04, 0x0f, 0x00, 0x17, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
+    0x05, 0x02, 0x01, 0x12, 0x03, 0x0f, 0x05, 0x0d, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x00,
+    0x12, 0x03, 0x10, 0x02, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x10, 0x02, 0x09, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x00, 0x02, 0x12, 0x03, 0x10, 0x0c,
+    0x0d, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x03, 0x11, 0x02, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x11, 0x02, 0x0c, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x02, 0x02, 0x01, 0x02, 0x12, 0x03, 0x11, 0x0f, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x02,
+    0x02, 0x02, 0x12, 0x03, 0x12, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x02, 0x01,
+    0x12, 0x03, 0x12, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x02, 0x02, 0x12, 0x03,
+    0x12, 0x0d, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x03, 0x12, 0x03, 0x13, 0x02, 0x0f,
+    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x03, 0x01, 0x12, 0x03, 0x13, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x02, 0x02, 0x03, 0x02, 0x12, 0x03, 0x13, 0x0d, 0x0e, 0x0a, 0x0b, 0x0a, 0x04,
+    0x05, 0x02, 0x02, 0x04, 0x12, 0x03, 0x14, 0x02, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02,
+    0x04, 0x01, 0x12, 0x03, 0x14, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x04, 0x02,
+    0x12, 0x03, 0x14, 0x0b, 0x0c, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x05, 0x12, 0x03, 0x15,
+    0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x05, 0x01, 0x12, 0x03, 0x15, 0x02, 0x0c,
+    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x05, 0x02, 0x12, 0x03, 0x15, 0x0f, 0x10, 0x0a, 0x0b,
+    0x0a, 0x04, 0x05, 0x02, 0x02, 0x06, 0x12, 0x03, 0x16, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
+    0x02, 0x02, 0x06, 0x01, 0x12, 0x03, 0x16, 0x02, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02,
+    0x06, 0x02, 0x12, 0x03, 0x16, 0x0e, 0x0f, 0x0a, 0x4e, 0x0a, 0x02, 0x05, 0x03, 0x12, 0x04, 0x1a,
+    0x00, 0x20, 0x01, 0x1a, 0x42, 0x20, 0x54, 0x68, 0x65, 0x20, 0x73, 0x74, 0x65, 0x70, 0x73, 0x20,
+    0x61, 0x20, 0x77, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x20, 0x67, 0x6f, 0x65, 0x73, 0x20, 0x74, 0x68,
+    0x72, 0x6f, 0x75, 0x67, 0x68, 0x20, 0x77, 0x68, 0x69, 0x6c, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x63,
+    0x65, 0x73, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x20, 0x6a, 0x6f, 0x62, 0x2c, 0x20, 0x69, 0x6e,
+    0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x03, 0x01, 0x12, 0x03,
+    0x1a, 0x05, 0x0d, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x03, 0x02, 0x00, 0x12, 0x03, 0x1b, 0x02, 0x0f,
+    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x1b, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x03, 0x02, 0x00, 0x02, 0x12, 0x03, 0x1b, 0x0d, 0x0e, 0x0a, 0x0b, 0x0a, 0x04,
+    0x05, 0x03, 0x02, 0x01, 0x12, 0x03, 0x1c, 0x02, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02,
+    0x01, 0x01, 0x12, 0x03, 0x1c, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x01, 0x02,
+    0x12, 0x03, 0x1c, 0x0a, 0x0b, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x03, 0x02, 0x02, 0x12, 0x03, 0x1d,
+    0x02, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x02, 0x01, 0x12, 0x03, 0x1d, 0x02, 0x07,
+    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x02, 0x02, 0x12, 0x03, 0x1d, 0x0a, 0x0b, 0x0a, 0x0b,
+    0x0a, 0x04, 0x05, 0x03, 0x02, 0x03, 0x12, 0x03, 0x1e, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
+    0x03, 0x02, 0x03, 0x01, 0x12, 0x03, 0x1e, 0x02, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02,
+    0x03, 0x02, 0x12, 0x03, 0x1e, 0x10, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x03, 0x02, 0x04, 0x12,
+    0x03, 0x1f, 0x02, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x04, 0x01, 0x12, 0x03, 0x1f,
+    0x02, 0x09, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x04, 0x02, 0x12, 0x03, 0x1f, 0x0c, 0x0d,
+    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x22, 0x00, 0x28, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x00, 0x01, 0x12, 0x03, 0x22, 0x08, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00,
+    0x12, 0x03, 0x23, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03,
+    0x23, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x06, 0x12, 0x03, 0x23, 0x0b,
+    0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x23, 0x14, 0x19, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x23, 0x1c, 0x1d, 0x0a, 0x59, 0x0a,
+    0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x26, 0x02, 0x21, 0x1a, 0x4c, 0x20, 0x54, 0x68, 0x65,
+    0x20, 0x52, 0x46, 0x43, 0x33, 0x33, 0x33, 0x39, 0x2d, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x74,
+    0x65, 0x64, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x68, 0x61,
+    0x73, 0x65, 0x20, 0x73, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x20, 0x61, 0x6e, 0x64, 0x2c, 0x20,
+    0x6f, 0x6e, 0x63, 0x65, 0x20, 0x69, 0x74, 0x20, 0x68, 0x61, 0x73, 0x2c, 0x0a, 0x20, 0x66, 0x69,
+    0x6e, 0x69, 0x73, 0x68, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01,
+    0x04, 0x12, 0x03, 0x26, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12,
+    0x03, 0x26, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x26,
+    0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x26, 0x1f, 0x20,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x27, 0x02, 0x22, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x02, 0x04, 0x12, 0x03, 0x27, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x02, 0x05, 0x12, 0x03, 0x27, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x02, 0x01, 0x12, 0x03, 0x27, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03,
+    0x12, 0x03, 0x27, 0x20, 0x21, 0x0a, 0x82, 0x01, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x2c, 0x00,
+    0x32, 0x01, 0x1a, 0x76, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6f, 0x75, 0x74, 0x63, 0x6f, 0x6d, 0x65,
+    0x20, 0x6f, 0x66, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70,
+    0x6f, 0x73, 0x74, 0x2d, 0x70, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x73,
+    0x74, 0x65, 0x70, 0x73, 0x20, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75, 0x72, 0x65, 0x64, 0x20,
+    0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x6a, 0x6f, 0x62, 0x27, 0x73, 0x20, 0x70, 0x72, 0x6f, 0x6a,
+    0x65, 0x63, 0x74, 0x2c, 0x20, 0x72, 0x65, 0x63, 0x6f, 0x72, 0x64, 0x65, 0x64, 0x0a, 0x20, 0x69,
+    0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x73, 0x74, 0x65, 0x70, 0x73, 0x20, 0x72, 0x75, 0x6e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01,
+    0x01, 0x12, 0x03, 0x2c, 0x08, 0x19, 0x0a, 0x4f, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03,
+    0x2e, 0x02, 0x1b, 0x1a, 0x42, 0x20, 0x4b, 0x69, 0x6e, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x73, 0x74,
+    0x65, 0x70, 0x2c, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x22, 0x70, 0x75, 0x62, 0x6c,
+    0x69, 0x73, 0x68, 0x22, 0x2c, 0x20, 0x22, 0x65, 0x78, 0x70, 0x6f, 0x72, 0x74, 0x22, 0x2c, 0x20,
+    0x22, 0x73, 0x6d, 0x6f, 0x6b, 0x65, 0x5f, 0x74, 0x65, 0x73, 0x74, 0x22, 0x20, 0x6f, 0x72, 0x20,
+    0x22, 0x73, 0x62, 0x6f, 0x6d, 0x22, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x04,
+    0x12, 0x03, 0x2e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03,
+    0x2e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x2e, 0x12,
+    0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x2e, 0x19, 0x1a, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x2f, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x01, 0x04, 0x12, 0x03, 0x2f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x01, 0x05, 0x12, 0x03, 0x2f, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01,
+    0x01, 0x12, 0x03, 0x2f, 0x10, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12,
+    0x03, 0x2f, 0x1a, 0x1b, 0x0a, 0x66, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x02, 0x12, 0x03, 0x31, 0x02,
+    0x1d, 0x1a, 0x59, 0x20, 0x57, 0x68, 0x61, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x65,
+    0x70, 0x20, 0x70, 0x72, 0x6f, 0x64, 0x75, 0x63, 0x65, 0x64, 0x2c, 0x20, 0x73, 0x75, 0x63, 0x68,
+    0x20, 0x61, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6d, 0x61, 0x67, 0x65, 0x20, 0x61, 0x6e,
+    0x20, 0x61, 0x72, 0x74, 0x69, 0x66, 0x61, 0x63, 0x74, 0x20, 0x77, 0x61, 0x73, 0x20, 0x65, 0x78,
+    0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x2c, 0x20, 0x6f, 0x72, 0x20, 0x77, 0x68,
+    0x79, 0x20, 0x69, 0x74, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x02, 0x04, 0x12, 0x03, 0x31, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x02, 0x05, 0x12, 0x03, 0x31, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x31, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12,
+    0x03, 0x31, 0x1b, 0x1c, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x34, 0x00, 0x3d, 0x01,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x34, 0x08, 0x11, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x35, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x00, 0x04, 0x12, 0x03, 0x35, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05,
+    0x12, 0x03, 0x35, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x35, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x35, 0x1d,
+    0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x36, 0x02, 0x15, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x04, 0x12, 0x03, 0x36, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x01, 0x06, 0x12, 0x03, 0x36, 0x0b, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x01, 0x01, 0x12, 0x03, 0x36, 0x0e, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01,
+    0x03, 0x12, 0x03, 0x36, 0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x02, 0x12, 0x03,
+    0x37, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x04, 0x12, 0x03, 0x37, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x06, 0x12, 0x03, 0x37, 0x0b, 0x16, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x01, 0x12, 0x03, 0x37, 0x17, 0x1c, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x02, 0x03, 0x12, 0x03, 0x37, 0x1f, 0x20, 0x0a, 0x5e, 0x0a, 0x04, 0x04,
+    0x02, 0x02, 0x03, 0x12, 0x03, 0x39, 0x02, 0x1c, 0x1a, 0x51, 0x20, 0x4e, 0x75, 0x6d, 0x62, 0x65,
+    0x72, 0x20, 0x6f, 0x66, 0x20, 0x6a, 0x6f, 0x62, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x77, 0x6f,
+    0x72, 0x6b, 0x65, 0x72, 0x20, 0x62, 0x75, 0x69, 0x6c, 0x64, 0x73, 0x20, 0x63, 0x6f, 0x6e, 0x63,
+    0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x6c, 0x79, 0x2c, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x68, 0x6f,
+    0x77, 0x20, 0x6d, 0x61, 0x6e, 0x79, 0x20, 0x6d, 0x6f, 0x72, 0x65, 0x20, 0x69, 0x74, 0x20, 0x63,
+    0x61, 0x6e, 0x20, 0x74, 0x61, 0x6b, 0x65, 0x20, 0x6f, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x03, 0x04, 0x12, 0x03, 0x39, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x03, 0x05, 0x12, 0x03, 0x39, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x01,
+    0x12, 0x03, 0x39, 0x12, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x03, 0x12, 0x03,
+    0x39, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x04, 0x12, 0x03, 0x3a, 0x02, 0x21,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x04, 0x04, 0x12, 0x03, 0x3a, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x04, 0x05, 0x12, 0x03, 0x3a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x04, 0x01, 0x12, 0x03, 0x3a, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x04, 0x03, 0x12, 0x03, 0x3a, 0x1f, 0x20, 0x0a, 0x68, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x05,
+    0x12, 0x03, 0x3c, 0x02, 0x24, 0x1a, 0x5b, 0x20, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x20, 0x6f,
+    0x66, 0x20, 0x6a, 0x6f, 0x62, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x77, 0x6f, 0x72, 0x6b, 0x65,
+    0x72, 0x20, 0x68, 0x61, 0x64, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x20, 0x66,
+    0x72, 0x6f, 0x6d, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6a, 0x6f, 0x62, 0x20, 0x73, 0x65, 0x72, 0x76,
+    0x65, 0x72, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x69, 0x74, 0x20, 0x63, 0x6f, 0x75, 0x6e, 0x74,
+    0x65, 0x64, 0x20, 0x69, 0x74, 0x73, 0x20, 0x66, 0x72, 0x65, 0x65, 0x20, 0x73, 0x6c, 0x6f, 0x74,
+    0x73, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x05, 0x04, 0x12, 0x03, 0x3c, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x05, 0x05, 0x12, 0x03, 0x3c, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x05, 0x01, 0x12, 0x03, 0x3c, 0x12, 0x1f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x02, 0x02, 0x05, 0x03, 0x12, 0x03, 0x3c, 0x22, 0x23, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03,
+    0x12, 0x04, 0x3f, 0x00, 0x65, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x3f,
+    0x08, 0x0b, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x09, 0x12, 0x03, 0x40, 0x0b, 0x0e, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x03, 0x09, 0x00, 0x12, 0x03, 0x40, 0x0b, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x09, 0x00, 0x01, 0x12, 0x03, 0x40, 0x0b, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x09,
+    0x00, 0x02, 0x12, 0x03, 0x40, 0x0b, 0x0d, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x0a, 0x12, 0x03,
+    0x41, 0x0b, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x0a, 0x00, 0x12, 0x03, 0x41, 0x0b, 0x14,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x42, 0x02, 0x19, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x00, 0x04, 0x12, 0x03, 0x42, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x42, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x00, 0x01, 0x12, 0x03, 0x42, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03,
+    0x12, 0x03, 0x42, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x43,
+    0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x04, 0x12, 0x03, 0x43, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x05, 0x12, 0x03, 0x43, 0x0b, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x43, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x43, 0x1d, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03,
+    0x02, 0x02, 0x12, 0x03, 0x44, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x04,
+    0x12, 0x03, 0x44, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x06, 0x12, 0x03,
+    0x44, 0x0b, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x01, 0x12, 0x03, 0x44, 0x14,
+    0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x03, 0x12, 0x03, 0x44, 0x1c, 0x1d, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x03, 0x12, 0x03, 0x45, 0x02, 0x2f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x03, 0x04, 0x12, 0x03, 0x45, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x03, 0x06, 0x12, 0x03, 0x45, 0x0b, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03,
+    0x01, 0x12, 0x03, 0x45, 0x23, 0x2a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x03, 0x12,
+    0x03, 0x45, 0x2d, 0x2e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x04, 0x12, 0x03, 0x46, 0x02,
+    0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x04, 0x04, 0x12, 0x03, 0x46, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x04, 0x06, 0x12, 0x03, 0x46, 0x0b, 0x17, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x04, 0x01, 0x12, 0x03, 0x46, 0x18, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x04, 0x03, 0x12, 0x03, 0x46, 0x20, 0x21, 0x0a, 0xa5, 0x01, 0x0a, 0x04, 0x04, 0x03,
+    0x02, 0x05, 0x12, 0x03, 0x4a, 0x02, 0x21, 0x1a, 0x97, 0x01, 0x20, 0x54, 0x68, 0x65, 0x20, 0x52,
+    0x46, 0x43, 0x33, 0x33, 0x33, 0x39, 0x2d, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x74, 0x65, 0x64,
+    0x20, 0x74, 0x69, 0x6d, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6a, 0x6f, 0x62, 0x20, 0x77, 0x61,
+    0x73, 0x20, 0x65, 0x6e, 0x74, 0x65, 0x72, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x74, 0x6f, 0x20, 0x74,
+    0x68, 0x65, 0x0a, 0x20, 0x73, 0x79, 0x73, 0x74, 0x65, 0x6d, 0x2e, 0x20, 0x49, 0x74, 0x20, 0x6d,
+    0x61, 0x79, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x62, 0x65, 0x67, 0x69, 0x6e, 0x20, 0x70, 0x72, 0x6f,
+    0x63, 0x65, 0x73, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x6f, 0x6d, 0x65,
+    0x20, 0x74, 0x69, 0x6d, 0x65, 0x20, 0x61, 0x66, 0x74, 0x65, 0x72, 0x20, 0x74, 0x68, 0x69, 0x73,
+    0x2c, 0x0a, 0x20, 0x62, 0x61, 0x73, 0x65, 0x64, 0x20, 0x6f, 0x6e, 0x20, 0x63, 0x75, 0x72, 0x72,
+    0x65, 0x6e, 0x74, 0x20, 0x73, 0x79, 0x73, 0x74, 0x65, 0x6d, 0x20, 0x6c, 0x6f, 0x61, 0x64, 0x2e,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x05, 0x04, 0x12, 0x03, 0x4a, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x05, 0x05, 0x12, 0x03, 0x4a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x05, 0x01, 0x12, 0x03, 0x4a, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x05, 0x03, 0x12, 0x03, 0x4a, 0x1f, 0x20, 0x0a, 0x52, 0x0a, 0x04, 0x04, 0x03, 0x02,
+    0x06, 0x12, 0x03, 0x4d, 0x02, 0x27, 0x1a, 0x45, 0x20, 0x54, 0x68, 0x65, 0x20, 0x52, 0x46, 0x43,
+    0x33, 0x33, 0x33, 0x39, 0x2d, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x74, 0x65, 0x64, 0x20, 0x74,
+    0x69, 0x6d, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x60, 0x68, 0x61, 0x62, 0x20, 0x73, 0x74, 0x75,
+    0x64, 0x69, 0x6f, 0x20, 0x62, 0x75, 0x69, 0x6c, 0x64, 0x60, 0x20, 0x70, 0x72, 0x6f, 0x63, 0x65,
+    0x73, 0x73, 0x0a, 0x20, 0x73, 0x74, 0x61, 0x72, 0x74, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x06, 0x04, 0x12, 0x03, 0x4d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x06, 0x05, 0x12, 0x03, 0x4d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x06, 0x01, 0x12, 0x03, 0x4d, 0x12, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x06, 0x03,
+    0x12, 0x03, 0x4d, 0x25, 0x26, 0x0a, 0x65, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x07, 0x12, 0x03, 0x50,
+    0x02, 0x28, 0x1a, 0x58, 0x20, 0x54, 0x68, 0x65, 0x20, 0x52, 0x46, 0x43, 0x33, 0x33, 0x33, 0x39,
+    0x2d, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x74, 0x65, 0x64, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x60, 0x68, 0x61, 0x62, 0x20, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f, 0x20,
+    0x62, 0x75, 0x69, 0x6c, 0x64, 0x60, 0x20, 0x70, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x0a, 0x20,
+    0x73, 0x74, 0x6f, 0x70, 0x70, 0x65, 0x64, 0x2c, 0x20, 0x73, 0x75, 0x63, 0x63, 0x65, 0x73, 0x73,
+    0x66, 0x75, 0x6c, 0x20, 0x6f, 0x72, 0x20, 0x6e, 0x6f, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x07, 0x04, 0x12, 0x03, 0x50, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x07, 0x05, 0x12, 0x03, 0x50, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x07,
+    0x01, 0x12, 0x03, 0x50, 0x12, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x07, 0x03, 0x12,
+    0x03, 0x50, 0x26, 0x27, 0x0a, 0x62, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x08, 0x12, 0x03, 0x53, 0x02,
+    0x3a, 0x1a, 0x55, 0x20, 0x54, 0x68, 0x65, 0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69,
+    0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x61, 0x63, 0x6b, 0x61, 0x67,
+    0x65, 0x20, 0x62, 0x75, 0x69, 0x6c, 0x74, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6a,
+    0x6f, 0x62, 0x2e, 0x20, 0x53, 0x65, 0x74, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x61, 0x0a, 0x20,
+    0x73, 0x75, 0x63, 0x63, 0x65, 0x73, 0x73, 0x66, 0x75, 0x6c, 0x6c, 0x79, 0x2d, 0x62, 0x75, 0x69,
+    0x6c, 0x74, 0x20, 0x4a, 0x6f, 0x62, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x08,
+    0x04, 0x12, 0x03, 0x53, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x08, 0x06, 0x12,
+    0x03, 0x53, 0x0b, 0x27, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x08, 0x01, 0x12, 0x03, 0x53,
+    0x28, 0x35, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x08, 0x03, 0x12, 0x03, 0x53, 0x38, 0x39,
+    0x0a, 0x43, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x09, 0x12, 0x03, 0x55, 0x02, 0x21, 0x1a, 0x36, 0x20,
+    0x57, 0x68, 0x65, 0x74, 0x68, 0x65, 0x72, 0x20, 0x6f, 0x72, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x6c, 0x6f, 0x67, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6a,
+    0x6f, 0x62, 0x20, 0x68, 0x61, 0x73, 0x20, 0x62, 0x65, 0x65, 0x6e, 0x20, 0x61, 0x72, 0x63, 0x68,
+    0x69, 0x76, 0x65, 0x64, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x09, 0x04, 0x12, 0x03,
+    0x55, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x09, 0x05, 0x12, 0x03, 0x55, 0x0b,
+    0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x09, 0x01, 0x12, 0x03, 0x55, 0x10, 0x1b, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x09, 0x03, 0x12, 0x03, 0x55, 0x1e, 0x20, 0x0a, 0x7b, 0x0a,
+    0x04, 0x04, 0x03, 0x02, 0x0a, 0x12, 0x03, 0x58, 0x02, 0x24, 0x1a, 0x6e, 0x20, 0x43, 0x50, 0x55,
+    0x20, 0x74, 0x69, 0x6d, 0x65, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x70, 0x65, 0x61, 0x6b, 0x20, 0x6d,
+    0x65, 0x6d, 0x6f, 0x72, 0x79, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x62, 0x75, 0x69, 0x6c, 0x64, 0x27, 0x73, 0x20, 0x73, 0x74, 0x75, 0x64, 0x69, 0x6f,
+    0x2e, 0x20, 0x4f, 0x6e, 0x6c, 0x79, 0x20, 0x72, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x20,
+    0x62, 0x79, 0x20, 0x77, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x73, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68,
+    0x20, 0x72, 0x75, 0x6e, 0x0a, 0x20, 0x62, 0x75, 0x69, 0x6c, 0x64, 0x73, 0x20, 0x69, 0x6e, 0x20,
+    0x61, 0x20, 0x63, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x0a, 0x04, 0x12, 0x03, 0x58, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0a,
+    0x05, 0x12, 0x03, 0x58, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0a, 0x01, 0x12,
+    0x03, 0x58, 0x12, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0a, 0x03, 0x12, 0x03, 0x58,
+    0x21, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x0b, 0x12, 0x03, 0x59, 0x02, 0x2a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0b, 0x04, 0x12, 0x03, 0x59, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x0b, 0x05, 0x12, 0x03, 0x59, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x0b, 0x01, 0x12, 0x03, 0x59, 0x12, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x0b, 0x03, 0x12, 0x03, 0x59, 0x27, 0x29, 0x0a, 0x63, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x0c, 0x12,
+    0x03, 0x5c, 0x02, 0x24, 0x1a, 0x56, 0x20, 0x54, 0x68, 0x65, 0x20, 0x70, 0x68, 0x61, 0x73, 0x65,
+    0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x77, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x20, 0x68, 0x61, 0x73,
+    0x20, 0x67, 0x6f, 0x6e, 0x65, 0x20, 0x74, 0x68, 0x72, 0x6f, 0x75, 0x67, 0x68, 0x20, 0x73, 0x6f,
+    0x20, 0x66, 0x61, 0x72, 0x2c, 0x20, 0x72, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x65, 0x64, 0x20, 0x61,
+    0x73, 0x20, 0x69, 0x74, 0x0a, 0x20, 0x72, 0x65, 0x61, 0x63, 0x68, 0x65, 0x73, 0x20, 0x65, 0x61,
+    0x63, 0x68, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x6d, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x0c, 0x04, 0x12, 0x03, 0x5c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x0c, 0x06, 0x12, 0x03, 0x5c, 0x0b, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0c,
+    0x01, 0x12, 0x03, 0x5c, 0x18, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0c, 0x03, 0x12,
+    0x03, 0x5c, 0x21, 0x23, 0x0a, 0x44, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x0d, 0x12, 0x03, 0x5e, 0x02,
+    0x1e, 0x1a, 0x37, 0x20, 0x45, 0x6e, 0x64, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x77, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6a,
+    0x6f, 0x62, 0x20, 0x77, 0x61, 0x73, 0x20, 0x6c, 0x61, 0x73, 0x74, 0x20, 0x64, 0x69, 0x73, 0x70,
+    0x61, 0x74, 0x63, 0x68, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x0d, 0x04, 0x12, 0x03, 0x5e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0d,
+    0x05, 0x12, 0x03, 0x5e, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0d, 0x01, 0x12,
+    0x03, 0x5e, 0x12, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0d, 0x03, 0x12, 0x03, 0x5e,
+    0x1b, 0x1d, 0x0a, 0x9d, 0x01, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x0e, 0x12, 0x03, 0x61, 0x02, 0x1d,
+    0x1a, 0x8f, 0x01, 0x20, 0x57, 0x68, 0x65, 0x74, 0x68, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x62, 0x75, 0x69, 0x6c, 0x74, 0x20, 0x61, 0x72, 0x74, 0x69, 0x66, 0x61, 0x63, 0x74, 0x20, 0x69,
+    0x73, 0x20, 0x6b, 0x65, 0x70, 0x74, 0x20, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6a, 0x6f,
+    0x62, 0x27, 0x73, 0x20, 0x77, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x20, 0x61, 0x66, 0x74, 0x65, 0x72,
+    0x20, 0x70, 0x6f, 0x73, 0x74, 0x2d, 0x70, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x69, 0x6e, 0x67,
+    0x20, 0x66, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x2c, 0x20, 0x73, 0x6f, 0x0a, 0x20, 0x74, 0x68, 0x61,
+    0x74, 0x20, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x73, 0x68, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x74, 0x20,
+    0x63, 0x61, 0x6e, 0x20, 0x62, 0x65, 0x20, 0x72, 0x65, 0x74, 0x72, 0x69, 0x65, 0x64, 0x20, 0x77,
+    0x69, 0x74, 0x68, 0x6f, 0x75, 0x74, 0x20, 0x72, 0x65, 0x62, 0x75, 0x69, 0x6c, 0x64, 0x69, 0x6e,
+    0x67, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0e, 0x04, 0x12, 0x03, 0x61, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0e, 0x05, 0x12, 0x03, 0x61, 0x0b, 0x0f, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x0e, 0x01, 0x12, 0x03, 0x61, 0x10, 0x17, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x0e, 0x03, 0x12, 0x03, 0x61, 0x1a, 0x1c, 0x0a, 0x90, 0x01, 0x0a, 0x04, 0x04,
+    0x03, 0x02, 0x0f, 0x12, 0x03, 0x64, 0x02, 0x2f, 0x1a, 0x82, 0x01, 0x20, 0x52, 0x65, 0x73, 0x75,
+    0x6c, 0x74, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x6f, 0x73, 0x74, 0x2d,
+    0x70, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x73, 0x74, 0x65, 0x70, 0x73,
+    0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x68, 0x61, 0x76, 0x65, 0x20, 0x72, 0x75, 0x6e, 0x20,
+    0x73, 0x6f, 0x20, 0x66, 0x61, 0x72, 0x2e, 0x20, 0x41, 0x20, 0x73, 0x74, 0x65, 0x70, 0x20, 0x77,
+    0x68, 0x69, 0x63, 0x68, 0x20, 0x73, 0x75, 0x63, 0x63, 0x65, 0x65, 0x64, 0x65, 0x64, 0x20, 0x69,
+    0x73, 0x20, 0x6e, 0x6f, 0x74, 0x0a, 0x20, 0x72, 0x75, 0x6e, 0x20, 0x61, 0x67, 0x61, 0x69, 0x6e,
+    0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x73, 0x68, 0x69, 0x6e, 0x67,
+    0x20, 0x69, 0x73, 0x20, 0x72, 0x65, 0x74, 0x72, 0x69, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x0f, 0x04, 0x12, 0x03, 0x64, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x0f, 0x06, 0x12, 0x03, 0x64, 0x0b, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x0f, 0x01, 0x12, 0x03, 0x64, 0x1d, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0f, 0x03,
+    0x12, 0x03, 0x64, 0x2c, 0x2e, 0x0a, 0x83, 0x02, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x6a, 0x00,
+    0x6c, 0x01, 0x1a, 0xf6, 0x01, 0x20, 0x53, 0x74, 0x6f, 0x70, 0x20, 0x61, 0x20, 0x6a, 0x6f, 0x62,
+    0x2e, 0x20, 0x41, 0x20, 0x6a, 0x6f, 0x62, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x68, 0x61,
+    0x73, 0x6e, 0x27, 0x74, 0x20, 0x62, 0x65, 0x65, 0x6e, 0x20, 0x64, 0x69, 0x73, 0x70, 0x61, 0x74,
+    0x63, 0x68, 0x65, 0x64, 0x20, 0x79, 0x65, 0x74, 0x20, 0x69, 0x73, 0x20, 0x63, 0x61, 0x6e, 0x63,
+    0x65, 0x6c, 0x6c, 0x65, 0x64, 0x20, 0x72, 0x69, 0x67, 0x68, 0x74, 0x20, 0x61, 0x77, 0x61, 0x79,
+    0x2c, 0x20, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x77, 0x69, 0x73, 0x65, 0x20, 0x74, 0x68, 0x65, 0x0a,
+    0x20, 0x77, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x20, 0x62, 0x75, 0x69, 0x6c, 0x64, 0x69, 0x6e, 0x67,
+    0x20, 0x69, 0x74, 0x20, 0x69, 0x73, 0x20, 0x61, 0x73, 0x6b, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20,
+    0x6b, 0x69, 0x6c, 0x6c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x75, 0x69, 0x6c, 0x64, 0x20, 0x61,
+    0x6e, 0x64, 0x20, 0x72, 0x65, 0x70, 0x6f, 0x72, 0x74, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6a,
+    0x6f, 0x62, 0x20, 0x61, 0x73, 0x20, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x6c, 0x65, 0x64, 0x20,
+    0x6f, 0x6e, 0x63, 0x65, 0x20, 0x64, 0x6f, 0x6e, 0x65, 0x2e, 0x0a, 0x20, 0x43, 0x61, 0x6e, 0x63,
+    0x65, 0x6c, 0x6c, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x20,
+    0x6a, 0x6f, 0x62, 0x20, 0x61, 0x62, 0x61, 0x6e, 0x64, 0x6f, 0x6e, 0x73, 0x20, 0x69, 0x74, 0x73,
+    0x20, 0x73, 0x68, 0x65, 0x6c, 0x76, 0x65, 0x64, 0x20, 0x61, 0x72, 0x74, 0x69, 0x66, 0x61, 0x63,
+    0x74, 0x2c, 0x20, 0x69, 0x66, 0x20, 0x61, 0x6e, 0x79, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
+    0x04, 0x01, 0x12, 0x03, 0x6a, 0x08, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x00, 0x12,
+    0x03, 0x6b, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x04, 0x12, 0x03, 0x6b,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x05, 0x12, 0x03, 0x6b, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x6b, 0x12, 0x14, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x6b, 0x17, 0x18, 0x0a, 0x59, 0x0a, 0x02,
+    0x04, 0x05, 0x12, 0x04, 0x6f, 0x00, 0x71, 0x01, 0x1a, 0x4d, 0x20, 0x50, 0x75, 0x62, 0x6c, 0x69,
+    0x73, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x68, 0x65, 0x6c, 0x76, 0x65, 0x64, 0x20, 0x61,
+    0x72, 0x74, 0x69, 0x66, 0x61, 0x63, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x6a, 0x6f, 0x62,
+    0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x66, 0x61, 0x69, 0x6c, 0x65, 0x64, 0x20, 0x69, 0x6e,
+    0x20, 0x70, 0x6f, 0x73, 0x74, 0x2d, 0x70, 0x72, 0x6f, 0x63, 0x65, 0x73, 0x73, 0x69, 0x6e, 0x67,
+    0x20, 0x61, 0x67, 0x61, 0x69, 0x6e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03,
+    0x6f, 0x08, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x03, 0x70, 0x02, 0x19,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x04, 0x12, 0x03, 0x70, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x05, 0x12, 0x03, 0x70, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x03, 0x70, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x05,
+    0x02, 0x00, 0x03, 0x12, 0x03, 0x70, 0x17, 0x18, 0x0a, 0x29, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04,
+    0x74, 0x00, 0x76, 0x01, 0x1a, 0x1d, 0x20, 0x52, 0x65, 0x74, 0x72, 0x69, 0x65, 0x76, 0x65, 0x20,
+    0x61, 0x20, 0x73, 0x69, 0x6e, 0x67, 0x6c, 0x65, 0x20, 0x6a, 0x6f, 0x62, 0x20, 0x62, 0x79, 0x20,
+    0x49, 0x44, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x74, 0x08, 0x0e, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x75, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x00, 0x04, 0x12, 0x03, 0x75, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
+    0x02, 0x00, 0x05, 0x12, 0x03, 0x75, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x75, 0x12, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12,
+    0x03, 0x75, 0x17, 0x18, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x04, 0x78, 0x00, 0x7b, 0x01,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x03, 0x78, 0x08, 0x0f, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x07, 0x02, 0x00, 0x12, 0x03, 0x79, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02,
+    0x00, 0x04, 0x12, 0x03, 0x79, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x05,
+    0x12, 0x03, 0x79, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x03,
+    0x79, 0x12, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03, 0x79, 0x1d,
+    0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x01, 0x12, 0x03, 0x7a, 0x02, 0x2f, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x04, 0x12, 0x03, 0x7a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x07, 0x02, 0x01, 0x06, 0x12, 0x03, 0x7a, 0x0b, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07,
+    0x02, 0x01, 0x01, 0x12, 0x03, 0x7a, 0x23, 0x2a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01,
+    0x03, 0x12, 0x03, 0x7a, 0x2d, 0x2e, 0x0a, 0x8a, 0x01, 0x0a, 0x02, 0x04, 0x08, 0x12, 0x06, 0x81,
+    0x01, 0x00, 0x84, 0x01, 0x01, 0x1a, 0x7c, 0x20, 0x52, 0x65, 0x74, 0x72, 0x69, 0x65, 0x76, 0x65,
+    0x20, 0x6a, 0x6f, 0x62, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x73, 0x70, 0x65, 0x63,
+    0x69, 0x66, 0x69, 0x63, 0x20, 0x70, 0x72, 0x6f, 0x6a, 0x65, 0x63, 0x74, 0x2e, 0x0a, 0x0a, 0x20,
+    0x43, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x6c, 0x79, 0x20, 0x72, 0x65, 0x74, 0x72, 0x69, 0x65,
+    0x76, 0x65, 0x73, 0x20, 0x35, 0x30, 0x20, 0x6d, 0x6f, 0x73, 0x74, 0x20, 0x72, 0x65, 0x63, 0x65,
+    0x6e, 0x74, 0x3b, 0x20, 0x61, 0x64, 0x64, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x20, 0x66,
+    0x69, 0x6c, 0x74, 0x65, 0x72, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x6e, 0x64, 0x0a, 0x20, 0x73, 0x6f,
+    0x72, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x63, 0x6f, 0x6d, 0x65, 0x73, 0x20, 0x6c, 0x61, 0x74, 0x65,
+    0x72, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12, 0x04, 0x81, 0x01, 0x08, 0x16,
+    0x0a, 0x49, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x04, 0x83, 0x01, 0x02, 0x1b, 0x1a, 0x3b,
+    0x20, 0x54, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x69, 0x67, 0x69, 0x6e, 0x2d, 0x71, 0x75, 0x61, 0x6c,
+    0x69, 0x66, 0x69, 0x65, 0x64, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20,
+    0x70, 0x72, 0x6f, 0x6a, 0x65, 0x63, 0x74, 0x2c, 0x20, 0x65, 0x2e, 0x67, 0x2e, 0x20, 0x22, 0x63,
+    0x6f, 0x72, 0x65, 0x2f, 0x6e, 0x67, 0x69, 0x6e, 0x78, 0x22, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x00, 0x04, 0x12, 0x04, 0x83, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08,
+    0x02, 0x00, 0x05, 0x12, 0x04, 0x83, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0x83, 0x01, 0x12, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00,
+    0x03, 0x12, 0x04, 0x83, 0x01, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x09, 0x12, 0x06, 0x86,
+    0x01, 0x00, 0x88, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x04, 0x86, 0x01,
+    0x08, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x04, 0x87, 0x01, 0x02, 0x18,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x04, 0x12, 0x04, 0x87, 0x01, 0x02, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x06, 0x12, 0x04, 0x87, 0x01, 0x0b, 0x0e, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x01, 0x12, 0x04, 0x87, 0x01, 0x0f, 0x13, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x09, 0x02, 0x00, 0x03, 0x12, 0x04, 0x87, 0x01, 0x16, 0x17, 0x0a, 0x53, 0x0a, 0x02,
+    0x04, 0x0a, 0x12, 0x06, 0x8b, 0x01, 0x00, 0x94, 0x01, 0x01, 0x1a, 0x45, 0x20, 0x53, 0x65, 0x6e,
+    0x74, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x61, 0x20, 0x77, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x20,
+    0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6a, 0x6f, 0x62, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65,
+    0x72, 0x27, 0x73, 0x20, 0x6c, 0x6f, 0x67, 0x20, 0x69, 0x6e, 0x67, 0x65, 0x73, 0x74, 0x65, 0x72,
+    0x20, 0x64, 0x75, 0x72, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x20, 0x62, 0x75, 0x69, 0x6c, 0x64, 0x2e,
+    0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0a, 0x01, 0x12, 0x04, 0x8b, 0x01, 0x08, 0x13, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x0a, 0x02, 0x00, 0x12, 0x04, 0x8c, 0x01, 0x02, 0x1d, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0a, 0x02, 0x00, 0x04, 0x12, 0x04, 0x8c, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0a, 0x02, 0x00, 0x05, 0x12, 0x04, 0x8c, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a,
+    0x02, 0x00, 0x01, 0x12, 0x04, 0x8c, 0x01, 0x12, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02,
+    0x00, 0x03, 0x12, 0x04, 0x8c, 0x01, 0x1b, 0x1c, 0x0a, 0x9d, 0x01, 0x0a, 0x04, 0x04, 0x0a, 0x02,
+    0x01, 0x12, 0x04, 0x90, 0x01, 0x02, 0x1a, 0x1a, 0x8e, 0x01, 0x20, 0x4f, 0x72, 0x64, 0x65, 0x72,
+    0x69, 0x6e, 0x67, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x63, 0x68, 0x75, 0x6e,
+    0x6b, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x76, 0x65, 0x72, 0x61, 0x6c, 0x6c,
+    0x20, 0x6c, 0x6f, 0x67, 0x20, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x2c, 0x20, 0x73, 0x74, 0x61,
+    0x72, 0x74, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x74, 0x20, 0x31, 0x3b, 0x0a, 0x20, 0x57, 0x65, 0x20,
+    0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x6c, 0x79, 0x20, 0x73, 0x65, 0x6e, 0x64, 0x20, 0x6f,
+    0x6e, 0x65, 0x20, 0x6c, 0x69, 0x6e, 0x65, 0x20, 0x61, 0x74, 0x20, 0x61, 0x20, 0x74, 0x69, 0x6d,
+    0x65, 0x2c, 0x20, 0x73, 0x6f, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x63, 0x6f, 0x72, 0x72, 0x65,
+    0x73, 0x70, 0x6f, 0x6e, 0x64, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x6c, 0x69, 0x6e, 0x65, 0x0a, 0x20,
+    0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01,
+    0x04, 0x12, 0x04, 0x90, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x05,
+    0x12, 0x04, 0x90, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x01, 0x12,
+    0x04, 0x90, 0x01, 0x12, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x03, 0x12, 0x04,
+    0x90, 0x01, 0x18, 0x19, 0x0a, 0x65, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x02, 0x12, 0x04, 0x93, 0x01,
+    0x02, 0x1e, 0x1a, 0x57, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6c, 0x6f, 0x67, 0x20, 0x63, 0x6f, 0x6e,
+    0x74, 0x65, 0x6e, 0x74, 0x20, 0x62, 0x65, 0x69, 0x6e, 0x67, 0x20, 0x73, 0x65, 0x6e, 0x74, 0x0a,
+    0x20, 0x54, 0x4f, 0x44, 0x4f, 0x3a, 0x20, 0x4d, 0x61, 0x6b, 0x65, 0x20, 0x74, 0x68, 0x69, 0x73,
+    0x20, 0x61, 0x20, 0x72, 0x65, 0x70, 0x65, 0x61, 0x74, 0x65, 0x64, 0x20, 0x66, 0x69, 0x65, 0x6c,
+    0x64, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x66, 0x75, 0x74, 0x75, 0x72, 0x65, 0x20, 0x63, 0x6f, 0x6d,
+    0x70, 0x61, 0x74, 0x69, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x79, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0a, 0x02, 0x02, 0x04, 0x12, 0x04, 0x93, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a,
+    0x02, 0x02, 0x05, 0x12, 0x04, 0x93, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02,
+    0x02, 0x01, 0x12, 0x04, 0x93, 0x01, 0x12, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02,
+    0x03, 0x12, 0x04, 0x93, 0x01, 0x1c, 0x1d, 0x0a, 0x5e, 0x0a, 0x02, 0x04, 0x0b, 0x12, 0x06, 0x98,
+    0x01, 0x00, 0x9a, 0x01, 0x01, 0x1a, 0x50, 0x20, 0x53, 0x65, 0x6e, 0x74, 0x20, 0x66, 0x72, 0x6f,
+    0x6d, 0x20, 0x61, 0x20, 0x77, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x6a, 0x6f, 0x62, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x72, 0x27, 0x73, 0x20, 0x6c,
+    0x6f, 0x67, 0x20, 0x69, 0x6e, 0x67, 0x65, 0x73, 0x74, 0x65, 0x72, 0x20, 0x77, 0x68, 0x65, 0x6e,
+    0x20, 0x61, 0x20, 0x62, 0x75, 0x69, 0x6c, 0x64, 0x20, 0x69, 0x73, 0x0a, 0x20, 0x63, 0x6f, 0x6d,
+    0x70, 0x6c, 0x65, 0x74, 0x65, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0b, 0x01, 0x12, 0x04,
+    0x98, 0x01, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x00, 0x12, 0x04, 0x99, 0x01,
+    0x02, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x04, 0x12, 0x04, 0x99, 0x01, 0x02,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x05, 0x12, 0x04, 0x99, 0x01, 0x0b, 0x11,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x01, 0x12, 0x04, 0x99, 0x01, 0x12, 0x18, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x03, 0x12, 0x04, 0x99, 0x01, 0x1b, 0x1c, 0x0a, 0x6e,
+    0x0a, 0x02, 0x04, 0x0c, 0x12, 0x06, 0x9e, 0x01, 0x00, 0xa2, 0x01, 0x01, 0x1a, 0x60, 0x20, 0x49,
+    0x6e, 0x69, 0x74, 0x69, 0x61, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x41, 0x50, 0x49, 0x20,
+    0x72, 0x65, 0x71, 0x75, 0x65, 0x73, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x72, 0x65, 0x74, 0x72, 0x69,
+    0x65, 0x76, 0x65, 0x20, 0x61, 0x20, 0x70, 0x6f, 0x72, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66,
+    0x20, 0x61, 0x20, 0x6a, 0x6f, 0x62, 0x27, 0x73, 0x20, 0x6c, 0x6f, 0x67, 0x20, 0x64, 0x61, 0x74,
+    0x61, 0x2c, 0x0a, 0x20, 0x62, 0x65, 0x67, 0x69, 0x6e, 0x6e, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x74,
+    0x20, 0x6c, 0x69, 0x6e, 0x65, 0x20, 0x60, 0x73, 0x74, 0x61, 0x72, 0x74, 0x60, 0x0a, 0x0a, 0x0b,
+    0x0a, 0x03, 0x04, 0x0c, 0x01, 0x12, 0x04, 0x9e, 0x01, 0x08, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x0c, 0x02, 0x00, 0x12, 0x04, 0x9f, 0x01, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02,
+    0x00, 0x04, 0x12, 0x04, 0x9f, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00,
+    0x05, 0x12, 0x04, 0x9f, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x01,
+    0x12, 0x04, 0x9f, 0x01, 0x12, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x03, 0x12,
+    0x04, 0x9f, 0x01, 0x17, 0x18, 0x0a, 0x41, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x01, 0x12, 0x04, 0xa1,
+    0x01, 0x02, 0x1c, 0x1a, 0x33, 0x20, 0x5a, 0x65, 0x72, 0x6f, 0x2d, 0x69, 0x6e, 0x64, 0x65, 0x78,
+    0x65, 0x64, 0x20, 0x6c, 0x69, 0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c,
+    0x6f, 0x67, 0x20, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x73, 0x74, 0x61,
+    0x72, 0x74, 0x20, 0x77, 0x69, 0x74, 0x68, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01,
+    0x04, 0x12, 0x04, 0xa1, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x05,
+    0x12, 0x04, 0xa1, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x01, 0x12,
+    0x04, 0xa1, 0x01, 0x12, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x03, 0x12, 0x04,
+    0xa1, 0x01, 0x1a, 0x1b, 0x0a, 0x2c, 0x0a, 0x02, 0x04, 0x0d, 0x12, 0x06, 0xa5, 0x01, 0x00, 0xb0,
+    0x01, 0x01, 0x1a, 0x1e, 0x20, 0x53, 0x65, 0x6e, 0x74, 0x20, 0x69, 0x6e, 0x20, 0x72, 0x65, 0x70,
+    0x6c, 0x79, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x20, 0x4a, 0x6f, 0x62, 0x4c, 0x6f, 0x67, 0x47, 0x65,
+    0x74, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0d, 0x01, 0x12, 0x04, 0xa5, 0x01, 0x08, 0x0e, 0x0a,
+    0x4c, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x00, 0x12, 0x04, 0xa7, 0x01, 0x02, 0x1c, 0x1a, 0x3e, 0x20,
+    0x5a, 0x65, 0x72, 0x6f, 0x2d, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x65, 0x64, 0x20, 0x28, 0x69, 0x6e,
     0x63, 0x6c, 0x75, 0x73, 0x69, 0x76, 0x65, 0x29, 0x20, 0x6c, 0x69, 0x6e, 0x65, 0x20, 0x6f, 0x66,
     0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x6f, 0x67, 0x20, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x20,
-    0x69, 0x6e, 0x20, 0x60, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x60, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x09, 0x02, 0x01, 0x04, 0x12, 0x03, 0x6d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x09, 0x02, 0x01, 0x05, 0x12, 0x03, 0x6d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
-    0x01, 0x01, 0x12, 0x03, 0x6d, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x03,
-    0x12, 0x03, 0x6d, 0x19, 0x1a, 0x0a, 0x22, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x02, 0x12, 0x03, 0x6f,
-    0x02, 0x1e, 0x1a, 0x15, 0x20, 0x4c, 0x69, 0x6e, 0x65, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x6c, 0x6f,
-    0x67, 0x20, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
-    0x02, 0x04, 0x12, 0x03, 0x6f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x05,
-    0x12, 0x03, 0x6f, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x01, 0x12, 0x03,
-    0x6f, 0x12, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x02, 0x03, 0x12, 0x03, 0x6f, 0x1c,
-    0x1d, 0x0a, 0x9c, 0x01, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x03, 0x12, 0x03, 0x73, 0x02, 0x20, 0x1a,
-    0x8e, 0x01, 0x20, 0x57, 0x68, 0x69, 0x6c, 0x65, 0x20, 0x77, 0x65, 0x20, 0x6e, 0x65, 0x65, 0x64,
-    0x20, 0x74, 0x6f, 0x20, 0x70, 0x6f, 0x6c, 0x6c, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x6c, 0x6f, 0x67,
-    0x73, 0x2c, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x73, 0x65, 0x72, 0x76, 0x65, 0x73, 0x20, 0x61,
-    0x73, 0x20, 0x61, 0x6e, 0x20, 0x69, 0x6e, 0x64, 0x69, 0x63, 0x61, 0x74, 0x6f, 0x72, 0x20, 0x74,
-    0x6f, 0x0a, 0x20, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x69, 0x66, 0x20, 0x74, 0x68,
-    0x65, 0x79, 0x20, 0x6e, 0x65, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x69,
-    0x6e, 0x75, 0x65, 0x20, 0x70, 0x6f, 0x6c, 0x6c, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x6f, 0x20, 0x72,
-    0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x20, 0x6d, 0x6f, 0x72, 0x65, 0x20, 0x6c, 0x6f, 0x67, 0x73,
-    0x2c, 0x20, 0x6f, 0x72, 0x0a, 0x20, 0x63, 0x61, 0x6e, 0x20, 0x73, 0x74, 0x6f, 0x70, 0x2e, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x04, 0x12, 0x03, 0x73, 0x02, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x09, 0x02, 0x03, 0x05, 0x12, 0x03, 0x73, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x09, 0x02, 0x03, 0x01, 0x12, 0x03, 0x73, 0x10, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09,
-    0x02, 0x03, 0x03, 0x12, 0x03, 0x73, 0x1e, 0x1f,
+    0x69, 0x6e, 0x20, 0x60, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x6e, 0x74, 0x60, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0d, 0x02, 0x00, 0x04, 0x12, 0x04, 0xa7, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0d, 0x02, 0x00, 0x05, 0x12, 0x04, 0xa7, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0d, 0x02, 0x00, 0x01, 0x12, 0x04, 0xa7, 0x01, 0x12, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d,
+    0x02, 0x00, 0x03, 0x12, 0x04, 0xa7, 0x01, 0x1a, 0x1b, 0x0a, 0x4c, 0x0a, 0x04, 0x04, 0x0d, 0x02,
+    0x01, 0x12, 0x04, 0xa9, 0x01, 0x02, 0x1b, 0x1a, 0x3e, 0x20, 0x5a, 0x65, 0x72, 0x6f, 0x2d, 0x69,
+    0x6e, 0x64, 0x65, 0x78, 0x65, 0x64, 0x20, 0x28, 0x65, 0x78, 0x63, 0x6c, 0x75, 0x73, 0x69, 0x76,
+    0x65, 0x29, 0x20, 0x6c, 0x69, 0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c,
+    0x6f, 0x67, 0x20, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x20, 0x69, 0x6e, 0x20, 0x60, 0x63, 0x6f,
+    0x6e, 0x74, 0x65, 0x6e, 0x74, 0x60, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x04,
+    0x12, 0x04, 0xa9, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x05, 0x12,
+    0x04, 0xa9, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x01, 0x12, 0x04,
+    0xa9, 0x01, 0x12, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x03, 0x12, 0x04, 0xa9,
+    0x01, 0x19, 0x1a, 0x0a, 0x23, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x02, 0x12, 0x04, 0xab, 0x01, 0x02,
+    0x1e, 0x1a, 0x15, 0x20, 0x4c, 0x69, 0x6e, 0x65, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x6c, 0x6f, 0x67,
+    0x20, 0x6f, 0x75, 0x74, 0x70, 0x75, 0x74, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02,
+    0x04, 0x12, 0x04, 0xab, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x05,
+    0x12, 0x04, 0xab, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x01, 0x12,
+    0x04, 0xab, 0x01, 0x12, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x02, 0x03, 0x12, 0x04,
+    0xab, 0x01, 0x1c, 0x1d, 0x0a, 0x9d, 0x01, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x03, 0x12, 0x04, 0xaf,
+    0x01, 0x02, 0x20, 0x1a, 0x8e, 0x01, 0x20, 0x57, 0x68, 0x69, 0x6c, 0x65, 0x20, 0x77, 0x65, 0x20,
+    0x6e, 0x65, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x70, 0x6f, 0x6c, 0x6c, 0x20, 0x66, 0x6f, 0x72,
+    0x20, 0x6c, 0x6f, 0x67, 0x73, 0x2c, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x73, 0x65, 0x72, 0x76,
+    0x65, 0x73, 0x20, 0x61, 0x73, 0x20, 0x61, 0x6e, 0x20, 0x69, 0x6e, 0x64, 0x69, 0x63, 0x61, 0x74,
+    0x6f, 0x72, 0x20, 0x74, 0x6f, 0x0a, 0x20, 0x63, 0x6c, 0x69, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x69,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x79, 0x20, 0x6e, 0x65, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x63,
+    0x6f, 0x6e, 0x74, 0x69, 0x6e, 0x75, 0x65, 0x20, 0x70, 0x6f, 0x6c, 0x6c, 0x69, 0x6e, 0x67, 0x20,
+    0x74, 0x6f, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x20, 0x6d, 0x6f, 0x72, 0x65, 0x20,
+    0x6c, 0x6f, 0x67, 0x73, 0x2c, 0x20, 0x6f, 0x72, 0x0a, 0x20, 0x63, 0x61, 0x6e, 0x20, 0x73, 0x74,
+    0x6f, 0x70, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x03, 0x04, 0x12, 0x04, 0xaf,
+    0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x03, 0x05, 0x12, 0x04, 0xaf, 0x01,
+    0x0b, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x03, 0x01, 0x12, 0x04, 0xaf, 0x01, 0x10,
+    0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x03, 0x03, 0x12, 0x04, 0xaf, 0x01, 0x1e, 0x1f,
 ];
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -1236,6 +1236,7 @@ pub enum ErrCode {
     VCS_CLONE = 1003,
     BUILD = 1004,
     POST_PROCESSOR = 1005,
+    BUILD_TIMEOUT = 1006,
 }
 
 impl ::protobuf::ProtobufEnum for ErrCode {
@@ -1263,6 +1264,7 @@ impl ::protobuf::ProtobufEnum for ErrCode {
             1003 => ::std::option::Option::Some(ErrCode::VCS_CLONE),
             1004 => ::std::option::Option::Some(ErrCode::BUILD),
             1005 => ::std::option::Option::Some(ErrCode::POST_PROCESSOR),
+            1006 => ::std::option::Option::Some(ErrCode::BUILD_TIMEOUT),
             _ => ::std::option::Option::None
         }
     }
@@ -1287,6 +1289,7 @@ impl ::protobuf::ProtobufEnum for ErrCode {
             ErrCode::VCS_CLONE,
             ErrCode::BUILD,
             ErrCode::POST_PROCESSOR,
+            ErrCode::BUILD_TIMEOUT,
         ];
         values
     }
@@ -1338,7 +1341,7 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x65, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x53, 0x72, 0x76, 0x10, 0x02, 0x12, 0x0d, 0x0a, 0x09, 0x4f,
     0x72, 0x69, 0x67, 0x69, 0x6e, 0x53, 0x72, 0x76, 0x10, 0x03, 0x12, 0x0a, 0x0a, 0x06, 0x4a, 0x6f,
     0x62, 0x53, 0x72, 0x76, 0x10, 0x04, 0x12, 0x0d, 0x0a, 0x09, 0x53, 0x63, 0x68, 0x65, 0x64, 0x75,
-    0x6c, 0x65, 0x72, 0x10, 0x05, 0x2a, 0xca, 0x02, 0x0a, 0x07, 0x45, 0x72, 0x72, 0x43, 0x6f, 0x64,
+    0x6c, 0x65, 0x72, 0x10, 0x05, 0x2a, 0xde, 0x02, 0x0a, 0x07, 0x45, 0x72, 0x72, 0x43, 0x6f, 0x64,
     0x65, 0x12, 0x07, 0x0a, 0x03, 0x42, 0x55, 0x47, 0x10, 0x00, 0x12, 0x0b, 0x0a, 0x07, 0x54, 0x49,
     0x4d, 0x45, 0x4f, 0x55, 0x54, 0x10, 0x01, 0x12, 0x13, 0x0a, 0x0f, 0x52, 0x45, 0x4d, 0x4f, 0x54,
     0x45, 0x5f, 0x52, 0x45, 0x4a, 0x45, 0x43, 0x54, 0x45, 0x44, 0x10, 0x02, 0x12, 0x14, 0x0a, 0x10,
@@ -1359,113 +1362,117 @@ static file_descriptor_proto_data: &'static [u8] = &[
     0x07, 0x12, 0x0e, 0x0a, 0x09, 0x56, 0x43, 0x53, 0x5f, 0x43, 0x4c, 0x4f, 0x4e, 0x45, 0x10, 0xeb,
     0x07, 0x12, 0x0a, 0x0a, 0x05, 0x42, 0x55, 0x49, 0x4c, 0x44, 0x10, 0xec, 0x07, 0x12, 0x13, 0x0a,
     0x0e, 0x50, 0x4f, 0x53, 0x54, 0x5f, 0x50, 0x52, 0x4f, 0x43, 0x45, 0x53, 0x53, 0x4f, 0x52, 0x10,
-    0xed, 0x07, 0x4a, 0x9f, 0x0d, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x35, 0x0f, 0x0a, 0x08, 0x0a,
-    0x01, 0x02, 0x12, 0x03, 0x00, 0x08, 0x0b, 0x0a, 0x0a, 0x0a, 0x02, 0x05, 0x00, 0x12, 0x04, 0x02,
-    0x00, 0x09, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x00, 0x01, 0x12, 0x03, 0x02, 0x05, 0x0d, 0x0a,
-    0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x00, 0x12, 0x03, 0x03, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x05, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x03, 0x02, 0x05, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00,
-    0x02, 0x00, 0x02, 0x12, 0x03, 0x03, 0x08, 0x09, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x01,
-    0x12, 0x03, 0x04, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03,
-    0x04, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x04, 0x0d,
-    0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x02, 0x12, 0x03, 0x05, 0x02, 0x11, 0x0a, 0x0c,
-    0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x05, 0x02, 0x0c, 0x0a, 0x0c, 0x0a, 0x05,
-    0x05, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x05, 0x0f, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00,
-    0x02, 0x03, 0x12, 0x03, 0x06, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x01,
-    0x12, 0x03, 0x06, 0x02, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03,
-    0x06, 0x0e, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x04, 0x12, 0x03, 0x07, 0x02, 0x0d,
-    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x07, 0x02, 0x08, 0x0a, 0x0c,
-    0x0a, 0x05, 0x05, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x07, 0x0b, 0x0c, 0x0a, 0x0b, 0x0a, 0x04,
-    0x05, 0x00, 0x02, 0x05, 0x12, 0x03, 0x08, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02,
-    0x05, 0x01, 0x12, 0x03, 0x08, 0x02, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x05, 0x02,
-    0x12, 0x03, 0x08, 0x0e, 0x0f, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x0b, 0x00, 0x0e,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x0b, 0x08, 0x11, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x0c, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x00, 0x04, 0x12, 0x03, 0x0c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00,
-    0x06, 0x12, 0x03, 0x0c, 0x0b, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12,
-    0x03, 0x0c, 0x14, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0c,
-    0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x0d, 0x02, 0x1b, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12, 0x03, 0x0d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x0d, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0d, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x01, 0x03, 0x12, 0x03, 0x0d, 0x19, 0x1a, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x10,
-    0x00, 0x14, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x10, 0x08, 0x0b, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x11, 0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x01, 0x02, 0x00, 0x04, 0x12, 0x03, 0x11, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
-    0x02, 0x00, 0x05, 0x12, 0x03, 0x11, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x11, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x11, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x12, 0x02,
-    0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x04, 0x12, 0x03, 0x12, 0x02, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x12, 0x0b, 0x10, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x12, 0x11, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x12, 0x18, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02,
-    0x02, 0x12, 0x03, 0x13, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x04, 0x12,
-    0x03, 0x13, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x06, 0x12, 0x03, 0x13,
-    0x0b, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x13, 0x15, 0x1f,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x13, 0x22, 0x23, 0x0a, 0x0a,
-    0x0a, 0x02, 0x05, 0x01, 0x12, 0x04, 0x16, 0x00, 0x2c, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x01,
-    0x01, 0x12, 0x03, 0x16, 0x05, 0x0c, 0x0a, 0x16, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x00, 0x12, 0x03,
-    0x18, 0x02, 0x0a, 0x1a, 0x09, 0x20, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x69, 0x63, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x05, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x18, 0x02, 0x05, 0x0a, 0x0c, 0x0a, 0x05,
-    0x05, 0x01, 0x02, 0x00, 0x02, 0x12, 0x03, 0x18, 0x08, 0x09, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01,
-    0x02, 0x01, 0x12, 0x03, 0x19, 0x02, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x01,
-    0x12, 0x03, 0x19, 0x02, 0x09, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x02, 0x12, 0x03,
-    0x19, 0x0c, 0x0d, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x02, 0x12, 0x03, 0x1a, 0x02, 0x16,
-    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x1a, 0x02, 0x11, 0x0a, 0x0c,
-    0x0a, 0x05, 0x05, 0x01, 0x02, 0x02, 0x02, 0x12, 0x03, 0x1a, 0x14, 0x15, 0x0a, 0x0b, 0x0a, 0x04,
-    0x05, 0x01, 0x02, 0x03, 0x12, 0x03, 0x1b, 0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02,
-    0x03, 0x01, 0x12, 0x03, 0x1b, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x03, 0x02,
-    0x12, 0x03, 0x1b, 0x15, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x04, 0x12, 0x03, 0x1c,
-    0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x04, 0x01, 0x12, 0x03, 0x1c, 0x02, 0x12,
-    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x04, 0x02, 0x12, 0x03, 0x1c, 0x15, 0x16, 0x0a, 0x0b,
-    0x0a, 0x04, 0x05, 0x01, 0x02, 0x05, 0x12, 0x03, 0x1d, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
-    0x01, 0x02, 0x05, 0x01, 0x12, 0x03, 0x1d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02,
-    0x05, 0x02, 0x12, 0x03, 0x1d, 0x0d, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x06, 0x12,
-    0x03, 0x1e, 0x02, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x06, 0x01, 0x12, 0x03, 0x1e,
-    0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x06, 0x02, 0x12, 0x03, 0x1e, 0x12, 0x13,
-    0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x07, 0x12, 0x03, 0x1f, 0x02, 0x16, 0x0a, 0x0c, 0x0a,
-    0x05, 0x05, 0x01, 0x02, 0x07, 0x01, 0x12, 0x03, 0x1f, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
-    0x01, 0x02, 0x07, 0x02, 0x12, 0x03, 0x1f, 0x14, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02,
-    0x08, 0x12, 0x03, 0x20, 0x02, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x08, 0x01, 0x12,
-    0x03, 0x20, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x08, 0x02, 0x12, 0x03, 0x20,
-    0x14, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x09, 0x12, 0x03, 0x21, 0x02, 0x0b, 0x0a,
-    0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x09, 0x01, 0x12, 0x03, 0x21, 0x02, 0x05, 0x0a, 0x0c, 0x0a,
-    0x05, 0x05, 0x01, 0x02, 0x09, 0x02, 0x12, 0x03, 0x21, 0x08, 0x0a, 0x0a, 0x0b, 0x0a, 0x04, 0x05,
-    0x01, 0x02, 0x0a, 0x12, 0x03, 0x22, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x0a,
-    0x01, 0x12, 0x03, 0x22, 0x02, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x0a, 0x02, 0x12,
-    0x03, 0x22, 0x0f, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x0b, 0x12, 0x03, 0x23, 0x02,
-    0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x0b, 0x01, 0x12, 0x03, 0x23, 0x02, 0x0c, 0x0a,
-    0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x0b, 0x02, 0x12, 0x03, 0x23, 0x0f, 0x11, 0x0a, 0x15, 0x0a,
-    0x04, 0x05, 0x01, 0x02, 0x0c, 0x12, 0x03, 0x26, 0x02, 0x19, 0x1a, 0x08, 0x20, 0x57, 0x6f, 0x72,
-    0x6b, 0x65, 0x72, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x0c, 0x01, 0x12, 0x03, 0x26,
-    0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x0c, 0x02, 0x12, 0x03, 0x26, 0x14, 0x18,
-    0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x0d, 0x12, 0x03, 0x27, 0x02, 0x1a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x05, 0x01, 0x02, 0x0d, 0x01, 0x12, 0x03, 0x27, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
-    0x01, 0x02, 0x0d, 0x02, 0x12, 0x03, 0x27, 0x15, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02,
-    0x0e, 0x12, 0x03, 0x28, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x0e, 0x01, 0x12,
-    0x03, 0x28, 0x02, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x0e, 0x02, 0x12, 0x03, 0x28,
-    0x16, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x0f, 0x12, 0x03, 0x29, 0x02, 0x13, 0x0a,
-    0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x0f, 0x01, 0x12, 0x03, 0x29, 0x02, 0x0b, 0x0a, 0x0c, 0x0a,
-    0x05, 0x05, 0x01, 0x02, 0x0f, 0x02, 0x12, 0x03, 0x29, 0x0e, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x05,
-    0x01, 0x02, 0x10, 0x12, 0x03, 0x2a, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x10,
-    0x01, 0x12, 0x03, 0x2a, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x10, 0x02, 0x12,
-    0x03, 0x2a, 0x0a, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x11, 0x12, 0x03, 0x2b, 0x02,
-    0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x11, 0x01, 0x12, 0x03, 0x2b, 0x02, 0x10, 0x0a,
-    0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x11, 0x02, 0x12, 0x03, 0x2b, 0x13, 0x17, 0x0a, 0x0a, 0x0a,
-    0x02, 0x04, 0x02, 0x12, 0x04, 0x2e, 0x00, 0x31, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01,
-    0x12, 0x03, 0x2e, 0x08, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x2f,
-    0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x2f, 0x02, 0x0a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03, 0x2f, 0x0b, 0x12, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x2f, 0x13, 0x17, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x2f, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02,
-    0x02, 0x01, 0x12, 0x03, 0x30, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x04,
-    0x12, 0x03, 0x30, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x05, 0x12, 0x03,
-    0x30, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x30, 0x12,
-    0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x30, 0x18, 0x19, 0x0a,
-    0x09, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x03, 0x33, 0x00, 0x10, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03,
-    0x01, 0x12, 0x03, 0x33, 0x08, 0x0d, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x03, 0x34, 0x00,
-    0x0f, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x34, 0x08, 0x0c, 0x0a, 0x09, 0x0a,
-    0x02, 0x04, 0x05, 0x12, 0x03, 0x35, 0x00, 0x0f, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12,
-    0x03, 0x35, 0x08, 0x0c,
+    0xed, 0x07, 0x12, 0x12, 0x0a, 0x0d, 0x42, 0x55, 0x49, 0x4c, 0x44, 0x5f, 0x54, 0x49, 0x4d, 0x45,
+    0x4f, 0x55, 0x54, 0x10, 0xee, 0x07, 0x4a, 0xc8, 0x0d, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x36,
+    0x0f, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x00, 0x08, 0x0b, 0x0a, 0x0a, 0x0a, 0x02, 0x05,
+    0x00, 0x12, 0x04, 0x02, 0x00, 0x09, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x00, 0x01, 0x12, 0x03,
+    0x02, 0x05, 0x0d, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x00, 0x12, 0x03, 0x03, 0x02, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x03, 0x02, 0x05, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x03, 0x08, 0x09, 0x0a, 0x0b, 0x0a, 0x04,
+    0x05, 0x00, 0x02, 0x01, 0x12, 0x03, 0x04, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02,
+    0x01, 0x01, 0x12, 0x03, 0x04, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x02,
+    0x12, 0x03, 0x04, 0x0d, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x02, 0x12, 0x03, 0x05,
+    0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x05, 0x02, 0x0c,
+    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x05, 0x0f, 0x10, 0x0a, 0x0b,
+    0x0a, 0x04, 0x05, 0x00, 0x02, 0x03, 0x12, 0x03, 0x06, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
+    0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x06, 0x02, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02,
+    0x03, 0x02, 0x12, 0x03, 0x06, 0x0e, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x04, 0x12,
+    0x03, 0x07, 0x02, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x07,
+    0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x07, 0x0b, 0x0c,
+    0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x05, 0x12, 0x03, 0x08, 0x02, 0x10, 0x0a, 0x0c, 0x0a,
+    0x05, 0x05, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x08, 0x02, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
+    0x00, 0x02, 0x05, 0x02, 0x12, 0x03, 0x08, 0x0e, 0x0f, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12,
+    0x04, 0x0b, 0x00, 0x0e, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x0b, 0x08,
+    0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x0c, 0x02, 0x21, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x0c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x00, 0x02, 0x00, 0x06, 0x12, 0x03, 0x0c, 0x0b, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x00, 0x01, 0x12, 0x03, 0x0c, 0x14, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00,
+    0x03, 0x12, 0x03, 0x0c, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03,
+    0x0d, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x04, 0x12, 0x03, 0x0d, 0x02,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x0d, 0x0b, 0x11, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0d, 0x12, 0x16, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12, 0x03, 0x0d, 0x19, 0x1a, 0x0a, 0x0a, 0x0a, 0x02, 0x04,
+    0x01, 0x12, 0x04, 0x10, 0x00, 0x14, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03,
+    0x10, 0x08, 0x0b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x11, 0x02, 0x21,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x04, 0x12, 0x03, 0x11, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03, 0x11, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x11, 0x12, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x00, 0x03, 0x12, 0x03, 0x11, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01,
+    0x12, 0x03, 0x12, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x04, 0x12, 0x03,
+    0x12, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x12, 0x0b,
+    0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x12, 0x11, 0x15, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x12, 0x18, 0x19, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x01, 0x02, 0x02, 0x12, 0x03, 0x13, 0x02, 0x24, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x02, 0x04, 0x12, 0x03, 0x13, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02,
+    0x06, 0x12, 0x03, 0x13, 0x0b, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12,
+    0x03, 0x13, 0x15, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x13,
+    0x22, 0x23, 0x0a, 0x0a, 0x0a, 0x02, 0x05, 0x01, 0x12, 0x04, 0x16, 0x00, 0x2d, 0x01, 0x0a, 0x0a,
+    0x0a, 0x03, 0x05, 0x01, 0x01, 0x12, 0x03, 0x16, 0x05, 0x0c, 0x0a, 0x16, 0x0a, 0x04, 0x05, 0x01,
+    0x02, 0x00, 0x12, 0x03, 0x18, 0x02, 0x0a, 0x1a, 0x09, 0x20, 0x47, 0x65, 0x6e, 0x65, 0x72, 0x69,
+    0x63, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x18, 0x02, 0x05,
+    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x00, 0x02, 0x12, 0x03, 0x18, 0x08, 0x09, 0x0a, 0x0b,
+    0x0a, 0x04, 0x05, 0x01, 0x02, 0x01, 0x12, 0x03, 0x19, 0x02, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
+    0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x19, 0x02, 0x09, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02,
+    0x01, 0x02, 0x12, 0x03, 0x19, 0x0c, 0x0d, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x02, 0x12,
+    0x03, 0x1a, 0x02, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x1a,
+    0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x02, 0x02, 0x12, 0x03, 0x1a, 0x14, 0x15,
+    0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x03, 0x12, 0x03, 0x1b, 0x02, 0x17, 0x0a, 0x0c, 0x0a,
+    0x05, 0x05, 0x01, 0x02, 0x03, 0x01, 0x12, 0x03, 0x1b, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
+    0x01, 0x02, 0x03, 0x02, 0x12, 0x03, 0x1b, 0x15, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02,
+    0x04, 0x12, 0x03, 0x1c, 0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x04, 0x01, 0x12,
+    0x03, 0x1c, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x04, 0x02, 0x12, 0x03, 0x1c,
+    0x15, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x05, 0x12, 0x03, 0x1d, 0x02, 0x0f, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x05, 0x01, 0x12, 0x03, 0x1d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x05, 0x01, 0x02, 0x05, 0x02, 0x12, 0x03, 0x1d, 0x0d, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x05,
+    0x01, 0x02, 0x06, 0x12, 0x03, 0x1e, 0x02, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x06,
+    0x01, 0x12, 0x03, 0x1e, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x06, 0x02, 0x12,
+    0x03, 0x1e, 0x12, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x07, 0x12, 0x03, 0x1f, 0x02,
+    0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x07, 0x01, 0x12, 0x03, 0x1f, 0x02, 0x11, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x07, 0x02, 0x12, 0x03, 0x1f, 0x14, 0x15, 0x0a, 0x0b, 0x0a,
+    0x04, 0x05, 0x01, 0x02, 0x08, 0x12, 0x03, 0x20, 0x02, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01,
+    0x02, 0x08, 0x01, 0x12, 0x03, 0x20, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x08,
+    0x02, 0x12, 0x03, 0x20, 0x14, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x09, 0x12, 0x03,
+    0x21, 0x02, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x09, 0x01, 0x12, 0x03, 0x21, 0x02,
+    0x05, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x09, 0x02, 0x12, 0x03, 0x21, 0x08, 0x0a, 0x0a,
+    0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x0a, 0x12, 0x03, 0x22, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x01, 0x02, 0x0a, 0x01, 0x12, 0x03, 0x22, 0x02, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01,
+    0x02, 0x0a, 0x02, 0x12, 0x03, 0x22, 0x0f, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x0b,
+    0x12, 0x03, 0x23, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x0b, 0x01, 0x12, 0x03,
+    0x23, 0x02, 0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x0b, 0x02, 0x12, 0x03, 0x23, 0x0f,
+    0x11, 0x0a, 0x15, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x0c, 0x12, 0x03, 0x26, 0x02, 0x19, 0x1a, 0x08,
+    0x20, 0x57, 0x6f, 0x72, 0x6b, 0x65, 0x72, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x0c,
+    0x01, 0x12, 0x03, 0x26, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x0c, 0x02, 0x12,
+    0x03, 0x26, 0x14, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x0d, 0x12, 0x03, 0x27, 0x02,
+    0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x0d, 0x01, 0x12, 0x03, 0x27, 0x02, 0x12, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x0d, 0x02, 0x12, 0x03, 0x27, 0x15, 0x19, 0x0a, 0x0b, 0x0a,
+    0x04, 0x05, 0x01, 0x02, 0x0e, 0x12, 0x03, 0x28, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01,
+    0x02, 0x0e, 0x01, 0x12, 0x03, 0x28, 0x02, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x0e,
+    0x02, 0x12, 0x03, 0x28, 0x16, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x0f, 0x12, 0x03,
+    0x29, 0x02, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x0f, 0x01, 0x12, 0x03, 0x29, 0x02,
+    0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x0f, 0x02, 0x12, 0x03, 0x29, 0x0e, 0x12, 0x0a,
+    0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x10, 0x12, 0x03, 0x2a, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x01, 0x02, 0x10, 0x01, 0x12, 0x03, 0x2a, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01,
+    0x02, 0x10, 0x02, 0x12, 0x03, 0x2a, 0x0a, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x11,
+    0x12, 0x03, 0x2b, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x11, 0x01, 0x12, 0x03,
+    0x2b, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x11, 0x02, 0x12, 0x03, 0x2b, 0x13,
+    0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x12, 0x12, 0x03, 0x2c, 0x02, 0x17, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x01, 0x02, 0x12, 0x01, 0x12, 0x03, 0x2c, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x01, 0x02, 0x12, 0x02, 0x12, 0x03, 0x2c, 0x12, 0x16, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02,
+    0x12, 0x04, 0x2f, 0x00, 0x32, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x2f,
+    0x08, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x30, 0x02, 0x1c, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x04, 0x12, 0x03, 0x30, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x00, 0x06, 0x12, 0x03, 0x30, 0x0b, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x30, 0x13, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x00, 0x03, 0x12, 0x03, 0x30, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12,
+    0x03, 0x31, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x04, 0x12, 0x03, 0x31,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x05, 0x12, 0x03, 0x31, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x31, 0x12, 0x15, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x31, 0x18, 0x19, 0x0a, 0x09, 0x0a, 0x02,
+    0x04, 0x03, 0x12, 0x03, 0x34, 0x00, 0x10, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03,
+    0x34, 0x08, 0x0d, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x03, 0x35, 0x00, 0x0f, 0x0a, 0x0a,
+    0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x35, 0x08, 0x0c, 0x0a, 0x09, 0x0a, 0x02, 0x04, 0x05,
+    0x12, 0x03, 0x36, 0x00, 0x0f, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x36, 0x08,
+    0x0c,
 ];
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -56,6 +56,7 @@ impl error::Error for NetError {
             ErrCode::VCS_CLONE => "Worker runner unable to retrieve project source to build.",
             ErrCode::BUILD => "Worker runner failed to build project.",
             ErrCode::POST_PROCESSOR => "One or more post processing step failed in Worker runner.",
+            ErrCode::BUILD_TIMEOUT => "Worker runner stopped a build which ran out of time.",
         }
     }
 }
@@ -5,15 +5,18 @@
 // is made available under an open source license such as the Apache 2.0 License.
 
 use std::fmt;
+use std::path::Path;
 use std::result;
 use std::str::FromStr;
 
 use hab_core;
 use hab_core::package::{self, Identifiable, FromArchive, PackageArchive};
+use protobuf::RepeatedField;
 
 use serde::{Serialize, Serializer};
 use serde::ser::SerializeStruct;
 
+use error::ProtocolError;
 pub use message::originsrv::*;
 use message::Routable;
 use sharding::InstaId;
@@ -65,11 +68,12 @@ impl Serialize for Origin {
     fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
         where S: Serializer
     {
-        let mut strukt = try!(serializer.serialize_struct("origin", 4));
+        let mut strukt = try!(serializer.serialize_struct("origin", 5));
         try!(strukt.serialize_field("id", &self.get_id().to_string()));
         try!(strukt.serialize_field("name", self.get_name()));
         try!(strukt.serialize_field("owner_id", &self.get_owner_id().to_string()));
         try!(strukt.serialize_field("private_key_name", self.get_private_key_name()));
+        try!(strukt.serialize_field("rdeps_builds", &self.get_rdeps_builds()));
         strukt.end()
     }
 }
@@ -185,6 +189,14 @@ impl Routable for OriginCreate {
     }
 }
 
+impl Routable for OriginUpdate {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_name().to_string())
+    }
+}
+
 impl Routable for OriginGet {
     type H = String;
 
@@ -323,6 +335,9 @@ impl FromArchive for OriginPackage {
         let config = try!(archive.config());
         let checksum = try!(archive.checksum());
         let target = try!(archive.target());
+        let binds = try!(archive_binds(archive));
+        let files = try!(archive_files(archive));
+        let binaries = try!(archive_binaries(archive, &files));
 
         let mut package = OriginPackage::new();
         package.set_ident(ident);
@@ -331,6 +346,8 @@ impl FromArchive for OriginPackage {
         package.set_deps(deps);
         package.set_tdeps(tdeps);
         package.set_exposes(exposes);
+        package.set_binds(RepeatedField::from_vec(binds));
+        package.set_binaries(RepeatedField::from_vec(binaries));
         if let Some(cfg) = config {
             package.set_config(cfg);
         }
@@ -349,7 +366,7 @@ impl Serialize for OriginPackage {
     fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
         where S: Serializer
     {
-        let mut strukt = try!(serializer.serialize_struct("origin_package", 8));
+        let mut strukt = try!(serializer.serialize_struct("origin_package", 9));
         try!(strukt.serialize_field("ident", self.get_ident()));
         try!(strukt.serialize_field("checksum", self.get_checksum()));
         try!(strukt.serialize_field("manifest", self.get_manifest()));
@@ -358,10 +375,103 @@ impl Serialize for OriginPackage {
         try!(strukt.serialize_field("tdeps", self.get_tdeps()));
         try!(strukt.serialize_field("exposes", self.get_exposes()));
         try!(strukt.serialize_field("config", self.get_config()));
+        try!(strukt.serialize_field("yanked", &self.get_yanked()));
+        strukt.end()
+    }
+}
+
+impl Serialize for OriginPackageAudit {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let mut strukt = try!(serializer.serialize_struct("origin_package_audit", 6));
+        try!(strukt.serialize_field("ident", self.get_ident()));
+        try!(strukt.serialize_field("operation", &self.get_operation()));
+        try!(strukt.serialize_field("channel", self.get_channel()));
+        try!(strukt.serialize_field("requestor", self.get_requestor_name()));
+        try!(strukt.serialize_field("reason", self.get_reason()));
+        try!(strukt.serialize_field("created_at", self.get_created_at()));
+        strukt.end()
+    }
+}
+
+impl Routable for OriginPackageAuditListRequest {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(String::from(self.get_ident().get_origin()))
+    }
+}
+
+impl Serialize for OriginPackageAuditListResponse {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let mut strukt = try!(serializer.serialize_struct("origin_package_audit_list_response",
+                                                          1));
+        try!(strukt.serialize_field("events", self.get_events()));
         strukt.end()
     }
 }
 
+impl Serialize for OriginPackageDownloadCount {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let mut strukt = try!(serializer.serialize_struct("origin_package_download_count", 4));
+        try!(strukt.serialize_field("ident", self.get_ident()));
+        try!(strukt.serialize_field("target", self.get_target()));
+        try!(strukt.serialize_field("day", self.get_day()));
+        try!(strukt.serialize_field("count", &self.get_count()));
+        strukt.end()
+    }
+}
+
+impl Routable for OriginPackageDownloadRecord {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(String::from(self.get_ident().get_origin()))
+    }
+}
+
+impl Serialize for OriginPackageDownloadStats {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let mut strukt = try!(serializer.serialize_struct("origin_package_download_stats", 3));
+        try!(strukt.serialize_field("ident", self.get_ident()));
+        try!(strukt.serialize_field("total", &self.get_total()));
+        try!(strukt.serialize_field("downloads", self.get_downloads()));
+        strukt.end()
+    }
+}
+
+impl Routable for OriginPackageDownloadStatsGet {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(String::from(self.get_ident().get_origin()))
+    }
+}
+
+impl Routable for OriginPackageFilesSet {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(String::from(self.get_ident().get_origin()))
+    }
+}
+
+impl Routable for OriginPackageFilesUnindexedListRequest {
+    type H = String;
+
+    // Packages of every origin are listed, so any shard will do
+    fn route_key(&self) -> Option<Self::H> {
+        None
+    }
+}
+
 impl FromArchive for OriginPackageCreate {
     type Error = hab_core::Error;
 
@@ -386,6 +496,9 @@ impl FromArchive for OriginPackageCreate {
         let config = try!(archive.config());
         let checksum = try!(archive.checksum());
         let target = try!(archive.target());
+        let binds = try!(archive_binds(archive));
+        let files = try!(archive_files(archive));
+        let binaries = try!(archive_binaries(archive, &files));
 
         let mut package = OriginPackageCreate::new();
         package.set_ident(ident);
@@ -394,6 +507,9 @@ impl FromArchive for OriginPackageCreate {
         package.set_deps(deps);
         package.set_tdeps(tdeps);
         package.set_exposes(exposes);
+        package.set_binds(RepeatedField::from_vec(binds));
+        package.set_binaries(RepeatedField::from_vec(binaries));
+        package.set_files(RepeatedField::from_vec(files));
         if let Some(cfg) = config {
             package.set_config(cfg);
         }
@@ -410,6 +526,14 @@ impl Routable for OriginPackageCreate {
     }
 }
 
+impl Routable for OriginPackageDemote {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(String::from(self.get_ident().get_origin()))
+    }
+}
+
 impl Routable for OriginPackageGet {
     type H = String;
 
@@ -582,6 +706,17 @@ impl Routable for OriginPackagePromote {
     }
 }
 
+impl Serialize for OriginPackageOperation {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        match *self {
+            OriginPackageOperation::Demote => serializer.serialize_str("Demote"),
+            OriginPackageOperation::Yank => serializer.serialize_str("Yank"),
+        }
+    }
+}
+
 impl Pageable for OriginPackageSearchRequest {
     fn get_range(&self) -> [u64; 2] {
         [self.get_start(), self.get_stop()]
@@ -596,6 +731,32 @@ impl Routable for OriginPackageSearchRequest {
     }
 }
 
+impl Serialize for OriginPackageSearchResult {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        // The ident is flattened into the result so clients reading a plain list of package
+        // identifiers can still consume search results.
+        let ident = self.get_ident();
+        let mut strukt = try!(serializer.serialize_struct("origin_package_search_result", 10));
+        try!(strukt.serialize_field("origin", ident.get_origin()));
+        try!(strukt.serialize_field("name", ident.get_name()));
+        if !ident.get_version().is_empty() {
+            try!(strukt.serialize_field("version", ident.get_version()));
+        }
+        if !ident.get_release().is_empty() {
+            try!(strukt.serialize_field("release", ident.get_release()));
+        }
+        try!(strukt.serialize_field("target", self.get_target()));
+        try!(strukt.serialize_field("exposes", self.get_exposes()));
+        try!(strukt.serialize_field("binds", self.get_binds()));
+        try!(strukt.serialize_field("binaries", self.get_binaries()));
+        try!(strukt.serialize_field("files", self.get_files()));
+        try!(strukt.serialize_field("relevance", &self.get_relevance()));
+        strukt.end()
+    }
+}
+
 impl Pageable for OriginPackageUniqueListRequest {
     fn get_range(&self) -> [u64; 2] {
         [self.get_start(), self.get_stop()]
@@ -610,6 +771,14 @@ impl Routable for OriginPackageUniqueListRequest {
     }
 }
 
+impl Routable for OriginPackageYank {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(String::from(self.get_ident().get_origin()))
+    }
+}
+
 impl Serialize for OriginProject {
     fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
         where S: Serializer
@@ -624,10 +793,48 @@ impl Serialize for OriginProject {
         try!(state.serialize_field("owner_id", &self.get_owner_id().to_string()));
         try!(state.serialize_field("vcs_type", self.get_vcs_type()));
         try!(state.serialize_field("vcs_data", self.get_vcs_data()));
+        try!(state.serialize_field("cross_origin_rdeps", &self.get_cross_origin_rdeps()));
+        try!(state.serialize_field("build_timeout", &self.get_build_timeout()));
+        try!(state.serialize_field("vcs_ref", self.get_vcs_ref()));
+        try!(state.serialize_field("vcs_submodules", &self.get_vcs_submodules()));
+        try!(state.serialize_field("vcs_auth", &self.get_vcs_auth()));
+        try!(state.serialize_field("vcs_auth_secret", self.get_vcs_auth_secret()));
         state.end()
     }
 }
 
+impl Serialize for VcsAuth {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl VcsAuth {
+    /// Name of the credential kind used when cloning a project's source.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            VcsAuth::Anonymous => "anonymous",
+            VcsAuth::Token => "token",
+            VcsAuth::DeployKey => "deploy-key",
+        }
+    }
+}
+
+impl FromStr for VcsAuth {
+    type Err = ProtocolError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value {
+            "anonymous" => Ok(VcsAuth::Anonymous),
+            "token" => Ok(VcsAuth::Token),
+            "deploy-key" => Ok(VcsAuth::DeployKey),
+            _ => Err(ProtocolError::BadVcsAuth(value.to_string())),
+        }
+    }
+}
+
 impl Routable for OriginProjectCreate {
     type H = InstaId;
 
@@ -774,3 +981,270 @@ impl Routable for OriginSecretKeyGet {
         Some(String::from(self.get_origin()))
     }
 }
+
+impl Serialize for OriginEncryptionKey {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let mut strukt = try!(serializer.serialize_struct("origin_encryption_key", 7));
+        try!(strukt.serialize_field("id", &self.get_id().to_string()));
+        try!(strukt.serialize_field("origin_id", &self.get_origin_id().to_string()));
+        try!(strukt.serialize_field("name", self.get_name()));
+        try!(strukt.serialize_field("revision", self.get_revision()));
+        try!(strukt.serialize_field("public_body", self.get_public_body()));
+        try!(strukt.serialize_field("secret_body", self.get_secret_body()));
+        try!(strukt.serialize_field("owner_id", &self.get_owner_id().to_string()));
+        strukt.end()
+    }
+}
+
+impl Routable for OriginEncryptionKeyCreate {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
+impl Routable for OriginEncryptionKeyGet {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
+impl Serialize for OriginSecret {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let mut strukt = try!(serializer.serialize_struct("origin_secret", 5));
+        try!(strukt.serialize_field("id", &self.get_id().to_string()));
+        try!(strukt.serialize_field("origin_id", &self.get_origin_id().to_string()));
+        try!(strukt.serialize_field("project_id", &self.get_project_id().to_string()));
+        try!(strukt.serialize_field("name", self.get_name()));
+        try!(strukt.serialize_field("created_at", self.get_created_at()));
+        strukt.end()
+    }
+}
+
+impl Routable for OriginSecretCreate {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
+impl Routable for OriginSecretDelete {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
+impl Routable for OriginSecretListRequest {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
+impl Serialize for OriginWebhook {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let mut strukt = try!(serializer.serialize_struct("origin_webhook", 5));
+        try!(strukt.serialize_field("id", &self.get_id().to_string()));
+        try!(strukt.serialize_field("origin_id", &self.get_origin_id().to_string()));
+        try!(strukt.serialize_field("url", self.get_url()));
+        try!(strukt.serialize_field("events", self.get_events()));
+        try!(strukt.serialize_field("created_at", self.get_created_at()));
+        strukt.end()
+    }
+}
+
+impl Routable for OriginWebhookCreate {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
+impl Routable for OriginWebhookDelete {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
+impl Serialize for OriginWebhookDelivery {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let mut strukt = try!(serializer.serialize_struct("origin_webhook_delivery", 9));
+        try!(strukt.serialize_field("id", &self.get_id().to_string()));
+        try!(strukt.serialize_field("delivery_id", self.get_delivery_id()));
+        try!(strukt.serialize_field("event", &self.get_event()));
+        try!(strukt.serialize_field("payload", self.get_payload()));
+        try!(strukt.serialize_field("status_code", &self.get_status_code()));
+        try!(strukt.serialize_field("attempts", &self.get_attempts()));
+        try!(strukt.serialize_field("success", &self.get_success()));
+        try!(strukt.serialize_field("error", self.get_error()));
+        try!(strukt.serialize_field("created_at", self.get_created_at()));
+        strukt.end()
+    }
+}
+
+impl Routable for OriginWebhookDeliveryCreate {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
+impl Routable for OriginWebhookDeliveryListRequest {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
+impl Serialize for OriginWebhookDeliveryListResponse {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let mut strukt = try!(serializer.serialize_struct("origin_webhook_delivery_list_response",
+                                                          2));
+        try!(strukt.serialize_field("webhook_id", &self.get_webhook_id().to_string()));
+        try!(strukt.serialize_field("deliveries", self.get_deliveries()));
+        strukt.end()
+    }
+}
+
+impl Serialize for OriginWebhookEvent {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl OriginWebhookEvent {
+    /// Name of the event as it's registered through, and delivered by, the depot API.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            OriginWebhookEvent::Upload => "upload",
+            OriginWebhookEvent::Promote => "promote",
+            OriginWebhookEvent::Demote => "demote",
+            OriginWebhookEvent::KeyUpload => "key-upload",
+            OriginWebhookEvent::Invitation => "invitation",
+        }
+    }
+}
+
+impl FromStr for OriginWebhookEvent {
+    type Err = ProtocolError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value {
+            "upload" => Ok(OriginWebhookEvent::Upload),
+            "promote" => Ok(OriginWebhookEvent::Promote),
+            "demote" => Ok(OriginWebhookEvent::Demote),
+            "key-upload" => Ok(OriginWebhookEvent::KeyUpload),
+            "invitation" => Ok(OriginWebhookEvent::Invitation),
+            _ => Err(ProtocolError::BadWebhookEvent(value.to_string())),
+        }
+    }
+}
+
+impl Routable for OriginWebhookListRequest {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
+impl Serialize for OriginWebhookListResponse {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let mut strukt = try!(serializer.serialize_struct("origin_webhook_list_response", 2));
+        try!(strukt.serialize_field("origin_id", &self.get_origin_id().to_string()));
+        try!(strukt.serialize_field("webhooks", self.get_webhooks()));
+        strukt.end()
+    }
+}
+
+/// Returns the names of the services a package binds to, whether required or optional.
+fn archive_binds(archive: &mut PackageArchive) -> hab_core::Result<Vec<String>> {
+    let mut binds: Vec<String> = try!(archive.binds())
+        .into_iter()
+        .map(|b| b.service)
+        .collect();
+    binds.extend(try!(archive.binds_optional())
+                     .into_iter()
+                     .map(|b| b.service));
+    Ok(binds)
+}
+
+/// Returns the path of every file listed in the archive's `FILES` metafile.
+fn archive_files(archive: &mut PackageArchive) -> hab_core::Result<Vec<String>> {
+    Ok(try!(archive.file_list())
+           .into_iter()
+           .map(|file| file.path)
+           .collect())
+}
+
+/// Returns the names of the files which sit directly in one of the directories the archive
+/// adds to `PATH`.
+fn archive_binaries(archive: &mut PackageArchive,
+                    files: &[String])
+                    -> hab_core::Result<Vec<String>> {
+    match try!(archive.path()) {
+        Some(path) => Ok(binaries_on_path(&path, files)),
+        None => Ok(Vec::new()),
+    }
+}
+
+fn binaries_on_path(path: &str, files: &[String]) -> Vec<String> {
+    let dirs: Vec<&Path> = path.split(':')
+        .filter(|dir| !dir.is_empty())
+        .map(|dir| Path::new(dir))
+        .collect();
+    files
+        .iter()
+        .filter_map(|file| {
+                        let file = Path::new(file);
+                        match (file.parent(), file.file_name()) {
+                            (Some(parent), Some(name)) if dirs.contains(&parent) => {
+                                Some(name.to_string_lossy().into_owned())
+                            }
+                            _ => None,
+                        }
+                    })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn binaries_are_files_on_path() {
+        let files = vec!["/hab/pkgs/core/openssl/1.0.2l/20170513215923/bin/c_rehash".to_string(),
+                         "/hab/pkgs/core/openssl/1.0.2l/20170513215923/bin/openssl".to_string(),
+                         "/hab/pkgs/core/openssl/1.0.2l/20170513215923/lib/libssl.so.1.0.0"
+                             .to_string()];
+        assert_eq!(binaries_on_path("/hab/pkgs/core/openssl/1.0.2l/20170513215923/bin", &files),
+                   vec!["c_rehash", "openssl"]);
+        assert!(binaries_on_path("", &files).is_empty());
+    }
+}
@@ -72,6 +72,22 @@ impl Routable for GroupGet {
     }
 }
 
+impl Routable for GroupRetry {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_group_id().to_string())
+    }
+}
+
+impl Routable for GroupGraphGet {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_group_id().to_string())
+    }
+}
+
 impl Routable for PackageCreate {
     type H = String;
 
@@ -151,6 +167,33 @@ impl Serialize for Group {
     }
 }
 
+impl Serialize for GroupGraphNode {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let mut strukt = try!(serializer.serialize_struct("group_graph_node", 6));
+        try!(strukt.serialize_field("name", &self.get_name()));
+        try!(strukt.serialize_field("ident", &self.get_ident()));
+        try!(strukt.serialize_field("state", &self.get_state()));
+        try!(strukt.serialize_field("job_id", &self.get_job_id()));
+        try!(strukt.serialize_field("deps", &self.get_deps()));
+        try!(strukt.serialize_field("package_missing", &self.get_package_missing()));
+        strukt.end()
+    }
+}
+
+impl Serialize for GroupGraph {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let mut strukt = try!(serializer.serialize_struct("group_graph", 3));
+        try!(strukt.serialize_field("id", &self.get_id()));
+        try!(strukt.serialize_field("state", &self.get_state()));
+        try!(strukt.serialize_field("nodes", &self.get_nodes()));
+        strukt.end()
+    }
+}
+
 impl Serialize for PackageStats {
     fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
         where S: Serializer
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::{HashMap, HashSet};
+
 use db::pool::Pool;
 use db::migration::Migrator;
 use postgres;
@@ -249,6 +251,58 @@ impl DataStore {
                                     UPDATE projects SET project_state=state, updated_at=now() WHERE owner_id=gid AND project_name=pname;
                                 END
                              $$ LANGUAGE plpgsql VOLATILE"#)?;
+        // Reset the failed and skipped projects of a finished group, and make it pending again
+        migrator.migrate("scheduler-v2",
+                             r#"CREATE OR REPLACE FUNCTION retry_group_v1 (gid bigint) RETURNS SETOF projects AS $$
+                                BEGIN
+                                    PERFORM 1 FROM groups WHERE id=gid AND group_state IN ('Complete', 'Failed') FOR UPDATE;
+                                    IF NOT FOUND THEN
+                                        RETURN;
+                                    END IF;
+
+                                    RETURN QUERY UPDATE projects SET project_state='NotStarted', job_id=0, updated_at=now()
+                                    WHERE owner_id=gid AND project_state IN ('Failure', 'Skipped')
+                                    RETURNING *;
+
+                                    IF FOUND THEN
+                                        UPDATE groups SET group_state='Pending', updated_at=now() WHERE id=gid;
+                                    END IF;
+                                    RETURN;
+                                END
+                             $$ LANGUAGE plpgsql VOLATILE"#)?;
+        // Like retry_group_v1, but a failed group whose other projects are still building isn't
+        // retried until they're done
+        migrator.migrate("scheduler-v3",
+                             r#"CREATE OR REPLACE FUNCTION retry_group_v2 (gid bigint) RETURNS SETOF projects AS $$
+                                BEGIN
+                                    PERFORM 1 FROM groups WHERE id=gid AND group_state IN ('Complete', 'Failed') FOR UPDATE;
+                                    IF NOT FOUND THEN
+                                        RETURN;
+                                    END IF;
+
+                                    PERFORM 1 FROM projects WHERE owner_id=gid AND project_state IN ('InProgress', 'NotStarted');
+                                    IF FOUND THEN
+                                        RETURN;
+                                    END IF;
+
+                                    RETURN QUERY UPDATE projects SET project_state='NotStarted', job_id=0, updated_at=now()
+                                    WHERE owner_id=gid AND project_state IN ('Failure', 'Skipped')
+                                    RETURNING *;
+
+                                    IF FOUND THEN
+                                        UPDATE groups SET group_state='Pending', updated_at=now() WHERE id=gid;
+                                    END IF;
+                                    RETURN;
+                                END
+                             $$ LANGUAGE plpgsql VOLATILE"#)?;
+        migrator.migrate("scheduler-v3",
+                     r#"CREATE OR REPLACE FUNCTION get_packages_by_ident_v1 (pidents text[]) RETURNS SETOF packages AS $$
+                            BEGIN
+                              RETURN QUERY SELECT * FROM packages
+                              WHERE ident = ANY(pidents);
+                              RETURN;
+                            END
+                            $$ LANGUAGE plpgsql STABLE"#)?;
         migrator.finish()?;
 
         Ok(())
@@ -394,6 +448,69 @@ impl DataStore {
         Ok(Some(group))
     }
 
+    /// Returns the dependency graph of a group's projects. Edges only point at projects of the
+    /// same group, so the nodes without deps are the ones the rebuild starts from. Projects
+    /// whose package the scheduler has no record of are marked as such, without deps.
+    pub fn get_group_graph(&self, msg: &GroupGraphGet) -> Result<Option<GroupGraph>> {
+        let mut group_get = GroupGet::new();
+        group_get.set_group_id(msg.get_group_id());
+
+        let group = match self.get_group(&group_get)? {
+            Some(group) => group,
+            None => return Ok(None),
+        };
+
+        let names: HashSet<&str> = group.get_projects().iter().map(|p| p.get_name()).collect();
+        let idents: Vec<String> = group
+            .get_projects()
+            .iter()
+            .map(|p| p.get_ident().to_string())
+            .collect();
+
+        let conn = self.pool.get_shard(0)?;
+        let rows = &conn.query("SELECT * FROM get_packages_by_ident_v1($1)", &[&idents])
+                        .map_err(Error::PackagesGet)?;
+        let mut packages = HashMap::new();
+        for row in rows {
+            let package = self.row_to_package(&row)?;
+            packages.insert(package.get_ident().to_string(), package);
+        }
+
+        let mut nodes = RepeatedField::new();
+        for project in group.get_projects() {
+            let mut node = GroupGraphNode::new();
+            node.set_name(project.get_name().to_string());
+            node.set_ident(project.get_ident().to_string());
+            node.set_state(project.get_state());
+            node.set_job_id(project.get_job_id());
+            match packages.get(project.get_ident()) {
+                Some(package) => {
+                    let deps = package
+                        .get_deps()
+                        .iter()
+                        .map(|dep| dep.split("/").take(2).collect::<Vec<&str>>().join("/"))
+                        .filter(|name| names.contains(&name[..]))
+                        .collect();
+                    node.set_deps(deps);
+                }
+                None => {
+                    warn!("No package found for project {} of group {}, ident={}",
+                          project.get_name(),
+                          group.get_id(),
+                          project.get_ident());
+                    node.set_package_missing(true);
+                }
+            }
+            nodes.push(node);
+        }
+
+        let mut graph = GroupGraph::new();
+        graph.set_id(group.get_id());
+        graph.set_state(group.get_state());
+        graph.set_nodes(nodes);
+        Ok(Some(graph))
+    }
+
     fn row_to_package(&self, row: &postgres::rows::Row) -> Result<Package> {
         let mut package = Package::new();
 
@@ -520,7 +637,7 @@ impl DataStore {
 
         let state = match job.get_state() {
             JobState::Complete => "Success",
-            JobState::Failed | JobState::Rejected => "Failure",
+            JobState::Failed | JobState::Rejected | JobState::Cancelled => "Failure",
             _ => "InProgress",
         };
 
@@ -531,6 +648,26 @@ impl DataStore {
         Ok(())
     }
 
+    /// Sets the failed and skipped projects of a complete or failed group back to `NotStarted`,
+    /// and the group back to `Pending` so that the scheduler dispatches them again. Projects
+    /// which already succeeded are left alone.
+    ///
+    /// Returns the names of the projects which will be rebuilt; nothing is changed if the group
+    /// is still being dispatched.
+    pub fn retry_group(&self, group_id: u64) -> Result<Vec<String>> {
+        let conn = self.pool.get_shard(0)?;
+        let rows = &conn.query("SELECT * FROM retry_group_v2($1)", &[&(group_id as i64)])
+                        .map_err(Error::GroupRetry)?;
+
+        let mut names = Vec::new();
+        for row in rows {
+            let name: String = row.get("project_name");
+            names.push(name);
+        }
+
+        Ok(names)
+    }
+
     pub fn pending_groups(&self, count: i32) -> Result<Vec<Group>> {
         let mut groups = Vec::new();
 
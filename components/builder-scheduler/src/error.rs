@@ -42,6 +42,7 @@ pub enum Error {
     GroupCreate(postgres::error::Error),
     GroupGet(postgres::error::Error),
     GroupPending(postgres::error::Error),
+    GroupRetry(postgres::error::Error),
     GroupSetState(postgres::error::Error),
     ProjectSetState(postgres::error::Error),
     NetError(hab_net::Error),
@@ -80,6 +81,7 @@ impl fmt::Display for Error {
             Error::GroupCreate(ref e) => format!("Database error creating a new group, {}", e),
             Error::GroupGet(ref e) => format!("Database error getting group data, {}", e),
             Error::GroupPending(ref e) => format!("Database error getting pending group, {}", e),
+            Error::GroupRetry(ref e) => format!("Database error retrying group, {}", e),
             Error::GroupSetState(ref e) => format!("Database error setting group state, {}", e),
             Error::ProjectSetState(ref e) => format!("Database error setting project state, {}", e),
             Error::NetError(ref e) => format!("{}", e),
@@ -113,6 +115,7 @@ impl error::Error for Error {
             Error::GroupCreate(ref err) => err.description(),
             Error::GroupGet(ref err) => err.description(),
             Error::GroupPending(ref err) => err.description(),
+            Error::GroupRetry(ref err) => err.description(),
             Error::GroupSetState(ref err) => err.description(),
             Error::ProjectSetState(ref err) => err.description(),
             Error::NetError(ref err) => err.description(),
@@ -14,9 +14,13 @@
 
 //! A collection of handlers for the Scheduler dispatcher
 
+use std::collections::HashMap;
+
 use time::PreciseTime;
+use hab_net::routing::{Broker, BrokerConn};
 use hab_net::server::Envelope;
 use protocol::net::{self, ErrCode};
+use protocol::originsrv::{Origin, OriginGet, OriginProject, OriginProjectGet};
 use protocol::scheduler as proto;
 use protobuf::RepeatedField;
 use zmq;
@@ -75,17 +79,20 @@ pub fn group_create(req: &mut Envelope,
                    rdeps.len(),
                    start_time.to(end_time));
 
-            for s in rdeps {
-                let origin = s.0.split("/").nth(0).unwrap();
-
-                // We only build core packages for now
-                if origin == "core" {
-                    debug!("Adding to projects: {} ({})", s.0, s.1);
-                    projects.push(s.clone());
-                } else {
-                    debug!("Skipping non-core project: {} ({})", s.0, s.1);
+            let mut conn = match Broker::connect() {
+                Ok(conn) => conn,
+                Err(e) => {
+                    error!("Unable to connect to the broker, err={:?}", e);
+                    let err = net::err(ErrCode::ZMQ, "sc:group-create:2");
+                    try!(req.reply_complete(sock, &err));
+                    return Ok(());
                 }
-            }
+            };
+            let mut settings = BrokerRdepsSettings {
+                conn: &mut conn,
+                origins: HashMap::new(),
+            };
+            projects.extend(filter_rdeps(msg.get_origin(), rdeps, &mut settings));
         }
         None => {
             debug!("Graph rdeps: no entries found");
@@ -111,6 +118,79 @@ pub fn group_create(req: &mut Envelope,
     Ok(())
 }
 
+/// Where the reverse dependency build settings of origins and projects are looked up.
+trait RdepsSettings {
+    /// Returns whether reverse dependencies in the given origin are built.
+    fn origin_rdeps_builds(&mut self, origin: &str) -> bool;
+
+    /// Returns whether the given project is built for packages of other origins.
+    fn project_cross_origin_rdeps(&mut self, project_name: &str) -> bool;
+}
+
+struct BrokerRdepsSettings<'a> {
+    conn: &'a mut BrokerConn,
+    origins: HashMap<String, bool>,
+}
+
+impl<'a> RdepsSettings for BrokerRdepsSettings<'a> {
+    fn origin_rdeps_builds(&mut self, origin: &str) -> bool {
+        if let Some(enabled) = self.origins.get(origin) {
+            return *enabled;
+        }
+        let mut request = OriginGet::new();
+        request.set_name(origin.to_string());
+        let enabled = match self.conn.route::<OriginGet, Origin>(&request) {
+            Ok(origin) => origin.get_rdeps_builds(),
+            Err(err) => {
+                warn!("Unable to retrieve origin: {:?}, error: {:?}", origin, err);
+                false
+            }
+        };
+        self.origins.insert(origin.to_string(), enabled);
+        enabled
+    }
+
+    fn project_cross_origin_rdeps(&mut self, project_name: &str) -> bool {
+        let mut request = OriginProjectGet::new();
+        request.set_name(project_name.to_string());
+        match self.conn.route::<OriginProjectGet, OriginProject>(&request) {
+            Ok(project) => project.get_cross_origin_rdeps(),
+            Err(err) => {
+                warn!("Unable to retrieve project: {:?}, error: {:?}",
+                      project_name,
+                      err);
+                false
+            }
+        }
+    }
+}
+
+/// Picks the reverse dependencies of a package of `origin` which should be rebuilt. Only
+/// reverse dependencies in origins which have opted in are built, and only projects which have
+/// opted in as well are built across an origin boundary.
+fn filter_rdeps<S: RdepsSettings>(origin: &str,
+                                  rdeps: Vec<(String, String)>,
+                                  settings: &mut S)
+                                  -> Vec<(String, String)> {
+    let mut projects = Vec::new();
+    for s in rdeps {
+        let rdep_origin = s.0.split("/").nth(0).unwrap().to_string();
+        if !settings.origin_rdeps_builds(&rdep_origin) {
+            debug!("Skipping project, origin rdeps builds disabled: {} ({})",
+                   s.0,
+                   s.1);
+        } else if rdep_origin != origin && !settings.project_cross_origin_rdeps(&s.0) {
+            debug!("Skipping project, cross-origin rdeps builds disabled: {} ({})",
+                   s.0,
+                   s.1);
+        } else {
+            debug!("Adding to projects: {} ({})", s.0, s.1);
+            projects.push(s);
+        }
+    }
+    projects
+}
+
 pub fn group_get(req: &mut Envelope,
                  sock: &mut zmq::Socket,
                  state: &mut ServerState)
@@ -141,6 +221,95 @@ pub fn group_get(req: &mut Envelope,
     Ok(())
 }
 
+pub fn group_retry(req: &mut Envelope,
+                   sock: &mut zmq::Socket,
+                   state: &mut ServerState)
+                   -> Result<()> {
+    let msg: proto::GroupRetry = try!(req.parse_msg());
+    debug!("group_retry message: {:?}", msg);
+
+    let mut group_get = proto::GroupGet::new();
+    group_get.set_group_id(msg.get_group_id());
+
+    let group = match state.datastore().get_group(&group_get)? {
+        Some(group) => group,
+        None => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "sc:group-retry:1");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+    };
+
+    // Only a group which is done building can be retried, otherwise the scheduler could
+    // dispatch the same project twice. A failed group keeps building the projects which don't
+    // depend on the failure, so its state alone isn't enough.
+    let building = group
+        .get_projects()
+        .iter()
+        .any(|project| match project.get_state() {
+                 proto::ProjectState::InProgress |
+                 proto::ProjectState::NotStarted => true,
+                 _ => false,
+             });
+    match group.get_state() {
+        proto::GroupState::Complete |
+        proto::GroupState::Failed if !building => (),
+        _ => {
+            let err = net::err(ErrCode::ENTITY_CONFLICT, "sc:group-retry:2");
+            try!(req.reply_complete(sock, &err));
+            return Ok(());
+        }
+    }
+
+    let retried = state.datastore().retry_group(group.get_id())?;
+    if retried.is_empty() {
+        debug!("Nothing to retry in group {}", group.get_id());
+        try!(req.reply_complete(sock, &group));
+        return Ok(());
+    }
+
+    debug!("Retrying {} projects of group {}: {:?}",
+           retried.len(),
+           group.get_id(),
+           retried);
+    try!(state.schedule_cli().notify_work());
+
+    match state.datastore().get_group(&group_get)? {
+        Some(group) => try!(req.reply_complete(sock, &group)),
+        None => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "sc:group-retry:3");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+
+    Ok(())
+}
+
+pub fn group_graph_get(req: &mut Envelope,
+                       sock: &mut zmq::Socket,
+                       state: &mut ServerState)
+                       -> Result<()> {
+    let msg: proto::GroupGraphGet = try!(req.parse_msg());
+    debug!("group_graph_get message: {:?}", msg);
+
+    match state.datastore().get_group_graph(&msg) {
+        Ok(Some(graph)) => try!(req.reply_complete(sock, &graph)),
+        Ok(None) => {
+            let err = net::err(ErrCode::ENTITY_NOT_FOUND, "sc:group-graph-get:1");
+            try!(req.reply_complete(sock, &err));
+        }
+        Err(err) => {
+            error!("Unable to retrieve graph of group {}, err: {:?}",
+                   msg.get_group_id(),
+                   err);
+            let err = net::err(ErrCode::DATA_STORE, "sc:group-graph-get:2");
+            try!(req.reply_complete(sock, &err));
+        }
+    }
+
+    Ok(())
+}
+
 pub fn package_create(req: &mut Envelope,
                       sock: &mut zmq::Socket,
                       state: &mut ServerState)
@@ -200,3 +369,68 @@ pub fn package_stats_get(req: &mut Envelope,
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestSettings {
+        origins: Vec<&'static str>,
+        cross_origin_projects: Vec<&'static str>,
+        asked: Vec<String>,
+    }
+
+    impl RdepsSettings for TestSettings {
+        fn origin_rdeps_builds(&mut self, origin: &str) -> bool {
+            self.origins.iter().any(|o| *o == origin)
+        }
+
+        fn project_cross_origin_rdeps(&mut self, project_name: &str) -> bool {
+            self.asked.push(project_name.to_string());
+            self.cross_origin_projects.iter().any(|p| *p == project_name)
+        }
+    }
+
+    fn settings(origins: Vec<&'static str>,
+                cross_origin_projects: Vec<&'static str>)
+                -> TestSettings {
+        TestSettings {
+            origins: origins,
+            cross_origin_projects: cross_origin_projects,
+            asked: Vec::new(),
+        }
+    }
+
+    fn rdeps() -> Vec<(String, String)> {
+        vec![("core/curl".to_string(), "core/curl/7.54.0/20170513215448".to_string()),
+             ("acme/app".to_string(), "acme/app/1.0.0/20170513215448".to_string()),
+             ("acme/tool".to_string(), "acme/tool/1.0.0/20170513215448".to_string()),
+             ("other/app".to_string(), "other/app/1.0.0/20170513215448".to_string())]
+    }
+
+    fn names(projects: Vec<(String, String)>) -> Vec<String> {
+        projects.into_iter().map(|p| p.0).collect()
+    }
+
+    #[test]
+    fn filter_rdeps_skips_origins_without_rdeps_builds() {
+        let mut settings = settings(vec!["core"], vec!["acme/app", "other/app"]);
+        let projects = filter_rdeps("core", rdeps(), &mut settings);
+        assert_eq!(names(projects), vec!["core/curl"]);
+    }
+
+    #[test]
+    fn filter_rdeps_crosses_origins_only_for_opted_in_projects() {
+        let mut settings = settings(vec!["core", "acme"], vec!["acme/app", "other/app"]);
+        let projects = filter_rdeps("core", rdeps(), &mut settings);
+        assert_eq!(names(projects), vec!["core/curl", "acme/app"]);
+    }
+
+    #[test]
+    fn filter_rdeps_within_origin_ignores_project_opt_in() {
+        let mut settings = settings(vec!["acme"], vec![]);
+        let projects = filter_rdeps("acme", rdeps(), &mut settings);
+        assert_eq!(names(projects), vec!["acme/app", "acme/tool"]);
+        assert!(settings.asked.is_empty());
+    }
+}
@@ -111,6 +111,8 @@ impl Dispatcher for Worker {
         match message.message_id() {
             "GroupCreate" => handlers::group_create(message, sock, state),
             "GroupGet" => handlers::group_get(message, sock, state),
+            "GroupRetry" => handlers::group_retry(message, sock, state),
+            "GroupGraphGet" => handlers::group_graph_get(message, sock, state),
             "PackageCreate" => handlers::package_create(message, sock, state),
             "JobStatus" => handlers::job_status(message, sock, state),
             "PackageStatsGet" => handlers::package_stats_get(message, sock, state),
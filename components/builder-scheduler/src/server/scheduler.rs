@@ -405,7 +405,8 @@ impl ScheduleMgr {
                 match job.get_state() {
                     jobsrv::JobState::Complete |
                     jobsrv::JobState::Rejected |
-                    jobsrv::JobState::Failed => self.update_group_state(job.get_owner_id())?,
+                    jobsrv::JobState::Failed |
+                    jobsrv::JobState::Cancelled => self.update_group_state(job.get_owner_id())?,
                     _ => (),
                 }
             }
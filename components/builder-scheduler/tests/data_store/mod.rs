@@ -188,3 +188,109 @@ fn set_group_job_state() {
     assert_eq!(group.get_projects().last().unwrap().get_state(),
                scheduler::ProjectState::Success);
 }
+
+#[test]
+fn retry_group() {
+    let project_names = vec![(String::from("Foo/Bar"), String::from("Foo/Bar/0/Baz")),
+                             (String::from("Foo/Baz"), String::from("Foo/Baz/0/Baz")),
+                             (String::from("Foo/Qux"), String::from("Foo/Qux/0/Baz"))];
+    let mut msg = scheduler::GroupCreate::new();
+    msg.set_origin(String::from("Foo"));
+    msg.set_package(String::from("Bar"));
+
+    let ds = datastore_test!(DataStore);
+
+    let group = ds.create_group(&msg, project_names)
+        .expect("Failed to create a group");
+
+    // A group which is still pending has nothing to retry
+    let retried = ds.retry_group(group.get_id())
+        .expect("Failed to retry group");
+    assert!(retried.is_empty());
+
+    // Nor does a failed group which is still building its other projects
+    ds.set_group_project_state(group.get_id(), "Foo/Bar", scheduler::ProjectState::InProgress)
+        .expect("Failed to set project state");
+    ds.set_group_project_state(group.get_id(), "Foo/Baz", scheduler::ProjectState::Failure)
+        .expect("Failed to set project state");
+    ds.set_group_project_state(group.get_id(), "Foo/Qux", scheduler::ProjectState::Skipped)
+        .expect("Failed to set project state");
+    ds.set_group_state(group.get_id(), scheduler::GroupState::Failed)
+        .expect("Failed to update group state");
+    let retried = ds.retry_group(group.get_id())
+        .expect("Failed to retry group");
+    assert!(retried.is_empty());
+
+    ds.set_group_project_state(group.get_id(), "Foo/Bar", scheduler::ProjectState::Success)
+        .expect("Failed to set project state");
+
+    let mut retried = ds.retry_group(group.get_id())
+        .expect("Failed to retry group");
+    retried.sort();
+    assert_eq!(retried, vec!["Foo/Baz", "Foo/Qux"]);
+
+    let mut get_msg = scheduler::GroupGet::new();
+    get_msg.set_group_id(group.get_id());
+
+    let group = ds.get_group(&get_msg)
+        .expect("Failed to get group from database")
+        .expect("No group found");
+    assert_eq!(group.get_state(), scheduler::GroupState::Pending);
+    for project in group.get_projects() {
+        let expected = match project.get_name() {
+            "Foo/Bar" => scheduler::ProjectState::Success,
+            _ => scheduler::ProjectState::NotStarted,
+        };
+        assert_eq!(project.get_state(), expected);
+    }
+}
+
+#[test]
+fn get_group_graph() {
+    let ds = datastore_test!(DataStore);
+
+    let mut package = scheduler::PackageCreate::new();
+    package.set_ident(String::from("Foo/Bar/1.0/20170101000000"));
+    ds.create_package(&package)
+        .expect("Failed to create a package");
+    package.set_ident(String::from("Foo/Baz/1.0/20170101000000"));
+    package.set_deps(vec![String::from("Foo/Bar/1.0/20170101000000"),
+                          String::from("core/glibc/2.22/20160612063629")]
+                             .into());
+    ds.create_package(&package)
+        .expect("Failed to create a package");
+
+    // The scheduler has no record of Foo/Qux's package
+    let project_names = vec![(String::from("Foo/Bar"), String::from("Foo/Bar/1.0/20170101000000")),
+                             (String::from("Foo/Baz"), String::from("Foo/Baz/1.0/20170101000000")),
+                             (String::from("Foo/Qux"), String::from("Foo/Qux/1.0/20170101000000"))];
+    let mut msg = scheduler::GroupCreate::new();
+    msg.set_origin(String::from("Foo"));
+    msg.set_package(String::from("Bar"));
+
+    let group = ds.create_group(&msg, project_names)
+        .expect("Failed to create a group");
+
+    let mut graph_msg = scheduler::GroupGraphGet::new();
+    graph_msg.set_group_id(group.get_id());
+
+    let graph = ds.get_group_graph(&graph_msg)
+        .expect("Failed to get group graph")
+        .expect("No group found");
+    assert_eq!(graph.get_id(), group.get_id());
+    assert_eq!(graph.get_nodes().len(), 3);
+    for node in graph.get_nodes() {
+        assert_eq!(node.get_state(), scheduler::ProjectState::NotStarted);
+        assert_eq!(node.get_package_missing(), node.get_name() == "Foo/Qux");
+        match node.get_name() {
+            "Foo/Bar" | "Foo/Qux" => assert!(node.get_deps().is_empty()),
+            "Foo/Baz" => assert_eq!(node.get_deps(), &[String::from("Foo/Bar")]),
+            name => panic!("Unexpected node {}", name),
+        }
+    }
+
+    graph_msg.set_group_id(0);
+    let result = ds.get_group_graph(&graph_msg)
+        .expect("Failed to get group graph");
+    assert!(result.is_none());
+}
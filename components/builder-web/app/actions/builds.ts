@@ -64,6 +64,16 @@ export function fetchBuild(id: string, token: string) {
   };
 }
 
+// Fetches the build again without clearing it first, for following its progress.
+export function refreshBuild(id: string, token: string) {
+  return dispatch => {
+    new BuilderApiClient(token)
+      .getBuild(id)
+      .then((data) => dispatch(populateBuild(data)))
+      .catch((error) => console.error(error));
+  };
+}
+
 export function fetchBuildLog(id: string, token: string, start = 0) {
   return (dispatch, getState) => {
 
@@ -82,6 +92,7 @@ export function fetchBuildLog(id: string, token: string, start = 0) {
             setTimeout(() => { dispatch(fetchBuild(id, token)); }, 5000);
           }
           else if (!complete && getState().builds.selected.stream) {
+            dispatch(refreshBuild(id, token));
             setTimeout(() => { dispatch(fetchBuildLog(id, token, data["stop"])); }, 2000);
           }
       })
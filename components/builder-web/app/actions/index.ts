@@ -135,6 +135,7 @@ export const clearBuilds = buildActions.clearBuilds;
 export const fetchBuild = buildActions.fetchBuild;
 export const fetchBuildLog = buildActions.fetchBuildLog;
 export const fetchBuilds = buildActions.fetchBuilds;
+export const refreshBuild = buildActions.refreshBuild;
 export const streamBuildLog = buildActions.streamBuildLog;
 export const scheduleBuild = buildActions.scheduleBuild;
 
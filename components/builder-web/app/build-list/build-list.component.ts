@@ -20,6 +20,7 @@ export class BuildListComponent {
 
     iconFor(state) {
         return {
+            Cancelled: "circle-slash",
             Complete: "check",
             Dispatched: "sync",
             Failed: "issue-opened",
@@ -17,6 +17,7 @@ export class BuildStatusComponent {
 
   iconFor(state) {
       return {
+          Cancelled: "circle-slash",
           Complete: "check",
           Dispatched: "sync",
           Failed: "issue-opened",
@@ -130,6 +130,39 @@ describe("BuildComponent", () => {
     });
   });
 
+  describe("phases", () => {
+
+    it("shows the phase a failed build stopped in as failed", () => {
+      spyOn(store, "getState").and.returnValue({
+        builds: {
+          selected: {
+            info: {
+              state: "Failed",
+              phases: [
+                {
+                  phase: "FetchKey",
+                  started_at: "2017-05-01T10:00:00+00:00",
+                  finished_at: "2017-05-01T10:00:05+00:00"
+                },
+                {
+                  phase: "Clone",
+                  started_at: "2017-05-01T10:00:05+00:00",
+                  finished_at: "2017-05-01T10:01:05+00:00"
+                }
+              ]
+            }
+          }
+        }
+      });
+
+      let phases = component.phases;
+
+      expect(phases.map((p) => p.state)).toEqual(["Complete", "Failed"]);
+      expect(phases[1].name).toEqual("Clone source");
+      expect(phases[1].elapsed).toEqual("1 min, 0 sec");
+    });
+  });
+
   describe("on destroy", () => {
 
     it("terminates log streaming", () => {
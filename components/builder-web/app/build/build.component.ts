@@ -7,6 +7,21 @@ import { fetchBuildLog, streamBuildLog } from "../actions/index";
 import { requireSignIn } from "../util";
 import { AppStore } from "../AppStore";
 
+const phaseNames = {
+    FetchKey: "Fetch origin key",
+    Clone: "Clone source",
+    Build: "Build",
+    PostProcess: "Post-process",
+    Publish: "Publish"
+};
+
+// Formats the time between two timestamps, or since the first if the second isn't known yet.
+function duration(started, finished) {
+    let s = +moment.utc(started);
+    let f = finished ? +moment.utc(finished) : +moment.utc();
+    return moment.utc(f - s).format("m [min], s [sec]");
+}
+
 @Component({
     selector: "hab-build",
     template: require("./build.component.html")
@@ -42,6 +57,7 @@ export class BuildComponent implements OnChanges, OnDestroy {
 
     iconFor(state) {
         return {
+            Cancelled: "circle-slash",
             Complete: "check",
             Dispatched: "sync",
             Failed: "issue-opened",
@@ -51,6 +67,27 @@ export class BuildComponent implements OnChanges, OnDestroy {
         }[state];
     }
 
+    get phases() {
+        let phases = this.info.phases || [];
+
+        return phases.map((phase, i) => {
+            let state = "Complete";
+
+            // The phase a build stopped in takes on the state of the build
+            if (!phase.finished_at) {
+                state = "Processing";
+            } else if (i === phases.length - 1 && this.info.state !== "Complete") {
+                state = this.info.state;
+            }
+
+            return {
+                name: phaseNames[phase.phase] || phase.phase,
+                state: state,
+                elapsed: duration(phase.started_at, phase.finished_at)
+            };
+        });
+    }
+
     get buildsLink() {
         let link = ["/pkgs", this.build.origin, this.build.name];
 
@@ -67,9 +104,7 @@ export class BuildComponent implements OnChanges, OnDestroy {
         let e;
 
         if (started && finished) {
-            let s = +moment.utc(started);
-            let f = +moment.utc(finished);
-            e = moment.utc(f - s).format("m [min], s [sec]");
+            e = duration(started, finished);
         }
 
         return e;
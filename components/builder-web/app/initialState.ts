@@ -49,7 +49,8 @@ export default Record({
                 state: undefined,
                 build_start: undefined,
                 build_stop: undefined,
-                created_at: undefined
+                created_at: undefined,
+                phases: undefined
             })(),
             log: Record({
                 start: undefined,
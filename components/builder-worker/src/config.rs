@@ -25,20 +25,37 @@ use error::Error;
 #[derive(Clone, Debug, Deserialize)]
 #[serde(default)]
 pub struct Config {
+    /// Allow projects of the `local` VCS type, which build a repository on the worker's own
+    /// filesystem. Meant for testing the pipeline offline.
+    pub allow_local_vcs: bool,
     /// Token for authenticating with the public builder-api
     pub auth_token: String,
     /// Enable automatic publishing for all builds by default
     pub auto_publish: bool,
+    /// Maximum duration of a build in seconds for projects which don't set their own, 0 for no
+    /// limit
+    pub build_timeout: u64,
+    /// Resource limits of the studio builds are run in
+    pub cgroup: CgroupCfg,
     /// Filepath where persistent application data is stored
     pub data_path: PathBuf,
+    /// Size in megabytes the cache of artifacts builds install their dependencies from may
+    /// grow to, 0 to disable the cache
+    pub dep_cache_mb: u64,
     /// Default channel name for Publish post-processor to use to determine which channel to
     /// publish artifacts to
     pub depot_channel: String,
     /// Default URL for Publish post-processor to use to determine which Builder Depot to use
     /// for retrieving signing keys and publishing artifacts
     pub depot_url: String,
+    /// Default container registry for the Export post-processor to push images to
+    pub export_registry: String,
     /// List of Job Servers to connect to
     pub jobsrv: JobSrvCfg,
+    /// Seconds the artifact of a job which failed to publish is kept around for a retry
+    pub shelf_ttl: u64,
+    /// Number of jobs built concurrently, each in its own workspace and studio
+    pub slots: u32,
 }
 
 impl Config {
@@ -57,12 +74,19 @@ impl Config {
 impl Default for Config {
     fn default() -> Self {
         Config {
+            allow_local_vcs: false,
             auth_token: "".to_string(),
             auto_publish: true,
+            build_timeout: 0,
+            cgroup: CgroupCfg::default(),
             data_path: PathBuf::from("/tmp"),
+            dep_cache_mb: 10_240,
             depot_channel: String::from("unstable"),
             depot_url: url::default_depot_url(),
+            export_registry: String::from("localhost:5000"),
             jobsrv: vec![JobSrvAddr::default()],
+            shelf_ttl: 86_400,
+            slots: 1,
         }
     }
 }
@@ -71,6 +95,33 @@ impl ConfigFile for Config {
     type Error = Error;
 }
 
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct CgroupCfg {
+    /// Run builds in a cgroup, limiting and reporting the resources they use
+    pub enabled: bool,
+    /// Mount point of the cgroup (v1) controller hierarchies
+    pub root: PathBuf,
+    /// Name of the cgroup, created in each hierarchy, which holds the cgroups of builds
+    pub parent: String,
+    /// Number of CPUs a build may use, 0 for no limit
+    pub cpus: f64,
+    /// Memory a build may use in megabytes, 0 for no limit
+    pub memory_mb: u64,
+}
+
+impl Default for CgroupCfg {
+    fn default() -> Self {
+        CgroupCfg {
+            enabled: false,
+            root: PathBuf::from("/sys/fs/cgroup"),
+            parent: String::from("habitat-builder"),
+            cpus: 0.0,
+            memory_mb: 0,
+        }
+    }
+}
+
 pub type JobSrvCfg = Vec<JobSrvAddr>;
 
 #[derive(Clone, Debug, Deserialize)]
@@ -101,7 +152,18 @@ mod tests {
     fn config_from_file() {
         let content = r#"
         auth_token = "mytoken"
+        allow_local_vcs = true
         data_path = "/path/to/data"
+        dep_cache_mb = 2048
+        build_timeout = 7200
+        export_registry = "registry.example.com:5000"
+        shelf_ttl = 3600
+        slots = 4
+
+        [cgroup]
+        enabled = true
+        cpus = 1.5
+        memory_mb = 4096
 
         [[jobsrv]]
         host = "1:1:1:1:1:1:1:1"
@@ -115,7 +177,17 @@ mod tests {
 
         let config = Config::from_raw(&content).unwrap();
         assert_eq!(&config.auth_token, "mytoken");
+        assert!(config.allow_local_vcs);
         assert_eq!(&format!("{}", config.data_path.display()), "/path/to/data");
+        assert_eq!(config.dep_cache_mb, 2048);
+        assert_eq!(config.build_timeout, 7200);
+        assert_eq!(&config.export_registry, "registry.example.com:5000");
+        assert_eq!(config.shelf_ttl, 3600);
+        assert_eq!(config.slots, 4);
+        assert!(config.cgroup.enabled);
+        assert_eq!(config.cgroup.cpus, 1.5);
+        assert_eq!(config.cgroup.memory_mb, 4096);
+        assert_eq!(&config.cgroup.parent, "habitat-builder");
         assert_eq!(&format!("{}", config.jobsrv[0].host), "1:1:1:1:1:1:1:1");
         assert_eq!(config.jobsrv[0].port, 9000);
         assert_eq!(config.jobsrv[0].heartbeat, 9001);
@@ -17,19 +17,32 @@ use std::fmt;
 use std::io;
 use std::result;
 
+use depot_client;
 use git2;
+use hab;
 use hab_core;
 use protobuf;
 use zmq;
 
 #[derive(Debug)]
 pub enum Error {
+    ArtifactVerify(String),
+    BuildCancelled,
     BuildFailure(i32),
+    BuildSecret(String),
+    BuildTimeout(u64),
+    Cgroup(String, io::Error),
+    DepotClient(depot_client::Error),
     Git(git2::Error),
     HabitatCore(hab_core::Error),
     IO(io::Error),
+    LocalVCSDisabled,
+    PostProcessFailure(String, i32),
     Protobuf(protobuf::ProtobufError),
-    UnknownVCS,
+    Sbom(hab::error::Error),
+    UnknownVCS(String),
+    UnknownVCSUrl(String),
+    VCSAuthSecret(String),
     WorkspaceSetup(String, io::Error),
     WorkspaceTeardown(String, io::Error),
     Zmq(zmq::Error),
@@ -40,14 +53,38 @@ pub type Result<T> = result::Result<T, Error>;
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
+            Error::ArtifactVerify(ref e) => format!("Unable to verify artifact, {}", e),
+            Error::BuildCancelled => format!("Build was cancelled"),
             Error::BuildFailure(ref e) => {
                 format!("Build studio exited with non-zero exit code, {}", e)
             }
+            Error::BuildSecret(ref e) => {
+                format!("Build secret {} contains whitespace and can't be passed to the studio",
+                        e)
+            }
+            Error::BuildTimeout(ref s) => format!("Build did not finish within {} seconds", s),
+            Error::Cgroup(ref p, ref e) => {
+                format!("Error while managing build cgroup at {}, err={:?}", p, e)
+            }
+            Error::DepotClient(ref e) => format!("{}", e),
             Error::Git(ref e) => format!("{}", e),
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::IO(ref e) => format!("{}", e),
+            Error::LocalVCSDisabled => {
+                format!("Job requires a local VCS, which this worker does not allow")
+            }
+            Error::PostProcessFailure(ref c, ref e) => {
+                format!("Post-processing command {} exited with non-zero exit code, {}", c, e)
+            }
             Error::Protobuf(ref e) => format!("{}", e),
-            Error::UnknownVCS => format!("Job requires an unknown VCS"),
+            Error::Sbom(ref e) => format!("Unable to generate bill of materials, {}", e),
+            Error::UnknownVCS(ref e) => format!("Job requires an unknown VCS, {}", e),
+            Error::UnknownVCSUrl(ref e) => {
+                format!("Repository URL {} uses a scheme that can't be cloned", e)
+            }
+            Error::VCSAuthSecret(ref e) => {
+                format!("Build secret {} holding the VCS credentials was not found", e)
+            }
             Error::Zmq(ref e) => format!("{}", e),
             Error::WorkspaceSetup(ref p, ref e) => {
                 format!("Error while setting up workspace at {}, err={:?}", p, e)
@@ -63,12 +100,25 @@ impl fmt::Display for Error {
 impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
+            Error::ArtifactVerify(_) => "Unable to verify artifact",
+            Error::BuildCancelled => "Build was cancelled",
             Error::BuildFailure(_) => "Build studio exited with a non-zero exit code",
+            Error::BuildSecret(_) => "Build secret contains whitespace",
+            Error::BuildTimeout(_) => "Build did not finish in time",
+            Error::Cgroup(_, _) => "IO Error while managing the cgroup of a build",
+            Error::DepotClient(ref err) => err.description(),
             Error::Git(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
             Error::IO(ref err) => err.description(),
+            Error::LocalVCSDisabled => "Job requires a local VCS, which the worker does not allow",
+            Error::PostProcessFailure(_, _) => {
+                "Post-processing command exited with a non-zero exit code"
+            }
             Error::Protobuf(ref err) => err.description(),
-            Error::UnknownVCS => "Job requires an unknown VCS",
+            Error::Sbom(ref err) => err.description(),
+            Error::UnknownVCS(_) => "Job requires an unknown VCS",
+            Error::UnknownVCSUrl(_) => "Repository URL uses a scheme that can't be cloned",
+            Error::VCSAuthSecret(_) => "Build secret holding the VCS credentials was not found",
             Error::WorkspaceSetup(_, _) => "IO Error while creating workspace on disk",
             Error::WorkspaceTeardown(_, _) => "IO Error while destroying workspace on disk",
             Error::Zmq(ref err) => err.description(),
@@ -76,6 +126,12 @@ impl error::Error for Error {
     }
 }
 
+impl From<depot_client::Error> for Error {
+    fn from(err: depot_client::Error) -> Error {
+        Error::DepotClient(err)
+    }
+}
+
 impl From<git2::Error> for Error {
     fn from(err: git2::Error) -> Error {
         Error::Git(err)
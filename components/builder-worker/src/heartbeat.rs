@@ -92,16 +92,20 @@ impl HeartbeatCli {
         Ok(())
     }
 
-    /// Set the `HeartbeatMgr` state to busy
-    pub fn set_busy(&mut self) -> Result<()> {
-        try!(self.sock.send_str(PulseState::Pause.as_ref(), 0));
+    /// Set the `HeartbeatMgr` state to busy after receiving the given number of jobs
+    pub fn set_busy(&mut self, jobs_received: u64) -> Result<()> {
+        try!(self.sock.send_str(PulseState::Pause.as_ref(), zmq::SNDMORE));
+        try!(self.sock.send_str(&jobs_received.to_string(), 0));
         try!(self.sock.recv(&mut self.msg, 0));
         Ok(())
     }
 
-    /// Set the `HeartbeatMgr` state to ready
-    pub fn set_ready(&mut self) -> Result<()> {
-        try!(self.sock.send_str(PulseState::Pulse.as_ref(), 0));
+    /// Set the `HeartbeatMgr` state to ready, advertising the given number of free build slots
+    /// left after receiving the given number of jobs
+    pub fn set_ready(&mut self, free_slots: u32, jobs_received: u64) -> Result<()> {
+        try!(self.sock.send_str(PulseState::Pulse.as_ref(), zmq::SNDMORE));
+        try!(self.sock.send_str(&free_slots.to_string(), zmq::SNDMORE));
+        try!(self.sock.send_str(&jobs_received.to_string(), 0));
         try!(self.sock.recv(&mut self.msg, 0));
         Ok(())
     }
@@ -113,7 +117,6 @@ pub struct HeartbeatMgr {
     pub pub_sock: zmq::Socket,
     /// Internal socket for sending and receiving message to and from a `HeartbeatCli`
     pub cli_sock: zmq::Socket,
-    state: PulseState,
     config: Arc<RwLock<Config>>,
     reg: proto::Heartbeat,
     msg: zmq::Message,
@@ -146,8 +149,12 @@ impl HeartbeatMgr {
         reg.set_endpoint(Server::net_ident());
         reg.set_os(worker_os());
         reg.set_state(proto::WorkerState::Ready);
+        {
+            let cfg = config.read().unwrap();
+            reg.set_slots(cfg.slots);
+            reg.set_free_slots(cfg.slots);
+        }
         Ok(HeartbeatMgr {
-               state: PulseState::default(),
                config: config,
                pub_sock: pub_sock,
                cli_sock: cli_sock,
@@ -171,9 +178,8 @@ impl HeartbeatMgr {
         thread::sleep(Duration::from_millis(100));
         let mut cli_sock_msg = false;
         loop {
-            if self.state == PulseState::Pulse {
-                try!(self.pulse());
-            }
+            // Busy workers keep pulsing so jobsrv knows they are still building their jobs
+            try!(self.pulse());
             {
                 let mut items = [self.cli_sock.as_poll_item(1)];
                 // Poll until timeout or message is received. Checking for the zmq::POLLIN flag on
@@ -191,11 +197,11 @@ impl HeartbeatMgr {
         }
     }
 
-    // Set internal state to `PulseState::Pause` and notify client OK
+    // Advertise the worker as busy and notify client OK
     fn pause(&mut self) {
         debug!("heartbeat paused");
         self.reg.set_state(proto::WorkerState::Busy);
-        self.state = PulseState::Pause;
+        self.reg.set_free_slots(0);
         self.cli_sock.send(&[], 0).unwrap();
     }
 
@@ -210,19 +216,40 @@ impl HeartbeatMgr {
     // Wait receive for a command from a client
     fn recv_cmd(&mut self) -> Result<()> {
         try!(self.cli_sock.recv(&mut self.msg, 0));
-        match self.msg.as_str() {
-            Some(CMD_PAUSE) => self.pause(),
-            Some(CMD_PULSE) => self.resume(),
+        let pulse = match self.msg.as_str() {
+            Some(CMD_PAUSE) => false,
+            Some(CMD_PULSE) => true,
             _ => unreachable!("wk:hb:1, received unexpected message from client"),
+        };
+        let free_slots = if pulse {
+            try!(self.cli_sock.recv(&mut self.msg, 0));
+            self.msg
+                .as_str()
+                .and_then(|free| free.parse().ok())
+                .unwrap_or(0)
+        } else {
+            0
+        };
+        try!(self.cli_sock.recv(&mut self.msg, 0));
+        let jobs_received = self.msg
+            .as_str()
+            .and_then(|received| received.parse().ok())
+            .unwrap_or(0);
+        // jobsrv drops heartbeats which don't account for every job it has sent
+        self.reg.set_jobs_received(jobs_received);
+        if pulse {
+            self.resume(free_slots);
+        } else {
+            self.pause();
         }
         Ok(())
     }
 
-    // Set internal state to `PulseState::Pulse` and notify client OK
-    fn resume(&mut self) {
-        debug!("heartbeat resumed");
+    // Advertise the worker as ready and notify client OK
+    fn resume(&mut self, free_slots: u32) {
+        debug!("heartbeat resumed, free_slots={}", free_slots);
         self.reg.set_state(proto::WorkerState::Ready);
-        self.state = PulseState::Pulse;
+        self.reg.set_free_slots(free_slots);
         self.cli_sock.send(&[], 0).unwrap();
     }
 }
@@ -12,20 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+extern crate builder_core as bldr_core;
 extern crate chrono;
 extern crate habitat_builder_protocol as protocol;
+extern crate habitat_common as common;
 extern crate habitat_core as hab_core;
 extern crate habitat_depot_client as depot_client;
 extern crate habitat_net as hab_net;
 extern crate git2;
+extern crate hab;
 #[macro_use]
 extern crate lazy_static;
+extern crate libc;
 #[macro_use]
 extern crate log;
 extern crate protobuf;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
+#[cfg(test)]
+extern crate tempdir;
 extern crate toml;
 extern crate zmq;
 extern crate habitat_builder_protocol;
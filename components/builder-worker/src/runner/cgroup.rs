@@ -0,0 +1,153 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Resource limits for the studio of a build, using the cpu, cpuacct and memory controllers of
+//! cgroup v1.
+
+use std::ffi::CString;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::os::unix::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+
+use libc;
+
+use config::CgroupCfg;
+use error::{Error, Result};
+
+/// Period of the CFS bandwidth control, in microseconds
+const CFS_PERIOD_US: u64 = 100_000;
+
+/// Resources used by the processes of a cgroup over its lifetime
+#[derive(Debug)]
+pub struct Usage {
+    pub cpu_ms: u64,
+    pub memory_bytes: u64,
+}
+
+pub struct Cgroup {
+    cpu: PathBuf,
+    cpuacct: PathBuf,
+    memory: PathBuf,
+}
+
+impl Cgroup {
+    /// Create the cgroup of the given job, applying the configured limits
+    pub fn create(cfg: &CgroupCfg, job_id: u64) -> Result<Self> {
+        let path = |controller: &str| {
+            cfg.root
+                .join(controller)
+                .join(&cfg.parent)
+                .join(job_id.to_string())
+        };
+        let cgroup = Cgroup {
+            cpu: path("cpu"),
+            cpuacct: path("cpuacct"),
+            memory: path("memory"),
+        };
+        // The cpu and cpuacct controllers are usually mounted together, in which case the
+        // second directory already exists
+        for dir in cgroup.dirs().iter() {
+            try!(fs::create_dir_all(dir).map_err(|e| cgroup_err(dir, e)));
+        }
+        if cfg.cpus > 0.0 {
+            let quota = (cfg.cpus * CFS_PERIOD_US as f64) as u64;
+            try!(write_value(&cgroup.cpu.join("cpu.cfs_period_us"), CFS_PERIOD_US));
+            try!(write_value(&cgroup.cpu.join("cpu.cfs_quota_us"), quota));
+        }
+        if cfg.memory_mb > 0 {
+            try!(write_value(&cgroup.memory.join("memory.limit_in_bytes"),
+                             cfg.memory_mb * 1024 * 1024));
+        }
+        Ok(cgroup)
+    }
+
+    /// Paths of the files a process writes to in order to join the cgroup, see `join`
+    pub fn procs_files(&self) -> Vec<CString> {
+        self.dirs()
+            .iter()
+            .map(|dir| CString::new(dir.join("cgroup.procs").as_os_str().as_bytes()).unwrap())
+            .collect()
+    }
+
+    /// Resources used by the cgroup's processes so far
+    pub fn usage(&self) -> Result<Usage> {
+        let cpu_ns = try!(read_value(&self.cpuacct.join("cpuacct.usage")));
+        let memory_bytes = try!(read_value(&self.memory.join("memory.max_usage_in_bytes")));
+        Ok(Usage {
+               cpu_ms: cpu_ns / 1_000_000,
+               memory_bytes: memory_bytes,
+           })
+    }
+
+    /// Remove the cgroup. Its processes must have exited beforehand.
+    pub fn destroy(self) -> Result<()> {
+        for dir in self.dirs().iter() {
+            match fs::remove_dir(dir) {
+                Ok(()) => (),
+                Err(ref e) if e.kind() == io::ErrorKind::NotFound => (),
+                Err(e) => return Err(cgroup_err(dir, e)),
+            }
+        }
+        Ok(())
+    }
+
+    fn dirs(&self) -> [&Path; 3] {
+        [&self.cpu, &self.cpuacct, &self.memory]
+    }
+}
+
+/// Move the calling process into the cgroup whose `cgroup.procs` files are given.
+///
+/// Meant to be called between fork and exec, so it only makes raw system calls.
+pub fn join(procs_files: &[CString]) -> io::Result<()> {
+    for file in procs_files {
+        unsafe {
+            let fd = libc::open(file.as_ptr(), libc::O_WRONLY);
+            if fd < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            // Writing 0 moves the writing process
+            let written = libc::write(fd, b"0".as_ptr() as *const libc::c_void, 1);
+            libc::close(fd);
+            if written < 0 {
+                return Err(io::Error::last_os_error());
+            }
+        }
+    }
+    Ok(())
+}
+
+fn read_value(path: &Path) -> Result<u64> {
+    let mut content = String::new();
+    try!(File::open(path)
+             .and_then(|mut f| f.read_to_string(&mut content))
+             .map_err(|e| cgroup_err(path, e)));
+    content
+        .trim()
+        .parse()
+        .map_err(|_| {
+                     cgroup_err(path,
+                                io::Error::new(io::ErrorKind::InvalidData, content.clone()))
+                 })
+}
+
+fn write_value(path: &Path, value: u64) -> Result<()> {
+    File::create(path)
+        .and_then(|mut f| f.write_all(value.to_string().as_bytes()))
+        .map_err(|e| cgroup_err(path, e))
+}
+
+fn cgroup_err(path: &Path, err: io::Error) -> Error {
+    Error::Cgroup(format!("{}", path.display()), err)
+}
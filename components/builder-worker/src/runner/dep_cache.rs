@@ -0,0 +1,519 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cache of the artifacts builds install their dependencies from, shared by every job of the
+//! worker. Each artifact is kept in a directory named after its checksum, along with its
+//! identifier and when it was last used. The cache is mounted read-only into each studio,
+//! which links the artifacts into its own artifact cache. Once a build is done, the artifacts
+//! it had to download are added and the least recently used ones are evicted for as long as
+//! the cache is larger than it may be. A build controls everything in its studio, including
+//! the keys it trusts, so an artifact is only added once the depot vouches for it: its first
+//! signature must be made with a key of the package's origin, every signature must verify
+//! against public keys fetched from the depot, and its checksum must match the one the depot
+//! recorded for the package. That way one build can't plant artifacts for the builds after it.
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bldr_core::metrics::{Counter, Gauge};
+use depot_client::{self, DisplayProgress};
+use hab_core::crypto::{artifact, keys, SigKeyPair};
+use hab_core::fs::{CACHE_ARTIFACT_PATH, PKG_PATH};
+use hab_core::package::PackageIdent;
+use hab_core::package::archive::PackageArchive;
+
+use error::{Error, Result};
+
+/// Environment variable naming the directory a new studio mounts as its dependency cache
+pub const STUDIO_DEP_CACHE_ENV: &'static str = "HAB_STUDIO_DEP_CACHE";
+/// File holding the identifier of a cached artifact
+const IDENT_FILE: &'static str = "IDENT";
+/// File holding the time a cached artifact was last used, in seconds since the epoch
+const LAST_USED_FILE: &'static str = "LAST_USED";
+/// Hidden directory of the cache holding the public keys fetched from the depot
+const KEYS_DIR: &'static str = ".keys";
+
+lazy_static! {
+    /// Number of running builds whose studio links each cached artifact, by checksum. These
+    /// are never evicted. Also keeps the builds of other slots from changing the cache at the
+    /// same time.
+    static ref IN_USE: Mutex<HashMap<String, usize>> = Mutex::new(HashMap::new());
+}
+
+pub struct DepCache {
+    root: PathBuf,
+    max_bytes: u64,
+}
+
+/// The depot's records an artifact is checked against before it's cached
+pub trait Depot {
+    /// Checksum the depot recorded for the package's artifact
+    fn checksum(&self, ident: &PackageIdent) -> Result<String>;
+    /// Downloads the public half of an origin key revision into `dst`
+    fn fetch_origin_key(&self, origin: &str, revision: &str, dst: &Path) -> Result<()>;
+}
+
+impl Depot for depot_client::Client {
+    fn checksum(&self, ident: &PackageIdent) -> Result<String> {
+        let package = try!(self.show_package(ident, None));
+        Ok(package.get_checksum().to_string())
+    }
+
+    fn fetch_origin_key(&self, origin: &str, revision: &str, dst: &Path) -> Result<()> {
+        try!(depot_client::Client::fetch_origin_key(self,
+                                                    origin,
+                                                    revision,
+                                                    dst,
+                                                    None::<NoProgress>));
+        Ok(())
+    }
+}
+
+/// Artifacts of the cache pinned for as long as a build may use them
+pub struct Lease {
+    checksums: Vec<String>,
+}
+
+impl Drop for Lease {
+    fn drop(&mut self) {
+        let mut in_use = IN_USE.lock().unwrap();
+        for checksum in self.checksums.iter() {
+            let unused = match in_use.get_mut(checksum) {
+                Some(count) => {
+                    *count -= 1;
+                    *count == 0
+                }
+                None => false,
+            };
+            if unused {
+                in_use.remove(checksum);
+            }
+        }
+    }
+}
+
+struct Entry {
+    checksum: String,
+    path: PathBuf,
+    bytes: u64,
+    last_used: u64,
+}
+
+impl DepCache {
+    pub fn new<T>(data_path: T, size_mb: u64) -> Self
+        where T: AsRef<Path>
+    {
+        DepCache {
+            root: data_path.as_ref().join("dep-cache"),
+            max_bytes: size_mb * 1024 * 1024,
+        }
+    }
+
+    /// Directory the cached artifacts are kept in
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Pin every artifact currently in the cache until the returned lease is dropped. Taken
+    /// before starting a studio, which links all of them.
+    pub fn lease(&self) -> Result<Lease> {
+        let mut in_use = IN_USE.lock().unwrap();
+        try!(fs::create_dir_all(&self.root));
+        let mut checksums = Vec::new();
+        for entry in try!(self.entries()) {
+            *in_use.entry(entry.checksum.clone()).or_insert(0) += 1;
+            checksums.push(entry.checksum);
+        }
+        Ok(Lease { checksums: checksums })
+    }
+
+    /// Count the cached artifacts the build in `studio` installed as hits and the artifacts it
+    /// had to download as misses, adding the latter to the cache once the depot vouches for
+    /// them. Then evict the least recently used artifacts until the cache fits in its size
+    /// again.
+    pub fn collect<D: Depot>(&self, studio: &Path, depot: &D) -> Result<()> {
+        let in_use = IN_USE.lock().unwrap();
+        let artifacts = studio.join(CACHE_ARTIFACT_PATH);
+        if artifacts.is_dir() {
+            for artifact in try!(fs::read_dir(&artifacts)) {
+                let path = try!(artifact).path();
+                if path.extension().map_or(true, |ext| ext != "hart") {
+                    continue;
+                }
+                let metadata = try!(fs::symlink_metadata(&path));
+                if metadata.file_type().is_symlink() {
+                    if let Some(entry) = self.linked_entry(&path) {
+                        if installed(studio, &entry) {
+                            Counter::DepCacheHit.increment();
+                            try!(touch(&entry));
+                        }
+                    }
+                } else if metadata.is_file() {
+                    let mut archive = PackageArchive::new(&path);
+                    if let Some(err) = self.verify(&mut archive, depot).err() {
+                        warn!("not caching unverified artifact ({}), ERR={}",
+                              path.display(),
+                              err);
+                        continue;
+                    }
+                    Counter::DepCacheMiss.increment();
+                    if let Some(err) = self.insert(&mut archive).err() {
+                        warn!("unable to cache artifact ({}), ERR={}", path.display(), err);
+                    }
+                }
+            }
+        }
+        self.evict(&in_use)
+    }
+
+    /// Checks a downloaded artifact against the depot: the first signature must be made with a
+    /// key of the package's origin, every signature must verify against the depot's public
+    /// keys, and the artifact must have the checksum the depot recorded for the package.
+    fn verify<D: Depot>(&self, archive: &mut PackageArchive, depot: &D) -> Result<()> {
+        let ident = try!(archive.ident());
+        let keys = self.root.join(KEYS_DIR);
+        try!(fs::create_dir_all(&keys));
+        let header = try!(artifact::get_artifact_header(&archive.path));
+        for (i, signature) in header.signatures.iter().enumerate() {
+            let (name, revision) = try!(keys::parse_name_with_rev(&signature.key_name));
+            if i == 0 && name != ident.origin {
+                return Err(Error::ArtifactVerify(format!("{} is signed with {} which is not a \
+                                                          key of the {} origin",
+                                                         ident,
+                                                         signature.key_name,
+                                                         ident.origin)));
+            }
+            // Keys are only ever fetched from the depot into the cache's own key directory,
+            // which no studio can write to
+            if SigKeyPair::get_public_key_path(&signature.key_name, &keys).is_err() {
+                try!(depot.fetch_origin_key(&name, &revision, &keys));
+            }
+        }
+        try!(artifact::verify(&archive.path, &keys));
+        let checksum = try!(archive.checksum());
+        let expected = try!(depot.checksum(&ident));
+        if checksum != expected {
+            return Err(Error::ArtifactVerify(format!("{} has checksum {} but the depot \
+                                                      recorded {}",
+                                                     ident,
+                                                     checksum,
+                                                     expected)));
+        }
+        Ok(())
+    }
+
+    /// The entry a studio's link to a cached artifact points to. The link's target is where
+    /// the studio mounts the cache, so only its last two components are of use here.
+    fn linked_entry(&self, link: &Path) -> Option<PathBuf> {
+        let target = match fs::read_link(link) {
+            Ok(target) => target,
+            Err(_) => return None,
+        };
+        let entry = target
+            .parent()
+            .and_then(|p| p.file_name())
+            .map(|checksum| self.root.join(checksum));
+        entry.and_then(|entry| if entry.is_dir() { Some(entry) } else { None })
+    }
+
+    fn insert(&self, archive: &mut PackageArchive) -> Result<()> {
+        let checksum = try!(archive.checksum());
+        let ident = try!(archive.ident());
+        let path = self.root.join(&checksum);
+        if path.exists() {
+            return Ok(());
+        }
+        // Studios only link artifacts of entries which aren't hidden, so build the entry up
+        // under a hidden name first
+        let partial = self.root.join(format!(".{}", checksum));
+        if partial.exists() {
+            try!(fs::remove_dir_all(&partial));
+        }
+        try!(fs::create_dir_all(&partial));
+        try!(fs::copy(&archive.path, partial.join(archive.file_name())));
+        let mut file = try!(File::create(partial.join(IDENT_FILE)));
+        try!(file.write_all(ident.to_string().as_bytes()));
+        try!(touch(&partial));
+        try!(fs::rename(&partial, &path));
+        debug!("cached artifact, ident={}, checksum={}", ident, checksum);
+        Ok(())
+    }
+
+    fn evict(&self, in_use: &HashMap<String, usize>) -> Result<()> {
+        let mut entries = try!(self.entries());
+        let mut bytes = entries.iter().map(|entry| entry.bytes).sum::<u64>();
+        entries.sort_by_key(|entry| entry.last_used);
+        for entry in entries {
+            if bytes <= self.max_bytes {
+                break;
+            }
+            if in_use.contains_key(&entry.checksum) {
+                continue;
+            }
+            debug!("evicting cached artifact, checksum={}", entry.checksum);
+            try!(fs::remove_dir_all(&entry.path));
+            bytes -= entry.bytes;
+            Counter::DepCacheEvict.increment();
+        }
+        Gauge::DepCacheSize.set(bytes as f64);
+        Ok(())
+    }
+
+    fn entries(&self) -> Result<Vec<Entry>> {
+        let mut entries = Vec::new();
+        if !self.root.exists() {
+            return Ok(entries);
+        }
+        for dir in try!(fs::read_dir(&self.root)) {
+            let dir = try!(dir);
+            let checksum = dir.file_name().to_string_lossy().into_owned();
+            if checksum.starts_with(".") {
+                continue;
+            }
+            let mut bytes = 0;
+            for file in try!(fs::read_dir(dir.path())) {
+                bytes += try!(try!(file).metadata()).len();
+            }
+            entries.push(Entry {
+                             checksum: checksum,
+                             last_used: last_used(&dir.path()),
+                             path: dir.path(),
+                             bytes: bytes,
+                         });
+        }
+        Ok(entries)
+    }
+}
+
+/// Whether the package of a cached artifact was installed into the studio
+fn installed(studio: &Path, entry: &Path) -> bool {
+    let mut ident = String::new();
+    match File::open(entry.join(IDENT_FILE)).and_then(|mut f| f.read_to_string(&mut ident)) {
+        Ok(_) => studio.join(PKG_PATH).join(ident.trim()).is_dir(),
+        Err(_) => false,
+    }
+}
+
+fn last_used(entry: &Path) -> u64 {
+    let mut secs = String::new();
+    match File::open(entry.join(LAST_USED_FILE)).and_then(|mut f| f.read_to_string(&mut secs)) {
+        Ok(_) => secs.trim().parse().unwrap_or(0),
+        Err(_) => 0,
+    }
+}
+
+/// Keys are fetched in the background of a build, so there's no progress to display.
+struct NoProgress;
+
+impl Write for NoProgress {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl DisplayProgress for NoProgress {
+    fn size(&mut self, _size: u64) {}
+    fn finish(&mut self) {}
+}
+
+fn touch(entry: &Path) -> Result<()> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let mut file = try!(File::create(entry.join(LAST_USED_FILE)));
+    try!(file.write_all(now.to_string().as_bytes()));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::fs::symlink;
+
+    use hab_core::fs::CACHE_KEY_PATH;
+    use tempdir::TempDir;
+
+    use super::*;
+
+    const ARTIFACT: &'static str = "happyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart";
+    const ORIGIN_KEY: &'static str = "happyhumans-20160424223347.pub";
+
+    fn fixture() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../core/tests/fixtures")
+            .join(ARTIFACT)
+    }
+
+    /// Stands in for the depot, which may have the public key the fixture is signed with and
+    /// records `checksum` for its package
+    struct FakeDepot {
+        checksum: String,
+        has_key: bool,
+    }
+
+    impl Depot for FakeDepot {
+        fn checksum(&self, _ident: &PackageIdent) -> Result<String> {
+            Ok(self.checksum.clone())
+        }
+
+        fn fetch_origin_key(&self, _origin: &str, _revision: &str, dst: &Path) -> Result<()> {
+            if !self.has_key {
+                return Err(Error::ArtifactVerify("key not found".to_string()));
+            }
+            try!(fs::copy(fixture().with_file_name(ORIGIN_KEY), dst.join(ORIGIN_KEY)));
+            Ok(())
+        }
+    }
+
+    fn depot() -> FakeDepot {
+        FakeDepot {
+            checksum: PackageArchive::new(fixture()).checksum().unwrap(),
+            has_key: true,
+        }
+    }
+
+    fn entry(cache: &DepCache, checksum: &str, kb: usize, last_used: u64) {
+        let path = cache.root().join(checksum);
+        fs::create_dir_all(&path).unwrap();
+        File::create(path.join(format!("{}.hart", checksum)))
+            .unwrap()
+            .write_all(&vec![0; kb * 1024])
+            .unwrap();
+        File::create(path.join(LAST_USED_FILE))
+            .unwrap()
+            .write_all(last_used.to_string().as_bytes())
+            .unwrap();
+    }
+
+    #[test]
+    fn collect_caches_downloaded_artifacts() {
+        let tmp = TempDir::new("dep-cache").unwrap();
+        let cache = DepCache::new(tmp.path(), 1);
+        let studio = tmp.path().join("studio");
+        let artifacts = studio.join(CACHE_ARTIFACT_PATH);
+        fs::create_dir_all(&artifacts).unwrap();
+        fs::copy(fixture(), artifacts.join(ARTIFACT)).unwrap();
+        cache.collect(&studio, &depot()).unwrap();
+
+        let entries = cache.entries().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert!(entries[0].path.join(ARTIFACT).is_file());
+        assert!(entries[0].last_used > 0);
+
+        // A later studio links the artifact instead and installs its package from it
+        let studio = tmp.path().join("studio2");
+        let artifacts = studio.join(CACHE_ARTIFACT_PATH);
+        fs::create_dir_all(&artifacts).unwrap();
+        fs::create_dir_all(studio.join(PKG_PATH).join("happyhumans/possums/8.1.4/20160427165340"))
+            .unwrap();
+        let target = Path::new("/hab/cache/dep-artifacts")
+            .join(&entries[0].checksum)
+            .join(ARTIFACT);
+        symlink(&target, artifacts.join(ARTIFACT)).unwrap();
+        assert_eq!(cache.linked_entry(&artifacts.join(ARTIFACT)),
+                   Some(entries[0].path.clone()));
+        assert!(installed(&studio, &entries[0].path));
+        cache.collect(&studio, &depot()).unwrap();
+        assert_eq!(cache.entries().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn collect_skips_unverified_artifacts() {
+        let tmp = TempDir::new("dep-cache").unwrap();
+        let cache = DepCache::new(tmp.path(), 1);
+        let studio = tmp.path().join("studio");
+        let artifacts = studio.join(CACHE_ARTIFACT_PATH);
+        fs::create_dir_all(&artifacts).unwrap();
+        fs::copy(fixture(), artifacts.join(ARTIFACT)).unwrap();
+        // Without the depot's copy of the origin's public key the artifact can't be verified,
+        // even though the studio trusts the key
+        let keys = studio.join(CACHE_KEY_PATH);
+        fs::create_dir_all(&keys).unwrap();
+        fs::copy(fixture().with_file_name(ORIGIN_KEY), keys.join(ORIGIN_KEY)).unwrap();
+        let mut without_key = depot();
+        without_key.has_key = false;
+        cache.collect(&studio, &without_key).unwrap();
+        assert!(cache.entries().unwrap().is_empty());
+
+        // Nor when the depot recorded another checksum for the package
+        let mut other_checksum = depot();
+        other_checksum.checksum = "not-the-checksum-of-the-artifact".to_string();
+        cache.collect(&studio, &other_checksum).unwrap();
+        assert!(cache.entries().unwrap().is_empty());
+
+        // Nor once its content no longer matches its signature
+        let mut tampered = fs::OpenOptions::new()
+            .append(true)
+            .open(artifacts.join(ARTIFACT))
+            .unwrap();
+        tampered.write_all(b"tampered").unwrap();
+        cache.collect(&studio, &depot()).unwrap();
+        assert!(cache.entries().unwrap().is_empty());
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let tmp = TempDir::new("dep-cache").unwrap();
+        let cache = DepCache::new(tmp.path(), 1);
+        entry(&cache, "older", 400, 100);
+        entry(&cache, "newest", 400, 300);
+        entry(&cache, "oldest", 400, 50);
+        cache.collect(&tmp.path().join("studio"), &depot()).unwrap();
+
+        let mut left: Vec<String> = cache
+            .entries()
+            .unwrap()
+            .into_iter()
+            .map(|e| e.checksum)
+            .collect();
+        left.sort();
+        assert_eq!(left, vec!["newest".to_string(), "older".to_string()]);
+    }
+
+    #[test]
+    fn leased_artifacts_are_not_evicted() {
+        let tmp = TempDir::new("dep-cache").unwrap();
+        let cache = DepCache::new(tmp.path(), 1);
+        entry(&cache, "leased", 600, 10);
+        let lease = cache.lease().unwrap();
+        entry(&cache, "unleased", 600, 20);
+        cache.collect(&tmp.path().join("studio"), &depot()).unwrap();
+        let left: Vec<String> = cache
+            .entries()
+            .unwrap()
+            .into_iter()
+            .map(|e| e.checksum)
+            .collect();
+        assert_eq!(left, vec!["leased".to_string()]);
+
+        drop(lease);
+        entry(&cache, "newer", 600, 30);
+        cache.collect(&tmp.path().join("studio"), &depot()).unwrap();
+        let left: Vec<String> = cache
+            .entries()
+            .unwrap()
+            .into_iter()
+            .map(|e| e.checksum)
+            .collect();
+        assert_eq!(left, vec!["newer".to_string()]);
+    }
+}
@@ -27,11 +27,16 @@ const EOL_MARKER: &'static str = "\n";
 const LOG_LINE: &'static str = "L";
 /// ZMQ protocol frame to indicate a log has finished
 const LOG_COMPLETE: &'static str = "C";
+/// Replaces the value of a build secret wherever it appears in the log
+const REDACTED: &'static str = "[REDACTED]";
 
 
 pub struct LogPipe {
     job_id: u64,
     sock: zmq::Socket,
+    line_num: u64,
+    complete: bool,
+    secrets: Vec<String>,
 }
 
 impl LogPipe {
@@ -44,6 +49,9 @@ impl LogPipe {
         LogPipe {
             job_id: workspace.job.get_id(),
             sock: sock,
+            line_num: 0,
+            complete: false,
+            secrets: Vec::new(),
         }
     }
 
@@ -51,58 +59,92 @@ impl LogPipe {
     /// aggregation and streaming to downstream clients.
     ///
     /// Contents of STDOUT are streamed before any from STDERR (if
-    /// any).
+    /// any). The log is left open so that further lines may be
+    /// appended with `log_line` before calling `complete`.
     pub fn pipe(&mut self, process: &mut process::Child) {
-        let mut line_count = 0;
-
         if let Some(ref mut stdout) = process.stdout {
             let reader = BufReader::new(stdout);
-            line_count = self.stream_lines(reader, line_count);
+            self.stream_lines(reader);
         }
         if let Some(ref mut stderr) = process.stderr {
             let reader = BufReader::new(stderr);
-            // not capturing line_count output because we don't use it
-            self.stream_lines(reader, line_count);
+            self.stream_lines(reader);
         }
+    }
+
+    /// Keep the given secret values out of every line sent from now on.
+    pub fn redact(&mut self, secrets: Vec<String>) {
+        self.secrets.extend(secrets.into_iter().filter(|s| !s.is_empty()));
+        // Longer values go first so that a secret containing another is redacted whole
+        self.secrets.sort_by(|a, b| b.len().cmp(&a.len()));
+    }
+
+    /// Append a single line to the job's log.
+    pub fn log_line(&mut self, line: &str) {
+        self.send_line(line.to_string());
+    }
 
-        // Signal that the log is finished
+    /// Signal that the log is finished. Only the first call has any effect.
+    pub fn complete(&mut self) {
+        if self.complete {
+            return;
+        }
         let mut complete = JobLogComplete::new();
         complete.set_job_id(self.job_id);
         self.sock.send_str(LOG_COMPLETE, zmq::SNDMORE).unwrap();
         self.sock
             .send(complete.write_to_bytes().unwrap().as_slice(), 0)
             .unwrap();
+        self.complete = true;
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.complete
     }
 
     /// Send the lines of the reader out over the ZMQ socket as
-    /// `JobLogChunk` messages.
-    ///
-    /// `line_num` is the line number to start with when generating
-    /// JobLogChunk messages. This allows us to send multiple output
-    /// to the same job (i.e. standard output and standard error);
-    /// send the first set using `line_num` = 0, send the second using
-    /// whatever value the first invocation of `stream_lines`
-    /// returned, etc.
-    ///
-    /// (I wrestled with the type system for an alternative
-    /// implementation, but it defeated me :( This seems passable in
-    /// the meantime.)
-    fn stream_lines<B: BufRead>(&mut self, reader: B, mut line_num: u64) -> u64 {
+    /// `JobLogChunk` messages, numbered after any lines already sent
+    /// for this job.
+    fn stream_lines<B: BufRead>(&mut self, reader: B) {
         for line in reader.lines() {
-            line_num = line_num + 1;
-            let mut l: String = line.unwrap();
-            l = l + EOL_MARKER;
-
-            let mut chunk = JobLogChunk::new();
-            chunk.set_job_id(self.job_id);
-            chunk.set_seq(line_num);
-            chunk.set_content(l.clone());
-
-            self.sock.send_str(LOG_LINE, zmq::SNDMORE).unwrap();
-            self.sock
-                .send(chunk.write_to_bytes().unwrap().as_slice(), 0)
-                .unwrap();
+            self.send_line(line.unwrap());
+        }
+    }
+
+    fn send_line(&mut self, line: String) {
+        let line = redact_line(&self.secrets, line);
+        self.line_num = self.line_num + 1;
+        let mut chunk = JobLogChunk::new();
+        chunk.set_job_id(self.job_id);
+        chunk.set_seq(self.line_num);
+        chunk.set_content(line + EOL_MARKER);
+
+        self.sock.send_str(LOG_LINE, zmq::SNDMORE).unwrap();
+        self.sock
+            .send(chunk.write_to_bytes().unwrap().as_slice(), 0)
+            .unwrap();
+    }
+}
+
+fn redact_line(secrets: &[String], mut line: String) -> String {
+    for secret in secrets.iter() {
+        if line.contains(secret.as_str()) {
+            line = line.replace(secret.as_str(), REDACTED);
         }
-        line_num
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::redact_line;
+
+    #[test]
+    fn redact_line_replaces_secrets() {
+        let secrets = vec!["hunter22".to_string(), "hunter2".to_string()];
+        assert_eq!(redact_line(&secrets, "token=hunter22 pass=hunter2".to_string()),
+                   "token=[REDACTED] pass=[REDACTED]");
+        assert_eq!(redact_line(&secrets, "nothing to see".to_string()),
+                   "nothing to see");
     }
 }
@@ -12,18 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod cgroup;
+pub mod dep_cache;
 pub mod log_pipe;
 pub mod workspace;
 pub mod postprocessor;
+pub mod shelf;
 
 use std::ffi::OsString;
 use std::fs;
+use std::io::Write;
 use std::ops::{Deref, DerefMut};
+use std::os::unix::fs::OpenOptionsExt;
+use std::os::unix::process::CommandExt;
 use std::path::Path;
 use std::process::{Command, Stdio};
+use std::result;
 use std::str::FromStr;
-use std::sync::{mpsc, Arc, RwLock};
+use std::sync::{mpsc, Arc, Mutex, RwLock};
 use std::thread::{self, JoinHandle};
+use std::time::Duration;
 
 pub use protocol::jobsrv::JobState;
 use chrono::UTC;
@@ -32,16 +40,21 @@ use hab_core::{crypto, env};
 use hab_core::package::archive::PackageArchive;
 use hab_core::package::install::PackageInstall;
 use hab_core::package::PackageIdent;
+use hab_core::os::process::{self, Signal};
 use hab_net::server::ZMQ_CONTEXT;
 use protobuf::{parse_from_bytes, Message};
 use protocol::jobsrv as proto;
-use protocol::originsrv::OriginPackageIdent;
+use protocol::originsrv::{OriginPackageIdent, VcsAuth};
 use protocol::net::{self, ErrCode};
+use libc;
 use zmq;
 
 use {PRODUCT, VERSION};
+use self::cgroup::Cgroup;
+use self::dep_cache::DepCache;
 use self::log_pipe::LogPipe;
 use self::postprocessor::PostProcessor;
+use self::shelf::Shelf;
 use self::workspace::Workspace;
 use config::Config;
 use error::{Error, Result};
@@ -49,12 +62,21 @@ use vcs;
 
 /// Environment variable to enable or disable debug output in runner's studio
 const RUNNER_DEBUG_ENV: &'static str = "BUILDER_RUNNER_DEBUG";
-/// In-memory zmq address of Job RunnerMgr
+/// Prefix of the variables build secrets are handed to the studio in. The studio strips it,
+/// exposing each secret to the build under its own name.
+const STUDIO_SECRET_PREFIX: &'static str = "HAB_STUDIO_SECRET_";
+/// In-memory zmq address of Job RunnerMgr, suffixed with the build slot it runs jobs for
 const INPROC_ADDR: &'static str = "inproc://runner";
+/// In-memory zmq address Job Runners report the progress of their job to
+const STATUS_ADDR: &'static str = "inproc://runner-status";
 /// Protocol message to indicate the Job Runner has received a work request
 const WORK_ACK: &'static str = "A";
 /// Protocol message to indicate the Job Runner has completed a work request
 const WORK_COMPLETE: &'static str = "C";
+/// Protocol message to ask the Job Runner to build a job
+const WORK_BUILD: &'static str = "B";
+/// Protocol message to ask the Job Runner to publish the shelved artifact of a job
+const WORK_PUBLISH: &'static str = "P";
 
 lazy_static! {
     // JW TODO: expose public API functions in the core crate to check if the Rust process which
@@ -74,13 +96,12 @@ impl Job {
     }
 
     pub fn vcs(&self) -> vcs::VCS {
-        match self.0.get_project().get_vcs_type() {
-            "git" => {
-                vcs::VCS::new(String::from(self.0.get_project().get_vcs_type()),
-                              String::from(self.0.get_project().get_vcs_data()))
-            }
-            _ => panic!("unknown vcs associated with jobs project"),
-        }
+        let project = self.0.get_project();
+        let mut vcs = vcs::VCS::new(String::from(project.get_vcs_type()),
+                                    String::from(project.get_vcs_data()));
+        vcs.reference = String::from(project.get_vcs_ref());
+        vcs.submodules = project.get_vcs_submodules();
+        vcs
     }
 
     pub fn origin(&self) -> &str {
@@ -110,22 +131,106 @@ impl DerefMut for Job {
     }
 }
 
+/// Shared handle used to cancel the job being run, or to stop it once its build runs out of
+/// time, from outside of the runner's thread, which spends most of its time blocked on the
+/// studio.
+#[derive(Clone, Default)]
+pub struct Cancellation(Arc<Mutex<CancelState>>);
+
+#[derive(Default)]
+struct CancelState {
+    job_id: Option<u64>,
+    cancelled: bool,
+    timed_out: bool,
+    /// Pid of the running studio, which is also the id of its process group
+    studio_pid: Option<u32>,
+}
+
+impl Cancellation {
+    /// Cancel the given job if it is the one being run, killing its studio if one is running.
+    /// Returns `false` if the job is not being run by this worker.
+    pub fn cancel(&self, job_id: u64) -> bool {
+        let mut state = self.0.lock().unwrap();
+        if state.job_id != Some(job_id) {
+            return false;
+        }
+        state.cancelled = true;
+        if let Some(pid) = state.studio_pid {
+            kill_process_group(pid);
+        }
+        true
+    }
+
+    /// Stop the job because its build ran out of time, killing its studio. A studio which
+    /// already exited isn't timed out.
+    fn time_out(&self) {
+        let mut state = self.0.lock().unwrap();
+        if let Some(pid) = state.studio_pid {
+            state.timed_out = true;
+            kill_process_group(pid);
+        }
+    }
+
+    fn begin(&self, job_id: u64) {
+        let mut state = self.0.lock().unwrap();
+        *state = CancelState::default();
+        state.job_id = Some(job_id);
+    }
+
+    fn finish(&self) {
+        *self.0.lock().unwrap() = CancelState::default();
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.0.lock().unwrap().cancelled
+    }
+
+    fn is_timed_out(&self) -> bool {
+        self.0.lock().unwrap().timed_out
+    }
+
+    /// Record the running studio, killing it straight away if the job was cancelled while it
+    /// was being spawned.
+    fn studio_started(&self, pid: u32) {
+        let mut state = self.0.lock().unwrap();
+        if state.cancelled {
+            kill_process_group(pid);
+        } else {
+            state.studio_pid = Some(pid);
+        }
+    }
+
+    fn studio_exited(&self) {
+        self.0.lock().unwrap().studio_pid = None;
+    }
+}
+
 pub struct Runner {
+    cancellation: Cancellation,
     config: Config,
     depot_cli: depot_client::Client,
     log_pipe: Option<LogPipe>,
+    /// Decrypted build secrets of the job's origin and project, as name and value
+    secrets: Vec<(String, String)>,
+    status_sock: zmq::Socket,
     workspace: Workspace,
 }
 
 impl Runner {
-    pub fn new(job: Job, config: Config) -> Self {
+    pub fn new(job: Job, config: Config, cancellation: Cancellation) -> Self {
         let depot_cli = depot_client::Client::new(&config.depot_url, PRODUCT, VERSION, None)
             .unwrap();
+        let status_sock = (**ZMQ_CONTEXT).as_mut().socket(zmq::PUSH).unwrap();
+        status_sock.set_immediate(true).unwrap();
+        status_sock.connect(STATUS_ADDR).unwrap();
         Runner {
+            cancellation: cancellation,
             workspace: Workspace::new(config.data_path.clone(), job),
             config: config,
             depot_cli: depot_cli,
             log_pipe: None,
+            secrets: Vec::new(),
+            status_sock: status_sock,
         }
     }
 
@@ -146,7 +251,12 @@ impl Runner {
             error!("WORKSPACE SETUP ERR={:?}", err);
             return self.fail(net::err(ErrCode::WORKSPACE_SETUP, "wk:run:1"));
         }
+        if self.cancellation.is_cancelled() {
+            return self.cancel();
+        }
 
+        self.workspace.job.set_state(JobState::Processing);
+        self.start_phase(proto::JobPhase::FetchKey);
         if self.config.auth_token.is_empty() {
             warn!("WARNING: No auth token specified, will likely fail fetching secret key");
         };
@@ -174,21 +284,33 @@ impl Runner {
                 return self.fail(net::err(ErrCode::SECRET_KEY_FETCH, "wk:run:3"));
             }
         }
-        if let Some(err) = self.job().vcs().clone(&self.workspace.src()).err() {
-            error!("Unable to clone remote source repository, err={}", err);
-            return self.fail(net::err(ErrCode::VCS_CLONE, "wk:run:4"));
+        if let Some(err) = self.load_secrets().err() {
+            error!("Unable to load build secrets, err={}", err);
+            return self.fail(net::err(ErrCode::SECRET_KEY_FETCH, "wk:run:8"));
+        }
+
+        self.start_phase(proto::JobPhase::Clone);
+        match self.clone_src() {
+            Ok(()) => (),
+            Err(err @ Error::UnknownVCS(_)) |
+            Err(err @ Error::UnknownVCSUrl(_)) |
+            Err(err @ Error::LocalVCSDisabled) => {
+                error!("Unable to clone source repository, err={}", err);
+                return self.fail(net::err(ErrCode::VCS_CLONE, "wk:run:9"));
+            }
+            Err(err) => {
+                error!("Unable to clone remote source repository, err={}", err);
+                return self.fail(net::err(ErrCode::VCS_CLONE, "wk:run:4"));
+            }
+        }
+        if self.cancellation.is_cancelled() {
+            return self.cancel();
         }
 
         self.workspace
             .job
             .set_build_started_at(UTC::now().to_rfc3339());
-
-        // TODO: We don't actually update the state of the job to
-        // "Processing" (that should happen here), so an outside
-        // observer will see a job up going from "Dispatched" directly
-        // to "Complete" (or "Failed", etc.). As a result, we won't
-        // get the `build_started_at` time set until the job is actually
-        // finished.
+        self.start_phase(proto::JobPhase::Build);
         let mut archive = match self.build() {
             Ok(archive) => {
                 self.workspace
@@ -200,8 +322,16 @@ impl Runner {
                 self.workspace
                     .job
                     .set_build_finished_at(UTC::now().to_rfc3339());
+                if self.cancellation.is_cancelled() {
+                    return self.cancel();
+                }
                 error!("Unable to build in studio, err={}", err);
-                return self.fail(net::err(ErrCode::BUILD, "wk:run:5"));
+                match err {
+                    Error::BuildTimeout(_) => {
+                        return self.fail(net::err(ErrCode::BUILD_TIMEOUT, "wk:run:7"))
+                    }
+                    _ => return self.fail(net::err(ErrCode::BUILD, "wk:run:5")),
+                }
             }
         };
 
@@ -219,10 +349,34 @@ impl Runner {
         let ident = OriginPackageIdent::from(archive.ident().unwrap());
         self.workspace.job.set_package_ident(ident);
 
-        let mut post_processor = PostProcessor::new(&self.workspace);
-        if !post_processor.run(&mut archive, &self.config) {
-            // JW TODO: We should shelve the built artifacts and allow a retry on post-processing.
-            // If the job is killed then we can kill the shelved artifacts.
+        // Nothing has been published yet, so a cancellation can still be honoured
+        if self.cancellation.is_cancelled() {
+            return self.cancel();
+        }
+
+        self.start_phase(proto::JobPhase::PostProcess);
+        let pipeline = match PostProcessor::new(&self.workspace).load(&self.config) {
+            Ok(pipeline) => pipeline,
+            Err(err) => {
+                warn!("Failed to parse builder config, {}", err);
+                return self.fail(net::err(ErrCode::POST_PROCESSOR, "wk:run:6"));
+            }
+        };
+
+        let scratch = self.workspace.root().to_path_buf();
+        let studio = self.workspace.studio().to_path_buf();
+        if !pipeline.run(&mut archive,
+                         &self.config,
+                         &scratch,
+                         &studio,
+                         self.workspace.job.mut_post_process()) {
+            // Keep the artifact so that publishing it can be retried without rebuilding
+            match Shelf::new(&self.config.data_path).put(self.job().get_id(),
+                                                         self.workspace.out(),
+                                                         &pipeline) {
+                Ok(()) => self.workspace.job.set_shelved(true),
+                Err(err) => error!("Unable to shelve built artifact, err={}", err),
+            }
             return self.fail(net::err(ErrCode::POST_PROCESSOR, "wk:run:6"));
         }
 
@@ -234,6 +388,40 @@ impl Runner {
         self.complete()
     }
 
+    /// Run the post-processing steps of a job which previously failed in post-processing
+    /// against its shelved artifact, without rebuilding it. Steps which already succeeded are
+    /// skipped, and the artifact stays on the shelf if a step fails again.
+    pub fn publish(mut self) -> Job {
+        let shelf = Shelf::new(&self.config.data_path);
+        let job_id = self.job().get_id();
+        self.workspace.job.set_state(JobState::Processing);
+        self.start_phase(proto::JobPhase::Publish);
+        let (mut archive, pipeline) = match shelf.get(job_id) {
+            Ok(shelved) => shelved,
+            Err(err) => {
+                error!("Unable to load shelved artifact, err={}", err);
+                self.workspace.job.set_shelved(false);
+                return self.finish_publish(Err(net::err(ErrCode::POST_PROCESSOR, "wk:run:10")));
+            }
+        };
+        let scratch = self.workspace.root().to_path_buf();
+        let studio = self.workspace.studio().to_path_buf();
+        if !pipeline.run(&mut archive,
+                         &self.config,
+                         &scratch,
+                         &studio,
+                         self.workspace.job.mut_post_process()) {
+            return self.finish_publish(Err(net::err(ErrCode::POST_PROCESSOR, "wk:run:6")));
+        }
+        if let Some(err) = shelf.discard(job_id).err() {
+            warn!("unable to remove shelved artifact ({}), ERR={:?}",
+                  shelf.path(job_id).display(),
+                  err)
+        }
+        self.workspace.job.set_shelved(false);
+        self.finish_publish(Ok(()))
+    }
+
     fn build(&mut self) -> Result<PackageArchive> {
         let args = vec![OsString::from("-s"), // source path
                         OsString::from(self.workspace.src()),
@@ -247,33 +435,126 @@ impl Runner {
                                            .unwrap())];
         let command = studio_cmd();
         debug!("building, cmd={:?}, args={:?}", command, args);
-        let mut child = match env::var(RUNNER_DEBUG_ENV) {
+        let mut cmd = Command::new(command);
+        cmd.args(&args).env_clear();
+        // A secret the studio can't pass on whole fails the job, rather than the build running
+        // without it
+        let invalid = self.secrets
+            .iter()
+            .find(|&&(_, ref value)| value.contains(char::is_whitespace))
+            .map(|&(ref name, _)| name.clone());
+        if let Some(name) = invalid {
+            let err = Error::BuildSecret(name);
+            self.log_pipe().log_line(&err.to_string());
+            return Err(err);
+        }
+        for &(ref name, ref value) in self.secrets.iter() {
+            cmd.env(format!("{}{}", STUDIO_SECRET_PREFIX, name), value);
+        }
+        cmd.env("HAB_NONINTERACTIVE", "true");
+        match env::var(RUNNER_DEBUG_ENV) {
             Ok(val) => {
-                Command::new(command)
-                    .args(&args)
-                    .env_clear()
-                    .env("HAB_NONINTERACTIVE", "true")
-                    .env("DEBUG", val)
-                    .stdout(Stdio::piped())
-                    .stderr(Stdio::piped())
-                    .spawn()
-                    .expect("failed to spawn child")
+                cmd.env("DEBUG", val);
             }
             Err(_) => {
-                Command::new(command)
-                    .args(&args)
-                    .env_clear()
-                    .env("HAB_NONINTERACTIVE", "true")
-                    .env("TERM", "xterm-256color") // Gives us ANSI color codes
-                    .stdout(Stdio::piped())
-                    .stderr(Stdio::piped())
-                    .spawn()
-                    .expect("failed to spawn child")
+                cmd.env("TERM", "xterm-256color"); // Gives us ANSI color codes
             }
+        }
+        let cgroup = if self.config.cgroup.enabled {
+            Some(try!(Cgroup::create(&self.config.cgroup, self.job().get_id())))
+        } else {
+            None
+        };
+        // Let the studio install dependencies from the worker's cache, which holds on to the
+        // artifacts it links until the build is done
+        let dep_cache = match self.config.dep_cache_mb {
+            0 => None,
+            size_mb => Some(DepCache::new(&self.config.data_path, size_mb)),
         };
+        let lease = match dep_cache {
+            Some(ref cache) => {
+                match cache.lease() {
+                    Ok(lease) => {
+                        cmd.env(dep_cache::STUDIO_DEP_CACHE_ENV, cache.root());
+                        Some(lease)
+                    }
+                    Err(err) => {
+                        warn!("unable to use dependency cache, err={}", err);
+                        None
+                    }
+                }
+            }
+            None => None,
+        };
+        let procs_files = cgroup.as_ref().map_or(vec![], |c| c.procs_files());
+        // Lead a new process group so that stopping the job kills everything the studio ran, and
+        // join the job's cgroup so that its limits apply to all of it
+        cmd.before_exec(move || {
+                            unsafe {
+                                libc::setpgid(0, 0);
+                            }
+                            cgroup::join(&procs_files)
+                        });
+        let spawned = cmd.stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn();
+        let mut child = match spawned {
+            Ok(child) => child,
+            Err(err) => {
+                if let Some(cgroup) = cgroup {
+                    if let Err(err) = cgroup.destroy() {
+                        warn!("unable to remove build cgroup, err={}", err);
+                    }
+                }
+                return Err(Error::IO(err));
+            }
+        };
+        self.cancellation.studio_started(child.id());
+        let timeout = self.build_timeout();
+        let watchdog = timeout.map(|secs| {
+                                       start_watchdog(self.cancellation.clone(),
+                                                      Duration::from_secs(secs))
+                                   });
         self.log_pipe().pipe(&mut child);
         let exit_status = child.wait().expect("failed to wait on child");
+        drop(watchdog);
+        self.cancellation.studio_exited();
         debug!("build complete, status={:?}", exit_status);
+        if let (Some(cache), Some(lease)) = (dep_cache, lease) {
+            if let Some(err) = cache.collect(self.workspace.studio(), &self.depot_cli).err() {
+                warn!("unable to update dependency cache, err={}", err);
+            }
+            drop(lease);
+        }
+        if let Some(cgroup) = cgroup {
+            match cgroup.usage() {
+                Ok(usage) => {
+                    debug!("build resource usage, {:?}", usage);
+                    self.workspace.job.set_cpu_usage_ms(usage.cpu_ms);
+                    self.workspace
+                        .job
+                        .set_memory_usage_bytes(usage.memory_bytes);
+                }
+                Err(err) => warn!("unable to read build resource usage, err={}", err),
+            }
+            if let Err(err) = cgroup.destroy() {
+                warn!("unable to remove build cgroup, err={}", err);
+            }
+        }
+        if self.cancellation.is_cancelled() {
+            return Err(Error::BuildCancelled);
+        }
+        // The watchdog may fire between the studio exiting and being stopped, a studio which
+        // exited successfully finished in time
+        if let Some(secs) = timeout {
+            if self.cancellation.is_timed_out() && !exit_status.success() {
+                self.log_pipe()
+                    .log_line(&format!("Build timed out after {} seconds", secs));
+                self.log_pipe().complete();
+                return Err(Error::BuildTimeout(secs));
+            }
+        }
+        self.log_pipe().complete();
         if exit_status.success() {
             try!(fs::rename(self.workspace.src().join("results"), self.workspace.out()));
             self.workspace.last_built()
@@ -282,19 +563,166 @@ impl Runner {
         }
     }
 
+    /// Fetches the build secrets of the job's origin and project and decrypts them with the
+    /// origin's encryption key. A project secret replaces an origin secret of the same name.
+    fn load_secrets(&mut self) -> Result<()> {
+        let secrets = try!(self.depot_cli
+                               .fetch_origin_secrets(self.job().origin(),
+                                                     self.job().get_project().get_package_name(),
+                                                     &self.config.auth_token));
+        if secrets.is_empty() {
+            return Ok(());
+        }
+        let key = try!(self.depot_cli
+                           .fetch_origin_encryption_key(self.job().origin(),
+                                                        &self.config.auth_token));
+        let cache = self.workspace.keys().to_path_buf();
+        let result = self.decrypt_secrets(secrets, &key, &cache);
+        // The key is only needed while decrypting, don't leave it around for the build to find
+        if let Some(err) = fs::remove_dir_all(&cache).err() {
+            warn!("unable to remove keys directory ({}), ERR={:?}",
+                  cache.display(),
+                  err)
+        }
+        let values = try!(result);
+        self.log_pipe().redact(values);
+        Ok(())
+    }
+
+    fn decrypt_secrets(&mut self,
+                       secrets: Vec<depot_client::OriginSecret>,
+                       key: &depot_client::OriginEncryptionKey,
+                       cache: &Path)
+                       -> Result<Vec<String>> {
+        for body in vec![&key.public_body, &key.secret_body] {
+            try!(crypto::BoxKeyPair::write_file_from_str(&String::from_utf8_lossy(body), cache));
+        }
+        let mut values = Vec::new();
+        for secret in secrets {
+            let value = try!(crypto::BoxKeyPair::decrypt(secret.value.as_bytes(), cache));
+            let value = String::from_utf8_lossy(&value).into_owned();
+            self.secrets.retain(|&(ref name, _)| *name != secret.name);
+            self.secrets.push((secret.name, value.clone()));
+            values.push(value);
+        }
+        Ok(values)
+    }
+
+    /// Clones the project's source into the workspace, with the credentials named by the
+    /// project. The secret holding them is kept out of the build's environment.
+    fn clone_src(&mut self) -> Result<()> {
+        let mut vcs = self.job().vcs();
+        vcs.allow_local = self.config.allow_local_vcs;
+        let auth_secret = self.job().get_project().get_vcs_auth_secret().to_string();
+        let deploy_key = self.workspace.keys().join("deploy_key");
+        let auth = self.job().get_project().get_vcs_auth();
+        match auth {
+            VcsAuth::Anonymous => (),
+            VcsAuth::Token => {
+                let token = try!(self.take_secret(&auth_secret));
+                vcs.credentials = Some(vcs::Credentials::Token(token));
+            }
+            VcsAuth::DeployKey => {
+                let key = try!(self.take_secret(&auth_secret));
+                try!(write_deploy_key(&deploy_key, &key));
+                vcs.credentials = Some(vcs::Credentials::DeployKey(deploy_key.clone()));
+            }
+        }
+        let result = vcs.clone(self.workspace.src());
+        if deploy_key.exists() {
+            if let Some(err) = fs::remove_file(&deploy_key).err() {
+                warn!("unable to remove deploy key ({}), ERR={:?}",
+                      deploy_key.display(),
+                      err)
+            }
+        }
+        result
+    }
+
+    /// Removes a build secret, which stays redacted from the build's output, and returns its
+    /// value.
+    fn take_secret(&mut self, name: &str) -> Result<String> {
+        match self.secrets.iter().position(|&(ref n, _)| n == name) {
+            Some(idx) => Ok(self.secrets.remove(idx).1),
+            None => Err(Error::VCSAuthSecret(name.to_string())),
+        }
+    }
+
+    /// Maximum duration of the build in seconds, as set on the project or else by the worker
+    fn build_timeout(&self) -> Option<u64> {
+        match self.job().get_project().get_build_timeout() as u64 {
+            0 if self.config.build_timeout == 0 => None,
+            0 => Some(self.config.build_timeout),
+            secs => Some(secs),
+        }
+    }
+
+    /// Enter the next phase of the job, reporting the job's progress to the job server
+    fn start_phase(&mut self, phase: proto::JobPhase) {
+        let now = UTC::now().to_rfc3339();
+        self.finish_phase(&now);
+        let mut phase_time = proto::JobPhaseTime::new();
+        phase_time.set_phase(phase);
+        phase_time.set_started_at(now);
+        self.workspace.job.mut_phases().push(phase_time);
+        let status = self.workspace.job.write_to_bytes().unwrap();
+        if let Err(err) = self.status_sock.send(&status, 0) {
+            warn!("unable to report job status, err={}", err);
+        }
+    }
+
+    fn finish_phase(&mut self, now: &str) {
+        if let Some(phase) = self.workspace.job.mut_phases().last_mut() {
+            if !phase.has_finished_at() {
+                phase.set_finished_at(now.to_string());
+            }
+        }
+    }
+
     fn complete(mut self) -> Job {
+        self.finish_phase(&UTC::now().to_rfc3339());
         self.teardown().err().map(|e| error!("{}", e));
         self.workspace.job.set_state(JobState::Complete);
         self.workspace.job
     }
 
+    fn cancel(mut self) -> Job {
+        self.finish_phase(&UTC::now().to_rfc3339());
+        if let Some(ref mut log_pipe) = self.log_pipe {
+            if !log_pipe.is_complete() {
+                log_pipe.log_line("Build cancelled");
+                log_pipe.complete();
+            }
+        }
+        self.teardown().err().map(|e| error!("{}", e));
+        self.workspace.job.set_state(JobState::Cancelled);
+        self.workspace.job
+    }
+
     fn fail(mut self, err: net::NetError) -> Job {
+        self.finish_phase(&UTC::now().to_rfc3339());
         self.teardown().err().map(|e| error!("{}", e));
         self.workspace.job.set_state(JobState::Failed);
         self.workspace.job.set_error(err);
         self.workspace.job
     }
 
+    /// Like `complete` and `fail`, but for a publish retry, which has no studio to tear down
+    fn finish_publish(mut self, result: result::Result<(), net::NetError>) -> Job {
+        self.finish_phase(&UTC::now().to_rfc3339());
+        match result {
+            Ok(()) => {
+                self.workspace.job.clear_error();
+                self.workspace.job.set_state(JobState::Complete);
+            }
+            Err(err) => {
+                self.workspace.job.set_state(JobState::Failed);
+                self.workspace.job.set_error(err);
+            }
+        }
+        self.workspace.job
+    }
+
     fn setup(&mut self) -> Result<()> {
         if let Some(err) = fs::remove_dir_all(self.workspace.src()).err() {
             error!("unable to remove out directory ({}), ERR={:?}",
@@ -350,17 +778,19 @@ impl Runner {
     }
 }
 
-/// Client for sending and receiving messages to and from the Job Runner
+/// Client for sending and receiving messages to and from the Job Runner of a build slot
 pub struct RunnerCli {
+    slot: u32,
     sock: zmq::Socket,
     msg: zmq::Message,
 }
 
 impl RunnerCli {
     /// Create a new Job Runner client
-    pub fn new() -> Self {
+    pub fn new(slot: u32) -> Self {
         let sock = (**ZMQ_CONTEXT).as_mut().socket(zmq::DEALER).unwrap();
         RunnerCli {
+            slot: slot,
             sock: sock,
             msg: zmq::Message::new().unwrap(),
         }
@@ -373,7 +803,7 @@ impl RunnerCli {
 
     /// Connect to the Job Runner
     pub fn connect(&mut self) -> Result<()> {
-        try!(self.sock.connect(INPROC_ADDR));
+        try!(self.sock.connect(&runner_addr(self.slot)));
         Ok(())
     }
 
@@ -399,29 +829,79 @@ impl RunnerCli {
         Ok(&self.msg)
     }
 
-    /// Send a message to the Job Runner
+    /// Send a job to the Job Runner to build
     pub fn send(&mut self, msg: &zmq::Message) -> Result<()> {
+        try!(self.sock.send_str(WORK_BUILD, zmq::SNDMORE));
+        try!(self.sock.send(&*msg, 0));
+        Ok(())
+    }
+
+    /// Send a job to the Job Runner to publish its shelved artifact
+    pub fn send_publish(&mut self, msg: &zmq::Message) -> Result<()> {
+        try!(self.sock.send_str(WORK_PUBLISH, zmq::SNDMORE));
         try!(self.sock.send(&*msg, 0));
         Ok(())
     }
 }
 
+/// Client receiving the progress updates of the jobs being run in every build slot
+pub struct RunnerStatusCli {
+    sock: zmq::Socket,
+    msg: zmq::Message,
+}
+
+impl RunnerStatusCli {
+    pub fn new() -> Self {
+        let sock = (**ZMQ_CONTEXT).as_mut().socket(zmq::PULL).unwrap();
+        RunnerStatusCli {
+            sock: sock,
+            msg: zmq::Message::new().unwrap(),
+        }
+    }
+
+    /// Return a poll item used in `zmq::poll` for awaiting job status updates
+    pub fn as_poll_item<'a>(&'a self, events: i16) -> zmq::PollItem<'a> {
+        self.sock.as_poll_item(events)
+    }
+
+    /// Start listening for the progress of jobs. Must be called before any Job Runner starts.
+    pub fn bind(&mut self) -> Result<()> {
+        try!(self.sock.bind(STATUS_ADDR));
+        Ok(())
+    }
+
+    /// Receive the next job status update sent by a Job Runner while running a job and
+    /// return an encoded representation of the job, or `None` if there are no updates waiting.
+    pub fn recv_status(&mut self) -> Result<Option<&zmq::Message>> {
+        match self.sock.recv(&mut self.msg, zmq::DONTWAIT) {
+            Ok(()) => Ok(Some(&self.msg)),
+            Err(zmq::Error::EAGAIN) => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
 /// Receives work notifications from a `RunnerCli` and performs long-running tasks in a
-/// separate thread.
+/// separate thread, one job at a time. Every build slot of the worker has its own.
 pub struct RunnerMgr {
+    slot: u32,
     sock: zmq::Socket,
     msg: zmq::Message,
     config: Arc<RwLock<Config>>,
+    cancellation: Cancellation,
 }
 
 impl RunnerMgr {
     /// Start the Job Runner
-    pub fn start(config: Arc<RwLock<Config>>) -> Result<JoinHandle<()>> {
+    pub fn start(config: Arc<RwLock<Config>>,
+                 slot: u32,
+                 cancellation: Cancellation)
+                 -> Result<JoinHandle<()>> {
         let (tx, rx) = mpsc::sync_channel(0);
         let handle = thread::Builder::new()
-            .name("runner".to_string())
+            .name(format!("runner-{}", slot))
             .spawn(move || {
-                       let mut runner = Self::new(config).unwrap();
+                       let mut runner = Self::new(config, slot, cancellation).unwrap();
                        runner.run(tx).unwrap();
                    })
             .unwrap();
@@ -431,39 +911,60 @@ impl RunnerMgr {
         }
     }
 
-    fn new(config: Arc<RwLock<Config>>) -> Result<Self> {
+    fn new(config: Arc<RwLock<Config>>, slot: u32, cancellation: Cancellation) -> Result<Self> {
         let sock = try!((**ZMQ_CONTEXT).as_mut().socket(zmq::DEALER));
         Ok(RunnerMgr {
+               slot: slot,
                sock: sock,
                msg: zmq::Message::new().unwrap(),
                config: config,
+               cancellation: cancellation,
            })
     }
 
     // Main loop for server
     fn run(&mut self, rz: mpsc::SyncSender<()>) -> Result<()> {
-        try!(self.sock.bind(INPROC_ADDR));
+        try!(self.sock.bind(&runner_addr(self.slot)));
         rz.send(()).unwrap();
         loop {
-            let job = try!(self.recv_job());
+            let (publish, job) = try!(self.recv_job());
+            // Accept cancellations before acknowledging, after which the server may pass them on
+            self.cancellation.begin(job.get_id());
             try!(self.send_ack(&job));
-            try!(self.execute_job(job));
+            try!(self.execute_job(job, publish));
         }
     }
 
-    fn execute_job(&mut self, job: Job) -> Result<()> {
+    fn execute_job(&mut self, job: Job, publish: bool) -> Result<()> {
         let runner = {
-            Runner::new(job, (*self.config.read().unwrap()).clone())
+            Runner::new(job,
+                        (*self.config.read().unwrap()).clone(),
+                        self.cancellation.clone())
         };
-        debug!("executing work, job={:?}", runner.job());
-        let job = runner.run();
+        debug!("executing work, slot={}, publish={}, job={:?}",
+               self.slot,
+               publish,
+               runner.job());
+        let job = if publish {
+            runner.publish()
+        } else {
+            runner.run()
+        };
+        self.cancellation.finish();
         self.send_complete(&job)
     }
 
-    fn recv_job(&mut self) -> Result<Job> {
+    /// Receive the next job and whether only its shelved artifact is to be published
+    fn recv_job(&mut self) -> Result<(bool, Job)> {
+        try!(self.sock.recv(&mut self.msg, 0));
+        let publish = match self.msg.as_str() {
+            Some(WORK_BUILD) => false,
+            Some(WORK_PUBLISH) => true,
+            _ => unreachable!("wk:run:3, received unexpected request from server"),
+        };
         try!(self.sock.recv(&mut self.msg, 0));
         let job: proto::Job = parse_from_bytes(&self.msg).unwrap();
-        Ok(Job::new(job))
+        Ok((publish, Job::new(job)))
     }
 
     fn send_ack(&mut self, job: &Job) -> Result<()> {
@@ -481,6 +982,43 @@ impl RunnerMgr {
     }
 }
 
+/// Stop the running studio once `timeout` has elapsed, unless the returned sender is dropped
+/// first
+fn start_watchdog(cancellation: Cancellation, timeout: Duration) -> mpsc::Sender<()> {
+    let (tx, rx) = mpsc::channel();
+    thread::Builder::new()
+        .name("build-watchdog".to_string())
+        .spawn(move || if let Err(mpsc::RecvTimeoutError::Timeout) = rx.recv_timeout(timeout) {
+                   cancellation.time_out();
+               })
+        .unwrap();
+    tx
+}
+
+/// Kill every process in the group led by `pid`
+fn kill_process_group(pid: u32) {
+    if let Err(err) = process::signal(-(pid as process::Pid), Signal::KILL) {
+        error!("unable to kill studio, pid={}, err={}", pid, err);
+    }
+}
+
+fn runner_addr(slot: u32) -> String {
+    format!("{}-{}", INPROC_ADDR, slot)
+}
+
+/// Writes an SSH private key readable only by the worker
+fn write_deploy_key(path: &Path, key: &str) -> Result<()> {
+    try!(fs::create_dir_all(path.parent().unwrap()));
+    let mut file = try!(fs::OpenOptions::new()
+                            .write(true)
+                            .create(true)
+                            .truncate(true)
+                            .mode(0o600)
+                            .open(path));
+    try!(file.write_all(key.as_bytes()));
+    Ok(())
+}
+
 fn studio_cmd() -> String {
     match PackageInstall::load(&STUDIO_PKG, None) {
         Ok(package) => format!("{}/hab-studio", package.paths().unwrap()[0].display()),
@@ -493,9 +1031,109 @@ fn studio_cmd() -> String {
 
 #[cfg(test)]
 mod tests {
+    use std::os::unix::process::ExitStatusExt;
+    use std::process::Child;
+
     use super::*;
     use protocol::{jobsrv, originsrv};
 
+    fn spawn_studio() -> Child {
+        let mut cmd = Command::new("sleep");
+        cmd.arg("60");
+        cmd.before_exec(|| {
+                            unsafe {
+                                libc::setpgid(0, 0);
+                            }
+                            Ok(())
+                        });
+        cmd.spawn().unwrap()
+    }
+
+    #[test]
+    fn cancel_only_the_job_being_run() {
+        let cancellation = Cancellation::default();
+        assert!(!cancellation.cancel(1));
+        cancellation.begin(1);
+        assert!(!cancellation.cancel(2));
+        assert!(!cancellation.is_cancelled());
+        assert!(cancellation.cancel(1));
+        assert!(cancellation.is_cancelled());
+        cancellation.finish();
+        assert!(!cancellation.is_cancelled());
+        assert!(!cancellation.cancel(1));
+    }
+
+    #[test]
+    fn cancel_kills_running_studio() {
+        let cancellation = Cancellation::default();
+        let mut studio = spawn_studio();
+        cancellation.begin(1);
+        cancellation.studio_started(studio.id());
+        assert!(cancellation.cancel(1));
+        let status = studio.wait().unwrap();
+        assert_eq!(status.signal(), Some(libc::SIGKILL));
+    }
+
+    #[test]
+    fn studio_started_after_cancel_is_killed() {
+        let cancellation = Cancellation::default();
+        cancellation.begin(1);
+        assert!(cancellation.cancel(1));
+        let mut studio = spawn_studio();
+        cancellation.studio_started(studio.id());
+        let status = studio.wait().unwrap();
+        assert_eq!(status.signal(), Some(libc::SIGKILL));
+    }
+
+    #[test]
+    fn time_out_kills_running_studio() {
+        let cancellation = Cancellation::default();
+        let mut studio = spawn_studio();
+        cancellation.begin(1);
+        cancellation.studio_started(studio.id());
+        cancellation.time_out();
+        assert!(cancellation.is_timed_out());
+        let status = studio.wait().unwrap();
+        assert_eq!(status.signal(), Some(libc::SIGKILL));
+    }
+
+    #[test]
+    fn time_out_after_studio_exited() {
+        let cancellation = Cancellation::default();
+        let mut studio = spawn_studio();
+        cancellation.begin(1);
+        cancellation.studio_started(studio.id());
+        studio.kill().unwrap();
+        studio.wait().unwrap();
+        cancellation.studio_exited();
+        cancellation.time_out();
+        assert!(!cancellation.is_timed_out());
+    }
+
+    #[test]
+    #[ignore]
+    #[cfg(feature = "functional")]
+    fn secrets_are_visible_inside_studio() {
+        let root = "/hab/studios/worker-functional-tests";
+        let status = Command::new(studio_cmd())
+            .args(&["-r", root, "new"])
+            .env_clear()
+            .env("HAB_NONINTERACTIVE", "true")
+            .status()
+            .unwrap();
+        assert!(status.success());
+        let output = Command::new(studio_cmd())
+            .args(&["-r", root, "run", "printenv", "DEPLOY_TOKEN"])
+            .env_clear()
+            .env("HAB_NONINTERACTIVE", "true")
+            .env(format!("{}DEPLOY_TOKEN", STUDIO_SECRET_PREFIX), "s3cr3t*")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.lines().any(|line| line == "s3cr3t*"));
+    }
+
     #[test]
     fn extract_origin_from_job() {
         let mut inner = jobsrv::Job::new();
@@ -13,10 +13,20 @@
 // limitations under the License.
 
 use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::str::FromStr;
 
+use common::ui::{Coloring, UI};
+use hab::command::pkg::sbom::{self, SbomFormat};
+use hab_core::fs::cache_artifact_path;
 use hab_core::package::archive::PackageArchive;
+use hab_core::package::install::PackageInstall;
+use hab_core::package::PackageIdent;
 use hab_core::config::ConfigFile;
+use protobuf::RepeatedField;
+use protocol::jobsrv::PostProcessResult;
 
+use super::STUDIO_PKG;
 use super::workspace::Workspace;
 use {PRODUCT, VERSION};
 use config::Config;
@@ -26,7 +36,112 @@ use error::{Error, Result};
 /// Postprocessing config file name
 const CONFIG_FILE: &'static str = "builder.toml";
 
-#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+lazy_static! {
+    static ref DOCKER_PKG: PackageIdent = PackageIdent::from_str("core/docker").unwrap();
+    static ref DOCKERIZE_PKG: PackageIdent =
+        PackageIdent::from_str("core/hab-pkg-dockerize").unwrap();
+}
+
+/// The post-processing steps of a project, run in order against its built artifact
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Pipeline {
+    pub steps: Vec<Step>,
+}
+
+impl Pipeline {
+    /// Run every step which hasn't already succeeded according to `results`, recording the
+    /// result of each step it runs. Stops at the first step which fails and returns whether
+    /// all steps succeeded. `studio` is the root of the studio the artifact was built in, whose
+    /// packages describe its dependencies.
+    pub fn run(&self,
+               archive: &mut PackageArchive,
+               config: &Config,
+               scratch: &Path,
+               studio: &Path,
+               results: &mut RepeatedField<PostProcessResult>)
+               -> bool {
+        let tools = Tools::locate();
+        for (idx, step) in self.steps.iter().enumerate() {
+            // Steps which succeeded before publishing was retried are not run again
+            if results.get(idx).map_or(false, |r| r.get_success()) {
+                continue;
+            }
+            debug!("post process: {} ({:?})", step.name(), step);
+            let mut result = PostProcessResult::new();
+            result.set_name(step.name().to_string());
+            match step.run(archive, config, &tools, scratch, studio) {
+                Ok(detail) => {
+                    result.set_success(true);
+                    result.set_detail(detail);
+                }
+                Err(err) => {
+                    error!("post processing error, step={}, ERR={}", step.name(), err);
+                    result.set_success(false);
+                    result.set_detail(err.to_string());
+                }
+            }
+            let success = result.get_success();
+            if idx < results.len() {
+                results[idx] = result;
+            } else {
+                results.push(result);
+            }
+            if !success {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl ConfigFile for Pipeline {
+    type Error = Error;
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(tag = "type")]
+pub enum Step {
+    #[serde(rename = "publish")]
+    Publish(Publish),
+    #[serde(rename = "export")]
+    Export(Export),
+    #[serde(rename = "smoke_test")]
+    SmokeTest(SmokeTest),
+    #[serde(rename = "sbom")]
+    Sbom,
+}
+
+impl Step {
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Step::Publish(_) => "publish",
+            Step::Export(_) => "export",
+            Step::SmokeTest(_) => "smoke_test",
+            Step::Sbom => "sbom",
+        }
+    }
+
+    /// Run the step against the artifact and describe what it produced. The `sbom` step
+    /// produces the artifact's SPDX document, which is kept with the job's results.
+    fn run(&self,
+           archive: &mut PackageArchive,
+           config: &Config,
+           tools: &Tools,
+           scratch: &Path,
+           studio: &Path)
+           -> Result<String> {
+        match *self {
+            Step::Publish(ref publish) => publish.run(archive, &config.auth_token),
+            Step::Export(ref export) => export.run(archive, tools),
+            Step::SmokeTest(ref smoke_test) => {
+                smoke_test.run(archive, tools, scratch, &config.depot_url)
+            }
+            Step::Sbom => spdx(archive, studio),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
 pub struct Publish {
     pub enabled: bool,
     pub url: String,
@@ -34,25 +149,129 @@ pub struct Publish {
 }
 
 impl Publish {
-    pub fn run(&mut self, archive: &mut PackageArchive, auth_token: &str) -> bool {
+    fn run(&self, archive: &mut PackageArchive, auth_token: &str) -> Result<String> {
         if !self.enabled {
-            return true;
+            return Ok("publishing is disabled".to_string());
         }
-        debug!("post process: publish (url: {}, channel: {})",
-               self.url,
-               self.channel);
         let client = depot_client::Client::new(&self.url, PRODUCT, VERSION, None).unwrap();
-        if let Some(err) = client.x_put_package(archive, auth_token).err() {
-            error!("post processing error uploading package, ERR={:?}", err);
-            return false;
-        };
-        if let Some(err) = client
-               .promote_package(archive, &self.channel, auth_token)
+        try!(client.x_put_package(archive, auth_token));
+        try!(client.promote_package(archive, &self.channel, auth_token));
+        Ok(format!("{} in channel {}", self.url, self.channel))
+    }
+}
+
+/// Exports the artifact to a container image and pushes it to a registry. The image is made
+/// from the package as found in the depot, so the step must come after publishing it.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Export {
+    /// URL of the depot the package is installed into the image from
+    pub url: String,
+    /// Registry the image is pushed to
+    pub registry: String,
+}
+
+impl Export {
+    fn run(&self, archive: &mut PackageArchive, tools: &Tools) -> Result<String> {
+        let ident = try!(archive.ident());
+        // Same tag as the image built by hab-pkg-dockerize, which docker wants downcased
+        let image = format!("{}/{}:{}-{}",
+                            ident.origin,
+                            ident.name,
+                            ident.version.as_ref().unwrap(),
+                            ident.release.as_ref().unwrap())
+                .to_lowercase();
+        let remote = format!("{}/{}", self.registry, image);
+        try!(exec(Command::new(&tools.dockerize)
+                      .arg(ident.to_string())
+                      .env("HAB_DEPOT_URL", &self.url)));
+        let docker = &tools.docker;
+        try!(exec(Command::new(docker).arg("tag").arg(&image).arg(&remote)));
+        try!(exec(Command::new(docker).arg("push").arg(&remote)));
+        if let Some(err) = exec(Command::new(docker).arg("rmi").arg(&remote).arg(&image))
                .err() {
-            error!("post processing error promoting package, ERR={:?}", err);
-            return false;
+            warn!("unable to remove exported image, image={}, err={}", image, err);
+        }
+        Ok(remote)
+    }
+}
+
+/// Installs the artifact into a clean studio and runs a command against it there. The studio
+/// installs dependencies from the worker's depot and verifies the artifact with the keys of
+/// its origin, like the studio it was built in.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct SmokeTest {
+    pub command: String,
+}
+
+impl SmokeTest {
+    fn run(&self,
+           archive: &mut PackageArchive,
+           tools: &Tools,
+           scratch: &Path,
+           depot_url: &str)
+           -> Result<String> {
+        let origin = try!(archive.ident()).origin;
+        let studio = SmokeStudio {
+            cmd: &tools.studio,
+            // The artifact's directory is mounted into the studio as its source path
+            src: archive.path.parent().unwrap(),
+            root: &scratch.join("smoke-studio"),
+            origin: &origin,
+            depot_url: depot_url,
         };
-        true
+        let script = format!("hab pkg install /src/{} && {}",
+                             archive.file_name(),
+                             self.command);
+        let result = studio
+            .run(&["new"])
+            .and_then(|_| studio.run(&["run", &script]));
+        if let Some(err) = studio.run(&["rm"]).err() {
+            warn!("unable to remove smoke test studio, err={}", err);
+        }
+        try!(result);
+        Ok(format!("passed: {}", self.command))
+    }
+}
+
+/// The studio a smoke test runs in, with the artifact's directory as its source path
+struct SmokeStudio<'a> {
+    cmd: &'a Path,
+    src: &'a Path,
+    root: &'a Path,
+    origin: &'a str,
+    depot_url: &'a str,
+}
+
+impl<'a> SmokeStudio<'a> {
+    fn run(&self, args: &[&str]) -> Result<()> {
+        exec(Command::new(self.cmd)
+                 .arg("-s")
+                 .arg(self.src)
+                 .arg("-r")
+                 .arg(self.root)
+                 .arg("-k")
+                 .arg(self.origin)
+                 .args(args)
+                 .env_clear()
+                 .env("HAB_DEPOT_URL", self.depot_url)
+                 .env("HAB_NONINTERACTIVE", "true"))
+    }
+}
+
+/// The programs which post-processing steps run
+struct Tools {
+    studio: PathBuf,
+    dockerize: PathBuf,
+    docker: PathBuf,
+}
+
+impl Tools {
+    fn locate() -> Self {
+        Tools {
+            studio: pkg_cmd(&STUDIO_PKG, "hab-studio"),
+            dockerize: pkg_cmd(&DOCKERIZE_PKG, "hab-pkg-dockerize"),
+            docker: pkg_cmd(&DOCKER_PKG, "docker"),
+        }
     }
 }
 
@@ -68,28 +287,102 @@ struct PublishBuilder {
 }
 
 impl PublishBuilder {
+    fn build(self, config: &Config) -> Publish {
+        Publish {
+            enabled: self.enabled.unwrap_or(config.auto_publish),
+            url: self.url.unwrap_or(config.depot_url.clone()),
+            channel: self.channel.unwrap_or(config.depot_channel.clone()),
+        }
+    }
+}
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct ExportBuilder {
+    /// URL to Depot API to install the package from
+    url: Option<String>,
+    /// Registry to push the image to
+    registry: Option<String>,
+}
+
+impl ExportBuilder {
+    fn build(self, config: &Config) -> Export {
+        Export {
+            url: self.url.unwrap_or(config.depot_url.clone()),
+            registry: self.registry.unwrap_or(config.export_registry.clone()),
+        }
+    }
+}
+
+/// A post-processing step as configured by a project
+#[derive(Deserialize)]
+#[serde(tag = "type")]
+enum StepBuilder {
+    #[serde(rename = "publish")]
+    Publish(PublishBuilder),
+    #[serde(rename = "export")]
+    Export(ExportBuilder),
+    #[serde(rename = "smoke_test")]
+    SmokeTest(SmokeTest),
+    #[serde(rename = "sbom")]
+    Sbom,
+}
+
+impl StepBuilder {
+    fn build(self, config: &Config) -> Step {
+        match self {
+            StepBuilder::Publish(publish) => Step::Publish(publish.build(config)),
+            StepBuilder::Export(export) => Step::Export(export.build(config)),
+            StepBuilder::SmokeTest(smoke_test) => Step::SmokeTest(smoke_test),
+            StepBuilder::Sbom => Step::Sbom,
+        }
+    }
+}
+
+/// Contents of a `builder.toml`. Projects list their steps as `[[post_process]]` tables, each
+/// with a `type`. Without any, the artifact is published as set by the top-level `enabled`,
+/// `url` and `channel`, which was the only step there used to be.
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct PipelineBuilder {
+    enabled: Option<bool>,
+    url: Option<String>,
+    channel: Option<String>,
+    post_process: Vec<StepBuilder>,
+}
+
+impl PipelineBuilder {
     fn new(config_path: &Path) -> Result<Self> {
         let builder = if config_path.exists() {
             debug!("using post processing config from {}",
                    config_path.display());
-            PublishBuilder::from_file(config_path)?
+            PipelineBuilder::from_file(config_path)?
         } else {
             debug!("no post processing config - using defaults");
-            PublishBuilder::default()
+            PipelineBuilder::default()
         };
         Ok(builder)
     }
 
-    fn build(self, config: &Config) -> Publish {
-        Publish {
-            enabled: self.enabled.unwrap_or(config.auto_publish),
-            url: self.url.unwrap_or(config.depot_url.clone()),
-            channel: self.channel.unwrap_or(config.depot_channel.clone()),
-        }
+    fn build(self, config: &Config) -> Pipeline {
+        let steps = if self.post_process.is_empty() {
+            let publish = PublishBuilder {
+                enabled: self.enabled,
+                url: self.url,
+                channel: self.channel,
+            };
+            vec![Step::Publish(publish.build(config))]
+        } else {
+            self.post_process
+                .into_iter()
+                .map(|step| step.build(config))
+                .collect()
+        };
+        Pipeline { steps: steps }
     }
 }
 
-impl ConfigFile for PublishBuilder {
+impl ConfigFile for PipelineBuilder {
     type Error = Error;
 }
 
@@ -106,25 +399,121 @@ impl PostProcessor {
         PostProcessor { config_path: file_path }
     }
 
-    pub fn run(&mut self, archive: &mut PackageArchive, config: &Config) -> bool {
-        let mut publisher = match PublishBuilder::new(&self.config_path) {
-            Ok(builder) => builder.build(config),
-            Err(err) => {
-                warn!("Failed to parse builder config, {}", err);
-                return false;
-            }
-        };
+    /// Load the project's post processing configuration, falling back to the worker's defaults
+    pub fn load(&self, config: &Config) -> Result<Pipeline> {
+        let builder = try!(PipelineBuilder::new(&self.config_path));
         debug!("starting post processing");
-        publisher.run(archive, &config.auth_token)
+        Ok(builder.build(config))
+    }
+}
+
+/// Renders the SPDX document of the artifact with the generator behind `hab pkg sbom`. Its
+/// dependencies are described by the packages installed in the studio it was built in.
+fn spdx(archive: &PackageArchive, studio: &Path) -> Result<String> {
+    let mut ui = UI::default_with(Coloring::Never, Some(false));
+    sbom::generate(&mut ui,
+                   &archive.path.to_string_lossy(),
+                   SbomFormat::Spdx,
+                   studio,
+                   &cache_artifact_path(Some(studio)))
+            .map_err(Error::Sbom)
+}
+
+/// Path to a program shipped in the given package, or else the one on the worker's `PATH`
+fn pkg_cmd(ident: &PackageIdent, program: &str) -> PathBuf {
+    let paths = PackageInstall::load(ident, None).and_then(|package| package.paths());
+    match paths.ok().and_then(|paths| paths.into_iter().next()) {
+        Some(path) => path.join(program),
+        None => PathBuf::from(program),
+    }
+}
+
+/// Run a command to completion, failing if it exits unsuccessfully
+fn exec(cmd: &mut Command) -> Result<()> {
+    debug!("post process, cmd={:?}", cmd);
+    let status = try!(cmd.status());
+    if status.success() {
+        Ok(())
+    } else {
+        Err(Error::PostProcessFailure(format!("{:?}", cmd), status.code().unwrap_or(-1)))
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::fs::{self, File};
+    use std::io::{Read, Write};
+    use std::os::unix::fs::PermissionsExt;
+    use std::str::FromStr;
+
     use hab_core::config::ConfigFile;
+    use tempdir::TempDir;
+
     use super::*;
     use config::Config;
 
+    const ARTIFACT: &'static str = "happyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart";
+    const DEPOT_URL: &'static str = "http://depot.example.com/v1/depot";
+
+    fn fixture() -> PackageArchive {
+        PackageArchive::new(Path::new(env!("CARGO_MANIFEST_DIR"))
+                                .join("../core/tests/fixtures")
+                                .join(ARTIFACT))
+    }
+
+    /// Writes a shell script standing in for one of the programs a step runs
+    fn stand_in(dir: &Path, name: &str, script: &str) -> PathBuf {
+        let path = dir.join(name);
+        File::create(&path)
+            .unwrap()
+            .write_all(format!("#!/bin/sh\n{}\n", script).as_bytes())
+            .unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
+    fn read(path: &Path) -> String {
+        let mut content = String::new();
+        File::open(path)
+            .and_then(|mut file| file.read_to_string(&mut content))
+            .unwrap_or(0);
+        content
+    }
+
+    /// Tools whose `docker` pushes images to the `registry` file, listing one image per line
+    fn export_tools(dir: &Path, push: &str) -> Tools {
+        let log = dir.join("log");
+        let registry = dir.join("registry");
+        Tools {
+            studio: PathBuf::from("/nonexistent/hab-studio"),
+            dockerize: stand_in(dir,
+                                "hab-pkg-dockerize",
+                                &format!("echo \"dockerize $* $HAB_DEPOT_URL\" >> {}",
+                                         log.display())),
+            docker: stand_in(dir,
+                             "docker",
+                             &format!("echo \"docker $*\" >> {}\n\
+                                       if [ \"$1\" = push ]; then {}; fi",
+                                      log.display(),
+                                      push.replace("REGISTRY", &registry.display().to_string()))),
+        }
+    }
+
+    fn smoke_test_tools(dir: &Path, fail_on: &str) -> Tools {
+        let log = dir.join("log");
+        // The command is the seventh argument, after the source path, root and origin
+        Tools {
+            studio: stand_in(dir,
+                             "hab-studio",
+                             &format!("echo \"$* $HAB_DEPOT_URL\" >> {}\n\
+                                       if [ \"$7\" = {} ]; then exit 1; fi",
+                                      log.display(),
+                                      fail_on)),
+            dockerize: PathBuf::from("/nonexistent/hab-pkg-dockerize"),
+            docker: PathBuf::from("/nonexistent/docker"),
+        }
+    }
+
     #[test]
     fn test_publish_config_from_toml() {
         let toml = r#"
@@ -134,9 +523,172 @@ mod tests {
         "#;
 
         let config = Config::default();
-        let cfg = PublishBuilder::from_raw(toml).unwrap().build(&config);
-        assert_eq!("https://willem.habitat.sh/v1/depot", cfg.url);
-        assert_eq!(false, cfg.enabled);
-        assert_eq!("unstable", cfg.channel);
+        let pipeline = PipelineBuilder::from_raw(toml).unwrap().build(&config);
+        let expected = Publish {
+            enabled: false,
+            url: "https://willem.habitat.sh/v1/depot".to_string(),
+            channel: "unstable".to_string(),
+        };
+        assert_eq!(pipeline.steps, vec![Step::Publish(expected)]);
+    }
+
+    #[test]
+    fn test_pipeline_config_from_toml() {
+        let toml = r#"
+        [[post_process]]
+        type = "smoke_test"
+        command = "hab pkg exec core/nginx nginx -v"
+
+        [[post_process]]
+        type = "publish"
+        channel = "stable"
+
+        [[post_process]]
+        type = "publish"
+        url = "https://mirror.example.com/v1/depot"
+
+        [[post_process]]
+        type = "export"
+
+        [[post_process]]
+        type = "sbom"
+        "#;
+
+        let config = Config::default();
+        let pipeline = PipelineBuilder::from_raw(toml).unwrap().build(&config);
+        let names: Vec<&str> = pipeline.steps.iter().map(|s| s.name()).collect();
+        assert_eq!(names, vec!["smoke_test", "publish", "publish", "export", "sbom"]);
+        assert_eq!(pipeline.steps[0],
+                   Step::SmokeTest(SmokeTest {
+                                       command: "hab pkg exec core/nginx nginx -v".to_string(),
+                                   }));
+        assert_eq!(pipeline.steps[1],
+                   Step::Publish(Publish {
+                                     enabled: true,
+                                     url: config.depot_url.clone(),
+                                     channel: "stable".to_string(),
+                                 }));
+        assert_eq!(pipeline.steps[2],
+                   Step::Publish(Publish {
+                                     enabled: true,
+                                     url: "https://mirror.example.com/v1/depot".to_string(),
+                                     channel: config.depot_channel.clone(),
+                                 }));
+        assert_eq!(pipeline.steps[3],
+                   Step::Export(Export {
+                                    url: config.depot_url.clone(),
+                                    registry: "localhost:5000".to_string(),
+                                }));
+    }
+
+    #[test]
+    fn skips_steps_which_succeeded() {
+        let pipeline = Pipeline {
+            steps: vec![Step::Publish(Publish {
+                                          enabled: true,
+                                          url: "https://bldr.example.com/v1/depot".to_string(),
+                                          channel: "unstable".to_string(),
+                                      })],
+        };
+        let mut published = PostProcessResult::new();
+        published.set_name("publish".to_string());
+        published.set_success(true);
+        let mut results = RepeatedField::from_vec(vec![published.clone()]);
+        let mut archive = PackageArchive::new("/nonexistent/core-zlib.hart");
+        assert!(pipeline.run(&mut archive,
+                             &Config::default(),
+                             Path::new("/tmp"),
+                             Path::new("/tmp"),
+                             &mut results));
+        assert_eq!(results.into_vec(), vec![published]);
+    }
+
+    #[test]
+    fn export_pushes_image_to_registry() {
+        let tmp = TempDir::new("export").unwrap();
+        let tools = export_tools(tmp.path(), "echo \"$2\" >> REGISTRY");
+        let export = Export {
+            url: DEPOT_URL.to_string(),
+            registry: "registry.example.com:5000".to_string(),
+        };
+
+        let remote = export.run(&mut fixture(), &tools).unwrap();
+        assert_eq!(remote,
+                   "registry.example.com:5000/happyhumans/possums:8.1.4-20160427165340");
+        assert_eq!(read(&tmp.path().join("registry")), format!("{}\n", remote));
+        let log = read(&tmp.path().join("log"));
+        assert!(log.starts_with(&format!("dockerize happyhumans/possums/8.1.4/20160427165340 {}\n",
+                                         DEPOT_URL)));
+        assert!(log.ends_with(&format!("docker rmi {} happyhumans/possums:8.1.4-20160427165340\n",
+                                       remote)));
+    }
+
+    #[test]
+    fn export_fails_when_push_fails() {
+        let tmp = TempDir::new("export").unwrap();
+        let tools = export_tools(tmp.path(), "exit 1");
+        let export = Export {
+            url: DEPOT_URL.to_string(),
+            registry: "registry.example.com:5000".to_string(),
+        };
+
+        assert!(export.run(&mut fixture(), &tools).is_err());
+        assert_eq!(read(&tmp.path().join("registry")), "");
+        assert!(!read(&tmp.path().join("log")).contains("docker rmi"));
+    }
+
+    #[test]
+    fn smoke_test_runs_in_studio_with_origin_keys() {
+        let tmp = TempDir::new("smoke-test").unwrap();
+        let tools = smoke_test_tools(tmp.path(), "nothing");
+        let smoke_test = SmokeTest { command: "possums --version".to_string() };
+        let mut archive = fixture();
+
+        let detail = smoke_test
+            .run(&mut archive, &tools, tmp.path(), DEPOT_URL)
+            .unwrap();
+        assert_eq!(detail, "passed: possums --version");
+        let studio = format!("-s {} -r {} -k happyhumans",
+                             archive.path.parent().unwrap().display(),
+                             tmp.path().join("smoke-studio").display());
+        let expected = vec![format!("{} new {}", studio, DEPOT_URL),
+                            format!("{} run hab pkg install /src/{} && possums --version {}",
+                                    studio,
+                                    ARTIFACT,
+                                    DEPOT_URL),
+                            format!("{} rm {}", studio, DEPOT_URL)];
+        let log = read(&tmp.path().join("log"));
+        assert_eq!(log.lines().collect::<Vec<&str>>(), expected);
+    }
+
+    #[test]
+    fn failed_smoke_test_removes_studio() {
+        let tmp = TempDir::new("smoke-test").unwrap();
+        let tools = smoke_test_tools(tmp.path(), "run");
+        let smoke_test = SmokeTest { command: "possums --version".to_string() };
+
+        assert!(smoke_test
+                    .run(&mut fixture(), &tools, tmp.path(), DEPOT_URL)
+                    .is_err());
+        let log = read(&tmp.path().join("log"));
+        let commands: Vec<&str> = log.lines()
+            .map(|line| line.split_whitespace().nth(6).unwrap())
+            .collect();
+        assert_eq!(commands, vec!["new", "run", "rm"]);
+    }
+
+    #[test]
+    fn pkg_cmd_falls_back_to_path() {
+        let ident = PackageIdent::from_str("nonexistent/nonexistent").unwrap();
+        assert_eq!(pkg_cmd(&ident, "docker"), PathBuf::from("docker"));
+    }
+
+    #[test]
+    fn sbom_is_spdx_document_of_artifact() {
+        let tmp = TempDir::new("sbom").unwrap();
+        let doc = spdx(&fixture(), tmp.path()).unwrap();
+        assert!(doc.starts_with("SPDXVersion: SPDX-2.3\n"));
+        assert!(doc.contains("DocumentName: happyhumans/possums/8.1.4/20160427165340\n"));
+        assert!(doc.contains("PackageName: happyhumans/possums\n"));
     }
 }
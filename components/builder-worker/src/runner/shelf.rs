@@ -0,0 +1,163 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Keeps the output of builds which failed in post-processing, so that publishing them can be
+//! retried without rebuilding. Each job's output is shelved in its own directory along with
+//! the post-processing steps it was built with.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use hab_core::config::ConfigFile;
+use hab_core::package::archive::PackageArchive;
+use toml;
+
+use super::postprocessor::Pipeline;
+use super::workspace::LastBuild;
+use error::Result;
+
+/// File the post-processing steps of a shelved build are kept in
+const PIPELINE_FILE: &'static str = "post_process.toml";
+
+pub struct Shelf {
+    root: PathBuf,
+}
+
+impl Shelf {
+    pub fn new<T>(data_path: T) -> Self
+        where T: AsRef<Path>
+    {
+        Shelf { root: data_path.as_ref().join("shelf") }
+    }
+
+    /// Directory the output of the given job is shelved in
+    pub fn path(&self, job_id: u64) -> PathBuf {
+        self.root.join(job_id.to_string())
+    }
+
+    /// Move the build output of a job onto the shelf
+    pub fn put(&self, job_id: u64, out: &Path, pipeline: &Pipeline) -> Result<()> {
+        let path = self.path(job_id);
+        try!(fs::create_dir_all(&self.root));
+        if path.exists() {
+            try!(fs::remove_dir_all(&path));
+        }
+        try!(fs::rename(out, &path));
+        let content = toml::to_string(pipeline).expect("post-processing steps are serializable");
+        let mut file = try!(fs::File::create(path.join(PIPELINE_FILE)));
+        try!(file.write_all(content.as_bytes()));
+        Ok(())
+    }
+
+    /// The shelved artifact of a job and the post-processing steps to run against it
+    pub fn get(&self, job_id: u64) -> Result<(PackageArchive, Pipeline)> {
+        let path = self.path(job_id);
+        let pipeline = try!(Pipeline::from_file(path.join(PIPELINE_FILE)));
+        let build = try!(LastBuild::from_file(path.join("last_build.env")));
+        Ok((PackageArchive::new(path.join(build.pkg_artifact)), pipeline))
+    }
+
+    /// Throw away the shelved output of a job, if there is any
+    pub fn discard(&self, job_id: u64) -> Result<()> {
+        let path = self.path(job_id);
+        if path.exists() {
+            try!(fs::remove_dir_all(path));
+        }
+        Ok(())
+    }
+
+    /// Throw away the output of jobs shelved longer than `ttl` ago, which were abandoned
+    /// without the job server telling this worker
+    pub fn gc(&self, ttl: Duration) -> Result<()> {
+        if !self.root.exists() {
+            return Ok(());
+        }
+        let now = SystemTime::now();
+        for entry in try!(fs::read_dir(&self.root)) {
+            let entry = try!(entry);
+            let modified = try!(try!(entry.metadata()).modified());
+            let age = now.duration_since(modified).unwrap_or(Duration::from_secs(0));
+            if age >= ttl {
+                debug!("discarding abandoned shelved build, path={}",
+                       entry.path().display());
+                try!(fs::remove_dir_all(entry.path()));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::io::Write;
+    use std::time::Duration;
+
+    use tempdir::TempDir;
+
+    use super::*;
+    use super::super::postprocessor::{Publish, SmokeTest, Step};
+
+    fn pipeline() -> Pipeline {
+        let publish = Publish {
+            enabled: true,
+            url: "https://bldr.example.com/v1/depot".to_string(),
+            channel: "unstable".to_string(),
+        };
+        let smoke_test = SmokeTest { command: "hab pkg exec core/zlib true".to_string() };
+        Pipeline { steps: vec![Step::SmokeTest(smoke_test), Step::Publish(publish), Step::Sbom] }
+    }
+
+    fn build_out(path: &Path) {
+        fs::create_dir_all(path).unwrap();
+        File::create(path.join("last_build.env"))
+            .unwrap()
+            .write_all(b"pkg_artifact=core-zlib-1.2.8-20170101000000-x86_64-linux.hart\n")
+            .unwrap();
+    }
+
+    #[test]
+    fn put_and_get() {
+        let tmp = TempDir::new("shelf").unwrap();
+        let shelf = Shelf::new(tmp.path());
+        build_out(&tmp.path().join("out"));
+        shelf.put(42, &tmp.path().join("out"), &pipeline()).unwrap();
+        assert!(!tmp.path().join("out").exists());
+
+        let (archive, shelved) = shelf.get(42).unwrap();
+        assert_eq!(shelved, pipeline());
+        assert_eq!(archive.path,
+                   shelf
+                       .path(42)
+                       .join("core-zlib-1.2.8-20170101000000-x86_64-linux.hart"));
+
+        shelf.discard(42).unwrap();
+        assert!(shelf.get(42).is_err());
+    }
+
+    #[test]
+    fn gc() {
+        let tmp = TempDir::new("shelf").unwrap();
+        let shelf = Shelf::new(tmp.path());
+        build_out(&tmp.path().join("out"));
+        shelf.put(42, &tmp.path().join("out"), &pipeline()).unwrap();
+
+        shelf.gc(Duration::from_secs(3600)).unwrap();
+        assert!(shelf.path(42).exists());
+        shelf.gc(Duration::from_secs(0)).unwrap();
+        assert!(!shelf.path(42).exists());
+    }
+}
@@ -23,6 +23,7 @@ use error::Result;
 
 pub struct Workspace {
     pub job: Job,
+    keys: PathBuf,
     out: PathBuf,
     src: PathBuf,
     studio: PathBuf,
@@ -36,6 +37,7 @@ impl Workspace {
         let root = data_path.as_ref().join(job.get_id().to_string());
         Workspace {
             job: job,
+            keys: root.join("keys"),
             out: root.join("out"),
             src: root.join("src"),
             studio: root.join("studio"),
@@ -49,6 +51,12 @@ impl Workspace {
         Ok(PackageArchive::new(self.out().join(build.pkg_artifact)))
     }
 
+    /// Directory the origin's encryption key is loaded into while decrypting build secrets, and
+    /// the project's deploy key while cloning its source
+    pub fn keys(&self) -> &Path {
+        &self.keys
+    }
+
     /// Directory to the output directory containing built artifacts from studio build
     pub fn out(&self) -> &Path {
         &self.out
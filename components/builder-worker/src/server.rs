@@ -12,28 +12,37 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp;
 use std::sync::{Arc, RwLock};
+use std::time::Duration;
 
 use hab_net::server::{NetIdent, ZMQ_CONTEXT};
 use protobuf::{parse_from_bytes, Message};
 use protocol;
+use protocol::net::{self, ErrCode};
 use zmq;
 
 use config::Config;
 use error::Result;
 use heartbeat::{HeartbeatCli, HeartbeatMgr};
 use log_forwarder::LogForwarder;
-use runner::{RunnerCli, RunnerMgr};
+use runner::{Cancellation, RunnerCli, RunnerMgr, RunnerStatusCli};
+use runner::shelf::Shelf;
 
-enum State {
-    Ready,
-    Busy,
-}
+/// ZMQ protocol frame to indicate a job is being dispatched to this worker
+const JOB_DISPATCH: &'static str = "D";
+/// ZMQ protocol frame to indicate the job being built should be cancelled
+const JOB_CANCEL: &'static str = "C";
+/// ZMQ protocol frame to indicate the shelved artifact of a job should be published again
+const JOB_PUBLISH: &'static str = "P";
+/// ZMQ protocol frame to indicate the shelved artifact of a job should be thrown away
+const JOB_DISCARD: &'static str = "X";
 
-impl Default for State {
-    fn default() -> State {
-        State::Ready
-    }
+/// A build slot, running at most one job at a time with its own Job Runner
+struct Slot {
+    cancellation: Cancellation,
+    runner_cli: RunnerCli,
+    busy: bool,
 }
 
 pub struct Server {
@@ -41,8 +50,10 @@ pub struct Server {
     /// Dealer Socket connected to JobSrv
     fe_sock: zmq::Socket,
     hb_cli: HeartbeatCli,
-    runner_cli: RunnerCli,
-    state: State,
+    slots: Vec<Slot>,
+    /// Number of jobs received from the job server, dispatched or to publish again
+    jobs_received: u64,
+    status_cli: RunnerStatusCli,
     msg: zmq::Message,
 }
 
@@ -50,24 +61,42 @@ impl Server {
     pub fn new(config: Config) -> Result<Self> {
         let fe_sock = try!((**ZMQ_CONTEXT).as_mut().socket(zmq::DEALER));
         let hb_cli = HeartbeatCli::new();
-        let runner_cli = RunnerCli::new();
+        let slots = (0..cmp::max(config.slots, 1))
+            .map(|slot| {
+                     Slot {
+                         cancellation: Cancellation::default(),
+                         runner_cli: RunnerCli::new(slot),
+                         busy: false,
+                     }
+                 })
+            .collect();
         try!(fe_sock.set_identity(Self::net_ident().as_bytes()));
         Ok(Server {
                config: Arc::new(RwLock::new(config)),
                fe_sock: fe_sock,
                hb_cli: hb_cli,
-               runner_cli: runner_cli,
-               state: State::default(),
+               slots: slots,
+               jobs_received: 0,
+               status_cli: RunnerStatusCli::new(),
                msg: try!(zmq::Message::new()),
            })
     }
 
     pub fn run(&mut self) -> Result<()> {
+        // The worker connects with a new identity, so the job server can no longer reach
+        // anything shelved by a previous run of it. It unshelves those jobs once it stops
+        // hearing from the previous identity.
+        self.collect_shelf(Duration::from_secs(0));
         try!(HeartbeatMgr::start(self.config.clone()));
-        try!(RunnerMgr::start(self.config.clone()));
+        for (slot, state) in self.slots.iter().enumerate() {
+            try!(RunnerMgr::start(self.config.clone(), slot as u32, state.cancellation.clone()));
+        }
         try!(LogForwarder::start(self.config.clone()));
         try!(self.hb_cli.connect());
-        try!(self.runner_cli.connect());
+        try!(self.status_cli.bind());
+        for slot in self.slots.iter_mut() {
+            try!(slot.runner_cli.connect());
+        }
         {
             let cfg = self.config.read().unwrap();
             for (_, queue, _) in cfg.jobsrv_addrs() {
@@ -76,62 +105,154 @@ impl Server {
             }
         }
         let mut fe_msg = false;
-        let mut runner_msg = false;
-        info!("builder-worker is ready to go.");
+        let mut runner_msgs = vec![false; self.slots.len()];
+        let mut status_msg = false;
+        info!("builder-worker is ready to go, slots={}", self.slots.len());
         loop {
             {
-                let mut items = [self.fe_sock.as_poll_item(1),
-                                 self.runner_cli.as_poll_item(1)];
+                let mut items = vec![self.fe_sock.as_poll_item(1),
+                                     self.status_cli.as_poll_item(1)];
+                for slot in self.slots.iter() {
+                    items.push(slot.runner_cli.as_poll_item(1));
+                }
                 try!(zmq::poll(&mut items, -1));
                 if items[0].get_revents() & zmq::POLLIN > 0 {
                     fe_msg = true;
                 }
                 if items[1].get_revents() & zmq::POLLIN > 0 {
-                    runner_msg = true;
+                    status_msg = true;
+                }
+                for (idx, item) in items[2..].iter().enumerate() {
+                    if item.get_revents() & zmq::POLLIN > 0 {
+                        runner_msgs[idx] = true;
+                    }
                 }
             }
-            if runner_msg {
-                {
-                    let reply = try!(self.runner_cli.recv_complete());
-                    try!(self.fe_sock.send(reply, 0));
+            // Forward all pending progress updates before a completed job so that the job
+            // server never sees them out of order
+            if status_msg || runner_msgs.contains(&true) {
+                while let Some(status) = try!(self.status_cli.recv_status()) {
+                    try!(self.fe_sock.send(status, 0));
+                }
+                status_msg = false;
+            }
+            for slot in 0..self.slots.len() {
+                if runner_msgs[slot] {
+                    {
+                        let reply = try!(self.slots[slot].runner_cli.recv_complete());
+                        try!(self.fe_sock.send(reply, 0));
+                    }
+                    self.slots[slot].busy = false;
+                    try!(self.update_heartbeat());
+                    runner_msgs[slot] = false;
+                    let ttl = self.config.read().unwrap().shelf_ttl;
+                    self.collect_shelf(Duration::from_secs(ttl));
                 }
-                try!(self.set_ready());
-                runner_msg = false;
             }
             if fe_msg {
+                // Pop message delimiter
                 try!(self.fe_sock.recv(&mut self.msg, 0));
+                let command = try!(self.fe_sock.recv_string(0));
                 try!(self.fe_sock.recv(&mut self.msg, 0));
-                match self.state {
-                    State::Ready => {
-                        try!(self.runner_cli.send(&self.msg));
-                        {
-                            let reply = try!(self.runner_cli.recv_ack());
-                            try!(self.fe_sock.send(reply, 0));
-                        }
-                        try!(self.set_busy());
-                    }
-                    State::Busy => {
-                        let mut reply: protocol::jobsrv::Job = parse_from_bytes(&self.msg).unwrap();
-                        reply.set_state(protocol::jobsrv::JobState::Rejected);
-                        try!(self.fe_sock.send(&reply.write_to_bytes().unwrap(), 0));
-                    }
+                match command {
+                    Ok(ref command) if command == JOB_DISPATCH => try!(self.dispatch()),
+                    Ok(ref command) if command == JOB_CANCEL => self.cancel(),
+                    Ok(ref command) if command == JOB_PUBLISH => try!(self.publish()),
+                    Ok(ref command) if command == JOB_DISCARD => self.discard(),
+                    _ => warn!("received unknown command from job server, cmd={:?}", command),
                 }
                 fe_msg = false;
             }
         }
     }
 
-    fn set_busy(&mut self) -> Result<()> {
-        try!(self.hb_cli.set_busy());
-        self.state = State::Busy;
+    fn dispatch(&mut self) -> Result<()> {
+        self.jobs_received += 1;
+        match self.slots.iter().position(|slot| !slot.busy) {
+            Some(idx) => {
+                {
+                    let slot = &mut self.slots[idx];
+                    try!(slot.runner_cli.send(&self.msg));
+                    let reply = try!(slot.runner_cli.recv_ack());
+                    try!(self.fe_sock.send(reply, 0));
+                    slot.busy = true;
+                }
+                try!(self.update_heartbeat());
+            }
+            None => {
+                let mut reply: protocol::jobsrv::Job = parse_from_bytes(&self.msg).unwrap();
+                reply.set_state(protocol::jobsrv::JobState::Rejected);
+                try!(self.fe_sock.send(&reply.write_to_bytes().unwrap(), 0));
+                try!(self.update_heartbeat());
+            }
+        }
         Ok(())
     }
 
-    fn set_ready(&mut self) -> Result<()> {
-        try!(self.hb_cli.set_ready());
-        self.state = State::Ready;
+    /// Publish the shelved artifact of a job again in a free slot. The job stays failed, with
+    /// its artifact on the shelf, if every slot is busy.
+    fn publish(&mut self) -> Result<()> {
+        self.jobs_received += 1;
+        match self.slots.iter().position(|slot| !slot.busy) {
+            Some(idx) => {
+                {
+                    let slot = &mut self.slots[idx];
+                    try!(slot.runner_cli.send_publish(&self.msg));
+                    let reply = try!(slot.runner_cli.recv_ack());
+                    try!(self.fe_sock.send(reply, 0));
+                    slot.busy = true;
+                }
+                try!(self.update_heartbeat());
+            }
+            None => {
+                let mut reply: protocol::jobsrv::Job = parse_from_bytes(&self.msg).unwrap();
+                reply.set_state(protocol::jobsrv::JobState::Failed);
+                reply.set_error(net::err(ErrCode::REMOTE_REJECTED, "wk:srv:1"));
+                try!(self.fe_sock.send(&reply.write_to_bytes().unwrap(), 0));
+                try!(self.update_heartbeat());
+            }
+        }
         Ok(())
     }
+
+    fn discard(&mut self) {
+        let cancel: protocol::jobsrv::JobCancel = parse_from_bytes(&self.msg).unwrap();
+        let shelf = Shelf::new(&self.config.read().unwrap().data_path);
+        if let Some(err) = shelf.discard(cancel.get_id()).err() {
+            warn!("unable to discard shelved artifact, job={}, err={}",
+                  cancel.get_id(),
+                  err);
+        }
+    }
+
+    /// Throw away shelved artifacts which have not been published again within `ttl`
+    fn collect_shelf(&self, ttl: Duration) {
+        let shelf = Shelf::new(&self.config.read().unwrap().data_path);
+        if let Some(err) = shelf.gc(ttl).err() {
+            warn!("unable to collect shelved artifacts, err={}", err);
+        }
+    }
+
+    fn cancel(&mut self) {
+        let cancel: protocol::jobsrv::JobCancel = parse_from_bytes(&self.msg).unwrap();
+        // The runner reports the job as Cancelled once its studio has been torn down
+        if !self.slots
+                .iter()
+                .any(|slot| slot.cancellation.cancel(cancel.get_id())) {
+            warn!("received cancellation for a job not being built, job={}",
+                  cancel.get_id());
+        }
+    }
+
+    /// Advertise the number of free slots, or that the worker is busy once none are left
+    fn update_heartbeat(&mut self) -> Result<()> {
+        let free = self.slots.iter().filter(|slot| !slot.busy).count() as u32;
+        if free > 0 {
+            self.hb_cli.set_ready(free, self.jobs_received)
+        } else {
+            self.hb_cli.set_busy(self.jobs_received)
+        }
+    }
 }
 
 impl NetIdent for Server {}
@@ -12,15 +12,31 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use git2;
+use git2::{self, Cred, CredentialType, FetchOptions, ObjectType, Oid, RemoteCallbacks,
+           Repository};
+use git2::build::{CheckoutBuilder, RepoBuilder};
 
-use error::Result;
+use error::{Error, Result};
+
+/// Credentials used to clone a private repository
+pub enum Credentials {
+    /// Access token sent as the password of an HTTPS clone
+    Token(String),
+    /// Path to the private half of an SSH deploy key
+    DeployKey(PathBuf),
+}
 
 pub struct VCS {
     pub vcs_type: String,
     pub data: String,
+    /// Branch, tag or commit SHA to check out, the remote's default branch if empty
+    pub reference: String,
+    pub submodules: bool,
+    pub credentials: Option<Credentials>,
+    /// Allow the `local` type, which clones a path or `file://` URL on the worker itself
+    pub allow_local: bool,
 }
 
 impl VCS {
@@ -28,17 +44,322 @@ impl VCS {
         VCS {
             vcs_type: vcs_type,
             data: data,
+            reference: String::new(),
+            submodules: false,
+            credentials: None,
+            allow_local: false,
         }
     }
 
     pub fn clone(&self, path: &Path) -> Result<()> {
         match self.vcs_type.as_ref() {
-            "git" => {
-                debug!("cloning git repository, url={}, path={:?}", self.data, path);
-                try!(git2::Repository::clone(&self.data, path));
-                Ok(())
+            "git" => self.clone_git(path),
+            "local" if self.allow_local => self.clone_git(path),
+            "local" => Err(Error::LocalVCSDisabled),
+            vcs_type => Err(Error::UnknownVCS(vcs_type.to_string())),
+        }
+    }
+
+    fn clone_git(&self, path: &Path) -> Result<()> {
+        debug!("cloning git repository, url={}, ref={}, path={:?}",
+               self.data,
+               self.reference,
+               path);
+        try!(self.check_url(&self.data));
+        let repo = try!(self.repo_builder(true).clone(&self.data, path));
+        if !self.reference.is_empty() {
+            try!(checkout_ref(&repo, &self.reference));
+        }
+        if self.submodules {
+            try!(self.clone_submodules(&repo, &self.data, path));
+        }
+        Ok(())
+    }
+
+    /// Refuses URLs on the worker itself unless local repositories are allowed, along with
+    /// schemes git can't clone from.
+    fn check_url(&self, url: &str) -> Result<()> {
+        match url_scheme(url) {
+            Some("https") | Some("http") | Some("ssh") | Some("git") => Ok(()),
+            Some("file") | None if self.allow_local => Ok(()),
+            Some("file") | None => Err(Error::LocalVCSDisabled),
+            Some(_) => Err(Error::UnknownVCSUrl(url.to_string())),
+        }
+    }
+
+    fn repo_builder(&self, with_credentials: bool) -> RepoBuilder {
+        let mut callbacks = RemoteCallbacks::new();
+        if let Some(ref credentials) = self.credentials {
+            if with_credentials {
+                callbacks.credentials(move |_, username, allowed| {
+                                          credential(credentials, username, allowed)
+                                      });
+            }
+        }
+        let mut fetch_options = FetchOptions::new();
+        fetch_options.remote_callbacks(callbacks);
+        let mut builder = RepoBuilder::new();
+        builder.fetch_options(fetch_options);
+        builder
+    }
+
+    /// Clones the submodules of the repository at the commits it records, recursively. Their
+    /// URLs go through the same checks as the repository's own, and only submodules on the
+    /// repository's host are cloned with its credentials.
+    fn clone_submodules(&self, repo: &Repository, url: &str, path: &Path) -> Result<()> {
+        for submodule in try!(repo.submodules()) {
+            let sub_url = match submodule.url() {
+                Some(sub_url) => submodule_url(url, sub_url),
+                None => continue,
+            };
+            let sub_path = path.join(submodule.path());
+            let sub_id = match submodule.head_id() {
+                Some(id) => id,
+                None => continue,
+            };
+            debug!("cloning git submodule, url={}, path={:?}", sub_url, sub_path);
+            try!(self.check_url(&sub_url));
+            let same_host = url_host(&sub_url).is_some() &&
+                            url_host(&sub_url) == url_host(&self.data);
+            let sub_repo = try!(self.repo_builder(same_host).clone(&sub_url, &sub_path));
+            try!(checkout_commit(&sub_repo, sub_id));
+            try!(self.clone_submodules(&sub_repo, &sub_url, &sub_path));
+        }
+        Ok(())
+    }
+}
+
+fn credential(credentials: &Credentials,
+              username: Option<&str>,
+              allowed: CredentialType)
+              -> ::std::result::Result<Cred, git2::Error> {
+    match *credentials {
+        Credentials::Token(ref token) if allowed.contains(git2::USER_PASS_PLAINTEXT) => {
+            Cred::userpass_plaintext("x-access-token", token)
+        }
+        Credentials::DeployKey(ref key) if allowed.contains(git2::SSH_KEY) => {
+            Cred::ssh_key(username.unwrap_or("git"), None, key, None)
+        }
+        _ => Err(git2::Error::from_str("repository requires a different kind of credentials")),
+    }
+}
+
+/// Checks out a branch, tag or commit SHA. Branches resolve to the remote's copy, as a fresh
+/// clone only has a local branch for the default one.
+fn checkout_ref(repo: &Repository, reference: &str) -> Result<()> {
+    let object = try!(repo.revparse_single(&format!("origin/{}", reference))
+                          .or_else(|_| repo.revparse_single(reference)));
+    let commit = try!(object.peel(ObjectType::Commit));
+    checkout_commit(repo, commit.id())
+}
+
+fn checkout_commit(repo: &Repository, id: Oid) -> Result<()> {
+    let commit = try!(repo.find_object(id, Some(ObjectType::Commit)));
+    try!(repo.checkout_tree(&commit, Some(CheckoutBuilder::new().force())));
+    try!(repo.set_head_detached(id));
+    Ok(())
+}
+
+/// Returns the scheme of a repository URL, the transport of git's `<transport>::<address>`
+/// syntax, `ssh` for its scp-like `user@host:path` syntax, or `None` for a path on the worker.
+fn url_scheme(url: &str) -> Option<&str> {
+    if let Some(end) = url.find("://") {
+        return Some(&url[..end]);
+    }
+    match url.find("::") {
+        Some(end) if !url[..end].contains('/') => return Some(&url[..end]),
+        _ => (),
+    }
+    match url.find(':') {
+        Some(end) if !url[..end].contains('/') => Some("ssh"),
+        _ => None,
+    }
+}
+
+/// Returns the lowercased host of a remote repository URL, or `None` for a local one.
+fn url_host(url: &str) -> Option<String> {
+    let authority = match url.find("://") {
+        Some(start) => {
+            let rest = &url[start + 3..];
+            &rest[..rest.find('/').unwrap_or(rest.len())]
+        }
+        None if url_scheme(url) == Some("ssh") => &url[..url.find(':').unwrap()],
+        None => return None,
+    };
+    let host = authority.rsplit('@').next().unwrap();
+    let host = if host.starts_with('[') {
+        &host[..host.find(']').map_or(host.len(), |end| end + 1)]
+    } else {
+        &host[..host.find(':').unwrap_or(host.len())]
+    };
+    if host.is_empty() {
+        None
+    } else {
+        Some(host.to_lowercase())
+    }
+}
+
+/// Resolves a submodule's URL, which `.gitmodules` may give relative to its parent's.
+fn submodule_url(parent: &str, url: &str) -> String {
+    if !url.starts_with("./") && !url.starts_with("../") {
+        return url.to_string();
+    }
+    let mut base = parent.trim_right_matches('/').to_string();
+    for part in url.split('/') {
+        match part {
+            "." => (),
+            ".." => {
+                let len = base.rfind('/').unwrap_or(0);
+                base.truncate(len);
             }
-            _ => panic!("Unknown vcs type"),
+            part => {
+                base.push('/');
+                base.push_str(part);
+            }
+        }
+    }
+    base
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::io::{Read, Write};
+
+    use git2::Signature;
+    use tempdir::TempDir;
+
+    use super::*;
+
+    fn commit(repo: &Repository, content: &str) -> git2::Oid {
+        let root = repo.workdir().unwrap().to_path_buf();
+        File::create(root.join("plan.sh"))
+            .unwrap()
+            .write_all(content.as_bytes())
+            .unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("plan.sh")).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let sig = Signature::now("bldr", "bldr@example.com").unwrap();
+        let parent = repo.head()
+            .ok()
+            .and_then(|head| head.target())
+            .map(|id| repo.find_commit(id).unwrap());
+        let parents = parent.iter().collect::<Vec<_>>();
+        repo.commit(Some("HEAD"), &sig, &sig, content, &tree, &parents)
+            .unwrap()
+    }
+
+    fn plan(path: &Path) -> String {
+        let mut content = String::new();
+        File::open(path.join("plan.sh"))
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+        content
+    }
+
+    fn local_vcs(url: &Path) -> VCS {
+        let mut vcs = VCS::new("local".to_string(), format!("file://{}", url.display()));
+        vcs.allow_local = true;
+        vcs
+    }
+
+    #[test]
+    fn clone_local_refs() {
+        let tmp = TempDir::new("vcs").unwrap();
+        let origin = Repository::init(tmp.path().join("origin")).unwrap();
+        let first = commit(&origin, "first");
+        origin
+            .branch("release", &origin.find_commit(first).unwrap(), false)
+            .unwrap();
+        let second = commit(&origin, "second");
+
+        local_vcs(&tmp.path().join("origin"))
+            .clone(&tmp.path().join("head"))
+            .unwrap();
+        assert_eq!(plan(&tmp.path().join("head")), "second");
+
+        let mut vcs = local_vcs(&tmp.path().join("origin"));
+        vcs.reference = "release".to_string();
+        vcs.clone(&tmp.path().join("branch")).unwrap();
+        assert_eq!(plan(&tmp.path().join("branch")), "first");
+
+        vcs.reference = first.to_string();
+        vcs.clone(&tmp.path().join("sha")).unwrap();
+        assert_eq!(plan(&tmp.path().join("sha")), "first");
+
+        vcs.reference = second.to_string();
+        vcs.clone(&tmp.path().join("sha2")).unwrap();
+        assert_eq!(plan(&tmp.path().join("sha2")), "second");
+    }
+
+    #[test]
+    fn clone_local_disabled() {
+        let tmp = TempDir::new("vcs").unwrap();
+        let mut vcs = local_vcs(tmp.path());
+        vcs.allow_local = false;
+        match vcs.clone(&tmp.path().join("src")) {
+            Err(Error::LocalVCSDisabled) => (),
+            _ => panic!("local clones should require the worker to allow them"),
+        }
+    }
+
+    #[test]
+    fn clone_unknown_vcs() {
+        let tmp = TempDir::new("vcs").unwrap();
+        let vcs = VCS::new("svn".to_string(), "svn://example.com/plans".to_string());
+        match vcs.clone(&tmp.path().join("src")) {
+            Err(Error::UnknownVCS(ref vcs_type)) => assert_eq!(vcs_type, "svn"),
+            _ => panic!("unknown VCS types should be an error"),
+        }
+    }
+
+    #[test]
+    fn relative_submodule_url() {
+        assert_eq!(submodule_url("https://github.com/habitat-sh/core-plans.git",
+                                 "../habitat.git"),
+                   "https://github.com/habitat-sh/habitat.git");
+        assert_eq!(submodule_url("https://github.com/habitat-sh/core-plans", "./vendor"),
+                   "https://github.com/habitat-sh/core-plans/vendor");
+        assert_eq!(submodule_url("https://github.com/habitat-sh/core-plans",
+                                 "git@github.com:habitat-sh/habitat.git"),
+                   "git@github.com:habitat-sh/habitat.git");
+    }
+
+    #[test]
+    fn repository_url_checks() {
+        let vcs = VCS::new("git".to_string(),
+                           "https://github.com/habitat-sh/core-plans".to_string());
+        assert!(vcs.check_url("https://github.com/habitat-sh/habitat.git").is_ok());
+        assert!(vcs.check_url("git@github.com:habitat-sh/habitat.git").is_ok());
+        match vcs.check_url("file:///hab/svc/builder-worker/data/1/src") {
+            Err(Error::LocalVCSDisabled) => (),
+            _ => panic!("file URLs should require the worker to allow local clones"),
+        }
+        match vcs.check_url("/hab/svc/builder-worker/data/1/src") {
+            Err(Error::LocalVCSDisabled) => (),
+            _ => panic!("paths should require the worker to allow local clones"),
         }
+        match vcs.check_url("ext::sh -c touch% /tmp/pwned") {
+            Err(Error::UnknownVCSUrl(_)) => (),
+            _ => panic!("unknown schemes should be refused"),
+        }
+
+        let vcs = local_vcs(Path::new("/src"));
+        assert!(vcs.check_url("/hab/svc/builder-worker/data/1/src").is_ok());
+    }
+
+    #[test]
+    fn repository_url_host() {
+        assert_eq!(url_host("https://x-access-token@GitHub.com:443/habitat-sh/habitat.git"),
+                   Some("github.com".to_string()));
+        assert_eq!(url_host("git@github.com:habitat-sh/habitat.git"),
+                   Some("github.com".to_string()));
+        assert_eq!(url_host("ssh://git@[::1]:22/habitat.git"),
+                   Some("[::1]".to_string()));
+        assert_eq!(url_host("file:///hab/svc/builder-worker/data/1/src"), None);
+        assert_eq!(url_host("/hab/svc/builder-worker/data/1/src"), None);
     }
 }
@@ -28,11 +28,22 @@
 //!
 //! This would install the `3.0.1` version of redis.
 //!
+//! Installs may also be pinned with a lockfile which records every fully qualified package
+//! identifier of the install along with its artifact checksum:
+//!
+//! ```bash
+//! $ hab pkg install core/redis --lockfile hab.lock
+//! ```
+//!
+//! If the lockfile exists, unqualified identifiers resolve to their pinned releases and every
+//! artifact must match its pinned checksum. Any packages not yet pinned are added to the lockfile.
+//!
 //! # Internals
 //!
 //! * Download the artifact
-//! * Verify it is un-altered
-//! * Unpack it
+//! * Verify it is un-altered, then unpack it into a staging directory
+//! * Read its transitive dependencies from the verified contents and stage the missing ones
+//! * Move every staged package into the package path, dependencies first
 //!
 
 use std::fs;
@@ -44,7 +55,8 @@ use hcore;
 use hcore::fs::{am_i_root, cache_key_path};
 use hcore::crypto::{artifact, SigKeyPair};
 use hcore::crypto::keys::parse_name_with_rev;
-use hcore::package::{Identifiable, PackageArchive, PackageIdent, Target, PackageInstall};
+use hcore::package::{Identifiable, Lockfile, PackageArchive, PackageIdent, Target,
+                     PackageInstall, StagedPackage};
 use hyper::status::StatusCode;
 
 use error::{Error, Result};
@@ -63,7 +75,8 @@ pub fn start<P1, P2>(ui: &mut UI,
                      version: &str,
                      fs_root_path: &P1,
                      cache_artifact_path: &P2,
-                     ignore_target: bool)
+                     ignore_target: bool,
+                     lockfile: Option<&mut Lockfile>)
                      -> Result<PackageIdent>
     where P1: AsRef<Path> + ?Sized,
           P2: AsRef<Path> + ?Sized
@@ -79,19 +92,35 @@ pub fn start<P1, P2>(ui: &mut UI,
     let cache_key_path = cache_key_path(Some(fs_root_path.as_ref()));
     debug!("install cache_key_path: {}", cache_key_path.display());
 
-    let task = try!(InstallTask::new(url,
-                                     product,
-                                     version,
-                                     fs_root_path.as_ref(),
-                                     cache_artifact_path.as_ref(),
-                                     &cache_key_path,
-                                     ignore_target));
-
-    if Path::new(ident_or_archive).is_file() {
-        task.from_artifact(ui, &Path::new(ident_or_archive))
-    } else {
-        task.from_ident(ui, PackageIdent::from_str(ident_or_archive)?, channel)
+    let (ident, locked) = {
+        let task = try!(InstallTask::new(url,
+                                         product,
+                                         version,
+                                         fs_root_path.as_ref(),
+                                         cache_artifact_path.as_ref(),
+                                         &cache_key_path,
+                                         ignore_target,
+                                         lockfile.as_ref().map(|l| &**l)));
+
+        let ident = if Path::new(ident_or_archive).is_file() {
+            task.from_artifact(ui, &Path::new(ident_or_archive))?
+        } else {
+            task.from_ident(ui, PackageIdent::from_str(ident_or_archive)?, channel)?
+        };
+        let locked = if lockfile.is_some() {
+            task.locked_packages(ui, &ident)?
+        } else {
+            vec![]
+        };
+        (ident, locked)
+    };
+
+    if let Some(lockfile) = lockfile {
+        for (locked_ident, checksum) in locked {
+            lockfile.insert(&locked_ident, checksum)?;
+        }
     }
+    Ok(ident)
 }
 
 struct InstallTask<'a> {
@@ -100,6 +129,7 @@ struct InstallTask<'a> {
     cache_artifact_path: &'a Path,
     cache_key_path: &'a Path,
     ignore_target: bool,
+    lockfile: Option<&'a Lockfile>,
 }
 
 impl<'a> InstallTask<'a> {
@@ -109,7 +139,8 @@ impl<'a> InstallTask<'a> {
                fs_root_path: &'a Path,
                cache_artifact_path: &'a Path,
                cache_key_path: &'a Path,
-               ignore_target: bool)
+               ignore_target: bool,
+               lockfile: Option<&'a Lockfile>)
                -> Result<Self> {
         Ok(InstallTask {
                depot_client: try!(Client::new(url, product, version, Some(fs_root_path))),
@@ -117,6 +148,7 @@ impl<'a> InstallTask<'a> {
                cache_artifact_path: cache_artifact_path,
                cache_key_path: cache_key_path,
                ignore_target: ignore_target,
+               lockfile: lockfile,
            })
     }
 
@@ -128,7 +160,13 @@ impl<'a> InstallTask<'a> {
         try!(ui.begin(format!("Installing {}", &ident)));
         let mut ident = ident;
         if !ident.fully_qualified() {
-            ident = self.fetch_latest_pkg_ident_for(&ident, channel)?;
+            ident = match self.pinned_ident_for(&ident)? {
+                Some(pinned) => {
+                    debug!("Using {} pinned by lockfile for {}", &pinned, &ident);
+                    pinned
+                }
+                None => self.fetch_latest_pkg_ident_for(&ident, channel)?,
+            };
         }
         if try!(self.is_package_installed(&ident)) {
             try!(ui.status(Status::Using, &ident));
@@ -142,7 +180,12 @@ impl<'a> InstallTask<'a> {
     }
 
     pub fn from_artifact(&self, ui: &mut UI, artifact_path: &Path) -> Result<PackageIdent> {
-        let ident = try!(PackageArchive::new(artifact_path).ident());
+        // Nothing is read from the artifact until its signatures check out, so its identifier
+        // comes from the staged contents
+        try!(self.fetch_signer_keys(ui, artifact_path));
+        let staged = try!(PackageArchive::new(artifact_path)
+                              .stage_verified(self.cache_key_path, Some(self.fs_root_path)));
+        let ident = staged.ident().clone();
         if try!(self.is_package_installed(&ident)) {
             try!(ui.status(Status::Using, &ident));
             try!(ui.end(format!("Install of {} complete with {} new packages installed.",
@@ -150,10 +193,11 @@ impl<'a> InstallTask<'a> {
                                 0)));
             return Ok(ident);
         }
+        try!(self.validate_target(&staged));
         try!(self.cache_artifact(&ident, artifact_path));
         let src_path = artifact_path.parent().unwrap();
 
-        self.install_package(ui, ident, Some(src_path))
+        self.install_staged(ui, staged, Some(src_path))
     }
 
     fn install_package(&self,
@@ -161,21 +205,33 @@ impl<'a> InstallTask<'a> {
                        ident: PackageIdent,
                        src_path: Option<&Path>)
                        -> Result<PackageIdent> {
-        let mut artifact = try!(self.get_cached_artifact(ui, ident.clone(), src_path));
-        let mut artifacts: Vec<PackageArchive> = Vec::new();
+        let staged = try!(self.stage_artifact(ui, &ident, src_path));
+        self.install_staged(ui, staged, src_path)
+    }
 
-        for ident in try!(artifact.tdeps()) {
-            if try!(self.is_package_installed(&ident)) {
-                try!(ui.status(Status::Using, &ident));
+    /// Stages every transitive dependency of a staged package which is not installed yet, then
+    /// installs them all, dependencies first. Nothing is installed unless every package was
+    /// verified.
+    fn install_staged(&self,
+                      ui: &mut UI,
+                      staged: StagedPackage,
+                      src_path: Option<&Path>)
+                      -> Result<PackageIdent> {
+        let ident = staged.ident().clone();
+        let mut packages = Vec::new();
+
+        for dep in try!(staged.package().tdeps()) {
+            if try!(self.is_package_installed(&dep)) {
+                try!(ui.status(Status::Using, &dep));
             } else {
-                artifacts.push(try!(self.get_cached_artifact(ui, ident, src_path)));
+                packages.push(try!(self.stage_artifact(ui, &dep, src_path)));
             }
         }
-        artifacts.push(artifact);
+        packages.push(staged);
 
-        let num_installed = artifacts.len();
-        for mut artifact in artifacts {
-            try!(self.extract_artifact(ui, &mut artifact));
+        let num_installed = packages.len();
+        for package in packages {
+            try!(self.commit_package(ui, package));
         }
         try!(ui.end(format!("Install of {} complete with {} new packages installed.",
                             &ident,
@@ -183,6 +239,24 @@ impl<'a> InstallTask<'a> {
         Ok(ident)
     }
 
+    /// Verifies and unpacks the artifact of a package into a staging directory, checking that
+    /// its verified contents are the package which was asked for.
+    fn stage_artifact(&self,
+                      ui: &mut UI,
+                      ident: &PackageIdent,
+                      src_path: Option<&Path>)
+                      -> Result<StagedPackage> {
+        let artifact = try!(self.get_cached_artifact(ui, ident.clone(), src_path));
+        let staged = try!(artifact.stage_verified(self.cache_key_path, Some(self.fs_root_path)));
+        if staged.ident() != ident {
+            return Err(Error::ArtifactIdentMismatch((artifact.file_name(),
+                                                     staged.ident().to_string(),
+                                                     ident.to_string())));
+        }
+        try!(self.validate_target(&staged));
+        Ok(staged)
+    }
+
     fn get_cached_artifact(&self,
                            ui: &mut UI,
                            ident: PackageIdent,
@@ -207,14 +281,43 @@ impl<'a> InstallTask<'a> {
             }
         }
 
-        let mut artifact = PackageArchive::new(try!(self.cached_artifact_path(&ident)));
-        try!(self.verify_artifact(ui, &ident, &mut artifact));
+        let artifact = PackageArchive::new(try!(self.cached_artifact_path(&ident)));
+        try!(self.fetch_signer_keys(ui, &artifact.path));
+        try!(self.verify_locked_checksum(&ident, &artifact));
         Ok(artifact)
     }
 
-    fn extract_artifact(&self, ui: &mut UI, artifact: &mut PackageArchive) -> Result<()> {
-        try!(artifact.unpack(Some(self.fs_root_path)));
-        try!(ui.status(Status::Installed, try!(artifact.ident())));
+    /// Returns every fully qualified package identifier in the transitive closure of the given
+    /// installed package along with the checksum of its artifact, fetching any artifacts which
+    /// are no longer in the artifact cache.
+    fn locked_packages(&self,
+                       ui: &mut UI,
+                       ident: &PackageIdent)
+                       -> Result<Vec<(PackageIdent, String)>> {
+        let mut idents = try!(try!(PackageInstall::load(ident, Some(self.fs_root_path))).tdeps());
+        idents.push(ident.clone());
+
+        let mut locked = Vec::with_capacity(idents.len());
+        for ident in idents {
+            let mut artifact = try!(self.get_cached_artifact(ui, ident.clone(), None));
+            // Only pin artifacts which are genuine, and only trust their metadata once they are
+            try!(artifact.verify(&self.cache_key_path));
+            let artifact_ident = try!(artifact.ident());
+            if artifact_ident != ident {
+                return Err(Error::ArtifactIdentMismatch((artifact.file_name(),
+                                                         artifact_ident.to_string(),
+                                                         ident.to_string())));
+            }
+            locked.push((ident, try!(artifact.checksum())));
+        }
+        Ok(locked)
+    }
+
+    fn commit_package(&self, ui: &mut UI, staged: StagedPackage) -> Result<()> {
+        let ident = staged.ident().clone();
+        let (nwr, _) = try!(staged.commit());
+        info!("Verified {} signed by {}", &ident, &nwr);
+        try!(ui.status(Status::Installed, ident));
         Ok(())
     }
 
@@ -240,6 +343,31 @@ impl<'a> InstallTask<'a> {
         Ok(self.cache_artifact_path.join(name))
     }
 
+    fn pinned_ident_for(&self, ident: &PackageIdent) -> Result<Option<PackageIdent>> {
+        match self.lockfile {
+            Some(lockfile) => Ok(lockfile.pinned_ident_for(ident)?),
+            None => Ok(None),
+        }
+    }
+
+    fn verify_locked_checksum(&self,
+                              ident: &PackageIdent,
+                              artifact: &PackageArchive)
+                              -> Result<()> {
+        let expected = match self.lockfile.and_then(|l| l.checksum_for(ident)) {
+            Some(checksum) => checksum,
+            None => return Ok(()),
+        };
+        let computed = try!(artifact.checksum());
+        if computed != expected {
+            return Err(Error::LockedChecksumMismatch((ident.to_string(),
+                                                      expected.to_string(),
+                                                      computed)));
+        }
+        debug!("Verified {} against lockfile checksum {}", ident, expected);
+        Ok(())
+    }
+
     fn fetch_latest_pkg_ident_for(&self,
                                   ident: &PackageIdent,
                                   channel: Option<&str>)
@@ -301,33 +429,23 @@ impl<'a> InstallTask<'a> {
         Ok(())
     }
 
-    fn verify_artifact(&self,
-                       ui: &mut UI,
-                       ident: &PackageIdent,
-                       artifact: &mut PackageArchive)
-                       -> Result<()> {
-        let artifact_ident = try!(artifact.ident());
-        if ident != &artifact_ident {
-            return Err(Error::ArtifactIdentMismatch((artifact.file_name(),
-                                                     artifact_ident.to_string(),
-                                                     ident.to_string())));
+    /// Makes sure the public keys of every signer of an artifact are in the key cache. The
+    /// signatures themselves are verified while the artifact is unpacked.
+    fn fetch_signer_keys(&self, ui: &mut UI, artifact_path: &Path) -> Result<()> {
+        for nwr in try!(artifact::artifact_signers(&artifact_path)) {
+            if let Err(_) = SigKeyPair::get_public_key_path(&nwr, self.cache_key_path) {
+                try!(self.fetch_origin_key(ui, &nwr));
+            }
         }
+        Ok(())
+    }
 
+    fn validate_target(&self, staged: &StagedPackage) -> Result<()> {
         if self.ignore_target {
             info!("Skipping target validation for this package.");
-        } else {
-            let artifact_target = try!(artifact.target());
-            try!(artifact_target.validate());
-        }
-
-
-        let nwr = try!(artifact::artifact_signer(&artifact.path));
-        if let Err(_) = SigKeyPair::get_public_key_path(&nwr, self.cache_key_path) {
-            try!(self.fetch_origin_key(ui, &nwr));
+            return Ok(());
         }
-
-        try!(artifact.verify(&self.cache_key_path));
-        info!("Verified {} signed by {}", ident, &nwr);
+        try!(try!(staged.package().target()).validate());
         Ok(())
     }
 }
@@ -37,6 +37,7 @@ pub enum Error {
     HabitatCore(hcore::Error),
     /// Occurs when making lower level IO calls.
     IO(io::Error),
+    LockedChecksumMismatch((String, String, String)),
     RootRequired,
     StrFromUtf8Error(str::Utf8Error),
     StringFromUtf8Error(string::FromUtf8Error),
@@ -63,6 +64,13 @@ impl fmt::Display for Error {
             Error::FileNameError => format!("Failed to extract a filename"),
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::IO(ref err) => format!("{}", err),
+            Error::LockedChecksumMismatch((ref i, ref e, ref c)) => {
+                format!("Artifact checksum for {} does not match the lockfile (expected: {}, \
+                         computed: {})",
+                        i,
+                        e,
+                        c)
+            }
             Error::RootRequired => {
                 "Root or administrator permissions required to complete operation".to_string()
             }
@@ -86,6 +94,7 @@ impl error::Error for Error {
             Error::FileNameError => "Failed to extract a filename from a path",
             Error::HabitatCore(ref err) => err.description(),
             Error::IO(ref err) => err.description(),
+            Error::LockedChecksumMismatch((_, _, _)) => "Artifact checksum does not match the lockfile",
             Error::RootRequired => "Root or administrator permissions required to complete operation",
             Error::StrFromUtf8Error(_) => "Failed to convert a string as UTF-8",
             Error::StringFromUtf8Error(_) => "Failed to convert a string as UTF-8",
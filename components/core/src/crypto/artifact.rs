@@ -12,19 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cell::RefCell;
 use std::fs::File;
 use std::io;
 use std::io::prelude::*;
 use std::io::{BufReader, BufWriter};
 use std::path::Path;
+use std::rc::Rc;
 
 use base64;
 use sodiumoxide::crypto::sign;
 
 use error::{Error, Result};
-use super::{HART_FORMAT_VERSION, SIG_HASH_TYPE, SigKeyPair};
-use super::hash;
+use super::{HART_FORMAT_VERSION, HART_MULTI_SIG_FORMAT_VERSION, SIG_HASH_TYPE, SigKeyPair};
+use super::hash::{self, Blake2bHasher};
 use super::keys::parse_name_with_rev;
+use super::trust::TrustPolicy;
 
 /// Generate and sign a package
 pub fn sign<P1: ?Sized, P2: ?Sized>(src: &P1, dst: &P2, pair: &SigKeyPair) -> Result<()>
@@ -48,51 +51,92 @@ pub fn sign<P1: ?Sized, P2: ?Sized>(src: &P1, dst: &P2, pair: &SigKeyPair) -> Re
     Ok(())
 }
 
-/// return a BufReader to the .tar bytestream, skipping the signed header
-pub fn get_archive_reader<P: AsRef<Path>>(src: &P) -> Result<BufReader<File>> {
+/// Add a signature to an already signed artifact, writing a multi-signature artifact to `dst`.
+/// The payload is hashed again rather than trusting the existing signatures, so the new
+/// signature only vouches for the payload as it is on disk.
+pub fn countersign<P1: ?Sized, P2: ?Sized>(src: &P1, dst: &P2, pair: &SigKeyPair) -> Result<()>
+    where P1: AsRef<Path>,
+          P2: AsRef<Path>
+{
     let f = try!(File::open(src));
-    let mut your_format_version = String::new();
-    let mut your_key_name = String::new();
-    let mut your_hash_type = String::new();
-    let mut your_signature_raw = String::new();
-    let mut empty_line = String::new();
-
     let mut reader = BufReader::new(f);
-    if try!(reader.read_line(&mut your_format_version)) <= 0 {
-        return Err(Error::CryptoError("Can't read format version".to_string()));
+    let header = try!(read_header(&mut reader));
+    if header.signatures
+           .iter()
+           .any(|s| s.key_name == pair.name_with_rev()) {
+        let msg = format!("Artifact is already signed with {}", pair.name_with_rev());
+        return Err(Error::CryptoError(msg));
     }
-    if try!(reader.read_line(&mut your_key_name)) <= 0 {
-        return Err(Error::CryptoError("Can't read keyname".to_string()));
-    }
-    if try!(reader.read_line(&mut your_hash_type)) <= 0 {
-        return Err(Error::CryptoError("Can't read hash type".to_string()));
-    }
-    if try!(reader.read_line(&mut your_signature_raw)) <= 0 {
-        return Err(Error::CryptoError("Can't read signature".to_string()));
-    }
-    if try!(reader.read_line(&mut empty_line)) <= 0 {
-        return Err(Error::CryptoError("Can't end of header".to_string()));
+    let hash = hash::hash_reader(&mut reader)?;
+    debug!("Payload hash for {} = {}", src.as_ref().display(), &hash);
+
+    let signature = sign::sign(&hash.as_bytes(), try!(pair.secret()));
+    let output_file = try!(File::create(dst));
+    let mut writer = BufWriter::new(&output_file);
+    try!(write!(writer, "{}\n{}\n", HART_MULTI_SIG_FORMAT_VERSION, SIG_HASH_TYPE));
+    for sig in header.signatures.iter() {
+        try!(write!(writer, "{} {}\n", sig.key_name, sig.signature_raw));
     }
+    try!(write!(writer,
+                "{} {}\n\n",
+                pair.name_with_rev(),
+                base64::encode(&signature)));
+    let f = try!(File::open(src));
+    let mut reader = BufReader::new(f);
+    let _ = try!(read_header(&mut reader));
+    try!(io::copy(&mut reader, &mut writer));
+    Ok(())
+}
+
+/// return a BufReader to the .tar bytestream, skipping the signed header
+pub fn get_archive_reader<P: AsRef<Path>>(src: &P) -> Result<BufReader<File>> {
+    let f = try!(File::open(src));
+    let mut reader = BufReader::new(f);
+    let _ = try!(read_header(&mut reader));
     Ok(reader)
 }
 
 pub struct ArtifactHeader {
     pub format_version: String,
-    pub key_name: String,
     pub hash_type: String,
-    pub signature_raw: String,
+    pub signatures: Vec<ArtifactSignature>,
 }
 
 impl ArtifactHeader {
     pub fn new(format_version: String,
-               key_name: String,
                hash_type: String,
-               signature_raw: String)
+               signatures: Vec<ArtifactSignature>)
                -> ArtifactHeader {
         ArtifactHeader {
             format_version: format_version,
-            key_name: key_name,
             hash_type: hash_type,
+            signatures: signatures,
+        }
+    }
+
+    /// The name with revision of the key which made the first signature, which is always the
+    /// origin key of the artifact.
+    ///
+    /// # Failures
+    ///
+    /// * If the header carries no signatures
+    pub fn key_name(&self) -> Result<&str> {
+        match self.signatures.first() {
+            Some(sig) => Ok(&sig.key_name),
+            None => Err(Error::CryptoError("Corrupt payload, no signatures found".to_string())),
+        }
+    }
+}
+
+pub struct ArtifactSignature {
+    pub key_name: String,
+    pub signature_raw: String,
+}
+
+impl ArtifactSignature {
+    pub fn new(key_name: String, signature_raw: String) -> ArtifactSignature {
+        ArtifactSignature {
+            key_name: key_name,
             signature_raw: signature_raw,
         }
     }
@@ -105,158 +149,249 @@ pub fn get_artifact_header<P: ?Sized>(src: &P) -> Result<ArtifactHeader>
     where P: AsRef<Path>
 {
     let f = try!(File::open(src));
-    let mut your_format_version = String::new();
-    let mut your_key_name = String::new();
-    let mut your_hash_type = String::new();
-    let mut your_signature_raw = String::new();
-    let mut empty_line = String::new();
-
     let mut reader = BufReader::new(f);
-    if try!(reader.read_line(&mut your_format_version)) <= 0 {
-        return Err(Error::CryptoError("Can't read format version".to_string()));
-    }
-    if try!(reader.read_line(&mut your_key_name)) <= 0 {
-        return Err(Error::CryptoError("Can't read keyname".to_string()));
-    }
-    if try!(reader.read_line(&mut your_hash_type)) <= 0 {
-        return Err(Error::CryptoError("Can't read hash type".to_string()));
-    }
-    if try!(reader.read_line(&mut your_signature_raw)) <= 0 {
-        return Err(Error::CryptoError("Can't read signature".to_string()));
-    }
-    if try!(reader.read_line(&mut empty_line)) <= 0 {
-        return Err(Error::CryptoError("Can't end of header".to_string()));
-    }
-    let your_format_version = your_format_version.trim().to_string();
-    let your_key_name = your_key_name.trim().to_string();
-    let your_hash_type = your_hash_type.trim().to_string();
-    let your_signature_raw = your_signature_raw.trim().to_string();
-
-    Ok(ArtifactHeader::new(your_format_version,
-                           your_key_name,
-                           your_hash_type,
-                           your_signature_raw))
+    read_header(&mut reader)
 }
 
 /// verify the crypto signature of a .hart file
+///
+/// Every signature in the header must verify against a public key in the key cache and the
+/// local trust policy, if any, must be satisfied. On success the name with revision of the origin
+/// key is returned along with the verified payload hash.
 pub fn verify<P1: ?Sized, P2: ?Sized>(src: &P1, cache_key_path: &P2) -> Result<(String, String)>
     where P1: AsRef<Path>,
           P2: AsRef<Path>
 {
     let f = try!(File::open(src));
-    let mut reader = BufReader::new(f);
+    let reader = try!(ArtifactReader::new(f, cache_key_path));
+    reader.finish()
+}
 
-    let _ = {
-        let mut buffer = String::new();
-        match reader.read_line(&mut buffer) {
-            Ok(0) => {
-                return Err(Error::CryptoError("Corrupt payload, can't read format version"
+/// A reader over the payload of a signed Habitat artifact which verifies the artifact in the
+/// same pass as the payload is consumed, for example while it is being copied.
+///
+/// The header and its signatures are checked when the reader is created, and the payload is
+/// hashed as it is read. Nothing read from an `ArtifactReader` should be trusted until `finish`
+/// returns successfully. Clones share the same underlying stream, so a clone can be handed to a
+/// consumer which takes ownership of its reader while the original is kept to call `finish`.
+pub struct ArtifactReader<R: Read> {
+    inner: Rc<RefCell<PayloadReader<R>>>,
+}
+
+struct PayloadReader<R: Read> {
+    reader: BufReader<R>,
+    hasher: Option<Blake2bHasher>,
+    key_name: String,
+    expected_hash: String,
+}
+
+impl<R: Read> ArtifactReader<R> {
+    /// Read and check the header of an artifact stream, leaving the stream positioned at the
+    /// start of the payload.
+    ///
+    /// # Failures
+    ///
+    /// * If the header is missing or malformed
+    /// * If any signature does not verify against a public key in the key cache
+    /// * If the local trust policy is not satisfied
+    pub fn new<P: ?Sized>(src: R, cache_key_path: &P) -> Result<Self>
+        where P: AsRef<Path>
+    {
+        let mut reader = BufReader::new(src);
+        let header = try!(read_header(&mut reader));
+        let expected_hash = try!(verify_signatures(&header, cache_key_path));
+        let payload = PayloadReader {
+            reader: reader,
+            hasher: Some(Blake2bHasher::new()),
+            key_name: try!(header.key_name()).to_string(),
+            expected_hash: expected_hash,
+        };
+        Ok(ArtifactReader { inner: Rc::new(RefCell::new(payload)) })
+    }
+
+    /// Read any of the payload left unconsumed and compare the payload's hash with the signed
+    /// hash. On success the name with revision of the origin key is returned along with the
+    /// verified payload hash.
+    pub fn finish(&self) -> Result<(String, String)> {
+        let mut payload = self.inner.borrow_mut();
+        try!(io::copy(&mut *payload, &mut io::sink()));
+        let computed_hash = match payload.hasher.take() {
+            Some(hasher) => hasher.finalize(),
+            None => {
+                return Err(Error::CryptoError("Artifact verification has already finished"
                                                   .to_string()))
             }
-            Ok(_) => {
-                if buffer.trim() != HART_FORMAT_VERSION {
-                    let msg = format!("Unsupported format version: {}", &buffer.trim());
-                    return Err(Error::CryptoError(msg));
-                }
-            }
-            Err(e) => return Err(Error::from(e)),
+        };
+        if computed_hash == payload.expected_hash {
+            Ok((payload.key_name.clone(), computed_hash))
+        } else {
+            let msg = format!("Habitat artifact is invalid, \
+                              hashes don't match (expected: {}, computed: {})",
+                              payload.expected_hash,
+                              computed_hash);
+            Err(Error::CryptoError(msg))
+        }
+    }
+}
+
+impl<R: Read> Clone for ArtifactReader<R> {
+    fn clone(&self) -> Self {
+        ArtifactReader { inner: self.inner.clone() }
+    }
+}
+
+impl<R: Read> Read for ArtifactReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.inner.borrow_mut().read(buf)
+    }
+}
 
+impl<R: Read> Read for PayloadReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let bytes_read = try!(self.reader.read(buf));
+        if let Some(ref mut hasher) = self.hasher {
+            hasher.update(&buf[0..bytes_read]);
+        }
+        Ok(bytes_read)
+    }
+}
+
+/// Check every signature of an artifact header against the key cache and the local trust
+/// policy, returning the signed payload hash.
+fn verify_signatures<P: ?Sized>(header: &ArtifactHeader, cache_key_path: &P) -> Result<String>
+    where P: AsRef<Path>
+{
+    let policy = try!(TrustPolicy::load(cache_key_path));
+    let mut expected_hash: Option<String> = None;
+    for sig in header.signatures.iter() {
+        try!(policy.check_revoked(&sig.key_name));
+        let pair = try!(SigKeyPair::get_pair_for(&sig.key_name, cache_key_path));
+        let signature = try!(decode_signature(&sig.signature_raw));
+        let signed_hash = match sign::verify(signature.as_slice(), try!(pair.public())) {
+            Ok(signed_data) => {
+                try!(String::from_utf8(signed_data)
+                    .map_err(|_| Error::CryptoError("Error parsing artifact signature".to_string())))
+            }
+            Err(_) => return Err(Error::CryptoError("Verification failed".to_string())),
         };
-        buffer.trim().to_string()
-    };
-    let pair = {
+        match expected_hash {
+            Some(ref hash) if *hash != signed_hash => {
+                let msg = format!("Habitat artifact is invalid, signature from {} does not \
+                                  match the other signatures",
+                                  &sig.key_name);
+                return Err(Error::CryptoError(msg));
+            }
+            Some(_) => (),
+            None => expected_hash = Some(signed_hash),
+        }
+    }
+    try!(policy.check_required_signers(&header));
+    Ok(expected_hash.unwrap())
+}
+
+pub fn artifact_signer<P: AsRef<Path>>(src: &P) -> Result<String> {
+    let header = try!(get_artifact_header(src.as_ref()));
+    Ok(try!(header.key_name()).to_string())
+}
+
+/// Returns the names with revision of every key which signed the artifact, starting with the
+/// origin key.
+pub fn artifact_signers<P: AsRef<Path>>(src: &P) -> Result<Vec<String>> {
+    let header = try!(get_artifact_header(src.as_ref()));
+    Ok(header.signatures.into_iter().map(|s| s.key_name).collect())
+}
+
+fn read_header<R: BufRead>(reader: &mut R) -> Result<ArtifactHeader> {
+    let format_version = {
         let mut buffer = String::new();
         if try!(reader.read_line(&mut buffer)) <= 0 {
-            return Err(Error::CryptoError("Corrupt payload, can't read origin key name"
+            return Err(Error::CryptoError("Corrupt payload, can't read format version"
                                               .to_string()));
         }
-        try!(SigKeyPair::get_pair_for(buffer.trim(), cache_key_path))
+        buffer.trim().to_string()
     };
-    let _ = {
-        let mut buffer = String::new();
-        match reader.read_line(&mut buffer) {
-            Ok(0) => {
-                return Err(Error::CryptoError("Corrupt payload, can't read hash type".to_string()))
+    if format_version == HART_FORMAT_VERSION {
+        let key_name = try!(read_key_name(reader));
+        let hash_type = try!(read_hash_type(reader));
+        let signature_raw = {
+            let mut buffer = String::new();
+            if try!(reader.read_line(&mut buffer)) <= 0 {
+                return Err(Error::CryptoError("Corrupt payload, can't read signature"
+                                                  .to_string()));
             }
-            Ok(_) => {
-                if buffer.trim() != SIG_HASH_TYPE {
-                    let msg = format!("Unsupported signature type: {}", &buffer.trim());
-                    return Err(Error::CryptoError(msg));
-                }
+            try!(decode_signature(buffer.trim()));
+            buffer.trim().to_string()
+        };
+        let _ = {
+            let mut buffer = String::new();
+            if try!(reader.read_line(&mut buffer)) <= 0 {
+                return Err(Error::CryptoError("Corrupt payload, can't find end of header"
+                                                  .to_string()));
             }
-            Err(e) => return Err(Error::from(e)),
         };
-    };
-    let signature = {
-        let mut buffer = String::new();
-        match reader.read_line(&mut buffer) {
-            Ok(0) => {
-                return Err(Error::CryptoError("Corrupt payload, can't read signature".to_string()))
+        Ok(ArtifactHeader::new(format_version,
+                               hash_type,
+                               vec![ArtifactSignature::new(key_name, signature_raw)]))
+    } else if format_version == HART_MULTI_SIG_FORMAT_VERSION {
+        let hash_type = try!(read_hash_type(reader));
+        let mut signatures = Vec::new();
+        loop {
+            let mut buffer = String::new();
+            if try!(reader.read_line(&mut buffer)) <= 0 {
+                return Err(Error::CryptoError("Corrupt payload, can't find end of header"
+                                                  .to_string()));
             }
-            Ok(_) => {
-                try!(base64::decode(buffer.trim())
-                    .map_err(|e| Error::CryptoError(format!("Can't decode signature: {}", e))))
+            if buffer.trim().is_empty() {
+                break;
             }
-            Err(e) => return Err(Error::from(e)),
-        }
-    };
-    let _ = {
-        let mut buffer = String::new();
-        if try!(reader.read_line(&mut buffer)) <= 0 {
-            return Err(Error::CryptoError("Corrupt payload, can't find end of header".to_string()));
+            let mut parts = buffer.trim().splitn(2, ' ');
+            let key_name = parts.next().unwrap_or("");
+            try!(parse_name_with_rev(key_name));
+            let signature_raw = match parts.next() {
+                Some(s) => s.trim(),
+                None => {
+                    return Err(Error::CryptoError("Corrupt payload, can't read signature"
+                                                      .to_string()))
+                }
+            };
+            try!(decode_signature(signature_raw));
+            signatures.push(ArtifactSignature::new(key_name.to_string(),
+                                                   signature_raw.to_string()));
         }
-    };
-    let expected_hash = match sign::verify(signature.as_slice(), try!(pair.public())) {
-        Ok(signed_data) => {
-            try!(String::from_utf8(signed_data)
-                .map_err(|_| Error::CryptoError("Error parsing artifact signature".to_string())))
+        if signatures.is_empty() {
+            return Err(Error::CryptoError("Corrupt payload, no signatures found".to_string()));
         }
-        Err(_) => return Err(Error::CryptoError("Verification failed".to_string())),
-    };
-    let computed_hash = hash::hash_reader(&mut reader)?;
-    if computed_hash == expected_hash {
-        Ok((pair.name_with_rev(), expected_hash))
+        Ok(ArtifactHeader::new(format_version, hash_type, signatures))
     } else {
-        let msg = format!("Habitat artifact is invalid, \
-                          hashes don't match (expected: {}, computed: {})",
-                          expected_hash,
-                          computed_hash);
+        let msg = format!("Unsupported format version: {}", &format_version);
         Err(Error::CryptoError(msg))
     }
 }
 
-pub fn artifact_signer<P: AsRef<Path>>(src: &P) -> Result<String> {
-    let f = try!(File::open(src));
-    let mut reader = BufReader::new(f);
+fn read_key_name<R: BufRead>(reader: &mut R) -> Result<String> {
+    let mut buffer = String::new();
+    if try!(reader.read_line(&mut buffer)) <= 0 {
+        return Err(Error::CryptoError("Corrupt payload, can't read origin key name".to_string()));
+    }
+    try!(parse_name_with_rev(buffer.trim()));
+    Ok(buffer.trim().to_string())
+}
 
-    let _ = {
-        let mut buffer = String::new();
-        match reader.read_line(&mut buffer) {
-            Ok(0) => {
-                return Err(Error::CryptoError("Corrupt payload, can't read format version"
-                                                  .to_string()))
-            }
-            Ok(_) => {
-                if buffer.trim() != HART_FORMAT_VERSION {
-                    let msg = format!("Unsupported format version: {}", &buffer.trim());
-                    return Err(Error::CryptoError(msg));
-                }
-            }
-            Err(e) => return Err(Error::from(e)),
+fn read_hash_type<R: BufRead>(reader: &mut R) -> Result<String> {
+    let mut buffer = String::new();
+    if try!(reader.read_line(&mut buffer)) <= 0 {
+        return Err(Error::CryptoError("Corrupt payload, can't read hash type".to_string()));
+    }
+    if buffer.trim() != SIG_HASH_TYPE {
+        let msg = format!("Unsupported signature type: {}", &buffer.trim());
+        return Err(Error::CryptoError(msg));
+    }
+    Ok(buffer.trim().to_string())
+}
 
-        };
-        buffer.trim().to_string()
-    };
-    let name_with_rev = {
-        let mut buffer = String::new();
-        if try!(reader.read_line(&mut buffer)) <= 0 {
-            return Err(Error::CryptoError("Corrupt payload, can't read origin key name"
-                                              .to_string()));
-        }
-        try!(parse_name_with_rev(buffer.trim()));
-        buffer.trim().to_string()
-    };
-    Ok(name_with_rev)
+fn decode_signature(signature_raw: &str) -> Result<Vec<u8>> {
+    base64::decode(signature_raw)
+        .map_err(|e| Error::CryptoError(format!("Can't decode signature: {}", e)))
 }
 
 #[cfg(test)]
@@ -267,9 +402,11 @@ mod test {
     use tempdir::TempDir;
 
     use super::*;
-    use super::super::{HART_FORMAT_VERSION, SIG_HASH_TYPE, SigKeyPair};
+    use super::super::{HART_FORMAT_VERSION, HART_MULTI_SIG_FORMAT_VERSION, SIG_HASH_TYPE,
+                       SigKeyPair};
     use super::super::test_support::*;
     use super::super::keys::parse_name_with_rev;
+    use super::super::trust::TRUST_POLICY_FILE;
 
     #[test]
     fn sign_and_verify() {
@@ -282,6 +419,14 @@ mod test {
         assert!(true);
     }
 
+    #[test]
+    fn key_name_without_signatures() {
+        let header = ArtifactHeader::new(HART_MULTI_SIG_FORMAT_VERSION.to_string(),
+                                         SIG_HASH_TYPE.to_string(),
+                                         vec![]);
+        assert!(header.key_name().is_err());
+    }
+
     #[test]
     #[should_panic(expected = "Secret key is required but not present for")]
     fn sign_missing_private_key() {
@@ -493,9 +638,93 @@ mod test {
 
         let hart_header = get_artifact_header(&dst).unwrap();
         assert_eq!(HART_FORMAT_VERSION, hart_header.format_version);
-        let (key_name, _rev) = parse_name_with_rev(&hart_header.key_name).unwrap();
+        let (key_name, _rev) = parse_name_with_rev(hart_header.key_name().unwrap()).unwrap();
         assert_eq!("unicorn", key_name);
         assert_eq!(SIG_HASH_TYPE, hart_header.hash_type);
-        assert!(hart_header.signature_raw.len() > 0);
+        assert!(hart_header.signatures[0].signature_raw.len() > 0);
+    }
+
+    #[test]
+    fn artifact_reader_verifies_at_finish() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let src = cache.path().join("src.in");
+        let dst = cache.path().join("src.signed");
+        let mut f = File::create(&src).unwrap();
+        f.write_all("hearty goodness".as_bytes()).unwrap();
+        sign(&src, &dst, &pair).unwrap();
+
+        let reader = ArtifactReader::new(File::open(&dst).unwrap(), cache.path()).unwrap();
+        let mut buffer = [0u8; 6];
+        reader.clone().read_exact(&mut buffer).unwrap();
+        assert_eq!(&buffer, "hearty".as_bytes());
+        let (name_with_rev, _) = reader.finish().unwrap();
+        assert_eq!(pair.name_with_rev(), name_with_rev);
+        assert!(reader.finish().is_err());
+    }
+
+    #[test]
+    fn countersign_and_verify() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let origin = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let release = SigKeyPair::generate_pair_for_origin("unicorn-release", cache.path())
+            .unwrap();
+        let signed = cache.path().join("signed.dat");
+        let countersigned = cache.path().join("countersigned.dat");
+
+        sign(&fixture("signme.dat"), &signed, &origin).unwrap();
+        countersign(&signed, &countersigned, &release).unwrap();
+
+        let header = get_artifact_header(&countersigned).unwrap();
+        assert_eq!(HART_MULTI_SIG_FORMAT_VERSION, header.format_version);
+        assert_eq!(origin.name_with_rev(), header.key_name().unwrap());
+        assert_eq!(artifact_signers(&countersigned).unwrap(),
+                   vec![origin.name_with_rev(), release.name_with_rev()]);
+        let (name_with_rev, _) = verify(&countersigned, cache.path()).unwrap();
+        assert_eq!(origin.name_with_rev(), name_with_rev);
+
+        let mut buffer = String::new();
+        let mut expected = String::new();
+        get_archive_reader(&countersigned)
+            .unwrap()
+            .read_to_string(&mut buffer)
+            .unwrap();
+        File::open(fixture("signme.dat"))
+            .unwrap()
+            .read_to_string(&mut expected)
+            .unwrap();
+        assert_eq!(expected, buffer);
+    }
+
+    #[test]
+    #[should_panic(expected = "missing a signature from unicorn-release")]
+    fn verify_missing_required_signer() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let dst = cache.path().join("signed.dat");
+        let mut f = File::create(cache.path().join(TRUST_POLICY_FILE)).unwrap();
+        f.write_all(b"[required_signers]\nunicorn = [\"unicorn-release\"]\n")
+            .unwrap();
+
+        sign(&fixture("signme.dat"), &dst, &pair).unwrap();
+        verify(&dst, cache.path()).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "has been revoked")]
+    fn verify_revoked_signer() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let origin = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let release = SigKeyPair::generate_pair_for_origin("unicorn-release", cache.path())
+            .unwrap();
+        let signed = cache.path().join("signed.dat");
+        let countersigned = cache.path().join("countersigned.dat");
+        sign(&fixture("signme.dat"), &signed, &origin).unwrap();
+        countersign(&signed, &countersigned, &release).unwrap();
+        let mut f = File::create(cache.path().join(TRUST_POLICY_FILE)).unwrap();
+        f.write_all(format!("revoked_keys = [\"{}\"]\n", release.name_with_rev()).as_bytes())
+            .unwrap();
+
+        verify(&countersigned, cache.path()).unwrap();
     }
 }
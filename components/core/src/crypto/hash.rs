@@ -67,31 +67,62 @@ pub fn hash_bytes(data: &[u8]) -> String {
 }
 
 pub fn hash_reader(reader: &mut BufReader<File>) -> Result<String> {
-    let mut out = [0u8; libsodium_sys::crypto_generichash_BYTES];
-    let mut st = vec![0u8; (unsafe { libsodium_sys::crypto_generichash_statebytes() })];
-    let pst =
-        unsafe {
-            mem::transmute::<*mut u8,
-                             *mut libsodium_sys::crypto_generichash_state>(st.as_mut_ptr())
-        };
-    unsafe {
-        libsodium_sys::crypto_generichash_init(pst, ptr::null_mut(), 0, out.len());
-    }
+    let mut hasher = Blake2bHasher::new();
     let mut buf = [0u8; BUF_SIZE];
     loop {
         let bytes_read = try!(reader.read(&mut buf));
         if bytes_read == 0 {
             break;
         }
-        let chunk = &buf[0..bytes_read];
+        hasher.update(&buf[0..bytes_read]);
+    }
+    Ok(hasher.finalize())
+}
+
+/// Incrementally calculate the BLAKE2b hash of data which arrives in chunks, such as an artifact
+/// being streamed off the network. The digest is the same as `hash_bytes` would return for all of
+/// the chunks concatenated.
+pub struct Blake2bHasher {
+    state: Vec<u8>,
+}
+
+impl Blake2bHasher {
+    pub fn new() -> Self {
+        let mut hasher = Blake2bHasher {
+            state: vec![0u8; (unsafe { libsodium_sys::crypto_generichash_statebytes() })],
+        };
         unsafe {
-            libsodium_sys::crypto_generichash_update(pst, chunk.as_ptr(), chunk.len() as u64);
+            libsodium_sys::crypto_generichash_init(hasher.state_ptr(),
+                                                   ptr::null_mut(),
+                                                   0,
+                                                   libsodium_sys::crypto_generichash_BYTES);
         }
+        hasher
     }
-    unsafe {
-        libsodium_sys::crypto_generichash_final(pst, out.as_mut_ptr(), out.len());
+
+    pub fn update(&mut self, chunk: &[u8]) {
+        unsafe {
+            libsodium_sys::crypto_generichash_update(self.state_ptr(),
+                                                     chunk.as_ptr(),
+                                                     chunk.len() as u64);
+        }
+    }
+
+    /// Consume the hasher and return the digest as a hex string
+    pub fn finalize(mut self) -> String {
+        let mut out = [0u8; libsodium_sys::crypto_generichash_BYTES];
+        unsafe {
+            libsodium_sys::crypto_generichash_final(self.state_ptr(), out.as_mut_ptr(), out.len());
+        }
+        out.to_hex()
+    }
+
+    fn state_ptr(&mut self) -> *mut libsodium_sys::crypto_generichash_state {
+        unsafe {
+            mem::transmute::<*mut u8,
+                             *mut libsodium_sys::crypto_generichash_state>(self.state.as_mut_ptr())
+        }
     }
-    Ok(out.to_hex())
 }
 
 #[cfg(test)]
@@ -146,6 +177,16 @@ mod test {
         assert_eq!(computed, expected);
     }
 
+    #[test]
+    fn hasher_matches_hash_bytes() {
+        let data = "hearty goodness, in several chunks".as_bytes();
+        let mut hasher = Blake2bHasher::new();
+        for chunk in data.chunks(5) {
+            hasher.update(chunk);
+        }
+        assert_eq!(hasher.finalize(), hash_bytes(data));
+    }
+
     #[test]
     #[cfg(feature = "functional")]
     fn hash_file_large_binary() {
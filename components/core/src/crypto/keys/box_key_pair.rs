@@ -12,19 +12,23 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fs;
 use std::path::{Path, PathBuf};
 use std::str;
 
 use base64;
+use hex::ToHex;
 use sodiumoxide::crypto::box_;
 use sodiumoxide::crypto::box_::curve25519xsalsa20poly1305::PublicKey as BoxPublicKey;
 use sodiumoxide::crypto::box_::curve25519xsalsa20poly1305::SecretKey as BoxSecretKey;
 use sodiumoxide::crypto::box_::curve25519xsalsa20poly1305::{Nonce, gen_nonce};
+use sodiumoxide::randombytes::randombytes;
 
 use error::{Error, Result};
 use super::{get_key_revisions, mk_key_filename, mk_revision_string, parse_name_with_rev,
-            read_key_bytes, write_keypair_files, KeyPair, KeyType};
-use super::super::{BOX_FORMAT_VERSION, PUBLIC_KEY_SUFFIX, SECRET_BOX_KEY_SUFFIX};
+            read_key_bytes, write_keypair_files, KeyPair, KeyType, PairType, TmpKeyfile};
+use super::super::{BOX_FORMAT_VERSION, PUBLIC_BOX_KEY_VERSION, PUBLIC_KEY_SUFFIX,
+                   SECRET_BOX_KEY_SUFFIX, SECRET_BOX_KEY_VERSION, hash};
 
 pub type BoxKeyPair = KeyPair<BoxPublicKey, BoxSecretKey>;
 
@@ -59,6 +63,22 @@ impl BoxKeyPair {
         Ok(Self::new(name, revision, Some(public_key), Some(secret_key)))
     }
 
+    /// Generates the box key pair an origin encrypts its own data with, such as the build
+    /// secrets stored by Builder, where the origin is both the sender and the receiver.
+    pub fn generate_pair_for_origin<P: AsRef<Path> + ?Sized>(origin: &str,
+                                                             cache_key_path: &P)
+                                                             -> Result<Self> {
+        let revision = try!(mk_revision_string());
+        let keyname = Self::mk_key_name_for_origin(origin, &revision);
+        debug!("new origin box key name = {}", &keyname);
+        let (public_key, secret_key) = try!(Self::generate_pair_files(&keyname,
+                                                                      cache_key_path.as_ref()));
+        Ok(Self::new(origin.to_string(),
+                     revision,
+                     Some(public_key),
+                     Some(secret_key)))
+    }
+
     pub fn get_pairs_for<T, P>(name: T, cache_key_path: P) -> Result<Vec<Self>>
         where T: AsRef<str>,
               P: AsRef<Path>
@@ -140,6 +160,110 @@ impl BoxKeyPair {
         Ok(path)
     }
 
+    /// Writes a box key (public or secret) to the key cache from the contents of a string slice.
+    ///
+    /// # Errors
+    ///
+    /// * If the key content cannot be parsed
+    /// * If the key file cannot be written to disk
+    /// * If an existing key is already installed, but the new content is different from the
+    /// existing
+    pub fn write_file_from_str<P: AsRef<Path> + ?Sized>(content: &str,
+                                                        cache_key_path: &P)
+                                                        -> Result<(Self, PairType)> {
+        let (pair_type, name_with_rev, key_body) = try!(Self::parse_key_str(content));
+        let suffix = match pair_type {
+            PairType::Public => PUBLIC_KEY_SUFFIX,
+            PairType::Secret => SECRET_BOX_KEY_SUFFIX,
+        };
+        let keyfile = mk_key_filename(cache_key_path.as_ref(), &name_with_rev, &suffix);
+        let tmpfile = {
+            let mut t = keyfile.clone();
+            t.set_file_name(format!("{}.{}",
+                                    &keyfile.file_name().unwrap().to_str().unwrap(),
+                                    &randombytes(6).as_slice().to_hex()));
+            TmpKeyfile { path: t }
+        };
+
+        debug!("Writing temp key file {}", tmpfile.path.display());
+        match pair_type {
+            PairType::Public => {
+                try!(write_keypair_files(KeyType::Box,
+                                         &name_with_rev,
+                                         Some(&tmpfile.path),
+                                         Some(&key_body.as_bytes()),
+                                         None,
+                                         None));
+            }
+            PairType::Secret => {
+                try!(write_keypair_files(KeyType::Box,
+                                         &name_with_rev,
+                                         None,
+                                         None,
+                                         Some(&tmpfile.path),
+                                         Some(&key_body.as_bytes())));
+            }
+        }
+
+        if Path::new(&keyfile).is_file() {
+            let existing_hash = try!(hash::hash_file(&keyfile));
+            let new_hash = try!(hash::hash_file(&tmpfile.path));
+            if existing_hash != new_hash {
+                let msg = format!("Existing key file {} found but new version hash is different, \
+                                  failing to write new file over existing.",
+                                  keyfile.display());
+                return Err(Error::CryptoError(msg));
+            }
+            try!(fs::remove_file(&tmpfile.path));
+        } else {
+            try!(fs::rename(&tmpfile.path, keyfile));
+        }
+        Ok((try!(Self::get_pair_for(&name_with_rev, cache_key_path)), pair_type))
+    }
+
+    /// Parses a string slice of a public or secret box key.
+    ///
+    /// The return valid is a tuple consisting of:
+    ///   `(PairType, name_with_rev::String, key_body::String)`
+    pub fn parse_key_str(content: &str) -> Result<(PairType, String, String)> {
+        let mut lines = content.lines();
+        let pair_type = match lines.next() {
+            Some(PUBLIC_BOX_KEY_VERSION) => PairType::Public,
+            Some(SECRET_BOX_KEY_VERSION) => PairType::Secret,
+            Some(val) => {
+                return Err(Error::CryptoError(format!("Unsupported key version: {}", val)))
+            }
+            None => {
+                let msg = format!("write_box_key_from_str:1 Malformed box key string:\n({})",
+                                  content);
+                return Err(Error::CryptoError(msg));
+            }
+        };
+        let name_with_rev = match lines.next() {
+            Some(val) => val,
+            None => {
+                let msg = format!("write_box_key_from_str:2 Malformed box key string:\n({})",
+                                  content);
+                return Err(Error::CryptoError(msg));
+            }
+        };
+        match lines.nth(1) {
+            Some(val) => {
+                try!(base64::decode(val.trim()).map_err(|_| {
+                    Error::CryptoError(format!("write_box_key_from_str:3 Malformed box key \
+                                                string:\n({})",
+                                               content))
+                }));
+                Ok((pair_type, name_with_rev.to_string(), val.trim().to_string()))
+            }
+            None => {
+                let msg = format!("write_box_key_from_str:3 Malformed box key string:\n({})",
+                                  content);
+                Err(Error::CryptoError(msg))
+            }
+        }
+    }
+
     /// A user can encrypt data with a service as the recipient.
     /// Key names and nonce are embedded in the payload.
     pub fn encrypt(&self, data: &[u8], receiver: &Self) -> Result<Vec<u8>> {
@@ -280,6 +404,10 @@ impl BoxKeyPair {
         format!("{}@{}-{}", service_group, org, revision)
     }
 
+    fn mk_key_name_for_origin(origin: &str, revision: &str) -> String {
+        format!("{}-{}", origin, revision)
+    }
+
     fn mk_key_name_for_user(user: &str, revision: &str) -> String {
         format!("{}-{}", user, revision)
     }
@@ -287,7 +415,8 @@ impl BoxKeyPair {
 
 #[cfg(test)]
 mod test {
-    use std::fs;
+    use std::fs::{self, File};
+    use std::io::Read;
     use std::str;
 
     use tempdir::TempDir;
@@ -368,6 +497,58 @@ mod test {
                     .exists());
     }
 
+    #[test]
+    fn generated_origin_pair() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let pair = BoxKeyPair::generate_pair_for_origin("acme", cache.path()).unwrap();
+
+        assert_eq!(pair.name, "acme");
+        assert!(pair.public().is_ok());
+        assert!(pair.secret().is_ok());
+        assert!(cache
+                    .path()
+                    .join(format!("{}.box.key", pair.name_with_rev()))
+                    .exists());
+    }
+
+    #[test]
+    fn write_file_from_str_and_decrypt() {
+        let origin_cache = TempDir::new("origin_cache").unwrap();
+        let worker_cache = TempDir::new("worker_cache").unwrap();
+        let pair = BoxKeyPair::generate_pair_for_origin("acme", origin_cache.path()).unwrap();
+        let ciphertext = pair.encrypt("Acme token".as_bytes(), &pair).unwrap();
+
+        for path in vec![BoxKeyPair::get_public_key_path(&pair.name_with_rev(),
+                                                         origin_cache.path())
+                                 .unwrap(),
+                         BoxKeyPair::get_secret_key_path(&pair.name_with_rev(),
+                                                         origin_cache.path())
+                                 .unwrap()] {
+            let mut content = String::new();
+            File::open(path)
+                .unwrap()
+                .read_to_string(&mut content)
+                .unwrap();
+            let (written, _) = BoxKeyPair::write_file_from_str(&content, worker_cache.path())
+                .unwrap();
+            assert_eq!(written.name_with_rev(), pair.name_with_rev());
+            // Writing the same key twice is a no-op
+            BoxKeyPair::write_file_from_str(&content, worker_cache.path()).unwrap();
+        }
+
+        let message = BoxKeyPair::decrypt(&ciphertext, worker_cache.path()).unwrap();
+        assert_eq!(message, "Acme token".as_bytes());
+    }
+
+    #[test]
+    fn parse_key_str_wrong_version() {
+        let content = "SIG-PUB-1
+acme-20160517220007
+
+J+FGYVKgragA+dzQHCGORd2oLwCc2EvAnT9roz9BJh0=";
+        assert!(BoxKeyPair::parse_key_str(content).is_err());
+    }
+
     #[test]
     fn get_pairs_for() {
         let cache = TempDir::new("key_cache").unwrap();
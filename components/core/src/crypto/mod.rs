@@ -189,6 +189,30 @@
 //! is **not** a supported workflow for working with Habitat artifacts--they are signed for very
 //! important reasons.
 //!
+//! ## Multi-signature Habitat artifacts
+//!
+//! An artifact which has been countersigned, for example by a release-approval key in addition to
+//! its origin key, uses the `HART-2` format version. The header lines are as follows:
+//!
+//! 1. The artifact format version
+//! 1. The hashing algorithm used, which at present is only `BLAKE2b`
+//! 1. One line per signature, holding the name with revision of the signing key and the Base64
+//!    *signed* value of the binary blob's file hash separated by a single space. The first
+//!    signature is always made by the origin key.
+//! 1. An empty line separating the header from the payload
+//!
+//! ```text
+//! HART-2
+//! BLAKE2b
+//! habitat-20160405144945 signed BLAKE2b signature
+//! habitat-release-20160505144945 signed BLAKE2b signature
+//!
+//! <binary-blob>
+//! ```
+//!
+//! Every signature must verify against a public key in the key cache. A local trust policy (see
+//! the `trust` module) may additionally revoke key revisions and require signers per origin.
+//!
 //! ## Encrypted payloads
 //!
 //! The first 4 lines of an encrypted payload are as follows:
@@ -256,6 +280,7 @@ static PUBLIC_KEY_PERMISSIONS: u32 = 0o400;
 static SECRET_KEY_PERMISSIONS: u32 = 0o400;
 
 pub static HART_FORMAT_VERSION: &'static str = "HART-1";
+pub static HART_MULTI_SIG_FORMAT_VERSION: &'static str = "HART-2";
 pub static BOX_FORMAT_VERSION: &'static str = "BOX-1";
 
 pub const PUBLIC_SIG_KEY_VERSION: &'static str = "SIG-PUB-1";
@@ -271,6 +296,7 @@ pub use self::keys::sig_key_pair::SigKeyPair;
 pub mod artifact;
 pub mod hash;
 pub mod keys;
+pub mod trust;
 
 pub fn default_cache_key_path(fs_root_path: Option<&Path>) -> PathBuf {
     match henv::var(CACHE_KEY_PATH_ENV_VAR) {
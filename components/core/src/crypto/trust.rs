@@ -0,0 +1,178 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A local trust policy which is enforced whenever a Habitat artifact is verified.
+//!
+//! The policy lives next to the public keys in the key cache as `trust.toml`. When no policy
+//! file is present, every artifact with valid signatures is trusted.
+//!
+//! # Examples
+//!
+//! ```toml
+//! # Key revisions which must never be trusted, even if present in the key cache
+//! revoked_keys = ["acme-20160405144945"]
+//!
+//! # Artifacts signed with an `acme` origin key must also be signed by an `acme-release` key
+//! [required_signers]
+//! acme = ["acme-release"]
+//! ```
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use config::ConfigFile;
+use error::{Error, Result};
+use super::artifact::ArtifactHeader;
+use super::keys::parse_name_with_rev;
+
+/// File name of the trust policy within the key cache
+pub static TRUST_POLICY_FILE: &'static str = "trust.toml";
+
+#[derive(Debug, Default, Deserialize, PartialEq)]
+pub struct TrustPolicy {
+    #[serde(default)]
+    pub revoked_keys: Vec<String>,
+    #[serde(default)]
+    pub required_signers: HashMap<String, Vec<String>>,
+}
+
+impl ConfigFile for TrustPolicy {
+    type Error = Error;
+}
+
+impl TrustPolicy {
+    /// Loads the trust policy from the given key cache, returning an empty policy if the key
+    /// cache has no policy file.
+    ///
+    /// # Failures
+    ///
+    /// * If the policy file cannot be read or parsed
+    pub fn load<P: AsRef<Path> + ?Sized>(cache_key_path: &P) -> Result<Self> {
+        let path = cache_key_path.as_ref().join(TRUST_POLICY_FILE);
+        if path.is_file() {
+            debug!("Loading trust policy from {}", path.display());
+            TrustPolicy::from_file(path)
+        } else {
+            Ok(TrustPolicy::default())
+        }
+    }
+
+    /// Fails if the given key name with revision has been revoked.
+    pub fn check_revoked(&self, name_with_rev: &str) -> Result<()> {
+        if self.revoked_keys.iter().any(|k| k == name_with_rev) {
+            let msg = format!("Key {} has been revoked by the local trust policy",
+                              name_with_rev);
+            return Err(Error::CryptoError(msg));
+        }
+        Ok(())
+    }
+
+    /// Fails unless the artifact carries a signature from every key required for its origin
+    /// key. Only the presence of the signatures is checked here; verifying them is up to the
+    /// caller.
+    pub fn check_required_signers(&self, header: &ArtifactHeader) -> Result<()> {
+        let key_name = try!(header.key_name());
+        let (origin, _) = try!(parse_name_with_rev(key_name));
+        let required = match self.required_signers.get(&origin) {
+            Some(required) => required,
+            None => return Ok(()),
+        };
+        let mut signers = Vec::with_capacity(header.signatures.len());
+        for sig in header.signatures.iter() {
+            let (name, _) = try!(parse_name_with_rev(&sig.key_name));
+            signers.push(name);
+        }
+        for name in required.iter() {
+            if !signers.contains(name) {
+                let msg = format!("Artifact signed by {} is missing a signature from {} \
+                                  required by the local trust policy",
+                                  key_name,
+                                  name);
+                return Err(Error::CryptoError(msg));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs::File;
+    use std::io::Write;
+
+    use tempdir::TempDir;
+
+    use super::*;
+    use super::super::artifact::{ArtifactHeader, ArtifactSignature};
+
+    fn header(signers: &[&str]) -> ArtifactHeader {
+        ArtifactHeader::new("HART-2".to_string(),
+                            "BLAKE2b".to_string(),
+                            signers
+                                .iter()
+                                .map(|s| ArtifactSignature::new(s.to_string(), "sig".to_string()))
+                                .collect())
+    }
+
+    #[test]
+    fn load_without_policy_file() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let policy = TrustPolicy::load(cache.path()).unwrap();
+        assert_eq!(policy, TrustPolicy::default());
+    }
+
+    #[test]
+    fn load_policy_file() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let mut f = File::create(cache.path().join(TRUST_POLICY_FILE)).unwrap();
+        f.write_all(b"revoked_keys = [\"acme-20160405144945\"]\n\n\
+                      [required_signers]\nacme = [\"acme-release\"]\n")
+            .unwrap();
+
+        let policy = TrustPolicy::load(cache.path()).unwrap();
+        assert_eq!(policy.revoked_keys, vec!["acme-20160405144945".to_string()]);
+        assert_eq!(policy.required_signers.get("acme"),
+                   Some(&vec!["acme-release".to_string()]));
+    }
+
+    #[test]
+    #[should_panic(expected = "has been revoked")]
+    fn check_revoked_key() {
+        let mut policy = TrustPolicy::default();
+        policy
+            .revoked_keys
+            .push("acme-20160405144945".to_string());
+        policy.check_revoked("acme-20170101000000").unwrap();
+        policy.check_revoked("acme-20160405144945").unwrap();
+    }
+
+    #[test]
+    fn check_required_signers() {
+        let mut policy = TrustPolicy::default();
+        policy
+            .required_signers
+            .insert("acme".to_string(), vec!["acme-release".to_string()]);
+
+        assert!(policy
+                    .check_required_signers(&header(&["acme-20160405144945"]))
+                    .is_err());
+        assert!(policy
+                    .check_required_signers(&header(&["acme-20160405144945",
+                                                      "acme-release-20160505144945"]))
+                    .is_ok());
+        assert!(policy
+                    .check_required_signers(&header(&["other-20160405144945"]))
+                    .is_ok());
+    }
+}
@@ -79,6 +79,8 @@ pub enum Error {
     CryptoError(String),
     /// Occurs when a file that should exist does not or could not be read.
     FileNotFound(String),
+    /// Occurs when a package bundle holds unexpected or corrupt contents.
+    InvalidBundle(String),
     /// Occurs when a package identifier string cannot be successfully parsed.
     InvalidPackageIdent(String),
     /// Occurs when a package target string cannot be successfully parsed.
@@ -91,6 +93,8 @@ pub enum Error {
     InvalidServiceGroup(String),
     /// Occurs when making lower level IO calls.
     IO(io::Error),
+    /// Occurs when a package lockfile cannot be serialized.
+    LockfileSerialize(toml::ser::Error),
     /// Occurs when a BIND or BIND_OPTIONAL MetaFile is read and contains a bad entry.
     MetaFileBadBind,
     /// Occurs when a package metadata file cannot be opened, read, or parsed.
@@ -103,6 +107,8 @@ pub enum Error {
     NoOutboundAddr,
     /// Occurs when a suitable installed package cannot be found.
     PackageNotFound(package::PackageIdent),
+    /// Occurs when a verified artifact does not unpack to a single package.
+    PackageUnpackFailed(String),
     /// When an error occurs parsing an integer.
     ParseIntError(num::ParseIntError),
     /// Occurs when setting ownership or permissions on a file or directory fails.
@@ -212,6 +218,7 @@ impl fmt::Display for Error {
             }
             Error::CryptoError(ref e) => format!("Crypto error: {}", e),
             Error::FileNotFound(ref e) => format!("File not found at: {}", e),
+            Error::InvalidBundle(ref e) => format!("Invalid package bundle, {}", e),
             Error::InvalidPackageIdent(ref e) => {
                 format!("Invalid package identifier: {:?}. A valid identifier is in the form \
                          origin/name (example: acme/redis)",
@@ -230,6 +237,7 @@ impl fmt::Display for Error {
                         e)
             }
             Error::IO(ref err) => format!("{}", err),
+            Error::LockfileSerialize(ref e) => format!("Can't serialize package lockfile: {}", e),
             Error::MetaFileBadBind => format!("Bad value parsed from BIND or BIND_OPTIONAL"),
             Error::MetaFileMalformed(ref e) => {
                 format!("MetaFile: {:?}, didn't contain a valid UTF-8 string", e)
@@ -244,6 +252,7 @@ impl fmt::Display for Error {
                     format!("Cannot find a release of package: {}", pkg)
                 }
             }
+            Error::PackageUnpackFailed(ref e) => format!("Failed to unpack package: {}", e),
             Error::ParseIntError(ref e) => format!("{}", e),
             Error::PlanMalformed => format!("Failed to read or parse contents of Plan file"),
             Error::PermissionFailed(ref e) => format!("{}", e),
@@ -308,18 +317,21 @@ impl error::Error for Error {
             Error::ConfigInvalidUsize(_) => "Invalid usize value encountered while parsing a configuration file",
             Error::CryptoError(_) => "Crypto error",
             Error::FileNotFound(_) => "File not found",
+            Error::InvalidBundle(_) => "Package bundle holds unexpected or corrupt contents",
             Error::InvalidPackageIdent(_) => "Package identifiers must be in origin/name format (example: acme/redis)",
             Error::InvalidPackageTarget(_) => "Package targets must be in architecture-platform format (example: x86_64-linux)",
             Error::InvalidArchitecture(_) => "Unsupported target architecture supplied.",
             Error::InvalidPlatform(_) => "Unsupported target platform supplied.",
             Error::InvalidServiceGroup(_) => "Service group strings must be in service.group format (example: redis.production)",
             Error::IO(ref err) => err.description(),
+            Error::LockfileSerialize(_) => "Can't serialize package lockfile",
             Error::MetaFileBadBind => "Bad value parsed from BIND or BIND_OPTIONAL MetaFile",
             Error::MetaFileMalformed(_) => "MetaFile didn't contain a valid UTF-8 string",
             Error::MetaFileNotFound(_) => "Failed to read an archive's metafile",
             Error::MetaFileIO(_) => "MetaFile could not be read or written to",
             Error::NoOutboundAddr => "Failed to discover the outbound IP address",
             Error::PackageNotFound(_) => "Cannot find a package",
+            Error::PackageUnpackFailed(_) => "Failed to unpack a package",
             Error::ParseIntError(_) => "Failed to parse an integer from a string!",
             Error::PermissionFailed(_) => "Failed to set permissions",
             Error::PlanMalformed => "Failed to read or parse contents of Plan file",
@@ -89,6 +89,8 @@ pub enum Event {
     OriginInvitationAccept { id: String, account: String },
     OriginInvitationIgnore { id: String, account: String },
     JobCreate { package: String, account: String },
+    JobCancel { job: String, account: String },
+    JobPublishRetry { job: String, account: String },
     GithubAuthenticate { user: String, account: String },
 }
 
@@ -114,6 +116,8 @@ impl fmt::Display for Event {
             Event::OriginInvitationAccept { id: _, account: _ } => "origin-invitation-accept",
             Event::OriginInvitationIgnore { id: _, account: _ } => "origin-invitation-ignore",
             Event::JobCreate { package: _, account: _ } => "job-create",
+            Event::JobCancel { job: _, account: _ } => "job-cancel",
+            Event::JobPublishRetry { job: _, account: _ } => "job-publish-retry",
             Event::GithubAuthenticate { user: _, account: _ } => "github-authenticate",
         };
 
@@ -200,6 +204,20 @@ impl Serialize for Event {
                 try!(strukt.serialize_field("account", a));
                 strukt
             }
+            Event::JobCancel {
+                job: ref j,
+                account: ref a,
+            } |
+            Event::JobPublishRetry {
+                job: ref j,
+                account: ref a,
+            } => {
+                let mut strukt = try!(serializer.serialize_struct("event", 3));
+                try!(strukt.serialize_field("name", &self.to_string()));
+                try!(strukt.serialize_field("job", j));
+                try!(strukt.serialize_field("account", a));
+                strukt
+            }
             Event::GithubAuthenticate {
                 user: ref u,
                 account: ref a,
@@ -12,8 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::any::Any;
 use std::collections::HashMap;
 use std::error;
+use std::fs::{self, File};
+use std::io::{self, Read};
 use std::path::{Path, PathBuf};
 use std::result;
 use std::str::{self, FromStr};
@@ -23,14 +26,24 @@ use libarchive::reader::{self, Reader};
 use libarchive::archive::{Entry, ReadFilter, ReadFormat, ExtractOption, ExtractOptions};
 use regex::Regex;
 
-use super::{Identifiable, PackageIdent, PackageTarget};
-use super::metadata::MetaFile;
+use super::{Identifiable, PackageIdent, PackageInstall, PackageTarget};
+use super::metadata::{self, Bind, MetaFile, PackageFile};
 use error::{Error, Result};
 use crypto::{artifact, hash};
+use crypto::artifact::ArtifactReader;
+use fs::{cache_artifact_path, PKG_PATH};
+use os::process;
+use time;
 
 lazy_static! {
     static ref METAFILE_REGXS: HashMap<MetaFile, Regex> = {
         let mut map = HashMap::new();
+        map.insert(MetaFile::Binds,
+                   Regex::new(&format!(r"^/?hab/pkgs/([^/]+)/([^/]+)/([^/]+)/([^/]+)/{}$",
+                              MetaFile::Binds)).unwrap());
+        map.insert(MetaFile::BindsOptional,
+                   Regex::new(&format!(r"^/?hab/pkgs/([^/]+)/([^/]+)/([^/]+)/([^/]+)/{}$",
+                              MetaFile::BindsOptional)).unwrap());
         map.insert(MetaFile::CFlags,
                    Regex::new(&format!(r"^/?hab/pkgs/([^/]+)/([^/]+)/([^/]+)/([^/]+)/{}$",
                               MetaFile::CFlags)).unwrap());
@@ -46,6 +59,9 @@ lazy_static! {
         map.insert(MetaFile::Exposes,
                    Regex::new(&format!(r"^/?hab/pkgs/([^/]+)/([^/]+)/([^/]+)/([^/]+)/{}$",
                               MetaFile::Exposes)).unwrap());
+        map.insert(MetaFile::Files,
+                   Regex::new(&format!(r"^/?hab/pkgs/([^/]+)/([^/]+)/([^/]+)/([^/]+)/{}$",
+                              MetaFile::Files)).unwrap());
         map.insert(MetaFile::Ident,
                    Regex::new(&format!(r"^/?hab/pkgs/([^/]+)/([^/]+)/([^/]+)/([^/]+)/{}$",
                               MetaFile::Ident)).unwrap());
@@ -93,6 +109,18 @@ impl PackageArchive {
         hash::hash_file(&self.path)
     }
 
+    /// Returns the services this package requires a binding to, as declared in its `BINDS`
+    /// metafile.
+    pub fn binds(&mut self) -> Result<Vec<Bind>> {
+        self.read_binds(MetaFile::Binds)
+    }
+
+    /// Returns the services this package may optionally be bound to, as declared in its
+    /// `BINDS_OPTIONAL` metafile.
+    pub fn binds_optional(&mut self) -> Result<Vec<Bind>> {
+        self.read_binds(MetaFile::BindsOptional)
+    }
+
     pub fn cflags(&mut self) -> Result<Option<String>> {
         match self.read_metadata(MetaFile::CFlags) {
             Ok(data) => Ok(data.cloned()),
@@ -144,6 +172,25 @@ impl PackageArchive {
         }
     }
 
+    /// Returns the signed listing of BLAKE2b checksums for every file in the package, if the
+    /// archive contains one.
+    pub fn files(&mut self) -> Result<Option<String>> {
+        match self.read_metadata(MetaFile::Files) {
+            Ok(data) => Ok(data.cloned()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns every file listed in the archive's `FILES` metafile along with its checksum, or
+    /// nothing if the archive doesn't contain one.
+    pub fn file_list(&mut self) -> Result<Vec<PackageFile>> {
+        match self.read_metadata(MetaFile::Files) {
+            Ok(Some(data)) => Ok(metadata::parse_files(data)),
+            Ok(None) => Ok(vec![]),
+            Err(e) => Err(e),
+        }
+    }
+
     pub fn ident(&mut self) -> Result<PackageIdent> {
         match self.read_metadata(MetaFile::Ident) {
             Ok(None) => Err(Error::MetaFileNotFound(MetaFile::Ident)),
@@ -217,20 +264,49 @@ impl PackageArchive {
     pub fn unpack(&self, fs_root_path: Option<&Path>) -> Result<()> {
         let root = fs_root_path.unwrap_or(Path::new("/"));
         let tar_reader = try!(artifact::get_archive_reader(&self.path));
-        let mut builder = reader::Builder::new();
-        try!(builder.support_format(ReadFormat::Gnutar));
-        try!(builder.support_filter(ReadFilter::Xz));
-        let mut reader = try!(builder.open_stream(tar_reader));
-        let writer = writer::Disk::new();
-        let mut extract_options = ExtractOptions::new();
-        extract_options.add(ExtractOption::Time);
-        extract_options.add(ExtractOption::Owner);
-        extract_options.add(ExtractOption::Permissions);
-        try!(writer.set_options(&extract_options));
-        try!(writer.set_standard_lookup());
-        try!(writer.write(&mut reader, Some(root.to_string_lossy().as_ref())));
-        try!(writer.close());
-        Ok(())
+        extract(tar_reader, root)
+    }
+
+    /// Verify and unpack the package, installing it only if its signatures check out.
+    ///
+    /// # Failures
+    ///
+    /// * If the package cannot be verified or unpacked
+    pub fn unpack_verified<P: AsRef<Path> + ?Sized>(&self,
+                                                    cache_key_path: &P,
+                                                    fs_root_path: Option<&Path>)
+                                                    -> Result<(String, String)> {
+        unpack_verified(try!(File::open(&self.path)), cache_key_path, fs_root_path)
+    }
+
+    /// Verify and unpack the package without installing it yet. The metadata of the returned
+    /// `StagedPackage` can be trusted, unlike the metadata read from the archive itself.
+    ///
+    /// # Failures
+    ///
+    /// * If the package cannot be verified or unpacked
+    pub fn stage_verified<P: AsRef<Path> + ?Sized>(&self,
+                                                   cache_key_path: &P,
+                                                   fs_root_path: Option<&Path>)
+                                                   -> Result<StagedPackage> {
+        stage_verified(try!(File::open(&self.path)), cache_key_path, fs_root_path)
+    }
+
+    fn read_binds(&mut self, file: MetaFile) -> Result<Vec<Bind>> {
+        let mut binds = Vec::new();
+        match self.read_metadata(file.clone()) {
+            Ok(Some(body)) => {
+                for line in body.lines() {
+                    match Bind::from_str(line) {
+                        Ok(bind) => binds.push(bind),
+                        Err(_) => return Err(Error::MetaFileMalformed(file)),
+                    }
+                }
+                Ok(binds)
+            }
+            Ok(None) => Ok(binds),
+            Err(e) => Err(e),
+        }
     }
 
     fn read_deps(&mut self, file: MetaFile) -> Result<Vec<PackageIdent>> {
@@ -318,6 +394,238 @@ impl PackageArchive {
     }
 }
 
+/// Verify and unpack a package from an artifact stream.
+///
+/// The payload is written to a staging directory in the artifact cache while it is hashed, and
+/// only extracted there once the whole stream has been read and its signatures check out. The
+/// extracted package is then moved into the package path; otherwise the staging directory is
+/// discarded and nothing is installed. On success the name with revision of the origin key is
+/// returned along with the verified payload hash.
+///
+/// # Failures
+///
+/// * If the artifact cannot be verified
+/// * If the payload cannot be unpacked
+/// * If the staged packages cannot be moved into the package path
+pub fn unpack_verified<R, P>(src: R,
+                             cache_key_path: &P,
+                             fs_root_path: Option<&Path>)
+                             -> Result<(String, String)>
+    where R: Read + Any,
+          P: AsRef<Path> + ?Sized
+{
+    try!(stage_verified(src, cache_key_path, fs_root_path)).commit()
+}
+
+/// Verify and unpack a package from an artifact stream, like `unpack_verified`, but leave the
+/// package in its staging directory until the returned `StagedPackage` is committed. This lets a
+/// caller read the verified metadata of a package, such as its transitive dependencies, before
+/// anything is installed.
+///
+/// # Failures
+///
+/// * If the artifact cannot be verified
+/// * If the payload holds entries outside of a package install directory
+/// * If the payload cannot be unpacked
+/// * If the payload does not hold exactly one package
+pub fn stage_verified<R, P>(src: R,
+                            cache_key_path: &P,
+                            fs_root_path: Option<&Path>)
+                            -> Result<StagedPackage>
+    where R: Read + Any,
+          P: AsRef<Path> + ?Sized
+{
+    let root = fs_root_path.unwrap_or(Path::new("/"));
+    let staging = try!(StagingDir::new(root));
+    // Nothing is extracted before the whole artifact is verified, as the payload of a tampered
+    // artifact could otherwise write wherever it likes.
+    let payload = staging.path().join(STAGED_PAYLOAD);
+    let verified = {
+        let mut reader = try!(ArtifactReader::new(src, cache_key_path));
+        try!(io::copy(&mut reader, &mut try!(File::create(&payload))));
+        try!(reader.finish())
+    };
+    try!(check_entries(try!(File::open(&payload))));
+    try!(extract(try!(File::open(&payload)), staging.path()));
+
+    let mut installed = try!(staging.installed_paths());
+    if installed.len() != 1 {
+        return Err(Error::PackageUnpackFailed(format!("Artifact holds {} packages instead of one",
+                                                      installed.len())));
+    }
+    let installed_path = installed.pop().unwrap();
+    let ident = {
+        let parts: Vec<String> = installed_path
+            .strip_prefix(staging.path().join(PKG_PATH))
+            .expect("staged path is within the staging directory")
+            .iter()
+            .map(|part| part.to_string_lossy().into_owned())
+            .collect();
+        PackageIdent::new(parts[0].clone(),
+                          parts[1].clone(),
+                          Some(parts[2].clone()),
+                          Some(parts[3].clone()))
+    };
+    let package = PackageInstall::new_from_parts(ident,
+                                                 staging.path().to_path_buf(),
+                                                 staging.path().join(PKG_PATH),
+                                                 installed_path);
+    Ok(StagedPackage {
+           staging: staging,
+           package: package,
+           fs_root_path: root.to_path_buf(),
+           verified: verified,
+       })
+}
+
+/// A package unpacked from an artifact whose signatures checked out, but which is not installed
+/// yet. Dropping a `StagedPackage` without committing it discards its contents.
+pub struct StagedPackage {
+    staging: StagingDir,
+    package: PackageInstall,
+    fs_root_path: PathBuf,
+    verified: (String, String),
+}
+
+impl StagedPackage {
+    pub fn ident(&self) -> &PackageIdent {
+        self.package.ident()
+    }
+
+    /// The staged package, whose metadata is read from its verified contents.
+    pub fn package(&self) -> &PackageInstall {
+        &self.package
+    }
+
+    /// Move the package into the package path. The name with revision of the origin key is
+    /// returned along with the verified payload hash.
+    ///
+    /// # Failures
+    ///
+    /// * If the package cannot be moved into the package path
+    pub fn commit(self) -> Result<(String, String)> {
+        try!(self.staging.commit(&self.fs_root_path));
+        Ok(self.verified)
+    }
+}
+
+/// Check that every entry of a package payload lies within a package install directory, so
+/// extracting the payload can't write anywhere else.
+fn check_entries<R: Read + Any>(src: R) -> Result<()> {
+    let mut builder = reader::Builder::new();
+    try!(builder.support_format(ReadFormat::Gnutar));
+    try!(builder.support_filter(ReadFilter::Xz));
+    let mut reader = try!(builder.open_stream(src));
+    while let Some(entry) = reader.next_header() {
+        if !is_package_path(entry.pathname()) {
+            return Err(Error::PackageUnpackFailed(format!("Artifact holds {}, which is not \
+                                                           within a package",
+                                                          entry.pathname())));
+        }
+    }
+    Ok(())
+}
+
+/// Whether `path` lies within a package install directory
+/// (`hab/pkgs/<origin>/<name>/<version>/<release>`) without leaving it again.
+fn is_package_path(path: &str) -> bool {
+    let parts: Vec<&str> = path.trim_right_matches('/').split('/').collect();
+    parts.len() >= 6 && parts[0] == "hab" && parts[1] == "pkgs" &&
+    parts
+        .iter()
+        .all(|part| !part.is_empty() && *part != "." && *part != ".." && !part.contains('\\'))
+}
+
+fn extract<R: Read + Any>(src: R, root: &Path) -> Result<()> {
+    let mut builder = reader::Builder::new();
+    try!(builder.support_format(ReadFormat::Gnutar));
+    try!(builder.support_filter(ReadFilter::Xz));
+    let mut reader = try!(builder.open_stream(src));
+    let writer = writer::Disk::new();
+    let mut extract_options = ExtractOptions::new();
+    extract_options.add(ExtractOption::Time);
+    extract_options.add(ExtractOption::Owner);
+    extract_options.add(ExtractOption::Permissions);
+    extract_options.add(ExtractOption::SecureNoDotDot);
+    extract_options.add(ExtractOption::SecureSymlinks);
+    try!(writer.set_options(&extract_options));
+    try!(writer.set_standard_lookup());
+    try!(writer.write(&mut reader, Some(root.to_string_lossy().as_ref())));
+    try!(writer.close());
+    Ok(())
+}
+
+/// Name of the payload of an artifact being verified within its staging directory.
+const STAGED_PAYLOAD: &'static str = ".payload";
+
+/// A directory holding unverified package contents, which is removed when dropped.
+struct StagingDir {
+    path: PathBuf,
+}
+
+impl StagingDir {
+    fn new(fs_root_path: &Path) -> Result<Self> {
+        let path = cache_artifact_path(Some(fs_root_path)).join(format!(".unpack-{}-{}",
+                                                                        process::current_pid(),
+                                                                        time::precise_time_ns()));
+        try!(fs::create_dir_all(&path));
+        Ok(StagingDir { path: path })
+    }
+
+    fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Every staged package install directory
+    /// (`hab/pkgs/<origin>/<name>/<version>/<release>`).
+    fn installed_paths(&self) -> Result<Vec<PathBuf>> {
+        let mut paths = Vec::new();
+        let staged_pkgs = self.path.join(PKG_PATH);
+        if !staged_pkgs.is_dir() {
+            return Ok(paths);
+        }
+        for origin in try!(fs::read_dir(&staged_pkgs)) {
+            for name in try!(fs::read_dir(try!(origin).path())) {
+                for version in try!(fs::read_dir(try!(name).path())) {
+                    for release in try!(fs::read_dir(try!(version).path())) {
+                        paths.push(try!(release).path());
+                    }
+                }
+            }
+        }
+        Ok(paths)
+    }
+
+    /// Move every staged package install directory into place under the given filesystem root.
+    /// Packages which are already installed are left alone.
+    fn commit(&self, fs_root_path: &Path) -> Result<()> {
+        for staged in try!(self.installed_paths()) {
+            let relative = staged.strip_prefix(&self.path)
+                .expect("staged path is within the staging directory");
+            let installed = fs_root_path.join(relative);
+            if installed.exists() {
+                debug!("{} is already installed, skipping", installed.display());
+                continue;
+            }
+            if let Some(parent) = installed.parent() {
+                try!(fs::create_dir_all(parent));
+            }
+            try!(fs::rename(&staged, &installed));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for StagingDir {
+    fn drop(&mut self) {
+        if let Err(e) = fs::remove_dir_all(&self.path) {
+            debug!("Failed to remove staging directory {}, {}",
+                   self.path.display(),
+                   e);
+        }
+    }
+}
+
 pub trait FromArchive: Sized {
     type Error: error::Error;
 
@@ -326,8 +634,15 @@ pub trait FromArchive: Sized {
 
 #[cfg(test)]
 mod test {
+    use std::fs::{self, File};
     use std::path::PathBuf;
+
+    use tempdir::TempDir;
+
+    use crypto::SigKeyPair;
+    use fs::cache_artifact_path;
     use os::system::{Architecture, Platform};
+    use util::tar::TarWriter;
     use super::*;
 
     #[test]
@@ -357,6 +672,14 @@ mod test {
         let _ = hart.tdeps().unwrap();
     }
 
+    #[test]
+    fn reading_artifact_without_binds() {
+        let mut hart = PackageArchive::new(fixtures()
+            .join("happyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart"));
+        assert!(hart.binds().unwrap().is_empty());
+        assert!(hart.binds_optional().unwrap().is_empty());
+    }
+
     #[test]
     fn reading_artifact_large_tdeps() {
         let mut hart = PackageArchive::new(fixtures()
@@ -365,6 +688,102 @@ mod test {
         assert_eq!(1024, tdeps.len());
     }
 
+    #[test]
+    fn unpack_verified_stages_until_verified() {
+        let root = TempDir::new("fs_root").unwrap();
+        let cache = TempDir::new("key_cache").unwrap();
+        let hart = fixtures().join("happyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart");
+
+        // Without the origin's public key the artifact can't be verified and nothing is
+        // installed
+        assert!(unpack_verified(File::open(&hart).unwrap(), cache.path(), Some(root.path()))
+                    .is_err());
+        assert!(!root.path()
+                     .join("hab/pkgs/happyhumans/possums")
+                     .exists());
+
+        fs::copy(fixtures().join("happyhumans-20160424223347.pub"),
+                 cache.path().join("happyhumans-20160424223347.pub"))
+            .unwrap();
+        let (name_with_rev, _) = unpack_verified(File::open(&hart).unwrap(),
+                                                 cache.path(),
+                                                 Some(root.path()))
+            .unwrap();
+        assert_eq!(name_with_rev, "happyhumans-20160424223347");
+        assert!(root.path()
+                    .join("hab/pkgs/happyhumans/possums/8.1.4/20160427165340/IDENT")
+                    .is_file());
+        assert_eq!(fs::read_dir(cache_artifact_path(Some(root.path())))
+                       .unwrap()
+                       .count(),
+                   0);
+    }
+
+    #[test]
+    fn stage_verified_reads_verified_metadata() {
+        let root = TempDir::new("fs_root").unwrap();
+        let cache = TempDir::new("key_cache").unwrap();
+        let hart = fixtures().join("happyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart");
+        fs::copy(fixtures().join("happyhumans-20160424223347.pub"),
+                 cache.path().join("happyhumans-20160424223347.pub"))
+            .unwrap();
+
+        let staged = stage_verified(File::open(&hart).unwrap(), cache.path(), Some(root.path()))
+            .unwrap();
+        assert_eq!(staged.ident().to_string(),
+                   "happyhumans/possums/8.1.4/20160427165340");
+        assert_eq!(staged.package().tdeps().unwrap(),
+                   PackageArchive::new(&hart).tdeps().unwrap());
+        let installed = root.path()
+            .join("hab/pkgs/happyhumans/possums/8.1.4/20160427165340");
+        assert!(!installed.exists());
+
+        let (name_with_rev, _) = staged.commit().unwrap();
+        assert_eq!(name_with_rev, "happyhumans-20160424223347");
+        assert!(installed.join("IDENT").is_file());
+    }
+
+    #[test]
+    fn stage_verified_refuses_entries_outside_package() {
+        let root = TempDir::new("fs_root").unwrap();
+        let cache = TempDir::new("key_cache").unwrap();
+        let work = TempDir::new("artifact").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let prefix = "hab/pkgs/unicorn/foo/1.0.0/20170101000000";
+        let payload = work.path().join("payload.tar");
+        {
+            let mut tar = TarWriter::new(File::create(&payload).unwrap());
+            tar.append_file(&format!("{}/IDENT", prefix),
+                            0o644,
+                            18,
+                            &mut "unicorn/foo/1.0.0\n".as_bytes())
+                .unwrap();
+            tar.append_file(&format!("{}/../../../../../../../escaped", prefix),
+                            0o644,
+                            4,
+                            &mut "boo\n".as_bytes())
+                .unwrap();
+            tar.finish().unwrap();
+        }
+        let hart = work.path().join("unicorn-foo-1.0.0-20170101000000-x86_64-linux.hart");
+        artifact::sign(&payload, &hart, &pair).unwrap();
+
+        assert!(stage_verified(File::open(&hart).unwrap(), cache.path(), Some(root.path()))
+                    .is_err());
+        assert!(!root.path().join("escaped").exists());
+        assert!(!cache_artifact_path(Some(root.path())).join("escaped").exists());
+    }
+
+    #[test]
+    fn package_paths() {
+        assert!(is_package_path("hab/pkgs/core/foo/1.0.0/20170101000000/"));
+        assert!(is_package_path("hab/pkgs/core/foo/1.0.0/20170101000000/bin/foo"));
+        assert!(!is_package_path("hab/pkgs/core/foo/"));
+        assert!(!is_package_path("/hab/pkgs/core/foo/1.0.0/20170101000000/IDENT"));
+        assert!(!is_package_path("hab/pkgs/core/foo/1.0.0/20170101000000/../../../../../x"));
+        assert!(!is_package_path("hab/svc/foo/1.0.0/20170101000000/config"));
+    }
+
     #[test]
     fn reading_artifact_target() {
         let mut hart = PackageArchive::new(fixtures()
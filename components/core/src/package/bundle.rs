@@ -0,0 +1,279 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bundles of package artifacts together with the public origin keys needed to verify them, for
+//! moving packages into environments without network access.
+//!
+//! A bundle is an uncompressed tarball holding a `MANIFEST`, the artifacts under `artifacts/` and
+//! the public origin keys under `keys/`. The tarball is signed with an origin key in the same
+//! format as a package artifact, so bundles can also be inspected with `hab pkg header` and
+//! checked with `hab pkg verify`.
+//!
+//! Each line of the `MANIFEST` names a bundled file: `artifact <checksum> <file name>` for
+//! artifacts, in the order they should be installed in, and `key <file name>` for keys.
+
+use std::fs::{self, File};
+use std::io::{BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+use libarchive::reader::{self, Reader};
+use libarchive::archive::{Entry, ReadFormat};
+
+use crypto::{artifact, hash, SigKeyPair, PUBLIC_SIG_KEY_VERSION};
+use crypto::artifact::ArtifactReader;
+use error::{Error, Result};
+use util::tar::TarWriter;
+
+const MANIFEST: &'static str = "MANIFEST";
+const ARTIFACTS_DIR: &'static str = "artifacts";
+const KEYS_DIR: &'static str = "keys";
+
+/// The contents of a verified bundle which has been extracted to disk.
+#[derive(Debug)]
+pub struct Bundle {
+    /// Name with revision of the origin key the bundle was signed with
+    pub signer: String,
+    /// Bundled artifacts in the order they should be installed in
+    pub artifacts: Vec<PathBuf>,
+    /// Bundled public origin keys
+    pub keys: Vec<PathBuf>,
+}
+
+/// Write a signed bundle of the given artifacts and public origin keys to `dst`. Artifacts are
+/// listed in the bundle in the order given, which should have dependencies before their
+/// dependents.
+///
+/// # Failures
+///
+/// * If an artifact or key cannot be read
+/// * If the bundle cannot be written or signed
+pub fn create<P: AsRef<Path> + ?Sized>(dst: &P,
+                                       artifacts: &[PathBuf],
+                                       keys: &[PathBuf],
+                                       pair: &SigKeyPair)
+                                       -> Result<()> {
+    let dst = dst.as_ref();
+    let mut manifest = String::new();
+    for artifact in artifacts.iter() {
+        manifest.push_str(&format!("artifact {} {}\n",
+                                   try!(hash::hash_file(artifact)),
+                                   try!(file_name(artifact))));
+    }
+    for key in keys.iter() {
+        manifest.push_str(&format!("key {}\n", try!(file_name(key))));
+    }
+
+    let unsigned = dst.with_extension("unsigned");
+    {
+        let mut tar = TarWriter::new(BufWriter::new(try!(File::create(&unsigned))));
+        try!(tar.append_file(MANIFEST,
+                             0o644,
+                             manifest.len() as u64,
+                             &mut manifest.as_bytes()));
+        for artifact in artifacts.iter() {
+            let path = format!("{}/{}", ARTIFACTS_DIR, try!(file_name(artifact)));
+            try!(tar.append_file_from_path(&path, 0o644, artifact));
+        }
+        for key in keys.iter() {
+            let path = format!("{}/{}", KEYS_DIR, try!(file_name(key)));
+            try!(tar.append_file_from_path(&path, 0o644, key));
+        }
+        try!(try!(tar.finish()).flush());
+    }
+    let signed = artifact::sign(&unsigned, dst, pair);
+    try!(fs::remove_file(&unsigned));
+    signed
+}
+
+/// Verify a bundle against the keys in `cache_key_path` and extract it into `dst` in a single
+/// pass. Nothing extracted should be used if this fails, as the signature of the bundle is only
+/// known to be good once it's been read in full.
+///
+/// # Failures
+///
+/// * If the bundle's signature cannot be verified
+/// * If the bundle holds anything other than the files listed in its manifest
+/// * If the checksum of a bundled artifact doesn't match its manifest entry
+pub fn extract<P1: ?Sized, P2: ?Sized, P3: ?Sized>(src: &P1,
+                                                   cache_key_path: &P2,
+                                                   dst: &P3)
+                                                   -> Result<Bundle>
+    where P1: AsRef<Path>,
+          P2: AsRef<Path>,
+          P3: AsRef<Path>
+{
+    let dst = dst.as_ref();
+    try!(fs::create_dir_all(dst.join(ARTIFACTS_DIR)));
+    try!(fs::create_dir_all(dst.join(KEYS_DIR)));
+
+    let reader = try!(ArtifactReader::new(try!(File::open(src.as_ref())), cache_key_path));
+    let mut extracted = Vec::new();
+    {
+        let mut builder = reader::Builder::new();
+        try!(builder.support_format(ReadFormat::Gnutar));
+        let mut archive = try!(builder.open_stream(reader.clone()));
+        loop {
+            let path = match archive.next_header() {
+                Some(entry) => entry.pathname().to_string(),
+                None => break,
+            };
+            if !is_bundle_path(&path) || extracted.contains(&path) {
+                return Err(Error::InvalidBundle(format!("unexpected entry {}", path)));
+            }
+            let mut file = try!(File::create(dst.join(&path)));
+            while let Some(bytes) = try!(archive.read_block()) {
+                try!(file.write_all(bytes));
+            }
+            extracted.push(path);
+        }
+    }
+    let (signer, _) = try!(reader.finish());
+
+    let manifest = try!(read_manifest(&dst.join(MANIFEST)));
+    let mut bundle = Bundle {
+        signer: signer,
+        artifacts: Vec::new(),
+        keys: Vec::new(),
+    };
+    for line in manifest.lines() {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let path = match (fields.len(), fields.first()) {
+            (3, Some(&"artifact")) => {
+                let (checksum, name) = (fields[1], fields[2]);
+                let path = format!("{}/{}", ARTIFACTS_DIR, name);
+                let computed = try!(hash::hash_file(&dst.join(&path)));
+                if computed != checksum {
+                    return Err(Error::InvalidBundle(format!("checksum of {} is {}, expected {}",
+                                                            name,
+                                                            computed,
+                                                            checksum)));
+                }
+                bundle.artifacts.push(dst.join(&path));
+                path
+            }
+            (2, Some(&"key")) => {
+                let path = format!("{}/{}", KEYS_DIR, fields[1]);
+                if extracted.contains(&path) && !try!(is_public_key(&dst.join(&path))) {
+                    return Err(Error::InvalidBundle(format!("{} is not a public origin key",
+                                                            fields[1])));
+                }
+                bundle.keys.push(dst.join(&path));
+                path
+            }
+            _ => return Err(Error::InvalidBundle(format!("malformed manifest line {:?}", line))),
+        };
+        if !extracted.contains(&path) {
+            return Err(Error::InvalidBundle(format!("{} is missing", path)));
+        }
+    }
+    if extracted.len() != bundle.artifacts.len() + bundle.keys.len() + 1 {
+        return Err(Error::InvalidBundle("bundle holds files missing from its manifest"
+                                            .to_string()));
+    }
+    Ok(bundle)
+}
+
+fn read_manifest(path: &Path) -> Result<String> {
+    if !path.is_file() {
+        return Err(Error::InvalidBundle(format!("{} is missing", MANIFEST)));
+    }
+    let mut manifest = String::new();
+    try!(try!(File::open(path)).read_to_string(&mut manifest));
+    Ok(manifest)
+}
+
+fn is_public_key(path: &Path) -> Result<bool> {
+    let mut content = String::new();
+    try!(try!(File::open(path)).read_to_string(&mut content));
+    Ok(content.lines().next() == Some(PUBLIC_SIG_KEY_VERSION))
+}
+
+/// Returns whether a path is one a bundle may hold. Bundled files are never nested, so this also
+/// rules out entries escaping the directory the bundle is extracted into.
+fn is_bundle_path(path: &str) -> bool {
+    if path == MANIFEST {
+        return true;
+    }
+    let mut parts = path.splitn(2, '/');
+    let (dir, name) = match (parts.next(), parts.next()) {
+        (Some(dir), Some(name)) => (dir, name),
+        _ => return false,
+    };
+    if name.is_empty() || name.starts_with('.') || name.contains('/') || name.contains('\\') {
+        return false;
+    }
+    (dir == ARTIFACTS_DIR && name.ends_with(".hart")) || (dir == KEYS_DIR && name.ends_with(".pub"))
+}
+
+fn file_name(path: &Path) -> Result<String> {
+    match path.file_name() {
+        Some(name) => Ok(name.to_string_lossy().into_owned()),
+        None => Err(Error::FileNotFound(path.to_string_lossy().into_owned())),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use tempdir::TempDir;
+
+    use crypto::SigKeyPair;
+    use crypto::test_support::fixture;
+    use super::*;
+
+    #[test]
+    fn bundle_paths() {
+        assert!(is_bundle_path("MANIFEST"));
+        assert!(is_bundle_path("artifacts/core-foo-1.0.0-20170101000000-x86_64-linux.hart"));
+        assert!(is_bundle_path("keys/core-20160810182414.pub"));
+        assert!(!is_bundle_path("artifacts/../../etc/passwd.hart"));
+        assert!(!is_bundle_path("keys/core-20160810182414.sig.key"));
+        assert!(!is_bundle_path("artifacts/"));
+        assert!(!is_bundle_path("/etc/passwd"));
+        assert!(!is_bundle_path("hab/pkgs/core/foo"));
+    }
+
+    #[test]
+    fn create_and_extract() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let work = TempDir::new("bundle").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+        let key = SigKeyPair::get_public_key_path(&pair.name_with_rev(), cache.path()).unwrap();
+        let artifact = work.path().join("unicorn-foo-1.0.0-20170101000000-x86_64-linux.hart");
+        fs::copy(fixture("signme.dat"), &artifact).unwrap();
+
+        let dst = work.path().join("unicorn.bundle");
+        create(&dst, &[artifact.clone()], &[key.clone()], &pair).unwrap();
+        let bundle = extract(&dst, cache.path(), &work.path().join("extracted")).unwrap();
+
+        assert_eq!(bundle.signer, pair.name_with_rev());
+        assert_eq!(bundle.artifacts.len(), 1);
+        assert_eq!(bundle.artifacts[0].file_name(), artifact.file_name());
+        assert_eq!(bundle.keys.len(), 1);
+        assert_eq!(bundle.keys[0].file_name(), key.file_name());
+    }
+
+    #[test]
+    fn extract_requires_a_trusted_signer() {
+        let cache = TempDir::new("key_cache").unwrap();
+        let other_cache = TempDir::new("other_key_cache").unwrap();
+        let work = TempDir::new("bundle").unwrap();
+        let pair = SigKeyPair::generate_pair_for_origin("unicorn", cache.path()).unwrap();
+
+        let dst = work.path().join("unicorn.bundle");
+        create(&dst, &[], &[], &pair).unwrap();
+        assert!(extract(&dst, other_cache.path(), &work.path().join("extracted")).is_err());
+    }
+}
@@ -27,7 +27,7 @@ use toml;
 use toml::Value;
 
 use super::{Identifiable, PackageIdent, Target, PackageTarget};
-use super::metadata::{Bind, MetaFile};
+use super::metadata::{self, Bind, MetaFile, PackageFile};
 use error::{Error, Result};
 use fs;
 
@@ -245,7 +245,7 @@ impl PackageInstall {
         }
     }
 
-    fn deps(&self) -> Result<Vec<PackageIdent>> {
+    pub fn deps(&self) -> Result<Vec<PackageIdent>> {
         self.read_deps(MetaFile::Deps)
     }
 
@@ -360,6 +360,29 @@ impl PackageInstall {
         }
     }
 
+    /// Returns the signed listing of BLAKE2b checksums for every file in the package, if the
+    /// package has one.
+    pub fn files(&self) -> Result<Option<String>> {
+        match self.read_metafile(MetaFile::Files) {
+            Ok(body) => Ok(Some(body)),
+            Err(Error::MetaFileNotFound(MetaFile::Files)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Returns every file listed in the package's `FILES` metafile along with its checksum, or
+    /// nothing if the package doesn't have one.
+    pub fn file_list(&self) -> Result<Vec<PackageFile>> {
+        match try!(self.files()) {
+            Some(listing) => Ok(metadata::parse_files(&listing)),
+            None => Ok(vec![]),
+        }
+    }
+
+    pub fn manifest(&self) -> Result<String> {
+        self.read_metafile(MetaFile::Manifest)
+    }
+
     pub fn ident(&self) -> &PackageIdent {
         &self.ident
     }
@@ -533,7 +556,8 @@ impl PackageInstall {
         }
     }
 
-    fn target(&self) -> Result<PackageTarget> {
+    /// Returns the target the package was built for.
+    pub fn target(&self) -> Result<PackageTarget> {
         match self.read_metafile(MetaFile::Target) {
             Ok(body) => PackageTarget::from_str(&body),
             Err(e) => Err(e),
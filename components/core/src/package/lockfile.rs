@@ -0,0 +1,188 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A lockfile pins every fully qualified package identifier of an install, together with the
+//! BLAKE2b checksum of its artifact, so that the same install can be reproduced later on another
+//! machine.
+//!
+//! # Examples
+//!
+//! ```toml
+//! [[package]]
+//! ident = "core/redis/3.2.4/20170514150022"
+//! checksum = "0ba1ef8c4ed3d8b8d0e5d4a8dfb1c7c8a2f7e6e46b1cbbbd12c93d0ba9d4d9c8"
+//! ```
+
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::str::FromStr;
+
+use toml;
+
+use config::ConfigFile;
+use error::{Error, Result};
+use package::{Identifiable, PackageIdent};
+
+#[derive(Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct Lockfile {
+    #[serde(default, rename = "package")]
+    pub packages: Vec<LockedPackage>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct LockedPackage {
+    pub ident: String,
+    pub checksum: String,
+}
+
+impl ConfigFile for Lockfile {
+    type Error = Error;
+}
+
+impl Lockfile {
+    /// Returns the fully qualified package identifier pinned for the given identifier. If more
+    /// than one pinned package satisfies the identifier then the latest one is returned.
+    ///
+    /// # Failures
+    ///
+    /// * If a pinned package identifier cannot be parsed
+    pub fn pinned_ident_for(&self, ident: &PackageIdent) -> Result<Option<PackageIdent>> {
+        let mut latest: Option<PackageIdent> = None;
+        for package in self.packages.iter() {
+            let pinned = PackageIdent::from_str(&package.ident)?;
+            if !pinned.satisfies(ident) {
+                continue;
+            }
+            latest = match latest {
+                Some(ref current) if current > &pinned => Some(current.clone()),
+                _ => Some(pinned),
+            };
+        }
+        Ok(latest)
+    }
+
+    /// Returns the artifact checksum pinned for the given fully qualified package identifier.
+    pub fn checksum_for(&self, ident: &PackageIdent) -> Option<&str> {
+        let ident = ident.to_string();
+        self.packages
+            .iter()
+            .find(|p| p.ident == ident)
+            .map(|p| p.checksum.as_str())
+    }
+
+    /// Pins a fully qualified package identifier to an artifact checksum, replacing any previous
+    /// pin for the same identifier.
+    ///
+    /// # Failures
+    ///
+    /// * If the package identifier is not fully qualified
+    pub fn insert(&mut self, ident: &PackageIdent, checksum: String) -> Result<()> {
+        if !ident.fully_qualified() {
+            return Err(Error::InvalidPackageIdent(ident.to_string()));
+        }
+        let ident = ident.to_string();
+        match self.packages.iter_mut().find(|p| p.ident == ident) {
+            Some(package) => package.checksum = checksum,
+            None => {
+                self.packages.push(LockedPackage {
+                                       ident: ident,
+                                       checksum: checksum,
+                                   })
+            }
+        }
+        self.packages.sort_by(|a, b| a.ident.cmp(&b.ident));
+        Ok(())
+    }
+
+    /// Writes the lockfile out as TOML to the given path.
+    ///
+    /// # Failures
+    ///
+    /// * If the lockfile cannot be serialized
+    /// * If the file cannot be written
+    pub fn write<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let raw = toml::to_string(self).map_err(|e| Error::LockfileSerialize(e))?;
+        let mut file = File::create(path.as_ref())?;
+        file.write_all(raw.as_bytes())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use tempdir::TempDir;
+
+    use config::ConfigFile;
+    use package::PackageIdent;
+    use super::*;
+
+    fn ident(s: &str) -> PackageIdent {
+        PackageIdent::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn pinned_ident_for_returns_latest_satisfying_pin() {
+        let mut lockfile = Lockfile::default();
+        lockfile
+            .insert(&ident("core/redis/3.2.3/20170101000000"), "a".to_string())
+            .unwrap();
+        lockfile
+            .insert(&ident("core/redis/3.2.4/20170514150022"), "b".to_string())
+            .unwrap();
+        lockfile
+            .insert(&ident("core/glibc/2.22/20170513201042"), "c".to_string())
+            .unwrap();
+
+        assert_eq!(lockfile.pinned_ident_for(&ident("core/redis")).unwrap(),
+                   Some(ident("core/redis/3.2.4/20170514150022")));
+        assert_eq!(lockfile.pinned_ident_for(&ident("core/redis/3.2.3")).unwrap(),
+                   Some(ident("core/redis/3.2.3/20170101000000")));
+        assert_eq!(lockfile.pinned_ident_for(&ident("core/nginx")).unwrap(), None);
+    }
+
+    #[test]
+    fn insert_requires_fully_qualified_ident() {
+        let mut lockfile = Lockfile::default();
+        assert!(lockfile
+                    .insert(&ident("core/redis/3.2.4"), "a".to_string())
+                    .is_err());
+    }
+
+    #[test]
+    fn insert_replaces_existing_checksum() {
+        let mut lockfile = Lockfile::default();
+        let redis = ident("core/redis/3.2.4/20170514150022");
+        lockfile.insert(&redis, "a".to_string()).unwrap();
+        lockfile.insert(&redis, "b".to_string()).unwrap();
+        assert_eq!(lockfile.packages.len(), 1);
+        assert_eq!(lockfile.checksum_for(&redis), Some("b"));
+    }
+
+    #[test]
+    fn write_and_read_roundtrip() {
+        let dir = TempDir::new("lockfile").unwrap();
+        let path = dir.path().join("hab.lock");
+        let mut lockfile = Lockfile::default();
+        lockfile
+            .insert(&ident("core/redis/3.2.4/20170514150022"), "abc123".to_string())
+            .unwrap();
+        lockfile.write(&path).unwrap();
+
+        let read = Lockfile::from_file(&path).unwrap();
+        assert_eq!(read, lockfile);
+    }
+}
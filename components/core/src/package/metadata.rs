@@ -43,6 +43,38 @@ impl FromStr for Bind {
     }
 }
 
+/// A file listed in a package's `FILES` metafile.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PackageFile {
+    pub path: String,
+    /// BLAKE2b checksum of the file's contents
+    pub checksum: String,
+}
+
+/// Parses the contents of a signed `FILES` metafile, whose payload follows the signature and
+/// holds one `<checksum>  <path>` line per file.
+pub fn parse_files(listing: &str) -> Vec<PackageFile> {
+    let payload = match listing.find("\n\n") {
+        Some(i) => &listing[i + 2..],
+        None => return Vec::new(),
+    };
+    payload
+        .lines()
+        .filter_map(|line| {
+                        let mut parts = line.splitn(2, "  ");
+                        match (parts.next(), parts.next().map(|path| path.trim())) {
+                            (Some(checksum), Some(path)) if !path.is_empty() => {
+                                Some(PackageFile {
+                                         path: path.to_string(),
+                                         checksum: checksum.to_string(),
+                                     })
+                            }
+                            _ => None,
+                        }
+                    })
+        .collect()
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub enum MetaFile {
     Binds,
@@ -55,6 +87,7 @@ pub enum MetaFile {
     EnvironmentSep,
     Exports,
     Exposes,
+    Files,
     Ident,
     LdRunPath,
     LdFlags,
@@ -78,6 +111,7 @@ impl fmt::Display for MetaFile {
             MetaFile::EnvironmentSep => "ENVIRONMENT_SEP",
             MetaFile::Exports => "EXPORTS",
             MetaFile::Exposes => "EXPOSES",
+            MetaFile::Files => "FILES",
             MetaFile::Ident => "IDENT",
             MetaFile::LdRunPath => "LD_RUN_PATH",
             MetaFile::LdFlags => "LDFLAGS",
@@ -90,3 +124,31 @@ impl fmt::Display for MetaFile {
         write!(f, "{}", id)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_signed_files_listing() {
+        let listing = "HART-1\ncore-20160810182414\nBLAKE2b\nc2lnbmF0dXJl\n\n\
+                       abc123  /hab/pkgs/core/redis/3.2.4/20170514150022/bin/redis-server\n\
+                       def456  /hab/pkgs/core/redis/3.2.4/20170514150022/MANIFEST\n";
+        assert_eq!(parse_files(listing),
+                   vec![PackageFile {
+                            path: "/hab/pkgs/core/redis/3.2.4/20170514150022/bin/redis-server"
+                                .to_string(),
+                            checksum: "abc123".to_string(),
+                        },
+                        PackageFile {
+                            path: "/hab/pkgs/core/redis/3.2.4/20170514150022/MANIFEST"
+                                .to_string(),
+                            checksum: "def456".to_string(),
+                        }]);
+    }
+
+    #[test]
+    fn parse_files_without_signature() {
+        assert!(parse_files("not a signed listing").is_empty());
+    }
+}
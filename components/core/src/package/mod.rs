@@ -13,15 +13,19 @@
 // limitations under the License.
 
 pub mod archive;
+pub mod bundle;
 pub mod ident;
 pub mod install;
+pub mod lockfile;
 pub mod metadata;
 pub mod plan;
 pub mod target;
 
-pub use self::archive::{FromArchive, PackageArchive};
+pub use self::archive::{FromArchive, PackageArchive, StagedPackage};
 pub use self::ident::{Identifiable, PackageIdent};
 pub use self::install::PackageInstall;
+pub use self::lockfile::Lockfile;
+pub use self::metadata::PackageFile;
 pub use self::plan::Plan;
 pub use self::target::{Target, PackageTarget};
 
@@ -14,6 +14,7 @@
 
 pub mod perm;
 pub mod sys;
+pub mod tar;
 
 use std::error;
 use std::fmt;
@@ -0,0 +1,182 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A minimal writer of POSIX tarballs, for archives which don't need compression or any of the
+//! metadata of the files they contain.
+
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+const TAR_BLOCK_SIZE: usize = 512;
+
+/// Writes entries to a tarball. Paths which don't fit in a ustar header are written to a
+/// PAX extended header.
+pub struct TarWriter<W: Write> {
+    inner: W,
+}
+
+impl<W: Write> TarWriter<W> {
+    pub fn new(inner: W) -> Self {
+        TarWriter { inner: inner }
+    }
+
+    pub fn append_dir(&mut self, path: &str, mode: u32) -> io::Result<()> {
+        self.append_header(&format!("{}/", path), mode, 0, b'5', "")
+    }
+
+    pub fn append_symlink(&mut self, path: &str, target: &str) -> io::Result<()> {
+        self.append_header(path, 0o777, 0, b'2', target)
+    }
+
+    pub fn append_file_from_path(&mut self, path: &str, mode: u32, src: &Path) -> io::Result<()> {
+        let mut file = try!(File::open(src));
+        let size = try!(file.metadata()).len();
+        self.append_file(path, mode, size, &mut file)
+    }
+
+    pub fn append_file<R: Read>(&mut self,
+                                path: &str,
+                                mode: u32,
+                                size: u64,
+                                data: &mut R)
+                                -> io::Result<()> {
+        try!(self.append_header(path, mode, size, b'0', ""));
+        let copied = try!(io::copy(&mut data.take(size), &mut self.inner));
+        if copied != size {
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof,
+                                      format!("{} changed size while being archived", path)));
+        }
+        self.pad(size)
+    }
+
+    pub fn finish(mut self) -> io::Result<W> {
+        try!(self.inner.write_all(&[0; TAR_BLOCK_SIZE * 2]));
+        Ok(self.inner)
+    }
+
+    fn append_header(&mut self,
+                     path: &str,
+                     mode: u32,
+                     size: u64,
+                     kind: u8,
+                     link: &str)
+                     -> io::Result<()> {
+        if path.len() > 100 || link.len() > 100 {
+            let mut records = String::new();
+            if path.len() > 100 {
+                records.push_str(&pax_record("path", path));
+            }
+            if link.len() > 100 {
+                records.push_str(&pax_record("linkpath", link));
+            }
+            let header = ustar_header("././@PaxHeader", 0o644, records.len() as u64, b'x', "");
+            try!(self.inner.write_all(&header));
+            try!(self.inner.write_all(records.as_bytes()));
+            try!(self.pad(records.len() as u64));
+        }
+        self.inner
+            .write_all(&ustar_header(path, mode, size, kind, link))
+    }
+
+    fn pad(&mut self, size: u64) -> io::Result<()> {
+        let remainder = (size % TAR_BLOCK_SIZE as u64) as usize;
+        if remainder > 0 {
+            try!(self.inner
+                     .write_all(&[0; TAR_BLOCK_SIZE][..TAR_BLOCK_SIZE - remainder]));
+        }
+        Ok(())
+    }
+}
+
+/// Builds a ustar header, truncating names which are too long. Entries are always owned by root
+/// with a modification time of zero.
+fn ustar_header(path: &str, mode: u32, size: u64, kind: u8, link: &str) -> [u8; TAR_BLOCK_SIZE] {
+    let mut header = [0u8; TAR_BLOCK_SIZE];
+    copy_truncated(&mut header[0..100], path.as_bytes());
+    copy_truncated(&mut header[100..108], format!("{:07o}", mode).as_bytes());
+    copy_truncated(&mut header[108..116], b"0000000");
+    copy_truncated(&mut header[116..124], b"0000000");
+    copy_truncated(&mut header[124..136], format!("{:011o}", size).as_bytes());
+    copy_truncated(&mut header[136..148], b"00000000000");
+    header[156] = kind;
+    copy_truncated(&mut header[157..257], link.as_bytes());
+    copy_truncated(&mut header[257..263], b"ustar\0");
+    copy_truncated(&mut header[263..265], b"00");
+    copy_truncated(&mut header[265..297], b"root");
+    copy_truncated(&mut header[297..329], b"root");
+
+    // The checksum is computed with the checksum field itself filled with spaces
+    for b in header[148..156].iter_mut() {
+        *b = b' ';
+    }
+    let checksum: u32 = header.iter().map(|b| *b as u32).sum();
+    copy_truncated(&mut header[148..156], format!("{:06o}\0 ", checksum).as_bytes());
+    header
+}
+
+fn copy_truncated(dst: &mut [u8], src: &[u8]) {
+    let len = if src.len() < dst.len() { src.len() } else { dst.len() };
+    dst[..len].copy_from_slice(&src[..len]);
+}
+
+/// Formats a PAX extended header record, which is prefixed with its own length in bytes.
+fn pax_record(key: &str, value: &str) -> String {
+    let body = format!(" {}={}\n", key, value);
+    let mut len = body.len() + 1;
+    while (len.to_string().len() + body.len()) != len {
+        len = len.to_string().len() + body.len();
+    }
+    format!("{}{}", len, body)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{pax_record, ustar_header, TarWriter};
+
+    #[test]
+    fn pax_record_includes_own_length() {
+        assert_eq!(pax_record("path", "a"), "9 path=a\n");
+        let long = "a".repeat(200);
+        let record = pax_record("path", &long);
+        let (len, _) = record.split_at(record.find(' ').unwrap());
+        assert_eq!(len.parse::<usize>().unwrap(), record.len());
+    }
+
+    #[test]
+    fn ustar_header_checksum() {
+        let header = ustar_header("hab/", 0o755, 0, b'5', "");
+        let stored = String::from_utf8(header[148..154].to_vec()).unwrap();
+        let mut blanked = header;
+        for b in blanked[148..156].iter_mut() {
+            *b = b' ';
+        }
+        let expected: u32 = blanked.iter().map(|b| *b as u32).sum();
+        assert_eq!(u32::from_str_radix(&stored, 8).unwrap(), expected);
+        assert_eq!(&header[257..263], b"ustar\0");
+    }
+
+    #[test]
+    fn tar_entries_are_block_aligned() {
+        let mut tar = TarWriter::new(Vec::new());
+        tar.append_dir("hab", 0o755).unwrap();
+        tar.append_file("hab/file", 0o644, 3, &mut &b"abc"[..]).unwrap();
+        tar.append_symlink(&format!("hab/{}", "l".repeat(120)), "file")
+            .unwrap();
+        let bytes = tar.finish().unwrap();
+        // dir header, file header + data block, pax header + records block, symlink header,
+        // and two end of archive blocks
+        assert_eq!(bytes.len(), 512 * 8);
+    }
+}
@@ -21,6 +21,8 @@ use hcore::crypto::keys::PairType;
 use regex::Regex;
 use url::Url;
 
+use command::pkg::sbom::SbomFormat;
+
 pub fn get() -> App<'static, 'static> {
     let alias_apply = sub_config_apply()
         .about("Alias for 'config apply'")
@@ -41,6 +43,26 @@ pub fn get() -> App<'static, 'static> {
         (author: "\nAuthors: The Habitat Maintainers <humans@habitat.sh>\n")
         (@setting VersionlessSubcommands)
         (@setting ArgRequiredElseHelp)
+        (@subcommand bldr =>
+            (about: "Commands relating to Habitat Builder")
+            (aliases: &["b", "bl", "bld"])
+            (@setting ArgRequiredElseHelp)
+            (@subcommand group =>
+                (about: "Commands relating to groups of packages rebuilt by the Builder scheduler")
+                (aliases: &["g", "gr", "gro", "grou"])
+                (@setting ArgRequiredElseHelp)
+                (@subcommand status =>
+                    (about: "Shows the progress of a group rebuild and the projects which failed")
+                    (aliases: &["s", "st", "sta", "stat", "statu"])
+                    (@arg GROUP_ID: +required +takes_value {valid_group_id}
+                        "The group id returned when the rebuild was scheduled")
+                    (@arg DEPOT_URL: -u --url +takes_value {valid_url}
+                        "Use a specific Depot URL (ex: http://depot.example.com/v1/depot)")
+                    (@arg WATCH: -w --watch
+                        "Keep polling the group until it is complete or failed")
+                )
+            )
+        )
         (@subcommand cli =>
             (about: "Commands relating to Habitat runtime config")
             (aliases: &["cl"])
@@ -141,6 +163,39 @@ pub fn get() -> App<'static, 'static> {
                 (@arg DEST_DIR: -d --dest +takes_value
                     "Sets the destination directory (default: /bin)")
             )
+            (@subcommand bundle =>
+                (about: "Commands relating to offline bundles of Habitat packages")
+                (aliases: &["bun", "bund", "bundl"])
+                (@setting ArgRequiredElseHelp)
+                (@subcommand create =>
+                    (about: "Creates a signed bundle of packages, their transitive dependencies \
+                        and the public origin keys needed to verify them")
+                    (aliases: &["c", "cr", "cre", "crea", "creat"])
+                    (@arg PKG_IDENT: +required +multiple
+                        "One or more package identifiers (ex: core/redis, \
+                        core/busybox-static/1.42.2)")
+                    (@arg ORIGIN: --origin +takes_value "Origin key used to sign the bundle")
+                    (@arg OUTPUT: -o --output +takes_value
+                        "The path of the bundle to write (default: named after the last package)")
+                    (@arg DEPOT_URL: -u --url +takes_value {valid_url}
+                        "Use a specific Depot URL (ex: http://depot.example.com/v1/depot)")
+                    (@arg CHANNEL: --channel +takes_value
+                        "Install missing packages from the specified release channel")
+                )
+                (@subcommand install =>
+                    (about: "Installs every package in a bundle without contacting a Depot")
+                    (aliases: &["i", "in", "ins", "inst", "insta", "instal"])
+                    (@arg BUNDLE: +required {file_exists} "A path to a bundle")
+                )
+                (@subcommand upload =>
+                    (about: "Uploads a bundle to a Depot, importing the packages and keys in it")
+                    (aliases: &["u", "up", "upl", "uplo", "uploa"])
+                    (@arg DEPOT_URL: -u --url +takes_value {valid_url}
+                        "Use a specific Depot URL (ex: http://depot.example.com/v1/depot)")
+                    (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for the Depot")
+                    (@arg BUNDLE: +required {file_exists} "A path to a bundle")
+                )
+            )
             (@subcommand config =>
                 (about: "Displays the default configuration options for a service")
                 (aliases: &["conf", "cfg"])
@@ -167,9 +222,12 @@ pub fn get() -> App<'static, 'static> {
                 (about: "Exports the package to the specified format")
                 (aliases: &["exp"])
                 (@arg FORMAT: +required +takes_value
-                    "The export format (ex: docker, aci, mesos, or tar)")
+                    "The export format (ex: docker, aci, mesos, tar, oci, or docker-archive)")
                 (@arg PKG_IDENT: +required +takes_value
                     "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+                (@arg OUTPUT: -o --output +takes_value
+                    "Where to write an oci or docker-archive image \
+                    (default: a directory or tarball named after the package)")
             )
             (@subcommand hash =>
                 (about: "Generates a blake2b hashsum from a target at any given filepath")
@@ -193,16 +251,25 @@ pub fn get() -> App<'static, 'static> {
                     (ex: core/busybox-static/1.24.2/20160708162350)")
                 (@arg FULL_PATHS: -p "Show full path to file")
             )
-            (@subcommand search =>
-                (about: "Search for a package on a Depot")
-                (@arg SEARCH_TERM: +required +takes_value "Search term")
-                (@arg DEPOT_URL: -u --url +takes_value {valid_url}
-                    "Use a specific Depot URL (ex: http://depot.example.com/v1/depot)")
+            (@subcommand sbom =>
+                (about: "Generates a software bill of materials for a package and its \
+                    dependencies")
+                (@arg PKG_IDENT_OR_ARTIFACT: +required +takes_value
+                    "An installed package identifier or a path to a Habitat Artifact \
+                    (ex: core/redis, /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
+                (@arg FORMAT: -f --format +takes_value {valid_sbom_format}
+                    "The format of the bill of materials: spdx or cyclonedx [default: spdx]")
+                (@arg OUTPUT: -o --output +takes_value
+                    "Write the bill of materials to a file instead of stdout")
             )
+            (subcommand: sub_pkg_search())
             (@subcommand sign =>
                 (about: "Signs an archive with an origin key, generating a Habitat Artifact")
                 (aliases: &["s", "si", "sig"])
                 (@arg ORIGIN: --origin +takes_value "Origin key used to create signature")
+                (@arg COUNTERSIGN: --countersign
+                    "Adds a signature to an existing Habitat Artifact instead of signing a \
+                    source archive (ex: with a release-approval key)")
                 (@arg SOURCE: +required {file_exists}
                     "A path to a source archive file \
                     (ex: /home/acme-redis-3.0.7-21120102031201.tar.xz)")
@@ -210,6 +277,20 @@ pub fn get() -> App<'static, 'static> {
                     "The destination path to the signed Habitat Artifact \
                     (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
             )
+            (@subcommand stats =>
+                (about: "Shows how many times the releases of a package were downloaded \
+                    from a Depot each day")
+                (@arg PKG_IDENT: +required +takes_value
+                    "A package identifier (ex: core/redis, core/redis/3.2.4)")
+                (@arg DEPOT_URL: -u --url +takes_value {valid_url}
+                    "Use a specific Depot URL (ex: http://depot.example.com/v1/depot)")
+                (@arg TARGET: --target +takes_value
+                    "Only count downloads of packages built for this target (ex: x86_64-linux)")
+                (@arg FROM: --from +takes_value {valid_day}
+                    "Only count downloads on or after this day (ex: 2017-06-01)")
+                (@arg TO: --to +takes_value {valid_day}
+                    "Only count downloads on or before this day (ex: 2017-06-30)")
+            )
             (@subcommand upload =>
                 (about: "Uploads a local Habitat Artifact to a Depot")
                 (aliases: &["u", "up", "upl", "uplo", "uploa"])
@@ -479,6 +560,9 @@ fn sub_pkg_install() -> App<'static, 'static> {
             "One or more Habitat package identifiers (ex: acme/redis) and/or filepaths \
             to a Habitat Artifact (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
         (@arg BINLINK: -b --binlink "Binlink all binaries from installed package(s)")
+        (@arg LOCKFILE: --lockfile +takes_value
+            "Pin installed packages and their artifact checksums in a lockfile, adding any \
+            packages which are not yet pinned (ex: hab.lock)")
     );
     sub.arg(Arg::with_name("IGNORE_TARGET")
                 .help("Skips target validation for package installation.")
@@ -487,6 +571,36 @@ fn sub_pkg_install() -> App<'static, 'static> {
                 .hidden(true))
 }
 
+fn sub_pkg_search() -> App<'static, 'static> {
+    let sub = clap_app!(@subcommand search =>
+        (about: "Search for a package on a Depot")
+        (@arg SEARCH_TERM: +takes_value "Search term, matched against package names")
+        (@arg DEPOT_URL: -u --url +takes_value {valid_url}
+            "Use a specific Depot URL (ex: http://depot.example.com/v1/depot)")
+        (@arg ORIGIN: --origin +takes_value "Search in this origin [default: core]")
+        (@arg CHANNEL: --channel +takes_value "Only match packages promoted to this channel")
+        (@arg TARGET: --target +takes_value
+            "Only match packages built for this target (ex: x86_64-linux)")
+        (@arg EXPOSES: --exposes +takes_value {valid_ports}
+            "Only match packages exposing all of these ports (ex: 80,443)")
+        (@arg BINDS: --binds +takes_value
+            "Only match packages binding to all of these services (ex: database)")
+        (@arg BINARIES: --binaries +takes_value
+            "Only match packages putting all of these binaries on the PATH \
+            (ex: openssl,c_rehash)")
+        (@arg FILE: --file +takes_value
+            "Only match packages containing a file ending with this path (ex: bin/openssl)")
+    );
+    sub.arg(Arg::with_name("MIN_VERSION")
+                .help("Only match packages of this version or newer")
+                .long("min-version")
+                .takes_value(true))
+        .arg(Arg::with_name("MAX_VERSION")
+                 .help("Only match packages older than this version")
+                 .long("max-version")
+                 .takes_value(true))
+}
+
 fn file_exists(val: String) -> result::Result<(), String> {
     if Path::new(&val).is_file() {
         Ok(())
@@ -518,6 +632,37 @@ fn valid_service_group(val: String) -> result::Result<(), String> {
     }
 }
 
+fn valid_sbom_format(val: String) -> result::Result<(), String> {
+    match SbomFormat::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("FORMAT: {} is invalid, must be one of (spdx, cyclonedx)", &val)),
+    }
+}
+
+fn valid_ports(val: String) -> result::Result<(), String> {
+    for port in val.split(',') {
+        if port.trim().parse::<u16>().is_err() {
+            return Err(format!("PORT: '{}' is not a valid port", port));
+        }
+    }
+    Ok(())
+}
+
+fn valid_day(val: String) -> result::Result<(), String> {
+    if depot_client::is_valid_day(&val) {
+        Ok(())
+    } else {
+        Err(format!("DAY: '{}' is not a valid YYYY-MM-DD day", &val))
+    }
+}
+
+fn valid_group_id(val: String) -> result::Result<(), String> {
+    match val.parse::<u64>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("GROUP_ID: '{}' is not a valid group id", &val)),
+    }
+}
+
 fn valid_url(val: String) -> result::Result<(), String> {
     match Url::parse(&val) {
         Ok(_) => Ok(()),
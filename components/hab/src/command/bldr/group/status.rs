@@ -0,0 +1,91 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+use std::thread;
+use std::time::Duration;
+
+use depot_client::{Client, ScheduleStatus};
+
+use error::Result;
+use {PRODUCT, VERSION};
+
+/// Seconds to wait between two polls of a group which is still being rebuilt
+const WATCH_INTERVAL: u64 = 10;
+
+pub fn start(url: &str, group_id: u64, watch: bool) -> Result<()> {
+    let depot_client = Client::new(url, PRODUCT, VERSION, None)?;
+    let mut last_summary = String::new();
+    loop {
+        let status = depot_client.schedule_status(group_id)?;
+        // Only print the progress when it changed, so that following a rebuild of hundreds of
+        // packages doesn't flood the terminal
+        let summary = summary(&status);
+        if summary != last_summary {
+            println!("{}", summary);
+            last_summary = summary;
+        }
+        if !watch || is_finished(&status) {
+            print_unbuilt(&status);
+            return Ok(());
+        }
+        thread::sleep(Duration::from_secs(WATCH_INTERVAL));
+    }
+}
+
+/// Lists the projects which failed, and the ones skipped because they could not be built
+fn print_unbuilt(status: &ScheduleStatus) {
+    for state in &["Failure", "Skipped"] {
+        let projects: Vec<_> = status
+            .projects
+            .iter()
+            .filter(|p| p.state == *state)
+            .collect();
+        if projects.is_empty() {
+            continue;
+        }
+        println!("{} ({}):", state, projects.len());
+        for project in projects {
+            if project.job_id == 0 {
+                println!("    {}", project.ident);
+            } else {
+                println!("    {} (job {})", project.ident, project.job_id);
+            }
+        }
+    }
+}
+
+/// A group is marked as failed as soon as one of its projects fails, while the projects which
+/// don't depend on it keep building, so it is only finished once none of them is left to build.
+fn is_finished(status: &ScheduleStatus) -> bool {
+    (status.state == "Complete" || status.state == "Failed") &&
+    !status
+         .projects
+         .iter()
+         .any(|p| p.state == "InProgress" || p.state == "NotStarted")
+}
+
+fn summary(status: &ScheduleStatus) -> String {
+    let count = |state: &str| status.projects.iter().filter(|p| p.state == state).count();
+    format!("Group {} {}: {}/{} succeeded, {} in progress, {} not started, {} failed, \
+             {} skipped",
+            status.id,
+            status.state,
+            count("Success"),
+            status.projects.len(),
+            count("InProgress"),
+            count("NotStarted"),
+            count("Failure"),
+            count("Skipped"))
+}
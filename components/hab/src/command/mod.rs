@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod bldr;
 pub mod butterfly;
 pub mod cli;
 pub mod pkg;
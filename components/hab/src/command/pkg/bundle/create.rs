@@ -0,0 +1,165 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Creates a signed bundle of packages, all of their transitive dependencies and the public
+//! origin keys needed to verify them.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg bundle create core/redis core/nginx --origin acme -o web.bundle
+//! ```
+//!
+//! Will install `core/redis` and `core/nginx` if they aren't already installed, and write their
+//! artifacts and those of their dependencies to `web.bundle`, signed with the latest `acme`
+//! origin key.
+
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use common::command::package::install;
+use common::ui::{Status, UI};
+use depot_client::Client;
+use hcore::crypto::{artifact, SigKeyPair};
+use hcore::crypto::keys::parse_name_with_rev;
+use hcore::fs::{cache_artifact_path, cache_key_path};
+use hcore::package::{bundle, PackageIdent, PackageInstall};
+
+use {PRODUCT, VERSION};
+use error::Result;
+
+/// Create a bundle of the given packages at `dst`. Artifacts and public keys which are missing
+/// from the local caches are fetched from the Depot at `url`.
+///
+/// # Failures
+///
+/// * If a package cannot be installed
+/// * If an artifact or public key cannot be found locally or in the Depot
+/// * If the bundle cannot be written or signed
+pub fn start(ui: &mut UI,
+             url: &str,
+             channel: Option<&str>,
+             idents: &[&str],
+             pair: &SigKeyPair,
+             fs_root_path: &Path,
+             dst: Option<&Path>)
+             -> Result<()> {
+    let artifact_cache = cache_artifact_path(Some(fs_root_path));
+    let key_cache = cache_key_path(Some(fs_root_path));
+    let depot_client = try!(Client::new(url, PRODUCT, VERSION, Some(fs_root_path)));
+
+    let mut packages: Vec<PackageIdent> = Vec::new();
+    for ident in idents.iter() {
+        let pkg = try!(load_or_install(ui, url, channel, ident, fs_root_path));
+        let mut deps = try!(pkg.tdeps());
+        deps.push(pkg.ident().clone());
+        for dep in deps {
+            if !packages.contains(&dep) {
+                packages.push(dep);
+            }
+        }
+    }
+    let dst = match dst {
+        Some(dst) => dst.to_path_buf(),
+        None => default_dst(packages.last().unwrap()),
+    };
+
+    try!(ui.begin(format!("Creating bundle {} of {} packages",
+                          dst.display(),
+                          packages.len())));
+    let mut artifacts = Vec::with_capacity(packages.len());
+    let mut keys: Vec<PathBuf> = Vec::new();
+    for ident in packages.iter() {
+        let artifact = try!(cached_artifact(ui, &depot_client, ident, &artifact_cache));
+        for signer in try!(artifact::artifact_signers(&artifact)) {
+            let key = try!(cached_key(ui, &depot_client, &signer, &key_cache));
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+        try!(ui.status(Status::Using, ident));
+        artifacts.push(artifact);
+    }
+
+    try!(ui.status(Status::Signing, format!("bundle with {}", pair.name_with_rev())));
+    try!(bundle::create(&dst, &artifacts, &keys, pair));
+    try!(ui.status(Status::Custom('✓', "Created".to_string()),
+                   dst.display()));
+    try!(ui.end(format!("Bundle {} holds {} packages and {} public origin keys.",
+                        dst.display(),
+                        artifacts.len(),
+                        keys.len())));
+    Ok(())
+}
+
+fn load_or_install(ui: &mut UI,
+                   url: &str,
+                   channel: Option<&str>,
+                   ident: &str,
+                   fs_root_path: &Path)
+                   -> Result<PackageInstall> {
+    let pkg_ident = try!(PackageIdent::from_str(ident));
+    if let Ok(install) = PackageInstall::load(&pkg_ident, Some(fs_root_path)) {
+        return Ok(install);
+    }
+    try!(ui.status(Status::Missing, format!("package for {}", &pkg_ident)));
+    let installed = try!(install::start(ui,
+                                        url,
+                                        channel,
+                                        ident,
+                                        PRODUCT,
+                                        VERSION,
+                                        fs_root_path,
+                                        &cache_artifact_path(Some(fs_root_path)),
+                                        false,
+                                        None));
+    Ok(try!(PackageInstall::load(&installed, Some(fs_root_path))))
+}
+
+fn cached_artifact(ui: &mut UI,
+                   depot_client: &Client,
+                   ident: &PackageIdent,
+                   artifact_cache: &Path)
+                   -> Result<PathBuf> {
+    // Installed packages are always fully qualified
+    let path = artifact_cache.join(ident.archive_name().unwrap());
+    if path.is_file() {
+        return Ok(path);
+    }
+    try!(ui.status(Status::Downloading, ident));
+    let archive = try!(depot_client.fetch_package(ident, artifact_cache, ui.progress()));
+    Ok(archive.path)
+}
+
+fn cached_key(ui: &mut UI,
+              depot_client: &Client,
+              name_with_rev: &str,
+              key_cache: &Path)
+              -> Result<PathBuf> {
+    if let Ok(path) = SigKeyPair::get_public_key_path(name_with_rev, key_cache) {
+        return Ok(path);
+    }
+    try!(ui.status(Status::Downloading,
+                   format!("{} public origin key", name_with_rev)));
+    let (name, rev) = try!(parse_name_with_rev(name_with_rev));
+    Ok(try!(depot_client.fetch_origin_key(&name, &rev, key_cache, ui.progress())))
+}
+
+fn default_dst(ident: &PackageIdent) -> PathBuf {
+    PathBuf::from(format!("{}-{}-{}-{}.bundle",
+                          ident.origin,
+                          ident.name,
+                          ident.version.as_ref().unwrap(),
+                          ident.release.as_ref().unwrap()))
+}
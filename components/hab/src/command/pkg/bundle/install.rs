@@ -0,0 +1,105 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Installs every package held by a bundle without contacting a Depot.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg bundle install web.bundle
+//! ```
+//!
+//! Will verify `web.bundle`, add the public origin keys it holds to the key cache and install its
+//! packages.
+//!
+//! # Notes
+//!
+//! The bundle must be signed with an origin key whose public key is already in the key cache, as
+//! that is what establishes trust in the keys and artifacts inside of it.
+
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::Path;
+
+use common::command::package::install;
+use common::ui::{Status, UI};
+use hcore::crypto::SigKeyPair;
+use hcore::fs::{cache_artifact_path, cache_key_path};
+use hcore::package::bundle;
+use uuid::Uuid;
+
+use {PRODUCT, VERSION};
+use error::Result;
+
+/// Verify the bundle at `src` and install every package it holds.
+///
+/// # Failures
+///
+/// * If the bundle is not signed with a trusted key or fails verification
+/// * If a bundled key cannot be added to the key cache
+/// * If a bundled package cannot be installed
+pub fn start(ui: &mut UI, url: &str, src: &Path, fs_root_path: &Path) -> Result<()> {
+    let artifact_cache = cache_artifact_path(Some(fs_root_path));
+    // Extracted artifacts are installed like local archives, which copies them into the
+    // artifact cache. The working directory is removed once the bundle is installed.
+    let work_dir = artifact_cache.join(format!(".bundle-{}", Uuid::new_v4()));
+
+    try!(ui.begin(format!("Installing bundle {}", src.display())));
+    let result = install_bundle(ui, url, src, &work_dir, fs_root_path);
+    if work_dir.exists() {
+        try!(fs::remove_dir_all(&work_dir));
+    }
+    result
+}
+
+fn install_bundle(ui: &mut UI,
+                  url: &str,
+                  src: &Path,
+                  work_dir: &Path,
+                  fs_root_path: &Path)
+                  -> Result<()> {
+    let artifact_cache = cache_artifact_path(Some(fs_root_path));
+    let key_cache = cache_key_path(Some(fs_root_path));
+
+    let bundle = try!(bundle::extract(src, &key_cache, work_dir));
+    try!(ui.status(Status::Verified,
+                   format!("bundle signed with {}", bundle.signer)));
+
+    for key in bundle.keys.iter() {
+        let mut content = String::new();
+        try!(try!(File::open(key)).read_to_string(&mut content));
+        // Written under the name the key declares rather than its file name in the bundle
+        let (pair, _) = try!(SigKeyPair::write_file_from_str(&content, &key_cache));
+        try!(ui.status(Status::Cached,
+                       format!("public origin key {}", pair.name_with_rev())));
+    }
+
+    let count = bundle.artifacts.len();
+    for artifact in bundle.artifacts.iter() {
+        try!(install::start(ui,
+                            url,
+                            None,
+                            &artifact.to_string_lossy(),
+                            PRODUCT,
+                            VERSION,
+                            fs_root_path,
+                            &artifact_cache,
+                            false,
+                            None));
+    }
+    try!(ui.end(format!("Install of bundle {} complete with {} packages.",
+                        src.display(),
+                        count)));
+    Ok(())
+}
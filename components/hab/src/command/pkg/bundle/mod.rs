@@ -0,0 +1,24 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Offline bundles of packages for installing into environments without network access to a
+//! Depot.
+//!
+//! A bundle holds package artifacts, all of their transitive dependencies and the public origin
+//! keys needed to verify them, and is itself signed with an origin key. See
+//! `habitat_core::package::bundle` for the format.
+
+pub mod create;
+pub mod install;
+pub mod upload;
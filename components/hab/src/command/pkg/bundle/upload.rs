@@ -0,0 +1,53 @@
+// Copyright (c) 2016 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Uploads a bundle to a [Depot](../depot), which verifies it and imports every package and
+//! public origin key it doesn't already have.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg bundle upload web.bundle -u http://localhost:9632
+//! ```
+
+use std::path::Path;
+
+use common::ui::{Status, UI};
+use depot_client::Client;
+
+use {PRODUCT, VERSION};
+use error::Result;
+
+/// Upload the bundle at `src` to the Depot at `url`.
+///
+/// # Failures
+///
+/// * If the bundle cannot be read
+/// * If the Depot rejects the bundle or cannot be reached
+pub fn start(ui: &mut UI, url: &str, token: &str, src: &Path) -> Result<()> {
+    let depot_client = try!(Client::new(url, PRODUCT, VERSION, None));
+
+    try!(ui.begin(format!("Uploading bundle {}", src.display())));
+    let import = try!(depot_client.put_bundle(src, token, ui.progress()));
+    for ident in import.existing.iter() {
+        try!(ui.status(Status::Using, format!("existing {}", ident)));
+    }
+    for ident in import.imported.iter() {
+        try!(ui.status(Status::Uploaded, ident));
+    }
+    try!(ui.end(format!("Upload of bundle {} complete with {} new packages imported.",
+                        src.display(),
+                        import.imported.len())));
+    Ok(())
+}
@@ -12,30 +12,27 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::path::Path;
+
 use common::ui::UI;
 use hcore::package::PackageIdent;
 
+use command::pkg::oci::ImageFormat;
 use error::Result;
 
-#[allow(dead_code)]
-pub struct ExportFormat {
-    pkg_ident: PackageIdent,
-    cmd: String,
+pub enum ExportFormat {
+    /// Exported by running the command of an exporter package
+    Package { pkg_ident: PackageIdent, cmd: String },
+    /// Exported natively to a container image
+    Image(ImageFormat),
 }
 
-#[allow(dead_code)]
-impl ExportFormat {
-    pub fn pkg_ident(&self) -> &PackageIdent {
-        &self.pkg_ident
-    }
-
-    pub fn cmd(&self) -> &str {
-        &self.cmd
-    }
-}
-
-pub fn start(ui: &mut UI, ident: &PackageIdent, format: &ExportFormat) -> Result<()> {
-    inner::start(ui, ident, format)
+pub fn start(ui: &mut UI,
+             ident: &PackageIdent,
+             format: &ExportFormat,
+             dst: Option<&Path>)
+             -> Result<()> {
+    inner::start(ui, ident, format, dst)
 }
 
 pub fn format_for(ui: &mut UI, value: &str) -> Result<ExportFormat> {
@@ -55,35 +52,39 @@ mod inner {
     use hcore::url::default_depot_url;
 
     use {PRODUCT, VERSION};
-    use command::pkg::exec;
+    use command::pkg::{exec, oci};
+    use command::pkg::oci::ImageFormat;
     use error::{Error, Result};
     use super::ExportFormat;
 
     pub fn format_for(_ui: &mut UI, value: &str) -> Result<ExportFormat> {
+        if let Ok(image_format) = ImageFormat::from_str(value) {
+            return Ok(ExportFormat::Image(image_format));
+        }
         match value {
             "docker" => {
-                let format = ExportFormat {
+                let format = ExportFormat::Package {
                     pkg_ident: try!(PackageIdent::from_str("core/hab-pkg-dockerize")),
                     cmd: "hab-pkg-dockerize".to_string(),
                 };
                 Ok(format)
             }
             "aci" => {
-                let format = ExportFormat {
+                let format = ExportFormat::Package {
                     pkg_ident: try!(PackageIdent::from_str("core/hab-pkg-aci")),
                     cmd: "hab-pkg-aci".to_string(),
                 };
                 Ok(format)
             }
             "mesos" => {
-                let format = ExportFormat {
+                let format = ExportFormat::Package {
                     pkg_ident: try!(PackageIdent::from_str("core/hab-pkg-mesosize")),
                     cmd: "hab-pkg-mesosize".to_string(),
                 };
                 Ok(format)
             }
             "tar" => {
-                let format = ExportFormat {
+                let format = ExportFormat::Package {
                     pkg_ident: try!(PackageIdent::from_str("core/hab-pkg-tarize")),
                     cmd: "hab-pkg-tarize".to_string(),
                 };
@@ -93,9 +94,27 @@ mod inner {
         }
     }
 
-    pub fn start(ui: &mut UI, ident: &PackageIdent, format: &ExportFormat) -> Result<()> {
-        let format_ident = format.pkg_ident();
-        match PackageInstall::load(format.pkg_ident(), None) {
+    pub fn start(ui: &mut UI,
+                 ident: &PackageIdent,
+                 format: &ExportFormat,
+                 dst: Option<&Path>)
+                 -> Result<()> {
+        match *format {
+            ExportFormat::Package { ref pkg_ident, ref cmd } => {
+                start_package(ui, ident, pkg_ident, cmd)
+            }
+            ExportFormat::Image(image_format) => {
+                oci::start(ui, ident, image_format, Path::new(&*FS_ROOT_PATH), dst)
+            }
+        }
+    }
+
+    fn start_package(ui: &mut UI,
+                     ident: &PackageIdent,
+                     format_ident: &PackageIdent,
+                     cmd: &str)
+                     -> Result<()> {
+        match PackageInstall::load(format_ident, None) {
             Ok(_) => {}
             _ => {
                 try!(ui.status(Status::Missing, format!("package for {}", &format_ident)));
@@ -107,11 +126,12 @@ mod inner {
                                     VERSION,
                                     Path::new(&*FS_ROOT_PATH),
                                     &cache_artifact_path(None),
-                                    false));
+                                    false,
+                                    None));
             }
         }
         let pkg_arg = OsString::from(&ident.to_string());
-        exec::start(&format_ident, &format.cmd(), vec![pkg_arg])
+        exec::start(format_ident, cmd, vec![pkg_arg])
     }
 }
 
@@ -121,6 +141,7 @@ mod inner {
     use common::ui::UI;
     use hcore::package::PackageIdent;
     use std::env;
+    use std::path::Path;
     use super::ExportFormat;
 
     pub fn format_for(ui: &mut UI, value: &str) -> Result<ExportFormat> {
@@ -133,7 +154,11 @@ mod inner {
         Err(e)
     }
 
-    pub fn start(ui: &mut UI, _ident: &PackageIdent, _format: &ExportFormat) -> Result<()> {
+    pub fn start(ui: &mut UI,
+                 _ident: &PackageIdent,
+                 _format: &ExportFormat,
+                 _dst: Option<&Path>)
+                 -> Result<()> {
         let subcmd = env::args().nth(1).unwrap_or("<unknown>".to_string());
         let subsubcmd = env::args().nth(2).unwrap_or("<unknown>".to_string());
         try!(ui.warn("Exporting packages from this operating system is not yet supported. Try \
@@ -27,9 +27,12 @@ pub fn start(ui: &mut UI, src: &Path) -> Result<()> {
         try!(io::stdout().write(format!("Package        : {}\n", &src.display()).as_bytes()));
         try!(io::stdout().write(format!("Format Version : {}\n", header.format_version)
                                     .as_bytes()));
-        try!(io::stdout().write(format!("Key Name       : {}\n", header.key_name).as_bytes()));
         try!(io::stdout().write(format!("Hash Type      : {}\n", header.hash_type).as_bytes()));
-        try!(io::stdout().write(format!("Raw Signature  : {}\n", header.signature_raw).as_bytes()));
+        for sig in header.signatures.iter() {
+            try!(io::stdout().write(format!("Key Name       : {}\n", sig.key_name).as_bytes()));
+            try!(io::stdout().write(format!("Raw Signature  : {}\n", sig.signature_raw)
+                                        .as_bytes()));
+        }
     } else {
         try!(ui.warn("Failed to read package header."));
     }
@@ -14,14 +14,18 @@
 
 pub mod binlink;
 pub mod build;
+pub mod bundle;
 pub mod env;
 pub mod exec;
 pub mod export;
 pub mod hash;
 pub mod header;
+pub mod oci;
 pub mod path;
 pub mod provides;
+pub mod sbom;
 pub mod search;
 pub mod sign;
+pub mod stats;
 pub mod upload;
 pub mod verify;
@@ -0,0 +1,560 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Exports an installed package to a container image without the need for a container runtime.
+//!
+//! The image is assembled directly from the installed package, the Supervisor and their
+//! transitive dependencies. Every package is written to its own layer so that images which share
+//! dependencies can share layers, followed by a small base layer containing `/bin`, `/etc/passwd`
+//! and friends. The Supervisor is the image's entrypoint and the ports in the package's
+//! `EXPOSES` metafile are exposed alongside the Supervisor's own ports.
+//!
+//! Two formats are supported:
+//!
+//! * `oci`: an [OCI image layout](https://github.com/opencontainers/image-spec) directory
+//! * `docker-archive`: a tarball which can be loaded with `docker load`
+//!
+//! Layers are written as uncompressed tarballs, with every entry owned by root and a fixed
+//! modification time, so the same package always produces the same layer digest.
+
+use std::collections::HashSet;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use common::command::package::install;
+use common::ui::{Status, UI};
+use hcore::fs::{cache_artifact_path, PKG_PATH};
+use hcore::package::{PackageIdent, PackageInstall};
+use hcore::util::tar::TarWriter;
+use hcore::url::default_depot_url;
+use serde_json::{self, Value};
+use sha2::{Digest, Sha256};
+use time;
+use walkdir::WalkDir;
+
+use {PRODUCT, VERSION};
+use error::{Error, Result};
+
+/// The Supervisor package which is run as the image's entrypoint
+const SUP_PKG_IDENT: &'static str = "core/hab-sup";
+/// The package linked to `/bin` to provide a shell and basic utilities for hooks
+const BUSYBOX_IDENT: &'static str = "core/busybox-static";
+/// Ports the Supervisor listens on for its HTTP gateway and gossip
+const SUP_PORTS: &'static [&'static str] = &["9631/tcp", "9638/tcp", "9638/udp"];
+
+const OCI_LAYER_MEDIA_TYPE: &'static str = "application/vnd.oci.image.layer.v1.tar";
+const OCI_CONFIG_MEDIA_TYPE: &'static str = "application/vnd.oci.image.config.v1+json";
+const OCI_MANIFEST_MEDIA_TYPE: &'static str = "application/vnd.oci.image.manifest.v1+json";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ImageFormat {
+    Oci,
+    DockerArchive,
+}
+
+impl FromStr for ImageFormat {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "oci" => Ok(ImageFormat::Oci),
+            "docker-archive" => Ok(ImageFormat::DockerArchive),
+            _ => Err(Error::UnsupportedExportFormat(value.to_string())),
+        }
+    }
+}
+
+/// A blob written to the image, identified by its SHA256 digest.
+#[derive(Debug)]
+struct Blob {
+    digest: String,
+    size: u64,
+}
+
+impl Blob {
+    fn hex(&self) -> &str {
+        &self.digest["sha256:".len()..]
+    }
+
+    fn descriptor(&self, media_type: &str) -> Value {
+        json!({ "mediaType": media_type, "digest": self.digest, "size": self.size })
+    }
+}
+
+pub fn start(ui: &mut UI,
+             ident: &PackageIdent,
+             format: ImageFormat,
+             fs_root_path: &Path,
+             dst: Option<&Path>)
+             -> Result<()> {
+    let pkg = try!(load_or_install(ui, &ident.to_string(), fs_root_path));
+    let sup = try!(load_or_install(ui, SUP_PKG_IDENT, fs_root_path));
+    let busybox = try!(load_or_install(ui, BUSYBOX_IDENT, fs_root_path));
+
+    let mut idents = Vec::new();
+    for install in [&busybox, &sup, &pkg].iter() {
+        for tdep in try!(install.tdeps()).into_iter() {
+            if !idents.contains(&tdep) {
+                idents.push(tdep);
+            }
+        }
+        if !idents.contains(install.ident()) {
+            idents.push(install.ident().clone());
+        }
+    }
+
+    let pkg_ident = pkg.ident();
+    let dst = match dst {
+        Some(dst) => dst.to_path_buf(),
+        None => default_dst(pkg_ident, format),
+    };
+    try!(ui.begin(format!("Exporting {} to {}", pkg_ident, dst.display())));
+
+    let blob_dir = match format {
+        ImageFormat::Oci => dst.join("blobs").join("sha256"),
+        ImageFormat::DockerArchive => {
+            let name = dst.file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or("image".to_string());
+            dst.with_file_name(format!(".{}.layers", name))
+        }
+    };
+    try!(fs::create_dir_all(&blob_dir));
+
+    let mut layers = Vec::with_capacity(idents.len() + 1);
+    for dep in idents.iter() {
+        try!(ui.status(Status::Custom('↑', "Layering".to_string()), dep));
+        layers.push(try!(write_layer(&blob_dir, |tar| {
+            append_package(tar, dep, fs_root_path)
+        })));
+    }
+    let base = try!(write_layer(&blob_dir, |tar| append_base(tar, &busybox, &pkg)));
+    layers.push(base);
+
+    let config = try!(image_config(pkg_ident, &sup, &pkg, &layers));
+    let config_blob = try!(write_json_blob(&blob_dir, &config));
+
+    match format {
+        ImageFormat::Oci => try!(finish_oci(&dst, pkg_ident, &config_blob, &layers)),
+        ImageFormat::DockerArchive => {
+            let result = finish_docker_archive(&dst, &blob_dir, pkg_ident, &config_blob, &layers);
+            try!(fs::remove_dir_all(&blob_dir));
+            try!(result);
+        }
+    }
+    try!(ui.end(format!("Exported {} to {}", pkg_ident, dst.display())));
+    Ok(())
+}
+
+fn load_or_install(ui: &mut UI, ident: &str, fs_root_path: &Path) -> Result<PackageInstall> {
+    let pkg_ident = try!(PackageIdent::from_str(ident));
+    if let Ok(install) = PackageInstall::load(&pkg_ident, Some(fs_root_path)) {
+        return Ok(install);
+    }
+    try!(ui.status(Status::Missing, format!("package for {}", &pkg_ident)));
+    let installed = try!(install::start(ui,
+                                        &default_depot_url(),
+                                        None,
+                                        ident,
+                                        PRODUCT,
+                                        VERSION,
+                                        fs_root_path,
+                                        &cache_artifact_path(Some(fs_root_path)),
+                                        false,
+                                        None));
+    Ok(try!(PackageInstall::load(&installed, Some(fs_root_path))))
+}
+
+fn default_dst(ident: &PackageIdent, format: ImageFormat) -> PathBuf {
+    let name = format!("{}-{}-{}-{}",
+                       ident.origin,
+                       ident.name,
+                       ident.version.as_ref().unwrap(),
+                       ident.release.as_ref().unwrap());
+    match format {
+        ImageFormat::Oci => PathBuf::from(format!("{}-oci", name)),
+        ImageFormat::DockerArchive => PathBuf::from(format!("{}-docker.tar", name)),
+    }
+}
+
+/// The path of an installed package within the image, without a leading slash.
+fn image_pkg_path(ident: &PackageIdent) -> String {
+    format!("{}/{}/{}/{}/{}",
+            PKG_PATH,
+            ident.origin,
+            ident.name,
+            ident.version.as_ref().unwrap(),
+            ident.release.as_ref().unwrap())
+}
+
+/// The tag of the image, in `origin/name:version-release` form.
+fn image_tag(ident: &PackageIdent) -> String {
+    format!("{}/{}:{}-{}",
+            ident.origin,
+            ident.name,
+            ident.version.as_ref().unwrap(),
+            ident.release.as_ref().unwrap())
+}
+
+fn image_config(ident: &PackageIdent,
+                sup: &PackageInstall,
+                pkg: &PackageInstall,
+                layers: &[Blob])
+                -> Result<Value> {
+    let mut ports = json!({});
+    for port in SUP_PORTS.iter() {
+        ports[*port] = json!({});
+    }
+    for port in try!(pkg.exposes()).iter() {
+        ports[format!("{}/tcp", port)] = json!({});
+    }
+    let created = time::now_utc().rfc3339().to_string();
+    let history: Vec<Value> = layers
+        .iter()
+        .map(|_| {
+                 json!({
+                     "created": created,
+                     "created_by": format!("{} pkg export {}", PRODUCT, ident),
+                 })
+             })
+        .collect();
+    Ok(json!({
+        "created": created,
+        "architecture": "amd64",
+        "os": "linux",
+        "config": {
+            "Entrypoint": [format!("/{}/bin/hab-sup", image_pkg_path(sup.ident()))],
+            "Cmd": ["start", ident.to_string()],
+            "Env": [format!("PATH=/bin:/{}/bin", image_pkg_path(sup.ident()))],
+            "ExposedPorts": ports,
+            "WorkingDir": "/",
+            "Labels": {
+                "org.opencontainers.image.title": image_tag(ident),
+                "org.opencontainers.image.version": format!("{}/{}",
+                                                            ident.version.as_ref().unwrap(),
+                                                            ident.release.as_ref().unwrap()),
+            },
+        },
+        "rootfs": {
+            "type": "layers",
+            "diff_ids": layers.iter().map(|l| l.digest.clone()).collect::<Vec<String>>(),
+        },
+        "history": history,
+    }))
+}
+
+fn finish_oci(dst: &Path, ident: &PackageIdent, config: &Blob, layers: &[Blob]) -> Result<()> {
+    let blob_dir = dst.join("blobs").join("sha256");
+    let manifest = json!({
+        "schemaVersion": 2,
+        "config": config.descriptor(OCI_CONFIG_MEDIA_TYPE),
+        "layers": layers
+            .iter()
+            .map(|l| l.descriptor(OCI_LAYER_MEDIA_TYPE))
+            .collect::<Vec<Value>>(),
+    });
+    let manifest_blob = try!(write_json_blob(&blob_dir, &manifest));
+    let mut descriptor = manifest_blob.descriptor(OCI_MANIFEST_MEDIA_TYPE);
+    descriptor["annotations"] = json!({ "org.opencontainers.image.ref.name": image_tag(ident) });
+    let index = json!({ "schemaVersion": 2, "manifests": [descriptor] });
+
+    try!(write_json(&dst.join("index.json"), &index));
+    try!(write_json(&dst.join("oci-layout"), &json!({ "imageLayoutVersion": "1.0.0" })));
+    Ok(())
+}
+
+fn finish_docker_archive(dst: &Path,
+                         blob_dir: &Path,
+                         ident: &PackageIdent,
+                         config: &Blob,
+                         layers: &[Blob])
+                         -> Result<()> {
+    let config_name = format!("{}.json", config.hex());
+    let layer_names: Vec<String> = layers
+        .iter()
+        .map(|l| format!("{}/layer.tar", l.hex()))
+        .collect();
+    let manifest = json!([{
+        "Config": config_name,
+        "RepoTags": [image_tag(ident), format!("{}/{}:latest", ident.origin, ident.name)],
+        "Layers": layer_names,
+    }]);
+    let manifest = try!(serde_json::to_vec(&manifest));
+
+    let mut tar = TarWriter::new(BufWriter::new(try!(File::create(dst))));
+    try!(tar.append_file_from_path(&config_name, 0o644, &blob_dir.join(config.hex())));
+    let mut seen = HashSet::new();
+    for (layer, name) in layers.iter().zip(layer_names.iter()) {
+        if !seen.insert(layer.hex()) {
+            continue;
+        }
+        try!(tar.append_dir(layer.hex(), 0o755));
+        try!(tar.append_file_from_path(name, 0o644, &blob_dir.join(layer.hex())));
+    }
+    try!(tar.append_file("manifest.json", 0o644, manifest.len() as u64, &mut &manifest[..]));
+    try!(try!(tar.finish()).flush());
+    Ok(())
+}
+
+/// Writes a layer into the blob directory, named after its digest.
+fn write_layer<F>(blob_dir: &Path, f: F) -> Result<Blob>
+    where F: FnOnce(&mut TarWriter<DigestWriter<BufWriter<File>>>) -> Result<()>
+{
+    let tmp = blob_dir.join(".layer.tar.tmp");
+    let mut tar = TarWriter::new(DigestWriter::new(BufWriter::new(try!(File::create(&tmp)))));
+    try!(f(&mut tar));
+    let (mut writer, blob) = try!(tar.finish()).finish();
+    try!(writer.flush());
+    try!(fs::rename(&tmp, blob_dir.join(blob.hex())));
+    Ok(blob)
+}
+
+fn write_json_blob(blob_dir: &Path, value: &Value) -> Result<Blob> {
+    let bytes = try!(serde_json::to_vec(value));
+    let mut writer = DigestWriter::new(Vec::with_capacity(bytes.len()));
+    try!(writer.write_all(&bytes));
+    let (bytes, blob) = writer.finish();
+    let mut file = try!(File::create(blob_dir.join(blob.hex())));
+    try!(file.write_all(&bytes));
+    Ok(blob)
+}
+
+fn write_json(path: &Path, value: &Value) -> Result<()> {
+    let mut file = try!(File::create(path));
+    try!(file.write_all(&try!(serde_json::to_vec(value))));
+    Ok(())
+}
+
+/// Adds an installed package, and the directories leading up to it, to a layer.
+fn append_package<W: Write>(tar: &mut TarWriter<W>,
+                            ident: &PackageIdent,
+                            fs_root_path: &Path)
+                            -> Result<()> {
+    let pkg_path = image_pkg_path(ident);
+    let mut parent = String::new();
+    for component in pkg_path.split('/') {
+        if !parent.is_empty() {
+            parent.push('/');
+        }
+        parent.push_str(component);
+        try!(tar.append_dir(&parent, 0o755));
+    }
+
+    let src = fs_root_path.join(&pkg_path);
+    for entry in WalkDir::new(&src).min_depth(1).sort_by(|a, b| a.cmp(b)) {
+        let entry = try!(entry.map_err(|e| Error::IO(io::Error::from(e))));
+        let relative = try!(entry.path().strip_prefix(&src));
+        let name = format!("{}/{}", pkg_path, relative.to_string_lossy());
+        let metadata = try!(fs::symlink_metadata(entry.path()));
+        let file_type = metadata.file_type();
+        if file_type.is_symlink() {
+            let target = try!(fs::read_link(entry.path()));
+            try!(tar.append_symlink(&name, &target.to_string_lossy()));
+        } else if file_type.is_dir() {
+            try!(tar.append_dir(&name, mode(&metadata)));
+        } else if file_type.is_file() {
+            try!(tar.append_file_from_path(&name, mode(&metadata), entry.path()));
+        }
+    }
+    Ok(())
+}
+
+/// Adds the filesystem around the packages to a layer: a `/bin` with a shell for hooks, the
+/// users and groups services run as, and the directories the Supervisor writes to.
+fn append_base<W: Write>(tar: &mut TarWriter<W>,
+                         busybox: &PackageInstall,
+                         pkg: &PackageInstall)
+                         -> Result<()> {
+    try!(tar.append_symlink("bin", &format!("/{}/bin", image_pkg_path(busybox.ident()))));
+
+    let mut passwd = String::from("root:x:0:0:root:/:/bin/sh\nhab:x:42:42:hab:/:/bin/false\n");
+    let mut group = String::from("root:x:0:\nhab:x:42:hab\n");
+    if let Some(user) = try!(pkg.svc_user()) {
+        if user != "root" && user != "hab" {
+            passwd.push_str(&format!("{}:x:43:43:{}:/:/bin/false\n", user, user));
+        }
+    }
+    if let Some(grp) = try!(pkg.svc_group()) {
+        if grp != "root" && grp != "hab" {
+            group.push_str(&format!("{}:x:43:\n", grp));
+        }
+    }
+    try!(tar.append_dir("etc", 0o755));
+    try!(tar.append_file("etc/passwd", 0o644, passwd.len() as u64, &mut passwd.as_bytes()));
+    try!(tar.append_file("etc/group", 0o644, group.len() as u64, &mut group.as_bytes()));
+    try!(tar.append_dir("tmp", 0o1777));
+    try!(tar.append_dir("hab", 0o755));
+    try!(tar.append_dir("hab/svc", 0o755));
+    Ok(())
+}
+
+#[cfg(unix)]
+fn mode(metadata: &fs::Metadata) -> u32 {
+    use std::os::unix::fs::PermissionsExt;
+    metadata.permissions().mode() & 0o7777
+}
+
+#[cfg(not(unix))]
+fn mode(metadata: &fs::Metadata) -> u32 {
+    if metadata.is_dir() { 0o755 } else { 0o644 }
+}
+
+/// Computes the SHA256 digest and size of everything written through it.
+struct DigestWriter<W: Write> {
+    inner: W,
+    hasher: Sha256,
+    size: u64,
+}
+
+impl<W: Write> DigestWriter<W> {
+    fn new(inner: W) -> Self {
+        DigestWriter {
+            inner: inner,
+            hasher: Sha256::default(),
+            size: 0,
+        }
+    }
+
+    fn finish(self) -> (W, Blob) {
+        let digest: String = self.hasher
+            .result()
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect();
+        (self.inner,
+         Blob {
+             digest: format!("sha256:{}", digest),
+             size: self.size,
+         })
+    }
+}
+
+impl<W: Write> Write for DigestWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = try!(self.inner.write(buf));
+        self.hasher.input(&buf[..n]);
+        self.size += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::io::{self, Read, Write};
+    use std::path::{Path, PathBuf};
+    use std::str::FromStr;
+
+    use common::ui::{Coloring, UI};
+    use hcore::package::{PackageArchive, PackageIdent};
+    use serde_json::{self, Value};
+    use tempdir::TempDir;
+
+    fn fixture() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../core/tests/fixtures")
+            .join("happyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart")
+    }
+
+    /// Installs an empty stand-in for a package under `fs_root_path`
+    fn fake_install(fs_root_path: &Path, ident: &str) {
+        let path = fs_root_path.join(PKG_PATH).join(ident);
+        fs::create_dir_all(path.join("bin")).unwrap();
+        File::create(path.join("IDENT"))
+            .unwrap()
+            .write_all(ident.as_bytes())
+            .unwrap();
+        File::create(path.join("TARGET"))
+            .unwrap()
+            .write_all(b"x86_64-linux")
+            .unwrap();
+    }
+
+    /// Reads the blob a descriptor points to, checking its digest and size
+    fn read_blob(dst: &Path, descriptor: &Value) -> Vec<u8> {
+        let digest = descriptor["digest"].as_str().unwrap();
+        let mut bytes = Vec::new();
+        File::open(dst.join("blobs/sha256").join(&digest["sha256:".len()..]))
+            .unwrap()
+            .read_to_end(&mut bytes)
+            .unwrap();
+        let mut writer = DigestWriter::new(io::sink());
+        writer.write_all(&bytes).unwrap();
+        let (_, blob) = writer.finish();
+        assert_eq!(blob.digest, digest);
+        assert_eq!(descriptor["size"], json!(blob.size));
+        bytes
+    }
+
+    fn read_json(path: &Path) -> Value {
+        serde_json::from_reader(File::open(path).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn exports_oci_layout() {
+        let root = TempDir::new("fs_root").unwrap();
+        PackageArchive::new(fixture()).unpack(Some(root.path())).unwrap();
+        fake_install(root.path(), "core/hab-sup/0.1.0/20170101000000");
+        fake_install(root.path(), "core/busybox-static/1.0.0/20170101000000");
+        let ident = PackageIdent::from_str("happyhumans/possums/8.1.4/20160427165340").unwrap();
+        let dst = root.path().join("image");
+        let mut ui = UI::default_with(Coloring::Never, Some(false));
+        start(&mut ui, &ident, ImageFormat::Oci, root.path(), Some(&dst)).unwrap();
+
+        assert_eq!(read_json(&dst.join("oci-layout")),
+                   json!({ "imageLayoutVersion": "1.0.0" }));
+        let index = read_json(&dst.join("index.json"));
+        let descriptor = &index["manifests"][0];
+        assert_eq!(index["manifests"].as_array().unwrap().len(), 1);
+        assert_eq!(descriptor["mediaType"], OCI_MANIFEST_MEDIA_TYPE);
+        assert_eq!(descriptor["annotations"]["org.opencontainers.image.ref.name"],
+                   "happyhumans/possums:8.1.4-20160427165340");
+
+        let manifest: Value = serde_json::from_slice(&read_blob(&dst, descriptor)).unwrap();
+        assert_eq!(manifest["config"]["mediaType"], OCI_CONFIG_MEDIA_TYPE);
+        let config: Value = serde_json::from_slice(&read_blob(&dst, &manifest["config"]))
+            .unwrap();
+        // One layer for each of busybox, the Supervisor and the package, then the base layer
+        let layers = manifest["layers"].as_array().unwrap();
+        assert_eq!(layers.len(), 4);
+        for layer in layers.iter() {
+            assert_eq!(layer["mediaType"], OCI_LAYER_MEDIA_TYPE);
+            read_blob(&dst, layer);
+        }
+        let digests: Vec<Value> = layers.iter().map(|l| l["digest"].clone()).collect();
+        assert_eq!(config["rootfs"]["diff_ids"], Value::Array(digests));
+        assert_eq!(config["config"]["Entrypoint"],
+                   json!(["/hab/pkgs/core/hab-sup/0.1.0/20170101000000/bin/hab-sup"]));
+        assert_eq!(config["config"]["Cmd"],
+                   json!(["start", "happyhumans/possums/8.1.4/20160427165340"]));
+    }
+
+    #[test]
+    fn digest_writer_hashes_contents() {
+        let mut writer = DigestWriter::new(Vec::new());
+        writer.write_all(b"abc").unwrap();
+        let (bytes, blob) = writer.finish();
+        assert_eq!(bytes, b"abc");
+        assert_eq!(blob.size, 3);
+        assert_eq!(blob.digest,
+                   "sha256:ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+    }
+}
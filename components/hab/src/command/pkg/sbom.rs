@@ -0,0 +1,494 @@
+// Copyright (c) 2016-2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Generates a software bill of materials for a package and its transitive dependencies.
+//!
+//! The package can either be given as a path to a Habitat Artifact or as the identifier of an
+//! installed package. Dependencies are read from the installed packages, falling back to
+//! artifacts in the artifact cache.
+//!
+//! Two formats are supported:
+//!
+//! * `spdx`: an SPDX 2.3 document in tag-value format. SPDX requires a SHA1 checksum for every
+//!   file it lists, so only the BLAKE2b checksum of each artifact is included.
+//! * `cyclonedx`: a CycloneDX 1.4 document in JSON format, which also lists every file of each
+//!   package together with the BLAKE2b checksum recorded in the package's `FILES` metafile.
+
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::str::FromStr;
+
+use common::ui::UI;
+use hcore::crypto::hash;
+use hcore::package::{PackageArchive, PackageFile, PackageIdent, PackageInstall};
+use serde_json::{self, Value};
+use time;
+use uuid::Uuid;
+
+use {PRODUCT, VERSION};
+use error::{Error, Result};
+
+const NOASSERTION: &'static str = "NOASSERTION";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SbomFormat {
+    Spdx,
+    CycloneDx,
+}
+
+impl FromStr for SbomFormat {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "spdx" => Ok(SbomFormat::Spdx),
+            "cyclonedx" => Ok(SbomFormat::CycloneDx),
+            _ => Err(Error::UnsupportedSbomFormat(value.to_string())),
+        }
+    }
+}
+
+/// A package listed in the bill of materials.
+#[derive(Debug)]
+struct Component {
+    ident: PackageIdent,
+    deps: Vec<PackageIdent>,
+    licenses: Vec<String>,
+    source: Option<String>,
+    source_shasum: Option<String>,
+    upstream_url: Option<String>,
+    checksum: Option<String>,
+    files: Vec<PackageFile>,
+}
+
+impl Component {
+    fn new(ident: PackageIdent,
+           deps: Vec<PackageIdent>,
+           manifest: &str,
+           checksum: Option<String>,
+           files: Vec<PackageFile>)
+           -> Self {
+        Component {
+            ident: ident,
+            deps: deps,
+            licenses: manifest_field(manifest, "License")
+                .map(|l| l.split_whitespace().map(|s| s.to_string()).collect())
+                .unwrap_or(Vec::new()),
+            source: manifest_field(manifest, "Source").and_then(|s| parse_link(&s)),
+            source_shasum: manifest_field(manifest, "SHA").and_then(|s| parse_code(&s)),
+            upstream_url: manifest_field(manifest, "Upstream URL").and_then(|s| parse_link(&s)),
+            checksum: checksum,
+            files: files,
+        }
+    }
+
+    /// A component for a dependency which is neither installed nor cached, about which nothing
+    /// but its identifier is known.
+    fn unknown(ident: PackageIdent) -> Self {
+        Component::new(ident, Vec::new(), "", None, Vec::new())
+    }
+
+    fn from_archive(archive: &mut PackageArchive) -> Result<Self> {
+        let ident = try!(archive.ident());
+        let deps = try!(archive.deps());
+        let manifest = try!(archive.manifest());
+        let checksum = try!(archive.checksum());
+        let files = try!(archive.file_list());
+        Ok(Component::new(ident, deps, &manifest, Some(checksum), files))
+    }
+
+    fn from_install(install: &PackageInstall, artifact_cache: &Path) -> Result<Self> {
+        let ident = install.ident().clone();
+        let checksum = match ident.archive_name() {
+            Some(name) => {
+                let artifact = artifact_cache.join(name);
+                if artifact.is_file() {
+                    Some(try!(hash::hash_file(&artifact)))
+                } else {
+                    None
+                }
+            }
+            None => None,
+        };
+        Ok(Component::new(ident,
+                          try!(install.deps()),
+                          &try!(install.manifest()),
+                          checksum,
+                          try!(install.file_list())))
+    }
+}
+
+pub fn start(ui: &mut UI,
+             src: &str,
+             format: SbomFormat,
+             fs_root_path: &Path,
+             artifact_cache: &Path,
+             dst: Option<&Path>)
+             -> Result<()> {
+    let document = try!(generate(ui, src, format, fs_root_path, artifact_cache));
+    match dst {
+        Some(path) => {
+            let mut file = try!(File::create(path));
+            try!(file.write_all(document.as_bytes()));
+        }
+        None => print!("{}", document),
+    }
+    Ok(())
+}
+
+/// Renders the bill of materials of a package, given as the path to an artifact or the
+/// identifier of a package installed under `fs_root_path`.
+pub fn generate(ui: &mut UI,
+                src: &str,
+                format: SbomFormat,
+                fs_root_path: &Path,
+                artifact_cache: &Path)
+                -> Result<String> {
+    let (root, tdeps) = if Path::new(src).is_file() {
+        let mut archive = PackageArchive::new(src);
+        (try!(Component::from_archive(&mut archive)), try!(archive.tdeps()))
+    } else {
+        let ident = try!(PackageIdent::from_str(src));
+        let install = try!(PackageInstall::load(&ident, Some(fs_root_path)));
+        (try!(Component::from_install(&install, artifact_cache)), try!(install.tdeps()))
+    };
+
+    let mut components = vec![root];
+    for tdep in tdeps.into_iter() {
+        components.push(try!(component_for(ui, tdep, fs_root_path, artifact_cache)));
+    }
+
+    match format {
+        SbomFormat::Spdx => Ok(spdx(&components)),
+        SbomFormat::CycloneDx => cyclonedx(&components),
+    }
+}
+
+fn component_for(ui: &mut UI,
+                 ident: PackageIdent,
+                 fs_root_path: &Path,
+                 artifact_cache: &Path)
+                 -> Result<Component> {
+    if let Ok(install) = PackageInstall::load(&ident, Some(fs_root_path)) {
+        return Component::from_install(&install, artifact_cache);
+    }
+    if let Some(name) = ident.archive_name() {
+        let artifact = artifact_cache.join(name);
+        if artifact.is_file() {
+            return Component::from_archive(&mut PackageArchive::new(artifact));
+        }
+    }
+    try!(ui.warn(format!("Dependency {} is neither installed nor in the artifact cache, only \
+                          its identifier will be listed",
+                         &ident)));
+    Ok(Component::unknown(ident))
+}
+
+fn spdx(components: &[Component]) -> String {
+    let root = &components[0];
+    let mut doc = String::new();
+    doc.push_str("SPDXVersion: SPDX-2.3\n");
+    doc.push_str("DataLicense: CC0-1.0\n");
+    doc.push_str("SPDXID: SPDXRef-DOCUMENT\n");
+    doc.push_str(&format!("DocumentName: {}\n", root.ident));
+    doc.push_str(&format!("DocumentNamespace: https://habitat.sh/spdxdocs/{}-{}\n",
+                          root.ident,
+                          Uuid::new_v4()));
+    doc.push_str(&format!("Creator: Tool: {}-{}\n", PRODUCT, VERSION.trim()));
+    doc.push_str(&format!("Created: {}\n", time::now_utc().rfc3339()));
+
+    for component in components.iter() {
+        doc.push_str("\n");
+        doc.push_str(&format!("PackageName: {}/{}\n",
+                              component.ident.origin,
+                              component.ident.name));
+        doc.push_str(&format!("SPDXID: {}\n", spdx_id(&component.ident)));
+        if let (Some(ref version), Some(ref release)) =
+            (component.ident.version.as_ref(), component.ident.release.as_ref()) {
+            doc.push_str(&format!("PackageVersion: {}/{}\n", version, release));
+        }
+        doc.push_str(&format!("PackageSupplier: Organization: {}\n", component.ident.origin));
+        if let Some(name) = component.ident.archive_name() {
+            doc.push_str(&format!("PackageFileName: {}\n", name));
+        }
+        doc.push_str(&format!("PackageDownloadLocation: {}\n",
+                              component
+                                  .source
+                                  .as_ref()
+                                  .map(|s| s.as_str())
+                                  .unwrap_or(NOASSERTION)));
+        doc.push_str("FilesAnalyzed: false\n");
+        if let Some(ref checksum) = component.checksum {
+            doc.push_str(&format!("PackageChecksum: BLAKE2b-256: {}\n", checksum));
+        }
+        if let Some(ref url) = component.upstream_url {
+            doc.push_str(&format!("PackageHomePage: {}\n", url));
+        }
+        if let Some(ref shasum) = component.source_shasum {
+            doc.push_str(&format!("PackageSourceInfo: <text>Built from source with SHA256 \
+                                   checksum {}</text>\n",
+                                  shasum));
+        }
+        doc.push_str(&format!("PackageLicenseConcluded: {}\n", NOASSERTION));
+        doc.push_str(&format!("PackageLicenseDeclared: {}\n",
+                              license_expression(&component.licenses)
+                                  .unwrap_or(NOASSERTION.to_string())));
+        doc.push_str(&format!("PackageCopyrightText: {}\n", NOASSERTION));
+    }
+
+    doc.push_str("\n");
+    doc.push_str(&format!("Relationship: SPDXRef-DOCUMENT DESCRIBES {}\n",
+                          spdx_id(&root.ident)));
+    for component in components.iter() {
+        for dep in component.deps.iter() {
+            doc.push_str(&format!("Relationship: {} DEPENDS_ON {}\n",
+                                  spdx_id(&component.ident),
+                                  spdx_id(dep)));
+        }
+    }
+    doc
+}
+
+fn cyclonedx(components: &[Component]) -> Result<String> {
+    let mut dependencies = Vec::with_capacity(components.len());
+    for component in components.iter() {
+        let depends_on: Vec<String> = component.deps.iter().map(|d| d.to_string()).collect();
+        dependencies.push(json!({
+            "ref": component.ident.to_string(),
+            "dependsOn": depends_on,
+        }));
+    }
+    let bom = json!({
+        "bomFormat": "CycloneDX",
+        "specVersion": "1.4",
+        "serialNumber": format!("urn:uuid:{}", Uuid::new_v4()),
+        "version": 1,
+        "metadata": {
+            "timestamp": time::now_utc().rfc3339().to_string(),
+            "tools": [{ "vendor": "Habitat", "name": PRODUCT, "version": VERSION.trim() }],
+            "component": cyclonedx_component(&components[0], "application"),
+        },
+        "components": components[1..]
+            .iter()
+            .map(|c| cyclonedx_component(c, "library"))
+            .collect::<Vec<Value>>(),
+        "dependencies": dependencies,
+    });
+    let mut doc = try!(serde_json::to_string_pretty(&bom));
+    doc.push('\n');
+    Ok(doc)
+}
+
+fn cyclonedx_component(component: &Component, kind: &str) -> Value {
+    let ident = &component.ident;
+    let mut value = json!({
+        "type": kind,
+        "bom-ref": ident.to_string(),
+        "group": ident.origin,
+        "name": ident.name,
+    });
+    if let Some(ref version) = ident.version {
+        value["version"] = json!(version);
+    }
+    if let Some(ref release) = ident.release {
+        value["properties"] = json!([{ "name": "habitat:release", "value": release }]);
+    }
+    if let Some(ref checksum) = component.checksum {
+        value["hashes"] = json!([{ "alg": "BLAKE2b-256", "content": checksum }]);
+    }
+    if let Some(expression) = license_expression(&component.licenses) {
+        value["licenses"] = json!([{ "expression": expression }]);
+    }
+    let mut refs = Vec::new();
+    if let Some(ref source) = component.source {
+        let mut source_ref = json!({ "type": "distribution", "url": source });
+        if let Some(ref shasum) = component.source_shasum {
+            source_ref["hashes"] = json!([{ "alg": "SHA-256", "content": shasum }]);
+        }
+        refs.push(source_ref);
+    }
+    if let Some(ref url) = component.upstream_url {
+        refs.push(json!({ "type": "website", "url": url }));
+    }
+    if !refs.is_empty() {
+        value["externalReferences"] = Value::Array(refs);
+    }
+    if !component.files.is_empty() {
+        let files: Vec<Value> = component
+            .files
+            .iter()
+            .map(|file| {
+                     json!({
+                         "type": "file",
+                         "bom-ref": format!("{}:{}", ident, file.path),
+                         "name": file.path,
+                         "hashes": [{ "alg": "BLAKE2b-256", "content": file.checksum }],
+                     })
+                 })
+            .collect();
+        value["components"] = Value::Array(files);
+    }
+    value
+}
+
+/// Returns the value of a `* __Name__: value` line from a package's MANIFEST.
+fn manifest_field(manifest: &str, name: &str) -> Option<String> {
+    let prefix = format!("* __{}__:", name);
+    manifest
+        .lines()
+        .find(|l| l.starts_with(&prefix))
+        .map(|l| l[prefix.len()..].trim().to_string())
+        .and_then(|v| if v.is_empty() { None } else { Some(v) })
+}
+
+/// Extracts the URL from a Markdown link (ex: `[http://example.com](http://example.com)`). Any
+/// other value is a placeholder written when the plan didn't define a URL.
+fn parse_link(value: &str) -> Option<String> {
+    if !value.starts_with('[') || !value.ends_with(')') {
+        return None;
+    }
+    value
+        .find("](")
+        .map(|i| value[i + 2..value.len() - 1].to_string())
+}
+
+/// Extracts the value of Markdown inline code (ex: `` `abc123` ``). Any other value is a
+/// placeholder written when the plan didn't define a value.
+fn parse_code(value: &str) -> Option<String> {
+    if value.len() > 2 && value.starts_with('`') && value.ends_with('`') {
+        Some(value[1..value.len() - 1].to_string())
+    } else {
+        None
+    }
+}
+
+fn license_expression(licenses: &[String]) -> Option<String> {
+    if licenses.is_empty() {
+        None
+    } else {
+        Some(licenses.join(" AND "))
+    }
+}
+
+fn spdx_id(ident: &PackageIdent) -> String {
+    let id: String = ident
+        .to_string()
+        .chars()
+        .map(|c| match c {
+                 'a'...'z' | 'A'...'Z' | '0'...'9' | '.' => c,
+                 _ => '-',
+             })
+        .collect();
+    format!("SPDXRef-Package-{}", id)
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+    use std::str::FromStr;
+
+    use common::ui::{Coloring, UI};
+    use hcore::crypto::hash;
+    use hcore::package::PackageIdent;
+    use serde_json::{self, Value};
+
+    use super::{generate, manifest_field, parse_code, parse_link, spdx_id, SbomFormat};
+
+    fn fixture() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../core/tests/fixtures")
+            .join("happyhumans-possums-8.1.4-20160427165340-x86_64-linux.hart")
+    }
+
+    fn generate_for_fixture(format: SbomFormat) -> String {
+        let mut ui = UI::default_with(Coloring::Never, Some(false));
+        let nowhere = Path::new("/nonexistent");
+        generate(&mut ui,
+                 &fixture().to_string_lossy(),
+                 format,
+                 nowhere,
+                 nowhere)
+            .unwrap()
+    }
+
+    const MANIFEST: &'static str = "# core / redis\n\
+                                    Persistent key-value database\n\
+                                    \n\
+                                    * __Maintainer__: The Habitat Maintainers\n\
+                                    * __Upstream URL__: [http://redis.io](http://redis.io)\n\
+                                    * __License__: BSD-3-Clause \n\
+                                    * __Source__: [http://download.redis.io/redis-3.2.4.tar.gz]\
+                                    (http://download.redis.io/redis-3.2.4.tar.gz)\n\
+                                    * __SHA__: `2ad042c5a6c508223adeb9`\n";
+
+    #[test]
+    fn reads_manifest_fields() {
+        assert_eq!(manifest_field(MANIFEST, "License"),
+                   Some("BSD-3-Clause".to_string()));
+        assert_eq!(manifest_field(MANIFEST, "Source").and_then(|s| parse_link(&s)),
+                   Some("http://download.redis.io/redis-3.2.4.tar.gz".to_string()));
+        assert_eq!(manifest_field(MANIFEST, "Upstream URL").and_then(|s| parse_link(&s)),
+                   Some("http://redis.io".to_string()));
+        assert_eq!(manifest_field(MANIFEST, "SHA").and_then(|s| parse_code(&s)),
+                   Some("2ad042c5a6c508223adeb9".to_string()));
+        assert_eq!(manifest_field(MANIFEST, "Interpreters"), None);
+    }
+
+    #[test]
+    fn ignores_manifest_placeholders() {
+        assert_eq!(parse_link("source URL not provided or required"), None);
+        assert_eq!(parse_code("SHA256 checksum not provided or required"), None);
+    }
+
+    #[test]
+    fn generates_spdx_for_artifact() {
+        let doc = generate_for_fixture(SbomFormat::Spdx);
+        let checksum = hash::hash_file(&fixture()).unwrap();
+        let id = "SPDXRef-Package-happyhumans-possums-8.1.4-20160427165340";
+        assert!(doc.starts_with("SPDXVersion: SPDX-2.3\n"));
+        assert!(doc.contains("DocumentName: happyhumans/possums/8.1.4/20160427165340\n"));
+        assert!(doc.contains("PackageName: happyhumans/possums\n"));
+        assert!(doc.contains("PackageVersion: 8.1.4/20160427165340\n"));
+        assert!(doc.contains(&format!("SPDXID: {}\n", id)));
+        assert!(doc.contains(&format!("PackageChecksum: BLAKE2b-256: {}\n", checksum)));
+        assert!(doc.contains(&format!("Relationship: SPDXRef-DOCUMENT DESCRIBES {}\n", id)));
+        assert!(!doc.contains("DEPENDS_ON"));
+    }
+
+    #[test]
+    fn generates_cyclonedx_for_artifact() {
+        let doc = generate_for_fixture(SbomFormat::CycloneDx);
+        let bom: Value = serde_json::from_str(&doc).unwrap();
+        let component = &bom["metadata"]["component"];
+        assert_eq!(bom["bomFormat"], "CycloneDX");
+        assert_eq!(component["type"], "application");
+        assert_eq!(component["bom-ref"], "happyhumans/possums/8.1.4/20160427165340");
+        assert_eq!(component["group"], "happyhumans");
+        assert_eq!(component["name"], "possums");
+        assert_eq!(component["version"], "8.1.4");
+        assert_eq!(component["hashes"][0]["content"],
+                   Value::String(hash::hash_file(&fixture()).unwrap()));
+        assert_eq!(bom["components"], json!([]));
+        assert_eq!(bom["dependencies"][0]["ref"],
+                   "happyhumans/possums/8.1.4/20160427165340");
+    }
+
+    #[test]
+    fn spdx_ids_only_use_valid_characters() {
+        let ident = PackageIdent::from_str("core/gcc-libs/5.2.0_1/20170513212920").unwrap();
+        assert_eq!(spdx_id(&ident),
+                   "SPDXRef-Package-core-gcc-libs-5.2.0-1-20170513212920");
+    }
+}
@@ -13,14 +13,19 @@
 // limitations under the License.
 
 use error::Result;
-use depot_client::Client;
+use depot_client::{Client, SearchFilters};
 use {PRODUCT, VERSION};
 
-pub fn start(st: &str, url: &str) -> Result<()> {
+pub fn start(st: Option<&str>, filters: &SearchFilters, url: &str) -> Result<()> {
     let depot_client = Client::new(url, PRODUCT, VERSION, None)?;
-    let (packages, more) = depot_client.search_package(st)?;
+    let (packages, more) = depot_client.search_packages(st, filters)?;
     match packages.len() {
-        0 => println!("No packages found that match '{}'", st),
+        0 => {
+            match st {
+                Some(st) => println!("No packages found that match '{}'", st),
+                None => println!("No packages found that match the given filters"),
+            }
+        }
         _ => {
             for p in &packages {
                 if let (&Some(ref version), &Some(ref release)) = (&p.version, &p.release) {
@@ -28,6 +33,9 @@ pub fn start(st: &str, url: &str) -> Result<()> {
                 } else {
                     println!("{}/{}", p.origin, p.name);
                 }
+                for file in &p.files {
+                    println!("    {}", file);
+                }
             }
             if more {
                 println!("Search returned too many items, only showing the first {}",
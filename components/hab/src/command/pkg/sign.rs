@@ -19,14 +19,23 @@ use hcore::crypto::{artifact, SigKeyPair};
 
 use error::Result;
 
-pub fn start(ui: &mut UI, origin: &SigKeyPair, src: &Path, dst: &Path) -> Result<()> {
+pub fn start(ui: &mut UI,
+             origin: &SigKeyPair,
+             src: &Path,
+             dst: &Path,
+             countersign: bool)
+             -> Result<()> {
     try!(ui.begin(format!("Signing {}", src.display())));
     try!(ui.status(Status::Signing,
                    format!("{} with {} to create {}",
                            src.display(),
                            &origin.name_with_rev(),
                            dst.display())));
-    try!(artifact::sign(src, dst, origin));
+    if countersign {
+        try!(artifact::countersign(src, dst, origin));
+    } else {
+        try!(artifact::sign(src, dst, origin));
+    }
     try!(ui.end(format!("Signed artifact {}.", dst.display())));
     Ok(())
 }
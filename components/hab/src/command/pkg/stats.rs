@@ -0,0 +1,59 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use depot_client::Client;
+use hcore::package::PackageIdent;
+
+use error::Result;
+use {PRODUCT, VERSION};
+
+pub fn start(ident: &PackageIdent,
+             target: Option<&str>,
+             from: Option<&str>,
+             to: Option<&str>,
+             url: &str)
+             -> Result<()> {
+    let depot_client = Client::new(url, PRODUCT, VERSION, None)?;
+    let stats = depot_client
+        .package_download_stats(ident, target, from, to)?;
+    if stats.downloads.is_empty() {
+        println!("No downloads of {} recorded", ident);
+        return Ok(());
+    }
+
+    // Group the time series by release so that releases which are no longer pulled stand out
+    let mut releases = BTreeMap::new();
+    for download in &stats.downloads {
+        releases
+            .entry((download.ident.to_string(), download.target.clone()))
+            .or_insert(Vec::new())
+            .push(download);
+    }
+    for (&(ref release, ref target), downloads) in &releases {
+        let total: u64 = downloads.iter().map(|d| d.count).sum();
+        let last_day = &downloads.last().unwrap().day;
+        println!("{} ({}): {} downloads, last on {}",
+                 release,
+                 target,
+                 total,
+                 last_day);
+        for download in downloads {
+            println!("    {}  {}", download.day, download.count);
+        }
+    }
+    println!("Total: {} downloads", stats.total);
+    Ok(())
+}
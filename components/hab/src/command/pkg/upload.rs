@@ -63,13 +63,14 @@ pub fn start<P: AsRef<Path>>(ui: &mut UI,
     let hart_header = try!(get_artifact_header(&archive_path.as_ref()));
 
     let key_buf = key_path.as_ref().to_path_buf();
-    let public_keyfile_name = format!("{}.pub", &hart_header.key_name);
+    let key_name = try!(hart_header.key_name());
+    let public_keyfile_name = format!("{}.pub", key_name);
     let public_keyfile = key_buf.join(&public_keyfile_name);
 
     try!(ui.status(Status::Signed,
                    format!("artifact with {}", &public_keyfile_name)));
 
-    let (name, rev) = try!(parse_name_with_rev(&hart_header.key_name));
+    let (name, rev) = try!(parse_name_with_rev(key_name));
     let depot_client = try!(Client::new(url, PRODUCT, VERSION, None));
 
     try!(ui.begin(format!("Uploading public origin key {}", &public_keyfile_name)));
@@ -23,6 +23,7 @@ use depot_client;
 use common;
 use hcore;
 use handlebars;
+use serde_json;
 use toml;
 
 pub type Result<T> = result::Result<T, Error>;
@@ -46,12 +47,14 @@ pub enum Error {
     HabitatCore(hcore::Error),
     HandlebarsRenderError(handlebars::TemplateRenderError),
     IO(io::Error),
+    JsonSerializeError(serde_json::Error),
     PackageArchiveMalformed(String),
     PathPrefixError(path::StripPrefixError),
     ProvidesError(String),
     RootRequired,
     SubcommandNotSupported(String),
     UnsupportedExportFormat(String),
+    UnsupportedSbomFormat(String),
     TomlDeserializeError(toml::de::Error),
     TomlSerializeError(toml::ser::Error),
     Utf8Error(String),
@@ -103,6 +106,7 @@ impl fmt::Display for Error {
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::HandlebarsRenderError(ref e) => format!("{}", e),
             Error::IO(ref err) => format!("{}", err),
+            Error::JsonSerializeError(ref e) => format!("Can't serialize JSON: {}", e),
             Error::PackageArchiveMalformed(ref e) => {
                 format!("Package archive was unreadable or contained unexpected contents: {:?}",
                         e)
@@ -116,6 +120,7 @@ impl fmt::Display for Error {
                 format!("Subcommand `{}' not supported on this operating system", e)
             }
             Error::UnsupportedExportFormat(ref e) => format!("Unsupported export format: {}", e),
+            Error::UnsupportedSbomFormat(ref e) => format!("Unsupported SBOM format: {}", e),
             Error::TomlDeserializeError(ref e) => format!("Can't deserialize TOML: {}", e),
             Error::TomlSerializeError(ref e) => format!("Can't serialize TOML: {}", e),
             Error::Utf8Error(ref e) => format!("Error processing a string as UTF-8: {}", e),
@@ -143,12 +148,14 @@ impl error::Error for Error {
             Error::HabitatCore(ref err) => err.description(),
             Error::HandlebarsRenderError(ref err) => err.description(),
             Error::IO(ref err) => err.description(),
+            Error::JsonSerializeError(_) => "Can't serialize JSON",
             Error::PackageArchiveMalformed(_) => "Package archive was unreadable or had unexpected contents",
             Error::PathPrefixError(ref err) => err.description(),
             Error::ProvidesError(_) => "Can't find a package that provides the given search parameter",
             Error::RootRequired => "Root or administrator permissions required to complete operation",
             Error::SubcommandNotSupported(_) => "Subcommand not supported on this operating system",
             Error::UnsupportedExportFormat(_) => "Unsupported export format",
+            Error::UnsupportedSbomFormat(_) => "Unsupported SBOM format",
             Error::TomlDeserializeError(_) => "Can't deserialize TOML",
             Error::TomlSerializeError(_) => "Can't serialize TOML",
             Error::Utf8Error(_) => "Error processing string as UTF-8",
@@ -192,6 +199,12 @@ impl From<io::Error> for Error {
     }
 }
 
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Error {
+        Error::JsonSerializeError(err)
+    }
+}
+
 impl From<path::StripPrefixError> for Error {
     fn from(err: path::StripPrefixError) -> Error {
         Error::PathPrefixError(err)
@@ -66,7 +66,8 @@ pub fn command_from_min_pkg(ui: &mut UI,
                                                           VERSION,
                                                           fs_root_path,
                                                           &cache_artifact_path(None),
-                                                          false));
+                                                          false,
+                                                          None));
             command_from_min_pkg(ui, &command, &ident, &cache_key_path, retry + 1)
         }
         Err(e) => return Err(Error::from(e)),
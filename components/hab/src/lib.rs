@@ -30,6 +30,12 @@ extern crate retry;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
+#[macro_use]
+extern crate serde_json;
+extern crate sha2;
+#[cfg(test)]
+extern crate tempdir;
+extern crate time;
 extern crate toml;
 extern crate url;
 extern crate uuid;
@@ -17,6 +17,7 @@ extern crate env_logger;
 extern crate hab;
 extern crate habitat_core as hcore;
 extern crate habitat_common as common;
+extern crate habitat_depot_client as depot_client;
 extern crate handlebars;
 #[macro_use]
 extern crate lazy_static;
@@ -34,14 +35,17 @@ use std::thread;
 use clap::{ArgMatches, Shell};
 
 use common::ui::{Coloring, UI, NOCOLORING_ENVVAR, NONINTERACTIVE_ENVVAR};
+use depot_client::SearchFilters;
+use hcore::config::ConfigFile;
 use hcore::env as henv;
 use hcore::crypto::{init, default_cache_key_path, SigKeyPair};
 use hcore::crypto::keys::PairType;
 use hcore::fs::{cache_artifact_path, cache_analytics_path, cache_key_path};
 use hcore::service::ServiceGroup;
-use hcore::package::PackageIdent;
+use hcore::package::{Lockfile, PackageIdent};
 use hcore::url::{DEFAULT_DEPOT_URL, DEPOT_URL_ENVVAR};
 
+use hab::command::pkg::sbom::SbomFormat;
 use hab::{analytics, cli, command, config, AUTH_TOKEN_ENVVAR, ORIGIN_ENVVAR, PRODUCT, VERSION};
 use hab::error::{Error, Result};
 
@@ -86,6 +90,17 @@ fn start(ui: &mut UI) -> Result<()> {
                             e.exit();
                         });
     match app_matches.subcommand() {
+        ("bldr", Some(matches)) => {
+            match matches.subcommand() {
+                ("group", Some(m)) => {
+                    match m.subcommand() {
+                        ("status", Some(sc)) => try!(sub_bldr_group_status(sc)),
+                        _ => unreachable!(),
+                    }
+                }
+                _ => unreachable!(),
+            }
+        }
         ("cli", Some(matches)) => {
             match matches.subcommand() {
                 ("setup", Some(_)) => try!(sub_cli_setup(ui)),
@@ -113,6 +128,14 @@ fn start(ui: &mut UI) -> Result<()> {
             match matches.subcommand() {
                 ("binlink", Some(m)) => try!(sub_pkg_binlink(ui, m)),
                 ("build", Some(m)) => try!(sub_pkg_build(ui, m)),
+                ("bundle", Some(m)) => {
+                    match m.subcommand() {
+                        ("create", Some(sc)) => try!(sub_pkg_bundle_create(ui, sc)),
+                        ("install", Some(sc)) => try!(sub_pkg_bundle_install(ui, sc)),
+                        ("upload", Some(sc)) => try!(sub_pkg_bundle_upload(ui, sc)),
+                        _ => unreachable!(),
+                    }
+                }
                 ("config", Some(m)) => try!(sub_pkg_config(m)),
                 ("env", Some(m)) => try!(sub_pkg_env(m)),
                 ("exec", Some(m)) => try!(sub_pkg_exec(m, remaining_args)),
@@ -121,8 +144,10 @@ fn start(ui: &mut UI) -> Result<()> {
                 ("install", Some(m)) => try!(sub_pkg_install(ui, m)),
                 ("path", Some(m)) => try!(sub_pkg_path(m)),
                 ("provides", Some(m)) => try!(sub_pkg_provides(m)),
+                ("sbom", Some(m)) => try!(sub_pkg_sbom(ui, m)),
                 ("search", Some(m)) => try!(sub_pkg_search(m)),
                 ("sign", Some(m)) => try!(sub_pkg_sign(ui, m)),
+                ("stats", Some(m)) => try!(sub_pkg_stats(m)),
                 ("upload", Some(m)) => try!(sub_pkg_upload(ui, m)),
                 ("verify", Some(m)) => try!(sub_pkg_verify(ui, m)),
                 ("header", Some(m)) => try!(sub_pkg_header(ui, m)),
@@ -176,6 +201,13 @@ fn start(ui: &mut UI) -> Result<()> {
     Ok(())
 }
 
+fn sub_bldr_group_status(m: &ArgMatches) -> Result<()> {
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let group_id = m.value_of("GROUP_ID").unwrap().parse::<u64>().unwrap(); // Validated via clap
+    command::bldr::group::status::start(&url, group_id, m.is_present("WATCH"))
+}
+
 fn sub_cli_setup(ui: &mut UI) -> Result<()> {
     init();
 
@@ -306,11 +338,42 @@ fn sub_pkg_exec(m: &ArgMatches, cmd_args: Vec<OsString>) -> Result<()> {
     command::pkg::exec::start(&ident, cmd, cmd_args)
 }
 
+fn sub_pkg_bundle_create(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let channel = m.value_of("CHANNEL");
+    let idents: Vec<&str> = m.values_of("PKG_IDENT").unwrap().collect(); // Required via clap
+    let dst = m.value_of("OUTPUT").map(|p| Path::new(p));
+    init();
+    let pair = try!(SigKeyPair::get_latest_pair_for(&try!(origin_param_or_env(&m)),
+                                                    &default_cache_key_path(Some(&*FS_ROOT))));
+
+    command::pkg::bundle::create::start(ui, url, channel, &idents, &pair, &*FS_ROOT, dst)
+}
+
+fn sub_pkg_bundle_install(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let src = Path::new(m.value_of("BUNDLE").unwrap()); // Required via clap
+    init();
+
+    command::pkg::bundle::install::start(ui, &env_or_default, &src, &*FS_ROOT)
+}
+
+fn sub_pkg_bundle_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let token = try!(auth_token_param_or_env(&m));
+    let src = Path::new(m.value_of("BUNDLE").unwrap()); // Required via clap
+
+    command::pkg::bundle::upload::start(ui, &url, &token, &src)
+}
+
 fn sub_pkg_export(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())); // Required via clap
     let format = &m.value_of("FORMAT").unwrap(); // Required via clap
     let export_fmt = try!(command::pkg::export::format_for(ui, &format));
-    command::pkg::export::start(ui, &ident, &export_fmt)
+    let dst = m.value_of("OUTPUT").map(|p| Path::new(p));
+    command::pkg::export::start(ui, &ident, &export_fmt, dst)
 }
 
 fn sub_pkg_hash(m: &ArgMatches) -> Result<()> {
@@ -349,6 +412,12 @@ fn sub_pkg_install(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     } else {
         false
     };
+    let lockfile_path = m.value_of("LOCKFILE").map(|p| Path::new(p));
+    let mut lockfile = match lockfile_path {
+        Some(path) if path.is_file() => Some(Lockfile::from_file(path)?),
+        Some(_) => Some(Lockfile::default()),
+        None => None,
+    };
     init();
 
     for ident_or_artifact in ident_or_artifacts {
@@ -360,12 +429,16 @@ fn sub_pkg_install(ui: &mut UI, m: &ArgMatches) -> Result<()> {
                                                       VERSION,
                                                       &*FS_ROOT,
                                                       &cache_artifact_path(Some(&*FS_ROOT)),
-                                                      ignore_target));
+                                                      ignore_target,
+                                                      lockfile.as_mut()));
         if m.is_present("BINLINK") {
             let dest_dir = Path::new(m.value_of("DEST_DIR").unwrap_or(DEFAULT_BINLINK_DIR));
             command::pkg::binlink::binlink_all_in_pkg(ui, &pkg_ident, dest_dir, &*FS_ROOT)?;
         }
     }
+    if let (Some(path), Some(lockfile)) = (lockfile_path, lockfile) {
+        lockfile.write(path)?;
+    }
     Ok(())
 }
 
@@ -384,11 +457,57 @@ fn sub_pkg_provides(m: &ArgMatches) -> Result<()> {
     command::pkg::provides::start(&filename, &*FS_ROOT, full_releases, full_paths)
 }
 
+fn sub_pkg_sbom(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let src = m.value_of("PKG_IDENT_OR_ARTIFACT").unwrap(); // Required via clap
+    let format = try!(SbomFormat::from_str(m.value_of("FORMAT").unwrap_or("spdx")));
+    let dst = m.value_of("OUTPUT").map(|p| Path::new(p));
+
+    command::pkg::sbom::start(ui,
+                              &src,
+                              format,
+                              &*FS_ROOT,
+                              &cache_artifact_path(Some(&*FS_ROOT)),
+                              dst)
+}
+
 fn sub_pkg_search(m: &ArgMatches) -> Result<()> {
     let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
     let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
-    let search_term = m.value_of("SEARCH_TERM").unwrap(); // Required via clap
-    command::pkg::search::start(&search_term, &url)
+    let search_term = m.value_of("SEARCH_TERM");
+    let list = |name: &str| -> Vec<String> {
+        m.value_of(name)
+            .map(|v| {
+                     v.split(',')
+                         .map(|s| s.trim().to_string())
+                         .filter(|s| !s.is_empty())
+                         .collect()
+                 })
+            .unwrap_or(Vec::new())
+    };
+    let filters = SearchFilters {
+        origin: m.value_of("ORIGIN").map(|v| v.to_string()),
+        channel: m.value_of("CHANNEL").map(|v| v.to_string()),
+        target: m.value_of("TARGET").map(|v| v.to_string()),
+        min_version: m.value_of("MIN_VERSION").map(|v| v.to_string()),
+        max_version: m.value_of("MAX_VERSION").map(|v| v.to_string()),
+        // Ports are checked by clap
+        exposes: list("EXPOSES").iter().map(|p| p.parse().unwrap()).collect(),
+        binds: list("BINDS"),
+        binaries: list("BINARIES"),
+        file: m.value_of("FILE").map(|v| v.to_string()),
+    };
+    command::pkg::search::start(search_term, &filters, &url)
+}
+
+fn sub_pkg_stats(m: &ArgMatches) -> Result<()> {
+    let env_or_default = henv::var(DEPOT_URL_ENVVAR).unwrap_or(DEFAULT_DEPOT_URL.to_string());
+    let url = m.value_of("DEPOT_URL").unwrap_or(&env_or_default);
+    let ident = try!(PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap()));
+    command::pkg::stats::start(&ident,
+                               m.value_of("TARGET"),
+                               m.value_of("FROM"),
+                               m.value_of("TO"),
+                               &url)
 }
 
 fn sub_pkg_sign(ui: &mut UI, m: &ArgMatches) -> Result<()> {
@@ -398,7 +517,7 @@ fn sub_pkg_sign(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let pair = try!(SigKeyPair::get_latest_pair_for(&try!(origin_param_or_env(&m)),
                                                     &default_cache_key_path(Some(&*FS_ROOT))));
 
-    command::pkg::sign::start(ui, &pair, &src, &dst)
+    command::pkg::sign::start(ui, &pair, &src, &dst, m.is_present("COUNTERSIGN"))
 }
 
 fn sub_pkg_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
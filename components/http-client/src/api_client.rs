@@ -15,6 +15,7 @@
 use std::path::Path;
 use std::time::Duration;
 
+use hab_core::package::PackageTarget;
 use hab_core::util::sys;
 use hyper::client::{Client as HyperClient, IntoUrl, RequestBuilder};
 use hyper::client::pool::{Config, Pool};
@@ -71,6 +72,38 @@ impl ApiClient {
                   fs_root_path: Option<&Path>)
                   -> Result<Self>
         where T: IntoUrl
+    {
+        Self::build(endpoint, product, version, fs_root_path, None)
+    }
+
+    /// Creates and returns a new `ApiClient` instance which identifies itself as running on the
+    /// given package target rather than on the current system, for callers which act on behalf
+    /// of a client on another platform.
+    ///
+    /// # Errors
+    ///
+    /// * If the underlying Hyper client cannot be created
+    /// * If a suitable SSL context cannot be established
+    /// * If an HTTP proxy cannot be correctly setup
+    /// * If a `User-Agent` HTTP header string cannot be constructed
+    pub fn new_for_target<T>(endpoint: T,
+                             product: &str,
+                             version: &str,
+                             fs_root_path: Option<&Path>,
+                             target: &PackageTarget)
+                             -> Result<Self>
+        where T: IntoUrl
+    {
+        Self::build(endpoint, product, version, fs_root_path, Some(target))
+    }
+
+    fn build<T>(endpoint: T,
+                product: &str,
+                version: &str,
+                fs_root_path: Option<&Path>,
+                target: Option<&PackageTarget>)
+                -> Result<Self>
+        where T: IntoUrl
     {
         let endpoint = endpoint.into_url()?;
         Ok(ApiClient {
@@ -78,7 +111,7 @@ impl ApiClient {
                proxy: proxy_unless_domain_exempted(Some(&endpoint))?,
                target_scheme: endpoint.scheme().to_string(),
                endpoint: endpoint,
-               user_agent_header: user_agent(product, version)?,
+               user_agent_header: user_agent(product, version, target)?,
            })
     }
 
@@ -283,13 +316,23 @@ fn new_hyper_client(url: &Url, fs_root_path: Option<&Path>) -> Result<HyperClien
 /// # Errors
 ///
 /// * If system information cannot be obtained via `uname`
-fn user_agent(product: &str, version: &str) -> Result<UserAgent> {
+fn user_agent(product: &str,
+              version: &str,
+              target: Option<&PackageTarget>)
+              -> Result<UserAgent> {
     let uname = try!(sys::uname());
-    let ua = format!("{}/{} ({}-{}; {})",
+    let target = match target {
+        Some(target) => target.to_string(),
+        None => {
+            format!("{}-{}",
+                    uname.machine.trim().to_lowercase(),
+                    uname.sys_name.trim().to_lowercase())
+        }
+    };
+    let ua = format!("{}/{} ({}; {})",
                      product.trim(),
                      version.trim(),
-                     uname.machine.trim().to_lowercase(),
-                     uname.sys_name.trim().to_lowercase(),
+                     target,
                      uname.release.trim().to_lowercase());
     debug!("User-Agent: {}", &ua);
     Ok(UserAgent(ua))
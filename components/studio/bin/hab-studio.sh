@@ -88,6 +88,10 @@ ENVIRONMENT VARIABLES:
     HAB_ORIGIN          Propagates this variable into any studios
     HAB_ORIGIN_KEYS     Installs secret keys (\`-k' option overrides)
     HAB_STUDIOS_HOME    Sets a home path for all Studios (default: /hab/studios)
+    HAB_STUDIO_DEP_CACHE
+                        Mounts this directory of cached artifacts read-only
+                        into a new Studio and uses them for installing
+                        packages
     HAB_STUDIO_ROOT     Sets a Studio root (\`-r' option overrides)
     NO_SRC_PATH         If set, do not mount source path (\`-n' flag overrides)
     QUIET               Prints less output (\`-q' flag overrides)
@@ -433,6 +437,26 @@ new_studio() {
       fi
     fi
 
+    # Mount a cache of artifacts shared with other Studios read-only, and link
+    # its artifacts into the artifact cache so that they aren't downloaded again
+    if [ -n "${HAB_STUDIO_DEP_CACHE:-}" ]; then
+      $bb mkdir -p $v $HAB_STUDIO_ROOT$HAB_DEP_CACHE_PATH
+      if ! $bb mount | $bb grep -q "on $HAB_STUDIO_ROOT$HAB_DEP_CACHE_PATH type"; then
+        $bb mount $v --bind $HAB_STUDIO_DEP_CACHE $HAB_STUDIO_ROOT$HAB_DEP_CACHE_PATH
+        $bb mount $v -o remount,ro,bind $HAB_STUDIO_ROOT$HAB_DEP_CACHE_PATH
+      fi
+      $bb mkdir -p $v $HAB_STUDIO_ROOT$HAB_CACHE_ARTIFACT_PATH
+      for artifact in $HAB_STUDIO_ROOT$HAB_DEP_CACHE_PATH/*/*.hart; do
+        if [ ! -f "$artifact" ]; then
+          continue
+        fi
+        local cached="$HAB_STUDIO_ROOT$HAB_CACHE_ARTIFACT_PATH/$($bb basename $artifact)"
+        if [ ! -e "$cached" ]; then
+          $bb ln -s $v "${artifact#$HAB_STUDIO_ROOT}" "$cached"
+        fi
+      done
+    fi
+
     if [ -h "$HAB_STUDIO_ROOT/dev/shm" ]; then
       $bb mkdir -p $v $HAB_STUDIO_ROOT/$($bb readlink $HAB_STUDIO_ROOT/dev/shm)
     fi
@@ -688,8 +712,7 @@ enter_studio() {
   trap cleanup_studio EXIT
 
   # Become the `chroot` process
-  $bb chroot "$HAB_STUDIO_ROOT" \
-    $studio_env_command -i $env $studio_enter_command $*
+  chroot_studio "$env" $studio_enter_command $*
 }
 
 # **Internal** Run a build command using a Studio.
@@ -723,8 +746,7 @@ build_studio() {
   fi
 
   # Run the build command in the `chroot` environment
-  echo $studio_build_command $* | $bb chroot "$HAB_STUDIO_ROOT" \
-    $studio_env_command -i $env $studio_run_command
+  echo $studio_build_command $* | chroot_studio "$env" $studio_run_command
 }
 
 # **Internal** Run an arbitrary command in a Studio.
@@ -751,8 +773,7 @@ run_studio() {
   fi
 
   # Run the command in the `chroot` environment
-  echo $* | $bb chroot "$HAB_STUDIO_ROOT" \
-    $studio_env_command -i $env $studio_run_command
+  echo $* | chroot_studio "$env" $studio_run_command
 }
 
 # **Internal** Destroy a Studio.
@@ -813,6 +834,10 @@ rm_studio() {
     $bb umount $v -l $HAB_STUDIO_ROOT/var/run/docker.sock
   fi
 
+  if $bb mount | $bb grep -q "on $HAB_STUDIO_ROOT$HAB_DEP_CACHE_PATH type"; then
+    $bb umount $v -l $HAB_STUDIO_ROOT$HAB_DEP_CACHE_PATH
+  fi
+
   # Remove remaining filesystem
   $bb rm -rf $v $HAB_STUDIO_ROOT
 }
@@ -903,6 +928,21 @@ exit_with() {
   exit $2
 }
 
+# **Internal** Runs a command as the `chroot` process of the Studio, with the
+# environment set built by `chroot_env`. Any build secrets, set as
+# `HAB_STUDIO_SECRET_<name>`, are passed into the Studio's environment as
+# `<name>`. They are handed to `env(1)` as separate arguments rather than
+# through `$env`, so their values are never split on whitespace or globbed.
+chroot_studio() {
+  local env="$1"
+  shift
+  local secret_name
+  for secret_name in $($bb env | $bb sed -n 's/^HAB_STUDIO_SECRET_\([A-Za-z0-9_]*\)=.*$/\1/p'); do
+    eval "set -- \"$secret_name=\$HAB_STUDIO_SECRET_$secret_name\" \"\$@\""
+  done
+  $bb chroot "$HAB_STUDIO_ROOT" $studio_env_command -i $env "$@"
+}
+
 # **Internal** Builds up the environment set to pass to an `env(1)` command for
 # use in a `chroot` environment which is printed on stdout.
 chroot_env() {
@@ -1074,6 +1114,9 @@ HAB_PKG_PATH=$HAB_ROOT_PATH/pkgs
 # The default download root path for package artifacts, used on package
 # installation
 HAB_CACHE_ARTIFACT_PATH=$HAB_ROOT_PATH/cache/artifacts
+# The path a cache of artifacts shared with other Studios is mounted at, when
+# one is given
+HAB_DEP_CACHE_PATH=$HAB_ROOT_PATH/cache/dep-artifacts
 
 #
 bb="$libexec_path/busybox"
@@ -82,7 +82,8 @@ pub fn run(cfg: ManagerConfig,
                                                      VERSION,
                                                      Path::new(&*FS_ROOT_PATH),
                                                      &fs::cache_artifact_path(None),
-                                                     false)?;
+                                                     false,
+                                                     None)?;
         }
         Manager::save_spec_for(&cfg, spec)?;
     }
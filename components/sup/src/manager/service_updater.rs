@@ -397,9 +397,8 @@ impl Worker {
                                                   &Path::new(&*FS_ROOT_PATH)
                                                        .join(CACHE_ARTIFACT_PATH),
                                                   self.ui.progress()));
-        try!(archive.verify(&default_cache_key_path(None)));
         outputln!("Installing {}", package);
-        try!(archive.unpack(None));
+        try!(archive.unpack_verified(&default_cache_key_path(None), None));
         let pkg = PackageInstall::load(archive.ident().as_ref().unwrap(), Some(&*FS_ROOT_PATH))?;
         Ok(pkg)
     }
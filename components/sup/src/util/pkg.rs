@@ -42,7 +42,8 @@ pub fn install(ui: &mut UI,
                                                                    VERSION,
                                                                    fs_root_path,
                                                                    &fs::cache_artifact_path(None),
-                                                                   false)?;
+                                                                   false,
+                                                                   None)?;
     Ok(PackageInstall::load(&installed_ident, Some(&fs_root_path))?)
 }
 